@@ -0,0 +1,107 @@
+//! The [`AwsKms`] struct provides functionality to create AWS KMS signers from
+//! a configuration.
+
+use agglayer_config::AwsKmsConfig;
+use alloy::signers::aws::AwsSigner;
+use aws_config::{BehaviorVersion, Region};
+use aws_sdk_kms::types::KeySpec;
+use eyre::{bail, Context as _};
+use tracing::debug;
+
+pub(crate) mod signer;
+
+pub use signer::KmsSigner;
+
+#[derive(Debug)]
+pub struct AwsKms {
+    /// The L1 chain id.
+    chain_id: u64,
+    /// The AWS KMS configuration.
+    config: AwsKmsConfig,
+}
+
+#[derive(Debug)]
+pub struct KmsSigners {
+    // The signer for PP settlement.
+    pub pp_settlement: KmsSigner,
+    // The signer for transaction settlement, if not defined is expected
+    // that `pp_settlement` signer will be used.
+    pub tx_settlement: Option<KmsSigner>,
+}
+
+impl AwsKms {
+    /// Creates a new AWS KMS instance.
+    pub fn new(chain_id: u64, config: AwsKmsConfig) -> Self {
+        Self { chain_id, config }
+    }
+
+    /// Creates the AWS KMS signers from the configuration.
+    ///
+    /// Credentials are loaded through the default AWS provider chain. The
+    /// region is taken from the configuration, falling back to the provider
+    /// chain (e.g. the `AWS_REGION` environment variable).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a key cannot be described, if it
+    /// is not an `ECC_SECG_P256K1` key, or if its public key cannot be
+    /// retrieved.
+    pub async fn aws_kms_signers(&self) -> eyre::Result<KmsSigners> {
+        let mut loader = aws_config::defaults(BehaviorVersion::latest());
+        if let Some(region) = &self.config.region {
+            loader = loader.region(Region::new(region.clone()));
+        }
+        let client = aws_sdk_kms::Client::new(&loader.load().await);
+
+        self.signers(&client).await
+    }
+
+    /// Creates the signers of the configured keys through the given client.
+    async fn signers(&self, client: &aws_sdk_kms::Client) -> eyre::Result<KmsSigners> {
+        let pp_settlement_signer = self
+            .signer(client, &self.config.pp_settlement_key_id)
+            .await
+            .wrap_err("Unable to create PP settlement AwsSigner")?;
+
+        let tx_settlement_signer =
+            if self.config.pp_settlement_key_id == self.config.tx_settlement_key_id {
+                None
+            } else {
+                Some(
+                    self.signer(client, &self.config.tx_settlement_key_id)
+                        .await
+                        .wrap_err("Unable to create tx settlement AwsSigner")?,
+                )
+            };
+
+        Ok(KmsSigners {
+            pp_settlement: KmsSigner::new(pp_settlement_signer),
+            tx_settlement: tx_settlement_signer.map(KmsSigner::new),
+        })
+    }
+
+    /// Creates the signer of a single key, checking its key spec first.
+    async fn signer(&self, client: &aws_sdk_kms::Client, key_id: &str) -> eyre::Result<AwsSigner> {
+        let description = client
+            .describe_key()
+            .key_id(key_id)
+            .send()
+            .await
+            .wrap_err_with(|| format!("Unable to describe AWS KMS key {key_id}"))?;
+
+        let key_spec = description
+            .key_metadata()
+            .and_then(|metadata| metadata.key_spec());
+        debug!("Using AWS KMS key {key_id} with spec {key_spec:?}");
+        if key_spec != Some(&KeySpec::EccSecgP256K1) {
+            bail!("AWS KMS key {key_id} is not an ECC_SECG_P256K1 key: {key_spec:?}");
+        }
+
+        AwsSigner::new(client.clone(), key_id.to_owned(), Some(self.chain_id))
+            .await
+            .wrap_err_with(|| format!("Unable to load AWS KMS key {key_id}"))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use agglayer_config::AwsKmsConfig;
+use alloy::signers::{local::PrivateKeySigner, SignerSync as _};
+use alloy_primitives::{B256, U256};
+use aws_sdk_kms::config::{BehaviorVersion, Credentials, Region};
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse as _, Response},
+    routing::post,
+    Router,
+};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde_json::{json, Value};
+
+use super::*;
+
+const CHAIN_ID: u64 = 1337;
+
+const KEY_ID: &str = "alias/pp-settlement";
+
+/// Prefix of the DER-encoded SubjectPublicKeyInfo of a secp256k1 key, followed
+/// by its uncompressed point.
+const SPKI_PREFIX: [u8; 23] = [
+    0x30, 0x56, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b,
+    0x81, 0x04, 0x00, 0x0a, 0x03, 0x42, 0x00,
+];
+
+/// An AWS KMS holding a single key, answering the JSON protocol of the SDK.
+struct MockKms {
+    /// The key actually used to sign.
+    wallet: PrivateKeySigner,
+    /// The key spec advertised through `DescribeKey`.
+    key_spec: &'static str,
+    /// Whether the signatures are returned with a high `s`, which KMS does
+    /// not normalize.
+    high_s: bool,
+    /// Whether `Sign` fails.
+    sign_fails: bool,
+}
+
+impl MockKms {
+    fn new() -> Self {
+        Self {
+            wallet: PrivateKeySigner::from_slice(&[0x55; 32]).unwrap(),
+            key_spec: "ECC_SECG_P256K1",
+            high_s: false,
+            sign_fails: false,
+        }
+    }
+
+    fn public_key_der(&self) -> Vec<u8> {
+        let point = self
+            .wallet
+            .credential()
+            .verifying_key()
+            .to_encoded_point(false);
+
+        [SPKI_PREFIX.as_slice(), point.as_bytes()].concat()
+    }
+
+    /// Sign the digest into a DER-encoded signature, as KMS does.
+    fn sign(&self, digest: B256) -> Vec<u8> {
+        let signature = self.wallet.sign_hash_sync(&digest).unwrap();
+        let s = if self.high_s {
+            secp256k1_order() - signature.s()
+        } else {
+            signature.s()
+        };
+
+        der_signature(signature.r(), s)
+    }
+}
+
+fn secp256k1_order() -> U256 {
+    "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+        .parse()
+        .unwrap()
+}
+
+fn der_integer(value: U256) -> Vec<u8> {
+    let bytes = value.to_be_bytes::<32>();
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(31);
+    let mut integer = bytes[start..].to_vec();
+    if integer[0] & 0x80 != 0 {
+        integer.insert(0, 0);
+    }
+
+    [vec![0x02, integer.len() as u8], integer].concat()
+}
+
+fn der_signature(r: U256, s: U256) -> Vec<u8> {
+    let integers = [der_integer(r), der_integer(s)].concat();
+
+    [vec![0x30, integers.len() as u8], integers].concat()
+}
+
+fn kms_response(status: StatusCode, body: Value) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/x-amz-json-1.1")],
+        body.to_string(),
+    )
+        .into_response()
+}
+
+fn kms_error(kind: &str, message: &str) -> Response {
+    kms_response(
+        StatusCode::BAD_REQUEST,
+        json!({ "__type": kind, "message": message }),
+    )
+}
+
+async fn handle(State(mock): State<Arc<MockKms>>, headers: HeaderMap, body: Bytes) -> Response {
+    let target = headers
+        .get("x-amz-target")
+        .and_then(|target| target.to_str().ok())
+        .unwrap_or_default();
+    let request: Value = serde_json::from_slice(&body).unwrap();
+    if request["KeyId"] != KEY_ID {
+        return kms_error("NotFoundException", "Key does not exist");
+    }
+
+    match target {
+        "TrentService.DescribeKey" => kms_response(
+            StatusCode::OK,
+            json!({ "KeyMetadata": { "KeyId": KEY_ID, "KeySpec": mock.key_spec } }),
+        ),
+        "TrentService.GetPublicKey" => kms_response(
+            StatusCode::OK,
+            json!({
+                "KeyId": KEY_ID,
+                "KeySpec": mock.key_spec,
+                "KeyUsage": "SIGN_VERIFY",
+                "PublicKey": STANDARD.encode(mock.public_key_der()),
+            }),
+        ),
+        "TrentService.Sign" if mock.sign_fails => {
+            kms_error("KMSInvalidStateException", "Key is disabled")
+        }
+        "TrentService.Sign" => {
+            let digest = STANDARD
+                .decode(request["Message"].as_str().unwrap())
+                .unwrap();
+            kms_response(
+                StatusCode::OK,
+                json!({
+                    "KeyId": KEY_ID,
+                    "Signature": STANDARD.encode(mock.sign(B256::from_slice(&digest))),
+                    "SigningAlgorithm": "ECDSA_SHA_256",
+                }),
+            )
+        }
+        target => panic!("unexpected KMS call {target}"),
+    }
+}
+
+/// Starts the mock KMS, returning a client pointing to it.
+async fn start_mock_kms(mock: MockKms) -> aws_sdk_kms::Client {
+    let app = Router::new()
+        .route("/", post(handle))
+        .with_state(Arc::new(mock));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let config = aws_sdk_kms::Config::builder()
+        .behavior_version(BehaviorVersion::latest())
+        .region(Region::new("us-east-1"))
+        .credentials_provider(Credentials::new("test", "test", None, None, "test"))
+        .endpoint_url(url)
+        .build();
+
+    aws_sdk_kms::Client::from_conf(config)
+}
+
+fn aws_kms(key_id: &str) -> AwsKms {
+    AwsKms::new(
+        CHAIN_ID,
+        AwsKmsConfig {
+            region: None,
+            pp_settlement_key_id: key_id.to_owned(),
+            tx_settlement_key_id: key_id.to_owned(),
+        },
+    )
+}
+
+async fn pp_settlement_signer(mock: MockKms) -> KmsSigner {
+    let client = start_mock_kms(mock).await;
+    let signers = aws_kms(KEY_ID).signers(&client).await.unwrap();
+    assert!(signers.tx_settlement.is_none());
+
+    signers.pp_settlement
+}
+
+#[tokio::test]
+async fn signs_hashes_and_messages() {
+    let mock = MockKms::new();
+    let address = mock.wallet.address();
+    let signer = pp_settlement_signer(mock).await;
+    assert_eq!(signer.address(), address);
+    assert_eq!(signer.chain_id(), Some(CHAIN_ID));
+
+    let hash = B256::from([0x42; 32]);
+    let signature = alloy::signers::Signer::sign_hash(&signer, &hash)
+        .await
+        .unwrap();
+    assert_eq!(
+        signature.recover_address_from_prehash(&hash).unwrap(),
+        address
+    );
+
+    let message = b"test message";
+    let signature = signer.sign_message(message).await.unwrap();
+    assert_eq!(
+        signature.recover_address_from_msg(message).unwrap(),
+        address
+    );
+}
+
+#[tokio::test]
+async fn normalizes_high_s_signatures() {
+    let mock = MockKms {
+        high_s: true,
+        ..MockKms::new()
+    };
+    let wallet = mock.wallet.clone();
+    let signer = pp_settlement_signer(mock).await;
+
+    let hash = B256::from([0x42; 32]);
+    let signature = alloy::signers::Signer::sign_hash(&signer, &hash)
+        .await
+        .unwrap();
+
+    assert!(signature.s() <= secp256k1_order() >> 1);
+    assert_eq!(signature, wallet.sign_hash_sync(&hash).unwrap());
+    assert_eq!(
+        signature.recover_address_from_prehash(&hash).unwrap(),
+        wallet.address()
+    );
+}
+
+#[tokio::test]
+async fn refuses_keys_of_another_spec() {
+    let client = start_mock_kms(MockKms {
+        key_spec: "ECC_NIST_P256",
+        ..MockKms::new()
+    })
+    .await;
+
+    let error = aws_kms(KEY_ID).signers(&client).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unable to create PP settlement AwsSigner"
+    );
+    assert!(error
+        .chain()
+        .any(|cause| cause.to_string().contains("is not an ECC_SECG_P256K1 key")));
+}
+
+#[tokio::test]
+async fn reports_unknown_keys() {
+    let client = start_mock_kms(MockKms::new()).await;
+
+    let error = aws_kms("alias/unknown").signers(&client).await.unwrap_err();
+    assert!(error
+        .chain()
+        .any(|cause| cause.to_string() == "Unable to describe AWS KMS key alias/unknown"));
+}
+
+#[tokio::test]
+async fn reports_signing_failures() {
+    let signer = pp_settlement_signer(MockKms {
+        sign_fails: true,
+        ..MockKms::new()
+    })
+    .await;
+
+    let error = alloy::signers::Signer::sign_hash(&signer, &B256::from([0x42; 32]))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, alloy::signers::Error::Other(_)));
+
+    let error = signer.sign_message(b"test message").await.unwrap_err();
+    assert_eq!(error.to_string(), "Unable to sign message");
+}
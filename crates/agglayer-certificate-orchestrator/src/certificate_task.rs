@@ -14,7 +14,7 @@ use agglayer_telemetry::certificate::{self, CertificateStage};
 use agglayer_types::SettlementTxHash;
 use agglayer_types::{
     Certificate, CertificateHeader, CertificateStatus, CertificateStatusError, ContractCallOutcome,
-    Digest, Proof, SettlementJob, SettlementJobResult, U256,
+    Digest, Proof, SettlementJob, SettlementJobResult, SettlementTxType, U256,
 };
 use pessimistic_proof::{core::PESSIMISTIC_PROOF_PROGRAM_SELECTOR, PessimisticProofOutput};
 use tokio::sync::{mpsc, oneshot};
@@ -171,6 +171,11 @@ where
             {
                 info!(%job_id, "Proven certificate already has a settlement job; resuming");
                 self.set_status(CertificateStatus::Candidate)?;
+                self.send_to_network_task(NetworkTaskMessage::CertificateCandidate {
+                    height: self.header.height,
+                    certificate_id,
+                })
+                .await?;
                 return self.process_from_candidate(true).await;
             }
 
@@ -348,6 +353,11 @@ where
         // Close out the `proven` (submission) stage, then record the new status.
         self.record_stage();
         self.set_status(CertificateStatus::Candidate)?;
+        self.send_to_network_task(NetworkTaskMessage::CertificateCandidate {
+            height: self.header.height,
+            certificate_id,
+        })
+        .await?;
 
         #[cfg(feature = "testutils")]
         testutils::inject_fail_points_after_proving(
@@ -426,6 +436,10 @@ where
             eth_value: U256::ZERO,
             // Resolved and capped at the ceiling by the settlement service.
             gas_limit: 0,
+            network_id: Some(self.header.network_id),
+            tx_type: SettlementTxType::Eip1559,
+            access_list: None,
+            batched_jobs: Vec::new(),
         })
     }
 
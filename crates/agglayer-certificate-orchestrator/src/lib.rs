@@ -18,12 +18,15 @@ use agglayer_storage::{
         PerEpochReader, PerEpochWriter, StateReader, StateWriter,
     },
 };
-use agglayer_types::{CertificateId, EpochNumber, Height, NetworkId};
+use agglayer_types::{CertificateHeader, CertificateId, EpochNumber, Height, NetworkId};
 use arc_swap::ArcSwap;
 use futures_util::{stream::FuturesUnordered, FutureExt, Stream, StreamExt, TryFutureExt};
 use network_task::{NetworkTask, NewCertificate};
 use tokio::{
-    sync::mpsc::{self, Receiver},
+    sync::{
+        broadcast,
+        mpsc::{self, Receiver},
+    },
     task::JoinHandle,
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
@@ -110,6 +113,10 @@ pub struct CertificateOrchestrator<
 
     /// Settlement service for submitting settlement jobs
     settlement_service: Arc<SettlementService>,
+
+    /// Optional sender used by the network tasks to publish certificate
+    /// status transitions.
+    certificate_status_sender: Option<broadcast::Sender<CertificateHeader>>,
 }
 
 impl<CertifierClient, PendingStore, EpochsStore, PerEpochStore, StateStore, SettlementService>
@@ -158,6 +165,7 @@ where
             spawned_network_tasks: Default::default(),
             network_tasks: FuturesUnordered::new(),
             settlement_service,
+            certificate_status_sender: None,
         })
     }
 }
@@ -190,6 +198,8 @@ where
     /// - `cancellation_token`: Sets the cancellation token for graceful
     ///   shutdown.
     /// - `epoch_packing_builder`: Sets the task builder for epoch packing.
+    /// - `certificate_status_sender`: Optionally sets the channel on which
+    ///   certificate status transitions are published.
     /// - `start`: Starts the CertificateOrchestrator.
     ///
     /// # Errors
@@ -209,6 +219,7 @@ where
         current_epoch: Arc<ArcSwap<PerEpochStore>>,
         state_store: Arc<StateStore>,
         settlement_service: Arc<SettlementService>,
+        certificate_status_sender: Option<broadcast::Sender<CertificateHeader>>,
     ) -> eyre::Result<JoinHandle<()>> {
         let mut orchestrator = Self::try_new(
             clock,
@@ -221,6 +232,7 @@ where
             state_store,
             settlement_service,
         )?;
+        orchestrator.certificate_status_sender = certificate_status_sender;
 
         // Try to spawn the certifier tasks for the next height of each network
         for ProvenCertificate(_, network_id, _height) in
@@ -270,7 +282,8 @@ where
             receiver,
             self.settlement_service.clone(),
             self.current_epoch.clone(),
-        )?;
+        )?
+        .with_certificate_status_sender(self.certificate_status_sender.clone());
 
         let task_future = task
             .run(self.cancellation_token.clone())
@@ -11,11 +11,11 @@ use agglayer_storage::{
 };
 use agglayer_types::{
     primitives::{Digest, Hashable as _},
-    CertificateId, CertificateStatus, CertificateStatusError, ExecutionMode, Height,
-    LocalNetworkStateData, NetworkId,
+    CertificateHeader, CertificateId, CertificateStatus, CertificateStatusError, ExecutionMode,
+    Height, LocalNetworkStateData, NetworkId,
 };
 use arc_swap::ArcSwap;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -59,6 +59,13 @@ pub enum NetworkTaskMessage {
         certificate_id: CertificateId,
     },
 
+    /// Notify the network task that the settlement of a certificate has been
+    /// submitted.
+    CertificateCandidate {
+        height: Height,
+        certificate_id: CertificateId,
+    },
+
     /// Notify the network task that a certificate has been successfully
     /// settled.
     CertificateSettled {
@@ -103,6 +110,8 @@ pub(crate) struct NetworkTask<
     settlement_service: Arc<SettlementService>,
     /// The current epoch store for epoch assignment
     current_epoch: Arc<ArcSwap<PerEpochStore>>,
+    /// Optional sender to publish the certificate status transitions.
+    certificate_status_sender: Option<broadcast::Sender<CertificateHeader>>,
 }
 
 impl<CertifierClient, PendingStore, StateStore, SettlementService, PerEpochStore>
@@ -154,9 +163,53 @@ where
             latest_settled,
             settlement_service,
             current_epoch,
+            certificate_status_sender: None,
         })
     }
 
+    /// Sets the sender used to publish the certificate status transitions.
+    pub(crate) fn with_certificate_status_sender(
+        mut self,
+        certificate_status_sender: Option<broadcast::Sender<CertificateHeader>>,
+    ) -> Self {
+        self.certificate_status_sender = certificate_status_sender;
+        self
+    }
+
+    /// Publish the current header of a certificate to the status subscribers.
+    ///
+    /// The header is read back from the state store so that subscribers see
+    /// exactly what a `getCertificateHeader` call would return, including the
+    /// error of a certificate in error.
+    fn publish_certificate_status(&self, certificate_id: &CertificateId) {
+        let Some(sender) = &self.certificate_status_sender else {
+            return;
+        };
+
+        if sender.receiver_count() == 0 {
+            return;
+        }
+
+        match self.state_store.get_certificate_header(certificate_id) {
+            Ok(Some(header)) => {
+                // Sending only fails when there is no subscriber left, which is fine.
+                _ = sender.send(header);
+            }
+            Ok(None) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Unable to publish the certificate status: header not found"
+                );
+            }
+            Err(error) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Unable to publish the certificate status: {error}"
+                );
+            }
+        }
+    }
+
     #[tracing::instrument(
         name = "NetworkTask::run",
         skip_all,
@@ -348,6 +401,11 @@ where
                                 "Failed to set the latest proven certificate per network: {:?}", error
                             );
                         }
+                        self.publish_certificate_status(&certificate_id);
+                        continue;
+                    }
+                    Some(NetworkTaskMessage::CertificateCandidate { certificate_id, .. }) => {
+                        self.publish_certificate_status(&certificate_id);
                         continue;
                     }
                     Some(NetworkTaskMessage::CertificateSettled { height, certificate_id }) => {
@@ -436,10 +494,12 @@ where
                         self.latest_settled = Some(SettledCertificate(
                             certificate_id, height, epoch_number, certificate_index,
                         ));
+                        self.publish_certificate_status(&certificate_id);
                         break;
                     }
-                    Some(NetworkTaskMessage::CertificateErrored { .. }) => {
+                    Some(NetworkTaskMessage::CertificateErrored { certificate_id, .. }) => {
                         // The certificate task already logged everything that should be logged.
+                        self.publish_certificate_status(&certificate_id);
                         break;
                     }
                 }
@@ -59,6 +59,7 @@ fn settlement_result(
             block_hash: B256::ZERO,
             block_number: 0,
             tx_hash,
+            gas_cost: None,
         },
     }
 }
@@ -76,6 +77,10 @@ fn dummy_settlement_job() -> agglayer_types::SettlementJob {
         calldata: Default::default(),
         eth_value: agglayer_types::U256::ZERO,
         gas_limit: 0,
+        network_id: None,
+        tx_type: agglayer_types::SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
 
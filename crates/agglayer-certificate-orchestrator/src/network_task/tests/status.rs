@@ -31,6 +31,7 @@ async fn from_pending_to_settled() {
     let clock_ref = clock();
     let network_id = 1.into();
     let (_sender, certificate_stream) = mpsc::channel(100);
+    let (status_sender, mut status_receiver) = tokio::sync::broadcast::channel(10);
 
     let mut forest = Forest::default();
 
@@ -130,7 +131,8 @@ async fn from_pending_to_settled() {
         Arc::new(settlement_service),
         mock_current_epoch(),
     )
-    .expect("Failed to create a new network task");
+    .expect("Failed to create a new network task")
+    .with_certificate_status_sender(Some(status_sender));
 
     let mut next_expected_height = Height::ZERO;
     let mut first_run = true;
@@ -151,6 +153,17 @@ async fn from_pending_to_settled() {
         .unwrap();
 
     assert!(header.status == CertificateStatus::Settled);
+
+    for expected in [
+        CertificateStatus::Proven,
+        CertificateStatus::Candidate,
+        CertificateStatus::Settled,
+    ] {
+        let published = status_receiver.try_recv().unwrap();
+        assert_eq!(published.certificate_id, certificate_id);
+        assert_eq!(published.status, expected);
+    }
+    assert!(status_receiver.try_recv().is_err());
 }
 
 #[rstest]
@@ -179,12 +179,43 @@ impl StateReader for DummyPendingStore {
             .and_then(|id| self.certificate_headers.read().unwrap().get(id).cloned()))
     }
 
+    fn list_certificate_headers(
+        &self,
+        _network_id: NetworkId,
+        _from_height: Height,
+        _limit: usize,
+        _direction: agglayer_types::ListDirection,
+    ) -> Result<Vec<CertificateHeader>, agglayer_storage::error::Error> {
+        todo!()
+    }
+
     fn read_local_network_state(
         &self,
         _network_id: NetworkId,
     ) -> Result<Option<LocalNetworkStateData>, agglayer_storage::error::Error> {
         todo!()
     }
+    fn read_local_network_state_at(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+    ) -> Result<Option<LocalNetworkStateData>, agglayer_storage::error::Error> {
+        todo!()
+    }
+    fn has_network_state_snapshot(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+    ) -> Result<bool, agglayer_storage::error::Error> {
+        todo!()
+    }
+    fn read_local_exit_tree_leaves(
+        &self,
+        _network_id: NetworkId,
+        _leaves: std::ops::Range<u32>,
+    ) -> Result<Option<Vec<Digest>>, agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 impl EpochStoreReader for DummyPendingStore {
     fn get_certificate(
@@ -297,6 +328,16 @@ impl PendingCertificateWriter for DummyPendingStore {
     ) -> Result<(), agglayer_storage::error::Error> {
         Ok(())
     }
+
+    fn rollback_pending_certificates(
+        &self,
+        _network_id: &NetworkId,
+        _height: &Height,
+        _certificate_id: &CertificateId,
+        _removed_proofs: &[CertificateId],
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 
 impl StateWriter for DummyPendingStore {
@@ -404,6 +445,27 @@ impl StateWriter for DummyPendingStore {
     ) -> Result<(), agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn write_network_state_snapshot(
+        &self,
+        _network_id: &NetworkId,
+        _height: &Height,
+        _state: &LocalNetworkStateData,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
+
+    fn rollback_network_state(
+        &self,
+        _network_id: &NetworkId,
+        _settled: &SettledCertificate,
+        _state: &LocalNetworkStateData,
+        _leaves: &[Digest],
+        _removed_certificates: &[CertificateId],
+        _cancelled_settlement_jobs: &[SettlementJobId],
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 
 impl PendingCertificateReader for DummyPendingStore {
@@ -420,6 +482,21 @@ impl PendingCertificateReader for DummyPendingStore {
         todo!()
     }
 
+    fn count_pending_certificates_for_network(
+        &self,
+        _network_id: NetworkId,
+    ) -> Result<usize, agglayer_storage::error::Error> {
+        todo!()
+    }
+
+    fn list_pending_certificates_for_network(
+        &self,
+        _network_id: NetworkId,
+        _from_height: Height,
+    ) -> Result<Vec<Certificate>, agglayer_storage::error::Error> {
+        todo!()
+    }
+
     fn get_current_pending_heights(
         &self,
     ) -> Result<Vec<(NetworkId, PendingCertificate)>, agglayer_storage::error::Error> {
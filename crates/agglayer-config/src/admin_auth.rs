@@ -0,0 +1,133 @@
+//! Authentication of the admin JSON-RPC.
+//!
+//! Configured in the `[rpc.admin-auth]` section. Without that section the
+//! admin listener accepts every request, and relies on not being reachable
+//! from untrusted networks.
+
+use std::{fmt, path::PathBuf, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// What an authenticated admin caller may do.
+///
+/// Roles are ordered: a role grants everything the roles below it grant.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdminRole {
+    /// Inspection methods only, which never change the node state.
+    ReadOnly,
+    /// Every admin method, including the destructive mutations.
+    Operator,
+}
+
+impl fmt::Display for AdminRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AdminRole::ReadOnly => "read-only",
+            AdminRole::Operator => "operator",
+        })
+    }
+}
+
+/// Credentials accepted by the admin JSON-RPC.
+///
+/// Callers authenticate with an `Authorization: Bearer` header carrying
+/// either one of the static `tokens` or a JWT verified with `jwt`. When
+/// `tls` requires client certificates, a caller presenting a valid one
+/// without a bearer credential is granted `tls.client-certificate-role`, if
+/// set.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AdminAuthConfig {
+    /// Static bearer tokens, each read from its own file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tokens: Vec<AdminTokenConfig>,
+
+    /// Verification of JWT bearer tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwt: Option<AdminJwtConfig>,
+
+    /// TLS termination of the admin listener, with optional client
+    /// certificate authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<AdminTlsConfig>,
+}
+
+/// A static bearer token.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AdminTokenConfig {
+    /// Name of the token holder, used in logs.
+    pub name: String,
+
+    /// File holding the token. Surrounding whitespace is ignored.
+    pub token_file: PathBuf,
+
+    pub role: AdminRole,
+}
+
+/// Signature algorithm of admin JWTs.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256, verified with a shared secret.
+    #[serde(rename = "HS256")]
+    Hs256,
+    /// ECDSA on P-256 with SHA-256, verified with a public key.
+    #[serde(rename = "ES256")]
+    Es256,
+}
+
+/// Verification of admin JWTs.
+///
+/// A token must carry an `exp` claim, and a `role` claim holding an
+/// [`AdminRole`]. Its `sub` claim names the caller in logs.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AdminJwtConfig {
+    pub algorithm: JwtAlgorithm,
+
+    /// File holding the raw shared secret for `HS256`, or the PEM-encoded
+    /// public key for `ES256`.
+    pub key_file: PathBuf,
+
+    /// Required `iss` claim, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+
+    /// Required `aud` claim, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Clock skew tolerated on the `exp` and `nbf` claims.
+    #[serde_as(as = "crate::with::HumanDuration")]
+    #[serde(default = "default_jwt_leeway")]
+    pub leeway: Duration,
+}
+
+/// TLS termination of the admin listener.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct AdminTlsConfig {
+    /// PEM-encoded certificate chain of the listener.
+    pub cert_file: PathBuf,
+
+    /// PEM-encoded private key of the listener.
+    pub key_file: PathBuf,
+
+    /// PEM-encoded CA certificates verifying client certificates. When set,
+    /// connections without a valid client certificate are refused (mTLS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ca_file: Option<PathBuf>,
+
+    /// Role of callers authenticated by their client certificate alone.
+    /// Without it, a client certificate must be paired with a bearer
+    /// credential. Requires `client-ca-file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate_role: Option<AdminRole>,
+}
+
+const fn default_jwt_leeway() -> Duration {
+    Duration::from_secs(60)
+}
@@ -1,8 +1,10 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
+use agglayer_primitives::Address;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::{serde_as, NoneAsEmptyString};
 use tracing::warn;
+use url::Url;
 
 /// The transaction management configuration.
 ///
@@ -24,6 +26,8 @@ use tracing::warn;
 pub enum AuthConfig {
     Local(LocalConfig),
     GcpKms(GcpKmsConfig),
+    AwsKms(AwsKmsConfig),
+    Remote(RemoteSignerConfig),
 }
 
 impl Default for AuthConfig {
@@ -188,6 +192,77 @@ impl<'de> Deserialize<'de> for GcpKmsConfig {
     }
 }
 
+/// AWS KMS configuration.
+///
+/// The keys must be asymmetric `ECC_SECG_P256K1` signing keys. Credentials
+/// are loaded through the default AWS provider chain (environment, profile,
+/// web identity or instance metadata).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AwsKmsConfig {
+    /// The AWS region of the keys, the region of the default provider chain
+    /// is used if not specified.
+    #[serde(default)]
+    pub region: Option<String>,
+
+    /// The key ID, ARN or alias for PP certificate settlement.
+    pub pp_settlement_key_id: String,
+
+    /// The key ID, ARN or alias for Tx certificate settlement.
+    pub tx_settlement_key_id: String,
+}
+
+/// Remote signer configuration.
+///
+/// The keys are held by a Web3Signer-compatible service, which is asked to
+/// sign over JSON-RPC (`eth_signTransaction` and `eth_sign`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RemoteSignerConfig {
+    /// The JSON-RPC endpoint of the remote signer.
+    pub url: Url,
+
+    /// The address of the key used for PP certificate settlement.
+    pub pp_settlement_address: Address,
+
+    /// The address of the key used for Tx certificate settlement, the PP
+    /// settlement key is used if not specified.
+    #[serde(default)]
+    pub tx_settlement_address: Option<Address>,
+
+    /// Timeout of a single request to the remote signer.
+    #[serde(
+        default = "RemoteSignerConfig::default_request_timeout",
+        with = "crate::with::HumanDuration"
+    )]
+    pub request_timeout: Duration,
+
+    /// Number of times a request failing with a transient error is retried.
+    #[serde(default = "RemoteSignerConfig::default_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled after every failed attempt.
+    #[serde(
+        default = "RemoteSignerConfig::default_retry_backoff",
+        with = "crate::with::HumanDuration"
+    )]
+    pub retry_backoff: Duration,
+}
+
+impl RemoteSignerConfig {
+    const fn default_request_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    const fn default_max_retries() -> u32 {
+        3
+    }
+
+    const fn default_retry_backoff() -> Duration {
+        Duration::from_millis(500)
+    }
+}
+
 // This is a workaround to support `EthTxManager` for PrivateKeys as it is used
 // by kurtosis.
 #[derive(Deserialize)]
@@ -196,6 +271,10 @@ struct IntermediateAuthConfig {
     local: Option<LocalConfig>,
     #[serde(default)]
     gcpkms: Option<GcpKmsConfig>,
+    #[serde(default)]
+    awskms: Option<AwsKmsConfig>,
+    #[serde(default)]
+    remote: Option<RemoteSignerConfig>,
     #[serde(default, alias = "PrivateKeys")]
     private_keys: Option<Vec<PrivateKey>>,
     #[serde(flatten)]
@@ -210,6 +289,10 @@ impl TryFrom<IntermediateAuthConfig> for AuthConfig {
             Ok(AuthConfig::Local(local))
         } else if let Some(gcpkms) = intermediate.gcpkms {
             Ok(AuthConfig::GcpKms(gcpkms))
+        } else if let Some(awskms) = intermediate.awskms {
+            Ok(AuthConfig::AwsKms(awskms))
+        } else if let Some(remote) = intermediate.remote {
+            Ok(AuthConfig::Remote(remote))
         } else if let Some(private_keys) = intermediate.private_keys {
             Ok(AuthConfig::Local(LocalConfig { private_keys }))
         } else if let Some(kms) = intermediate.kms {
@@ -16,6 +16,7 @@ pub use self::telemetry::TelemetryConfig;
 
 pub(crate) const DEFAULT_IP: std::net::Ipv4Addr = std::net::Ipv4Addr::new(0, 0, 0, 0);
 
+pub mod admin_auth;
 pub(crate) mod auth;
 pub mod certificate_orchestrator;
 pub mod epoch;
@@ -34,7 +35,9 @@ pub mod storage;
 pub(crate) mod telemetry;
 mod with;
 
-pub use auth::{AuthConfig, GcpKmsConfig, LocalConfig, PrivateKey};
+pub use auth::{
+    AuthConfig, AwsKmsConfig, GcpKmsConfig, LocalConfig, PrivateKey, RemoteSignerConfig,
+};
 pub use epoch::Epoch;
 pub use l1::L1;
 pub use l2::L2;
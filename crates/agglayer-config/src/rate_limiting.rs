@@ -29,6 +29,11 @@ impl TimeRateLimit {
         Self::Unlimited
     }
 
+    /// Default rate limiting for certificate submission.
+    pub const fn send_certificate_default() -> Self {
+        Self::Unlimited
+    }
+
     /// Create a time-based rate limiting
     pub const fn limited(max_per_interval: u32, time_interval: Duration) -> Self {
         Self::Limited {
@@ -43,6 +48,8 @@ impl TimeRateLimit {
 #[serde(rename_all = "kebab-case")]
 struct RateLimitOverride {
     send_tx: Option<TimeRateLimit>,
+    send_certificate: Option<TimeRateLimit>,
+    max_pending_certificates: Option<u32>,
 }
 
 /// Rate limiting configuration for a single network.
@@ -50,6 +57,12 @@ struct RateLimitOverride {
 pub struct NetworkRateLimitingConfig<'a> {
     /// Rate limit for `sendTx` for given network.
     pub send_tx: &'a TimeRateLimit,
+
+    /// Rate limit for certificate submission for given network.
+    pub send_certificate: &'a TimeRateLimit,
+
+    /// Maximum number of pending certificates for given network, if capped.
+    pub max_pending_certificates: Option<u32>,
 }
 
 /// Full rate limiting config.
@@ -62,6 +75,21 @@ pub struct RateLimitingConfig {
     #[serde(default = "TimeRateLimit::send_tx_default")]
     send_tx: TimeRateLimit,
 
+    /// Rate limiting for certificate submission, through both
+    /// `interop_sendCertificate` and the gRPC `SubmitCertificate`.
+    #[serde(default = "TimeRateLimit::send_certificate_default")]
+    send_certificate: TimeRateLimit,
+
+    /// Maximum number of certificates of a network waiting in the pending
+    /// store. No cap if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_pending_certificates: Option<u32>,
+
+    /// Whether the state of the rate limiters is persisted in the storage, so
+    /// that the limits still apply across restarts.
+    #[serde(default)]
+    persist: bool,
+
     /// Per-network rate limiting overrides.
     #[serde(default)]
     #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
@@ -75,7 +103,36 @@ impl RateLimitingConfig {
     /// New rate limiting config with no network-specific settings.
     pub const fn new(send_tx: TimeRateLimit) -> Self {
         let network = BTreeMap::new();
-        Self { send_tx, network }
+        Self {
+            send_tx,
+            send_certificate: TimeRateLimit::send_certificate_default(),
+            max_pending_certificates: None,
+            persist: false,
+            network,
+        }
+    }
+
+    /// Set whether the state of the rate limiters is persisted.
+    pub fn with_persist(mut self, persist: bool) -> Self {
+        self.persist = persist;
+        self
+    }
+
+    /// Whether the state of the rate limiters is persisted.
+    pub fn persist(&self) -> bool {
+        self.persist
+    }
+
+    /// Set the default certificate submission rate limiting.
+    pub fn with_send_certificate(mut self, limit: TimeRateLimit) -> Self {
+        self.send_certificate = limit;
+        self
+    }
+
+    /// Set the default cap on pending certificates per network.
+    pub fn with_max_pending_certificates(mut self, max: u32) -> Self {
+        self.max_pending_certificates = Some(max);
+        self
     }
 
     /// Override `sendTx`setting for given network.
@@ -84,13 +141,38 @@ impl RateLimitingConfig {
         self
     }
 
+    /// Override certificate submission setting for given network.
+    pub fn with_send_certificate_override(mut self, nid: NetworkId, limit: TimeRateLimit) -> Self {
+        self.network.entry(nid).or_default().send_certificate = Some(limit);
+        self
+    }
+
+    /// Override the pending certificate cap for given network.
+    pub fn with_max_pending_certificates_override(mut self, nid: NetworkId, max: u32) -> Self {
+        self.network
+            .entry(nid)
+            .or_default()
+            .max_pending_certificates = Some(max);
+        self
+    }
+
     /// Get rate limiting configuration for given network.
     pub fn config_for(&self, network_id: NetworkId) -> NetworkRateLimitingConfig<'_> {
         let overrides = self.override_for(network_id);
         let send_tx = overrides
             .and_then(|l| l.send_tx.as_ref())
             .unwrap_or(&self.send_tx);
-        NetworkRateLimitingConfig { send_tx }
+        let send_certificate = overrides
+            .and_then(|l| l.send_certificate.as_ref())
+            .unwrap_or(&self.send_certificate);
+        let max_pending_certificates = overrides
+            .and_then(|l| l.max_pending_certificates)
+            .or(self.max_pending_certificates);
+        NetworkRateLimitingConfig {
+            send_tx,
+            send_certificate,
+            max_pending_certificates,
+        }
     }
 
     fn override_for(&self, nid: NetworkId) -> Option<&RateLimitOverride> {
@@ -161,10 +243,14 @@ mod test {
         let network_1_send_tx_limit = TimeRateLimit::limited(4, Duration::from_secs(2400));
         let network_1_override = RateLimitOverride {
             send_tx: Some(network_1_send_tx_limit.clone()),
+            ..Default::default()
         };
 
         let expected = RateLimitingConfig {
             send_tx: default_send_tx_limit.clone(),
+            send_certificate: TimeRateLimit::Unlimited,
+            max_pending_certificates: None,
+            persist: false,
             network: BTreeMap::from_iter([(1, network_1_override)]),
         };
 
@@ -173,4 +259,54 @@ mod test {
         assert_eq!(config.config_for(2).send_tx, &default_send_tx_limit);
         assert_eq!(config.config_for(1337).send_tx, &default_send_tx_limit);
     }
+
+    #[test]
+    fn send_certificate_and_pending_cap() {
+        #[rustfmt::skip]
+        let config_str = "max-pending-certificates = 10\n\
+            [send-certificate]\n\
+            max-per-interval = 5\n\
+            time-interval = \"1min\"\n\
+            [network.1]\n\
+            send-certificate = \"unlimited\"\n\
+            [network.2]\n\
+            max-pending-certificates = 3\n";
+        let config: RateLimitingConfig = toml::from_str(config_str).unwrap();
+
+        let default_limit = TimeRateLimit::limited(5, Duration::from_secs(60));
+        let expected = RateLimitingConfig::DEFAULT
+            .with_send_certificate(default_limit.clone())
+            .with_max_pending_certificates(10)
+            .with_send_certificate_override(1, TimeRateLimit::Unlimited)
+            .with_max_pending_certificates_override(2, 3);
+        assert_eq!(config, expected);
+
+        let network_1 = config.config_for(1);
+        assert_eq!(network_1.send_certificate, &TimeRateLimit::Unlimited);
+        assert_eq!(network_1.max_pending_certificates, Some(10));
+        let network_2 = config.config_for(2);
+        assert_eq!(network_2.send_certificate, &default_limit);
+        assert_eq!(network_2.max_pending_certificates, Some(3));
+        assert_eq!(config.config_for(3).send_certificate, &default_limit);
+        assert_eq!(config.config_for(3).send_tx, &TimeRateLimit::Unlimited);
+    }
+
+    #[test]
+    fn no_pending_cap_by_default() {
+        let config = RateLimitingConfig::DEFAULT;
+        assert_eq!(
+            config.config_for(1).send_certificate,
+            &TimeRateLimit::Unlimited
+        );
+        assert_eq!(config.config_for(1).max_pending_certificates, None);
+    }
+
+    #[test]
+    fn persistence_is_opt_in() {
+        assert!(!RateLimitingConfig::DEFAULT.persist());
+
+        let config: RateLimitingConfig = toml::from_str("persist = true").unwrap();
+        assert!(config.persist());
+        assert_eq!(config, RateLimitingConfig::DEFAULT.with_persist(true));
+    }
 }
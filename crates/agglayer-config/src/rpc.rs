@@ -4,7 +4,7 @@ use jsonrpsee::core::TEN_MB_SIZE_BYTES;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::{Port, PortDefaults};
+use crate::{admin_auth::AdminAuthConfig, Port, PortDefaults};
 
 pub enum GrpcService {}
 impl PortDefaults for GrpcService {
@@ -85,6 +85,11 @@ pub struct RpcConfig {
     #[serde_as(as = "crate::with::HumanDuration")]
     #[serde(default = "default_request_timeout")]
     pub request_timeout: Duration,
+
+    /// Authentication of the AdminRPC server. Without it, the AdminRPC
+    /// server accepts any request reaching `admin_port`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_auth: Option<AdminAuthConfig>,
 }
 
 impl Default for RpcConfig {
@@ -100,6 +105,7 @@ impl Default for RpcConfig {
             batch_request_limit: None,
             ping_interval: None,
             request_timeout: default_request_timeout(),
+            admin_auth: None,
         }
     }
 }
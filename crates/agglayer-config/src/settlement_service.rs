@@ -1,8 +1,8 @@
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
-use agglayer_primitives::U256;
+use agglayer_primitives::{Address, U256};
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, serde_conv};
+use serde_with::{serde_as, serde_conv, DisplayFromStr};
 
 use crate::{with::HumanDuration, Multiplier};
 
@@ -41,6 +41,50 @@ pub enum SettlementPolicy {
     FinalizedBlock,
 }
 
+/// Source of the fee estimates settlement transactions are priced from.
+///
+/// The configured multipliers, floors and ceilings are applied on top of the
+/// oracle's estimate.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(
+    tag = "type",
+    rename_all = "kebab-case",
+    rename_all_fields = "kebab-case"
+)]
+pub enum GasOracleConfig {
+    /// The L1 provider's own EIP-1559 fee estimation.
+    #[default]
+    Provider,
+
+    /// A percentile of the priority fees paid over the last `block-count`
+    /// blocks, read from `eth_feeHistory`. The max fee is twice the next base
+    /// fee plus the priority fee.
+    FeeHistory {
+        /// Number of recent blocks to sample.
+        #[serde(default = "default_fee_history_block_count")]
+        block_count: u64,
+
+        /// Percentile (0 to 100) of the priority fees paid in each block, e.g.
+        /// 50 for the median or 90 to get included faster.
+        #[serde(
+            default = "default_fee_history_reward_percentile",
+            deserialize_with = "deserialize_percentile"
+        )]
+        reward_percentile: u8,
+    },
+
+    /// Fixed fees, for testnets with a predictable fee market.
+    /// Can be specified with units: "1gwei", "0.1eth", "1000000000wei".
+    Fixed {
+        #[serde_as(as = "crate::with::EthAmount")]
+        max_fee_per_gas: u128,
+
+        #[serde_as(as = "crate::with::EthAmount")]
+        max_priority_fee_per_gas: u128,
+    },
+}
+
 /// Transaction retry policy.
 #[serde_as]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
@@ -233,6 +277,24 @@ pub struct SettlementTransactionConfig {
     /// Increase rate for the `max_priority_fee_per_gas` for each retry attempt.
     #[serde(default, skip_serializing_if = "crate::is_default")]
     pub max_priority_fee_per_gas_multiplier_factor: Multiplier,
+
+    /// Oracle the base fee estimates are read from.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub gas_oracle: GasOracleConfig,
+
+    /// Simulate each transaction with `eth_call` against the pending block
+    /// before broadcasting it. A transaction that would revert is not
+    /// broadcast, and its job fails with the decoded revert reason instead of
+    /// spending gas.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub simulate_before_broadcast: bool,
+
+    /// Precompute the access list of each new settlement job with
+    /// `eth_createAccessList`, unless the job already carries one. Declaring
+    /// the storage slots read by the rollup manager up front makes those reads
+    /// cheaper.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub precompute_access_list: bool,
 }
 
 impl Default for SettlementTransactionConfig {
@@ -255,6 +317,9 @@ impl Default for SettlementTransactionConfig {
             max_priority_fee_per_gas_multiplier_factor: Multiplier::default(),
             max_priority_fee_per_gas_floor: 0,
             max_priority_fee_per_gas_ceiling: default_max_priority_fee_per_gas_ceiling(),
+            gas_oracle: GasOracleConfig::default(),
+            simulate_before_broadcast: false,
+            precompute_access_list: false,
         }
     }
 }
@@ -270,6 +335,19 @@ impl Default for SettlementTransactionConfig {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct SettlementServiceConfig {
+    /// Pool of wallets used to sign settlement transactions.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub wallet_pool: SettlementWalletPoolConfig,
+
+    /// Monitoring of the settlement wallet balances. Disabled when the
+    /// section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_monitor: Option<SettlementBalanceMonitorConfig>,
+
+    /// Batching of the settlement of several certificates in one L1
+    /// transaction. Disabled when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batching: Option<SettlementBatchingConfig>,
     // TODO: introduce regexes / error numbers to identify "nonce already used" errors
     // The exact format of this config will depend on the implementation and tests of RPC error
     // handling in the settlement service.
@@ -336,6 +414,150 @@ pub struct SettlementConfig {
     pub settlement_service_config: SettlementServiceConfig,
 }
 
+/// Policy used to assign a settlement job to one of the pool wallets.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WalletAssignmentPolicy {
+    /// Cycle through the pool wallets in the configured order.
+    #[default]
+    RoundRobin,
+
+    /// Pick the wallet with the fewest in-flight settlement jobs.
+    LeastPending,
+
+    /// Use the wallet configured for the job's network in `pinned-networks`.
+    /// Networks without a pinned wallet fall back to least-pending.
+    Pinned,
+}
+
+/// Settlement wallet pool configuration.
+///
+/// Every wallet of the pool has its own nonce lane: jobs assigned to
+/// different wallets never wait on each other's nonces. The provider's
+/// default tx-settlement signer is always part of the pool, and every
+/// additional wallet listed here must have a signer configured in the `auth`
+/// section.
+///
+/// A job whose pending nonces stay unmined for `failover-after` is moved to
+/// another wallet of the pool. The stuck transactions are still tracked, so
+/// whichever lands first settles the job and the others revert on the rollup
+/// manager.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SettlementWalletPoolConfig {
+    /// Additional settlement wallets, on top of the tx-settlement signer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wallets: Vec<Address>,
+
+    /// Policy used to assign new settlement jobs to wallets.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    pub assignment_policy: WalletAssignmentPolicy,
+
+    /// Wallet used for each network with the `pinned` policy.
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pinned_networks: BTreeMap<u32, Address>,
+
+    /// How long the pending transactions of a job may stay unmined before the
+    /// job fails over to another wallet.
+    #[serde(default = "default_failover_after")]
+    #[serde_as(as = "HumanDuration")]
+    pub failover_after: Duration,
+}
+
+impl Default for SettlementWalletPoolConfig {
+    fn default() -> Self {
+        Self {
+            wallets: Vec::new(),
+            assignment_policy: WalletAssignmentPolicy::default(),
+            pinned_networks: BTreeMap::new(),
+            failover_after: default_failover_after(),
+        }
+    }
+}
+
+/// Settlement wallet balance monitoring configuration.
+///
+/// When enabled, the balance of every settlement wallet is polled in the
+/// background and exported as a metric. While a wallet is below
+/// `min-wallet-balance`, no settlement attempt is built from it; settlement
+/// resumes on its own once the wallet is topped up.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SettlementBalanceMonitorConfig {
+    /// Minimum balance a settlement wallet needs for new attempts.
+    /// Can be specified with units: "0.5eth", "100gwei", "1000wei".
+    /// Zero disables the circuit breaker; balances are still exported.
+    #[serde(default, skip_serializing_if = "crate::is_default")]
+    #[serde_as(as = "crate::with::EthAmount")]
+    pub min_wallet_balance: u128,
+
+    /// Interval between two balance checks of the settlement wallets.
+    #[serde(default = "default_balance_poll_interval")]
+    #[serde_as(as = "HumanDuration")]
+    pub poll_interval: Duration,
+}
+
+impl Default for SettlementBalanceMonitorConfig {
+    fn default() -> Self {
+        Self {
+            min_wallet_balance: 0,
+            poll_interval: default_balance_poll_interval(),
+        }
+    }
+}
+
+/// Settlement batching configuration.
+///
+/// Certificate settlements requested within `window` of the first one are
+/// combined into a single transaction to `batcher-address`, which performs
+/// their calls in order through a Multicall3-compatible `aggregate3` entry
+/// point. The calls are made with the batcher as sender, so the batcher must
+/// hold the trusted aggregator role on the rollup manager, and must only
+/// accept calls from the settlement wallets.
+///
+/// The batch reverts as a whole if any of its calls reverts. Its certificates
+/// are then settled one transaction each, as they would be without batching.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SettlementBatchingConfig {
+    /// Contract batching the settlement calls.
+    pub batcher_address: Address,
+
+    /// How long a batch stays open after its first settlement request.
+    #[serde(default = "default_batching_window")]
+    #[serde_as(as = "HumanDuration")]
+    pub window: Duration,
+
+    /// Number of settlements closing a batch before the end of its window.
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+}
+
+/// Default time a settlement batch stays open.
+const fn default_batching_window() -> Duration {
+    Duration::from_secs(12)
+}
+
+/// Default maximum number of settlements in one batch.
+const fn default_max_batch_size() -> usize {
+    16
+}
+
+/// Default interval between two balance checks of the settlement wallets.
+const fn default_balance_poll_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Default delay after which a stuck settlement job fails over to another
+/// wallet of the pool.
+const fn default_failover_after() -> Duration {
+    Duration::from_secs(15 * 60)
+}
+
 /// Default number of confirmations required
 /// for the transaction to resolve a receipt.
 const fn default_confirmations() -> usize {
@@ -365,3 +587,26 @@ const fn default_max_priority_fee_per_gas_ceiling() -> u128 {
     // 100 gwei
     100_000_000_000_u128
 }
+
+/// Default number of blocks sampled by the fee history gas oracle.
+const fn default_fee_history_block_count() -> u64 {
+    20
+}
+
+/// Default priority fee percentile of the fee history gas oracle.
+const fn default_fee_history_reward_percentile() -> u8 {
+    50
+}
+
+fn deserialize_percentile<'de, D>(deserializer: D) -> Result<u8, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let percentile = u8::deserialize(deserializer)?;
+    if percentile > 100 {
+        return Err(serde::de::Error::custom(format!(
+            "percentile must be between 0 and 100, got {percentile}"
+        )));
+    }
+    Ok(percentile)
+}
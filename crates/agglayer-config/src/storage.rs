@@ -2,6 +2,7 @@ use std::path::{Component, Path, PathBuf};
 
 use agglayer_types::EpochNumber;
 use backup::BackupConfig;
+use epoch_retention::EpochRetentionConfig;
 use serde::{Deserialize, Serialize};
 
 pub(crate) const STORAGE_DIR: &str = "storage";
@@ -12,6 +13,7 @@ const EPOCHS_DB_PATH: &str = "epochs";
 const DEBUG_DB_PATH: &str = "debug";
 
 pub mod backup;
+pub mod epoch_retention;
 
 /// Configuration for the storage.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -30,6 +32,8 @@ pub struct StorageConfig {
     pub debug_db_path: PathBuf,
     /// Backup config
     pub backup: BackupConfig,
+    /// Retention policy of the epoch databases.
+    pub epoch_retention: EpochRetentionConfig,
 }
 
 impl Default for StorageConfig {
@@ -41,6 +45,7 @@ impl Default for StorageConfig {
             epochs_db_path: Path::new("./").join(STORAGE_DIR).join(EPOCHS_DB_PATH),
             debug_db_path: Path::new("./").join(STORAGE_DIR).join(DEBUG_DB_PATH),
             backup: BackupConfig::default(),
+            epoch_retention: EpochRetentionConfig::default(),
         }
     }
 }
@@ -72,6 +77,7 @@ impl StorageConfig {
             epochs_db_path: db_path.join(EPOCHS_DB_PATH),
             debug_db_path: db_path.join(DEBUG_DB_PATH),
             backup: BackupConfig::default(),
+            epoch_retention: EpochRetentionConfig::default(),
         }
     }
 
@@ -99,6 +105,9 @@ struct StorageConfigHelper {
     /// Backup config.
     #[serde(default, skip_serializing_if = "BackupConfig::is_disabled")]
     pub backup: BackupConfig,
+    /// Epoch retention config.
+    #[serde(default, skip_serializing_if = "EpochRetentionConfig::is_disabled")]
+    pub epoch_retention: EpochRetentionConfig,
 }
 
 impl From<StorageConfigHelper> for StorageConfig {
@@ -120,6 +129,7 @@ impl From<StorageConfigHelper> for StorageConfig {
                 .debug_db_path
                 .unwrap_or_else(|| value.db_path.join(DEBUG_DB_PATH)),
             backup: value.backup,
+            epoch_retention: value.epoch_retention,
         }
     }
 }
@@ -140,6 +150,7 @@ impl From<StorageConfig> for StorageConfigHelper {
             epochs_db_path: None,
             debug_db_path: None,
             backup: value.backup,
+            epoch_retention: value.epoch_retention,
         }
     }
 }
@@ -208,5 +219,43 @@ mod tests {
         assert_eq!(config.state_db_path, PathBuf::from("/tmp/base/state"));
         assert_eq!(config.epochs_db_path, PathBuf::from("/tmp/base/epochs"));
         assert_eq!(config.debug_db_path, PathBuf::from("/tmp/base/debug"));
+        assert!(config.epoch_retention.is_disabled());
+    }
+
+    #[test]
+    fn epoch_retention_cold_files() {
+        let value = toml::toml! {
+            db-path = "/tmp/base"
+
+            [epoch-retention]
+            keep-epochs = 10
+
+            [epoch-retention.archive]
+            kind = "cold-files"
+            path = "/tmp/cold"
+        };
+
+        let cfg = toml::to_string(&value).unwrap();
+        let config: StorageConfig = toml::from_str(&cfg).unwrap();
+
+        assert_eq!(
+            config.epoch_retention,
+            EpochRetentionConfig::new(
+                std::num::NonZeroU64::new(10).unwrap(),
+                epoch_retention::EpochArchiveConfig::ColdFiles {
+                    path: PathBuf::from("/tmp/cold"),
+                },
+            )
+        );
+
+        let value = toml::toml! {
+            [epoch-retention]
+            keep-epochs = 0
+
+            [epoch-retention.archive]
+            kind = "database"
+            path = "/tmp/archive"
+        };
+        assert!(toml::from_str::<StorageConfig>(&toml::to_string(&value).unwrap()).is_err());
     }
 }
@@ -1,8 +1,11 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use url::Url;
 
 /// Configuration for Storage backups.
+#[serde_as]
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum BackupConfig {
@@ -21,6 +24,15 @@ pub enum BackupConfig {
         /// Maximum number of backups to keep for the pending storage.
         #[serde(default = "default_max_backup_number")]
         pending_max_backup_count: usize,
+        /// Optional S3-compatible bucket the completed backups are uploaded
+        /// to.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        remote: Option<RemoteBackupConfig>,
+        /// Interval at which the latest state and pending backups are
+        /// restored and verified. Disabled if not set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde_as(as = "Option<crate::with::HumanDuration>")]
+        restore_drill_interval: Option<Duration>,
     },
 }
 
@@ -33,6 +45,48 @@ impl BackupConfig {
             path: path.into(),
             state_max_backup_count: default_max_backup_number(),
             pending_max_backup_count: default_max_backup_number(),
+            remote: None,
+            restore_drill_interval: None,
+        }
+    }
+
+    /// Upload the backups to the given bucket, if backups are enabled.
+    pub fn with_remote(mut self, remote_config: RemoteBackupConfig) -> Self {
+        if let BackupConfig::Enabled { remote, .. } = &mut self {
+            *remote = Some(remote_config);
+        }
+        self
+    }
+
+    /// Restore and verify the latest backups at the given interval, if backups
+    /// are enabled.
+    pub fn with_restore_drill_interval(mut self, interval: Duration) -> Self {
+        if let BackupConfig::Enabled {
+            restore_drill_interval,
+            ..
+        } = &mut self
+        {
+            *restore_drill_interval = Some(interval);
+        }
+        self
+    }
+
+    /// The interval of the restore drills, if any.
+    pub fn restore_drill_interval(&self) -> Option<Duration> {
+        match self {
+            BackupConfig::Disabled => None,
+            BackupConfig::Enabled {
+                restore_drill_interval,
+                ..
+            } => *restore_drill_interval,
+        }
+    }
+
+    /// The bucket the backups are uploaded to, if any.
+    pub fn remote(&self) -> Option<&RemoteBackupConfig> {
+        match self {
+            BackupConfig::Disabled => None,
+            BackupConfig::Enabled { remote, .. } => remote.as_ref(),
         }
     }
 
@@ -44,3 +98,35 @@ impl BackupConfig {
 const fn default_max_backup_number() -> usize {
     BackupConfig::DEFAULT_MAX_BACKUP_NUMBER
 }
+
+/// Configuration of the upload of backups to an S3-compatible object storage.
+///
+/// Credentials are loaded through the default AWS provider chain, e.g. the
+/// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` environment variables.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RemoteBackupConfig {
+    /// Name of the bucket.
+    pub bucket: String,
+    /// Prefix of the keys of the backup objects in the bucket.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub prefix: String,
+    /// Region of the bucket, taken from the provider chain if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Endpoint of an S3-compatible object storage, e.g. a MinIO server. AWS
+    /// S3 is used if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<Url>,
+}
+
+impl RemoteBackupConfig {
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            prefix: String::new(),
+            region: None,
+            endpoint: None,
+        }
+    }
+}
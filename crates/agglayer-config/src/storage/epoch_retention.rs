@@ -0,0 +1,57 @@
+use std::{num::NonZeroU64, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Retention policy of the per-epoch databases.
+///
+/// Every epoch gets its own database. When enabled, the packed epochs older
+/// than the most recent ones are moved to an archive and their database is
+/// removed. Archived epochs remain readable through the epochs store.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EpochRetentionConfig {
+    /// Epoch databases are kept forever.
+    #[default]
+    Disabled,
+
+    /// Old packed epochs are archived.
+    #[serde(untagged, rename_all = "kebab-case")]
+    Enabled {
+        /// Number of the most recent settled epochs kept in their own
+        /// database. The latest settled epoch is always kept.
+        keep_epochs: NonZeroU64,
+        /// Where the older epochs are archived.
+        archive: EpochArchiveConfig,
+    },
+}
+
+impl EpochRetentionConfig {
+    pub fn new(keep_epochs: NonZeroU64, archive: EpochArchiveConfig) -> Self {
+        Self::Enabled {
+            keep_epochs,
+            archive,
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        *self == EpochRetentionConfig::Disabled
+    }
+}
+
+/// Archive of the epochs evicted by the retention policy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", tag = "kind")]
+pub enum EpochArchiveConfig {
+    /// Merge the epochs into a single compacted database.
+    Database {
+        /// Path of the archive database.
+        path: PathBuf,
+    },
+
+    /// Export the epochs to a cold-archive directory, as content-addressed
+    /// files listed by one manifest per epoch.
+    ColdFiles {
+        /// Path of the cold-archive directory.
+        path: PathBuf,
+    },
+}
@@ -0,0 +1,85 @@
+use std::{path::PathBuf, time::Duration};
+
+use agglayer_config::{
+    admin_auth::{AdminAuthConfig, AdminRole, AdminTokenConfig, JwtAlgorithm},
+    Config,
+};
+
+#[test]
+fn admin_auth_is_disabled_by_default() {
+    let config: Config = toml::from_str("").unwrap();
+
+    assert_eq!(config.rpc.admin_auth, None);
+}
+
+#[test]
+fn deserialize_full_admin_auth_config() {
+    let input = "./tests/fixtures/admin_auth/full.toml";
+    let content = std::fs::read_to_string(input).unwrap();
+    let config: Config = toml::from_str(&content).unwrap();
+
+    let admin_auth = config.rpc.admin_auth.expect("admin auth must be set");
+    assert_eq!(
+        admin_auth.tokens,
+        vec![
+            AdminTokenConfig {
+                name: "dashboard".to_string(),
+                token_file: PathBuf::from("/etc/agglayer/admin/dashboard.token"),
+                role: AdminRole::ReadOnly,
+            },
+            AdminTokenConfig {
+                name: "oncall".to_string(),
+                token_file: PathBuf::from("/etc/agglayer/admin/oncall.token"),
+                role: AdminRole::Operator,
+            },
+        ]
+    );
+
+    let jwt = admin_auth.jwt.expect("jwt must be set");
+    assert_eq!(jwt.algorithm, JwtAlgorithm::Es256);
+    assert_eq!(jwt.issuer.as_deref(), Some("ops-portal"));
+    assert_eq!(jwt.audience.as_deref(), Some("agglayer-admin"));
+    assert_eq!(jwt.leeway, Duration::from_secs(30));
+
+    let tls = admin_auth.tls.expect("tls must be set");
+    assert_eq!(
+        tls.client_ca_file,
+        Some(PathBuf::from("/etc/agglayer/admin/clients-ca.crt"))
+    );
+    assert_eq!(tls.client_certificate_role, Some(AdminRole::ReadOnly));
+}
+
+#[test]
+fn jwt_leeway_defaults_to_one_minute() {
+    let config: AdminAuthConfig = toml::from_str(
+        r#"
+        [jwt]
+        algorithm = "HS256"
+        key-file = "/etc/agglayer/admin/jwt.secret"
+        "#,
+    )
+    .unwrap();
+
+    let jwt = config.jwt.expect("jwt must be set");
+    assert_eq!(jwt.algorithm, JwtAlgorithm::Hs256);
+    assert_eq!(jwt.leeway, Duration::from_secs(60));
+    assert_eq!(jwt.issuer, None);
+}
+
+#[test]
+fn unknown_jwt_algorithm_is_rejected() {
+    let result = toml::from_str::<AdminAuthConfig>(
+        r#"
+        [jwt]
+        algorithm = "none"
+        key-file = "/etc/agglayer/admin/jwt.secret"
+        "#,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn operator_role_grants_read_only_access() {
+    assert!(AdminRole::Operator > AdminRole::ReadOnly);
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use agglayer_config::{assert_toml_snapshot, AuthConfig, Config};
 
@@ -101,3 +101,51 @@ fn auth_distinct_pp_and_tx_settlement_keys_are_preserved() {
 
     assert_toml_snapshot!(config);
 }
+
+#[test]
+fn auth_remote() {
+    let input = "./tests/fixtures/auth/remote.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    let AuthConfig::Remote(remote) = &config.auth else {
+        panic!("Expected remote signer config to be present");
+    };
+
+    assert_eq!(remote.url.as_str(), "http://web3signer:9000/");
+    assert_eq!(
+        remote.pp_settlement_address,
+        "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap()
+    );
+    assert_eq!(
+        remote.tx_settlement_address,
+        Some(
+            "0x2222222222222222222222222222222222222222"
+                .parse()
+                .unwrap()
+        )
+    );
+    assert_eq!(remote.request_timeout, Duration::from_secs(5));
+    assert_eq!(remote.max_retries, 3);
+    assert_eq!(remote.retry_backoff, Duration::from_millis(500));
+}
+
+#[test]
+fn auth_aws_kms() {
+    let input = "./tests/fixtures/auth/aws_kms.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    let AuthConfig::AwsKms(aws_kms) = &config.auth else {
+        panic!("Expected AWS KMS config to be present");
+    };
+
+    assert_eq!(aws_kms.region, Some("eu-west-1".into()));
+    assert_eq!(aws_kms.pp_settlement_key_id, "alias/pp-settlement");
+    assert_eq!(
+        aws_kms.tx_settlement_key_id,
+        "arn:aws:kms:eu-west-1:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+    );
+}
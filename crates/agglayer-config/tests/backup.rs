@@ -10,3 +10,25 @@ fn backup_enabled() {
 
     assert_toml_snapshot!(config);
 }
+
+#[test]
+fn backup_remote() {
+    let input = "./tests/fixtures/valide_config/backup_remote.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    assert_toml_snapshot!(config);
+}
+
+#[test]
+fn backup_restore_drill() {
+    let input = "./tests/fixtures/valide_config/backup_restore_drill.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    assert_eq!(
+        config.storage.backup.restore_drill_interval(),
+        Some(std::time::Duration::from_secs(3600))
+    );
+    assert_toml_snapshot!(config);
+}
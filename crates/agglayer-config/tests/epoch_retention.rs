@@ -0,0 +1,25 @@
+use std::{num::NonZeroU64, path::Path};
+
+use agglayer_config::{
+    assert_toml_snapshot,
+    storage::epoch_retention::{EpochArchiveConfig, EpochRetentionConfig},
+    Config,
+};
+
+#[test]
+fn epoch_retention_database() {
+    let input = "./tests/fixtures/valide_config/epoch_retention.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    assert_eq!(
+        config.storage.epoch_retention,
+        EpochRetentionConfig::new(
+            NonZeroU64::new(100).unwrap(),
+            EpochArchiveConfig::Database {
+                path: "/var/lib/agglayer/epochs-archive".into(),
+            },
+        )
+    );
+    assert_toml_snapshot!(config);
+}
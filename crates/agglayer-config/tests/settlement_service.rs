@@ -2,10 +2,14 @@ use std::time::Duration;
 
 use agglayer_config::{
     assert_toml_snapshot,
-    settlement_service::{SettlementConfig, SettlementPolicy, SettlementTransactionConfig},
+    settlement_service::{
+        GasOracleConfig, SettlementBalanceMonitorConfig, SettlementBatchingConfig,
+        SettlementConfig, SettlementPolicy, SettlementTransactionConfig,
+        SettlementWalletPoolConfig, WalletAssignmentPolicy,
+    },
     Multiplier,
 };
-use agglayer_primitives::U256;
+use agglayer_primitives::{Address, U256};
 
 #[test]
 fn deserialize_default_settlement_tx_config() {
@@ -193,6 +197,9 @@ fn test_settlement_transaction_config_defaults() {
     assert_eq!(config.max_fee_per_gas_multiplier_factor.as_f64(), 1.0);
     assert_eq!(config.max_fee_per_gas_floor, 0_u128);
     assert_eq!(config.max_fee_per_gas_ceiling, 100_000_000_000_u128); // 100 gwei
+    assert!(!config.simulate_before_broadcast);
+    assert!(!config.precompute_access_list);
+    assert_eq!(config.gas_oracle, GasOracleConfig::Provider);
 
     assert_toml_snapshot!(config);
 }
@@ -222,3 +229,186 @@ fn test_human_duration_formats() {
         Duration::from_secs(30)
     );
 }
+
+#[test]
+fn deserialize_wallet_pool() {
+    let input = "./tests/fixtures/settlement/wallet_pool.toml";
+    let content = std::fs::read_to_string(input).unwrap();
+    let config: SettlementConfig = toml::from_str(&content).unwrap();
+
+    let pool = &config.settlement_service_config.wallet_pool;
+    let second_wallet: Address = "0x2222222222222222222222222222222222222222"
+        .parse()
+        .unwrap();
+    assert_eq!(pool.wallets.len(), 2);
+    assert_eq!(pool.wallets[1], second_wallet);
+    assert_eq!(pool.assignment_policy, WalletAssignmentPolicy::Pinned);
+    assert_eq!(pool.pinned_networks.get(&1), Some(&second_wallet));
+    assert_eq!(pool.failover_after, Duration::from_secs(300));
+
+    let roundtrip: SettlementConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(roundtrip, config);
+}
+
+#[test]
+fn test_wallet_pool_defaults() {
+    let config: SettlementConfig = toml::from_str("").unwrap();
+
+    assert_eq!(
+        config.settlement_service_config.wallet_pool,
+        SettlementWalletPoolConfig::default()
+    );
+    let pool = &config.settlement_service_config.wallet_pool;
+    assert!(pool.wallets.is_empty());
+    assert_eq!(pool.assignment_policy, WalletAssignmentPolicy::RoundRobin);
+    assert_eq!(pool.failover_after, Duration::from_secs(15 * 60));
+}
+
+#[test]
+fn deserialize_balance_monitor() {
+    let input = "./tests/fixtures/settlement/balance_monitor.toml";
+    let content = std::fs::read_to_string(input).unwrap();
+    let config: SettlementConfig = toml::from_str(&content).unwrap();
+
+    let monitor = config
+        .settlement_service_config
+        .balance_monitor
+        .as_ref()
+        .unwrap();
+    assert_eq!(monitor.min_wallet_balance, 500_000_000_000_000_000);
+    assert_eq!(monitor.poll_interval, Duration::from_secs(30));
+
+    let roundtrip: SettlementConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(roundtrip, config);
+}
+
+#[test]
+fn test_balance_monitor_defaults() {
+    let config: SettlementConfig = toml::from_str("").unwrap();
+    assert_eq!(config.settlement_service_config.balance_monitor, None);
+
+    let config: SettlementConfig =
+        toml::from_str("[settlement-service-config.balance-monitor]").unwrap();
+    assert_eq!(
+        config.settlement_service_config.balance_monitor,
+        Some(SettlementBalanceMonitorConfig::default())
+    );
+    let monitor = config
+        .settlement_service_config
+        .balance_monitor
+        .as_ref()
+        .unwrap();
+    assert_eq!(monitor.min_wallet_balance, 0);
+    assert_eq!(monitor.poll_interval, Duration::from_secs(60));
+}
+
+#[test]
+fn deserialize_batching() {
+    let input = "./tests/fixtures/settlement/batching.toml";
+    let content = std::fs::read_to_string(input).unwrap();
+    let config: SettlementConfig = toml::from_str(&content).unwrap();
+
+    assert_eq!(
+        config.settlement_service_config.batching,
+        Some(SettlementBatchingConfig {
+            batcher_address: "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+                .parse()
+                .unwrap(),
+            window: Duration::from_secs(30),
+            max_batch_size: 8,
+        })
+    );
+
+    let roundtrip: SettlementConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(roundtrip, config);
+}
+
+#[test]
+fn test_batching_defaults() {
+    let config: SettlementConfig = toml::from_str("").unwrap();
+    assert_eq!(config.settlement_service_config.batching, None);
+
+    let config: SettlementConfig = toml::from_str(
+        r#"
+        [settlement-service-config.batching]
+        batcher-address = "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+    "#,
+    )
+    .unwrap();
+    let batching = config.settlement_service_config.batching.unwrap();
+    assert_eq!(batching.window, Duration::from_secs(12));
+    assert_eq!(batching.max_batch_size, 16);
+}
+
+#[test]
+fn test_simulate_before_broadcast() {
+    let toml = r#"
+        [pessimistic-proof-tx-config]
+        simulate-before-broadcast = true
+    "#;
+
+    let config: SettlementConfig = toml::from_str(toml).unwrap();
+    assert!(config.pessimistic_proof_tx_config.simulate_before_broadcast);
+}
+
+#[test]
+fn test_precompute_access_list() {
+    let toml = r#"
+        [pessimistic-proof-tx-config]
+        precompute-access-list = true
+    "#;
+
+    let config: SettlementConfig = toml::from_str(toml).unwrap();
+    assert!(config.pessimistic_proof_tx_config.precompute_access_list);
+}
+
+#[test]
+fn test_fee_history_gas_oracle() {
+    let toml = r#"
+        [pessimistic-proof-tx-config.gas-oracle]
+        type = "fee-history"
+        reward-percentile = 90
+    "#;
+
+    let config: SettlementConfig = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.pessimistic_proof_tx_config.gas_oracle,
+        GasOracleConfig::FeeHistory {
+            block_count: 20,
+            reward_percentile: 90,
+        }
+    );
+}
+
+#[test]
+fn test_fee_history_gas_oracle_rejects_invalid_percentile() {
+    let toml = r#"
+        [pessimistic-proof-tx-config.gas-oracle]
+        type = "fee-history"
+        reward-percentile = 101
+    "#;
+
+    let error = toml::from_str::<SettlementConfig>(toml).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("percentile must be between 0 and 100"));
+}
+
+#[test]
+fn test_fixed_gas_oracle() {
+    let toml = r#"
+        [pessimistic-proof-tx-config.gas-oracle]
+        type = "fixed"
+        max-fee-per-gas = "20gwei"
+        max-priority-fee-per-gas = "1gwei"
+    "#;
+
+    let config: SettlementConfig = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.pessimistic_proof_tx_config.gas_oracle,
+        GasOracleConfig::Fixed {
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+        }
+    );
+}
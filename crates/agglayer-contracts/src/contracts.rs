@@ -43,6 +43,28 @@ sol!(
     "src/contracts/PolygonZkEVMGlobalExitRootV2.json"
 );
 
+sol!(
+    #[allow(missing_docs)]
+    #[derive(Debug, Eq, PartialEq)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls)
+            external
+            payable
+            returns (Result[] memory returnData);
+    }
+);
+
 pub(crate) type AggchainBaseRpcClient<RpcProvider> =
     AggchainBase::AggchainBaseInstance<RpcProvider, Ethereum>;
 
@@ -0,0 +1,247 @@
+//! Fee estimation for L1 transactions.
+//!
+//! A [`GasOracle`] returns the raw EIP-1559 fee estimate a transaction is
+//! priced from, along with the data it was derived from so the choice can be
+//! audited later. Callers apply their own multipliers, floors and ceilings on
+//! top of it.
+
+use agglayer_types::{GasOracleInputs, GasOracleSource};
+use alloy::{
+    eips::BlockNumberOrTag,
+    providers::Provider,
+    rpc::types::FeeHistory,
+    transports::{TransportError, TransportResult},
+};
+
+/// Source of EIP-1559 fee estimates.
+#[async_trait::async_trait]
+pub trait GasOracle: Send + Sync {
+    /// Estimates the fees of a transaction sent now.
+    async fn estimate_fees<P: Provider>(&self, provider: &P) -> TransportResult<GasOracleInputs>;
+}
+
+/// Uses the provider's own EIP-1559 fee estimation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProviderGasOracle;
+
+#[async_trait::async_trait]
+impl GasOracle for ProviderGasOracle {
+    async fn estimate_fees<P: Provider>(&self, provider: &P) -> TransportResult<GasOracleInputs> {
+        let estimate = provider.estimate_eip1559_fees().await?;
+
+        Ok(GasOracleInputs {
+            source: GasOracleSource::Provider,
+            max_fee_per_gas: estimate.max_fee_per_gas,
+            max_priority_fee_per_gas: estimate.max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// Prices transactions from a percentile of the priority fees paid over the
+/// last `block_count` blocks, read from `eth_feeHistory`.
+///
+/// The priority fee is the average, over the sampled blocks that included
+/// transactions, of each block's `reward_percentile` priority fee. The max fee
+/// is twice the next block's base fee plus that priority fee, which keeps the
+/// transaction includable through several full blocks in a row.
+#[derive(Clone, Copy, Debug)]
+pub struct FeeHistoryGasOracle {
+    pub block_count: u64,
+    /// Percentile between 0 and 100.
+    pub reward_percentile: u8,
+}
+
+#[async_trait::async_trait]
+impl GasOracle for FeeHistoryGasOracle {
+    async fn estimate_fees<P: Provider>(&self, provider: &P) -> TransportResult<GasOracleInputs> {
+        let history = provider
+            .get_fee_history(
+                self.block_count,
+                BlockNumberOrTag::Latest,
+                &[f64::from(self.reward_percentile)],
+            )
+            .await?;
+
+        self.estimate_from_history(&history)
+    }
+}
+
+impl FeeHistoryGasOracle {
+    fn estimate_from_history(&self, history: &FeeHistory) -> TransportResult<GasOracleInputs> {
+        let base_fee_per_gas = history.next_block_base_fee().ok_or_else(|| {
+            TransportError::local_usage_str("fee history did not report the next base fee")
+        })?;
+
+        // Blocks without transactions report a zero reward, which says nothing
+        // about the fee market.
+        let rewards = history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|percentiles| percentiles.first().copied())
+            .filter(|reward| *reward > 0)
+            .collect::<Vec<_>>();
+        let max_priority_fee_per_gas = if rewards.is_empty() {
+            0
+        } else {
+            rewards
+                .iter()
+                .fold(0_u128, |sum, reward| sum.saturating_add(*reward))
+                / rewards.len() as u128
+        };
+
+        Ok(GasOracleInputs {
+            source: GasOracleSource::FeeHistory {
+                block_count: self.block_count,
+                reward_percentile: self.reward_percentile,
+                base_fee_per_gas,
+            },
+            max_fee_per_gas: base_fee_per_gas
+                .saturating_mul(2)
+                .saturating_add(max_priority_fee_per_gas),
+            max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// Prices every transaction with the same fees, for testnets with a
+/// predictable fee market.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedGasOracle {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+#[async_trait::async_trait]
+impl GasOracle for FixedGasOracle {
+    async fn estimate_fees<P: Provider>(&self, _provider: &P) -> TransportResult<GasOracleInputs> {
+        Ok(GasOracleInputs {
+            source: GasOracleSource::Fixed,
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+        })
+    }
+}
+
+/// One of the available gas oracles, selected at runtime.
+#[derive(Clone, Copy, Debug)]
+pub enum AnyGasOracle {
+    Provider(ProviderGasOracle),
+    FeeHistory(FeeHistoryGasOracle),
+    Fixed(FixedGasOracle),
+}
+
+#[async_trait::async_trait]
+impl GasOracle for AnyGasOracle {
+    async fn estimate_fees<P: Provider>(&self, provider: &P) -> TransportResult<GasOracleInputs> {
+        match self {
+            Self::Provider(oracle) => oracle.estimate_fees(provider).await,
+            Self::FeeHistory(oracle) => oracle.estimate_fees(provider).await,
+            Self::Fixed(oracle) => oracle.estimate_fees(provider).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::providers::{mock::Asserter, ProviderBuilder};
+
+    use super::*;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn fee_history(base_fees: Vec<u128>, rewards: Vec<u128>) -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: base_fees,
+            reward: Some(rewards.into_iter().map(|reward| vec![reward]).collect()),
+            oldest_block: 100,
+            ..FeeHistory::default()
+        }
+    }
+
+    fn oracle() -> FeeHistoryGasOracle {
+        FeeHistoryGasOracle {
+            block_count: 3,
+            reward_percentile: 90,
+        }
+    }
+
+    #[test]
+    fn fee_history_averages_rewards_of_non_empty_blocks() {
+        let history = fee_history(
+            vec![10 * GWEI, 11 * GWEI, 12 * GWEI, 13 * GWEI],
+            vec![GWEI, 0, 3 * GWEI],
+        );
+
+        let inputs = oracle().estimate_from_history(&history).unwrap();
+
+        assert_eq!(
+            inputs,
+            GasOracleInputs {
+                source: GasOracleSource::FeeHistory {
+                    block_count: 3,
+                    reward_percentile: 90,
+                    base_fee_per_gas: 13 * GWEI,
+                },
+                max_fee_per_gas: 28 * GWEI,
+                max_priority_fee_per_gas: 2 * GWEI,
+            }
+        );
+    }
+
+    #[test]
+    fn fee_history_of_empty_blocks_has_no_priority_fee() {
+        let history = fee_history(vec![GWEI, GWEI], vec![0]);
+
+        let inputs = oracle().estimate_from_history(&history).unwrap();
+
+        assert_eq!(inputs.max_priority_fee_per_gas, 0);
+        assert_eq!(inputs.max_fee_per_gas, 2 * GWEI);
+    }
+
+    #[test]
+    fn fee_history_without_base_fee_is_an_error() {
+        let history = fee_history(Vec::new(), Vec::new());
+
+        assert!(oracle().estimate_from_history(&history).is_err());
+    }
+
+    #[tokio::test]
+    async fn fee_history_oracle_prices_from_the_node_fee_history() {
+        let asserter = Asserter::new();
+        asserter.push_success(&fee_history(
+            vec![10 * GWEI, 10 * GWEI, 10 * GWEI, 10 * GWEI],
+            vec![GWEI, GWEI, GWEI],
+        ));
+        let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+        let inputs = AnyGasOracle::FeeHistory(oracle())
+            .estimate_fees(&provider)
+            .await
+            .unwrap();
+
+        assert_eq!(inputs.max_fee_per_gas, 21 * GWEI);
+        assert_eq!(inputs.max_priority_fee_per_gas, GWEI);
+    }
+
+    #[tokio::test]
+    async fn fixed_oracle_makes_no_rpc_call() {
+        // An empty asserter fails any request.
+        let provider = ProviderBuilder::new().connect_mocked_client(Asserter::new());
+        let oracle = FixedGasOracle {
+            max_fee_per_gas: 20 * GWEI,
+            max_priority_fee_per_gas: GWEI,
+        };
+
+        let inputs = oracle.estimate_fees(&provider).await.unwrap();
+
+        assert_eq!(
+            inputs,
+            GasOracleInputs {
+                source: GasOracleSource::Fixed,
+                max_fee_per_gas: 20 * GWEI,
+                max_priority_fee_per_gas: GWEI,
+            }
+        );
+    }
+}
@@ -20,10 +20,14 @@ use tracing::{debug, info};
 pub mod aggchain;
 mod block_pinning;
 pub mod contracts;
+pub mod gas_oracle;
 pub mod rollup;
 pub mod settler;
 
 pub use aggchain::AggchainContract;
+pub use gas_oracle::{
+    AnyGasOracle, FeeHistoryGasOracle, FixedGasOracle, GasOracle, ProviderGasOracle,
+};
 pub use rollup::RollupContract;
 pub use settler::Settler;
 
@@ -1,12 +1,16 @@
 use alloy::{
     contract::Error as ContractError,
     eips::eip1559::Eip1559Estimation,
-    primitives::Bytes,
+    primitives::{Address, Bytes},
     providers::{PendingTransactionBuilder, Provider},
 };
 use tracing::debug;
 
-use crate::{adjust_gas_estimate, contracts::PolygonRollupManager, L1RpcClient};
+use crate::{
+    adjust_gas_estimate,
+    contracts::{IMulticall3, PolygonRollupManager},
+    L1RpcClient,
+};
 
 /// ABI-encode a `verifyPessimisticTrustedAggregator` call into raw settlement
 /// calldata, without a provider. The settlement task owns gas/nonce/fees and
@@ -33,6 +37,46 @@ pub fn verify_pessimistic_trusted_aggregator_calldata(
     .into()
 }
 
+/// ABI-encode a Multicall3 `aggregate3` call performing the given calls in
+/// order. None of them is allowed to fail: the first revert reverts the whole
+/// call.
+pub fn aggregate3_calldata(calls: impl IntoIterator<Item = (Address, Bytes)>) -> Bytes {
+    use alloy::sol_types::SolCall as _;
+
+    IMulticall3::aggregate3Call {
+        calls: calls
+            .into_iter()
+            .map(|(target, call_data)| IMulticall3::Call3 {
+                target,
+                allowFailure: false,
+                callData: call_data,
+            })
+            .collect(),
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Decode the revert reason of a failed contract call: the revert string when
+/// the revert data carries one, else the raw revert data in hex. Non-revert
+/// errors are rendered with their debug representation.
+pub fn decode_contract_revert(error: &ContractError) -> Option<String> {
+    // Try to get raw revert data and decode it manually if the interface method
+    // fails
+    if let Some(revert_data) = error.as_revert_data() {
+        // If specific error decoding fails, try to extract a revert reason string
+        if let Some(reason) = alloy::sol_types::decode_revert_reason(revert_data.as_ref()) {
+            return Some(reason);
+        }
+
+        // Fall back to hex representation of revert data
+        return Some(format!("0x{}", hex::encode(revert_data)));
+    }
+
+    // For non-revert errors, return the debug representation
+    Some(format!("{error:?}"))
+}
+
 const DEFAULT_GAS_PRICE_REPEAT_TX_INCREASE_FACTOR_PERCENTS: u128 = 150; //1.5X
 
 #[async_trait::async_trait]
@@ -59,20 +103,7 @@ where
     RpcProvider: Provider + Clone + 'static,
 {
     fn decode_contract_revert(error: &ContractError) -> Option<String> {
-        // Try to get raw revert data and decode it manually if the interface method
-        // fails
-        if let Some(revert_data) = error.as_revert_data() {
-            // If specific error decoding fails, try to extract a revert reason string
-            if let Some(reason) = alloy::sol_types::decode_revert_reason(revert_data.as_ref()) {
-                return Some(reason);
-            }
-
-            // Fall back to hex representation of revert data
-            return Some(format!("0x{}", hex::encode(revert_data)));
-        }
-
-        // For non-revert errors, return the debug representation
-        Some(format!("{error:?}"))
+        decode_contract_revert(error)
     }
 
     #[tracing::instrument(skip(self, proof))]
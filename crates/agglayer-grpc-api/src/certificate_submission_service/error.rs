@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use agglayer_grpc_types::node::v1::SubmitCertificateErrorKind;
-use agglayer_rpc::CertificateSubmissionError;
+use agglayer_grpc_types::node::v1::{SimulateCertificateErrorKind, SubmitCertificateErrorKind};
+use agglayer_rpc::{CertificateSimulationError, CertificateSubmissionError, StateProofError};
 use tonic_types::{ErrorDetails, StatusExt as _};
 use tracing::{error, warn};
 
@@ -79,6 +79,100 @@ impl From<CertificateSubmissionErrorWrapper> for tonic::Status {
                     ),
                 )
             }
+
+            agglayer_rpc::CertificateSubmissionError::RateLimited(rate_limited) => {
+                let mut details = ErrorDetails::with_error_info(
+                    SubmitCertificateErrorKind::RateLimited.as_str_name(),
+                    error.context,
+                    [("error".into(), rate_limited.to_string())],
+                );
+                details.set_retry_info(rate_limited.retry_after());
+                tonic::Status::with_error_details(
+                    tonic::Code::ResourceExhausted,
+                    "Certificate submission rate limited",
+                    details,
+                )
+            }
+
+            agglayer_rpc::CertificateSubmissionError::TooManyPendingCertificates {
+                network_id,
+                max,
+            } => tonic::Status::with_error_details(
+                tonic::Code::ResourceExhausted,
+                "Too many pending certificates",
+                ErrorDetails::with_error_info(
+                    SubmitCertificateErrorKind::RateLimited.as_str_name(),
+                    error.context,
+                    [
+                        ("network_id".into(), network_id.to_string()),
+                        ("max_pending_certificates".into(), max.to_string()),
+                    ],
+                ),
+            ),
+        }
+    }
+}
+
+pub(crate) struct CertificateSimulationErrorWrapper {
+    inner: CertificateSimulationError,
+    context: &'static str,
+}
+
+impl CertificateSimulationErrorWrapper {
+    pub(crate) fn new(inner: CertificateSimulationError, context: &'static str) -> Self {
+        Self { inner, context }
+    }
+}
+
+impl From<CertificateSimulationErrorWrapper> for tonic::Status {
+    fn from(error: CertificateSimulationErrorWrapper) -> Self {
+        match error.inner {
+            CertificateSimulationError::Unavailable => {
+                tonic::Status::unimplemented("Certificate simulation is not available")
+            }
+
+            CertificateSimulationError::UnexpectedHeight {
+                network_id,
+                expected,
+                got,
+            } => tonic::Status::with_error_details(
+                tonic::Code::InvalidArgument,
+                "Unexpected certificate height",
+                ErrorDetails::with_error_info(
+                    SimulateCertificateErrorKind::UnexpectedHeight.as_str_name(),
+                    error.context,
+                    [
+                        ("network_id".into(), network_id.to_string()),
+                        ("expected_height".into(), expected.to_string()),
+                        ("height".into(), got.to_string()),
+                    ],
+                ),
+            ),
+
+            CertificateSimulationError::SettledState(
+                inner @ StateProofError::UnanchoredState { .. },
+            ) => tonic::Status::with_error_details(
+                tonic::Code::Unavailable,
+                inner.to_string(),
+                ErrorDetails::with_error_info(
+                    SimulateCertificateErrorKind::Unavailable.as_str_name(),
+                    error.context,
+                    [],
+                ),
+            ),
+
+            CertificateSimulationError::SettledState(inner) => {
+                error!(error = ?inner, "returning internal error to RPC");
+                tonic::Status::with_error_details(
+                    tonic::Code::Internal,
+                    "Internal error",
+                    ErrorDetails::with_error_info(
+                        SimulateCertificateErrorKind::InternalError.as_str_name(),
+                        error.context,
+                        [],
+                    ),
+                )
+            }
         }
     }
 }
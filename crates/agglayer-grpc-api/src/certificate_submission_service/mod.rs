@@ -3,19 +3,22 @@ use std::sync::Arc;
 use agglayer_contracts::{AggchainContract, L1TransactionFetcher, RollupContract};
 use agglayer_grpc_server::node::v1::certificate_submission_service_server::CertificateSubmissionService;
 use agglayer_grpc_types::node::v1::{
+    SimulateCertificateErrorKind, SimulateCertificateRequest, SimulateCertificateResponse,
     SubmitCertificateErrorKind, SubmitCertificateRequest, SubmitCertificateResponse,
 };
 use agglayer_rpc::AgglayerService;
 use agglayer_storage::stores::{
-    DebugReader, DebugWriter, EpochStoreReader, PendingCertificateReader, PendingCertificateWriter,
-    SettlementReader, StateReader, StateWriter,
+    DebugReader, DebugWriter, EpochStoreReader, NetworkInfoReader, PendingCertificateReader,
+    PendingCertificateWriter, SettlementReader, StateReader, StateWriter,
 };
-use error::CertificateSubmissionErrorWrapper;
+use error::{CertificateSimulationErrorWrapper, CertificateSubmissionErrorWrapper};
 use tonic_types::{ErrorDetails, StatusExt};
 use tracing::instrument;
 
 const SUBMIT_CERTIFICATE_METHOD_PATH: &str =
     "agglayer-node.grpc-api.v1.certificate-submission-service.submit_certificate";
+const SIMULATE_CERTIFICATE_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.certificate-submission-service.simulate_certificate";
 
 pub struct CertificateSubmissionServer<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore> {
     pub(crate) service:
@@ -29,7 +32,7 @@ impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore> CertificateSubmis
     for CertificateSubmissionServer<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 where
     PendingStore: PendingCertificateReader + PendingCertificateWriter + 'static,
-    StateStore: SettlementReader + StateReader + StateWriter + 'static,
+    StateStore: NetworkInfoReader + SettlementReader + StateReader + StateWriter + 'static,
     DebugStore: DebugReader + DebugWriter + 'static,
     L1Rpc: RollupContract + AggchainContract + L1TransactionFetcher + Send + Sync + 'static,
     EpochsStore: EpochStoreReader + 'static,
@@ -84,4 +87,53 @@ where
             certificate_id: Some(certificate_id.into()),
         }))
     }
+    #[instrument(skip(self, request), level = "debug", fields(
+        certificate_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn simulate_certificate(
+        &self,
+        request: tonic::Request<SimulateCertificateRequest>,
+    ) -> Result<tonic::Response<SimulateCertificateResponse>, tonic::Status> {
+        let certificate: agglayer_types::Certificate = match request.into_inner().certificate {
+            Some(certificate) => certificate.try_into().map_err(
+                |error: agglayer_grpc_types::compat::v1::CertificateConversionError| {
+                    let (message, kind, details) = match error.unsupported_proof_version() {
+                        Some(version) => (
+                            "Unsupported proof version",
+                            SimulateCertificateErrorKind::UnsupportedProofVersion.as_str_name(),
+                            [("proof_version".into(), version.to_owned())],
+                        ),
+                        None => (
+                            "Invalid certificate",
+                            SimulateCertificateErrorKind::from(error.kind()).as_str_name(),
+                            [("error".into(), format!("{error:?}"))],
+                        ),
+                    };
+
+                    tonic::Status::with_error_details(
+                        tonic::Code::InvalidArgument,
+                        message,
+                        ErrorDetails::with_error_info(
+                            kind,
+                            SIMULATE_CERTIFICATE_METHOD_PATH,
+                            details,
+                        ),
+                    )
+                },
+            )?,
+            None => return Err(tonic::Status::invalid_argument("Missing certificate")),
+        };
+        tracing::Span::current().record("certificate_id", certificate.hash().to_string());
+
+        let simulation = self
+            .service
+            .simulate_certificate(certificate)
+            .await
+            .map_err(|error| {
+                CertificateSimulationErrorWrapper::new(error, SIMULATE_CERTIFICATE_METHOD_PATH)
+            })?;
+
+        Ok(tonic::Response::new(simulation.into()))
+    }
 }
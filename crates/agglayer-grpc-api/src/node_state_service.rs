@@ -1,21 +1,31 @@
-use std::sync::Arc;
+use std::{pin::Pin, sync::Arc};
 
 use agglayer_grpc_server::node::v1::node_state_service_server::NodeStateService;
 use agglayer_grpc_types::{
     compat::v1::Error,
     node::v1::{
+        GetBalanceProofErrorKind, GetBalanceProofRequest, GetBalanceProofResponse,
         GetCertificateHeaderErrorKind, GetCertificateHeaderRequest, GetCertificateHeaderResponse,
         GetLatestCertificateHeaderErrorKind, GetLatestCertificateHeaderRequest,
-        GetLatestCertificateHeaderResponse, GetNetworkInfoErrorKind, GetNetworkInfoRequest,
-        GetNetworkInfoResponse, LatestCertificateRequestType,
+        GetLatestCertificateHeaderResponse, GetLocalExitTreeProofErrorKind,
+        GetLocalExitTreeProofRequest, GetLocalExitTreeProofResponse, GetNetworkInfoErrorKind,
+        GetNetworkInfoRequest, GetNetworkInfoResponse, GetNullifierProofErrorKind,
+        GetNullifierProofRequest, GetNullifierProofResponse, LatestCertificateRequestType,
+        ListCertificateHeadersErrorKind, ListCertificateHeadersRequest,
+        ListCertificateHeadersResponse, ListDirection, SubscribeCertificateStatusErrorKind,
+        SubscribeCertificateStatusRequest, SubscribeCertificateStatusResponse,
     },
 };
-use agglayer_rpc::AgglayerService;
+use agglayer_rpc::{AgglayerService, CertificateStatusFilter, StateProofError};
 use agglayer_storage::stores::{
     DebugReader, EpochStoreReader, NetworkInfoReader, PendingCertificateReader, StateReader,
 };
+use tokio_stream::{
+    wrappers::{errors::BroadcastStreamRecvError, BroadcastStream},
+    Stream, StreamExt as _,
+};
 use tonic_types::{ErrorDetails, StatusExt as _};
-use tracing::error;
+use tracing::{error, warn};
 
 const GET_CERTIFICATE_HEADER_METHOD_PATH: &str =
     "agglayer-node.grpc-api.v1.node-state-service.get_certificate_header";
@@ -23,6 +33,81 @@ const GET_LATEST_CERTIFICATE_HEADER_METHOD_PATH: &str =
     "agglayer-node.grpc-api.v1.node-state-service.get_latest_certificate_header";
 const GET_NETWORK_INFO_METHOD_PATH: &str =
     "agglayer-node.grpc-api.v1.node-state-service.get_network_info";
+const SUBSCRIBE_CERTIFICATE_STATUS_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.node-state-service.subscribe_certificate_status";
+const LIST_CERTIFICATE_HEADERS_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.node-state-service.list_certificate_headers";
+const GET_BALANCE_PROOF_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.node-state-service.get_balance_proof";
+const GET_NULLIFIER_PROOF_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.node-state-service.get_nullifier_proof";
+const GET_LOCAL_EXIT_TREE_PROOF_METHOD_PATH: &str =
+    "agglayer-node.grpc-api.v1.node-state-service.get_local_exit_tree_proof";
+
+/// Error kinds shared by the settled state proof methods.
+trait StateProofErrorKind {
+    const INVALID_DATA: Self;
+    const NOT_FOUND: Self;
+    const UNAVAILABLE: Self;
+    const INTERNAL_ERROR: Self;
+
+    fn as_str_name(&self) -> &'static str;
+}
+
+macro_rules! impl_state_proof_error_kind {
+    ($($kind:ident),* $(,)?) => {
+        $(
+            impl StateProofErrorKind for $kind {
+                const INVALID_DATA: Self = $kind::InvalidData;
+                const NOT_FOUND: Self = $kind::NotFound;
+                const UNAVAILABLE: Self = $kind::Unavailable;
+                const INTERNAL_ERROR: Self = $kind::InternalError;
+
+                fn as_str_name(&self) -> &'static str {
+                    $kind::as_str_name(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_state_proof_error_kind!(
+    GetBalanceProofErrorKind,
+    GetNullifierProofErrorKind,
+    GetLocalExitTreeProofErrorKind,
+);
+
+fn state_proof_status<K: StateProofErrorKind>(
+    error: StateProofError,
+    method_path: &'static str,
+) -> tonic::Status {
+    let (code, kind) = match &error {
+        StateProofError::NoSettledState { .. }
+        | StateProofError::NoStateSnapshot { .. }
+        | StateProofError::LeavesUnavailable { .. } => (tonic::Code::NotFound, K::NOT_FOUND),
+        StateProofError::LeafIndexOutOfRange { .. } | StateProofError::HeightNotSettled { .. } => {
+            (tonic::Code::InvalidArgument, K::INVALID_DATA)
+        }
+        StateProofError::UnanchoredState { .. } => (tonic::Code::Unavailable, K::UNAVAILABLE),
+        StateProofError::Storage(_) | StateProofError::ProofGeneration { .. } => {
+            error!(?error, "returning internal error to RPC");
+            return tonic::Status::with_error_details(
+                tonic::Code::Internal,
+                "Internal error",
+                ErrorDetails::with_error_info(K::INTERNAL_ERROR.as_str_name(), method_path, []),
+            );
+        }
+    };
+
+    tonic::Status::with_error_details(
+        code,
+        error.to_string(),
+        ErrorDetails::with_error_info(kind.as_str_name(), method_path, []),
+    )
+}
+
+type SubscribeCertificateStatusStream =
+    Pin<Box<dyn Stream<Item = Result<SubscribeCertificateStatusResponse, tonic::Status>> + Send>>;
 
 pub struct NodeStateServer<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore> {
     pub(crate) service:
@@ -39,6 +124,8 @@ where
     L1Rpc: Send + Sync + 'static,
     EpochsStore: EpochStoreReader + 'static,
 {
+    type SubscribeCertificateStatusStream = SubscribeCertificateStatusStream;
+
     #[tracing::instrument(level = "debug", skip(self, request), fields(
         request_id = tracing::field::Empty,
         client = crate::client_info_from_metadata(request.metadata())
@@ -196,4 +283,224 @@ where
             network_info: Some(network_info),
         }))
     }
+    #[tracing::instrument(level = "debug", skip(self, request), fields(
+        request_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn subscribe_certificate_status(
+        &self,
+        request: tonic::Request<SubscribeCertificateStatusRequest>,
+    ) -> Result<tonic::Response<Self::SubscribeCertificateStatusStream>, tonic::Status> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        tracing::Span::current().record("request_id", &request_id);
+        let request = request.into_inner();
+
+        let certificate_id: Option<agglayer_types::CertificateId> = request
+            .certificate_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|error: Error| {
+                tonic::Status::with_error_details(
+                    tonic::Code::InvalidArgument,
+                    "Invalid certificate ID",
+                    ErrorDetails::with_error_info(
+                        SubscribeCertificateStatusErrorKind::from(error.kind()).as_str_name(),
+                        SUBSCRIBE_CERTIFICATE_STATUS_METHOD_PATH,
+                        [("error".into(), format!("{error:?}"))],
+                    ),
+                )
+            })?;
+
+        let filter = CertificateStatusFilter {
+            network_id: request.network_id.map(Into::into),
+            certificate_id,
+        };
+
+        let receiver = self.service.subscribe_certificate_status();
+        let stream = BroadcastStream::new(receiver).filter_map(move |header| match header {
+            Ok(header) if filter.matches(&header) => Some(Ok(SubscribeCertificateStatusResponse {
+                certificate_header: Some(header.into()),
+            })),
+            Ok(_) => None,
+            // Returning an error ends the stream, letting the client know that
+            // it missed some transitions and should resubscribe.
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                warn!(skipped, "Certificate status subscriber lagged behind");
+                Some(Err(tonic::Status::data_loss(format!(
+                    "Subscriber lagged behind, {skipped} certificate status transitions were \
+                     dropped"
+                ))))
+            }
+        });
+
+        Ok(tonic::Response::new(Box::pin(stream)))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, request), fields(
+        request_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn list_certificate_headers(
+        &self,
+        request: tonic::Request<ListCertificateHeadersRequest>,
+    ) -> Result<tonic::Response<ListCertificateHeadersResponse>, tonic::Status> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        tracing::Span::current().record("request_id", &request_id);
+        let request = request.into_inner();
+
+        let direction = match request.direction() {
+            ListDirection::Unspecified | ListDirection::Ascending => {
+                agglayer_types::ListDirection::Ascending
+            }
+            ListDirection::Descending => agglayer_types::ListDirection::Descending,
+        };
+
+        let headers = self
+            .service
+            .list_certificate_headers(
+                request.network_id.into(),
+                agglayer_types::Height::new(request.from_height),
+                request.limit as usize,
+                direction,
+            )
+            .map_err(|error| match error {
+                agglayer_rpc::ListCertificateHeadersError::InvalidLimit => {
+                    let error = Error::invalid_data(error.to_string()).inside_field("limit");
+                    tonic::Status::with_error_details(
+                        tonic::Code::InvalidArgument,
+                        "Invalid limit",
+                        ErrorDetails::with_error_info(
+                            ListCertificateHeadersErrorKind::InvalidData.as_str_name(),
+                            LIST_CERTIFICATE_HEADERS_METHOD_PATH,
+                            [("error".into(), format!("{error:?}"))],
+                        ),
+                    )
+                }
+                agglayer_rpc::ListCertificateHeadersError::Storage(error) => {
+                    error!(?error, "returning internal storage error to RPC");
+                    tonic::Status::with_error_details(
+                        tonic::Code::Internal,
+                        "Internal storage error",
+                        ErrorDetails::with_error_info(
+                            ListCertificateHeadersErrorKind::InternalError.as_str_name(),
+                            LIST_CERTIFICATE_HEADERS_METHOD_PATH,
+                            [],
+                        ),
+                    )
+                }
+            })?;
+
+        Ok(tonic::Response::new(ListCertificateHeadersResponse {
+            certificate_headers: headers.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, request), fields(
+        request_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn get_balance_proof(
+        &self,
+        request: tonic::Request<GetBalanceProofRequest>,
+    ) -> Result<tonic::Response<GetBalanceProofResponse>, tonic::Status> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        tracing::Span::current().record("request_id", &request_id);
+        let request = request.into_inner();
+
+        let token_info: agglayer_types::TokenInfo = request
+            .token_info
+            .ok_or_else(|| Error::missing_field("token_info"))
+            .and_then(|t| t.try_into())
+            .map_err(|error| {
+                tonic::Status::with_error_details(
+                    tonic::Code::InvalidArgument,
+                    "Invalid token info",
+                    ErrorDetails::with_error_info(
+                        GetBalanceProofErrorKind::from(error.kind()).as_str_name(),
+                        GET_BALANCE_PROOF_METHOD_PATH,
+                        [("error".into(), format!("{error:?}"))],
+                    ),
+                )
+            })?;
+
+        let proof = self
+            .service
+            .get_balance_proof(request.network_id.into(), token_info, None)
+            .map_err(|error| {
+                state_proof_status::<GetBalanceProofErrorKind>(error, GET_BALANCE_PROOF_METHOD_PATH)
+            })?;
+
+        Ok(tonic::Response::new(proof.into()))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, request), fields(
+        request_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn get_nullifier_proof(
+        &self,
+        request: tonic::Request<GetNullifierProofRequest>,
+    ) -> Result<tonic::Response<GetNullifierProofResponse>, tonic::Status> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        tracing::Span::current().record("request_id", &request_id);
+        let request = request.into_inner();
+
+        let global_index = request
+            .global_index
+            .ok_or_else(|| Error::missing_field("global_index"))
+            .and_then(<[u8; 32]>::try_from)
+            .and_then(|bytes| {
+                agglayer_types::GlobalIndex::from_u256(agglayer_types::U256::from_be_bytes(bytes))
+                    .map_err(|error| Error::invalid_data(error.to_string()))
+            })
+            .map_err(|error| {
+                let error = error.inside_field("global_index");
+                tonic::Status::with_error_details(
+                    tonic::Code::InvalidArgument,
+                    "Invalid global index",
+                    ErrorDetails::with_error_info(
+                        GetNullifierProofErrorKind::from(error.kind()).as_str_name(),
+                        GET_NULLIFIER_PROOF_METHOD_PATH,
+                        [("error".into(), format!("{error:?}"))],
+                    ),
+                )
+            })?;
+
+        let proof = self
+            .service
+            .get_nullifier_proof(request.network_id.into(), global_index, None)
+            .map_err(|error| {
+                state_proof_status::<GetNullifierProofErrorKind>(
+                    error,
+                    GET_NULLIFIER_PROOF_METHOD_PATH,
+                )
+            })?;
+
+        Ok(tonic::Response::new(proof.into()))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, request), fields(
+        request_id = tracing::field::Empty,
+        client = crate::client_info_from_metadata(request.metadata())
+    ))]
+    async fn get_local_exit_tree_proof(
+        &self,
+        request: tonic::Request<GetLocalExitTreeProofRequest>,
+    ) -> Result<tonic::Response<GetLocalExitTreeProofResponse>, tonic::Status> {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        tracing::Span::current().record("request_id", &request_id);
+        let request = request.into_inner();
+
+        let proof = self
+            .service
+            .get_local_exit_tree_proof(request.network_id.into(), request.leaf_index, None)
+            .map_err(|error| {
+                state_proof_status::<GetLocalExitTreeProofErrorKind>(
+                    error,
+                    GET_LOCAL_EXIT_TREE_PROOF_METHOD_PATH,
+                )
+            })?;
+
+        Ok(tonic::Response::new(proof.into()))
+    }
 }
@@ -2,17 +2,26 @@ use std::{sync::Arc, time::Duration};
 
 use agglayer_config::Config;
 use agglayer_grpc_server::node::v1::node_state_service_server::NodeStateServiceServer;
-use agglayer_grpc_types::node::v1::GetCertificateHeaderRequest;
+use agglayer_grpc_types::node::{
+    types::v1,
+    v1::{
+        GetCertificateHeaderRequest, GetLocalExitTreeProofRequest, ListCertificateHeadersRequest,
+        ListDirection, SubscribeCertificateStatusRequest,
+    },
+};
 use agglayer_rpc::AgglayerService;
 use agglayer_storage::{
     backup::BackupClient,
     stores::{
         debug::DebugStore, epochs::EpochsStore, pending::PendingStore, state::StateStore,
-        StateWriter as _,
+        StateReader as _, StateWriter as _,
     },
     tests::TempDBDir,
 };
-use agglayer_types::{CertificateId, CertificateStatus, Digest, Height};
+use agglayer_types::{
+    CertificateId, CertificateIndex, CertificateStatus, Digest, EpochNumber, Height,
+    LocalNetworkStateData,
+};
 use tokio::{net::TcpListener, sync::oneshot};
 use tonic::Code;
 use tower::ServiceExt as _;
@@ -115,3 +124,355 @@ async fn get_certificate_header() {
     tx.send(()).unwrap();
     jh.await.unwrap();
 }
+
+#[tokio::test]
+async fn subscribe_certificate_status() {
+    let tmp = TempDBDir::new();
+    let config = Arc::new(Config::new(&tmp.path));
+
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(
+        StateStore::new_with_path(&config.storage.state_db_path, BackupClient::noop()).unwrap(),
+    );
+    let debug_store = Arc::new(DebugStore::new_with_path(&config.storage.debug_db_path).unwrap());
+
+    let certificate = agglayer_types::Certificate::new_for_test(1.into(), Height::ZERO);
+    let other_certificate = agglayer_types::Certificate::new_for_test(2.into(), Height::ZERO);
+    for certificate in [&certificate, &other_certificate] {
+        state_store
+            .insert_certificate_header(certificate, CertificateStatus::Pending)
+            .expect("Failed to insert certificate header");
+    }
+
+    let certificate_id = certificate.hash();
+    let header = state_store
+        .get_certificate_header(&certificate_id)
+        .unwrap()
+        .unwrap();
+    let other_header = state_store
+        .get_certificate_header(&other_certificate.hash())
+        .unwrap()
+        .unwrap();
+
+    let (sender, _receiver) = tokio::sync::mpsc::channel(10);
+    let (status_sender, _) = tokio::sync::broadcast::channel(10);
+    let service = Arc::new(
+        AgglayerService::new(
+            sender,
+            pending_store.clone(),
+            state_store.clone(),
+            debug_store,
+            Arc::new(
+                EpochsStore::new(
+                    config.clone(),
+                    pending_store,
+                    state_store,
+                    BackupClient::noop(),
+                )
+                .unwrap(),
+            ),
+            config,
+            Arc::new(L1Rpc {}),
+        )
+        .with_certificate_status_sender(status_sender.clone()),
+    );
+    let (tx, rx) = oneshot::channel::<()>();
+    let svc = NodeStateServiceServer::new(NodeStateServer { service });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let app = axum::Router::new().route_service(
+        "/agglayer.node.v1.NodeStateService/{*rest}",
+        svc.map_request(|r: http::Request<axum::body::Body>| r.map(tonic::body::Body::new)),
+    );
+
+    let jh = tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async { drop(rx.await) })
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client =
+        agglayer_grpc_client::node::v1::node_state_service_client::NodeStateServiceClient::connect(
+            format!("http://{addr}"),
+        )
+        .await
+        .unwrap();
+
+    let mut stream = client
+        .subscribe_certificate_status(SubscribeCertificateStatusRequest {
+            network_id: Some(1),
+            certificate_id: None,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    while status_sender.receiver_count() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The transition of the other network is filtered out.
+    status_sender.send(other_header).unwrap();
+
+    let mut settled = header.clone();
+    settled.status = CertificateStatus::Settled;
+    status_sender.send(header).unwrap();
+    status_sender.send(settled).unwrap();
+
+    for expected in [
+        v1::CertificateStatus::Pending,
+        v1::CertificateStatus::Settled,
+    ] {
+        let header = stream
+            .message()
+            .await
+            .unwrap()
+            .unwrap()
+            .certificate_header
+            .unwrap();
+
+        assert_eq!(
+            CertificateId::try_from(header.certificate_id.unwrap()).unwrap(),
+            certificate_id
+        );
+        assert_eq!(header.status(), expected);
+    }
+
+    drop(stream);
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+}
+
+#[tokio::test]
+async fn list_certificate_headers() {
+    let tmp = TempDBDir::new();
+    let config = Arc::new(Config::new(&tmp.path));
+
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(
+        StateStore::new_with_path(&config.storage.state_db_path, BackupClient::noop()).unwrap(),
+    );
+    let debug_store = Arc::new(DebugStore::new_with_path(&config.storage.debug_db_path).unwrap());
+
+    let certificate_ids: Vec<_> = (0..3)
+        .map(|height| {
+            let certificate =
+                agglayer_types::Certificate::new_for_test(1.into(), Height::new(height));
+            state_store
+                .insert_certificate_header(&certificate, CertificateStatus::Settled)
+                .expect("Failed to insert certificate header");
+            certificate.hash()
+        })
+        .collect();
+
+    let (sender, _receiver) = tokio::sync::mpsc::channel(10);
+    let service = Arc::new(AgglayerService::new(
+        sender,
+        pending_store.clone(),
+        state_store.clone(),
+        debug_store,
+        Arc::new(
+            EpochsStore::new(
+                config.clone(),
+                pending_store,
+                state_store,
+                BackupClient::noop(),
+            )
+            .unwrap(),
+        ),
+        config,
+        Arc::new(L1Rpc {}),
+    ));
+    let (tx, rx) = oneshot::channel::<()>();
+    let svc = NodeStateServiceServer::new(NodeStateServer { service });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let app = axum::Router::new().route_service(
+        "/agglayer.node.v1.NodeStateService/{*rest}",
+        svc.map_request(|r: http::Request<axum::body::Body>| r.map(tonic::body::Body::new)),
+    );
+
+    let jh = tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async { drop(rx.await) })
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client =
+        agglayer_grpc_client::node::v1::node_state_service_client::NodeStateServiceClient::connect(
+            format!("http://{addr}"),
+        )
+        .await
+        .unwrap();
+
+    let error = client
+        .list_certificate_headers(ListCertificateHeadersRequest {
+            network_id: 1,
+            from_height: 0,
+            limit: 0,
+            direction: ListDirection::Unspecified.into(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), Code::InvalidArgument);
+
+    let response = client
+        .list_certificate_headers(ListCertificateHeadersRequest {
+            network_id: 1,
+            from_height: 2,
+            limit: 2,
+            direction: ListDirection::Descending.into(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let listed: Vec<_> = response
+        .certificate_headers
+        .into_iter()
+        .map(|header| CertificateId::try_from(header.certificate_id.unwrap()).unwrap())
+        .collect();
+    assert_eq!(listed, vec![certificate_ids[2], certificate_ids[1]]);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+}
+
+#[tokio::test]
+async fn get_local_exit_tree_proof() {
+    let tmp = TempDBDir::new();
+    let config = Arc::new(Config::new(&tmp.path));
+
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(
+        StateStore::new_with_path(&config.storage.state_db_path, BackupClient::noop()).unwrap(),
+    );
+    let debug_store = Arc::new(DebugStore::new_with_path(&config.storage.debug_db_path).unwrap());
+
+    let leaves: Vec<_> = (1..=3u8).map(|i| Digest([i; 32])).collect();
+    let mut state = LocalNetworkStateData::default();
+    for leaf in &leaves {
+        state.exit_tree.add_leaf(*leaf).unwrap();
+    }
+    let mut certificate = agglayer_types::Certificate::new_for_test(1.into(), Height::ZERO);
+    certificate.new_local_exit_root = state.exit_tree.get_root().into();
+    state_store
+        .insert_certificate_header(&certificate, CertificateStatus::Settled)
+        .unwrap();
+    state_store
+        .write_local_network_state(&1.into(), &state, &leaves)
+        .unwrap();
+    state_store
+        .set_latest_settled_certificate_for_network(
+            &1.into(),
+            &Height::ZERO,
+            &certificate.hash(),
+            &EpochNumber::ZERO,
+            &CertificateIndex::ZERO,
+        )
+        .unwrap();
+
+    let (sender, _receiver) = tokio::sync::mpsc::channel(10);
+    let service = Arc::new(AgglayerService::new(
+        sender,
+        pending_store.clone(),
+        state_store.clone(),
+        debug_store,
+        Arc::new(
+            EpochsStore::new(
+                config.clone(),
+                pending_store,
+                state_store,
+                BackupClient::noop(),
+            )
+            .unwrap(),
+        ),
+        config,
+        Arc::new(L1Rpc {}),
+    ));
+    let (tx, rx) = oneshot::channel::<()>();
+    let svc = NodeStateServiceServer::new(NodeStateServer { service });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let app = axum::Router::new().route_service(
+        "/agglayer.node.v1.NodeStateService/{*rest}",
+        svc.map_request(|r: http::Request<axum::body::Body>| r.map(tonic::body::Body::new)),
+    );
+
+    let jh = tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async { drop(rx.await) })
+            .await
+            .unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client =
+        agglayer_grpc_client::node::v1::node_state_service_client::NodeStateServiceClient::connect(
+            format!("http://{addr}"),
+        )
+        .await
+        .unwrap();
+
+    let response = client
+        .get_local_exit_tree_proof(GetLocalExitTreeProofRequest {
+            network_id: 1,
+            leaf_index: 1,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    let anchor = response.anchor.unwrap();
+    assert_eq!(
+        CertificateId::try_from(anchor.certificate_id.unwrap()).unwrap(),
+        certificate.hash()
+    );
+    assert_eq!(anchor.let_leaf_count, 3);
+    assert_eq!(
+        Digest::from(<[u8; 32]>::try_from(anchor.local_exit_root.unwrap()).unwrap()),
+        state.exit_tree.get_root()
+    );
+    assert_eq!(
+        Digest::from(<[u8; 32]>::try_from(response.leaf.unwrap()).unwrap()),
+        leaves[1]
+    );
+    assert_eq!(response.siblings.len(), 32);
+
+    let error = client
+        .get_local_exit_tree_proof(GetLocalExitTreeProofRequest {
+            network_id: 1,
+            leaf_index: 3,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), Code::InvalidArgument);
+
+    let error = client
+        .get_local_exit_tree_proof(GetLocalExitTreeProofRequest {
+            network_id: 2,
+            leaf_index: 0,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), Code::NotFound);
+
+    tx.send(()).unwrap();
+    jh.await.unwrap();
+}
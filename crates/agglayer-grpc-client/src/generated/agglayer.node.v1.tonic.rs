@@ -121,6 +121,35 @@ pub mod certificate_submission_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn simulate_certificate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateCertificateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateCertificateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.CertificateSubmissionService/SimulateCertificate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.CertificateSubmissionService",
+                        "SimulateCertificate",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -423,5 +452,152 @@ pub mod node_state_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn subscribe_certificate_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeCertificateStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::SubscribeCertificateStatusResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.NodeStateService/SubscribeCertificateStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.NodeStateService",
+                        "SubscribeCertificateStatus",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn list_certificate_headers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCertificateHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCertificateHeadersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.NodeStateService/ListCertificateHeaders",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.NodeStateService",
+                        "ListCertificateHeaders",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_balance_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBalanceProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBalanceProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.NodeStateService/GetBalanceProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.NodeStateService",
+                        "GetBalanceProof",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_nullifier_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNullifierProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNullifierProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.NodeStateService/GetNullifierProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.NodeStateService",
+                        "GetNullifierProof",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_local_exit_tree_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLocalExitTreeProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLocalExitTreeProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/agglayer.node.v1.NodeStateService/GetLocalExitTreeProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "agglayer.node.v1.NodeStateService",
+                        "GetLocalExitTreeProof",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
@@ -19,6 +19,13 @@ pub mod certificate_submission_service_server {
             tonic::Response<super::SubmitCertificateResponse>,
             tonic::Status,
         >;
+        async fn simulate_certificate(
+            &self,
+            request: tonic::Request<super::SimulateCertificateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateCertificateResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct CertificateSubmissionServiceServer<T> {
@@ -148,6 +155,57 @@ pub mod certificate_submission_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/agglayer.node.v1.CertificateSubmissionService/SimulateCertificate" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateCertificateSvc<T: CertificateSubmissionService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: CertificateSubmissionService,
+                    > tonic::server::UnaryService<super::SimulateCertificateRequest>
+                    for SimulateCertificateSvc<T> {
+                        type Response = super::SimulateCertificateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateCertificateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CertificateSubmissionService>::simulate_certificate(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SimulateCertificateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -409,6 +467,50 @@ pub mod node_state_service_server {
             tonic::Response<super::GetNetworkInfoResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the SubscribeCertificateStatus method.
+        type SubscribeCertificateStatusStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::SubscribeCertificateStatusResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn subscribe_certificate_status(
+            &self,
+            request: tonic::Request<super::SubscribeCertificateStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeCertificateStatusStream>,
+            tonic::Status,
+        >;
+        async fn list_certificate_headers(
+            &self,
+            request: tonic::Request<super::ListCertificateHeadersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCertificateHeadersResponse>,
+            tonic::Status,
+        >;
+        async fn get_balance_proof(
+            &self,
+            request: tonic::Request<super::GetBalanceProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBalanceProofResponse>,
+            tonic::Status,
+        >;
+        async fn get_nullifier_proof(
+            &self,
+            request: tonic::Request<super::GetNullifierProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNullifierProofResponse>,
+            tonic::Status,
+        >;
+        async fn get_local_exit_tree_proof(
+            &self,
+            request: tonic::Request<super::GetLocalExitTreeProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLocalExitTreeProofResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct NodeStateServiceServer<T> {
@@ -635,6 +737,265 @@ pub mod node_state_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/agglayer.node.v1.NodeStateService/SubscribeCertificateStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeCertificateStatusSvc<T: NodeStateService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: NodeStateService,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeCertificateStatusRequest,
+                    > for SubscribeCertificateStatusSvc<T> {
+                        type Response = super::SubscribeCertificateStatusResponse;
+                        type ResponseStream = T::SubscribeCertificateStatusStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SubscribeCertificateStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeStateService>::subscribe_certificate_status(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeCertificateStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/agglayer.node.v1.NodeStateService/ListCertificateHeaders" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCertificateHeadersSvc<T: NodeStateService>(pub Arc<T>);
+                    impl<
+                        T: NodeStateService,
+                    > tonic::server::UnaryService<super::ListCertificateHeadersRequest>
+                    for ListCertificateHeadersSvc<T> {
+                        type Response = super::ListCertificateHeadersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ListCertificateHeadersRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeStateService>::list_certificate_headers(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListCertificateHeadersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/agglayer.node.v1.NodeStateService/GetBalanceProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBalanceProofSvc<T: NodeStateService>(pub Arc<T>);
+                    impl<
+                        T: NodeStateService,
+                    > tonic::server::UnaryService<super::GetBalanceProofRequest>
+                    for GetBalanceProofSvc<T> {
+                        type Response = super::GetBalanceProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetBalanceProofRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeStateService>::get_balance_proof(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBalanceProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/agglayer.node.v1.NodeStateService/GetNullifierProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNullifierProofSvc<T: NodeStateService>(pub Arc<T>);
+                    impl<
+                        T: NodeStateService,
+                    > tonic::server::UnaryService<super::GetNullifierProofRequest>
+                    for GetNullifierProofSvc<T> {
+                        type Response = super::GetNullifierProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetNullifierProofRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeStateService>::get_nullifier_proof(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetNullifierProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/agglayer.node.v1.NodeStateService/GetLocalExitTreeProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLocalExitTreeProofSvc<T: NodeStateService>(pub Arc<T>);
+                    impl<
+                        T: NodeStateService,
+                    > tonic::server::UnaryService<super::GetLocalExitTreeProofRequest>
+                    for GetLocalExitTreeProofSvc<T> {
+                        type Response = super::GetLocalExitTreeProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetLocalExitTreeProofRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeStateService>::get_local_exit_tree_proof(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLocalExitTreeProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
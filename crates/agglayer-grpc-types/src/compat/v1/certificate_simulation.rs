@@ -0,0 +1,33 @@
+use agglayer_types::{CertificateSimulation, SimulatedRoots, SimulationOutcome};
+
+use crate::node::{
+    types::v1,
+    v1::{simulate_certificate_response::Outcome, SimulateCertificateResponse},
+};
+
+impl From<SimulatedRoots> for v1::SimulatedRoots {
+    fn from(value: SimulatedRoots) -> Self {
+        v1::SimulatedRoots {
+            new_local_exit_root: Some(value.new_local_exit_root.into()),
+            new_let_leaf_count: value.new_let_leaf_count,
+            new_balance_root: Some(value.new_balance_root.into()),
+            new_nullifier_root: Some(value.new_nullifier_root.into()),
+            prev_pessimistic_root: Some(value.prev_pessimistic_root.into()),
+            new_pessimistic_root: Some(value.new_pessimistic_root.into()),
+        }
+    }
+}
+
+impl From<CertificateSimulation> for SimulateCertificateResponse {
+    fn from(value: CertificateSimulation) -> Self {
+        let outcome = match value.outcome {
+            SimulationOutcome::Success(roots) => Outcome::Roots(roots.into()),
+            SimulationOutcome::Failure(error) => Outcome::Error(error.into()),
+        };
+
+        SimulateCertificateResponse {
+            certificate_id: Some(value.certificate_id.into()),
+            outcome: Some(outcome),
+        }
+    }
+}
@@ -1,3 +1,6 @@
 mod certificate_submission_error_kind;
 mod get_certificate_header_error_kind;
 mod get_latest_certificate_header_error_kind;
+mod simulate_certificate_error_kind;
+mod state_proof_error_kinds;
+mod subscribe_certificate_status_error_kind;
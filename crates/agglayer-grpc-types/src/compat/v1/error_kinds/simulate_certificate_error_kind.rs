@@ -0,0 +1,12 @@
+use agglayer_interop::grpc::compat::v1::ErrorKind;
+
+use crate::node::v1::SimulateCertificateErrorKind;
+
+impl From<ErrorKind> for SimulateCertificateErrorKind {
+    fn from(value: ErrorKind) -> Self {
+        match value {
+            ErrorKind::InvalidData => SimulateCertificateErrorKind::InvalidData,
+            ErrorKind::MissingField => SimulateCertificateErrorKind::MissingField,
+        }
+    }
+}
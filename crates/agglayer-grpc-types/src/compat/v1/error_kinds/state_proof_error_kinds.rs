@@ -0,0 +1,26 @@
+use agglayer_interop::grpc::compat::v1::ErrorKind;
+
+use crate::node::v1::{
+    GetBalanceProofErrorKind, GetLocalExitTreeProofErrorKind, GetNullifierProofErrorKind,
+};
+
+macro_rules! impl_from_error_kind {
+    ($($kind:ident),* $(,)?) => {
+        $(
+            impl From<ErrorKind> for $kind {
+                fn from(value: ErrorKind) -> Self {
+                    match value {
+                        ErrorKind::MissingField => $kind::MissingField,
+                        ErrorKind::InvalidData => $kind::InvalidData,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_error_kind!(
+    GetBalanceProofErrorKind,
+    GetNullifierProofErrorKind,
+    GetLocalExitTreeProofErrorKind,
+);
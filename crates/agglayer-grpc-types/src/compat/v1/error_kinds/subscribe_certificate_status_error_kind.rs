@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use agglayer_interop::grpc::compat::v1::ErrorKind;
+
+use crate::node::v1::SubscribeCertificateStatusErrorKind;
+
+impl Display for SubscribeCertificateStatusErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubscribeCertificateStatusErrorKind::Unspecified => write!(f, "Unspecified error"),
+            SubscribeCertificateStatusErrorKind::MissingField => write!(f, "Missing field"),
+            SubscribeCertificateStatusErrorKind::InvalidData => write!(f, "Invalid data"),
+        }
+    }
+}
+
+impl From<SubscribeCertificateStatusErrorKind> for String {
+    fn from(kind: SubscribeCertificateStatusErrorKind) -> String {
+        kind.to_string()
+    }
+}
+
+impl From<ErrorKind> for SubscribeCertificateStatusErrorKind {
+    fn from(value: ErrorKind) -> Self {
+        match value {
+            ErrorKind::MissingField => SubscribeCertificateStatusErrorKind::MissingField,
+            ErrorKind::InvalidData => SubscribeCertificateStatusErrorKind::InvalidData,
+        }
+    }
+}
@@ -12,9 +12,11 @@ macro_rules! required_field {
 mod certificate;
 mod certificate_header;
 mod certificate_id;
+mod certificate_simulation;
 mod epoch_configuration;
 mod error_kinds;
 mod network_info;
+mod state_proof;
 
 pub use agglayer_interop::grpc::compat::v1::{Error, ErrorKind};
 pub use certificate::CertificateConversionError;
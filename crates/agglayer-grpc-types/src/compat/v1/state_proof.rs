@@ -0,0 +1,63 @@
+use agglayer_interop::grpc::v1::FixedBytes32;
+use agglayer_types::{
+    BalanceProof, Digest, LocalExitTreeProof, NullifierProof, NullifierProofPath,
+    SettledStateAnchor,
+};
+
+use crate::node::{types::v1, v1 as service};
+
+fn siblings<'a>(siblings: impl IntoIterator<Item = &'a Digest>) -> Vec<FixedBytes32> {
+    siblings
+        .into_iter()
+        .copied()
+        .map(FixedBytes32::from)
+        .collect()
+}
+
+impl From<SettledStateAnchor> for v1::SettledStateAnchor {
+    fn from(value: SettledStateAnchor) -> Self {
+        v1::SettledStateAnchor {
+            certificate_id: Some(value.certificate_id.into()),
+            height: value.height.as_u64(),
+            local_exit_root: Some(value.local_exit_root.into()),
+            let_leaf_count: value.let_leaf_count,
+            balance_root: Some(value.balance_root.into()),
+            nullifier_root: Some(value.nullifier_root.into()),
+        }
+    }
+}
+
+impl From<BalanceProof> for service::GetBalanceProofResponse {
+    fn from(value: BalanceProof) -> Self {
+        service::GetBalanceProofResponse {
+            anchor: Some(value.anchor.into()),
+            balance: Some(Digest(value.balance.to_be_bytes()).into()),
+            siblings: siblings(&value.path.siblings),
+        }
+    }
+}
+
+impl From<NullifierProof> for service::GetNullifierProofResponse {
+    fn from(value: NullifierProof) -> Self {
+        let (claimed, siblings) = match &value.path {
+            NullifierProofPath::Inclusion(path) => (true, siblings(&path.siblings)),
+            NullifierProofPath::NonInclusion(path) => (false, siblings(&path.siblings)),
+        };
+
+        service::GetNullifierProofResponse {
+            anchor: Some(value.anchor.into()),
+            claimed,
+            siblings,
+        }
+    }
+}
+
+impl From<LocalExitTreeProof> for service::GetLocalExitTreeProofResponse {
+    fn from(value: LocalExitTreeProof) -> Self {
+        service::GetLocalExitTreeProofResponse {
+            anchor: Some(value.anchor.into()),
+            leaf: Some(value.leaf.into()),
+            siblings: siblings(&value.path.siblings),
+        }
+    }
+}
@@ -1554,3 +1554,374 @@ impl<'de> serde::Deserialize<'de> for SettledClaim {
         deserializer.deserialize_struct("agglayer.node.types.v1.SettledClaim", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SettledStateAnchor {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.let_leaf_count != 0 {
+            len += 1;
+        }
+        if self.balance_root.is_some() {
+            len += 1;
+        }
+        if self.nullifier_root.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.types.v1.SettledStateAnchor", len)?;
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.local_exit_root.as_ref() {
+            struct_ser.serialize_field("localExitRoot", v)?;
+        }
+        if self.let_leaf_count != 0 {
+            struct_ser.serialize_field("letLeafCount", &self.let_leaf_count)?;
+        }
+        if let Some(v) = self.balance_root.as_ref() {
+            struct_ser.serialize_field("balanceRoot", v)?;
+        }
+        if let Some(v) = self.nullifier_root.as_ref() {
+            struct_ser.serialize_field("nullifierRoot", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SettledStateAnchor {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_id",
+            "certificateId",
+            "height",
+            "local_exit_root",
+            "localExitRoot",
+            "let_leaf_count",
+            "letLeafCount",
+            "balance_root",
+            "balanceRoot",
+            "nullifier_root",
+            "nullifierRoot",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateId,
+            Height,
+            LocalExitRoot,
+            LetLeafCount,
+            BalanceRoot,
+            NullifierRoot,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            "height" => Ok(GeneratedField::Height),
+                            "localExitRoot" | "local_exit_root" => Ok(GeneratedField::LocalExitRoot),
+                            "letLeafCount" | "let_leaf_count" => Ok(GeneratedField::LetLeafCount),
+                            "balanceRoot" | "balance_root" => Ok(GeneratedField::BalanceRoot),
+                            "nullifierRoot" | "nullifier_root" => Ok(GeneratedField::NullifierRoot),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SettledStateAnchor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.types.v1.SettledStateAnchor")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SettledStateAnchor, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_id__ = None;
+                let mut height__ = None;
+                let mut local_exit_root__ = None;
+                let mut let_leaf_count__ = None;
+                let mut balance_root__ = None;
+                let mut nullifier_root__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LocalExitRoot => {
+                            if local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("localExitRoot"));
+                            }
+                            local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::LetLeafCount => {
+                            if let_leaf_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("letLeafCount"));
+                            }
+                            let_leaf_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BalanceRoot => {
+                            if balance_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceRoot"));
+                            }
+                            balance_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NullifierRoot => {
+                            if nullifier_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifierRoot"));
+                            }
+                            nullifier_root__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SettledStateAnchor {
+                    certificate_id: certificate_id__,
+                    height: height__.unwrap_or_default(),
+                    local_exit_root: local_exit_root__,
+                    let_leaf_count: let_leaf_count__.unwrap_or_default(),
+                    balance_root: balance_root__,
+                    nullifier_root: nullifier_root__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.types.v1.SettledStateAnchor", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulatedRoots {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.new_let_leaf_count != 0 {
+            len += 1;
+        }
+        if self.new_balance_root.is_some() {
+            len += 1;
+        }
+        if self.new_nullifier_root.is_some() {
+            len += 1;
+        }
+        if self.prev_pessimistic_root.is_some() {
+            len += 1;
+        }
+        if self.new_pessimistic_root.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.types.v1.SimulatedRoots", len)?;
+        if let Some(v) = self.new_local_exit_root.as_ref() {
+            struct_ser.serialize_field("newLocalExitRoot", v)?;
+        }
+        if self.new_let_leaf_count != 0 {
+            struct_ser.serialize_field("newLetLeafCount", &self.new_let_leaf_count)?;
+        }
+        if let Some(v) = self.new_balance_root.as_ref() {
+            struct_ser.serialize_field("newBalanceRoot", v)?;
+        }
+        if let Some(v) = self.new_nullifier_root.as_ref() {
+            struct_ser.serialize_field("newNullifierRoot", v)?;
+        }
+        if let Some(v) = self.prev_pessimistic_root.as_ref() {
+            struct_ser.serialize_field("prevPessimisticRoot", v)?;
+        }
+        if let Some(v) = self.new_pessimistic_root.as_ref() {
+            struct_ser.serialize_field("newPessimisticRoot", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulatedRoots {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_local_exit_root",
+            "newLocalExitRoot",
+            "new_let_leaf_count",
+            "newLetLeafCount",
+            "new_balance_root",
+            "newBalanceRoot",
+            "new_nullifier_root",
+            "newNullifierRoot",
+            "prev_pessimistic_root",
+            "prevPessimisticRoot",
+            "new_pessimistic_root",
+            "newPessimisticRoot",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewLocalExitRoot,
+            NewLetLeafCount,
+            NewBalanceRoot,
+            NewNullifierRoot,
+            PrevPessimisticRoot,
+            NewPessimisticRoot,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newLocalExitRoot" | "new_local_exit_root" => Ok(GeneratedField::NewLocalExitRoot),
+                            "newLetLeafCount" | "new_let_leaf_count" => Ok(GeneratedField::NewLetLeafCount),
+                            "newBalanceRoot" | "new_balance_root" => Ok(GeneratedField::NewBalanceRoot),
+                            "newNullifierRoot" | "new_nullifier_root" => Ok(GeneratedField::NewNullifierRoot),
+                            "prevPessimisticRoot" | "prev_pessimistic_root" => Ok(GeneratedField::PrevPessimisticRoot),
+                            "newPessimisticRoot" | "new_pessimistic_root" => Ok(GeneratedField::NewPessimisticRoot),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulatedRoots;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.types.v1.SimulatedRoots")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulatedRoots, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_local_exit_root__ = None;
+                let mut new_let_leaf_count__ = None;
+                let mut new_balance_root__ = None;
+                let mut new_nullifier_root__ = None;
+                let mut prev_pessimistic_root__ = None;
+                let mut new_pessimistic_root__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewLocalExitRoot => {
+                            if new_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newLocalExitRoot"));
+                            }
+                            new_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewLetLeafCount => {
+                            if new_let_leaf_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newLetLeafCount"));
+                            }
+                            new_let_leaf_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NewBalanceRoot => {
+                            if new_balance_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalanceRoot"));
+                            }
+                            new_balance_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewNullifierRoot => {
+                            if new_nullifier_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newNullifierRoot"));
+                            }
+                            new_nullifier_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevPessimisticRoot => {
+                            if prev_pessimistic_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevPessimisticRoot"));
+                            }
+                            prev_pessimistic_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewPessimisticRoot => {
+                            if new_pessimistic_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newPessimisticRoot"));
+                            }
+                            new_pessimistic_root__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SimulatedRoots {
+                    new_local_exit_root: new_local_exit_root__,
+                    new_let_leaf_count: new_let_leaf_count__.unwrap_or_default(),
+                    new_balance_root: new_balance_root__,
+                    new_nullifier_root: new_nullifier_root__,
+                    prev_pessimistic_root: prev_pessimistic_root__,
+                    new_pessimistic_root: new_pessimistic_root__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.types.v1.SimulatedRoots", FIELDS, GeneratedVisitor)
+    }
+}
@@ -14,6 +14,36 @@ pub struct SubmitCertificateResponse {
     #[prost(message, optional, tag="1")]
     pub certificate_id: ::core::option::Option<super::types::v1::CertificateId>,
 }
+/// Type used to simulate a certificate.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateCertificateRequest {
+    /// The certificate to simulate.
+    #[prost(message, optional, tag="1")]
+    pub certificate: ::core::option::Option<super::types::v1::Certificate>,
+}
+/// Type used as response to a certificate simulation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateCertificateResponse {
+    /// The certificate id of the simulated certificate.
+    #[prost(message, optional, tag="1")]
+    pub certificate_id: ::core::option::Option<super::types::v1::CertificateId>,
+    /// The outcome of the simulation.
+    #[prost(oneof="simulate_certificate_response::Outcome", tags="2, 3")]
+    pub outcome: ::core::option::Option<simulate_certificate_response::Outcome>,
+}
+/// Nested message and enum types in `SimulateCertificateResponse`.
+pub mod simulate_certificate_response {
+    /// The outcome of the simulation.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        /// The roots the certificate leads to, if it would be proven.
+        #[prost(message, tag="2")]
+        Roots(super::super::types::v1::SimulatedRoots),
+        /// The error the certificate would be put in error with.
+        #[prost(message, tag="3")]
+        Error(super::super::types::v1::CertificateStatusError),
+    }
+}
 /// The kind of error that occurred and that are reported by the service.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -30,6 +60,9 @@ pub enum SubmitCertificateErrorKind {
     UnableToReplacePendingCertificate = 4,
     /// The certificate carries a proof version that gRPC ingress does not accept.
     UnsupportedProofVersion = 5,
+    /// The network exceeded its certificate submission rate or its cap of
+    /// pending certificates.
+    RateLimited = 6,
 }
 impl SubmitCertificateErrorKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -44,6 +77,7 @@ impl SubmitCertificateErrorKind {
             Self::SignatureVerification => "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION",
             Self::UnableToReplacePendingCertificate => "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE",
             Self::UnsupportedProofVersion => "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            Self::RateLimited => "SUBMIT_CERTIFICATE_ERROR_KIND_RATE_LIMITED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -55,6 +89,56 @@ impl SubmitCertificateErrorKind {
             "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION" => Some(Self::SignatureVerification),
             "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE" => Some(Self::UnableToReplacePendingCertificate),
             "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION" => Some(Self::UnsupportedProofVersion),
+            "SUBMIT_CERTIFICATE_ERROR_KIND_RATE_LIMITED" => Some(Self::RateLimited),
+            _ => None,
+        }
+    }
+}
+/// The kind of error that occurred and that are reported by the service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SimulateCertificateErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Missing field.
+    MissingField = 1,
+    /// Invalid data.
+    InvalidData = 2,
+    /// The certificate carries a proof version that gRPC ingress does not accept.
+    UnsupportedProofVersion = 3,
+    /// The certificate height does not follow the settled state of the network.
+    UnexpectedHeight = 4,
+    /// The settled state of the network is being updated, retry later.
+    Unavailable = 5,
+    /// Internal service error.
+    InternalError = 6,
+}
+impl SimulateCertificateErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SIMULATE_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "SIMULATE_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "SIMULATE_CERTIFICATE_ERROR_KIND_INVALID_DATA",
+            Self::UnsupportedProofVersion => "SIMULATE_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            Self::UnexpectedHeight => "SIMULATE_CERTIFICATE_ERROR_KIND_UNEXPECTED_HEIGHT",
+            Self::Unavailable => "SIMULATE_CERTIFICATE_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "SIMULATE_CERTIFICATE_ERROR_KIND_INTERNAL_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_MISSING_FIELD" => Some(Self::MissingField),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION" => Some(Self::UnsupportedProofVersion),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNEXPECTED_HEIGHT" => Some(Self::UnexpectedHeight),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNAVAILABLE" => Some(Self::Unavailable),
+            "SIMULATE_CERTIFICATE_ERROR_KIND_INTERNAL_ERROR" => Some(Self::InternalError),
             _ => None,
         }
     }
@@ -146,6 +230,118 @@ pub struct GetNetworkInfoResponse {
     #[prost(message, optional, tag="1")]
     pub network_info: ::core::option::Option<super::types::v1::NetworkInfo>,
 }
+/// Request to subscribe to certificate status transitions.
+///
+/// When no filter is set, every transition of every network is streamed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeCertificateStatusRequest {
+    /// Only stream transitions of certificates from this network.
+    #[prost(uint32, optional, tag="1")]
+    pub network_id: ::core::option::Option<u32>,
+    /// Only stream transitions of this certificate.
+    #[prost(message, optional, tag="2")]
+    pub certificate_id: ::core::option::Option<super::types::v1::CertificateId>,
+}
+/// A certificate status transition.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeCertificateStatusResponse {
+    /// The certificate header, as stored after the transition.
+    #[prost(message, optional, tag="1")]
+    pub certificate_header: ::core::option::Option<super::types::v1::CertificateHeader>,
+}
+/// Request to list the settled certificate headers of a network.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListCertificateHeadersRequest {
+    /// The network identifier.
+    #[prost(uint32, tag="1")]
+    pub network_id: u32,
+    /// The height from which the listing starts, inclusive.
+    #[prost(uint64, tag="2")]
+    pub from_height: u64,
+    /// The maximum number of certificate headers to return, which must be
+    /// positive.
+    #[prost(uint32, tag="3")]
+    pub limit: u32,
+    /// The direction in which heights are walked.
+    #[prost(enumeration="ListDirection", tag="4")]
+    pub direction: i32,
+}
+/// Response to the certificate header listing request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCertificateHeadersResponse {
+    /// The settled certificate headers, in the requested order.
+    #[prost(message, repeated, tag="1")]
+    pub certificate_headers: ::prost::alloc::vec::Vec<super::types::v1::CertificateHeader>,
+}
+/// Request to prove the balance of a token.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBalanceProofRequest {
+    /// The network identifier.
+    #[prost(uint32, tag="1")]
+    pub network_id: u32,
+    /// The token whose balance is proven.
+    #[prost(message, optional, tag="2")]
+    pub token_info: ::core::option::Option<::agglayer_interop::grpc::v1::TokenInfo>,
+}
+/// Proof of the balance of a token in the local balance tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBalanceProofResponse {
+    /// The settled state the proof is issued against.
+    #[prost(message, optional, tag="1")]
+    pub anchor: ::core::option::Option<super::types::v1::SettledStateAnchor>,
+    /// The balance, as the big-endian leaf value. Zero if the token has no balance.
+    #[prost(message, optional, tag="2")]
+    pub balance: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// The siblings of the path from the leaf to the balance root.
+    #[prost(message, repeated, tag="3")]
+    pub siblings: ::prost::alloc::vec::Vec<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// Request to prove whether a global index is claimed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNullifierProofRequest {
+    /// The network identifier.
+    #[prost(uint32, tag="1")]
+    pub network_id: u32,
+    /// The global index, as a big-endian 256-bit integer.
+    #[prost(message, optional, tag="2")]
+    pub global_index: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// Proof that a global index is claimed or not in the nullifier tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNullifierProofResponse {
+    /// The settled state the proof is issued against.
+    #[prost(message, optional, tag="1")]
+    pub anchor: ::core::option::Option<super::types::v1::SettledStateAnchor>,
+    /// Whether the global index is claimed, i.e. whether this is an inclusion proof.
+    #[prost(bool, tag="2")]
+    pub claimed: bool,
+    /// The siblings of the path to the nullifier root.
+    #[prost(message, repeated, tag="3")]
+    pub siblings: ::prost::alloc::vec::Vec<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// Request to prove a leaf of the local exit tree.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetLocalExitTreeProofRequest {
+    /// The network identifier.
+    #[prost(uint32, tag="1")]
+    pub network_id: u32,
+    /// The index of the leaf.
+    #[prost(uint32, tag="2")]
+    pub leaf_index: u32,
+}
+/// Proof of a leaf of the local exit tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLocalExitTreeProofResponse {
+    /// The settled state the proof is issued against.
+    #[prost(message, optional, tag="1")]
+    pub anchor: ::core::option::Option<super::types::v1::SettledStateAnchor>,
+    /// The hash of the bridge exit at the requested index.
+    #[prost(message, optional, tag="2")]
+    pub leaf: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// The siblings of the path from the leaf to the local exit root.
+    #[prost(message, repeated, tag="3")]
+    pub siblings: ::prost::alloc::vec::Vec<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
 /// Error kind for GetCertificateHeader RPC.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -261,6 +457,207 @@ impl GetNetworkInfoErrorKind {
         }
     }
 }
+/// Error kind for SubscribeCertificateStatus RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SubscribeCertificateStatusErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Missing field.
+    MissingField = 1,
+    /// Invalid data.
+    InvalidData = 2,
+}
+impl SubscribeCertificateStatusErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_INVALID_DATA",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_MISSING_FIELD" => Some(Self::MissingField),
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            _ => None,
+        }
+    }
+}
+/// Error kind for ListCertificateHeaders RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ListCertificateHeadersErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Invalid data.
+    InvalidData = 1,
+    /// Internal service error.
+    InternalError = 2,
+}
+impl ListCertificateHeadersErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_UNSPECIFIED",
+            Self::InvalidData => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INVALID_DATA",
+            Self::InternalError => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INTERNAL_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INTERNAL_ERROR" => Some(Self::InternalError),
+            _ => None,
+        }
+    }
+}
+/// Error kind for GetBalanceProof RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GetBalanceProofErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Missing field.
+    MissingField = 1,
+    /// Invalid data.
+    InvalidData = 2,
+    /// No settled state for the network.
+    NotFound = 3,
+    /// The settled state is being updated, retry later.
+    Unavailable = 4,
+    /// Internal service error.
+    InternalError = 5,
+}
+impl GetBalanceProofErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "GET_BALANCE_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_BALANCE_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_BALANCE_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_BALANCE_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_BALANCE_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_BALANCE_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GET_BALANCE_PROOF_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "GET_BALANCE_PROOF_ERROR_KIND_MISSING_FIELD" => Some(Self::MissingField),
+            "GET_BALANCE_PROOF_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            "GET_BALANCE_PROOF_ERROR_KIND_NOT_FOUND" => Some(Self::NotFound),
+            "GET_BALANCE_PROOF_ERROR_KIND_UNAVAILABLE" => Some(Self::Unavailable),
+            "GET_BALANCE_PROOF_ERROR_KIND_INTERNAL_ERROR" => Some(Self::InternalError),
+            _ => None,
+        }
+    }
+}
+/// Error kind for GetNullifierProof RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GetNullifierProofErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Missing field.
+    MissingField = 1,
+    /// Invalid data.
+    InvalidData = 2,
+    /// No settled state for the network.
+    NotFound = 3,
+    /// The settled state is being updated, retry later.
+    Unavailable = 4,
+    /// Internal service error.
+    InternalError = 5,
+}
+impl GetNullifierProofErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "GET_NULLIFIER_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_NULLIFIER_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_NULLIFIER_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_NULLIFIER_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_NULLIFIER_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_NULLIFIER_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GET_NULLIFIER_PROOF_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "GET_NULLIFIER_PROOF_ERROR_KIND_MISSING_FIELD" => Some(Self::MissingField),
+            "GET_NULLIFIER_PROOF_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            "GET_NULLIFIER_PROOF_ERROR_KIND_NOT_FOUND" => Some(Self::NotFound),
+            "GET_NULLIFIER_PROOF_ERROR_KIND_UNAVAILABLE" => Some(Self::Unavailable),
+            "GET_NULLIFIER_PROOF_ERROR_KIND_INTERNAL_ERROR" => Some(Self::InternalError),
+            _ => None,
+        }
+    }
+}
+/// Error kind for GetLocalExitTreeProof RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GetLocalExitTreeProofErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// Missing field.
+    MissingField = 1,
+    /// Invalid data.
+    InvalidData = 2,
+    /// No settled state for the network.
+    NotFound = 3,
+    /// The settled state is being updated, retry later.
+    Unavailable = 4,
+    /// Internal service error.
+    InternalError = 5,
+}
+impl GetLocalExitTreeProofErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_MISSING_FIELD" => Some(Self::MissingField),
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INVALID_DATA" => Some(Self::InvalidData),
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_NOT_FOUND" => Some(Self::NotFound),
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNAVAILABLE" => Some(Self::Unavailable),
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INTERNAL_ERROR" => Some(Self::InternalError),
+            _ => None,
+        }
+    }
+}
 /// The type of latest certificate we want to get.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -294,351 +691,754 @@ impl LatestCertificateRequestType {
         }
     }
 }
+/// The direction in which certificate headers are listed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ListDirection {
+    /// Default value, treated as ascending.
+    Unspecified = 0,
+    /// Increasing heights.
+    Ascending = 1,
+    /// Decreasing heights.
+    Descending = 2,
+}
+impl ListDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "LIST_DIRECTION_UNSPECIFIED",
+            Self::Ascending => "LIST_DIRECTION_ASCENDING",
+            Self::Descending => "LIST_DIRECTION_DESCENDING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LIST_DIRECTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "LIST_DIRECTION_ASCENDING" => Some(Self::Ascending),
+            "LIST_DIRECTION_DESCENDING" => Some(Self::Descending),
+            _ => None,
+        }
+    }
+}
 /// Encoded file descriptor set for the `agglayer.node.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xe4, 0x10, 0x0a, 0x2d, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f,
+    0x0a, 0xc7, 0x25, 0x0a, 0x2d, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f,
     0x64, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
     0x65, 0x5f, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f,
     0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64,
     0x65, 0x2e, 0x76, 0x31, 0x1a, 0x28, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e,
     0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72,
-    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x2b,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x2f,
     0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79,
     0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
-    0x74, 0x65, 0x5f, 0x69, 0x64, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x61, 0x0a, 0x18, 0x53,
-    0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x45, 0x0a, 0x0b, 0x63, 0x65, 0x72, 0x74, 0x69,
-    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61,
+    0x74, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a,
+    0x2b, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x33, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x5f, 0x73, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x22, 0x61, 0x0a, 0x18, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x45, 0x0a,
+    0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
+    0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x22, 0x69, 0x0a, 0x19, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x12, 0x4c, 0x0a, 0x0e, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64,
+    0x52, 0x0d, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x22,
+    0x63, 0x0a, 0x1a, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x45, 0x0a,
+    0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
+    0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x22, 0xfe, 0x01, 0x0a, 0x1b, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74,
+    0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0e, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61,
     0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70,
     0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
-    0x65, 0x52, 0x0b, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x22, 0x69,
-    0x0a, 0x19, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4c, 0x0a, 0x0e, 0x63,
-    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
-    0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72,
-    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x52, 0x0d, 0x63, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x2a, 0xec, 0x02, 0x0a, 0x1a, 0x53, 0x75,
-    0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x45,
-    0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2d, 0x0a, 0x29, 0x53, 0x55, 0x42, 0x4d,
-    0x49, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45,
-    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
-    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x2f, 0x0a, 0x2b, 0x53, 0x55, 0x42, 0x4d, 0x49,
+    0x65, 0x49, 0x64, 0x52, 0x0d, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x49, 0x64, 0x12, 0x3e, 0x0a, 0x05, 0x72, 0x6f, 0x6f, 0x74, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64,
+    0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x69, 0x6d, 0x75, 0x6c,
+    0x61, 0x74, 0x65, 0x64, 0x52, 0x6f, 0x6f, 0x74, 0x73, 0x48, 0x00, 0x52, 0x05, 0x72, 0x6f, 0x6f,
+    0x74, 0x73, 0x12, 0x46, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64,
+    0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x45, 0x72, 0x72, 0x6f,
+    0x72, 0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x09, 0x0a, 0x07, 0x6f, 0x75,
+    0x74, 0x63, 0x6f, 0x6d, 0x65, 0x2a, 0x9c, 0x03, 0x0a, 0x1a, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74,
+    0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x45, 0x72, 0x72, 0x6f, 0x72,
+    0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2d, 0x0a, 0x29, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43,
+    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45,
+    0x44, 0x10, 0x00, 0x12, 0x2f, 0x0a, 0x2b, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43, 0x45,
+    0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45,
+    0x4c, 0x44, 0x10, 0x01, 0x12, 0x2e, 0x0a, 0x2a, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43,
+    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41,
+    0x54, 0x41, 0x10, 0x02, 0x12, 0x38, 0x0a, 0x34, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43,
+    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x49, 0x47, 0x4e, 0x41, 0x54, 0x55, 0x52, 0x45, 0x5f,
+    0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x03, 0x12, 0x47,
+    0x0a, 0x43, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49,
+    0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x55, 0x4e, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x54, 0x4f, 0x5f, 0x52, 0x45, 0x50, 0x4c, 0x41, 0x43,
+    0x45, 0x5f, 0x50, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46,
+    0x49, 0x43, 0x41, 0x54, 0x45, 0x10, 0x04, 0x12, 0x3b, 0x0a, 0x37, 0x53, 0x55, 0x42, 0x4d, 0x49,
     0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52,
-    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e, 0x47,
-    0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x2e, 0x0a, 0x2a, 0x53, 0x55, 0x42, 0x4d,
-    0x49, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45,
-    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49,
-    0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x38, 0x0a, 0x34, 0x53, 0x55, 0x42, 0x4d,
-    0x49, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45,
-    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x49, 0x47, 0x4e, 0x41, 0x54,
-    0x55, 0x52, 0x45, 0x5f, 0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x49, 0x4f, 0x4e,
-    0x10, 0x03, 0x12, 0x47, 0x0a, 0x43, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43, 0x45, 0x52,
-    0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b,
-    0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x54, 0x4f, 0x5f, 0x52, 0x45,
-    0x50, 0x4c, 0x41, 0x43, 0x45, 0x5f, 0x50, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47, 0x5f, 0x43, 0x45,
-    0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x10, 0x04, 0x12, 0x3b, 0x0a, 0x37, 0x53,
-    0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54,
-    0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53,
-    0x55, 0x50, 0x50, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56,
-    0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x10, 0x05, 0x32, 0x8c, 0x01, 0x0a, 0x1c, 0x43, 0x65, 0x72,
-    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69,
-    0x6f, 0x6e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x6c, 0x0a, 0x11, 0x53, 0x75, 0x62,
-    0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x12, 0x2a,
-    0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76,
-    0x31, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x61, 0x67, 0x67,
-    0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75,
-    0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x94, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e,
-    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31,
-    0x42, 0x1a, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x75, 0x62,
-    0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02,
-    0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
-    0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79,
-    0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41, 0x67, 0x67,
-    0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50,
-    0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c,
-    0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x4e, 0x6f, 0x64, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xde,
-    0x08, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x2c, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03,
-    0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a,
-    0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x05, 0x00, 0x35, 0x0a, 0x45, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x08, 0x00, 0x0b, 0x01, 0x1a,
-    0x39, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x75,
-    0x62, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
-    0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x6c, 0x61,
-    0x79, 0x65, 0x72, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00,
-    0x01, 0x12, 0x03, 0x08, 0x08, 0x24, 0x0a, 0x33, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x0a, 0x02, 0x56, 0x1a, 0x26, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65,
-    0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x20, 0x61, 0x20, 0x63, 0x65,
-    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x06, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x00, 0x02, 0x12, 0x03, 0x0a, 0x18, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x0a, 0x3b, 0x54, 0x0a, 0x30, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0e, 0x00, 0x11,
-    0x01, 0x1a, 0x24, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f,
-    0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66,
-    0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x0e, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x10, 0x02,
-    0x16, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x27, 0x1a, 0x1c,
+    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x55, 0x50, 0x50, 0x4f,
+    0x52, 0x54, 0x45, 0x44, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56, 0x45, 0x52, 0x53, 0x49,
+    0x4f, 0x4e, 0x10, 0x05, 0x12, 0x2e, 0x0a, 0x2a, 0x53, 0x55, 0x42, 0x4d, 0x49, 0x54, 0x5f, 0x43,
+    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x52, 0x41, 0x54, 0x45, 0x5f, 0x4c, 0x49, 0x4d, 0x49, 0x54,
+    0x45, 0x44, 0x10, 0x06, 0x2a, 0x8f, 0x03, 0x0a, 0x1c, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74,
+    0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x45, 0x72, 0x72, 0x6f,
+    0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2f, 0x0a, 0x2b, 0x53, 0x49, 0x4d, 0x55, 0x4c, 0x41, 0x54,
+    0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52,
+    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x31, 0x0a, 0x2d, 0x53, 0x49, 0x4d, 0x55, 0x4c, 0x41,
+    0x54, 0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45,
+    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e,
+    0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x30, 0x0a, 0x2c, 0x53, 0x49, 0x4d,
+    0x55, 0x4c, 0x41, 0x54, 0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54,
+    0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56,
+    0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x3d, 0x0a, 0x39, 0x53,
+    0x49, 0x4d, 0x55, 0x4c, 0x41, 0x54, 0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43,
+    0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55,
+    0x4e, 0x53, 0x55, 0x50, 0x50, 0x4f, 0x52, 0x54, 0x45, 0x44, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
+    0x5f, 0x56, 0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x10, 0x03, 0x12, 0x35, 0x0a, 0x31, 0x53, 0x49,
+    0x4d, 0x55, 0x4c, 0x41, 0x54, 0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41,
+    0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e,
+    0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x45, 0x44, 0x5f, 0x48, 0x45, 0x49, 0x47, 0x48, 0x54, 0x10,
+    0x04, 0x12, 0x2f, 0x0a, 0x2b, 0x53, 0x49, 0x4d, 0x55, 0x4c, 0x41, 0x54, 0x45, 0x5f, 0x43, 0x45,
+    0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41, 0x56, 0x41, 0x49, 0x4c, 0x41, 0x42, 0x4c, 0x45,
+    0x10, 0x05, 0x12, 0x32, 0x0a, 0x2e, 0x53, 0x49, 0x4d, 0x55, 0x4c, 0x41, 0x54, 0x45, 0x5f, 0x43,
+    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x5f, 0x45,
+    0x52, 0x52, 0x4f, 0x52, 0x10, 0x06, 0x32, 0x80, 0x02, 0x0a, 0x1c, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x6c, 0x0a, 0x11, 0x53, 0x75, 0x62, 0x6d, 0x69,
+    0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x12, 0x2a, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2b, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75, 0x62, 0x6d,
+    0x69, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x72, 0x0a, 0x13, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74,
+    0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x12, 0x2c, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2d, 0x2e, 0x61, 0x67, 0x67,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x69,
+    0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x94, 0x01, 0x0a, 0x14, 0x63, 0x6f,
+    0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e,
+    0x76, 0x31, 0x42, 0x1a, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53,
+    0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01,
+    0xa2, 0x02, 0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0x5c,
+    0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x4e, 0x6f, 0x64, 0x65, 0x3a, 0x3a, 0x56, 0x31,
+    0x4a, 0xbf, 0x15, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x62, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
+    0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a,
+    0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x05, 0x00, 0x39, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x00, 0x35,
+    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x3d, 0x0a, 0x45, 0x0a, 0x02, 0x06,
+    0x00, 0x12, 0x04, 0x0a, 0x00, 0x11, 0x01, 0x1a, 0x39, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x6f, 0x20,
+    0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x20, 0x6e, 0x6f, 0x64, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x24, 0x0a, 0x33,
+    0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x56, 0x1a, 0x26, 0x20, 0x4d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62,
+    0x6d, 0x69, 0x74, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x06,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x18, 0x30, 0x0a,
+    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x3b, 0x54, 0x0a, 0x75, 0x0a,
+    0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x5c, 0x1a, 0x68, 0x20, 0x4d, 0x65, 0x74,
+    0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x72, 0x79, 0x2d,
+    0x72, 0x75, 0x6e, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x69,
+    0x74, 0x73, 0x0a, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2c, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x6f, 0x75, 0x74, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10,
+    0x06, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x10, 0x1a, 0x34,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x3f, 0x5a, 0x0a, 0x30,
+    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x14, 0x00, 0x17, 0x01, 0x1a, 0x24, 0x20, 0x54, 0x79, 0x70,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74,
+    0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x14, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x02, 0x16, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x27, 0x1a, 0x1c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62,
+    0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x16, 0x17, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x25,
+    0x26, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1a, 0x00, 0x1d, 0x01, 0x1a, 0x34, 0x20,
+    0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x72, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1c, 0x02, 0x18, 0x0a, 0x3f, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x2c, 0x1a, 0x32, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x69, 0x64, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x19, 0x27, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x2a, 0x2b, 0x0a, 0x53, 0x0a, 0x02, 0x05, 0x00,
+    0x12, 0x04, 0x20, 0x00, 0x36, 0x01, 0x1a, 0x47, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e,
+    0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x20, 0x05, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x02, 0x2b, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x22, 0x02, 0x30, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
+    0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x22, 0x2e, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x25, 0x02, 0x2d, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x25, 0x02, 0x32, 0x1a, 0x10, 0x20, 0x4d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x25, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x28, 0x02, 0x2c, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x28, 0x02, 0x31,
+    0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x28, 0x2f, 0x30, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2b, 0x02, 0x36, 0x0a, 0x31, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x2b, 0x02, 0x3b, 0x1a, 0x24, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x2b, 0x39, 0x3a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x45, 0x0a, 0x35, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x4a, 0x1a, 0x28, 0x20, 0x55, 0x6e, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x70, 0x65,
+    0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x2e, 0x48,
+    0x49, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x31, 0x02, 0x39, 0x0a,
+    0x59, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x03, 0x31, 0x02, 0x3e, 0x1a, 0x4c, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x63,
+    0x61, 0x72, 0x72, 0x69, 0x65, 0x73, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x67, 0x52, 0x50, 0x43,
+    0x20, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x20, 0x6e, 0x6f,
+    0x74, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x05, 0x02, 0x12, 0x03, 0x31, 0x3c, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x35, 0x02, 0x2c, 0x0a, 0x68, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x06, 0x12, 0x03,
+    0x35, 0x02, 0x31, 0x1a, 0x5b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x20, 0x65, 0x78, 0x63, 0x65, 0x65, 0x64, 0x65, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x63,
+    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x63, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e,
+    0x67, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x35, 0x2f, 0x30, 0x0a, 0x32,
+    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x39, 0x00, 0x3c, 0x01, 0x1a, 0x26, 0x20, 0x54, 0x79, 0x70,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x69, 0x6d, 0x75, 0x6c, 0x61,
+    0x74, 0x65, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x39, 0x08, 0x22, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3b, 0x02, 0x16, 0x0a, 0x2b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x3b, 0x02, 0x27, 0x1a, 0x1e, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x73,
+    0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x3b, 0x17, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x3b, 0x25, 0x26, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x3f, 0x00, 0x4a,
+    0x01, 0x1a, 0x34, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x73,
+    0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x63,
+    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73, 0x69, 0x6d, 0x75, 0x6c,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
+    0x3f, 0x08, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x41, 0x02,
+    0x18, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x41, 0x02, 0x2c, 0x1a, 0x32,
     0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
-    0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x17, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x25, 0x26, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
-    0x14, 0x00, 0x17, 0x01, 0x1a, 0x34, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64,
-    0x20, 0x61, 0x73, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20,
-    0x61, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73, 0x75,
-    0x62, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
-    0x01, 0x12, 0x03, 0x14, 0x08, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12,
-    0x03, 0x16, 0x02, 0x18, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02,
-    0x2c, 0x1a, 0x32, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x75,
-    0x62, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x16, 0x19, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x2a,
-    0x2b, 0x0a, 0x53, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x1a, 0x00, 0x2c, 0x01, 0x1a, 0x47, 0x20,
+    0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x6d, 0x75, 0x6c,
+    0x61, 0x74, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x19, 0x27,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41, 0x2a, 0x2b, 0x0a, 0x2e,
+    0x0a, 0x04, 0x04, 0x03, 0x08, 0x00, 0x12, 0x04, 0x44, 0x02, 0x49, 0x03, 0x1a, 0x20, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x08, 0x00, 0x01, 0x12, 0x03, 0x44, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x46, 0x04, 0x1b, 0x0a, 0x49, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x01, 0x12, 0x03, 0x46, 0x04, 0x26, 0x1a, 0x3c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x6f,
+    0x6f, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x69, 0x66,
+    0x20, 0x69, 0x74, 0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62, 0x65, 0x20, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x46, 0x1c, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x46, 0x24,
+    0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x06, 0x12, 0x03, 0x48, 0x04, 0x23, 0x0a,
+    0x44, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x48, 0x04, 0x2e, 0x1a, 0x37, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x62,
+    0x65, 0x20, 0x70, 0x75, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x48, 0x24, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x48, 0x2c,
+    0x2d, 0x0a, 0x53, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x4d, 0x00, 0x62, 0x01, 0x1a, 0x47, 0x20,
     0x54, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f,
     0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20,
     0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70,
     0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72,
-    0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x1a,
-    0x05, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x2b,
-    0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x30, 0x1a, 0x14, 0x20,
+    0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x4d,
+    0x05, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x2d,
+    0x0a, 0x21, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x4f, 0x02, 0x32, 0x1a, 0x14, 0x20,
     0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f,
-    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1c, 0x2e,
-    0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x2d, 0x0a,
-    0x1d, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x32, 0x1a, 0x10, 0x20, 0x4d,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x4f, 0x30,
+    0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x52, 0x02, 0x2f, 0x0a,
+    0x1d, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x52, 0x02, 0x34, 0x1a, 0x10, 0x20, 0x4d,
     0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1f, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x22, 0x02, 0x2c, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x02, 0x12, 0x03, 0x22, 0x02, 0x31, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
-    0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
-    0x02, 0x12, 0x03, 0x22, 0x2f, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x25, 0x02, 0x36, 0x0a, 0x31, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x25, 0x02,
-    0x3b, 0x1a, 0x24, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
-    0x65, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66,
-    0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02,
-    0x12, 0x03, 0x25, 0x39, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
-    0x28, 0x02, 0x45, 0x0a, 0x35, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02, 0x4a,
-    0x1a, 0x28, 0x20, 0x55, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x70,
-    0x6c, 0x61, 0x63, 0x65, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x65, 0x72,
-    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x04, 0x02, 0x12, 0x03, 0x28, 0x48, 0x49, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x2b, 0x02, 0x39, 0x0a, 0x59, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x03,
-    0x2b, 0x02, 0x3e, 0x1a, 0x4c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66,
-    0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x73, 0x20, 0x61, 0x20,
-    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68,
-    0x61, 0x74, 0x20, 0x67, 0x52, 0x50, 0x43, 0x20, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x20,
-    0x64, 0x6f, 0x65, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x2b, 0x3c, 0x3d, 0x62,
-    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xea, 0x0b, 0x0a, 0x24, 0x61, 0x67, 0x67, 0x6c,
-    0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f, 0x6e,
-    0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-    0x12, 0x10, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e,
-    0x76, 0x31, 0x1a, 0x30, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64,
-    0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x65, 0x70, 0x6f, 0x63, 0x68,
-    0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x22, 0x1e, 0x0a, 0x1c, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68,
-    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x22, 0x7c, 0x0a, 0x1d, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68,
-    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5b, 0x0a, 0x13, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x5f, 0x63,
-    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
-    0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x70, 0x6f, 0x63,
-    0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12,
-    0x65, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x2a, 0x9b, 0x01, 0x0a, 0x1e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68, 0x43,
-    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x72, 0x72, 0x6f,
-    0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45, 0x54, 0x5f, 0x45, 0x50, 0x4f,
-    0x43, 0x48, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47, 0x55, 0x52, 0x41, 0x54, 0x49, 0x4f, 0x4e,
-    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
-    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x45, 0x0a, 0x41, 0x47, 0x45, 0x54,
-    0x5f, 0x45, 0x50, 0x4f, 0x43, 0x48, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47, 0x55, 0x52, 0x41,
-    0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
-    0x55, 0x4e, 0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x45, 0x44, 0x5f, 0x43, 0x4c, 0x4f, 0x43, 0x4b,
-    0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47, 0x55, 0x52, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x01,
-    0x32, 0x90, 0x01, 0x0a, 0x14, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15, 0x47, 0x65, 0x74,
-    0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x12, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
-    0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
-    0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x42, 0x8c, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6c,
-    0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x42, 0x12, 0x43, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f,
-    0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61,
-    0x79, 0x65, 0x72, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67,
-    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02,
-    0x1c, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56,
-    0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12,
-    0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x4e, 0x6f, 0x64, 0x65, 0x3a, 0x3a,
-    0x56, 0x31, 0x4a, 0x97, 0x06, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x1e, 0x01, 0x0a, 0x08, 0x0a,
-    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
-    0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x3a, 0x0a, 0x43, 0x0a, 0x02,
-    0x06, 0x00, 0x12, 0x04, 0x07, 0x00, 0x0a, 0x01, 0x1a, 0x37, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69,
-    0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x70, 0x6f, 0x63,
-    0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x1c, 0x0a, 0x42, 0x0a,
-    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x62, 0x1a, 0x35, 0x20, 0x4d, 0x65, 0x74,
-    0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x70, 0x6f, 0x63,
-    0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x06, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x1c, 0x38, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x43, 0x60, 0x0a, 0x62, 0x0a, 0x02, 0x05,
-    0x00, 0x12, 0x04, 0x0e, 0x00, 0x15, 0x01, 0x1a, 0x56, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6b, 0x69,
-    0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74,
-    0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68,
-    0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20,
-    0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a,
-    0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x05, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x02, 0x30, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x10, 0x02, 0x35, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
-    0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x10, 0x33, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x14, 0x02, 0x43, 0x0a, 0x77, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x14, 0x02, 0x48, 0x1a, 0x6a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x41, 0x67, 0x67, 0x4c,
-    0x61, 0x79, 0x65, 0x72, 0x20, 0x69, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69,
-    0x67, 0x75, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x42, 0x6c, 0x6f,
-    0x63, 0x6b, 0x43, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x75, 0x73, 0x20, 0x6e, 0x6f, 0x0a, 0x20,
-    0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x14, 0x46, 0x47, 0x0a,
-    0x3c, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x03, 0x18, 0x00, 0x27, 0x1a, 0x31, 0x20, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x18, 0x08, 0x24, 0x0a, 0x42, 0x0a, 0x02, 0x04, 0x01, 0x12,
-    0x04, 0x1b, 0x00, 0x1e, 0x01, 0x1a, 0x36, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20,
-    0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
-    0x03, 0x1d, 0x02, 0x36, 0x1a, 0x1a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x52, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x55, 0x02, 0x2e, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x01,
+    0x02, 0x02, 0x12, 0x03, 0x55, 0x02, 0x33, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+    0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x55, 0x31, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x58, 0x02, 0x3b, 0x0a, 0x59, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x58, 0x02,
+    0x40, 0x1a, 0x4c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x73, 0x20, 0x61, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x67, 0x52, 0x50, 0x43, 0x20, 0x69, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x20, 0x64, 0x6f,
+    0x65, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x58, 0x3e, 0x3f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x33, 0x0a, 0x57, 0x0a, 0x04, 0x05,
+    0x01, 0x02, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x38, 0x1a, 0x4a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63,
+    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x69, 0x67, 0x68,
+    0x74, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f,
+    0x77, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x02, 0x12, 0x03, 0x5b,
+    0x36, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5e, 0x02, 0x2d,
+    0x0a, 0x4e, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x32, 0x1a, 0x41, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b,
+    0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65,
+    0x64, 0x2c, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x72, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x02, 0x12, 0x03, 0x5e, 0x30, 0x31, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x61, 0x02, 0x30, 0x0a, 0x26, 0x0a, 0x04,
+    0x05, 0x01, 0x02, 0x06, 0x12, 0x03, 0x61, 0x02, 0x35, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65,
+    0x72, 0x6e, 0x61, 0x6c, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72,
+    0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x02, 0x12, 0x03, 0x61,
+    0x33, 0x34, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xea, 0x0b, 0x0a, 0x24, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x76, 0x31, 0x2f,
+    0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
+    0x64, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x30, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f,
+    0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x65, 0x70,
+    0x6f, 0x63, 0x68, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x1e, 0x0a, 0x1c, 0x47, 0x65, 0x74, 0x45, 0x70,
+    0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x7c, 0x0a, 0x1d, 0x47, 0x65, 0x74, 0x45, 0x70,
+    0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5b, 0x0a, 0x13, 0x65, 0x70, 0x6f, 0x63,
+    0x68, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x45,
+    0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x52, 0x12, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2a, 0x9b, 0x01, 0x0a, 0x1e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f,
+    0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45,
+    0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45, 0x54, 0x5f,
+    0x45, 0x50, 0x4f, 0x43, 0x48, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47, 0x55, 0x52, 0x41, 0x54,
+    0x49, 0x4f, 0x4e, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55,
+    0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x45, 0x0a, 0x41,
+    0x47, 0x45, 0x54, 0x5f, 0x45, 0x50, 0x4f, 0x43, 0x48, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47,
+    0x55, 0x52, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
+    0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x45, 0x58, 0x50, 0x45, 0x43, 0x54, 0x45, 0x44, 0x5f, 0x43, 0x4c,
+    0x4f, 0x43, 0x4b, 0x5f, 0x43, 0x4f, 0x4e, 0x46, 0x49, 0x47, 0x55, 0x52, 0x41, 0x54, 0x49, 0x4f,
+    0x4e, 0x10, 0x01, 0x32, 0x90, 0x01, 0x0a, 0x14, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15,
+    0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63,
+    0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x70, 0x6f, 0x63,
+    0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x8c, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x42,
+    0x12, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72,
+    0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02,
+    0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56,
+    0x31, 0xe2, 0x02, 0x1c, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64,
+    0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+    0xea, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x4e, 0x6f, 0x64,
+    0x65, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0x97, 0x06, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x1e, 0x01,
+    0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12,
+    0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x3a, 0x0a,
+    0x43, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x07, 0x00, 0x0a, 0x01, 0x1a, 0x37, 0x20, 0x53, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65,
+    0x70, 0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x1c,
+    0x0a, 0x42, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x62, 0x1a, 0x35, 0x20,
+    0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67,
+    0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65,
+    0x70, 0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09,
+    0x06, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x1c, 0x38,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x43, 0x60, 0x0a, 0x62,
+    0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x0e, 0x00, 0x15, 0x01, 0x1a, 0x56, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74,
+    0x68, 0x61, 0x74, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74,
+    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x05, 0x23, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x02, 0x30, 0x0a, 0x21, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x35, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70,
+    0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x10, 0x33, 0x34, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x14, 0x02, 0x43, 0x0a, 0x77, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x14, 0x02, 0x48, 0x1a, 0x6a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x41,
+    0x67, 0x67, 0x4c, 0x61, 0x79, 0x65, 0x72, 0x20, 0x69, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x63, 0x6f,
+    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x43, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69,
+    0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x75, 0x73, 0x20, 0x6e,
+    0x6f, 0x0a, 0x20, 0x45, 0x70, 0x6f, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62,
+    0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x14,
+    0x46, 0x47, 0x0a, 0x3c, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x03, 0x18, 0x00, 0x27, 0x1a, 0x31, 0x20,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68,
     0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x1e, 0x31, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x34, 0x35, 0x62, 0x06, 0x70, 0x72,
-    0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xdd, 0x29, 0x0a, 0x21, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
-    0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x5f, 0x73,
-    0x74, 0x61, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6c,
-    0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x2f, 0x61, 0x67,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x18, 0x08, 0x24, 0x0a, 0x42, 0x0a, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x1b, 0x00, 0x1e, 0x01, 0x1a, 0x36, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65,
+    0x6e, 0x74, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75,
+    0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x25, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x1d, 0x02, 0x36, 0x1a, 0x1a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x70,
+    0x6f, 0x63, 0x68, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x1e,
+    0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x34, 0x35, 0x62,
+    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33, 0x0a, 0xa8, 0x7b, 0x0a, 0x21, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x6e, 0x6f, 0x64,
+    0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x1a,
+    0x2b, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
+    0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x62, 0x72, 0x69, 0x64, 0x67,
+    0x65, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x25, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x1a, 0x2f, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f,
+    0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x2b, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e,
+    0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x1a, 0x29, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65,
+    0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x28, 0x61, 0x67,
     0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65,
-    0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
-    0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x2b, 0x61,
-    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70,
-    0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
-    0x65, 0x5f, 0x69, 0x64, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x29, 0x61, 0x67, 0x67, 0x6c,
-    0x61, 0x79, 0x65, 0x72, 0x2f, 0x6e, 0x6f, 0x64, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f,
-    0x76, 0x31, 0x2f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x2e,
-    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x6b, 0x0a, 0x1b, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x12, 0x4c, 0x0a, 0x0e, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61,
-    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70,
-    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
-    0x65, 0x49, 0x64, 0x52, 0x0d, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
-    0x49, 0x64, 0x22, 0x78, 0x0a, 0x1c, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
-    0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x73, 0x65, 0x12, 0x58, 0x0a, 0x12, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
-    0x65, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29,
+    0x73, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x6b, 0x0a, 0x1b, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4c, 0x0a, 0x0e, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e,
+    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x49, 0x64, 0x52, 0x0d, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x49, 0x64, 0x22, 0x78, 0x0a, 0x1c, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x58, 0x0a, 0x12, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x11, 0x63, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x22, 0x86, 0x01,
+    0x0a, 0x21, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x12, 0x42, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0e, 0x32, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64,
+    0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70,
+    0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x22, 0x7e, 0x0a, 0x22, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74,
+    0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65,
+    0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x58, 0x0a, 0x12,
+    0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x52, 0x11, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x22, 0x36, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x22, 0x60,
+    0x0a, 0x16, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x0c, 0x6e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23,
     0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74,
-    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x11, 0x63, 0x65, 0x72, 0x74, 0x69,
-    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x22, 0x86, 0x01, 0x0a,
-    0x21, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66,
-    0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x42, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e,
-    0x32, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65,
-    0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66,
-    0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65,
-    0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
-    0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77,
-    0x6f, 0x72, 0x6b, 0x49, 0x64, 0x22, 0x7e, 0x0a, 0x22, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65,
-    0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61,
-    0x64, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x58, 0x0a, 0x12, 0x63,
-    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x68, 0x65, 0x61, 0x64, 0x65,
-    0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
-    0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
-    0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64,
-    0x65, 0x72, 0x52, 0x11, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48,
-    0x65, 0x61, 0x64, 0x65, 0x72, 0x22, 0x36, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77,
-    0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d,
-    0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x22, 0x60, 0x0a,
-    0x16, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x0c, 0x6e, 0x65, 0x74, 0x77, 0x6f,
-    0x72, 0x6b, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49,
+    0x6e, 0x66, 0x6f, 0x52, 0x0b, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f,
+    0x22, 0xa4, 0x01, 0x0a, 0x21, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x22, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x48, 0x00, 0x52, 0x09, 0x6e, 0x65,
+    0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x88, 0x01, 0x01, 0x12, 0x4c, 0x0a, 0x0e, 0x63, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
+    0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x52, 0x0d, 0x63, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x42, 0x0d, 0x0a, 0x0b, 0x5f, 0x6e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x22, 0x7e, 0x0a, 0x22, 0x53, 0x75, 0x62, 0x73, 0x63,
+    0x72, 0x69, 0x62, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x58, 0x0a,
+    0x12, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f, 0x68, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65,
+    0x61, 0x64, 0x65, 0x72, 0x52, 0x11, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x22, 0xb4, 0x01, 0x0a, 0x1d, 0x4c, 0x69, 0x73, 0x74,
+    0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x66, 0x72, 0x6f, 0x6d,
+    0x5f, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x66,
+    0x72, 0x6f, 0x6d, 0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d,
+    0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x12,
+    0x3d, 0x0a, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x1f, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f,
+    0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x44, 0x69, 0x72, 0x65, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x52, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x7c,
+    0x0a, 0x1e, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x5a, 0x0a, 0x13, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x5f,
+    0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x29, 0x2e,
     0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79,
-    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e,
-    0x66, 0x6f, 0x52, 0x0b, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x2a,
-    0xec, 0x01, 0x0a, 0x1d, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
-    0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e,
-    0x64, 0x12, 0x31, 0x0a, 0x2d, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49,
-    0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f,
-    0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49,
-    0x45, 0x44, 0x10, 0x00, 0x12, 0x33, 0x0a, 0x2f, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54,
-    0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45,
-    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e,
-    0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45, 0x54,
-    0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41,
-    0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49,
-    0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x2f, 0x0a,
-    0x2b, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45,
-    0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
-    0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03, 0x2a, 0x8e,
-    0x02, 0x0a, 0x23, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x45, 0x72, 0x72,
-    0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x38, 0x0a, 0x34, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x41,
-    0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45,
-    0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
-    0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
-    0x12, 0x3a, 0x0a, 0x36, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43,
-    0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45,
-    0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53,
-    0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x39, 0x0a, 0x35,
-    0x47, 0x45, 0x54, 0x5f, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x52, 0x12, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x22, 0x7c, 0x0a, 0x16,
+    0x47, 0x65, 0x74, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x43, 0x0a, 0x0a, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69,
+    0x6e, 0x66, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x6e, 0x66, 0x6f, 0x52,
+    0x09, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x6e, 0x66, 0x6f, 0x22, 0xe5, 0x01, 0x0a, 0x17, 0x47,
+    0x65, 0x74, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x42, 0x0a, 0x06, 0x61, 0x6e, 0x63, 0x68, 0x6f, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x53, 0x74, 0x61, 0x74, 0x65, 0x41, 0x6e, 0x63, 0x68,
+    0x6f, 0x72, 0x52, 0x06, 0x61, 0x6e, 0x63, 0x68, 0x6f, 0x72, 0x12, 0x41, 0x0a, 0x07, 0x62, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0x33, 0x32, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x43, 0x0a,
+    0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
+    0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e,
+    0x67, 0x73, 0x22, 0x85, 0x01, 0x0a, 0x18, 0x47, 0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66,
+    0x69, 0x65, 0x72, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x4a,
+    0x0a, 0x0c, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
+    0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67,
+    0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xbe, 0x01, 0x0a, 0x19, 0x47,
+    0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x42, 0x0a, 0x06, 0x61, 0x6e, 0x63, 0x68,
+    0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x53, 0x74, 0x61, 0x74, 0x65, 0x41, 0x6e,
+    0x63, 0x68, 0x6f, 0x72, 0x52, 0x06, 0x61, 0x6e, 0x63, 0x68, 0x6f, 0x72, 0x12, 0x18, 0x0a, 0x07,
+    0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x63,
+    0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x12, 0x43, 0x0a, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e,
+    0x67, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33,
+    0x32, 0x52, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x22, 0x5c, 0x0a, 0x1c, 0x47,
+    0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52,
+    0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x1d, 0x0a, 0x0a, 0x6c, 0x65,
+    0x61, 0x66, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09,
+    0x6c, 0x65, 0x61, 0x66, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xe5, 0x01, 0x0a, 0x1d, 0x47, 0x65,
+    0x74, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x42, 0x0a, 0x06, 0x61,
+    0x6e, 0x63, 0x68, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x53, 0x74, 0x61, 0x74,
+    0x65, 0x41, 0x6e, 0x63, 0x68, 0x6f, 0x72, 0x52, 0x06, 0x61, 0x6e, 0x63, 0x68, 0x6f, 0x72, 0x12,
+    0x3b, 0x0a, 0x04, 0x6c, 0x65, 0x61, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e,
+    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
+    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
+    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x04, 0x6c, 0x65, 0x61, 0x66, 0x12, 0x43, 0x0a, 0x08,
+    0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27,
+    0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
+    0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64,
+    0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x08, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67,
+    0x73, 0x2a, 0xec, 0x01, 0x0a, 0x1d, 0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x31, 0x0a, 0x2d, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49,
     0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52,
-    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44,
-    0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x36, 0x0a, 0x32, 0x47, 0x45, 0x54, 0x5f, 0x4c,
-    0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54,
-    0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b,
-    0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03, 0x2a,
-    0x94, 0x02, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e,
-    0x66, 0x6f, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2b, 0x0a, 0x27, 0x47,
-    0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f,
-    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
-    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45, 0x54, 0x5f,
-    0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52,
-    0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f,
-    0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x44, 0x10, 0x01, 0x12, 0x32, 0x0a, 0x2e,
-    0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f,
-    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x45, 0x54, 0x57,
-    0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x02,
-    0x12, 0x34, 0x0a, 0x30, 0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f,
-    0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
-    0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f,
-    0x54, 0x59, 0x50, 0x45, 0x10, 0x03, 0x12, 0x2e, 0x0a, 0x2a, 0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45,
+    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x33, 0x0a, 0x2f, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45,
+    0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53,
+    0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x32, 0x0a, 0x2e, 0x47,
+    0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48,
+    0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12,
+    0x2f, 0x0a, 0x2b, 0x47, 0x45, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41,
+    0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03,
+    0x2a, 0x8e, 0x02, 0x0a, 0x23, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x45,
+    0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x38, 0x0a, 0x34, 0x47, 0x45, 0x54, 0x5f,
+    0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41,
+    0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+    0x10, 0x00, 0x12, 0x3a, 0x0a, 0x36, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54,
+    0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41,
+    0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d,
+    0x49, 0x53, 0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x39,
+    0x0a, 0x35, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52,
+    0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f,
+    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+    0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x36, 0x0a, 0x32, 0x47, 0x45, 0x54,
+    0x5f, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43,
+    0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
+    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10,
+    0x03, 0x2a, 0x94, 0x02, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b,
+    0x49, 0x6e, 0x66, 0x6f, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2b, 0x0a,
+    0x27, 0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46,
+    0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53,
+    0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45,
+    0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45,
+    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49,
+    0x44, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x44, 0x10, 0x01, 0x12, 0x32,
+    0x0a, 0x2e, 0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e,
+    0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x45,
     0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52,
-    0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x5f, 0x45,
-    0x52, 0x52, 0x4f, 0x52, 0x10, 0x04, 0x2a, 0xa9, 0x01, 0x0a, 0x1c, 0x4c, 0x61, 0x74, 0x65, 0x73,
-    0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75,
-    0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x12, 0x2f, 0x0a, 0x2b, 0x4c, 0x41, 0x54, 0x45, 0x53,
+    0x10, 0x02, 0x12, 0x34, 0x0a, 0x30, 0x47, 0x45, 0x54, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52,
+    0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x5f, 0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52,
+    0x4b, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x10, 0x03, 0x12, 0x2e, 0x0a, 0x2a, 0x47, 0x45, 0x54, 0x5f,
+    0x4e, 0x45, 0x54, 0x57, 0x4f, 0x52, 0x4b, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x5f, 0x45, 0x52, 0x52,
+    0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x04, 0x2a, 0xd3, 0x01, 0x0a, 0x23, 0x53, 0x75, 0x62,
+    0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64,
+    0x12, 0x37, 0x0a, 0x33, 0x53, 0x55, 0x42, 0x53, 0x43, 0x52, 0x49, 0x42, 0x45, 0x5f, 0x43, 0x45,
+    0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x39, 0x0a, 0x35, 0x53, 0x55, 0x42,
+    0x53, 0x43, 0x52, 0x49, 0x42, 0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41,
+    0x54, 0x45, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45,
+    0x4c, 0x44, 0x10, 0x01, 0x12, 0x38, 0x0a, 0x34, 0x53, 0x55, 0x42, 0x53, 0x43, 0x52, 0x49, 0x42,
+    0x45, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x53, 0x54,
+    0x41, 0x54, 0x55, 0x53, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x2a, 0xc4,
+    0x01, 0x0a, 0x1f, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69,
+    0x6e, 0x64, 0x12, 0x33, 0x0a, 0x2f, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49,
+    0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x53, 0x5f, 0x45,
+    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
+    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x34, 0x0a, 0x30, 0x4c, 0x49, 0x53, 0x54, 0x5f,
+    0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x48, 0x45, 0x41, 0x44,
+    0x45, 0x52, 0x53, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49,
+    0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x01, 0x12, 0x36, 0x0a,
+    0x32, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54,
+    0x45, 0x5f, 0x48, 0x45, 0x41, 0x44, 0x45, 0x52, 0x53, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x5f, 0x45, 0x52,
+    0x52, 0x4f, 0x52, 0x10, 0x02, 0x2a, 0xb2, 0x02, 0x0a, 0x18, 0x47, 0x65, 0x74, 0x42, 0x61, 0x6c,
+    0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69,
+    0x6e, 0x64, 0x12, 0x2c, 0x0a, 0x28, 0x47, 0x45, 0x54, 0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43,
+    0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
+    0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+    0x12, 0x2e, 0x0a, 0x2a, 0x47, 0x45, 0x54, 0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43, 0x45, 0x5f,
+    0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01,
+    0x12, 0x2d, 0x0a, 0x29, 0x47, 0x45, 0x54, 0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43, 0x45, 0x5f,
+    0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12,
+    0x2a, 0x0a, 0x26, 0x47, 0x45, 0x54, 0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43, 0x45, 0x5f, 0x50,
+    0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03, 0x12, 0x2c, 0x0a, 0x28, 0x47,
+    0x45, 0x54, 0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41, 0x56,
+    0x41, 0x49, 0x4c, 0x41, 0x42, 0x4c, 0x45, 0x10, 0x04, 0x12, 0x2f, 0x0a, 0x2b, 0x47, 0x45, 0x54,
+    0x5f, 0x42, 0x41, 0x4c, 0x41, 0x4e, 0x43, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45,
+    0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e,
+    0x41, 0x4c, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x05, 0x2a, 0xc0, 0x02, 0x0a, 0x1a, 0x47,
+    0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x2e, 0x0a, 0x2a, 0x47, 0x45, 0x54,
+    0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x30, 0x0a, 0x2c, 0x47, 0x45, 0x54,
+    0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53,
+    0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x2f, 0x0a, 0x2b, 0x47,
+    0x45, 0x54, 0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f,
+    0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e,
+    0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44, 0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x2c, 0x0a, 0x28,
+    0x47, 0x45, 0x54, 0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52,
+    0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e,
+    0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03, 0x12, 0x2e, 0x0a, 0x2a, 0x47, 0x45,
+    0x54, 0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4f,
+    0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41,
+    0x56, 0x41, 0x49, 0x4c, 0x41, 0x42, 0x4c, 0x45, 0x10, 0x04, 0x12, 0x31, 0x0a, 0x2d, 0x47, 0x45,
+    0x54, 0x5f, 0x4e, 0x55, 0x4c, 0x4c, 0x49, 0x46, 0x49, 0x45, 0x52, 0x5f, 0x50, 0x52, 0x4f, 0x4f,
+    0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54,
+    0x45, 0x52, 0x4e, 0x41, 0x4c, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x05, 0x2a, 0xe8, 0x02,
+    0x0a, 0x1e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72,
+    0x65, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64,
+    0x12, 0x34, 0x0a, 0x30, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x4f, 0x43, 0x41, 0x4c, 0x5f, 0x45, 0x58,
+    0x49, 0x54, 0x5f, 0x54, 0x52, 0x45, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52,
+    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x36, 0x0a, 0x32, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x4f,
+    0x43, 0x41, 0x4c, 0x5f, 0x45, 0x58, 0x49, 0x54, 0x5f, 0x54, 0x52, 0x45, 0x45, 0x5f, 0x50, 0x52,
+    0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d,
+    0x49, 0x53, 0x53, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x49, 0x45, 0x4c, 0x44, 0x10, 0x01, 0x12, 0x35,
+    0x0a, 0x31, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x4f, 0x43, 0x41, 0x4c, 0x5f, 0x45, 0x58, 0x49, 0x54,
+    0x5f, 0x54, 0x52, 0x45, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f,
+    0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x44,
+    0x41, 0x54, 0x41, 0x10, 0x02, 0x12, 0x32, 0x0a, 0x2e, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x4f, 0x43,
+    0x41, 0x4c, 0x5f, 0x45, 0x58, 0x49, 0x54, 0x5f, 0x54, 0x52, 0x45, 0x45, 0x5f, 0x50, 0x52, 0x4f,
+    0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x4f,
+    0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10, 0x03, 0x12, 0x34, 0x0a, 0x30, 0x47, 0x45, 0x54,
+    0x5f, 0x4c, 0x4f, 0x43, 0x41, 0x4c, 0x5f, 0x45, 0x58, 0x49, 0x54, 0x5f, 0x54, 0x52, 0x45, 0x45,
+    0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x55, 0x4e, 0x41, 0x56, 0x41, 0x49, 0x4c, 0x41, 0x42, 0x4c, 0x45, 0x10, 0x04, 0x12,
+    0x37, 0x0a, 0x33, 0x47, 0x45, 0x54, 0x5f, 0x4c, 0x4f, 0x43, 0x41, 0x4c, 0x5f, 0x45, 0x58, 0x49,
+    0x54, 0x5f, 0x54, 0x52, 0x45, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52,
+    0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c,
+    0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x05, 0x2a, 0xa9, 0x01, 0x0a, 0x1c, 0x4c, 0x61, 0x74,
+    0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x12, 0x2f, 0x0a, 0x2b, 0x4c, 0x41, 0x54,
+    0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f,
+    0x52, 0x45, 0x51, 0x55, 0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53,
+    0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x2b, 0x0a, 0x27, 0x4c, 0x41,
+    0x54, 0x45, 0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45,
+    0x5f, 0x52, 0x45, 0x51, 0x55, 0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x50, 0x45,
+    0x4e, 0x44, 0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x2b, 0x0a, 0x27, 0x4c, 0x41, 0x54, 0x45, 0x53,
     0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x52, 0x45,
-    0x51, 0x55, 0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
-    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x2b, 0x0a, 0x27, 0x4c, 0x41, 0x54, 0x45,
-    0x53, 0x54, 0x5f, 0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x52,
-    0x45, 0x51, 0x55, 0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x50, 0x45, 0x4e, 0x44,
-    0x49, 0x4e, 0x47, 0x10, 0x01, 0x12, 0x2b, 0x0a, 0x27, 0x4c, 0x41, 0x54, 0x45, 0x53, 0x54, 0x5f,
-    0x43, 0x45, 0x52, 0x54, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x45, 0x5f, 0x52, 0x45, 0x51, 0x55,
-    0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53, 0x45, 0x54, 0x54, 0x4c, 0x45, 0x44,
-    0x10, 0x02, 0x32, 0xf8, 0x02, 0x0a, 0x10, 0x4e, 0x6f, 0x64, 0x65, 0x53, 0x74, 0x61, 0x74, 0x65,
+    0x51, 0x55, 0x45, 0x53, 0x54, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53, 0x45, 0x54, 0x54, 0x4c,
+    0x45, 0x44, 0x10, 0x02, 0x2a, 0x6c, 0x0a, 0x0d, 0x4c, 0x69, 0x73, 0x74, 0x44, 0x69, 0x72, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1e, 0x0a, 0x1a, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x44, 0x49,
+    0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1c, 0x0a, 0x18, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x44, 0x49,
+    0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x41, 0x53, 0x43, 0x45, 0x4e, 0x44, 0x49, 0x4e,
+    0x47, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x4c, 0x49, 0x53, 0x54, 0x5f, 0x44, 0x49, 0x52, 0x45,
+    0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x44, 0x45, 0x53, 0x43, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47,
+    0x10, 0x02, 0x32, 0xd1, 0x07, 0x0a, 0x10, 0x4e, 0x6f, 0x64, 0x65, 0x53, 0x74, 0x61, 0x74, 0x65,
     0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x75, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x43, 0x65,
     0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x12,
     0x2d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e,
@@ -661,205 +1461,654 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75,
     0x65, 0x73, 0x74, 0x1a, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
     0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72,
-    0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x88, 0x01,
-    0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
-    0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x42, 0x0e, 0x4e, 0x6f, 0x64, 0x65, 0x53, 0x74, 0x61, 0x74,
-    0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02,
-    0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56,
-    0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64,
-    0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c,
-    0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64,
-    0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a,
-    0x4e, 0x6f, 0x64, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xc1, 0x17, 0x0a, 0x06, 0x12, 0x04, 0x00,
-    0x00, 0x75, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a,
-    0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04,
-    0x00, 0x39, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x05, 0x00, 0x35, 0x0a, 0x09, 0x0a,
-    0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x00, 0x33, 0x0a, 0x2e, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04,
-    0x09, 0x00, 0x12, 0x01, 0x1a, 0x22, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66,
-    0x6f, 0x72, 0x20, 0x71, 0x75, 0x65, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6e, 0x6f, 0x64, 0x65,
-    0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12,
-    0x03, 0x09, 0x08, 0x18, 0x0a, 0x55, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02,
-    0x5f, 0x1a, 0x48, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
-    0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x61, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
-    0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61,
-    0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63, 0x75, 0x6c, 0x61, 0x72, 0x20, 0x43, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x06, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x00, 0x02, 0x12, 0x03, 0x0b, 0x1b, 0x36, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x0b, 0x41, 0x5d, 0x0a, 0x54, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0e,
-    0x02, 0x71, 0x1a, 0x47, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64,
-    0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65,
-    0x73, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
-    0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20,
-    0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0e, 0x06, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
-    0x01, 0x02, 0x12, 0x03, 0x0e, 0x21, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x0e, 0x4d, 0x6f, 0x0a, 0x4c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x03, 0x11,
-    0x02, 0x4d, 0x1a, 0x3f, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64,
-    0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20,
-    0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x20, 0x61, 0x73, 0x20,
-    0x73, 0x65, 0x65, 0x6e, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x64,
-    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11, 0x06,
-    0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x11, 0x15, 0x2a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11, 0x35, 0x4b, 0x0a, 0x36, 0x0a,
-    0x02, 0x05, 0x00, 0x12, 0x04, 0x15, 0x00, 0x21, 0x01, 0x1a, 0x2a, 0x20, 0x45, 0x72, 0x72, 0x6f,
-    0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x43, 0x65,
-    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20,
-    0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x15, 0x05,
-    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x17, 0x02, 0x2f, 0x0a,
-    0x21, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x17, 0x02, 0x34, 0x1a, 0x14, 0x20, 0x55,
-    0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x17, 0x32, 0x33,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x31, 0x0a, 0x1d,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x36, 0x1a, 0x10, 0x20, 0x4d, 0x69,
-    0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1a, 0x34, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x30, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x02, 0x12, 0x03, 0x1d, 0x02, 0x35, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
-    0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02,
-    0x12, 0x03, 0x1d, 0x33, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x20, 0x02, 0x2d, 0x0a, 0x25, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x20, 0x02, 0x32,
-    0x1a, 0x18, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x6e,
-    0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x03, 0x02, 0x12, 0x03, 0x20, 0x30, 0x31, 0x0a, 0x3c, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04,
-    0x24, 0x00, 0x30, 0x01, 0x1a, 0x30, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e,
-    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x43,
-    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72,
-    0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x24,
-    0x05, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x02, 0x36,
-    0x0a, 0x21, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x26, 0x02, 0x3b, 0x1a, 0x14, 0x20,
+    0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x89, 0x01,
+    0x0a, 0x1a, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x33, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+    0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x34, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64,
+    0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x7b, 0x0a, 0x16, 0x4c, 0x69, 0x73,
+    0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x73, 0x12, 0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
+    0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69,
+    0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x1a, 0x30, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
+    0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x66, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x42, 0x61, 0x6c,
+    0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74,
+    0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x1a, 0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
+    0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+    0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x6c,
+    0x0a, 0x11, 0x47, 0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x12, 0x2a, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e,
+    0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66,
+    0x69, 0x65, 0x72, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+    0x2b, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x78, 0x0a, 0x15,
+    0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61,
+    0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61,
+    0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x88, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x76, 0x31, 0x42,
+    0x0e, 0x4e, 0x6f, 0x64, 0x65, 0x53, 0x74, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50,
+    0x01, 0xa2, 0x02, 0x03, 0x41, 0x4e, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c,
+    0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x4e, 0x6f, 0x64, 0x65, 0x5c, 0x56, 0x31,
+    0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x4e, 0x6f, 0x64, 0x65, 0x3a, 0x3a, 0x56,
+    0x31, 0x4a, 0xf6, 0x4a, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xcf, 0x02, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x35, 0x0a, 0x09, 0x0a, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06,
+    0x00, 0x39, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x35, 0x0a, 0x09, 0x0a,
+    0x02, 0x03, 0x04, 0x12, 0x03, 0x08, 0x00, 0x33, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x09, 0x00, 0x32, 0x0a, 0x2e, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x0c, 0x00, 0x24, 0x01, 0x1a,
+    0x22, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x71, 0x75,
+    0x65, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x08, 0x18, 0x0a,
+    0x55, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x02, 0x5f, 0x1a, 0x48, 0x20, 0x4d,
+    0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65,
+    0x74, 0x20, 0x61, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74,
+    0x69, 0x63, 0x75, 0x6c, 0x61, 0x72, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x49, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x0e, 0x06, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0e,
+    0x1b, 0x36, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x41, 0x5d,
+    0x0a, 0x54, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x11, 0x02, 0x71, 0x1a, 0x47, 0x20,
+    0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67,
+    0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x6b, 0x6e,
+    0x6f, 0x77, 0x6e, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
+    0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x11, 0x06, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x11,
+    0x21, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x11, 0x4d, 0x6f,
+    0x0a, 0x4c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x03, 0x14, 0x02, 0x4d, 0x1a, 0x3f, 0x20,
+    0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67,
+    0x65, 0x74, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x20, 0x61, 0x73, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x20,
+    0x62, 0x79, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x14, 0x06, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x14, 0x15, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x14, 0x35, 0x4b, 0x0a, 0x4a, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x03,
+    0x12, 0x03, 0x17, 0x02, 0x78, 0x1a, 0x3d, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75,
+    0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65,
+    0x20, 0x74, 0x6f, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
+    0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x17,
+    0x06, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x17, 0x21, 0x42,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x17, 0x4d, 0x53, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x17, 0x54, 0x76, 0x0a, 0x50, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x65, 0x1a, 0x43, 0x20, 0x4d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x06, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1a, 0x1d, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x1a, 0x45, 0x63, 0x0a, 0x6a, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x05,
+    0x12, 0x03, 0x1d, 0x02, 0x50, 0x1a, 0x5d, 0x20, 0x4d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75,
+    0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c,
+    0x65, 0x64, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65,
+    0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1d,
+    0x06, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x1d, 0x16, 0x2c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1d, 0x37, 0x4e, 0x0a, 0x75,
+    0x0a, 0x04, 0x06, 0x00, 0x02, 0x06, 0x12, 0x03, 0x20, 0x02, 0x56, 0x1a, 0x68, 0x20, 0x4d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+    0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65,
+    0x64, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x6e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65,
+    0x72, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03,
+    0x20, 0x06, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x20, 0x18,
+    0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x20, 0x3b, 0x54, 0x0a,
+    0x57, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x07, 0x12, 0x03, 0x23, 0x02, 0x62, 0x1a, 0x4a, 0x20, 0x4d,
+    0x65, 0x74, 0x68, 0x6f, 0x64, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72,
+    0x6f, 0x76, 0x65, 0x20, 0x61, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20,
+    0x65, 0x78, 0x69, 0x74, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07,
+    0x01, 0x12, 0x03, 0x23, 0x06, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x02, 0x12,
+    0x03, 0x23, 0x1c, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x23,
+    0x43, 0x60, 0x0a, 0x36, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x27, 0x00, 0x33, 0x01, 0x1a, 0x2a,
+    0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x47, 0x65, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65,
+    0x61, 0x64, 0x65, 0x72, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00,
+    0x01, 0x12, 0x03, 0x27, 0x05, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x29, 0x02, 0x2f, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x29, 0x02,
+    0x34, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20,
+    0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x03, 0x29, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x2c, 0x02, 0x31, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x02, 0x36,
+    0x1a, 0x10, 0x20, 0x4d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x2c, 0x34, 0x35,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x02, 0x30, 0x0a, 0x1c,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x35, 0x1a, 0x0f, 0x20, 0x49, 0x6e,
+    0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x33, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x32, 0x02, 0x2d, 0x0a, 0x25, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03,
+    0x12, 0x03, 0x32, 0x02, 0x32, 0x1a, 0x18, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x32, 0x30, 0x31, 0x0a, 0x3c, 0x0a,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0x36, 0x00, 0x42, 0x01, 0x1a, 0x30, 0x20, 0x45, 0x72, 0x72, 0x6f,
+    0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4c, 0x61,
+    0x74, 0x65, 0x73, 0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05,
+    0x01, 0x01, 0x12, 0x03, 0x36, 0x05, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x38, 0x02, 0x36, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x38,
+    0x02, 0x3b, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64,
+    0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x38, 0x39, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x3b, 0x02, 0x38, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x02,
+    0x3d, 0x1a, 0x10, 0x20, 0x4d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x3b, 0x3b,
+    0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x37, 0x0a,
+    0x1c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x3e, 0x02, 0x3c, 0x1a, 0x0f, 0x20, 0x49,
+    0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3e, 0x3a, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x41, 0x02, 0x34, 0x0a, 0x25, 0x0a, 0x04, 0x05, 0x01, 0x02,
+    0x03, 0x12, 0x03, 0x41, 0x02, 0x39, 0x1a, 0x18, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x41, 0x37, 0x38, 0x0a, 0x30,
+    0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x45, 0x00, 0x54, 0x01, 0x1a, 0x24, 0x20, 0x45, 0x72, 0x72,
+    0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x45, 0x05, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x47, 0x02, 0x29, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x02,
+    0x02, 0x00, 0x12, 0x03, 0x47, 0x02, 0x2e, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63,
+    0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x47, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4a, 0x02, 0x30, 0x0a, 0x34, 0x0a, 0x04, 0x05, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x4a, 0x02, 0x35, 0x1a, 0x27, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+    0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x49, 0x44, 0x2f, 0x6e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x4a, 0x33, 0x34, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x02, 0x30, 0x0a, 0x25, 0x0a, 0x04, 0x05,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x4d, 0x02, 0x35, 0x1a, 0x18, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c,
+    0x69, 0x64, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x03, 0x4d, 0x33, 0x34,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x50, 0x02, 0x32, 0x0a, 0x2f,
+    0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x50, 0x02, 0x37, 0x1a, 0x22, 0x20, 0x43, 0x6f,
+    0x75, 0x6c, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x64, 0x65, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e,
+    0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x74, 0x79, 0x70, 0x65, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x50, 0x35, 0x36, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x53, 0x02, 0x2c, 0x0a, 0x26, 0x0a, 0x04, 0x05,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x53, 0x02, 0x31, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72,
+    0x6e, 0x61, 0x6c, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x53, 0x2f,
+    0x30, 0x0a, 0x3c, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04, 0x57, 0x00, 0x60, 0x01, 0x1a, 0x30, 0x20,
+    0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x53,
+    0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x03, 0x57, 0x05, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x59, 0x02, 0x35, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x03, 0x02,
+    0x00, 0x12, 0x03, 0x59, 0x02, 0x3a, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
+    0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x03, 0x02, 0x00, 0x02, 0x12, 0x03, 0x59, 0x38, 0x39, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x5c, 0x02, 0x37, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x01,
+    0x12, 0x03, 0x5c, 0x02, 0x3c, 0x1a, 0x10, 0x20, 0x4d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x5c, 0x3a, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x5f, 0x02, 0x36, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x02, 0x12, 0x03, 0x5f, 0x02, 0x3b,
+    0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x02, 0x12, 0x03, 0x5f, 0x39, 0x3a, 0x0a,
+    0x38, 0x0a, 0x02, 0x05, 0x04, 0x12, 0x04, 0x63, 0x00, 0x6c, 0x01, 0x1a, 0x2c, 0x20, 0x45, 0x72,
+    0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x4c, 0x69, 0x73,
+    0x74, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64,
+    0x65, 0x72, 0x73, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x04, 0x01,
+    0x12, 0x03, 0x63, 0x05, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x65, 0x02, 0x31, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x04, 0x02, 0x00, 0x12, 0x03, 0x65, 0x02, 0x36,
+    0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65,
+    0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x00, 0x02, 0x12,
+    0x03, 0x65, 0x34, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x68,
+    0x02, 0x32, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x04, 0x02, 0x01, 0x12, 0x03, 0x68, 0x02, 0x37, 0x1a,
+    0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x01, 0x02, 0x12, 0x03, 0x68, 0x35, 0x36, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02, 0x34, 0x0a, 0x26, 0x0a, 0x04,
+    0x05, 0x04, 0x02, 0x02, 0x12, 0x03, 0x6b, 0x02, 0x39, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65,
+    0x72, 0x6e, 0x61, 0x6c, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72,
+    0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x6b,
+    0x37, 0x38, 0x0a, 0x32, 0x0a, 0x02, 0x05, 0x05, 0x12, 0x05, 0x6f, 0x00, 0x81, 0x01, 0x01, 0x1a,
+    0x25, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x47, 0x65, 0x74, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x05, 0x01, 0x12, 0x03, 0x6f,
+    0x05, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x71, 0x02, 0x2a,
+    0x0a, 0x21, 0x0a, 0x04, 0x05, 0x05, 0x02, 0x00, 0x12, 0x03, 0x71, 0x02, 0x2f, 0x1a, 0x14, 0x20,
     0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f,
-    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x26, 0x39,
-    0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x29, 0x02, 0x38, 0x0a,
-    0x1d, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x3d, 0x1a, 0x10, 0x20, 0x4d,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x00, 0x02, 0x12, 0x03, 0x71, 0x2d,
+    0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x74, 0x02, 0x2c, 0x0a,
+    0x1d, 0x0a, 0x04, 0x05, 0x05, 0x02, 0x01, 0x12, 0x03, 0x74, 0x02, 0x31, 0x1a, 0x10, 0x20, 0x4d,
     0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x29, 0x3b, 0x3c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x02, 0x37, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x01,
-    0x02, 0x02, 0x12, 0x03, 0x2c, 0x02, 0x3c, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
-    0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02,
-    0x02, 0x12, 0x03, 0x2c, 0x3a, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x2f, 0x02, 0x34, 0x0a, 0x25, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x02,
-    0x39, 0x1a, 0x18, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
-    0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x2f, 0x37, 0x38, 0x0a, 0x30, 0x0a, 0x02, 0x05, 0x02, 0x12,
-    0x04, 0x33, 0x00, 0x42, 0x01, 0x1a, 0x24, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69,
-    0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72,
-    0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05,
-    0x02, 0x01, 0x12, 0x03, 0x33, 0x05, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x35, 0x02, 0x29, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x00, 0x12, 0x03, 0x35,
-    0x02, 0x2e, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64,
-    0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x35, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x38, 0x02, 0x30, 0x0a, 0x34, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02,
-    0x35, 0x1a, 0x27, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x6e, 0x65, 0x74, 0x77,
-    0x6f, 0x72, 0x6b, 0x20, 0x49, 0x44, 0x2f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x6e,
-    0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x01, 0x02, 0x12, 0x03, 0x38, 0x33, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x3b, 0x02, 0x30, 0x0a, 0x25, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03,
-    0x3b, 0x02, 0x35, 0x1a, 0x18, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x6e, 0x65,
-    0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3b, 0x33, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x32, 0x0a, 0x2f, 0x0a, 0x04, 0x05, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x3e, 0x02, 0x37, 0x1a, 0x22, 0x20, 0x43, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x6e,
-    0x6f, 0x74, 0x20, 0x64, 0x65, 0x74, 0x65, 0x72, 0x6d, 0x69, 0x6e, 0x65, 0x20, 0x6e, 0x65, 0x74,
-    0x77, 0x6f, 0x72, 0x6b, 0x20, 0x74, 0x79, 0x70, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x03, 0x02, 0x12, 0x03, 0x3e, 0x35, 0x36, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04,
-    0x01, 0x12, 0x03, 0x41, 0x02, 0x2c, 0x0a, 0x26, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03,
-    0x41, 0x02, 0x31, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x20, 0x73,
-    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x41, 0x2f, 0x30, 0x0a, 0x50, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x45, 0x00, 0x48, 0x01, 0x1a, 0x44, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x61, 0x20, 0x43, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63, 0x75, 0x6c, 0x61, 0x72, 0x20, 0x43,
-    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64, 0x2e, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x45, 0x08, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x47, 0x02, 0x18, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
-    0x12, 0x03, 0x47, 0x02, 0x2c, 0x1a, 0x1d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69,
-    0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x47,
-    0x19, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x47, 0x2a, 0x2b,
-    0x0a, 0x38, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x4b, 0x00, 0x4e, 0x01, 0x1a, 0x2c, 0x20, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x43,
-    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72,
-    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
-    0x01, 0x12, 0x03, 0x4b, 0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12,
-    0x03, 0x4d, 0x02, 0x1c, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x4d, 0x02,
-    0x34, 0x1a, 0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4d, 0x1d, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x4d, 0x32, 0x33, 0x0a, 0x5f, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04,
-    0x51, 0x00, 0x57, 0x01, 0x1a, 0x53, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74,
-    0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74,
-    0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2f, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x2f, 0x73,
-    0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61,
-    0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
-    0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01,
-    0x12, 0x03, 0x51, 0x08, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x53, 0x02, 0x1e, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x53, 0x02, 0x28,
-    0x1a, 0x32, 0x20, 0x57, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66,
-    0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
-    0x61, 0x74, 0x65, 0x20, 0x77, 0x65, 0x20, 0x77, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67,
-    0x65, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x53,
-    0x1f, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x53, 0x26, 0x27,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x56, 0x02, 0x08, 0x0a, 0x26,
-    0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x56, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54, 0x68,
-    0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
-    0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x56, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x56,
-    0x16, 0x17, 0x0a, 0x3c, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04, 0x5a, 0x00, 0x63, 0x01, 0x1a, 0x30,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6c, 0x61, 0x74,
-    0x65, 0x73, 0x74, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
-    0x77, 0x65, 0x20, 0x77, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x03, 0x5a, 0x05, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5c, 0x02, 0x2d, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x03,
-    0x02, 0x00, 0x12, 0x03, 0x5c, 0x02, 0x32, 0x1a, 0x0f, 0x20, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c,
-    0x74, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x5c, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x5f, 0x02, 0x29, 0x0a, 0x23, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02,
-    0x2e, 0x1a, 0x16, 0x20, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x65, 0x72, 0x74,
-    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02,
-    0x01, 0x02, 0x12, 0x03, 0x5f, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x62, 0x02, 0x29, 0x0a, 0x23, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x02, 0x12, 0x03, 0x62,
-    0x02, 0x2e, 0x1a, 0x16, 0x20, 0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72,
-    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03,
-    0x02, 0x02, 0x02, 0x12, 0x03, 0x62, 0x2c, 0x2d, 0x0a, 0x56, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x66, 0x00, 0x69, 0x01, 0x1a, 0x4a, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20,
-    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x6b, 0x6e,
-    0x6f, 0x77, 0x6e, 0x2f, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x2f, 0x73, 0x65, 0x74, 0x74,
-    0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20,
-    0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x66, 0x08, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x68, 0x02, 0x1c, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x03,
-    0x02, 0x00, 0x12, 0x03, 0x68, 0x02, 0x34, 0x1a, 0x20, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61,
-    0x74, 0x65, 0x73, 0x74, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65,
-    0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x68, 0x1d, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x68, 0x32, 0x33, 0x0a, 0x53, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x6c, 0x00, 0x6f,
+    0x0a, 0x05, 0x05, 0x05, 0x02, 0x01, 0x02, 0x12, 0x03, 0x74, 0x2f, 0x30, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x77, 0x02, 0x2b, 0x0a, 0x1c, 0x0a, 0x04, 0x05, 0x05,
+    0x02, 0x02, 0x12, 0x03, 0x77, 0x02, 0x30, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+    0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x77, 0x2e, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x7a, 0x02, 0x28, 0x0a, 0x30, 0x0a, 0x04, 0x05, 0x05, 0x02, 0x03, 0x12, 0x03, 0x7a, 0x02,
+    0x2d, 0x1a, 0x23, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74,
+    0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x7a, 0x2b, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7d,
+    0x02, 0x2a, 0x0a, 0x3f, 0x0a, 0x04, 0x05, 0x05, 0x02, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x2f, 0x1a,
+    0x32, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x65, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x75, 0x70, 0x64,
+    0x61, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79, 0x20, 0x6c, 0x61, 0x74, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x04, 0x02, 0x12, 0x03, 0x7d, 0x2d,
+    0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x05, 0x02, 0x05, 0x01, 0x12, 0x04, 0x80, 0x01, 0x02, 0x2d,
+    0x0a, 0x27, 0x0a, 0x04, 0x05, 0x05, 0x02, 0x05, 0x12, 0x04, 0x80, 0x01, 0x02, 0x32, 0x1a, 0x19,
+    0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x05, 0x02,
+    0x05, 0x02, 0x12, 0x04, 0x80, 0x01, 0x30, 0x31, 0x0a, 0x35, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x06,
+    0x84, 0x01, 0x00, 0x96, 0x01, 0x01, 0x1a, 0x27, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b,
+    0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4e, 0x75, 0x6c, 0x6c, 0x69,
+    0x66, 0x69, 0x65, 0x72, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x05, 0x06, 0x01, 0x12, 0x04, 0x84, 0x01, 0x05, 0x1f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0x86, 0x01, 0x02, 0x2c, 0x0a, 0x22, 0x0a, 0x04, 0x05,
+    0x06, 0x02, 0x00, 0x12, 0x04, 0x86, 0x01, 0x02, 0x31, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70,
+    0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x05, 0x06, 0x02, 0x00, 0x02, 0x12, 0x04, 0x86, 0x01, 0x2f, 0x30, 0x0a, 0x0d,
+    0x0a, 0x05, 0x05, 0x06, 0x02, 0x01, 0x01, 0x12, 0x04, 0x89, 0x01, 0x02, 0x2e, 0x0a, 0x1e, 0x0a,
+    0x04, 0x05, 0x06, 0x02, 0x01, 0x12, 0x04, 0x89, 0x01, 0x02, 0x33, 0x1a, 0x10, 0x20, 0x4d, 0x69,
+    0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x06, 0x02, 0x01, 0x02, 0x12, 0x04, 0x89, 0x01, 0x31, 0x32, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x06, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x2d, 0x0a, 0x1d, 0x0a, 0x04, 0x05,
+    0x06, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x32, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61,
+    0x6c, 0x69, 0x64, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x06,
+    0x02, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x01, 0x30, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x06, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x02, 0x2a, 0x0a, 0x31, 0x0a, 0x04, 0x05, 0x06, 0x02, 0x03,
+    0x12, 0x04, 0x8f, 0x01, 0x02, 0x2f, 0x1a, 0x23, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x65, 0x74, 0x74,
+    0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x06, 0x02, 0x03, 0x02, 0x12, 0x04, 0x8f, 0x01, 0x2d, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x06,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x92, 0x01, 0x02, 0x2c, 0x0a, 0x40, 0x0a, 0x04, 0x05, 0x06, 0x02,
+    0x04, 0x12, 0x04, 0x92, 0x01, 0x02, 0x31, 0x1a, 0x32, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x69, 0x73, 0x20, 0x62,
+    0x65, 0x69, 0x6e, 0x67, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x72, 0x65,
+    0x74, 0x72, 0x79, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x06, 0x02, 0x04, 0x02, 0x12, 0x04, 0x92, 0x01, 0x2f, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x06,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0x95, 0x01, 0x02, 0x2f, 0x0a, 0x27, 0x0a, 0x04, 0x05, 0x06, 0x02,
+    0x05, 0x12, 0x04, 0x95, 0x01, 0x02, 0x34, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6e,
+    0x61, 0x6c, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x06, 0x02, 0x05, 0x02, 0x12, 0x04, 0x95, 0x01, 0x32,
+    0x33, 0x0a, 0x39, 0x0a, 0x02, 0x05, 0x07, 0x12, 0x06, 0x99, 0x01, 0x00, 0xab, 0x01, 0x01, 0x1a,
+    0x2b, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x54, 0x72, 0x65,
+    0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x52, 0x50, 0x43, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x05, 0x07, 0x01, 0x12, 0x04, 0x99, 0x01, 0x05, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x32, 0x0a, 0x22, 0x0a, 0x04, 0x05, 0x07, 0x02, 0x00,
+    0x12, 0x04, 0x9b, 0x01, 0x02, 0x37, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
+    0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x07, 0x02, 0x00, 0x02, 0x12, 0x04, 0x9b, 0x01, 0x35, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x07, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x34, 0x0a, 0x1e, 0x0a, 0x04, 0x05, 0x07,
+    0x02, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x39, 0x1a, 0x10, 0x20, 0x4d, 0x69, 0x73, 0x73, 0x69,
+    0x6e, 0x67, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07,
+    0x02, 0x01, 0x02, 0x12, 0x04, 0x9e, 0x01, 0x37, 0x38, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x33, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x07, 0x02, 0x02,
+    0x12, 0x04, 0xa1, 0x01, 0x02, 0x38, 0x1a, 0x0f, 0x20, 0x49, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+    0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x02, 0x02,
+    0x12, 0x04, 0xa1, 0x01, 0x36, 0x37, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xa4, 0x01, 0x02, 0x30, 0x0a, 0x31, 0x0a, 0x04, 0x05, 0x07, 0x02, 0x03, 0x12, 0x04, 0xa4,
+    0x01, 0x02, 0x35, 0x1a, 0x23, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64,
+    0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x03,
+    0x02, 0x12, 0x04, 0xa4, 0x01, 0x33, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0xa7, 0x01, 0x02, 0x32, 0x0a, 0x40, 0x0a, 0x04, 0x05, 0x07, 0x02, 0x04, 0x12, 0x04,
+    0xa7, 0x01, 0x02, 0x37, 0x1a, 0x32, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c,
+    0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e,
+    0x67, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x72, 0x65, 0x74, 0x72, 0x79,
+    0x20, 0x6c, 0x61, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x04,
+    0x02, 0x12, 0x04, 0xa7, 0x01, 0x35, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0xaa, 0x01, 0x02, 0x35, 0x0a, 0x27, 0x0a, 0x04, 0x05, 0x07, 0x02, 0x05, 0x12, 0x04,
+    0xaa, 0x01, 0x02, 0x3a, 0x1a, 0x19, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x05, 0x07, 0x02, 0x05, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x38, 0x39, 0x0a, 0x52,
+    0x0a, 0x02, 0x04, 0x00, 0x12, 0x06, 0xae, 0x01, 0x00, 0xb1, 0x01, 0x01, 0x1a, 0x44, 0x20, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x61, 0x20,
+    0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63, 0x75, 0x6c,
+    0x61, 0x72, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x49, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x04, 0xae, 0x01, 0x08, 0x23, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb0, 0x01, 0x02, 0x18, 0x0a, 0x2b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xb0, 0x01, 0x02, 0x2c, 0x1a, 0x1d, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb0, 0x01, 0x19, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xb0, 0x01, 0x2a, 0x2b, 0x0a, 0x3a, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x06, 0xb4, 0x01, 0x00, 0xb7, 0x01, 0x01, 0x1a, 0x2c, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x43, 0x65, 0x72, 0x74, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x48, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x04, 0xb4, 0x01,
+    0x08, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb6, 0x01, 0x02,
+    0x1c, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x34, 0x1a,
+    0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x1d, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xb6, 0x01, 0x32, 0x33, 0x0a, 0x61, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x06,
+    0xba, 0x01, 0x00, 0xc0, 0x01, 0x01, 0x1a, 0x53, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65,
+    0x73, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2f, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x2f, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x02, 0x01, 0x12, 0x04, 0xba, 0x01, 0x08, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xbc, 0x01, 0x02, 0x1e, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12,
+    0x04, 0xbc, 0x01, 0x02, 0x28, 0x1a, 0x32, 0x20, 0x57, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x79,
+    0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x77, 0x65, 0x20, 0x77, 0x61, 0x6e, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xbc, 0x01, 0x1f, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xbc, 0x01, 0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xbf, 0x01, 0x02, 0x08, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xbf, 0x01, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x09, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x16, 0x17, 0x0a, 0x3e, 0x0a,
+    0x02, 0x05, 0x08, 0x12, 0x06, 0xc3, 0x01, 0x00, 0xcc, 0x01, 0x01, 0x1a, 0x30, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x77, 0x65, 0x20,
+    0x77, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x05, 0x08, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x05, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x08,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x2d, 0x0a, 0x1d, 0x0a, 0x04, 0x05, 0x08, 0x02,
+    0x00, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x32, 0x1a, 0x0f, 0x20, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c,
+    0x74, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x08, 0x02, 0x00,
+    0x02, 0x12, 0x04, 0xc5, 0x01, 0x30, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x08, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xc8, 0x01, 0x02, 0x29, 0x0a, 0x24, 0x0a, 0x04, 0x05, 0x08, 0x02, 0x01, 0x12, 0x04,
+    0xc8, 0x01, 0x02, 0x2e, 0x1a, 0x16, 0x20, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x63,
+    0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x08, 0x02, 0x01, 0x02, 0x12, 0x04, 0xc8, 0x01, 0x2c, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x29, 0x0a, 0x24, 0x0a, 0x04, 0x05, 0x08,
+    0x02, 0x02, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x2e, 0x1a, 0x16, 0x20, 0x53, 0x65, 0x74, 0x74, 0x6c,
+    0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x08, 0x02, 0x02, 0x02, 0x12, 0x04, 0xcb, 0x01, 0x2c, 0x2d, 0x0a,
+    0x58, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x06, 0xcf, 0x01, 0x00, 0xd2, 0x01, 0x01, 0x1a, 0x4a, 0x20,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2f, 0x70, 0x65, 0x6e,
+    0x64, 0x69, 0x6e, 0x67, 0x2f, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x03, 0x01,
+    0x12, 0x04, 0xcf, 0x01, 0x08, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0xd1, 0x01, 0x02, 0x1c, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x04, 0xd1,
+    0x01, 0x02, 0x34, 0x1a, 0x20, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xd1, 0x01, 0x1d, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd1,
+    0x01, 0x32, 0x33, 0x0a, 0x55, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x06, 0xd5, 0x01, 0x00, 0xd8, 0x01,
     0x01, 0x1a, 0x47, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x67,
     0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x69,
     0x6e, 0x66, 0x6f, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
     0x72, 0x6b, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x73, 0x65, 0x65, 0x6e, 0x20, 0x62, 0x79, 0x20, 0x74,
-    0x68, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04,
-    0x01, 0x12, 0x03, 0x6c, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x6e, 0x02, 0x08, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x6e, 0x02,
-    0x18, 0x1a, 0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20,
-    0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6e, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x6e, 0x16, 0x17, 0x0a, 0x54, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04,
-    0x72, 0x00, 0x75, 0x01, 0x1a, 0x48, 0x20, 0x47, 0x65, 0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72,
-    0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x63, 0x6f,
-    0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65,
-    0x6e, 0x74, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66,
-    0x6f, 0x72, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x72, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x00, 0x06, 0x12, 0x03, 0x74, 0x02, 0x16, 0x0a, 0x36, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00,
-    0x12, 0x03, 0x74, 0x02, 0x28, 0x1a, 0x29, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72,
-    0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x74, 0x17, 0x23, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x74, 0x26, 0x27, 0x62, 0x06, 0x70, 0x72,
-    0x6f, 0x74, 0x6f, 0x33,
+    0x68, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x04,
+    0x01, 0x12, 0x04, 0xd5, 0x01, 0x08, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xd7, 0x01, 0x02, 0x08, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x04,
+    0xd7, 0x01, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+    0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x09, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd7, 0x01, 0x16, 0x17, 0x0a, 0x56, 0x0a,
+    0x02, 0x04, 0x05, 0x12, 0x06, 0xdb, 0x01, 0x00, 0xde, 0x01, 0x01, 0x1a, 0x48, 0x20, 0x47, 0x65,
+    0x74, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x04, 0xdb, 0x01,
+    0x08, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x04, 0xdd, 0x01, 0x02,
+    0x16, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x28, 0x1a,
+    0x29, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e,
+    0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xdd, 0x01, 0x17, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x26, 0x27, 0x0a, 0x90, 0x01, 0x0a, 0x02, 0x04, 0x06, 0x12,
+    0x06, 0xe3, 0x01, 0x00, 0xe9, 0x01, 0x01, 0x1a, 0x81, 0x01, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x75, 0x62, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x20,
+    0x74, 0x6f, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x73, 0x2e, 0x0a, 0x0a, 0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x6e, 0x6f, 0x20, 0x66, 0x69, 0x6c,
+    0x74, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x73, 0x65, 0x74, 0x2c, 0x20, 0x65, 0x76, 0x65, 0x72,
+    0x79, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20,
+    0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x73,
+    0x20, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x06, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x08, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x0a, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12,
+    0x04, 0xe5, 0x01, 0x02, 0x21, 0x1a, 0x3c, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x74, 0x72,
+    0x65, 0x61, 0x6d, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x73, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe5, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x12,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe5, 0x01, 0x1f, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x18, 0x0a,
+    0x3c, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x2c, 0x1a, 0x2e, 0x20,
+    0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20,
+    0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x19, 0x27, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x2a, 0x2b, 0x0a, 0x30, 0x0a, 0x02, 0x04,
+    0x07, 0x12, 0x06, 0xec, 0x01, 0x00, 0xef, 0x01, 0x01, 0x1a, 0x22, 0x20, 0x41, 0x20, 0x63, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x07, 0x01, 0x12, 0x04, 0xec, 0x01, 0x08, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xee, 0x01, 0x02, 0x1c, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x07, 0x02,
+    0x00, 0x12, 0x04, 0xee, 0x01, 0x02, 0x34, 0x1a, 0x39, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x2c, 0x20, 0x61, 0x73, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x04, 0xee, 0x01, 0x1d,
+    0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x04, 0xee, 0x01, 0x32, 0x33,
+    0x0a, 0x46, 0x0a, 0x02, 0x05, 0x09, 0x12, 0x06, 0xf2, 0x01, 0x00, 0xfb, 0x01, 0x01, 0x1a, 0x38,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69,
+    0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x6c, 0x69, 0x73, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x09, 0x01, 0x12,
+    0x04, 0xf2, 0x01, 0x05, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x09, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xf4, 0x01, 0x02, 0x1c, 0x0a, 0x34, 0x0a, 0x04, 0x05, 0x09, 0x02, 0x00, 0x12, 0x04, 0xf4, 0x01,
+    0x02, 0x21, 0x1a, 0x26, 0x20, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x20, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x2c, 0x20, 0x74, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61,
+    0x73, 0x63, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x09,
+    0x02, 0x00, 0x02, 0x12, 0x04, 0xf4, 0x01, 0x1f, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x09, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xf7, 0x01, 0x02, 0x1a, 0x0a, 0x23, 0x0a, 0x04, 0x05, 0x09, 0x02, 0x01,
+    0x12, 0x04, 0xf7, 0x01, 0x02, 0x1f, 0x1a, 0x15, 0x20, 0x49, 0x6e, 0x63, 0x72, 0x65, 0x61, 0x73,
+    0x69, 0x6e, 0x67, 0x20, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x09, 0x02, 0x01, 0x02, 0x12, 0x04, 0xf7, 0x01, 0x1d, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x09, 0x02, 0x02, 0x01, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x1b, 0x0a, 0x23, 0x0a, 0x04, 0x05,
+    0x09, 0x02, 0x02, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x20, 0x1a, 0x15, 0x20, 0x44, 0x65, 0x63, 0x72,
+    0x65, 0x61, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x09, 0x02, 0x02, 0x02, 0x12, 0x04, 0xfa, 0x01, 0x1e, 0x1f, 0x0a,
+    0x4d, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x06, 0xfe, 0x01, 0x00, 0x8a, 0x02, 0x01, 0x1a, 0x3f, 0x20,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65, 0x72, 0x74,
+    0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2e, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x08, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x05, 0x12, 0x04, 0x80, 0x02, 0x02, 0x08, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x00, 0x12, 0x04, 0x80, 0x02, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0x80, 0x02,
+    0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04, 0x80, 0x02, 0x16,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0x83, 0x02, 0x02, 0x08,
+    0x0a, 0x44, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x04, 0x83, 0x02, 0x02, 0x19, 0x1a, 0x36,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x69,
+    0x6e, 0x67, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x73, 0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75,
+    0x73, 0x69, 0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x83, 0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x83, 0x02, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x04, 0x86,
+    0x02, 0x02, 0x08, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x04, 0x86, 0x02, 0x02,
+    0x13, 0x1a, 0x36, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x78, 0x69, 0x6d, 0x75, 0x6d, 0x20,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72, 0x73, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x86, 0x02, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x86, 0x02, 0x11, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x06,
+    0x12, 0x04, 0x89, 0x02, 0x02, 0x0f, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x04,
+    0x89, 0x02, 0x02, 0x1e, 0x1a, 0x2c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x68, 0x65,
+    0x69, 0x67, 0x68, 0x74, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x77, 0x61, 0x6c, 0x6b, 0x65, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x04, 0x89, 0x02, 0x10,
+    0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x04, 0x89, 0x02, 0x1c, 0x1d,
+    0x0a, 0x43, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x06, 0x8d, 0x02, 0x00, 0x90, 0x02, 0x01, 0x1a, 0x35,
+    0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x65, 0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0x8d, 0x02,
+    0x08, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8f, 0x02, 0x02,
+    0x0a, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0x8f, 0x02, 0x02, 0x3e, 0x1a,
+    0x3a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x63, 0x65,
+    0x72, 0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x68, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x73, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8f, 0x02, 0x0b, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x26, 0x39, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0x8f, 0x02, 0x3c, 0x3d, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x06,
+    0x93, 0x02, 0x00, 0x99, 0x02, 0x01, 0x1a, 0x2a, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x04, 0x93, 0x02, 0x08, 0x1e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x04, 0x95, 0x02, 0x02, 0x08, 0x0a, 0x27,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x04, 0x95, 0x02, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74,
+    0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x95, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x95, 0x02, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0x98, 0x02, 0x02, 0x25, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x04, 0x98, 0x02,
+    0x02, 0x35, 0x1a, 0x24, 0x20, 0x54, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77,
+    0x68, 0x6f, 0x73, 0x65, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0x98, 0x02, 0x26, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0x98, 0x02, 0x33, 0x34, 0x0a, 0x4a, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0x9c, 0x02,
+    0x00, 0xa5, 0x02, 0x01, 0x1a, 0x3c, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f,
+    0x63, 0x61, 0x6c, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x72, 0x65, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x08, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x1d, 0x0a, 0x3e,
+    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x29, 0x1a, 0x30, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x69, 0x73,
+    0x73, 0x75, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9e, 0x02, 0x1e, 0x24, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9e, 0x02, 0x27, 0x28, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x28, 0x0a, 0x5c, 0x0a, 0x04, 0x04,
+    0x0b, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x35, 0x1a, 0x4e, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x69, 0x67, 0x2d, 0x65, 0x6e, 0x64, 0x69, 0x61, 0x6e, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x2e, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x20, 0x69, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20,
+    0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x29, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xa1, 0x02, 0x33, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04,
+    0x12, 0x04, 0xa4, 0x02, 0x02, 0x0a, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x04,
+    0xa4, 0x02, 0x02, 0x3f, 0x1a, 0x3d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x69, 0x62, 0x6c, 0x69,
+    0x6e, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x74, 0x68, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x72, 0x6f, 0x6f,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa4, 0x02,
+    0x0b, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x02, 0x32,
+    0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa4, 0x02, 0x3d, 0x3e,
+    0x0a, 0x43, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0xa8, 0x02, 0x00, 0xae, 0x02, 0x01, 0x1a, 0x35,
+    0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x61, 0x20, 0x67, 0x6c, 0x6f, 0x62,
+    0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6c, 0x61, 0x69,
+    0x6d, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0xa8, 0x02,
+    0x08, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xaa, 0x02, 0x02,
+    0x08, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04, 0xaa, 0x02, 0x02, 0x18, 0x1a,
+    0x19, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64,
+    0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xaa, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xaa, 0x02, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x06, 0x12, 0x04, 0xad, 0x02, 0x02, 0x28, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12,
+    0x04, 0xad, 0x02, 0x02, 0x3a, 0x1a, 0x34, 0x20, 0x54, 0x68, 0x65, 0x20, 0x67, 0x6c, 0x6f, 0x62,
+    0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x62,
+    0x69, 0x67, 0x2d, 0x65, 0x6e, 0x64, 0x69, 0x61, 0x6e, 0x20, 0x32, 0x35, 0x36, 0x2d, 0x62, 0x69,
+    0x74, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xad, 0x02, 0x29, 0x35, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xad, 0x02, 0x38, 0x39, 0x0a, 0x52, 0x0a, 0x02, 0x04, 0x0d, 0x12,
+    0x06, 0xb1, 0x02, 0x00, 0xba, 0x02, 0x01, 0x1a, 0x44, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e,
+    0x64, 0x65, 0x78, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x20, 0x6f,
+    0x72, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6c,
+    0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x74, 0x72, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0xb1, 0x02, 0x08, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x1d, 0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x00, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x29, 0x1a, 0x30, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x69, 0x73, 0x73, 0x75, 0x65, 0x64, 0x20,
+    0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x1e, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xb3, 0x02, 0x27, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xb6, 0x02, 0x02, 0x06, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04,
+    0xb6, 0x02, 0x02, 0x13, 0x1a, 0x4f, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20,
+    0x69, 0x73, 0x20, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x2e, 0x65, 0x2e,
+    0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73,
+    0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xb6, 0x02, 0x07, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb6,
+    0x02, 0x11, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x04, 0x12, 0x04, 0xb9, 0x02,
+    0x02, 0x0a, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0xb9, 0x02, 0x02, 0x3f,
+    0x1a, 0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x74, 0x68, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6e, 0x75, 0x6c, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x72, 0x6f, 0x6f,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x06, 0x12, 0x04, 0xb9, 0x02,
+    0x0b, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb9, 0x02, 0x32,
+    0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb9, 0x02, 0x3d, 0x3e,
+    0x0a, 0x3f, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0xbd, 0x02, 0x00, 0xc3, 0x02, 0x01, 0x1a, 0x31,
+    0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x20, 0x61, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x74, 0x72, 0x65, 0x65, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0xbd, 0x02, 0x08, 0x24, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x08, 0x0a, 0x27, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x18, 0x1a, 0x19, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xbf, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xbf, 0x02, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc2,
+    0x02, 0x02, 0x08, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x02,
+    0x18, 0x1a, 0x18, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xc2, 0x02, 0x16, 0x17, 0x0a, 0x37, 0x0a, 0x02, 0x04, 0x0f, 0x12,
+    0x06, 0xc6, 0x02, 0x00, 0xcf, 0x02, 0x01, 0x1a, 0x29, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x74, 0x72, 0x65, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0xc6, 0x02, 0x08, 0x25, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x1d, 0x0a, 0x3e,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x29, 0x1a, 0x30, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x69, 0x73,
+    0x73, 0x75, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x1e, 0x24, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x27, 0x28, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x01, 0x06, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x28, 0x0a, 0x43, 0x0a, 0x04, 0x04,
+    0x0f, 0x02, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x32, 0x1a, 0x35, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x72, 0x69, 0x64,
+    0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x29, 0x2d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcb, 0x02, 0x30, 0x31, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x04, 0xce, 0x02, 0x02, 0x0a, 0x0a, 0x4e, 0x0a,
+    0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0xce, 0x02, 0x02, 0x3f, 0x1a, 0x40, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x73, 0x69, 0x62, 0x6c, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x61, 0x74, 0x68, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6c, 0x65, 0x61, 0x66, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x63, 0x61,
+    0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x02, 0x06, 0x12, 0x04, 0xce, 0x02, 0x0b, 0x31, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04, 0xce, 0x02, 0x32, 0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0xce, 0x02, 0x3d, 0x3e, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x33,
 ];
 include!("agglayer.node.v1.serde.rs");
 // @@protoc_insertion_point(module)
\ No newline at end of file
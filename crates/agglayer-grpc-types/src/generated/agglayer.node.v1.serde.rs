@@ -1,4 +1,324 @@
 // @generated
+impl serde::Serialize for GetBalanceProofErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "GET_BALANCE_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_BALANCE_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_BALANCE_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_BALANCE_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_BALANCE_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_BALANCE_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetBalanceProofErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "GET_BALANCE_PROOF_ERROR_KIND_UNSPECIFIED",
+            "GET_BALANCE_PROOF_ERROR_KIND_MISSING_FIELD",
+            "GET_BALANCE_PROOF_ERROR_KIND_INVALID_DATA",
+            "GET_BALANCE_PROOF_ERROR_KIND_NOT_FOUND",
+            "GET_BALANCE_PROOF_ERROR_KIND_UNAVAILABLE",
+            "GET_BALANCE_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetBalanceProofErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "GET_BALANCE_PROOF_ERROR_KIND_UNSPECIFIED" => Ok(GetBalanceProofErrorKind::Unspecified),
+                    "GET_BALANCE_PROOF_ERROR_KIND_MISSING_FIELD" => Ok(GetBalanceProofErrorKind::MissingField),
+                    "GET_BALANCE_PROOF_ERROR_KIND_INVALID_DATA" => Ok(GetBalanceProofErrorKind::InvalidData),
+                    "GET_BALANCE_PROOF_ERROR_KIND_NOT_FOUND" => Ok(GetBalanceProofErrorKind::NotFound),
+                    "GET_BALANCE_PROOF_ERROR_KIND_UNAVAILABLE" => Ok(GetBalanceProofErrorKind::Unavailable),
+                    "GET_BALANCE_PROOF_ERROR_KIND_INTERNAL_ERROR" => Ok(GetBalanceProofErrorKind::InternalError),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetBalanceProofRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.token_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetBalanceProofRequest", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if let Some(v) = self.token_info.as_ref() {
+            struct_ser.serialize_field("tokenInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetBalanceProofRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "token_info",
+            "tokenInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            TokenInfo,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "tokenInfo" | "token_info" => Ok(GeneratedField::TokenInfo),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetBalanceProofRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.GetBalanceProofRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetBalanceProofRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut token_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TokenInfo => {
+                            if token_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tokenInfo"));
+                            }
+                            token_info__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetBalanceProofRequest {
+                    network_id: network_id__.unwrap_or_default(),
+                    token_info: token_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.GetBalanceProofRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetBalanceProofResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        if self.balance.is_some() {
+            len += 1;
+        }
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetBalanceProofResponse", len)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            struct_ser.serialize_field("balance", v)?;
+        }
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetBalanceProofResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "anchor",
+            "balance",
+            "siblings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Anchor,
+            Balance,
+            Siblings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "balance" => Ok(GeneratedField::Balance),
+                            "siblings" => Ok(GeneratedField::Siblings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetBalanceProofResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.GetBalanceProofResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetBalanceProofResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut anchor__ = None;
+                let mut balance__ = None;
+                let mut siblings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetBalanceProofResponse {
+                    anchor: anchor__,
+                    balance: balance__,
+                    siblings: siblings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.GetBalanceProofResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetCertificateHeaderErrorKind {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -776,40 +1096,42 @@ impl<'de> serde::Deserialize<'de> for GetLatestCertificateHeaderResponse {
         deserializer.deserialize_struct("agglayer.node.v1.GetLatestCertificateHeaderResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetNetworkInfoErrorKind {
+impl serde::Serialize for GetLocalExitTreeProofErrorKind {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let variant = match self {
-            Self::Unspecified => "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED",
-            Self::InvalidNetworkId => "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID",
-            Self::NetworkInfoError => "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR",
-            Self::UnknownNetworkType => "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE",
-            Self::InternalError => "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR",
+            Self::Unspecified => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INTERNAL_ERROR",
         };
         serializer.serialize_str(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for GetNetworkInfoErrorKind {
+impl<'de> serde::Deserialize<'de> for GetLocalExitTreeProofErrorKind {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED",
-            "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID",
-            "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR",
-            "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE",
-            "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNSPECIFIED",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_MISSING_FIELD",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INVALID_DATA",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_NOT_FOUND",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNAVAILABLE",
+            "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INTERNAL_ERROR",
         ];
 
         struct GeneratedVisitor;
 
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetNetworkInfoErrorKind;
+            type Value = GetLocalExitTreeProofErrorKind;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(formatter, "expected one of: {:?}", &FIELDS)
@@ -844,11 +1166,12 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoErrorKind {
                 E: serde::de::Error,
             {
                 match value {
-                    "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED" => Ok(GetNetworkInfoErrorKind::Unspecified),
-                    "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID" => Ok(GetNetworkInfoErrorKind::InvalidNetworkId),
-                    "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR" => Ok(GetNetworkInfoErrorKind::NetworkInfoError),
-                    "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE" => Ok(GetNetworkInfoErrorKind::UnknownNetworkType),
-                    "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR" => Ok(GetNetworkInfoErrorKind::InternalError),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNSPECIFIED" => Ok(GetLocalExitTreeProofErrorKind::Unspecified),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_MISSING_FIELD" => Ok(GetLocalExitTreeProofErrorKind::MissingField),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INVALID_DATA" => Ok(GetLocalExitTreeProofErrorKind::InvalidData),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_NOT_FOUND" => Ok(GetLocalExitTreeProofErrorKind::NotFound),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_UNAVAILABLE" => Ok(GetLocalExitTreeProofErrorKind::Unavailable),
+                    "GET_LOCAL_EXIT_TREE_PROOF_ERROR_KIND_INTERNAL_ERROR" => Ok(GetLocalExitTreeProofErrorKind::InternalError),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -856,7 +1179,7 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoErrorKind {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetNetworkInfoRequest {
+impl serde::Serialize for GetLocalExitTreeProofRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -867,14 +1190,20 @@ impl serde::Serialize for GetNetworkInfoRequest {
         if self.network_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNetworkInfoRequest", len)?;
+        if self.leaf_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetLocalExitTreeProofRequest", len)?;
         if self.network_id != 0 {
             struct_ser.serialize_field("networkId", &self.network_id)?;
         }
+        if self.leaf_index != 0 {
+            struct_ser.serialize_field("leafIndex", &self.leaf_index)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
+impl<'de> serde::Deserialize<'de> for GetLocalExitTreeProofRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -883,11 +1212,14 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
         const FIELDS: &[&str] = &[
             "network_id",
             "networkId",
+            "leaf_index",
+            "leafIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             NetworkId,
+            LeafIndex,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -910,6 +1242,7 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
                     {
                         match value {
                             "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "leafIndex" | "leaf_index" => Ok(GeneratedField::LeafIndex),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -919,17 +1252,18 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetNetworkInfoRequest;
+            type Value = GetLocalExitTreeProofRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct agglayer.node.v1.GetNetworkInfoRequest")
+                formatter.write_str("struct agglayer.node.v1.GetLocalExitTreeProofRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNetworkInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetLocalExitTreeProofRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut network_id__ = None;
+                let mut leaf_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::NetworkId => {
@@ -940,17 +1274,26 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::LeafIndex => {
+                            if leaf_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leafIndex"));
+                            }
+                            leaf_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
-                Ok(GetNetworkInfoRequest {
+                Ok(GetLocalExitTreeProofRequest {
                     network_id: network_id__.unwrap_or_default(),
+                    leaf_index: leaf_index__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("agglayer.node.v1.GetNetworkInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("agglayer.node.v1.GetLocalExitTreeProofRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetNetworkInfoResponse {
+impl serde::Serialize for GetLocalExitTreeProofResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -958,30 +1301,45 @@ impl serde::Serialize for GetNetworkInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.network_info.is_some() {
+        if self.anchor.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNetworkInfoResponse", len)?;
-        if let Some(v) = self.network_info.as_ref() {
-            struct_ser.serialize_field("networkInfo", v)?;
+        if self.leaf.is_some() {
+            len += 1;
+        }
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetLocalExitTreeProofResponse", len)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if let Some(v) = self.leaf.as_ref() {
+            struct_ser.serialize_field("leaf", v)?;
+        }
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetNetworkInfoResponse {
+impl<'de> serde::Deserialize<'de> for GetLocalExitTreeProofResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "network_info",
-            "networkInfo",
+            "anchor",
+            "leaf",
+            "siblings",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NetworkInfo,
+            Anchor,
+            Leaf,
+            Siblings,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1003,7 +1361,9 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "networkInfo" | "network_info" => Ok(GeneratedField::NetworkInfo),
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "leaf" => Ok(GeneratedField::Leaf),
+                            "siblings" => Ok(GeneratedField::Siblings),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1013,145 +1373,85 @@ impl<'de> serde::Deserialize<'de> for GetNetworkInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetNetworkInfoResponse;
+            type Value = GetLocalExitTreeProofResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct agglayer.node.v1.GetNetworkInfoResponse")
+                formatter.write_str("struct agglayer.node.v1.GetLocalExitTreeProofResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNetworkInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetLocalExitTreeProofResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut network_info__ = None;
+                let mut anchor__ = None;
+                let mut leaf__ = None;
+                let mut siblings__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NetworkInfo => {
-                            if network_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("networkInfo"));
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
                             }
-                            network_info__ = map_.next_value()?;
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::Leaf => {
+                            if leaf__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("leaf"));
+                            }
+                            leaf__ = map_.next_value()?;
+                        }
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(GetNetworkInfoResponse {
-                    network_info: network_info__,
+                Ok(GetLocalExitTreeProofResponse {
+                    anchor: anchor__,
+                    leaf: leaf__,
+                    siblings: siblings__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("agglayer.node.v1.GetNetworkInfoResponse", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for LatestCertificateRequestType {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let variant = match self {
-            Self::Unspecified => "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED",
-            Self::Pending => "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING",
-            Self::Settled => "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED",
-        };
-        serializer.serialize_str(variant)
-    }
-}
-impl<'de> serde::Deserialize<'de> for LatestCertificateRequestType {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED",
-            "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING",
-            "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED",
-        ];
-
-        struct GeneratedVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LatestCertificateRequestType;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
-            }
-
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match value {
-                    "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED" => Ok(LatestCertificateRequestType::Unspecified),
-                    "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING" => Ok(LatestCertificateRequestType::Pending),
-                    "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED" => Ok(LatestCertificateRequestType::Settled),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
-                }
-            }
-        }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("agglayer.node.v1.GetLocalExitTreeProofResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SubmitCertificateErrorKind {
+impl serde::Serialize for GetNetworkInfoErrorKind {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let variant = match self {
-            Self::Unspecified => "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
-            Self::MissingField => "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
-            Self::InvalidData => "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA",
-            Self::SignatureVerification => "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION",
-            Self::UnableToReplacePendingCertificate => "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE",
-            Self::UnsupportedProofVersion => "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            Self::Unspecified => "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED",
+            Self::InvalidNetworkId => "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID",
+            Self::NetworkInfoError => "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR",
+            Self::UnknownNetworkType => "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE",
+            Self::InternalError => "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR",
         };
         serializer.serialize_str(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for SubmitCertificateErrorKind {
+impl<'de> serde::Deserialize<'de> for GetNetworkInfoErrorKind {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
-            "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
-            "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA",
-            "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION",
-            "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE",
-            "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED",
+            "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID",
+            "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR",
+            "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE",
+            "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR",
         ];
 
         struct GeneratedVisitor;
 
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SubmitCertificateErrorKind;
+            type Value = GetNetworkInfoErrorKind;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(formatter, "expected one of: {:?}", &FIELDS)
@@ -1186,12 +1486,11 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateErrorKind {
                 E: serde::de::Error,
             {
                 match value {
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED" => Ok(SubmitCertificateErrorKind::Unspecified),
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD" => Ok(SubmitCertificateErrorKind::MissingField),
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA" => Ok(SubmitCertificateErrorKind::InvalidData),
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION" => Ok(SubmitCertificateErrorKind::SignatureVerification),
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE" => Ok(SubmitCertificateErrorKind::UnableToReplacePendingCertificate),
-                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION" => Ok(SubmitCertificateErrorKind::UnsupportedProofVersion),
+                    "GET_NETWORK_INFO_ERROR_KIND_UNSPECIFIED" => Ok(GetNetworkInfoErrorKind::Unspecified),
+                    "GET_NETWORK_INFO_ERROR_KIND_INVALID_NETWORK_ID" => Ok(GetNetworkInfoErrorKind::InvalidNetworkId),
+                    "GET_NETWORK_INFO_ERROR_KIND_NETWORK_INFO_ERROR" => Ok(GetNetworkInfoErrorKind::NetworkInfoError),
+                    "GET_NETWORK_INFO_ERROR_KIND_UNKNOWN_NETWORK_TYPE" => Ok(GetNetworkInfoErrorKind::UnknownNetworkType),
+                    "GET_NETWORK_INFO_ERROR_KIND_INTERNAL_ERROR" => Ok(GetNetworkInfoErrorKind::InternalError),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -1199,7 +1498,7 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateErrorKind {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
-impl serde::Serialize for SubmitCertificateRequest {
+impl serde::Serialize for GetNetworkInfoRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1207,29 +1506,30 @@ impl serde::Serialize for SubmitCertificateRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.certificate.is_some() {
+        if self.network_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubmitCertificateRequest", len)?;
-        if let Some(v) = self.certificate.as_ref() {
-            struct_ser.serialize_field("certificate", v)?;
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNetworkInfoRequest", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SubmitCertificateRequest {
+impl<'de> serde::Deserialize<'de> for GetNetworkInfoRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "certificate",
+            "network_id",
+            "networkId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Certificate,
+            NetworkId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1251,7 +1551,7 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "certificate" => Ok(GeneratedField::Certificate),
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1261,36 +1561,38 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SubmitCertificateRequest;
+            type Value = GetNetworkInfoRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct agglayer.node.v1.SubmitCertificateRequest")
+                formatter.write_str("struct agglayer.node.v1.GetNetworkInfoRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNetworkInfoRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut certificate__ = None;
+                let mut network_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Certificate => {
-                            if certificate__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("certificate"));
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
                             }
-                            certificate__ = map_.next_value()?;
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(SubmitCertificateRequest {
-                    certificate: certificate__,
+                Ok(GetNetworkInfoRequest {
+                    network_id: network_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("agglayer.node.v1.SubmitCertificateRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("agglayer.node.v1.GetNetworkInfoRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SubmitCertificateResponse {
+impl serde::Serialize for GetNetworkInfoResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1298,30 +1600,30 @@ impl serde::Serialize for SubmitCertificateResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.certificate_id.is_some() {
+        if self.network_info.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubmitCertificateResponse", len)?;
-        if let Some(v) = self.certificate_id.as_ref() {
-            struct_ser.serialize_field("certificateId", v)?;
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNetworkInfoResponse", len)?;
+        if let Some(v) = self.network_info.as_ref() {
+            struct_ser.serialize_field("networkInfo", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SubmitCertificateResponse {
+impl<'de> serde::Deserialize<'de> for GetNetworkInfoResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "certificate_id",
-            "certificateId",
+            "network_info",
+            "networkInfo",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            CertificateId,
+            NetworkInfo,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1343,7 +1645,7 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            "networkInfo" | "network_info" => Ok(GeneratedField::NetworkInfo),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1353,32 +1655,1671 @@ impl<'de> serde::Deserialize<'de> for SubmitCertificateResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SubmitCertificateResponse;
+            type Value = GetNetworkInfoResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct agglayer.node.v1.SubmitCertificateResponse")
+                formatter.write_str("struct agglayer.node.v1.GetNetworkInfoResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNetworkInfoResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut certificate_id__ = None;
+                let mut network_info__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::CertificateId => {
-                            if certificate_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                        GeneratedField::NetworkInfo => {
+                            if network_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkInfo"));
                             }
-                            certificate_id__ = map_.next_value()?;
+                            network_info__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(SubmitCertificateResponse {
-                    certificate_id: certificate_id__,
+                Ok(GetNetworkInfoResponse {
+                    network_info: network_info__,
                 })
             }
         }
-        deserializer.deserialize_struct("agglayer.node.v1.SubmitCertificateResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("agglayer.node.v1.GetNetworkInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetNullifierProofErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "GET_NULLIFIER_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "GET_NULLIFIER_PROOF_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "GET_NULLIFIER_PROOF_ERROR_KIND_INVALID_DATA",
+            Self::NotFound => "GET_NULLIFIER_PROOF_ERROR_KIND_NOT_FOUND",
+            Self::Unavailable => "GET_NULLIFIER_PROOF_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "GET_NULLIFIER_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetNullifierProofErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "GET_NULLIFIER_PROOF_ERROR_KIND_UNSPECIFIED",
+            "GET_NULLIFIER_PROOF_ERROR_KIND_MISSING_FIELD",
+            "GET_NULLIFIER_PROOF_ERROR_KIND_INVALID_DATA",
+            "GET_NULLIFIER_PROOF_ERROR_KIND_NOT_FOUND",
+            "GET_NULLIFIER_PROOF_ERROR_KIND_UNAVAILABLE",
+            "GET_NULLIFIER_PROOF_ERROR_KIND_INTERNAL_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetNullifierProofErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_UNSPECIFIED" => Ok(GetNullifierProofErrorKind::Unspecified),
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_MISSING_FIELD" => Ok(GetNullifierProofErrorKind::MissingField),
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_INVALID_DATA" => Ok(GetNullifierProofErrorKind::InvalidData),
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_NOT_FOUND" => Ok(GetNullifierProofErrorKind::NotFound),
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_UNAVAILABLE" => Ok(GetNullifierProofErrorKind::Unavailable),
+                    "GET_NULLIFIER_PROOF_ERROR_KIND_INTERNAL_ERROR" => Ok(GetNullifierProofErrorKind::InternalError),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetNullifierProofRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.global_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNullifierProofRequest", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if let Some(v) = self.global_index.as_ref() {
+            struct_ser.serialize_field("globalIndex", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetNullifierProofRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "global_index",
+            "globalIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            GlobalIndex,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "globalIndex" | "global_index" => Ok(GeneratedField::GlobalIndex),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetNullifierProofRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.GetNullifierProofRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNullifierProofRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut global_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GlobalIndex => {
+                            if global_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("globalIndex"));
+                            }
+                            global_index__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetNullifierProofRequest {
+                    network_id: network_id__.unwrap_or_default(),
+                    global_index: global_index__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.GetNullifierProofRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetNullifierProofResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        if self.claimed {
+            len += 1;
+        }
+        if !self.siblings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.GetNullifierProofResponse", len)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if self.claimed {
+            struct_ser.serialize_field("claimed", &self.claimed)?;
+        }
+        if !self.siblings.is_empty() {
+            struct_ser.serialize_field("siblings", &self.siblings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetNullifierProofResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "anchor",
+            "claimed",
+            "siblings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Anchor,
+            Claimed,
+            Siblings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "claimed" => Ok(GeneratedField::Claimed),
+                            "siblings" => Ok(GeneratedField::Siblings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetNullifierProofResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.GetNullifierProofResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNullifierProofResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut anchor__ = None;
+                let mut claimed__ = None;
+                let mut siblings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::Claimed => {
+                            if claimed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimed"));
+                            }
+                            claimed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Siblings => {
+                            if siblings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("siblings"));
+                            }
+                            siblings__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetNullifierProofResponse {
+                    anchor: anchor__,
+                    claimed: claimed__.unwrap_or_default(),
+                    siblings: siblings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.GetNullifierProofResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LatestCertificateRequestType {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED",
+            Self::Pending => "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING",
+            Self::Settled => "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for LatestCertificateRequestType {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED",
+            "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING",
+            "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LatestCertificateRequestType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "LATEST_CERTIFICATE_REQUEST_TYPE_UNSPECIFIED" => Ok(LatestCertificateRequestType::Unspecified),
+                    "LATEST_CERTIFICATE_REQUEST_TYPE_PENDING" => Ok(LatestCertificateRequestType::Pending),
+                    "LATEST_CERTIFICATE_REQUEST_TYPE_SETTLED" => Ok(LatestCertificateRequestType::Settled),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListCertificateHeadersErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_UNSPECIFIED",
+            Self::InvalidData => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INVALID_DATA",
+            Self::InternalError => "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INTERNAL_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListCertificateHeadersErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_UNSPECIFIED",
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INVALID_DATA",
+            "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INTERNAL_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListCertificateHeadersErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "LIST_CERTIFICATE_HEADERS_ERROR_KIND_UNSPECIFIED" => Ok(ListCertificateHeadersErrorKind::Unspecified),
+                    "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INVALID_DATA" => Ok(ListCertificateHeadersErrorKind::InvalidData),
+                    "LIST_CERTIFICATE_HEADERS_ERROR_KIND_INTERNAL_ERROR" => Ok(ListCertificateHeadersErrorKind::InternalError),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListCertificateHeadersRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.from_height != 0 {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        if self.direction != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.ListCertificateHeadersRequest", len)?;
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if self.from_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("fromHeight", ToString::to_string(&self.from_height).as_str())?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
+        if self.direction != 0 {
+            let v = ListDirection::try_from(self.direction)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.direction)))?;
+            struct_ser.serialize_field("direction", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListCertificateHeadersRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "from_height",
+            "fromHeight",
+            "limit",
+            "direction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            FromHeight,
+            Limit,
+            Direction,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "fromHeight" | "from_height" => Ok(GeneratedField::FromHeight),
+                            "limit" => Ok(GeneratedField::Limit),
+                            "direction" => Ok(GeneratedField::Direction),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListCertificateHeadersRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.ListCertificateHeadersRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListCertificateHeadersRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut from_height__ = None;
+                let mut limit__ = None;
+                let mut direction__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FromHeight => {
+                            if from_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fromHeight"));
+                            }
+                            from_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Direction => {
+                            if direction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("direction"));
+                            }
+                            direction__ = Some(map_.next_value::<ListDirection>()? as i32);
+                        }
+                    }
+                }
+                Ok(ListCertificateHeadersRequest {
+                    network_id: network_id__.unwrap_or_default(),
+                    from_height: from_height__.unwrap_or_default(),
+                    limit: limit__.unwrap_or_default(),
+                    direction: direction__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.ListCertificateHeadersRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListCertificateHeadersResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.certificate_headers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.ListCertificateHeadersResponse", len)?;
+        if !self.certificate_headers.is_empty() {
+            struct_ser.serialize_field("certificateHeaders", &self.certificate_headers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListCertificateHeadersResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_headers",
+            "certificateHeaders",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateHeaders,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateHeaders" | "certificate_headers" => Ok(GeneratedField::CertificateHeaders),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListCertificateHeadersResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.ListCertificateHeadersResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListCertificateHeadersResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_headers__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateHeaders => {
+                            if certificate_headers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateHeaders"));
+                            }
+                            certificate_headers__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListCertificateHeadersResponse {
+                    certificate_headers: certificate_headers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.ListCertificateHeadersResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListDirection {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "LIST_DIRECTION_UNSPECIFIED",
+            Self::Ascending => "LIST_DIRECTION_ASCENDING",
+            Self::Descending => "LIST_DIRECTION_DESCENDING",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListDirection {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "LIST_DIRECTION_UNSPECIFIED",
+            "LIST_DIRECTION_ASCENDING",
+            "LIST_DIRECTION_DESCENDING",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListDirection;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "LIST_DIRECTION_UNSPECIFIED" => Ok(ListDirection::Unspecified),
+                    "LIST_DIRECTION_ASCENDING" => Ok(ListDirection::Ascending),
+                    "LIST_DIRECTION_DESCENDING" => Ok(ListDirection::Descending),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateCertificateErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SIMULATE_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "SIMULATE_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "SIMULATE_CERTIFICATE_ERROR_KIND_INVALID_DATA",
+            Self::UnsupportedProofVersion => "SIMULATE_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            Self::UnexpectedHeight => "SIMULATE_CERTIFICATE_ERROR_KIND_UNEXPECTED_HEIGHT",
+            Self::Unavailable => "SIMULATE_CERTIFICATE_ERROR_KIND_UNAVAILABLE",
+            Self::InternalError => "SIMULATE_CERTIFICATE_ERROR_KIND_INTERNAL_ERROR",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateCertificateErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_INVALID_DATA",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNEXPECTED_HEIGHT",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_UNAVAILABLE",
+            "SIMULATE_CERTIFICATE_ERROR_KIND_INTERNAL_ERROR",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateCertificateErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_UNSPECIFIED" => Ok(SimulateCertificateErrorKind::Unspecified),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_MISSING_FIELD" => Ok(SimulateCertificateErrorKind::MissingField),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_INVALID_DATA" => Ok(SimulateCertificateErrorKind::InvalidData),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION" => Ok(SimulateCertificateErrorKind::UnsupportedProofVersion),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_UNEXPECTED_HEIGHT" => Ok(SimulateCertificateErrorKind::UnexpectedHeight),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_UNAVAILABLE" => Ok(SimulateCertificateErrorKind::Unavailable),
+                    "SIMULATE_CERTIFICATE_ERROR_KIND_INTERNAL_ERROR" => Ok(SimulateCertificateErrorKind::InternalError),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateCertificateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SimulateCertificateRequest", len)?;
+        if let Some(v) = self.certificate.as_ref() {
+            struct_ser.serialize_field("certificate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateCertificateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Certificate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificate" => Ok(GeneratedField::Certificate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateCertificateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SimulateCertificateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateCertificateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Certificate => {
+                            if certificate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificate"));
+                            }
+                            certificate__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SimulateCertificateRequest {
+                    certificate: certificate__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SimulateCertificateRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateCertificateResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        if self.outcome.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SimulateCertificateResponse", len)?;
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        if let Some(v) = self.outcome.as_ref() {
+            match v {
+                simulate_certificate_response::Outcome::Roots(v) => {
+                    struct_ser.serialize_field("roots", v)?;
+                }
+                simulate_certificate_response::Outcome::Error(v) => {
+                    struct_ser.serialize_field("error", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateCertificateResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_id",
+            "certificateId",
+            "roots",
+            "error",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateId,
+            Roots,
+            Error,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            "roots" => Ok(GeneratedField::Roots),
+                            "error" => Ok(GeneratedField::Error),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateCertificateResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SimulateCertificateResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateCertificateResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_id__ = None;
+                let mut outcome__ : std::option::Option<simulate_certificate_response::Outcome> = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Roots => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("roots"));
+                            }
+                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_certificate_response::Outcome::Roots)
+;
+                        }
+                        GeneratedField::Error => {
+                            if outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            outcome__ = map_.next_value::<::std::option::Option<_>>()?.map(simulate_certificate_response::Outcome::Error)
+;
+                        }
+                    }
+                }
+                Ok(SimulateCertificateResponse {
+                    certificate_id: certificate_id__,
+                    outcome: outcome__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SimulateCertificateResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitCertificateErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA",
+            Self::SignatureVerification => "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION",
+            Self::UnableToReplacePendingCertificate => "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE",
+            Self::UnsupportedProofVersion => "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            Self::RateLimited => "SUBMIT_CERTIFICATE_ERROR_KIND_RATE_LIMITED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitCertificateErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION",
+            "SUBMIT_CERTIFICATE_ERROR_KIND_RATE_LIMITED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitCertificateErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNSPECIFIED" => Ok(SubmitCertificateErrorKind::Unspecified),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_MISSING_FIELD" => Ok(SubmitCertificateErrorKind::MissingField),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_INVALID_DATA" => Ok(SubmitCertificateErrorKind::InvalidData),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_SIGNATURE_VERIFICATION" => Ok(SubmitCertificateErrorKind::SignatureVerification),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNABLE_TO_REPLACE_PENDING_CERTIFICATE" => Ok(SubmitCertificateErrorKind::UnableToReplacePendingCertificate),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_UNSUPPORTED_PROOF_VERSION" => Ok(SubmitCertificateErrorKind::UnsupportedProofVersion),
+                    "SUBMIT_CERTIFICATE_ERROR_KIND_RATE_LIMITED" => Ok(SubmitCertificateErrorKind::RateLimited),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitCertificateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubmitCertificateRequest", len)?;
+        if let Some(v) = self.certificate.as_ref() {
+            struct_ser.serialize_field("certificate", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitCertificateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Certificate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificate" => Ok(GeneratedField::Certificate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitCertificateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SubmitCertificateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Certificate => {
+                            if certificate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificate"));
+                            }
+                            certificate__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubmitCertificateRequest {
+                    certificate: certificate__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SubmitCertificateRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmitCertificateResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubmitCertificateResponse", len)?;
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmitCertificateResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_id",
+            "certificateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmitCertificateResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SubmitCertificateResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitCertificateResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubmitCertificateResponse {
+                    certificate_id: certificate_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SubmitCertificateResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubscribeCertificateStatusErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_UNSPECIFIED",
+            Self::MissingField => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_MISSING_FIELD",
+            Self::InvalidData => "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_INVALID_DATA",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubscribeCertificateStatusErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_UNSPECIFIED",
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_MISSING_FIELD",
+            "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_INVALID_DATA",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubscribeCertificateStatusErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_UNSPECIFIED" => Ok(SubscribeCertificateStatusErrorKind::Unspecified),
+                    "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_MISSING_FIELD" => Ok(SubscribeCertificateStatusErrorKind::MissingField),
+                    "SUBSCRIBE_CERTIFICATE_STATUS_ERROR_KIND_INVALID_DATA" => Ok(SubscribeCertificateStatusErrorKind::InvalidData),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubscribeCertificateStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.network_id.is_some() {
+            len += 1;
+        }
+        if self.certificate_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubscribeCertificateStatusRequest", len)?;
+        if let Some(v) = self.network_id.as_ref() {
+            struct_ser.serialize_field("networkId", v)?;
+        }
+        if let Some(v) = self.certificate_id.as_ref() {
+            struct_ser.serialize_field("certificateId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubscribeCertificateStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "network_id",
+            "networkId",
+            "certificate_id",
+            "certificateId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NetworkId,
+            CertificateId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "certificateId" | "certificate_id" => Ok(GeneratedField::CertificateId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubscribeCertificateStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SubscribeCertificateStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubscribeCertificateStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut network_id__ = None;
+                let mut certificate_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::CertificateId => {
+                            if certificate_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateId"));
+                            }
+                            certificate_id__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubscribeCertificateStatusRequest {
+                    network_id: network_id__,
+                    certificate_id: certificate_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SubscribeCertificateStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubscribeCertificateStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.certificate_header.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("agglayer.node.v1.SubscribeCertificateStatusResponse", len)?;
+        if let Some(v) = self.certificate_header.as_ref() {
+            struct_ser.serialize_field("certificateHeader", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubscribeCertificateStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "certificate_header",
+            "certificateHeader",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CertificateHeader,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "certificateHeader" | "certificate_header" => Ok(GeneratedField::CertificateHeader),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubscribeCertificateStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct agglayer.node.v1.SubscribeCertificateStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubscribeCertificateStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut certificate_header__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CertificateHeader => {
+                            if certificate_header__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("certificateHeader"));
+                            }
+                            certificate_header__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SubscribeCertificateStatusResponse {
+                    certificate_header: certificate_header__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("agglayer.node.v1.SubscribeCertificateStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
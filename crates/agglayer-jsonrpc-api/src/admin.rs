@@ -1,21 +1,31 @@
-use std::sync::Arc;
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
 use agglayer_config::Config;
+use agglayer_rpc::{
+    network_rollback::{NetworkRollbackPlan, RollbackNetwork},
+    state_verification::{NetworkStateVerification, VerifyState},
+};
 use agglayer_settlement_service::SettlementService;
 use agglayer_storage::stores::{
-    DebugReader, DebugWriter, PendingCertificateReader, PendingCertificateWriter, SettlementReader,
-    SettlementWriter, StateReader, StateWriter,
+    AdminAuditReader, AdminAuditWriter, DebugReader, DebugWriter, PendingCertificateReader,
+    PendingCertificateWriter, SettlementReader, SettlementWriter, StateReader, StateWriter,
 };
 use agglayer_tries::smt::SmtPath;
 use agglayer_types::{
-    Address, Certificate, CertificateHeader, CertificateId, CertificateStatus,
-    CertificateStatusError, Digest, Height, NetworkId, RpcErrorCode, SettlementJobId, U256,
+    Address, AdminAuditEvent, AdminAuditOutcome, Certificate, CertificateHeader, CertificateId,
+    CertificateStatus, CertificateStatusError, Digest, Height, NetworkId, RpcErrorCode,
+    SettlementAttemptResult, SettlementJobId, U256,
 };
 use alloy::providers::{Provider, WalletProvider};
 use eyre::Context as _;
-use jsonrpsee::{core::async_trait, proc_macros::rpc, server::ServerBuilder};
+use jsonrpsee::{core::async_trait, proc_macros::rpc, server::ServerBuilder, Extensions};
 use pessimistic_proof::local_balance_tree::BalanceTree;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use tokio::sync::mpsc;
 use tower_http::{compression::CompressionLayer, cors::CorsLayer};
 use tracing::{debug, error, info, instrument, warn};
@@ -23,12 +33,15 @@ use unified_bridge::TokenInfo;
 
 use super::error::RpcResult;
 use crate::{
+    admin_audit::{self, AdminAuditEventDto, AuditedResponse},
+    admin_auth::{self, AdminAuthenticator},
     error::Error,
-    rpc_middleware,
+    rpc_middleware::{self, AdminAuthorizationLayer},
     settlement_admin::{
         edit_even_if_completed, map_admin_error, render_last_error, Force, InsertAttemptParams,
-        MutationResponse, SettlementAttemptDetail, SettlementJobDetail, SettlementJobResultDto,
-        SettlementJobStatus, SettlementJobSummary,
+        MutationResponse, SettlementAttemptDetail, SettlementCostReport, SettlementCostSample,
+        SettlementCostShare, SettlementJobDetail, SettlementJobResultDto, SettlementJobStatus,
+        SettlementJobSummary,
     },
     JsonRpcService,
 };
@@ -37,7 +50,7 @@ use crate::{
 /// after edits are applied.
 ///
 /// Passed as the `process_now` parameter of `admin_forceEditCertificate`.
-#[derive(Debug, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum ProcessNow {
     /// Reprocess the certificate immediately after edits.
     ///
@@ -101,9 +114,10 @@ pub(crate) trait AdminAgglayer {
         certificate_id: CertificateId,
     ) -> RpcResult<(Certificate, Option<CertificateHeader>)>;
 
-    #[method(name = "forcePushPendingCertificate")]
+    #[method(name = "forcePushPendingCertificate", with_extensions)]
     async fn force_push_pending_certificate(
         &self,
+        extensions: &Extensions,
         certificate: Certificate,
         status: CertificateStatus,
     ) -> RpcResult<()>;
@@ -171,9 +185,10 @@ pub(crate) trait AdminAgglayer {
     /// | `INVALID_PARAMS` | Malformed operation string; `from=` mismatch; attempting to edit a `Settled` certificate; the retired `set-settlement-tx-hash` operation |
     /// | `ResourceNotFound` (`-10008`) | No certificate header found for `certificate_id` |
     /// | `INTERNAL_ERROR` | Storage read/write failure; orchestrator channel failure |
-    #[method(name = "forceEditCertificate")]
+    #[method(name = "forceEditCertificate", with_extensions)]
     async fn force_edit_certificate(
         &self,
+        extensions: &Extensions,
         certificate_id: CertificateId,
         process_now: ProcessNow,
         operation_1: Option<String>,
@@ -184,29 +199,47 @@ pub(crate) trait AdminAgglayer {
         // * (un)set settlement tx hash.
     ) -> RpcResult<()>;
 
-    #[method(name = "setLatestPendingCertificate")]
-    async fn set_latest_pending_certificate(&self, certificate_id: CertificateId) -> RpcResult<()>;
+    #[method(name = "setLatestPendingCertificate", with_extensions)]
+    async fn set_latest_pending_certificate(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()>;
 
-    #[method(name = "setLatestProvenCertificate")]
-    async fn set_latest_proven_certificate(&self, certificate_id: CertificateId) -> RpcResult<()>;
+    #[method(name = "setLatestProvenCertificate", with_extensions)]
+    async fn set_latest_proven_certificate(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()>;
 
-    #[method(name = "removePendingCertificate")]
+    #[method(name = "removePendingCertificate", with_extensions)]
     async fn remove_pending_certificate(
         &self,
+        extensions: &Extensions,
         network_id: NetworkId,
         height: Height,
         remove_proof: bool,
     ) -> RpcResult<()>;
 
-    #[method(name = "removePendingProof")]
-    async fn remove_pending_proof(&self, certificate_id: CertificateId) -> RpcResult<()>;
+    #[method(name = "removePendingProof", with_extensions)]
+    async fn remove_pending_proof(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()>;
 
     #[method(name = "getDisabledNetworks")]
     async fn get_disabled_networks(&self) -> RpcResult<Vec<NetworkId>>;
-    #[method(name = "disableNetwork")]
-    async fn disable_network(&self, network_id: NetworkId) -> RpcResult<()>;
-    #[method(name = "enableNetwork")]
-    async fn enable_network(&self, network_id: NetworkId) -> RpcResult<()>;
+    #[method(name = "disableNetwork", with_extensions)]
+    async fn disable_network(
+        &self,
+        extensions: &Extensions,
+        network_id: NetworkId,
+    ) -> RpcResult<()>;
+    #[method(name = "enableNetwork", with_extensions)]
+    async fn enable_network(&self, extensions: &Extensions, network_id: NetworkId)
+        -> RpcResult<()>;
 
     /// Stop the in-memory settlement task for a job.
     ///
@@ -225,8 +258,12 @@ pub(crate) trait AdminAgglayer {
     /// Unknown job IDs return `RpcErrorCode::NotFound`'s code; completed jobs
     /// return `RpcErrorCode::AlreadyCompleted`'s code; and pending jobs without
     /// a task return `RpcErrorCode::NoLiveTask`'s code.
-    #[method(name = "abortSettlementTask")]
-    async fn abort_settlement_task(&self, job_id: SettlementJobId) -> RpcResult<()>;
+    #[method(name = "abortSettlementTask", with_extensions)]
+    async fn abort_settlement_task(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()>;
 
     /// Reload a settlement task's state from storage, respawning it if needed.
     ///
@@ -247,8 +284,12 @@ pub(crate) trait AdminAgglayer {
     /// completed jobs return `RpcErrorCode::AlreadyCompleted`'s code; and a
     /// full task command queue, task teardown in progress, or failed storage
     /// reload returns `RpcErrorCode::Unavailable`'s code.
-    #[method(name = "reloadSettlementTask")]
-    async fn reload_settlement_task(&self, job_id: SettlementJobId) -> RpcResult<()>;
+    #[method(name = "reloadSettlementTask", with_extensions)]
+    async fn reload_settlement_task(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()>;
 
     /// List every settlement job known to storage.
     ///
@@ -282,6 +323,27 @@ pub(crate) trait AdminAgglayer {
     #[method(name = "getSettlementJob")]
     async fn get_settlement_job(&self, job_id: SettlementJobId) -> RpcResult<SettlementJobDetail>;
 
+    /// Report the gas paid for settlement transactions, in total and per
+    /// network, wallet and epoch.
+    ///
+    /// **JSON-RPC method:** `admin_getSettlementCostReport`
+    ///
+    /// Covers the mined attempts, successful or reverted, submitted in
+    /// `[from_unix_secs, to_unix_secs)`, of every job or only of the jobs of
+    /// `network_id`. A job's epoch is the one of the certificate it settles.
+    /// Attempts mined before gas costs were recorded are counted in
+    /// `uncostedTransactions` only.
+    ///
+    /// Like `admin_listSettlementJobs`, this scans every settlement job, and
+    /// fails on the first one that cannot be read.
+    #[method(name = "getSettlementCostReport")]
+    async fn get_settlement_cost_report(
+        &self,
+        network_id: Option<NetworkId>,
+        from_unix_secs: u64,
+        to_unix_secs: u64,
+    ) -> RpcResult<SettlementCostReport>;
+
     /// Append one new settlement attempt to a settlement job.
     ///
     /// **JSON-RPC method:** `admin_insertSettlementAttempt`
@@ -300,9 +362,10 @@ pub(crate) trait AdminAgglayer {
     /// store-assigned number. It fails if the job does not exist, or if it
     /// already has a terminal result and `force` is not `"force=true"` (see
     /// [`Force`]).
-    #[method(name = "insertSettlementAttempt")]
+    #[method(name = "insertSettlementAttempt", with_extensions)]
     async fn insert_settlement_attempt(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt: InsertAttemptParams,
         force: Option<Force>,
@@ -329,9 +392,10 @@ pub(crate) trait AdminAgglayer {
     /// reports whether a reload command was queued, with no promptness
     /// promise (see [`MutationResponse`]). Follow the abort → edit → reload
     /// flow when the task must not act on stale state.
-    #[method(name = "markSettlementAttemptDefinitelyFailed")]
+    #[method(name = "markSettlementAttemptDefinitelyFailed", with_extensions)]
     async fn mark_settlement_attempt_definitely_failed(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt_number: u64,
         reason: String,
@@ -352,9 +416,10 @@ pub(crate) trait AdminAgglayer {
     /// reports whether a reload command was queued, with no promptness
     /// promise (see [`MutationResponse`]). Follow the abort → edit → reload
     /// flow when the task must not act on stale state.
-    #[method(name = "removeSettlementAttemptResult")]
+    #[method(name = "removeSettlementAttemptResult", with_extensions)]
     async fn remove_settlement_attempt_result(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt_number: u64,
         force: Option<Force>,
@@ -383,8 +448,93 @@ pub(crate) trait AdminAgglayer {
     /// certificate processing for the associated job is quiesced; otherwise a
     /// certificate task could still act on the removed result while the fresh
     /// settlement task re-drives the job.
-    #[method(name = "forceRemoveSettlementJobResult")]
-    async fn force_remove_settlement_job_result(&self, job_id: SettlementJobId) -> RpcResult<()>;
+    #[method(name = "forceRemoveSettlementJobResult", with_extensions)]
+    async fn force_remove_settlement_job_result(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()>;
+
+    /// List the recorded admin mutations, oldest first.
+    ///
+    /// **JSON-RPC method:** `admin_listAuditEvents`
+    ///
+    /// Returns the first `limit` events recorded in `[from_unix_secs,
+    /// to_unix_secs)`, only those of `method` (e.g. `admin_disableNetwork`)
+    /// when given. The `limit` defaults to, and is clamped to, 1000 events.
+    /// Every mutating admin method is recorded once it returns, with its
+    /// caller, parameters, the touched record before and after, and whether
+    /// it was applied. Refused calls are recorded too, with their error.
+    #[method(name = "listAuditEvents")]
+    async fn list_audit_events(
+        &self,
+        from_unix_secs: u64,
+        to_unix_secs: u64,
+        method: Option<String>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<AdminAuditEventDto>>;
+
+    /// Rebuild the local network states by replaying their settled
+    /// certificates, and compare them with the persisted trees and with the
+    /// pessimistic roots settled on L1.
+    ///
+    /// **JSON-RPC method:** `admin_verifyState`
+    ///
+    /// Verifies `network_id`, or every network having settled a certificate.
+    /// Each report holds the first divergence found, if any. The whole history
+    /// of the networks is replayed and L1 is queried once per settled
+    /// certificate, so this is slow on long-lived networks.
+    #[method(name = "verifyState")]
+    async fn verify_state(
+        &self,
+        network_id: Option<NetworkId>,
+    ) -> RpcResult<Vec<NetworkStateVerification>>;
+
+    /// Roll a network back to its settled certificate at `height`.
+    ///
+    /// **JSON-RPC method:** `admin_rollbackNetwork`
+    ///
+    /// Without `plan_hash`, nothing is written: the plan of the rollback is
+    /// returned, listing the settled and pending certificates above `height`
+    /// that it removes, the settlement jobs it cancels, and the roots of the
+    /// state rebuilt at `height` by replaying the settled certificates. Call
+    /// again with the returned `planHash` to apply that exact plan. The plan
+    /// is recomputed and the call refused if anything changed in between.
+    ///
+    /// The network must be disabled with `admin_disableNetwork` to apply a
+    /// plan. The trees, the settled certificate index, and the latest pending
+    /// and proven certificates of the network are reset, and the headers,
+    /// proofs and settlement job links of the removed certificates are
+    /// deleted. The settlement jobs themselves and the epochs are kept for
+    /// audit.
+    ///
+    /// The live tasks of the cancelled settlement jobs are aborted like with
+    /// `admin_abortSettlementTask`, and the jobs are recorded as cancelled
+    /// along with the rollback, so that they aren't respawned at restart. The
+    /// attempts they broadcast may still land on L1: the applied plan lists
+    /// them in `broadcastSettlementAttempts`, to be watched or replaced by
+    /// the operator. The node must also be restarted before enabling the
+    /// network again, as its certificate processing holds the network state
+    /// in memory.
+    ///
+    /// Settlement batches also settle the certificates of other networks, so
+    /// they are never cancelled: the rollback is refused while a batch that
+    /// has not completed holds one of the cancelled jobs.
+    ///
+    /// # Errors
+    ///
+    /// Heights above the latest settled one, a mismatching `plan_hash`, an
+    /// enabled network or an open settlement batch holding a cancelled job
+    /// return an invalid argument error. The method is
+    /// disabled when the node isn't configured with a rollback service.
+    #[method(name = "rollbackNetwork", with_extensions)]
+    async fn rollback_network(
+        &self,
+        extensions: &Extensions,
+        network_id: NetworkId,
+        height: Height,
+        plan_hash: Option<Digest>,
+    ) -> RpcResult<NetworkRollbackPlan>;
 }
 
 /// The Admin RPC agglayer service implementation.
@@ -395,6 +545,8 @@ pub struct AdminAgglayerImpl<PendingStore, StateStore, DebugStore, L1Provider> {
     debug_store: Arc<DebugStore>,
     config: Arc<Config>,
     settlement_service: SettlementService<L1Provider, StateStore>,
+    state_verifier: Option<Arc<dyn VerifyState>>,
+    network_rollback: Option<Arc<dyn RollbackNetwork>>,
 }
 
 impl<PendingStore, StateStore, DebugStore, L1Provider>
@@ -416,15 +568,35 @@ impl<PendingStore, StateStore, DebugStore, L1Provider>
             debug_store,
             config,
             settlement_service,
+            state_verifier: None,
+            network_rollback: None,
         }
     }
+
+    /// Enable `admin_verifyState` with the given verifier.
+    pub fn with_state_verifier(mut self, state_verifier: Arc<dyn VerifyState>) -> Self {
+        self.state_verifier = Some(state_verifier);
+        self
+    }
+
+    /// Enable `admin_rollbackNetwork` with the given rollback service.
+    pub fn with_network_rollback(mut self, network_rollback: Arc<dyn RollbackNetwork>) -> Self {
+        self.network_rollback = Some(network_rollback);
+        self
+    }
 }
 
 impl<PendingStore, StateStore, DebugStore, L1Provider>
     AdminAgglayerImpl<PendingStore, StateStore, DebugStore, L1Provider>
 where
     PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
-    StateStore: StateReader + StateWriter + SettlementReader + SettlementWriter + 'static,
+    StateStore: StateReader
+        + StateWriter
+        + SettlementReader
+        + SettlementWriter
+        + AdminAuditReader
+        + AdminAuditWriter
+        + 'static,
     DebugStore: DebugReader + DebugWriter + 'static,
     L1Provider: Provider + WalletProvider + 'static,
 {
@@ -462,7 +634,7 @@ where
                 hyper::Method::OPTIONS,
             ])
             .allow_origin(tower_http::cors::Any)
-            .allow_headers([hyper::header::CONTENT_TYPE]);
+            .allow_headers([hyper::header::AUTHORIZATION, hyper::header::CONTENT_TYPE]);
 
         // Create a middleware stack with the CORS middleware and a proxy layer for
         // health checks.
@@ -470,8 +642,31 @@ where
             .layer(CompressionLayer::new())
             .layer(cors);
 
-        let service_builder =
-            server_builder.set_rpc_middleware(rpc_middleware::from_config(&config));
+        let authenticator = match &config.rpc.admin_auth {
+            Some(admin_auth) => {
+                if admin_auth.tls.is_none() {
+                    warn!(
+                        "Admin authentication is enabled without TLS: credentials are sent in \
+                         clear text"
+                    );
+                }
+                Some(Arc::new(
+                    AdminAuthenticator::from_config(admin_auth)
+                        .wrap_err("Failed to set up admin authentication")?,
+                ))
+            }
+            None => {
+                warn!(
+                    "Admin RPC authentication is disabled, configure [rpc.admin-auth] to enable it"
+                );
+                None
+            }
+        };
+
+        let service_builder = server_builder.set_rpc_middleware(
+            rpc_middleware::from_config(&config)
+                .layer(AdminAuthorizationLayer::new(authenticator.is_some())),
+        );
         let (stop_handle, server_handle) = jsonrpsee::server::stop_channel();
         std::mem::forget(server_handle);
 
@@ -482,10 +677,17 @@ where
                 .build(service, stop_handle),
         };
 
-        Ok(axum::Router::new()
+        let mut router = axum::Router::new()
             .route("/", axum::routing::get_service(service.clone()))
-            .route("/", axum::routing::post_service(service.clone()))
-            .layer(middleware))
+            .route("/", axum::routing::post_service(service.clone()));
+        if let Some(authenticator) = authenticator {
+            router = router.layer(axum::middleware::from_fn_with_state(
+                authenticator,
+                admin_auth::authenticate_request,
+            ));
+        }
+
+        Ok(router.layer(middleware))
     }
 
     fn read_settlement_job(
@@ -505,6 +707,10 @@ where
             .wrap_err_with(|| {
                 format!("Failed to read terminal result for settlement job {job_id}")
             })?;
+        let cancellation = self
+            .state
+            .get_settlement_job_cancellation(&job_id)
+            .wrap_err_with(|| format!("Failed to read cancellation of settlement job {job_id}"))?;
         let attempts = self
             .state
             .list_settlement_attempts(&job_id)
@@ -538,6 +744,8 @@ where
             has_live_task: self.settlement_service.has_live_task(job_id),
             status: if job_result.is_some() {
                 SettlementJobStatus::Completed
+            } else if cancellation.is_some() {
+                SettlementJobStatus::Cancelled
             } else {
                 SettlementJobStatus::Pending
             },
@@ -545,6 +753,7 @@ where
             eth_value: job.eth_value,
             gas_limit: job.gas_limit,
             calldata: job.calldata,
+            network_id: job.network_id,
             attempts,
             job_result: job_result.as_ref().map(SettlementJobResultDto::from),
             last_error: render_last_error(&attempt_results),
@@ -562,6 +771,204 @@ where
             .map(|job_id| summarize_settlement_job(job_id, self.read_settlement_job(job_id)))
             .collect())
     }
+
+    /// Runs an admin mutation and records it in the audit log.
+    ///
+    /// `snapshot` renders the record touched by the mutation as JSON. It is
+    /// read before the mutation and once it returned, failed or not, since a
+    /// failed mutation can have been partially applied. Failing to record the
+    /// event is logged but does not fail the call, which already took effect.
+    async fn audited<T: AuditedResponse>(
+        &self,
+        extensions: &Extensions,
+        method: &str,
+        network_id: Option<NetworkId>,
+        params: serde_json::Value,
+        snapshot: impl Fn() -> Option<String>,
+        mutation: impl Future<Output = RpcResult<T>>,
+    ) -> RpcResult<T> {
+        let before = snapshot();
+        let result = mutation.await;
+        let after = snapshot();
+
+        let event = AdminAuditEvent {
+            recorded_at: SystemTime::now(),
+            caller: admin_audit::caller(extensions),
+            method: method.to_string(),
+            network_id,
+            params: admin_audit::to_json(&params),
+            before,
+            after,
+            outcome: match &result {
+                Ok(response) => AdminAuditOutcome::Applied {
+                    live_task: response.live_task(),
+                },
+                Err(error) => AdminAuditOutcome::Failed {
+                    error: error.to_string(),
+                },
+            },
+        };
+        if let Err(error) = self.state.append_admin_audit_event(&event) {
+            error!(?error, ?event, "Failed to record admin audit event");
+        }
+
+        result
+    }
+
+    /// The network of a certificate, if its header can be read.
+    fn certificate_network(&self, certificate_id: &CertificateId) -> Option<NetworkId> {
+        self.state
+            .get_certificate_header(certificate_id)
+            .ok()?
+            .map(|header| header.network_id)
+    }
+
+    fn certificate_header_json(&self, certificate_id: &CertificateId) -> Option<String> {
+        self.state
+            .get_certificate_header(certificate_id)
+            .ok()?
+            .map(|header| admin_audit::to_json(&header))
+    }
+
+    fn network_disabled_json(&self, network_id: &NetworkId) -> Option<String> {
+        let disabled = self.state.is_network_disabled(network_id).ok()?;
+        Some(admin_audit::to_json(&json!({ "disabled": disabled })))
+    }
+
+    fn network_heights_json(&self, network_id: &NetworkId) -> Option<String> {
+        let settled = self
+            .state
+            .get_latest_settled_certificate_per_network(network_id)
+            .ok()?
+            .map(|(_, settled)| json!({ "certificateId": settled.0, "height": settled.1 }));
+        let pending = self
+            .pending_store
+            .get_latest_pending_certificate_for_network(network_id)
+            .ok()?
+            .map(|(certificate_id, height)| {
+                json!({ "certificateId": certificate_id, "height": height })
+            });
+        Some(admin_audit::to_json(
+            &json!({ "settled": settled, "pending": pending }),
+        ))
+    }
+
+    /// The network of a settlement job, if the job can be read.
+    fn settlement_job_network(&self, job_id: &SettlementJobId) -> Option<NetworkId> {
+        self.state.get_settlement_job(job_id).ok()??.network_id
+    }
+
+    fn settlement_job_json(&self, job_id: SettlementJobId) -> Option<String> {
+        self.read_settlement_job(job_id)
+            .ok()?
+            .map(|job| admin_audit::to_json(&job))
+    }
+}
+
+impl<PendingStore, StateStore, DebugStore, L1Provider>
+    AdminAgglayerImpl<PendingStore, StateStore, DebugStore, L1Provider>
+where
+    StateStore: StateReader + SettlementReader,
+{
+    /// Reads the mined attempts of every settlement job of `network_id`, or
+    /// of every job when it is `None`. A batch job is read when one of its
+    /// batched jobs is of `network_id`.
+    fn read_settlement_cost_samples(
+        &self,
+        network_id: Option<NetworkId>,
+    ) -> eyre::Result<Vec<SettlementCostSample>> {
+        let job_ids = self
+            .state
+            .list_settlement_job_ids()
+            .wrap_err("Failed to scan settlement job ids")?;
+
+        let mut samples = Vec::new();
+        for job_id in job_ids {
+            let Some(job) = self
+                .state
+                .get_settlement_job(&job_id)
+                .wrap_err_with(|| format!("Failed to read settlement job {job_id}"))?
+            else {
+                continue;
+            };
+            let shares = if job.is_batch() {
+                job.batched_jobs
+                    .iter()
+                    .map(|batched_job_id| {
+                        let batched_job = self
+                            .state
+                            .get_settlement_job(batched_job_id)
+                            .wrap_err_with(|| {
+                                format!("Failed to read batched settlement job {batched_job_id}")
+                            })?;
+                        self.settlement_cost_share(
+                            batched_job_id,
+                            batched_job.and_then(|batched_job| batched_job.network_id),
+                        )
+                    })
+                    .collect::<eyre::Result<Vec<_>>>()?
+            } else {
+                vec![self.settlement_cost_share(&job_id, job.network_id)?]
+            };
+            if network_id.is_some() && shares.iter().all(|share| share.network_id != network_id) {
+                continue;
+            }
+
+            let attempts = self
+                .state
+                .list_settlement_attempts(&job_id)
+                .wrap_err_with(|| format!("Failed to list attempts for settlement job {job_id}"))?;
+            let attempt_results = self
+                .state
+                .list_settlement_attempt_results(&job_id)
+                .wrap_err_with(|| {
+                    format!("Failed to list attempt results for settlement job {job_id}")
+                })?;
+
+            samples.extend(attempt_results.iter().filter_map(|(number, result)| {
+                let SettlementAttemptResult::ContractCall(call) = result else {
+                    return None;
+                };
+                let (_, attempt) = attempts
+                    .iter()
+                    .find(|(attempt_number, _)| attempt_number == number)?;
+                Some(SettlementCostSample {
+                    shares: shares.clone(),
+                    wallet: attempt.sender_wallet,
+                    submission_time: attempt.submission_time,
+                    gas_cost: call.gas_cost,
+                })
+            }));
+        }
+
+        Ok(samples)
+    }
+
+    /// The network and epoch charged for the cost of settling `job_id`.
+    fn settlement_cost_share(
+        &self,
+        job_id: &SettlementJobId,
+        network_id: Option<NetworkId>,
+    ) -> eyre::Result<SettlementCostShare> {
+        let epoch_number = match self
+            .state
+            .get_settlement_job_certificate_id(job_id)
+            .wrap_err_with(|| {
+                format!("Failed to read certificate link for settlement job {job_id}")
+            })? {
+            Some(certificate_id) => self
+                .state
+                .get_certificate_header(&certificate_id)
+                .wrap_err_with(|| format!("Failed to read header of certificate {certificate_id}"))?
+                .and_then(|header| header.epoch_number),
+            None => None,
+        };
+
+        Ok(SettlementCostShare {
+            network_id,
+            epoch_number,
+        })
+    }
 }
 
 fn summarize_settlement_job(
@@ -594,7 +1001,13 @@ impl<PendingStore, StateStore, DebugStore, L1Provider> AdminAgglayerServer
     for AdminAgglayerImpl<PendingStore, StateStore, DebugStore, L1Provider>
 where
     PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
-    StateStore: StateReader + StateWriter + SettlementReader + SettlementWriter + 'static,
+    StateStore: StateReader
+        + StateWriter
+        + SettlementReader
+        + SettlementWriter
+        + AdminAuditReader
+        + AdminAuditWriter
+        + 'static,
     DebugStore: DebugReader + DebugWriter + 'static,
     L1Provider: Provider + WalletProvider + 'static,
 {
@@ -666,344 +1079,490 @@ where
         }
     }
 
-    #[instrument(skip(self), fields(certificate_id = %certificate.hash()))]
+    #[instrument(skip(self, extensions), fields(certificate_id = %certificate.hash()))]
     async fn force_push_pending_certificate(
         &self,
+        extensions: &Extensions,
         certificate: Certificate,
         status: CertificateStatus,
     ) -> RpcResult<()> {
-        warn!(
-            "(ADMIN) Forcing push of pending certificate: {}",
-            certificate.hash()
-        );
-        let header = self
-            .state
-            .get_certificate_header(&certificate.hash())
-            .map_err(|error| {
-                error!(?error, "Failed to get certificate header");
-                Error::internal("Unable to get certificate header")
-            })?;
-        if let Some(header) = header {
-            if header.status == CertificateStatus::Settled {
-                return Err(Error::InvalidArgument(
-                    "Cannot change status of a settled certificate".to_string(),
-                ));
-            }
-        }
-        match self.pending_store.insert_pending_certificate(
-            certificate.network_id,
-            certificate.height,
-            &certificate,
-        ) {
-            Ok(_) => match self
-                .state
-                .update_certificate_header_status(&certificate.hash(), &status)
-            {
-                Ok(_) => Ok(()),
-                Err(error) => {
-                    error!("Failed to insert certificate header: {}", error);
-                    Err(Error::internal("Unable to insert certificate header"))
+        let certificate_id = certificate.hash();
+        let params = json!({
+            "certificateId": certificate_id,
+            "networkId": certificate.network_id,
+            "height": certificate.height,
+            "status": status,
+        });
+        self.audited(
+            extensions,
+            "admin_forcePushPendingCertificate",
+            Some(certificate.network_id),
+            params,
+            || self.certificate_header_json(&certificate_id),
+            async {
+                warn!(
+                    "(ADMIN) Forcing push of pending certificate: {}",
+                    certificate_id
+                );
+                let header =
+                    self.state
+                        .get_certificate_header(&certificate_id)
+                        .map_err(|error| {
+                            error!(?error, "Failed to get certificate header");
+                            Error::internal("Unable to get certificate header")
+                        })?;
+                if let Some(header) = header {
+                    if header.status == CertificateStatus::Settled {
+                        return Err(Error::InvalidArgument(
+                            "Cannot change status of a settled certificate".to_string(),
+                        ));
+                    }
+                }
+                match self.pending_store.insert_pending_certificate(
+                    certificate.network_id,
+                    certificate.height,
+                    &certificate,
+                ) {
+                    Ok(_) => match self
+                        .state
+                        .update_certificate_header_status(&certificate_id, &status)
+                    {
+                        Ok(_) => Ok(()),
+                        Err(error) => {
+                            error!("Failed to insert certificate header: {}", error);
+                            Err(Error::internal("Unable to insert certificate header"))
+                        }
+                    },
+                    Err(error) => {
+                        error!("Failed to insert pending certificate: {}", error);
+                        Err(Error::internal("Unable to insert pending certificate"))
+                    }
                 }
             },
-            Err(error) => {
-                error!("Failed to insert pending certificate: {}", error);
-                Err(Error::internal("Unable to insert pending certificate"))
-            }
-        }
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
+    #[instrument(skip(self, extensions))]
     async fn force_edit_certificate(
         &self,
+        extensions: &Extensions,
         certificate_id: CertificateId,
         process_now: ProcessNow,
         operation_1: Option<String>,
         operation_2: Option<String>,
     ) -> RpcResult<()> {
-        warn!("(ADMIN) Editing certificate");
-
-        enum Operation {
-            SetStatus {
-                from: CertificateStatus,
-                to: CertificateStatus,
-            },
-        }
+        let params = json!({
+            "certificateId": certificate_id,
+            "processNow": process_now,
+            "operation1": operation_1,
+            "operation2": operation_2,
+        });
+        self.audited(
+            extensions,
+            "admin_forceEditCertificate",
+            self.certificate_network(&certificate_id),
+            params,
+            || self.certificate_header_json(&certificate_id),
+            async {
+                warn!("(ADMIN) Editing certificate");
+
+                enum Operation {
+                    SetStatus {
+                        from: CertificateStatus,
+                        to: CertificateStatus,
+                    },
+                }
 
-        impl Operation {
-            fn parse(operation: &str) -> Result<Self, Error> {
-                if let Some(operation) = operation.strip_prefix("set-status,from=") {
-                    let parts = operation.split(",to=").collect::<Vec<_>>();
-                    let [from_status, to_status] = parts[..] else {
-                        return Err(Error::InvalidArgument(
-                            "Invalid set status operation format".to_string(),
-                        ));
-                    };
-                    fn parse_status(status_str: &str) -> Result<CertificateStatus, Error> {
-                        if status_str == "InError" {
-                            Ok(CertificateStatus::error(
-                                CertificateStatusError::InternalError(
-                                    "Set to InError by administrator".to_string(),
-                                ),
+                impl Operation {
+                    fn parse(operation: &str) -> Result<Self, Error> {
+                        if let Some(operation) = operation.strip_prefix("set-status,from=") {
+                            let parts = operation.split(",to=").collect::<Vec<_>>();
+                            let [from_status, to_status] = parts[..] else {
+                                return Err(Error::InvalidArgument(
+                                    "Invalid set status operation format".to_string(),
+                                ));
+                            };
+                            fn parse_status(status_str: &str) -> Result<CertificateStatus, Error> {
+                                if status_str == "InError" {
+                                    Ok(CertificateStatus::error(
+                                        CertificateStatusError::InternalError(
+                                            "Set to InError by administrator".to_string(),
+                                        ),
+                                    ))
+                                } else {
+                                    CertificateStatus::deserialize(
+                                        serde::de::value::BorrowedStrDeserializer::new(status_str),
+                                    )
+                                    .map_err(
+                                        |e: serde::de::value::Error| {
+                                            Error::InvalidArgument(format!(
+                                                "Invalid status {status_str}: {e:?}"
+                                            ))
+                                        },
+                                    )
+                                }
+                            }
+                            Ok(Operation::SetStatus {
+                                from: parse_status(from_status)?,
+                                to: parse_status(to_status)?,
+                            })
+                        } else if operation
+                            .strip_prefix("set-settlement-tx-hash")
+                            .is_some_and(|rest| rest.is_empty() || rest.starts_with(','))
+                        {
+                            Err(Error::InvalidArgument(
+                                "The set-settlement-tx-hash operation is retired: settlement is \
+                                 driven by a settlement job, so editing this field cannot recover \
+                                 it. Act on the settlement job instead."
+                                    .to_string(),
                             ))
                         } else {
-                            CertificateStatus::deserialize(
-                                serde::de::value::BorrowedStrDeserializer::new(status_str),
-                            )
-                            .map_err(|e: serde::de::value::Error| {
-                                Error::InvalidArgument(format!(
-                                    "Invalid status {status_str}: {e:?}"
-                                ))
-                            })
+                            Err(Error::InvalidArgument(format!(
+                                "Unknown operation: {operation:?}"
+                            )))
                         }
                     }
-                    Ok(Operation::SetStatus {
-                        from: parse_status(from_status)?,
-                        to: parse_status(to_status)?,
-                    })
-                } else if operation
-                    .strip_prefix("set-settlement-tx-hash")
-                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(','))
-                {
-                    Err(Error::InvalidArgument(
-                        "The set-settlement-tx-hash operation is retired: settlement is driven by \
-                         a settlement job, so editing this field cannot recover it. Act on the \
-                         settlement job instead."
-                            .to_string(),
-                    ))
-                } else {
-                    Err(Error::InvalidArgument(format!(
-                        "Unknown operation: {operation:?}"
-                    )))
                 }
-            }
-        }
-
-        let operations = [operation_1, operation_2]
-            .into_iter()
-            .flatten()
-            .map(|op_str| Operation::parse(&op_str))
-            .collect::<Result<Vec<_>, _>>()?;
 
-        let header = self
-            .state
-            .get_certificate_header(&certificate_id)
-            .map_err(|error| {
-                error!(?error, "Failed to get certificate header");
-                Error::internal("Unable to get certificate header")
-            })?
-            .ok_or_else(|| {
-                error!("Certificate header not found");
-                Error::ResourceNotFound(format!("CertificateHeader({certificate_id})"))
-            })?;
+                let operations = [operation_1, operation_2]
+                    .into_iter()
+                    .flatten()
+                    .map(|op_str| Operation::parse(&op_str))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let header = self
+                    .state
+                    .get_certificate_header(&certificate_id)
+                    .map_err(|error| {
+                        error!(?error, "Failed to get certificate header");
+                        Error::internal("Unable to get certificate header")
+                    })?
+                    .ok_or_else(|| {
+                        error!("Certificate header not found");
+                        Error::ResourceNotFound(format!("CertificateHeader({certificate_id})"))
+                    })?;
+
+                if header.status == CertificateStatus::Settled {
+                    return Err(Error::InvalidArgument(
+                        "Cannot edit a settled certificate".to_string(),
+                    ));
+                }
 
-        if header.status == CertificateStatus::Settled {
-            return Err(Error::InvalidArgument(
-                "Cannot edit a settled certificate".to_string(),
-            ));
-        }
+                // Check that the current values match the "from" value
+                for operation in operations.iter() {
+                    match operation {
+                        Operation::SetStatus { from, to: _ } => {
+                            // Ensure that the original status is the one described in `from=`.
+                            // However, for InError status, the `from=` does not contain the error
+                            // message. So, we match it separately, and
+                            // we do not verify the current error message if
+                            // we had `set-status,from=InError,to=*`
+                            if &header.status != from
+                                && !matches!(
+                                    (&header.status, &from),
+                                    (
+                                        &CertificateStatus::InError { .. },
+                                        &CertificateStatus::InError { .. }
+                                    )
+                                )
+                            {
+                                return Err(Error::InvalidArgument(format!(
+                                    "Current status ({:?}) does not match expected 'from' status \
+                                     ({:?})",
+                                    header.status, from
+                                )));
+                            }
+                        }
+                    }
+                }
 
-        // Check that the current values match the "from" value
-        for operation in operations.iter() {
-            match operation {
-                Operation::SetStatus { from, to: _ } => {
-                    // Ensure that the original status is the one described in `from=`.
-                    // However, for InError status, the `from=` does not contain the error message.
-                    // So, we match it separately, and we do not verify the current error message if
-                    // we had `set-status,from=InError,to=*`
-                    if &header.status != from
-                        && !matches!(
-                            (&header.status, &from),
-                            (
-                                &CertificateStatus::InError { .. },
-                                &CertificateStatus::InError { .. }
-                            )
-                        )
-                    {
-                        return Err(Error::InvalidArgument(format!(
-                            "Current status ({:?}) does not match expected 'from' status ({:?})",
-                            header.status, from
-                        )));
+                // Now, actually apply the operations
+                for operation in operations {
+                    match operation {
+                        Operation::SetStatus { from: _, to } => {
+                            self.state
+                                .update_certificate_header_status(&certificate_id, &to)
+                                .map_err(|error| {
+                                    error!(?error, ?to, "Failed to update certificate status");
+                                    Error::internal("Unable to update certificate status")
+                                })?;
+                        }
                     }
                 }
-            }
-        }
 
-        // Now, actually apply the operations
-        for operation in operations {
-            match operation {
-                Operation::SetStatus { from: _, to } => {
-                    self.state
-                        .update_certificate_header_status(&certificate_id, &to)
+                // Finally, if requested, reprocess the certificate
+                if process_now == ProcessNow::True {
+                    self.certificate_sender
+                        .send((header.network_id, header.height, certificate_id))
+                        .await
                         .map_err(|error| {
-                            error!(?error, ?to, "Failed to update certificate status");
-                            Error::internal("Unable to update certificate status")
+                            error!(?error, "Failed to send certificate to orchestrator");
+                            Error::internal("Unable to send certificate to orchestrator")
                         })?;
                 }
-            }
-        }
-
-        // Finally, if requested, reprocess the certificate
-        if process_now == ProcessNow::True {
-            self.certificate_sender
-                .send((header.network_id, header.height, certificate_id))
-                .await
-                .map_err(|error| {
-                    error!(?error, "Failed to send certificate to orchestrator");
-                    Error::internal("Unable to send certificate to orchestrator")
-                })?;
-        }
 
-        Ok(())
+                Ok(())
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn set_latest_pending_certificate(&self, certificate_id: CertificateId) -> RpcResult<()> {
-        warn!(
-            "(ADMIN) Setting latest pending certificate: {}",
-            certificate_id
-        );
-        let certificate = if let Some(certificate) = self
-            .state
-            .get_certificate_header(&certificate_id)
-            .map_err(|error| {
-                error!("Failed to get certificate header: {}", error);
-                Error::internal("Unable to get certificate header")
-            })? {
-            certificate
-        } else {
-            return Err(Error::ResourceNotFound(format!(
-                "CertificateHeader({certificate_id})"
-            )));
-        };
-
-        match self
-            .pending_store
-            .set_latest_pending_certificate_per_network(
-                &certificate.network_id,
-                &certificate.height,
-                &certificate.certificate_id,
-            ) {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                error!("Failed to update latest pending certificate: {}", error);
-                Err(Error::internal(
-                    "Unable to update latest pending certificate",
-                ))
-            }
-        }
+    #[instrument(skip(self, extensions))]
+    async fn set_latest_pending_certificate(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()> {
+        let network_id = self.certificate_network(&certificate_id);
+        self.audited(
+            extensions,
+            "admin_setLatestPendingCertificate",
+            network_id,
+            json!({ "certificateId": certificate_id }),
+            || {
+                let (certificate_id, height) = self
+                    .pending_store
+                    .get_latest_pending_certificate_for_network(&network_id?)
+                    .ok()??;
+                Some(admin_audit::to_json(&json!({
+                    "certificateId": certificate_id,
+                    "height": height,
+                })))
+            },
+            async {
+                warn!(
+                    "(ADMIN) Setting latest pending certificate: {}",
+                    certificate_id
+                );
+                let certificate = if let Some(certificate) = self
+                    .state
+                    .get_certificate_header(&certificate_id)
+                    .map_err(|error| {
+                        error!("Failed to get certificate header: {}", error);
+                        Error::internal("Unable to get certificate header")
+                    })? {
+                    certificate
+                } else {
+                    return Err(Error::ResourceNotFound(format!(
+                        "CertificateHeader({certificate_id})"
+                    )));
+                };
+
+                match self
+                    .pending_store
+                    .set_latest_pending_certificate_per_network(
+                        &certificate.network_id,
+                        &certificate.height,
+                        &certificate.certificate_id,
+                    ) {
+                    Ok(_) => Ok(()),
+                    Err(error) => {
+                        error!("Failed to update latest pending certificate: {}", error);
+                        Err(Error::internal(
+                            "Unable to update latest pending certificate",
+                        ))
+                    }
+                }
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn set_latest_proven_certificate(&self, certificate_id: CertificateId) -> RpcResult<()> {
-        warn!(
-            "(ADMIN) Setting latest proven certificate: {}",
-            certificate_id
-        );
-        let certificate = if let Some(certificate) = self
-            .state
-            .get_certificate_header(&certificate_id)
-            .map_err(|error| {
-                error!("Failed to get certificate header: {}", error);
-                Error::internal("Unable to get certificate header")
-            })? {
-            certificate
-        } else {
-            return Err(Error::ResourceNotFound(format!(
-                "CertificateHeader({certificate_id})"
-            )));
-        };
-
-        match self
-            .pending_store
-            .set_latest_proven_certificate_per_network(
-                &certificate.network_id,
-                &certificate.height,
-                &certificate.certificate_id,
-            ) {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                error!("Failed to update latest proven certificate: {}", error);
-                Err(Error::internal(
-                    "Unable to update latest proven certificate",
-                ))
-            }
-        }
+    #[instrument(skip(self, extensions))]
+    async fn set_latest_proven_certificate(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()> {
+        let network_id = self.certificate_network(&certificate_id);
+        self.audited(
+            extensions,
+            "admin_setLatestProvenCertificate",
+            network_id,
+            json!({ "certificateId": certificate_id }),
+            || {
+                let (_, height, certificate_id) = self
+                    .pending_store
+                    .get_latest_proven_certificate_per_network(&network_id?)
+                    .ok()??;
+                Some(admin_audit::to_json(&json!({
+                    "certificateId": certificate_id,
+                    "height": height,
+                })))
+            },
+            async {
+                warn!(
+                    "(ADMIN) Setting latest proven certificate: {}",
+                    certificate_id
+                );
+                let certificate = if let Some(certificate) = self
+                    .state
+                    .get_certificate_header(&certificate_id)
+                    .map_err(|error| {
+                        error!("Failed to get certificate header: {}", error);
+                        Error::internal("Unable to get certificate header")
+                    })? {
+                    certificate
+                } else {
+                    return Err(Error::ResourceNotFound(format!(
+                        "CertificateHeader({certificate_id})"
+                    )));
+                };
+
+                match self
+                    .pending_store
+                    .set_latest_proven_certificate_per_network(
+                        &certificate.network_id,
+                        &certificate.height,
+                        &certificate.certificate_id,
+                    ) {
+                    Ok(_) => Ok(()),
+                    Err(error) => {
+                        error!("Failed to update latest proven certificate: {}", error);
+                        Err(Error::internal(
+                            "Unable to update latest proven certificate",
+                        ))
+                    }
+                }
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn remove_pending_proof(&self, certificate_id: CertificateId) -> RpcResult<()> {
-        warn!("(ADMIN) Removing pending proof: {}", certificate_id);
-
-        self.pending_store
-            .remove_generated_proof(&certificate_id)
-            .map_err(|error| {
-                error!("Failed to remove generated proof: {}", error);
-                Error::internal("Unable to remove generated proof")
-            })
+    #[instrument(skip(self, extensions))]
+    async fn remove_pending_proof(
+        &self,
+        extensions: &Extensions,
+        certificate_id: CertificateId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_removePendingProof",
+            self.certificate_network(&certificate_id),
+            json!({ "certificateId": certificate_id }),
+            || {
+                let proof = self.pending_store.get_proof(certificate_id).ok()?;
+                Some(admin_audit::to_json(
+                    &json!({ "hasProof": proof.is_some() }),
+                ))
+            },
+            async {
+                warn!("(ADMIN) Removing pending proof: {}", certificate_id);
+
+                self.pending_store
+                    .remove_generated_proof(&certificate_id)
+                    .map_err(|error| {
+                        error!("Failed to remove generated proof: {}", error);
+                        Error::internal("Unable to remove generated proof")
+                    })
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self), fields(certificate_id))]
+    #[instrument(skip(self, extensions), fields(certificate_id))]
     async fn remove_pending_certificate(
         &self,
+        extensions: &Extensions,
         network_id: NetworkId,
         height: Height,
         remove_proof: bool,
     ) -> RpcResult<()> {
-        warn!(
-            "(ADMIN) Removing pending certificate for network {} at height {}",
-            network_id, height
-        );
-        let certificate_id = if let Some(certificate) = self
+        let pending_certificate_id = self
             .pending_store
             .get_certificate(network_id, height)
-            .map_err(|error| {
-                error!("Failed to get pending certificate: {}", error);
-                Error::internal("Unable to get pending certificate")
-            })? {
-            certificate.hash()
-        } else {
-            return Err(Error::ResourceNotFound(format!(
-                "PendingCertificate({network_id:?}, {height:?})",
-            )));
-        };
-        tracing::Span::current().record("certificate_id", certificate_id.to_string());
-
-        self.pending_store
-            .remove_pending_certificate(network_id, height)
-            .map_err(|error| {
-                error!("Failed to remove pending certificate: {error}");
-                Error::internal("Unable to remove pending certificate")
-            })?;
-
-        // Update certificate status to InError in the state store
-        let error_status = CertificateStatus::error(CertificateStatusError::InternalError(
-            "Certificate removed from pending store by administrator".to_string(),
-        ));
-        self.state
-            .update_certificate_header_status(&certificate_id, &error_status)
-            .map_err(|error| {
-                error!(
-                    ?error,
-                    "Failed to update certificate status in the state store on pending removal"
+            .ok()
+            .flatten()
+            .map(|certificate| certificate.hash());
+        self.audited(
+            extensions,
+            "admin_removePendingCertificate",
+            Some(network_id),
+            json!({
+                "networkId": network_id,
+                "height": height,
+                "removeProof": remove_proof,
+            }),
+            || {
+                let certificate_id = pending_certificate_id?;
+                let pending = self
+                    .pending_store
+                    .get_certificate(network_id, height)
+                    .ok()?;
+                let proof = self.pending_store.get_proof(certificate_id).ok()?;
+                Some(admin_audit::to_json(&json!({
+                    "certificateId": certificate_id,
+                    "pending": pending.is_some(),
+                    "status": self
+                        .state
+                        .get_certificate_header(&certificate_id)
+                        .ok()?
+                        .map(|header| header.status),
+                    "hasProof": proof.is_some(),
+                })))
+            },
+            async {
+                warn!(
+                    "(ADMIN) Removing pending certificate for network {} at height {}",
+                    network_id, height
                 );
-                Error::internal(format!(
-                    "Unable to update certificate_id: {certificate_id} status in the state store \
-                     on pending removal"
-                ))
-            })?;
-
-        if remove_proof {
-            self.pending_store
-                .remove_generated_proof(&certificate_id)
-                .map_err(|error| {
-                    error!(?error, "Failed to remove generated proof");
-                    Error::internal(format!(
-                        "Failed to remove generated proof for certificate_id: {certificate_id}"
-                    ))
-                })?;
-        }
+                let certificate_id = if let Some(certificate) = self
+                    .pending_store
+                    .get_certificate(network_id, height)
+                    .map_err(|error| {
+                        error!("Failed to get pending certificate: {}", error);
+                        Error::internal("Unable to get pending certificate")
+                    })? {
+                    certificate.hash()
+                } else {
+                    return Err(Error::ResourceNotFound(format!(
+                        "PendingCertificate({network_id:?}, {height:?})",
+                    )));
+                };
+                tracing::Span::current().record("certificate_id", certificate_id.to_string());
+
+                self.pending_store
+                    .remove_pending_certificate(network_id, height)
+                    .map_err(|error| {
+                        error!("Failed to remove pending certificate: {error}");
+                        Error::internal("Unable to remove pending certificate")
+                    })?;
+
+                // Update certificate status to InError in the state store
+                let error_status = CertificateStatus::error(CertificateStatusError::InternalError(
+                    "Certificate removed from pending store by administrator".to_string(),
+                ));
+                self.state
+                    .update_certificate_header_status(&certificate_id, &error_status)
+                    .map_err(|error| {
+                        error!(
+                            ?error,
+                            "Failed to update certificate status in the state store on pending \
+                             removal"
+                        );
+                        Error::internal(format!(
+                            "Unable to update certificate_id: {certificate_id} status in the \
+                             state store on pending removal"
+                        ))
+                    })?;
+
+                if remove_proof {
+                    self.pending_store
+                        .remove_generated_proof(&certificate_id)
+                        .map_err(|error| {
+                            error!(?error, "Failed to remove generated proof");
+                            Error::internal(format!(
+                                "Failed to remove generated proof for certificate_id: \
+                                 {certificate_id}"
+                            ))
+                        })?;
+                }
 
-        Ok(())
+                Ok(())
+            },
+        )
+        .await
     }
 
     #[instrument(skip(self))]
@@ -1014,40 +1573,96 @@ where
         })
     }
 
-    #[instrument(skip(self))]
-    async fn disable_network(&self, network_id: NetworkId) -> RpcResult<()> {
-        self.state
-            .disable_network(&network_id, agglayer_types::network_info::DisabledBy::Admin)
-            .map_err(|error| {
-                error!(?error, "Failed to disable network {network_id}");
-                Error::internal(format!("Unable to disable network {network_id}"))
-            })
+    #[instrument(skip(self, extensions))]
+    async fn disable_network(
+        &self,
+        extensions: &Extensions,
+        network_id: NetworkId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_disableNetwork",
+            Some(network_id),
+            json!({ "networkId": network_id }),
+            || self.network_disabled_json(&network_id),
+            async {
+                self.state
+                    .disable_network(&network_id, agglayer_types::network_info::DisabledBy::Admin)
+                    .map_err(|error| {
+                        error!(?error, "Failed to disable network {network_id}");
+                        Error::internal(format!("Unable to disable network {network_id}"))
+                    })
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn enable_network(&self, network_id: NetworkId) -> RpcResult<()> {
-        self.state.enable_network(&network_id).map_err(|error| {
-            error!(?error, "Failed to enable network {network_id}");
-            Error::internal(format!("Unable to enable network {network_id}"))
-        })
+    #[instrument(skip(self, extensions))]
+    async fn enable_network(
+        &self,
+        extensions: &Extensions,
+        network_id: NetworkId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_enableNetwork",
+            Some(network_id),
+            json!({ "networkId": network_id }),
+            || self.network_disabled_json(&network_id),
+            async {
+                self.state.enable_network(&network_id).map_err(|error| {
+                    error!(?error, "Failed to enable network {network_id}");
+                    Error::internal(format!("Unable to enable network {network_id}"))
+                })
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn abort_settlement_task(&self, job_id: SettlementJobId) -> RpcResult<()> {
-        warn!("(ADMIN) Aborting settlement task for job {job_id}");
-        self.settlement_service
-            .admin_abort_task(job_id)
-            .await
-            .map_err(map_admin_error)
+    #[instrument(skip(self, extensions))]
+    async fn abort_settlement_task(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_abortSettlementTask",
+            self.settlement_job_network(&job_id),
+            json!({ "jobId": job_id }),
+            || self.settlement_job_json(job_id),
+            async {
+                warn!("(ADMIN) Aborting settlement task for job {job_id}");
+                self.settlement_service
+                    .admin_abort_task(job_id)
+                    .await
+                    .map_err(map_admin_error)
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
-    async fn reload_settlement_task(&self, job_id: SettlementJobId) -> RpcResult<()> {
-        warn!("(ADMIN) Reloading settlement task for job {job_id}");
-        self.settlement_service
-            .admin_reload_and_restart_task(job_id)
-            .await
-            .map_err(map_admin_error)
+    #[instrument(skip(self, extensions))]
+    async fn reload_settlement_task(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_reloadSettlementTask",
+            self.settlement_job_network(&job_id),
+            json!({ "jobId": job_id }),
+            || self.settlement_job_json(job_id),
+            async {
+                warn!("(ADMIN) Reloading settlement task for job {job_id}");
+                self.settlement_service
+                    .admin_reload_and_restart_task(job_id)
+                    .await
+                    .map_err(map_admin_error)
+            },
+        )
+        .await
     }
 
     #[instrument(skip(self))]
@@ -1071,78 +1686,273 @@ where
     }
 
     #[instrument(skip(self))]
+    async fn get_settlement_cost_report(
+        &self,
+        network_id: Option<NetworkId>,
+        from_unix_secs: u64,
+        to_unix_secs: u64,
+    ) -> RpcResult<SettlementCostReport> {
+        debug!("Reading settlement costs from {from_unix_secs} to {to_unix_secs}");
+        if from_unix_secs > to_unix_secs {
+            return Err(Error::InvalidArgument(format!(
+                "Report start {from_unix_secs} is after its end {to_unix_secs}"
+            )));
+        }
+        let samples = self
+            .read_settlement_cost_samples(network_id)
+            .map_err(map_admin_error)?;
+        Ok(SettlementCostReport::aggregate(
+            network_id,
+            from_unix_secs,
+            to_unix_secs,
+            samples,
+        ))
+    }
+
+    #[instrument(skip(self, extensions))]
     async fn insert_settlement_attempt(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt: InsertAttemptParams,
         force: Option<Force>,
     ) -> RpcResult<MutationResponse> {
-        warn!("(ADMIN) Inserting settlement attempt for job {job_id}");
-        let (attempt_number, live_task) = self
-            .settlement_service
-            .admin_insert_settlement_attempt(job_id, attempt.into(), edit_even_if_completed(force))
-            .await
-            .map_err(map_admin_error)?;
-        Ok(MutationResponse {
-            attempt_number,
-            live_task,
-        })
+        let params = json!({ "jobId": job_id, "attempt": attempt, "force": force });
+        self.audited(
+            extensions,
+            "admin_insertSettlementAttempt",
+            self.settlement_job_network(&job_id),
+            params,
+            || self.settlement_job_json(job_id),
+            async {
+                warn!("(ADMIN) Inserting settlement attempt for job {job_id}");
+                let (attempt_number, live_task) = self
+                    .settlement_service
+                    .admin_insert_settlement_attempt(
+                        job_id,
+                        attempt.into(),
+                        edit_even_if_completed(force),
+                    )
+                    .await
+                    .map_err(map_admin_error)?;
+                Ok(MutationResponse {
+                    attempt_number,
+                    live_task,
+                })
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
+    #[instrument(skip(self, extensions))]
     async fn mark_settlement_attempt_definitely_failed(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt_number: u64,
         reason: String,
         force: Option<Force>,
     ) -> RpcResult<MutationResponse> {
-        warn!(
-            "(ADMIN) Marking settlement attempt {attempt_number} of job {job_id} as definitely \
-             failed"
-        );
-        let live_task = self
-            .settlement_service
-            .admin_mark_attempt_definitely_failed(
-                job_id,
-                attempt_number,
-                &reason,
-                edit_even_if_completed(force),
-            )
-            .await
-            .map_err(map_admin_error)?;
-        Ok(MutationResponse {
-            attempt_number,
-            live_task,
-        })
+        let params = json!({
+            "jobId": job_id,
+            "attemptNumber": attempt_number,
+            "reason": reason,
+            "force": force,
+        });
+        self.audited(
+            extensions,
+            "admin_markSettlementAttemptDefinitelyFailed",
+            self.settlement_job_network(&job_id),
+            params,
+            || self.settlement_job_json(job_id),
+            async {
+                warn!(
+                    "(ADMIN) Marking settlement attempt {attempt_number} of job {job_id} as \
+                     definitely failed"
+                );
+                let live_task = self
+                    .settlement_service
+                    .admin_mark_attempt_definitely_failed(
+                        job_id,
+                        attempt_number,
+                        &reason,
+                        edit_even_if_completed(force),
+                    )
+                    .await
+                    .map_err(map_admin_error)?;
+                Ok(MutationResponse {
+                    attempt_number,
+                    live_task,
+                })
+            },
+        )
+        .await
     }
 
-    #[instrument(skip(self))]
+    #[instrument(skip(self, extensions))]
     async fn remove_settlement_attempt_result(
         &self,
+        extensions: &Extensions,
         job_id: SettlementJobId,
         attempt_number: u64,
         force: Option<Force>,
     ) -> RpcResult<MutationResponse> {
-        warn!("(ADMIN) Removing result of settlement attempt {attempt_number} of job {job_id}");
-        let live_task = self
-            .settlement_service
-            .admin_remove_attempt_result(job_id, attempt_number, edit_even_if_completed(force))
-            .await
-            .map_err(map_admin_error)?;
-        Ok(MutationResponse {
-            attempt_number,
-            live_task,
-        })
+        let params = json!({ "jobId": job_id, "attemptNumber": attempt_number, "force": force });
+        self.audited(
+            extensions,
+            "admin_removeSettlementAttemptResult",
+            self.settlement_job_network(&job_id),
+            params,
+            || self.settlement_job_json(job_id),
+            async {
+                warn!(
+                    "(ADMIN) Removing result of settlement attempt {attempt_number} of job \
+                     {job_id}"
+                );
+                let live_task = self
+                    .settlement_service
+                    .admin_remove_attempt_result(
+                        job_id,
+                        attempt_number,
+                        edit_even_if_completed(force),
+                    )
+                    .await
+                    .map_err(map_admin_error)?;
+                Ok(MutationResponse {
+                    attempt_number,
+                    live_task,
+                })
+            },
+        )
+        .await
+    }
+
+    #[instrument(skip(self, extensions))]
+    async fn force_remove_settlement_job_result(
+        &self,
+        extensions: &Extensions,
+        job_id: SettlementJobId,
+    ) -> RpcResult<()> {
+        self.audited(
+            extensions,
+            "admin_forceRemoveSettlementJobResult",
+            self.settlement_job_network(&job_id),
+            json!({ "jobId": job_id }),
+            || self.settlement_job_json(job_id),
+            async {
+                warn!("(ADMIN) Force-removing terminal result of settlement job {job_id}");
+                self.settlement_service
+                    .admin_force_remove_settlement_job_result(job_id)
+                    .await
+                    .map_err(map_admin_error)
+            },
+        )
+        .await
     }
 
     #[instrument(skip(self))]
-    async fn force_remove_settlement_job_result(&self, job_id: SettlementJobId) -> RpcResult<()> {
-        warn!("(ADMIN) Force-removing terminal result of settlement job {job_id}");
-        self.settlement_service
-            .admin_force_remove_settlement_job_result(job_id)
-            .await
-            .map_err(map_admin_error)
+    async fn list_audit_events(
+        &self,
+        from_unix_secs: u64,
+        to_unix_secs: u64,
+        method: Option<String>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<AdminAuditEventDto>> {
+        debug!("Listing admin audit events from {from_unix_secs} to {to_unix_secs}");
+        if from_unix_secs > to_unix_secs {
+            return Err(Error::InvalidArgument(format!(
+                "Listing start {from_unix_secs} is after its end {to_unix_secs}"
+            )));
+        }
+        let limit = limit
+            .unwrap_or(admin_audit::MAX_AUDIT_EVENTS_LIMIT)
+            .min(admin_audit::MAX_AUDIT_EVENTS_LIMIT);
+        let events = self
+            .state
+            .list_admin_audit_events(
+                SystemTime::UNIX_EPOCH + Duration::from_secs(from_unix_secs),
+                SystemTime::UNIX_EPOCH + Duration::from_secs(to_unix_secs),
+                method.as_deref(),
+                limit,
+            )
+            .map_err(|error| {
+                error!(?error, "Failed to list admin audit events");
+                Error::internal("Unable to list admin audit events")
+            })?;
+
+        Ok(events.into_iter().map(AdminAuditEventDto::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn verify_state(
+        &self,
+        network_id: Option<NetworkId>,
+    ) -> RpcResult<Vec<NetworkStateVerification>> {
+        let Some(state_verifier) = &self.state_verifier else {
+            return Err(Error::MethodDisabled {
+                method: "admin_verifyState",
+            });
+        };
+
+        info!("(ADMIN) Verifying the state of network {network_id:?}");
+        Ok(state_verifier.verify_state(network_id).await?)
+    }
+
+    #[instrument(skip(self, extensions))]
+    async fn rollback_network(
+        &self,
+        extensions: &Extensions,
+        network_id: NetworkId,
+        height: Height,
+        plan_hash: Option<Digest>,
+    ) -> RpcResult<NetworkRollbackPlan> {
+        let Some(network_rollback) = &self.network_rollback else {
+            return Err(Error::MethodDisabled {
+                method: "admin_rollbackNetwork",
+            });
+        };
+
+        let Some(plan_hash) = plan_hash else {
+            info!("(ADMIN) Planning the rollback of network {network_id} to height {height}");
+            return Ok(network_rollback.plan_rollback(network_id, height)?.plan);
+        };
+
+        self.audited(
+            extensions,
+            "admin_rollbackNetwork",
+            Some(network_id),
+            json!({ "networkId": network_id, "height": height, "planHash": plan_hash }),
+            || self.network_heights_json(&network_id),
+            async {
+                let rollback = network_rollback.plan_rollback(network_id, height)?;
+                if rollback.plan.plan_hash != plan_hash {
+                    return Err(Error::InvalidArgument(format!(
+                        "The rollback plan of network {network_id} to height {height} is now {}, \
+                         not {plan_hash}",
+                        rollback.plan.plan_hash
+                    )));
+                }
+                if !rollback.plan.network_disabled {
+                    return Err(
+                        agglayer_rpc::NetworkRollbackError::NetworkNotDisabled(network_id).into(),
+                    );
+                }
+
+                warn!("(ADMIN) Rolling back network {network_id} to height {height}");
+                for &job_id in &rollback.plan.cancelled_settlement_jobs {
+                    if self.settlement_service.has_live_task(job_id) {
+                        warn!("(ADMIN) Aborting settlement task for job {job_id}");
+                        self.settlement_service
+                            .admin_abort_task(job_id)
+                            .await
+                            .map_err(map_admin_error)?;
+                    }
+                }
+
+                Ok(network_rollback.apply_rollback(&rollback)?)
+            },
+        )
+        .await
     }
 }
 
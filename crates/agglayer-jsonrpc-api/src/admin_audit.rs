@@ -0,0 +1,143 @@
+//! Audit log of admin mutations.
+//!
+//! Every admin method that mutates state records an [`AdminAuditEvent`] in the
+//! state store once it returns, whether the mutation was applied or refused.
+//! Events are read back through `admin_listAuditEvents`.
+
+use agglayer_rpc::network_rollback::NetworkRollbackPlan;
+use agglayer_settlement_service::LiveTaskNotification;
+use agglayer_types::{
+    AdminAuditCaller, AdminAuditEvent, AdminAuditEventId, AdminAuditLiveTask, AdminAuditOutcome,
+    NetworkId,
+};
+use jsonrpsee::Extensions;
+use serde::Serialize;
+
+use crate::{admin_auth::AdminPrincipal, settlement_admin::MutationResponse};
+
+/// Name recorded for callers when admin authentication is disabled.
+const UNAUTHENTICATED: &str = "unauthenticated";
+
+/// Maximum number of audit events returned by a single listing.
+///
+/// Larger limits requested by the clients are clamped to this value.
+pub(crate) const MAX_AUDIT_EVENTS_LIMIT: usize = 1000;
+
+/// The caller of an admin method, from the principal attached to the request
+/// by the authentication middleware.
+pub(crate) fn caller(extensions: &Extensions) -> AdminAuditCaller {
+    match extensions.get::<AdminPrincipal>() {
+        Some(principal) => AdminAuditCaller {
+            name: principal.name.clone(),
+            role: Some(principal.role.to_string()),
+        },
+        None => AdminAuditCaller {
+            name: UNAUTHENTICATED.to_string(),
+            role: None,
+        },
+    }
+}
+
+/// Renders a value recorded in an audit event as JSON.
+pub(crate) fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).unwrap_or_else(|error| {
+        serde_json::json!({ "unserializable": error.to_string() }).to_string()
+    })
+}
+
+/// Responses of audited admin methods, which tell how the live settlement
+/// task was notified of the mutation when they report it.
+pub(crate) trait AuditedResponse {
+    fn live_task(&self) -> Option<AdminAuditLiveTask>;
+}
+
+impl AuditedResponse for () {
+    fn live_task(&self) -> Option<AdminAuditLiveTask> {
+        None
+    }
+}
+
+impl AuditedResponse for MutationResponse {
+    fn live_task(&self) -> Option<AdminAuditLiveTask> {
+        Some(match self.live_task {
+            LiveTaskNotification::Queued => AdminAuditLiveTask::Queued,
+            LiveTaskNotification::Absent => AdminAuditLiveTask::Absent,
+            LiveTaskNotification::NotifyFailed => AdminAuditLiveTask::NotifyFailed,
+        })
+    }
+}
+
+impl AuditedResponse for NetworkRollbackPlan {
+    fn live_task(&self) -> Option<AdminAuditLiveTask> {
+        None
+    }
+}
+
+/// One event returned by `admin_listAuditEvents`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminAuditEventDto {
+    pub id: AdminAuditEventId,
+    pub recorded_at_unix_millis: u64,
+    /// Token name or JWT subject of the caller, or `unauthenticated`.
+    pub caller: String,
+    pub role: Option<String>,
+    pub method: String,
+    pub network_id: Option<NetworkId>,
+    pub params: serde_json::Value,
+    /// The touched record before the mutation, `null` when it did not exist
+    /// or could not be read.
+    pub before: Option<serde_json::Value>,
+    /// The touched record after the mutation, `null` when it no longer
+    /// exists or could not be read.
+    pub after: Option<serde_json::Value>,
+    pub outcome: AdminAuditOutcomeDto,
+}
+
+/// Outcome of an audited mutation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum AdminAuditOutcomeDto {
+    #[serde(rename_all = "camelCase")]
+    Applied {
+        /// Only reported by the settlement attempt mutations.
+        live_task: Option<LiveTaskNotification>,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+impl From<(AdminAuditEventId, AdminAuditEvent)> for AdminAuditEventDto {
+    fn from((id, event): (AdminAuditEventId, AdminAuditEvent)) -> Self {
+        Self {
+            id,
+            recorded_at_unix_millis: event
+                .recorded_at
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_millis() as u64),
+            caller: event.caller.name,
+            role: event.caller.role,
+            method: event.method,
+            network_id: event.network_id,
+            params: from_json(event.params),
+            before: event.before.map(from_json),
+            after: event.after.map(from_json),
+            outcome: match event.outcome {
+                AdminAuditOutcome::Applied { live_task } => AdminAuditOutcomeDto::Applied {
+                    live_task: live_task.map(|live_task| match live_task {
+                        AdminAuditLiveTask::Queued => LiveTaskNotification::Queued,
+                        AdminAuditLiveTask::Absent => LiveTaskNotification::Absent,
+                        AdminAuditLiveTask::NotifyFailed => LiveTaskNotification::NotifyFailed,
+                    }),
+                },
+                AdminAuditOutcome::Failed { error } => AdminAuditOutcomeDto::Failed { error },
+            },
+        }
+    }
+}
+
+/// Parses recorded JSON back, keeping it as a string if it is not valid JSON.
+fn from_json(json: String) -> serde_json::Value {
+    serde_json::from_str(&json).unwrap_or(serde_json::Value::String(json))
+}
@@ -0,0 +1,278 @@
+//! Authentication of admin JSON-RPC callers.
+//!
+//! Requests are authenticated at the HTTP layer by [`authenticate_request`],
+//! which attaches the caller's [`AdminPrincipal`] to the request. Each method
+//! call is then authorized against the caller's role by the
+//! [`AdminAuthorizationLayer`](crate::rpc_middleware::AdminAuthorizationLayer)
+//! RPC middleware, since a single HTTP request can batch several calls.
+
+use std::{path::Path, sync::Arc};
+
+use agglayer_config::admin_auth::{AdminAuthConfig, AdminJwtConfig, AdminRole, JwtAlgorithm};
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse as _, Response},
+};
+use eyre::Context as _;
+use http::{header, HeaderMap, StatusCode};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use subtle::ConstantTimeEq as _;
+use tracing::warn;
+
+mod tls;
+
+#[cfg(test)]
+mod tests;
+
+pub use tls::{serve_tls, TlsAcceptor};
+
+/// Admin methods that only read state, open to the read-only role. Any other
+/// method requires the operator role.
+const READ_ONLY_METHODS: &[&str] = &[
+    "admin_getTokenBalance",
+    "admin_getCertificate",
+    "admin_getDisabledNetworks",
+    "admin_listSettlementJobs",
+    "admin_getSettlementJob",
+    "admin_getSettlementCostReport",
+    "admin_listAuditEvents",
+    "admin_verifyState",
+];
+
+/// Role required to call an admin method.
+///
+/// Unknown methods require the operator role, so that a new method is never
+/// exposed to read-only callers by omission.
+pub(crate) fn required_role(method: &str) -> AdminRole {
+    if READ_ONLY_METHODS.contains(&method) {
+        AdminRole::ReadOnly
+    } else {
+        AdminRole::Operator
+    }
+}
+
+/// An authenticated admin caller.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminPrincipal {
+    /// Token name, JWT subject, or `client-certificate`.
+    pub name: String,
+    pub role: AdminRole,
+}
+
+/// Marks a request received on a TLS connection whose client certificate was
+/// verified against the configured client CA.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct VerifiedClientCertificate;
+
+/// Reasons an admin request is refused authentication.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AuthError {
+    #[error("missing bearer credential")]
+    MissingCredential,
+
+    #[error("malformed authorization header")]
+    MalformedHeader,
+
+    #[error("unknown bearer token")]
+    UnknownToken,
+
+    #[error("invalid JWT: {0}")]
+    InvalidJwt(#[source] jsonwebtoken::errors::Error),
+}
+
+struct StaticToken {
+    name: String,
+    token: Vec<u8>,
+    role: AdminRole,
+}
+
+struct JwtVerifier {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+#[derive(serde::Deserialize)]
+struct AdminClaims {
+    #[serde(default)]
+    sub: Option<String>,
+    role: AdminRole,
+}
+
+/// Verifies the credentials of admin requests.
+pub struct AdminAuthenticator {
+    tokens: Vec<StaticToken>,
+    jwt: Option<JwtVerifier>,
+    client_certificate_role: Option<AdminRole>,
+}
+
+impl AdminAuthenticator {
+    /// Loads the key material referenced by `config`.
+    pub fn from_config(config: &AdminAuthConfig) -> eyre::Result<Self> {
+        let tokens = config
+            .tokens
+            .iter()
+            .map(|token| {
+                let secret = read_key_file(&token.token_file)
+                    .wrap_err_with(|| format!("Failed to load admin token {}", token.name))?;
+                let secret = secret.trim_ascii().to_vec();
+                if secret.is_empty() {
+                    eyre::bail!("Admin token {} is empty", token.name);
+                }
+                Ok(StaticToken {
+                    name: token.name.clone(),
+                    token: secret,
+                    role: token.role,
+                })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        let jwt = config
+            .jwt
+            .as_ref()
+            .map(JwtVerifier::from_config)
+            .transpose()
+            .wrap_err("Failed to load admin JWT verification key")?;
+
+        let client_certificate_role = match &config.tls {
+            Some(tls) if tls.client_certificate_role.is_some() => {
+                if tls.client_ca_file.is_none() {
+                    eyre::bail!("client-certificate-role requires client-ca-file");
+                }
+                tls.client_certificate_role
+            }
+            _ => None,
+        };
+
+        if tokens.is_empty() && jwt.is_none() && client_certificate_role.is_none() {
+            eyre::bail!(
+                "Admin authentication is enabled but accepts no credential: configure tokens, \
+                 jwt, or tls.client-certificate-role"
+            );
+        }
+
+        Ok(Self {
+            tokens,
+            jwt,
+            client_certificate_role,
+        })
+    }
+
+    /// Authenticates a request from its headers, and whether it arrived with
+    /// a verified client certificate.
+    ///
+    /// A bearer credential, when present, always decides: an invalid one is
+    /// refused even over a verified TLS connection.
+    pub(crate) fn authenticate(
+        &self,
+        headers: &HeaderMap,
+        client_certificate: bool,
+    ) -> Result<AdminPrincipal, AuthError> {
+        let Some(authorization) = headers.get(header::AUTHORIZATION) else {
+            return match self.client_certificate_role {
+                Some(role) if client_certificate => Ok(AdminPrincipal {
+                    name: "client-certificate".to_string(),
+                    role,
+                }),
+                _ => Err(AuthError::MissingCredential),
+            };
+        };
+
+        let bearer = authorization
+            .to_str()
+            .ok()
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|bearer| !bearer.is_empty())
+            .ok_or(AuthError::MalformedHeader)?;
+
+        if let Some(token) = self
+            .tokens
+            .iter()
+            .find(|token| bool::from(token.token.as_slice().ct_eq(bearer.as_bytes())))
+        {
+            return Ok(AdminPrincipal {
+                name: token.name.clone(),
+                role: token.role,
+            });
+        }
+
+        match &self.jwt {
+            Some(jwt) => jwt.verify(bearer),
+            None => Err(AuthError::UnknownToken),
+        }
+    }
+}
+
+impl JwtVerifier {
+    fn from_config(config: &AdminJwtConfig) -> eyre::Result<Self> {
+        let key_material = read_key_file(&config.key_file)?;
+        let (algorithm, key) = match config.algorithm {
+            JwtAlgorithm::Hs256 => (
+                Algorithm::HS256,
+                DecodingKey::from_secret(key_material.trim_ascii()),
+            ),
+            JwtAlgorithm::Es256 => (
+                Algorithm::ES256,
+                DecodingKey::from_ec_pem(&key_material)
+                    .wrap_err("Invalid ES256 public key, expected PEM")?,
+            ),
+        };
+
+        let mut validation = Validation::new(algorithm);
+        validation.leeway = config.leeway.as_secs();
+        if let Some(issuer) = &config.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match &config.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        Ok(Self { key, validation })
+    }
+
+    fn verify(&self, token: &str) -> Result<AdminPrincipal, AuthError> {
+        let claims = jsonwebtoken::decode::<AdminClaims>(token, &self.key, &self.validation)
+            .map_err(AuthError::InvalidJwt)?
+            .claims;
+
+        Ok(AdminPrincipal {
+            name: claims.sub.unwrap_or_else(|| "jwt".to_string()),
+            role: claims.role,
+        })
+    }
+}
+
+fn read_key_file(path: &Path) -> eyre::Result<Vec<u8>> {
+    std::fs::read(path).wrap_err_with(|| format!("Failed to read {}", path.display()))
+}
+
+/// HTTP middleware authenticating every admin request, and attaching the
+/// caller's [`AdminPrincipal`] to it.
+pub(crate) async fn authenticate_request(
+    State(authenticator): State<Arc<AdminAuthenticator>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let client_certificate = request
+        .extensions()
+        .get::<VerifiedClientCertificate>()
+        .is_some();
+
+    match authenticator.authenticate(request.headers(), client_certificate) {
+        Ok(principal) => {
+            request.extensions_mut().insert(principal);
+            next.run(request).await
+        }
+        Err(error) => {
+            warn!(%error, "Rejected unauthenticated admin request");
+            (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Bearer")],
+                error.to_string(),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! TLS termination of the admin listener, optionally requiring client
+//! certificates.
+
+use std::sync::Arc;
+
+use agglayer_config::admin_auth::AdminTlsConfig;
+use eyre::Context as _;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto,
+    service::TowerToHyperService,
+};
+use rustls::{
+    pki_types::{pem::PemObject as _, CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+    RootCertStore, ServerConfig,
+};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use tower::ServiceExt as _;
+use tracing::{debug, warn};
+
+use super::VerifiedClientCertificate;
+
+/// Accepts TLS connections on the admin listener.
+#[derive(Clone)]
+pub struct TlsAcceptor {
+    acceptor: tokio_rustls::TlsAcceptor,
+}
+
+impl TlsAcceptor {
+    /// Loads the listener certificate and key, and the client CA if mTLS is
+    /// enabled.
+    pub fn from_config(config: &AdminTlsConfig) -> eyre::Result<Self> {
+        let cert_chain = CertificateDer::pem_file_iter(&config.cert_file)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .wrap_err_with(|| format!("Failed to read {}", config.cert_file.display()))?;
+        let key = PrivateKeyDer::from_pem_file(&config.key_file)
+            .wrap_err_with(|| format!("Failed to read {}", config.key_file.display()))?;
+
+        let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+        let builder = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .wrap_err("Failed to select TLS protocol versions")?;
+        let builder = match &config.client_ca_file {
+            Some(client_ca_file) => {
+                let mut roots = RootCertStore::empty();
+                for cert in CertificateDer::pem_file_iter(client_ca_file)
+                    .wrap_err_with(|| format!("Failed to read {}", client_ca_file.display()))?
+                {
+                    let cert = cert
+                        .wrap_err_with(|| format!("Failed to read {}", client_ca_file.display()))?;
+                    roots.add(cert).wrap_err("Invalid client CA certificate")?;
+                }
+                let verifier =
+                    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                        .build()
+                        .wrap_err("Failed to build the client certificate verifier")?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        let mut server_config = builder
+            .with_single_cert(cert_chain, key)
+            .wrap_err("Invalid admin TLS certificate or key")?;
+        server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+        Ok(Self {
+            acceptor: tokio_rustls::TlsAcceptor::from(Arc::new(server_config)),
+        })
+    }
+}
+
+/// Serves `router` over TLS on `listener` until `shutdown` is cancelled.
+///
+/// Requests received with a verified client certificate are marked with
+/// [`VerifiedClientCertificate`] for the authentication middleware.
+pub async fn serve_tls(
+    listener: TcpListener,
+    router: axum::Router,
+    acceptor: TlsAcceptor,
+    shutdown: CancellationToken,
+) {
+    loop {
+        let (stream, peer) = tokio::select! {
+            _ = shutdown.cancelled() => return,
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(error) => {
+                    warn!(%error, "Failed to accept admin connection");
+                    continue;
+                }
+            },
+        };
+
+        let acceptor = acceptor.acceptor.clone();
+        let router = router.clone();
+        let shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(error) => {
+                    debug!(%peer, %error, "Admin TLS handshake failed");
+                    return;
+                }
+            };
+            // The verifier only lets a handshake with client certificates
+            // complete if they chain to the client CA.
+            let client_certificate = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .is_some_and(|certs| !certs.is_empty());
+
+            let service =
+                router.map_request(move |mut request: http::Request<hyper::body::Incoming>| {
+                    if client_certificate {
+                        request.extensions_mut().insert(VerifiedClientCertificate);
+                    }
+                    request
+                });
+            let builder = auto::Builder::new(TokioExecutor::new());
+            let connection = builder.serve_connection_with_upgrades(
+                TokioIo::new(stream),
+                TowerToHyperService::new(service),
+            );
+
+            tokio::select! {
+                result = connection => {
+                    if let Err(error) = result {
+                        debug!(%peer, %error, "Admin connection closed with an error");
+                    }
+                }
+                _ = shutdown.cancelled() => {}
+            }
+        });
+    }
+}
@@ -116,6 +116,14 @@ pub enum Error {
         error: RateLimitedError,
     },
 
+    #[error("Certificate submission rate limited")]
+    #[serde(rename_all = "kebab-case")]
+    CertificateRateLimited {
+        detail: String,
+        /// Seconds to wait before submitting again, if known.
+        retry_after: Option<u64>,
+    },
+
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
 
@@ -148,6 +156,7 @@ impl Error {
             Self::Status(_) => RpcErrorCode::StatusError.code(),
             Self::SendCertificate { .. } => RpcErrorCode::SendCertificate.code(),
             Self::RateLimited { .. } => RpcErrorCode::RateLimited.code(),
+            Self::CertificateRateLimited { .. } => RpcErrorCode::CertificateRateLimited.code(),
             Self::MethodDisabled { .. } => RpcErrorCode::MethodDisabled.code(),
             Self::Classified { code, .. } => code.code(),
         }
@@ -217,7 +226,25 @@ impl From<TxStatusError> for Error {
 impl From<CertificateSubmissionError> for Error {
     fn from(error: CertificateSubmissionError) -> Self {
         let detail = error.to_string();
-        Self::SendCertificate { detail }
+        match error {
+            CertificateSubmissionError::RateLimited(error) => {
+                // Round up, retrying earlier would be limited again.
+                let retry_after = error
+                    .retry_after()
+                    .map(|after| after.as_secs() + u64::from(after.subsec_nanos() > 0));
+                Self::CertificateRateLimited {
+                    detail,
+                    retry_after,
+                }
+            }
+            CertificateSubmissionError::TooManyPendingCertificates { .. } => {
+                Self::CertificateRateLimited {
+                    detail,
+                    retry_after: None,
+                }
+            }
+            _ => Self::SendCertificate { detail },
+        }
     }
 }
 
@@ -234,6 +261,16 @@ impl From<agglayer_rpc::CertificateRetrievalError> for Error {
     }
 }
 
+impl From<agglayer_rpc::ListCertificateHeadersError> for Error {
+    fn from(err: agglayer_rpc::ListCertificateHeadersError) -> Self {
+        use agglayer_rpc::ListCertificateHeadersError as E;
+        match err {
+            E::Storage(error) => Self::internal(error.to_string()),
+            error @ E::InvalidLimit => Self::InvalidArgument(error.to_string()),
+        }
+    }
+}
+
 impl From<agglayer_rpc::GetNetworkInfoError> for Error {
     fn from(err: agglayer_rpc::GetNetworkInfoError) -> Self {
         // Since NetworkStateRetrievalError is currently empty, convert to internal
@@ -242,6 +279,66 @@ impl From<agglayer_rpc::GetNetworkInfoError> for Error {
     }
 }
 
+impl From<agglayer_rpc::StateProofError> for Error {
+    fn from(err: agglayer_rpc::StateProofError) -> Self {
+        use agglayer_rpc::StateProofError as E;
+        match err {
+            E::Storage(error) => Self::internal(error.to_string()),
+            E::NoSettledState { network_id } => {
+                Self::ResourceNotFound(format!("SettledState({network_id})"))
+            }
+            error @ E::LeavesUnavailable { .. } => Self::ResourceNotFound(error.to_string()),
+            error @ E::NoStateSnapshot { .. } => Self::ResourceNotFound(error.to_string()),
+            error @ E::LeafIndexOutOfRange { .. } => Self::InvalidArgument(error.to_string()),
+            error @ E::HeightNotSettled { .. } => Self::InvalidArgument(error.to_string()),
+            error @ E::UnanchoredState { .. } => Self::Classified {
+                code: RpcErrorCode::Unavailable,
+                message: error.to_string(),
+            },
+            error @ E::ProofGeneration { .. } => Self::internal(error.to_string()),
+        }
+    }
+}
+
+impl From<agglayer_rpc::CertificateSimulationError> for Error {
+    fn from(err: agglayer_rpc::CertificateSimulationError) -> Self {
+        use agglayer_rpc::CertificateSimulationError as E;
+        match err {
+            E::Unavailable => Self::MethodDisabled {
+                method: "interop_simulateCertificate",
+            },
+            error @ E::UnexpectedHeight { .. } => Self::InvalidArgument(error.to_string()),
+            E::SettledState(error) => error.into(),
+        }
+    }
+}
+
+impl From<agglayer_rpc::StateVerificationError> for Error {
+    fn from(err: agglayer_rpc::StateVerificationError) -> Self {
+        use agglayer_rpc::StateVerificationError as E;
+        match err {
+            E::Storage(error) => Self::internal(error.to_string()),
+            error @ E::L1 { .. } => Self::Classified {
+                code: RpcErrorCode::Unavailable,
+                message: error.to_string(),
+            },
+        }
+    }
+}
+
+impl From<agglayer_rpc::NetworkRollbackError> for Error {
+    fn from(err: agglayer_rpc::NetworkRollbackError) -> Self {
+        use agglayer_rpc::NetworkRollbackError as E;
+        match err {
+            E::Storage(error) => Self::internal(error.to_string()),
+            error @ E::Rebuild { .. } => Self::internal(error.to_string()),
+            error @ (E::HeightNotSettled { .. }
+            | E::NetworkNotDisabled(_)
+            | E::SettlementBatchOpen { .. }) => Self::InvalidArgument(error.to_string()),
+        }
+    }
+}
+
 // This impl establishes the integration with `jsonrpsee` errors.
 impl From<Error> for ErrorObjectOwned {
     fn from(err: Error) -> Self {
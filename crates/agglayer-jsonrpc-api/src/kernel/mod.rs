@@ -94,6 +94,12 @@ impl<RpcProvider> Kernel<RpcProvider> {
         })
     }
 
+    /// Use the given rate limiter, e.g. one shared with other services.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
     pub(crate) fn rate_limiter(&self) -> &RateLimiter {
         &self.rate_limiter
     }
@@ -11,22 +11,28 @@ use agglayer_storage::stores::{
     PendingCertificateWriter, SettlementReader, StateReader, StateWriter,
 };
 use agglayer_types::{
-    Certificate, CertificateHeader, CertificateId, EpochConfiguration, NetworkId, NetworkInfo,
+    BalanceProof, Certificate, CertificateHeader, CertificateId, CertificateSimulation,
+    EpochConfiguration, GlobalIndex, Height, ListDirection, LocalExitTreeProof, NetworkId,
+    NetworkInfo, NullifierProof, SettledStateAnchor, TokenInfo,
 };
 use alloy::{primitives::B256, providers::Provider};
 use error::{Error, RpcResult};
 use futures::FutureExt;
 use hyper::StatusCode;
 use jsonrpsee::{
-    core::async_trait,
+    core::{async_trait, SubscriptionResult},
     proc_macros::rpc,
     server::{HttpBody, PingConfig, ServerBuilder},
+    PendingSubscriptionSink, SubscriptionMessage,
 };
+use tokio::sync::broadcast::error::RecvError;
 use tower_http::{compression::CompressionLayer, cors::CorsLayer};
 use tracing::{info, warn};
 
 use crate::{service::AgglayerService, signed_tx::SignedTx};
 
+mod admin_audit;
+pub mod admin_auth;
 mod error;
 pub mod kernel;
 mod rpc_middleware;
@@ -54,6 +60,14 @@ trait Agglayer {
     #[method(name = "sendCertificate")]
     async fn send_certificate(&self, certificate: Certificate) -> RpcResult<CertificateId>;
 
+    /// Dry-run a certificate against the settled state of its network,
+    /// without submitting it.
+    #[method(name = "simulateCertificate")]
+    async fn simulate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> RpcResult<CertificateSimulation>;
+
     #[method(name = "getCertificateHeader")]
     async fn get_certificate_header(
         &self,
@@ -83,6 +97,72 @@ trait Agglayer {
 
     #[method(name = "getNetworkInfo")]
     async fn get_network_info(&self, network_id: NetworkId) -> RpcResult<NetworkInfo>;
+
+    /// List the settled certificate headers of a network, starting at
+    /// `from_height`. The direction defaults to ascending heights, and a zero
+    /// `limit` is refused.
+    #[method(name = "listCertificateHeaders")]
+    async fn list_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        direction: Option<ListDirection>,
+    ) -> RpcResult<Vec<CertificateHeader>>;
+
+    /// Get the roots of the state of a network once its certificate at
+    /// `height` got settled.
+    #[method(name = "getNetworkStateAt")]
+    async fn get_network_state_at(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<SettledStateAnchor>;
+
+    /// Prove the balance of a token in the local balance tree of a network,
+    /// as of its settled certificate at `height`, or its latest settled
+    /// certificate by default.
+    #[method(name = "getBalanceProof")]
+    async fn get_balance_proof(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+        height: Option<Height>,
+    ) -> RpcResult<BalanceProof>;
+
+    /// Prove that a global index is claimed or not in the nullifier tree of a
+    /// network, as of its settled certificate at `height`, or its latest
+    /// settled certificate by default.
+    #[method(name = "getNullifierProof")]
+    async fn get_nullifier_proof(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+        height: Option<Height>,
+    ) -> RpcResult<NullifierProof>;
+
+    /// Prove a leaf of the local exit tree of a network, as of its settled
+    /// certificate at `height`, or its latest settled certificate by default.
+    #[method(name = "getLocalExitTreeProof")]
+    async fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        height: Option<Height>,
+    ) -> RpcResult<LocalExitTreeProof>;
+
+    /// Subscribe to the certificate status transitions, optionally filtered
+    /// by network and/or certificate.
+    #[subscription(
+        name = "subscribeCertificateStatus" => "certificateStatus",
+        unsubscribe = "unsubscribeCertificateStatus",
+        item = CertificateHeader
+    )]
+    async fn subscribe_certificate_status(
+        &self,
+        network_id: Option<NetworkId>,
+        certificate_id: Option<CertificateId>,
+    ) -> SubscriptionResult;
 }
 
 /// The RPC agglayer service implementation.
@@ -233,6 +313,13 @@ where
         Ok(self.rpc_service.send_certificate(certificate).await?)
     }
 
+    async fn simulate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> RpcResult<CertificateSimulation> {
+        Ok(self.rpc_service.simulate_certificate(certificate).await?)
+    }
+
     async fn get_certificate_header(
         &self,
         certificate_id: CertificateId,
@@ -284,6 +371,105 @@ where
 
         Ok(state)
     }
+
+    async fn list_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        direction: Option<ListDirection>,
+    ) -> RpcResult<Vec<CertificateHeader>> {
+        let headers = self.rpc_service.list_certificate_headers(
+            network_id,
+            from_height,
+            limit,
+            direction.unwrap_or_default(),
+        )?;
+
+        Ok(headers)
+    }
+
+    async fn get_network_state_at(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<SettledStateAnchor> {
+        Ok(self.rpc_service.get_network_state_at(network_id, height)?)
+    }
+
+    async fn get_balance_proof(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+        height: Option<Height>,
+    ) -> RpcResult<BalanceProof> {
+        Ok(self
+            .rpc_service
+            .get_balance_proof(network_id, token_info, height)?)
+    }
+
+    async fn get_nullifier_proof(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+        height: Option<Height>,
+    ) -> RpcResult<NullifierProof> {
+        Ok(self
+            .rpc_service
+            .get_nullifier_proof(network_id, global_index, height)?)
+    }
+
+    async fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        height: Option<Height>,
+    ) -> RpcResult<LocalExitTreeProof> {
+        Ok(self
+            .rpc_service
+            .get_local_exit_tree_proof(network_id, leaf_index, height)?)
+    }
+
+    async fn subscribe_certificate_status(
+        &self,
+        pending: PendingSubscriptionSink,
+        network_id: Option<NetworkId>,
+        certificate_id: Option<CertificateId>,
+    ) -> SubscriptionResult {
+        let filter = agglayer_rpc::CertificateStatusFilter {
+            network_id,
+            certificate_id,
+        };
+
+        // Subscribe before accepting so that no transition is missed once the
+        // client knows about the subscription.
+        let mut receiver = self.rpc_service.subscribe_certificate_status();
+        let sink = pending.accept().await?;
+
+        loop {
+            tokio::select! {
+                _ = sink.closed() => return Ok(()),
+                header = receiver.recv() => match header {
+                    Ok(header) if filter.matches(&header) => {
+                        let message = SubscriptionMessage::from_json(&header)?;
+                        if sink.send(message).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Certificate status subscriber lagged behind");
+                        return Err(format!(
+                            "Subscriber lagged behind, {skipped} certificate status transitions \
+                             were dropped"
+                        )
+                        .into());
+                    }
+                    Err(RecvError::Closed) => return Ok(()),
+                }
+            }
+        }
+    }
 }
 
 type TxStatus = String;
@@ -0,0 +1,75 @@
+//! Role-based authorization of admin RPC calls.
+
+use agglayer_types::RpcErrorCode;
+use futures::future::{ready, Either, Ready};
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::ErrorObjectOwned, MethodResponse};
+use tracing::warn;
+
+use crate::{
+    admin_auth::{required_role, AdminPrincipal},
+    error::Error,
+};
+
+/// An RPC layer refusing admin calls the authenticated caller's role does not
+/// allow.
+///
+/// Relies on the HTTP authentication middleware to attach the caller's
+/// [`AdminPrincipal`] to the request. When `enforce` is unset, as for an
+/// admin listener without authentication, every call is let through.
+#[derive(Clone, Copy, Debug)]
+pub struct AdminAuthorizationLayer {
+    enforce: bool,
+}
+
+impl AdminAuthorizationLayer {
+    pub fn new(enforce: bool) -> Self {
+        Self { enforce }
+    }
+}
+
+impl<S> tower::Layer<S> for AdminAuthorizationLayer {
+    type Service = AdminAuthorizationService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AdminAuthorizationService {
+            inner,
+            enforce: self.enforce,
+        }
+    }
+}
+
+pub struct AdminAuthorizationService<S> {
+    inner: S,
+    enforce: bool,
+}
+
+impl<'a, S: RpcServiceT<'a>> RpcServiceT<'a> for AdminAuthorizationService<S> {
+    type Future = Either<Ready<MethodResponse>, S::Future>;
+
+    fn call(&self, request: jsonrpsee::types::Request<'a>) -> Self::Future {
+        if !self.enforce {
+            return Either::Right(self.inner.call(request));
+        }
+
+        let required = required_role(&request.method);
+        let principal = request.extensions().get::<AdminPrincipal>();
+        if principal.is_some_and(|principal| principal.role >= required) {
+            return Either::Right(self.inner.call(request));
+        }
+
+        let method = &*request.method;
+        let caller = principal.map_or("unauthenticated caller", |principal| &principal.name);
+        warn!(
+            method,
+            caller, "Denied admin call requiring the {required} role"
+        );
+        let error = Error::Classified {
+            code: RpcErrorCode::PermissionDenied,
+            message: format!("{method} requires the {required} role"),
+        };
+        Either::Left(ready(MethodResponse::error(
+            request.id,
+            ErrorObjectOwned::from(error),
+        )))
+    }
+}
@@ -3,12 +3,14 @@
 use jsonrpsee::{server::middleware::rpc::RpcServiceBuilder, types::Id};
 use tower::layer::util::{Identity, Stack};
 
+mod admin_authorization;
 mod cancel_logger;
 mod logging_timeout;
 
 #[cfg(test)]
 mod tests;
 
+pub use admin_authorization::AdminAuthorizationLayer;
 pub use cancel_logger::CancelLoggerLayer;
 pub use logging_timeout::LoggingTimeoutLayer;
 
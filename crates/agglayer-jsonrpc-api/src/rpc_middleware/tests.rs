@@ -11,7 +11,7 @@ use jsonrpsee::{
 };
 use tracing_subscriber::layer::SubscriberExt;
 
-use super::LoggingTimeoutLayer;
+use super::{AdminAuthorizationLayer, LoggingTimeoutLayer};
 
 #[rpc(server)]
 trait Test {
@@ -163,3 +163,62 @@ async fn request_dropped() {
     assert!(!log_contains(&log, TIMED_OUT_STR));
     assert!(log_contains(&log, CANCELLED_STR));
 }
+
+/// Inner service answering every call, to observe what the authorization
+/// layer lets through.
+struct AnswerEverything;
+
+impl<'a> RpcServiceT<'a> for AnswerEverything {
+    type Future = futures::future::Ready<jsonrpsee::MethodResponse>;
+
+    fn call(&self, request: jsonrpsee::types::Request<'a>) -> Self::Future {
+        futures::future::ready(jsonrpsee::MethodResponse::response(
+            request.id,
+            jsonrpsee::ResponsePayload::success("answered"),
+            usize::MAX,
+        ))
+    }
+}
+
+async fn call_as(
+    enforce: bool,
+    method: &'static str,
+    role: Option<agglayer_config::admin_auth::AdminRole>,
+) -> jsonrpsee::MethodResponse {
+    let service = tower::Layer::layer(&AdminAuthorizationLayer::new(enforce), AnswerEverything);
+    let mut request =
+        jsonrpsee::types::Request::new(method.into(), None, jsonrpsee::types::Id::Number(1));
+    if let Some(role) = role {
+        request
+            .extensions_mut()
+            .insert(crate::admin_auth::AdminPrincipal {
+                name: "tester".to_string(),
+                role,
+            });
+    }
+    service.call(request).await
+}
+
+#[tokio::test]
+async fn admin_calls_are_authorized_by_role() {
+    use agglayer_config::admin_auth::AdminRole;
+    use agglayer_types::RpcErrorCode;
+
+    let permission_denied = Some(RpcErrorCode::PermissionDenied.code());
+
+    let response = call_as(true, "admin_getCertificate", Some(AdminRole::ReadOnly)).await;
+    assert!(response.is_success());
+
+    let response = call_as(true, "admin_disableNetwork", Some(AdminRole::ReadOnly)).await;
+    assert_eq!(response.as_error_code(), permission_denied);
+
+    let response = call_as(true, "admin_disableNetwork", Some(AdminRole::Operator)).await;
+    assert!(response.is_success());
+
+    let response = call_as(true, "admin_getCertificate", None).await;
+    assert_eq!(response.as_error_code(), permission_denied);
+
+    // Without authentication configured, every call goes through.
+    let response = call_as(false, "admin_disableNetwork", None).await;
+    assert!(response.is_success());
+}
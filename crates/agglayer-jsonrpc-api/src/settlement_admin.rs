@@ -4,14 +4,17 @@
 //! representation is owned here, at the RPC boundary. Attempt-result enums are
 //! deliberately internally tagged (`#[serde(tag = "type")]`).
 
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime},
+};
 
 use agglayer_settlement_service::{LiveTaskNotification, NewSettlementAttempt};
 use agglayer_storage::stores::EditEvenIfCompleted;
 use agglayer_types::{
-    Address, CertificateId, ClientErrorType, ContractCallOutcome, Nonce, RpcErrorCode,
-    SettlementAttempt, SettlementAttemptResult, SettlementJobId, SettlementJobResult,
-    SettlementTxHash, B256,
+    Address, CertificateId, ClientErrorType, ContractCallOutcome, EpochNumber, NetworkId, Nonce,
+    RpcErrorCode, SettlementAttempt, SettlementAttemptResult, SettlementGasCost, SettlementJobId,
+    SettlementJobResult, SettlementTxHash, B256,
 };
 use serde::{Deserialize, Serialize};
 
@@ -23,13 +26,15 @@ mod tests;
 /// Status reported for a settlement job.
 ///
 /// Readable jobs are pending while no terminal result exists and completed
-/// once it does. `Unreadable` is only emitted by the list method when a
+/// once it does, or cancelled when a network rollback removed their
+/// certificate first. `Unreadable` is only emitted by the list method when a
 /// per-job storage read fails.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SettlementJobStatus {
     Pending,
     Completed,
+    Cancelled,
     Unreadable,
 }
 
@@ -105,6 +110,8 @@ pub struct SettlementJobDetail {
     pub eth_value: agglayer_types::U256,
     pub gas_limit: u128,
     pub calldata: alloy::primitives::Bytes,
+    /// Network the job settles for, which drives its wallet assignment.
+    pub network_id: Option<NetworkId>,
     pub attempts: Vec<SettlementAttemptDetail>,
     pub job_result: Option<SettlementJobResultDto>,
     pub last_error: Option<String>,
@@ -199,6 +206,7 @@ fn client_error_kind_tag(kind: ClientErrorType) -> &'static str {
         ClientErrorType::NonceAlreadyUsed => "nonceAlreadyUsed",
         ClientErrorType::SettlementSucceededElsewhere => "settlementSucceededElsewhere",
         ClientErrorType::AbandonedByAdmin => "abandonedByAdmin",
+        ClientErrorType::SimulatedRevert => "simulatedRevert",
     }
 }
 
@@ -271,7 +279,7 @@ pub(crate) fn render_last_error(results: &[(u64, SettlementAttemptResult)]) -> O
 }
 
 /// A settlement attempt as accepted by `admin_insertSettlementAttempt`.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct InsertAttemptParams {
     /// Hash of the settlement transaction. The only mandatory field. When L1
@@ -334,7 +342,7 @@ impl From<InsertAttemptParams> for NewSettlementAttempt {
 /// corrections must land while the job still has its terminal result,
 /// because removing the result immediately respawns the task, which could
 /// re-derive and re-record the job result from the uncorrected attempts.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum Force {
     /// Apply the mutation even if the job already has a terminal result.
     #[serde(rename = "force=true")]
@@ -371,6 +379,186 @@ pub struct MutationResponse {
     pub live_task: LiveTaskNotification,
 }
 
+/// Gas paid by a set of mined settlement transactions.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementCost {
+    /// Number of mined transactions, reverted ones included.
+    pub transactions: u64,
+    pub gas_used: u64,
+    /// Total of `gasUsed * effectiveGasPrice`, in wei.
+    pub spent_wei: u128,
+}
+
+impl SettlementCost {
+    /// Adds the share of one transaction's cost.
+    fn add(&mut self, gas_used: u64, spent_wei: u128) {
+        self.transactions += 1;
+        self.gas_used = self.gas_used.saturating_add(gas_used);
+        self.spent_wei = self.spent_wei.saturating_add(spent_wei);
+    }
+}
+
+/// Settlement spend of one network.
+///
+/// A batch transaction counts for every network it settles certificates of,
+/// each paying an even share of its cost per batched job.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkSettlementCost {
+    /// `null` for jobs not tied to a network.
+    pub network_id: Option<NetworkId>,
+    pub cost: SettlementCost,
+}
+
+/// Settlement spend of one settlement wallet.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSettlementCost {
+    pub wallet: Address,
+    pub cost: SettlementCost,
+}
+
+/// Settlement spend of the certificates of one epoch.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochSettlementCost {
+    /// `null` for jobs whose certificate has no epoch, or no certificate.
+    pub epoch_number: Option<EpochNumber>,
+    pub cost: SettlementCost,
+}
+
+/// Report returned by `admin_getSettlementCostReport`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementCostReport {
+    pub network_id: Option<NetworkId>,
+    pub from_unix_secs: u64,
+    pub to_unix_secs: u64,
+    pub total: SettlementCost,
+    pub networks: Vec<NetworkSettlementCost>,
+    pub wallets: Vec<WalletSettlementCost>,
+    pub epochs: Vec<EpochSettlementCost>,
+    /// Mined transactions recorded before gas costs were, which the totals
+    /// leave out.
+    pub uncosted_transactions: u64,
+}
+
+/// One mined settlement transaction, as read for a cost report.
+#[derive(Clone, Debug)]
+pub(crate) struct SettlementCostSample {
+    /// The jobs the transaction settled: the job itself, or the batched jobs
+    /// of a batch, each paying an even share of the cost.
+    pub shares: Vec<SettlementCostShare>,
+    pub wallet: Address,
+    pub submission_time: SystemTime,
+    pub gas_cost: Option<SettlementGasCost>,
+}
+
+/// Where one job settled by a [`SettlementCostSample`] charges its share.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SettlementCostShare {
+    pub network_id: Option<NetworkId>,
+    pub epoch_number: Option<EpochNumber>,
+}
+
+impl SettlementCostReport {
+    /// Aggregates the samples submitted in `[from_unix_secs, to_unix_secs)`.
+    ///
+    /// With a `network_id`, only the shares of that network are counted, the
+    /// wallet and total rows included.
+    pub(crate) fn aggregate(
+        network_id: Option<NetworkId>,
+        from_unix_secs: u64,
+        to_unix_secs: u64,
+        samples: impl IntoIterator<Item = SettlementCostSample>,
+    ) -> Self {
+        let from = SystemTime::UNIX_EPOCH + Duration::from_secs(from_unix_secs);
+        let to = SystemTime::UNIX_EPOCH + Duration::from_secs(to_unix_secs);
+
+        let mut total = SettlementCost::default();
+        let mut networks = BTreeMap::<Option<NetworkId>, SettlementCost>::new();
+        let mut wallets = BTreeMap::<Address, SettlementCost>::new();
+        let mut epochs = BTreeMap::<Option<EpochNumber>, SettlementCost>::new();
+        let mut uncosted_transactions = 0;
+        for sample in samples {
+            if sample.submission_time < from || sample.submission_time >= to {
+                continue;
+            }
+            let is_counted = |share: &SettlementCostShare| {
+                network_id.is_none() || share.network_id == network_id
+            };
+            if !sample.shares.iter().any(is_counted) {
+                continue;
+            }
+            let Some(gas_cost) = sample.gas_cost else {
+                uncosted_transactions += 1;
+                continue;
+            };
+
+            // Sum the shares per row first, so that a transaction counts once
+            // per row whatever the number of its jobs charged there.
+            let mut sample_total = (0, 0);
+            let mut sample_networks = BTreeMap::<Option<NetworkId>, (u64, u128)>::new();
+            let mut sample_epochs = BTreeMap::<Option<EpochNumber>, (u64, u128)>::new();
+            for (share, (gas_used, spent_wei)) in sample
+                .shares
+                .iter()
+                .zip(gas_cost.split(sample.shares.len()))
+                .filter(|(share, _)| is_counted(share))
+            {
+                for row in [
+                    &mut sample_total,
+                    sample_networks.entry(share.network_id).or_default(),
+                    sample_epochs.entry(share.epoch_number).or_default(),
+                ] {
+                    row.0 += gas_used;
+                    row.1 += spent_wei;
+                }
+            }
+
+            let (gas_used, spent_wei) = sample_total;
+            total.add(gas_used, spent_wei);
+            wallets
+                .entry(sample.wallet)
+                .or_default()
+                .add(gas_used, spent_wei);
+            for (network_id, (gas_used, spent_wei)) in sample_networks {
+                networks
+                    .entry(network_id)
+                    .or_default()
+                    .add(gas_used, spent_wei);
+            }
+            for (epoch_number, (gas_used, spent_wei)) in sample_epochs {
+                epochs
+                    .entry(epoch_number)
+                    .or_default()
+                    .add(gas_used, spent_wei);
+            }
+        }
+
+        Self {
+            network_id,
+            from_unix_secs,
+            to_unix_secs,
+            total,
+            networks: networks
+                .into_iter()
+                .map(|(network_id, cost)| NetworkSettlementCost { network_id, cost })
+                .collect(),
+            wallets: wallets
+                .into_iter()
+                .map(|(wallet, cost)| WalletSettlementCost { wallet, cost })
+                .collect(),
+            epochs: epochs
+                .into_iter()
+                .map(|(epoch_number, cost)| EpochSettlementCost { epoch_number, cost })
+                .collect(),
+            uncosted_transactions,
+        }
+    }
+}
+
 /// Turns a settlement-service error report into the private admin RPC error
 /// contract.
 pub(crate) fn map_admin_error(report: eyre::Report) -> Error {
@@ -1,8 +1,9 @@
 use std::time::{Duration, SystemTime};
 
 use agglayer_types::{
-    Address, ClientError, ClientErrorType, ContractCallOutcome, ContractCallResult, Digest, Nonce,
-    SettlementAttempt, SettlementAttemptResult, SettlementJobId, SettlementTxHash, B256, U256,
+    Address, ClientError, ClientErrorType, ContractCallOutcome, ContractCallResult, Digest,
+    EpochNumber, NetworkId, Nonce, SettlementAttempt, SettlementAttemptResult, SettlementGasCost,
+    SettlementJobId, SettlementTxHash, B256, U256,
 };
 
 use super::*;
@@ -15,6 +16,7 @@ fn attempt(seed: u64) -> SettlementAttempt {
         submission_time: SystemTime::UNIX_EPOCH + Duration::from_secs(seed),
         max_fee_per_gas: 30_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -36,6 +38,7 @@ fn contract_call_result(outcome: ContractCallOutcome) -> SettlementAttemptResult
         block_hash: B256::from([9_u8; 32]),
         block_number: 9,
         tx_hash: SettlementTxHash::new(Digest::from([9_u8; 32])),
+        gas_cost: None,
     })
 }
 
@@ -49,6 +52,7 @@ fn job_detail(attempts: Vec<SettlementAttemptDetail>) -> SettlementJobDetail {
         eth_value: U256::ZERO,
         gas_limit: 0,
         calldata: Default::default(),
+        network_id: None,
         attempts,
         job_result: None,
         last_error: Some("newest failure".to_string()),
@@ -146,6 +150,7 @@ fn client_error_kinds_serialize_as_stable_camel_case_tags() {
             "settlementSucceededElsewhere",
         ),
         (ClientErrorType::AbandonedByAdmin, "abandonedByAdmin"),
+        (ClientErrorType::SimulatedRevert, "simulatedRevert"),
     ] {
         let result = client_error_result(kind, "message");
         let dto = SettlementAttemptResultDto::from(&result);
@@ -193,3 +198,191 @@ fn unreadable_job_summary_serializes_the_exact_error() {
         })
     );
 }
+
+fn cost_share(network_id: u32, epoch_number: Option<u64>) -> SettlementCostShare {
+    SettlementCostShare {
+        network_id: Some(NetworkId::new(network_id)),
+        epoch_number: epoch_number.map(EpochNumber::new),
+    }
+}
+
+fn cost_sample(
+    network_id: u32,
+    epoch_number: Option<u64>,
+    wallet: u8,
+    submitted_at: u64,
+    gas_used: Option<u64>,
+) -> SettlementCostSample {
+    SettlementCostSample {
+        shares: vec![cost_share(network_id, epoch_number)],
+        wallet: Address::from([wallet; 20]),
+        submission_time: SystemTime::UNIX_EPOCH + Duration::from_secs(submitted_at),
+        gas_cost: gas_used.map(|gas_used| SettlementGasCost {
+            gas_used,
+            effective_gas_price: 10,
+        }),
+    }
+}
+
+#[test]
+fn cost_report_aggregates_per_network_wallet_and_epoch() {
+    let samples = vec![
+        cost_sample(1, Some(3), 1, 100, Some(1_000)),
+        cost_sample(1, Some(4), 2, 150, Some(2_000)),
+        cost_sample(2, Some(4), 1, 199, Some(4_000)),
+    ];
+
+    let report = SettlementCostReport::aggregate(None, 100, 200, samples);
+
+    assert_eq!(
+        report.total,
+        SettlementCost {
+            transactions: 3,
+            gas_used: 7_000,
+            spent_wei: 70_000,
+        }
+    );
+    assert_eq!(
+        report
+            .networks
+            .iter()
+            .map(|row| (row.network_id, row.cost.spent_wei))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(NetworkId::new(1)), 30_000),
+            (Some(NetworkId::new(2)), 40_000),
+        ]
+    );
+    assert_eq!(
+        report
+            .wallets
+            .iter()
+            .map(|row| (row.wallet, row.cost.transactions))
+            .collect::<Vec<_>>(),
+        vec![(Address::from([1; 20]), 2), (Address::from([2; 20]), 1)]
+    );
+    assert_eq!(
+        report
+            .epochs
+            .iter()
+            .map(|row| (row.epoch_number, row.cost.gas_used))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(EpochNumber::new(3)), 1_000),
+            (Some(EpochNumber::new(4)), 6_000)
+        ]
+    );
+}
+
+#[test]
+fn cost_report_splits_batch_transactions_across_their_jobs() {
+    let batch = || SettlementCostSample {
+        shares: vec![
+            cost_share(1, Some(3)),
+            cost_share(2, Some(3)),
+            cost_share(1, Some(4)),
+        ],
+        ..cost_sample(0, None, 1, 100, Some(3_000))
+    };
+
+    let report = SettlementCostReport::aggregate(None, 100, 200, vec![batch()]);
+
+    assert_eq!(
+        report.total,
+        SettlementCost {
+            transactions: 1,
+            gas_used: 3_000,
+            spent_wei: 30_000,
+        }
+    );
+    assert_eq!(
+        report
+            .networks
+            .iter()
+            .map(|row| (row.network_id, row.cost))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                Some(NetworkId::new(1)),
+                SettlementCost {
+                    transactions: 1,
+                    gas_used: 2_000,
+                    spent_wei: 20_000,
+                }
+            ),
+            (
+                Some(NetworkId::new(2)),
+                SettlementCost {
+                    transactions: 1,
+                    gas_used: 1_000,
+                    spent_wei: 10_000,
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        report
+            .epochs
+            .iter()
+            .map(|row| (row.epoch_number, row.cost.gas_used))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(EpochNumber::new(3)), 2_000),
+            (Some(EpochNumber::new(4)), 1_000)
+        ]
+    );
+
+    // A report on one network only counts the shares of that network.
+    let report = SettlementCostReport::aggregate(
+        Some(NetworkId::new(2)),
+        100,
+        200,
+        vec![batch(), cost_sample(3, None, 1, 150, Some(5_000))],
+    );
+
+    assert_eq!(
+        report.total,
+        SettlementCost {
+            transactions: 1,
+            gas_used: 1_000,
+            spent_wei: 10_000,
+        }
+    );
+    assert_eq!(report.wallets[0].cost, report.total);
+    assert_eq!(report.networks.len(), 1);
+    assert_eq!(report.epochs[0].epoch_number, Some(EpochNumber::new(3)));
+}
+
+#[test]
+fn cost_report_window_is_half_open_and_skips_uncosted_transactions() {
+    let samples = vec![
+        cost_sample(1, None, 1, 99, Some(1_000)),
+        cost_sample(1, None, 1, 100, Some(2_000)),
+        cost_sample(1, None, 1, 150, None),
+        cost_sample(1, None, 1, 200, Some(4_000)),
+    ];
+
+    let report = SettlementCostReport::aggregate(None, 100, 200, samples);
+
+    assert_eq!(report.total.transactions, 1);
+    assert_eq!(report.total.gas_used, 2_000);
+    assert_eq!(report.uncosted_transactions, 1);
+}
+
+#[test]
+fn cost_report_serializes_camel_case() {
+    let report = SettlementCostReport::aggregate(
+        Some(NetworkId::new(1)),
+        0,
+        10,
+        vec![cost_sample(1, Some(2), 3, 5, Some(21_000))],
+    );
+
+    let json = serde_json::to_value(report).expect("report must serialize");
+    assert_eq!(json["fromUnixSecs"], 0);
+    assert_eq!(json["uncostedTransactions"], 0);
+    assert_eq!(json["total"]["spentWei"], 210_000);
+    assert_eq!(json["networks"][0]["networkId"], 1);
+    assert_eq!(json["networks"][0]["cost"]["gasUsed"], 21_000);
+    assert_eq!(json["epochs"][0]["epochNumber"], 2);
+}
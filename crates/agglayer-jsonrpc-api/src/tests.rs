@@ -1,9 +1,16 @@
+mod admin_audit;
 mod errors;
 mod get_certificate_header;
 mod get_epoch_configuration;
 mod get_latest_known_certificate_header;
+mod get_network_state_at;
+mod get_state_proofs;
 mod get_token_balance;
 mod get_tx_status;
+mod list_certificate_headers;
+mod rollback_network;
 mod send_certificate;
 mod send_tx;
 mod settlement_admin;
+mod subscribe_certificate_status;
+mod verify_state;
@@ -0,0 +1,171 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use agglayer_types::{NetworkId, RpcErrorCode, SettlementJobId};
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+use serde_json::json;
+
+use crate::testutils::TestContext;
+
+fn unix_secs_in(seconds: u64) -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + seconds
+}
+
+#[test_log::test(tokio::test)]
+async fn admin_mutations_are_listed_in_the_audit_log() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let network_id = NetworkId::new(7);
+
+    let _: () = context
+        .admin_client
+        .request("admin_disableNetwork", rpc_params![network_id])
+        .await
+        .unwrap();
+    let _: () = context
+        .admin_client
+        .request("admin_enableNetwork", rpc_params![network_id])
+        .await
+        .unwrap();
+
+    let events: Vec<serde_json::Value> = context
+        .admin_client
+        .request(
+            "admin_listAuditEvents",
+            rpc_params![0, unix_secs_in(60), Option::<String>::None],
+        )
+        .await
+        .unwrap();
+
+    let events = events
+        .into_iter()
+        .map(|mut event| {
+            let fields = event.as_object_mut().unwrap();
+            assert!(fields.remove("id").is_some());
+            assert!(fields.remove("recordedAtUnixMillis").is_some());
+            event
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        vec![
+            json!({
+                "caller": "unauthenticated",
+                "role": null,
+                "method": "admin_disableNetwork",
+                "networkId": network_id,
+                "params": { "networkId": network_id },
+                "before": { "disabled": false },
+                "after": { "disabled": true },
+                "outcome": { "status": "applied", "liveTask": null },
+            }),
+            json!({
+                "caller": "unauthenticated",
+                "role": null,
+                "method": "admin_enableNetwork",
+                "networkId": network_id,
+                "params": { "networkId": network_id },
+                "before": { "disabled": true },
+                "after": { "disabled": false },
+                "outcome": { "status": "applied", "liveTask": null },
+            }),
+        ]
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn refused_admin_mutations_are_audited_and_filtered_by_method() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let job_id = SettlementJobId::from(404_u128);
+
+    let _: () = context
+        .admin_client
+        .request("admin_disableNetwork", rpc_params![NetworkId::new(1)])
+        .await
+        .unwrap();
+    context
+        .admin_client
+        .request::<(), _>("admin_abortSettlementTask", rpc_params![job_id])
+        .await
+        .unwrap_err();
+
+    let events: Vec<serde_json::Value> = context
+        .admin_client
+        .request(
+            "admin_listAuditEvents",
+            rpc_params![0, unix_secs_in(60), "admin_abortSettlementTask"],
+        )
+        .await
+        .unwrap();
+
+    let [event] = &events[..] else {
+        panic!("expected a single abort event, got {events:?}");
+    };
+    assert_eq!(event["method"], "admin_abortSettlementTask");
+    assert_eq!(event["params"], json!({ "jobId": job_id }));
+    assert_eq!(event["before"], json!(null));
+    assert_eq!(event["outcome"]["status"], "failed");
+    assert!(event["outcome"]["error"]
+        .as_str()
+        .unwrap()
+        .contains(&job_id.to_string()));
+}
+
+#[test_log::test(tokio::test)]
+async fn audit_listing_returns_at_most_limit_events() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+
+    for network_id in 1..=3 {
+        let _: () = context
+            .admin_client
+            .request(
+                "admin_disableNetwork",
+                rpc_params![NetworkId::new(network_id)],
+            )
+            .await
+            .unwrap();
+    }
+
+    let events: Vec<serde_json::Value> = context
+        .admin_client
+        .request(
+            "admin_listAuditEvents",
+            rpc_params![0, unix_secs_in(60), "admin_disableNetwork", 2],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event["networkId"].clone())
+            .collect::<Vec<_>>(),
+        vec![json!(1), json!(2)]
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn audit_listing_rejects_an_inverted_window() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+
+    let error = context
+        .admin_client
+        .request::<Vec<serde_json::Value>, _>(
+            "admin_listAuditEvents",
+            rpc_params![20, 10, Option::<String>::None],
+        )
+        .await
+        .unwrap_err();
+
+    match error {
+        ClientError::Call(error) => {
+            assert_eq!(error.code(), RpcErrorCode::InvalidParams.code());
+        }
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
@@ -147,6 +147,23 @@ type WallClockLimitedInfo = <component::SendTx as Component>::LimitedInfo;
     "cert_notfound",
     agglayer_rpc::CertificateRetrievalError::NotFound { certificate_id: CertificateId::new(Digest([0x51; 32])) }
 )]
+#[case(
+    "cert_rate_limited",
+    agglayer_rpc::CertificateSubmissionError::RateLimited(
+        agglayer_rate_limiting::RateLimited::SendCertificateRateLimited(WallClockLimitedInfo {
+            max_per_interval: 5,
+            time_interval: Duration::from_secs(60),
+            until_next: Some(Duration::from_millis(12_300)),
+        })
+    )
+)]
+#[case(
+    "cert_too_many_pending",
+    agglayer_rpc::CertificateSubmissionError::TooManyPendingCertificates {
+        network_id: agglayer_types::NetworkId::new(7),
+        max: 16,
+    }
+)]
 #[case(
     "method_disabled",
     Error::MethodDisabled { method: "interop_sendTx" }
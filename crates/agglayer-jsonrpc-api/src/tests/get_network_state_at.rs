@@ -0,0 +1,177 @@
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{
+    BalanceProof, Certificate, CertificateId, CertificateIndex, CertificateStatus, Digest,
+    EpochNumber, Height, LocalExitTreeProof, LocalNetworkStateData, NetworkId, RpcErrorCode,
+    SettledStateAnchor, U256,
+};
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+use unified_bridge::L1_ETH;
+
+use crate::testutils::TestContext;
+
+const NETWORK_ID: NetworkId = NetworkId::new(3);
+
+/// Settles a certificate at `height` for [`NETWORK_ID`], moving its state to
+/// `state`.
+fn settle_state(
+    context: &TestContext,
+    height: Height,
+    state: &LocalNetworkStateData,
+    new_leaves: &[Digest],
+) -> CertificateId {
+    let mut certificate = Certificate::new_for_test(NETWORK_ID, height);
+    certificate.new_local_exit_root = state.exit_tree.get_root().into();
+    let certificate_id = certificate.hash();
+
+    context
+        .state_store
+        .insert_certificate_header(&certificate, CertificateStatus::Settled)
+        .unwrap();
+    context
+        .state_store
+        .write_local_network_state(&NETWORK_ID, state, new_leaves)
+        .unwrap();
+    context
+        .state_store
+        .set_latest_settled_certificate_for_network(
+            &NETWORK_ID,
+            &height,
+            &certificate_id,
+            &EpochNumber::ZERO,
+            &CertificateIndex::new(height.as_u64()),
+        )
+        .unwrap();
+
+    certificate_id
+}
+
+/// Settles two certificates, each adding a leaf and changing the balance of
+/// [`L1_ETH`]. Returns the state and certificate after each of them.
+fn settle_history(context: &TestContext) -> Vec<(LocalNetworkStateData, CertificateId)> {
+    let mut state = LocalNetworkStateData::default();
+    let mut history = Vec::new();
+    for (height, balance) in [(0u8, 1_000u64), (1, 400)] {
+        let leaf = Digest([height + 1; 32]);
+        state.exit_tree.add_leaf(leaf).unwrap();
+        state
+            .balance_tree
+            .update(L1_ETH, Digest(U256::from(balance).to_be_bytes()))
+            .unwrap();
+
+        let certificate_id = settle_state(context, Height::new(height as u64), &state, &[leaf]);
+        history.push((state.clone(), certificate_id));
+    }
+    history
+}
+
+fn assert_call_error(error: ClientError, code: RpcErrorCode) {
+    match error {
+        ClientError::Call(error) => assert_eq!(error.code(), code.code()),
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn network_state_is_served_at_every_settled_height() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let history = settle_history(&context);
+
+    for (height, (state, certificate_id)) in history.iter().enumerate() {
+        let anchor: SettledStateAnchor = context
+            .api_client
+            .request("interop_getNetworkStateAt", rpc_params![NETWORK_ID, height])
+            .await
+            .unwrap();
+
+        assert_eq!(anchor.certificate_id, *certificate_id);
+        assert_eq!(anchor.height, Height::new(height as u64));
+        assert_eq!(anchor.local_exit_root, state.exit_tree.get_root().into());
+        assert_eq!(anchor.let_leaf_count, state.exit_tree.leaf_count());
+        assert_eq!(anchor.balance_root, state.balance_tree.root);
+        assert_eq!(anchor.nullifier_root, state.nullifier_tree.root);
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn proofs_can_be_drawn_at_a_previous_settled_height() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let history = settle_history(&context);
+    let (state, certificate_id) = &history[0];
+
+    let proof: BalanceProof = context
+        .api_client
+        .request(
+            "interop_getBalanceProof",
+            rpc_params![NETWORK_ID, L1_ETH, 0],
+        )
+        .await
+        .unwrap();
+    assert_eq!(proof.anchor.certificate_id, *certificate_id);
+    assert_eq!(proof.anchor.balance_root, state.balance_tree.root);
+    assert_eq!(proof.balance, U256::from(1_000u64));
+    let leaf = Digest(proof.balance.to_be_bytes());
+    assert_eq!(
+        proof
+            .path
+            .verify_and_update(L1_ETH, leaf, leaf, proof.anchor.balance_root),
+        Some(proof.anchor.balance_root)
+    );
+
+    // The latest settled balance is still served by default.
+    let latest: BalanceProof = context
+        .api_client
+        .request("interop_getBalanceProof", rpc_params![NETWORK_ID, L1_ETH])
+        .await
+        .unwrap();
+    assert_eq!(latest.anchor.height, Height::new(1));
+    assert_eq!(latest.balance, U256::from(400u64));
+
+    // The local exit tree only had its first leaf at height zero.
+    let root = state.exit_tree.get_root();
+    let proof: LocalExitTreeProof = context
+        .api_client
+        .request(
+            "interop_getLocalExitTreeProof",
+            rpc_params![NETWORK_ID, 0, 0],
+        )
+        .await
+        .unwrap();
+    assert_eq!(proof.anchor.let_leaf_count, 1);
+    assert!(proof.path.verify(proof.leaf, 0, root));
+
+    let error = context
+        .api_client
+        .request::<LocalExitTreeProof, _>(
+            "interop_getLocalExitTreeProof",
+            rpc_params![NETWORK_ID, 1, 0],
+        )
+        .await
+        .unwrap_err();
+    assert_call_error(error, RpcErrorCode::InvalidParams);
+}
+
+#[test_log::test(tokio::test)]
+async fn network_state_is_refused_above_the_settled_height() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    settle_history(&context);
+
+    let error = context
+        .api_client
+        .request::<SettledStateAnchor, _>("interop_getNetworkStateAt", rpc_params![NETWORK_ID, 2])
+        .await
+        .unwrap_err();
+    assert_call_error(error, RpcErrorCode::InvalidParams);
+
+    let error = context
+        .api_client
+        .request::<SettledStateAnchor, _>(
+            "interop_getNetworkStateAt",
+            rpc_params![NetworkId::new(4), 0],
+        )
+        .await
+        .unwrap_err();
+    assert_call_error(error, RpcErrorCode::NotFound);
+}
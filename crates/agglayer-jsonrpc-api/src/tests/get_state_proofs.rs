@@ -0,0 +1,242 @@
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{
+    primitives::FromBool as _, BalanceProof, Certificate, CertificateId, CertificateIndex,
+    CertificateStatus, Digest, EpochNumber, GlobalIndex, Height, LocalExitTreeProof,
+    LocalNetworkStateData, NetworkId, NullifierProof, NullifierProofPath, RpcErrorCode, U256,
+};
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+use pessimistic_proof::nullifier_tree::NullifierKey;
+use unified_bridge::{TokenInfo, L1_ETH};
+
+use crate::testutils::TestContext;
+
+const NETWORK_ID: NetworkId = NetworkId::new(3);
+
+/// Persists `state` as the settled state of [`NETWORK_ID`], anchored to a
+/// settled certificate at height zero.
+fn settle_state(
+    context: &TestContext,
+    state: &LocalNetworkStateData,
+    leaves: &[Digest],
+) -> CertificateId {
+    let mut certificate = Certificate::new_for_test(NETWORK_ID, Height::ZERO);
+    certificate.new_local_exit_root = state.exit_tree.get_root().into();
+    let certificate_id = certificate.hash();
+
+    context
+        .state_store
+        .insert_certificate_header(&certificate, CertificateStatus::Settled)
+        .unwrap();
+    context
+        .state_store
+        .write_local_network_state(&NETWORK_ID, state, leaves)
+        .unwrap();
+    context
+        .state_store
+        .set_latest_settled_certificate_for_network(
+            &NETWORK_ID,
+            &Height::ZERO,
+            &certificate_id,
+            &EpochNumber::ZERO,
+            &CertificateIndex::ZERO,
+        )
+        .unwrap();
+
+    certificate_id
+}
+
+fn leaves() -> Vec<Digest> {
+    (1..=5u8).map(|i| Digest([i; 32])).collect()
+}
+
+fn claimed_global_index() -> GlobalIndex {
+    GlobalIndex::new(NetworkId::new(0), 12)
+}
+
+fn settled_state() -> LocalNetworkStateData {
+    let mut state = LocalNetworkStateData::default();
+    for leaf in leaves() {
+        state.exit_tree.add_leaf(leaf).unwrap();
+    }
+    state
+        .balance_tree
+        .insert(L1_ETH, Digest(U256::from(1_000u64).to_be_bytes()))
+        .unwrap();
+    state
+        .nullifier_tree
+        .insert(
+            NullifierKey::from(claimed_global_index()),
+            Digest::from_bool(true),
+        )
+        .unwrap();
+    state
+}
+
+#[test_log::test(tokio::test)]
+async fn balance_proofs_verify_against_the_settled_balance_root() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let state = settled_state();
+    let certificate_id = settle_state(&context, &state, &leaves());
+
+    let held = L1_ETH;
+    let not_held = TokenInfo {
+        origin_network: NetworkId::new(9),
+        ..L1_ETH
+    };
+    for (token_info, balance) in [(held, U256::from(1_000u64)), (not_held, U256::ZERO)] {
+        let proof: BalanceProof = context
+            .api_client
+            .request(
+                "interop_getBalanceProof",
+                rpc_params![NETWORK_ID, token_info],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(proof.anchor.certificate_id, certificate_id);
+        assert_eq!(proof.anchor.balance_root, state.balance_tree.root);
+        assert_eq!(proof.balance, balance);
+
+        let leaf = Digest(balance.to_be_bytes());
+        assert_eq!(
+            proof
+                .path
+                .verify_and_update(token_info, leaf, leaf, proof.anchor.balance_root),
+            Some(proof.anchor.balance_root)
+        );
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn nullifier_proofs_tell_claimed_global_indices_apart() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let state = settled_state();
+    settle_state(&context, &state, &leaves());
+
+    let claimed: NullifierProof = context
+        .api_client
+        .request(
+            "interop_getNullifierProof",
+            rpc_params![NETWORK_ID, claimed_global_index()],
+        )
+        .await
+        .unwrap();
+    let root = claimed.anchor.nullifier_root;
+    assert_eq!(root, state.nullifier_tree.root);
+    let key = NullifierKey::from(claimed_global_index());
+    let one = Digest::from_bool(true);
+    match claimed.path {
+        NullifierProofPath::Inclusion(path) => {
+            assert_eq!(path.verify_and_update(key, one, one, root), Some(root));
+        }
+        path => panic!("expected an inclusion proof, got {path:?}"),
+    }
+
+    let unclaimed_global_index = GlobalIndex::new(NetworkId::new(0), 13);
+    let unclaimed: NullifierProof = context
+        .api_client
+        .request(
+            "interop_getNullifierProof",
+            rpc_params![NETWORK_ID, unclaimed_global_index],
+        )
+        .await
+        .unwrap();
+    match unclaimed.path {
+        NullifierProofPath::NonInclusion(path) => {
+            let key = NullifierKey::from(unclaimed_global_index);
+            assert!(path.verify_and_update(key, one, root).is_some());
+        }
+        path => panic!("expected a non-inclusion proof, got {path:?}"),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn local_exit_tree_proofs_verify_against_the_settled_local_exit_root() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let state = settled_state();
+    settle_state(&context, &state, &leaves());
+    let root = state.exit_tree.get_root();
+
+    for (leaf_index, leaf) in leaves().into_iter().enumerate() {
+        let proof: LocalExitTreeProof = context
+            .api_client
+            .request(
+                "interop_getLocalExitTreeProof",
+                rpc_params![NETWORK_ID, leaf_index],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(proof.leaf, leaf);
+        assert_eq!(proof.anchor.local_exit_root, root.into());
+        assert_eq!(proof.anchor.let_leaf_count, 5);
+        assert!(proof.path.verify(leaf, leaf_index as u32, root));
+    }
+
+    let error = context
+        .api_client
+        .request::<LocalExitTreeProof, _>(
+            "interop_getLocalExitTreeProof",
+            rpc_params![NETWORK_ID, 5],
+        )
+        .await
+        .unwrap_err();
+    match error {
+        ClientError::Call(error) => {
+            assert_eq!(error.code(), RpcErrorCode::InvalidParams.code());
+        }
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn proofs_require_a_settled_state() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+
+    let error = context
+        .api_client
+        .request::<BalanceProof, _>("interop_getBalanceProof", rpc_params![NETWORK_ID, L1_ETH])
+        .await
+        .unwrap_err();
+    match error {
+        ClientError::Call(error) => {
+            assert_eq!(error.code(), RpcErrorCode::NotFound.code());
+        }
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn proofs_are_refused_while_the_state_is_ahead_of_the_settled_certificate() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    let state = settled_state();
+    settle_state(&context, &state, &leaves());
+
+    // A settlement in progress has persisted the next state but not yet
+    // recorded its certificate as the latest settled one.
+    let mut next_state = state.clone();
+    let next_leaf = Digest([6; 32]);
+    next_state.exit_tree.add_leaf(next_leaf).unwrap();
+    context
+        .state_store
+        .write_local_network_state(&NETWORK_ID, &next_state, &[next_leaf])
+        .unwrap();
+
+    let error = context
+        .api_client
+        .request::<LocalExitTreeProof, _>(
+            "interop_getLocalExitTreeProof",
+            rpc_params![NETWORK_ID, 0],
+        )
+        .await
+        .unwrap_err();
+    match error {
+        ClientError::Call(error) => {
+            assert_eq!(error.code(), RpcErrorCode::Unavailable.code());
+        }
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
@@ -0,0 +1,115 @@
+use agglayer_config::Config;
+use agglayer_storage::{stores::StateWriter, tests::TempDBDir};
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateStatus, Height, NetworkId, RpcErrorCode,
+};
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+
+use crate::testutils::TestContext;
+
+fn insert_certificates(context: &TestContext, network_id: NetworkId) -> Vec<Certificate> {
+    let mut certificates = Vec::new();
+    for height in 0..4 {
+        let certificate = Certificate::new_for_test(network_id, Height::new(height));
+        context
+            .state_store
+            .insert_certificate_header(&certificate, CertificateStatus::Settled)
+            .expect("unable to insert settled certificate header");
+        certificates.push(certificate);
+    }
+
+    let pending = Certificate::new_for_test(network_id, Height::new(4));
+    context
+        .state_store
+        .insert_certificate_header(&pending, CertificateStatus::Pending)
+        .expect("unable to insert pending certificate header");
+
+    certificates
+}
+
+#[test_log::test(tokio::test)]
+async fn lists_settled_headers_in_ascending_order() {
+    let tmp = TempDBDir::new();
+    let context = TestContext::new_with_config(Config::new(&tmp.path)).await;
+    let network_id = 1.into();
+    let certificates = insert_certificates(&context, network_id);
+
+    let headers: Vec<CertificateHeader> = context
+        .api_client
+        .request(
+            "interop_listCertificateHeaders",
+            rpc_params![network_id, Height::new(1), 10],
+        )
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = headers.iter().map(|header| header.certificate_id).collect();
+    let expected: Vec<_> = certificates[1..].iter().map(Certificate::hash).collect();
+    assert_eq!(ids, expected);
+    assert!(headers
+        .iter()
+        .all(|header| header.status == CertificateStatus::Settled));
+}
+
+#[test_log::test(tokio::test)]
+async fn lists_settled_headers_in_descending_order_with_limit() {
+    let tmp = TempDBDir::new();
+    let context = TestContext::new_with_config(Config::new(&tmp.path)).await;
+    let network_id = 1.into();
+    let certificates = insert_certificates(&context, network_id);
+
+    let headers: Vec<CertificateHeader> = context
+        .api_client
+        .request(
+            "interop_listCertificateHeaders",
+            rpc_params![network_id, Height::new(2), 2, "descending"],
+        )
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = headers.iter().map(|header| header.certificate_id).collect();
+    assert_eq!(ids, vec![certificates[2].hash(), certificates[1].hash()]);
+}
+
+#[test_log::test(tokio::test)]
+async fn returns_empty_list_for_unknown_network() {
+    let tmp = TempDBDir::new();
+    let context = TestContext::new_with_config(Config::new(&tmp.path)).await;
+
+    let headers: Vec<CertificateHeader> = context
+        .api_client
+        .request(
+            "interop_listCertificateHeaders",
+            rpc_params![NetworkId::new(42), Height::ZERO, 10],
+        )
+        .await
+        .unwrap();
+
+    assert!(headers.is_empty());
+}
+
+#[test_log::test(tokio::test)]
+async fn rejects_a_zero_limit() {
+    let tmp = TempDBDir::new();
+    let context = TestContext::new_with_config(Config::new(&tmp.path)).await;
+    insert_certificates(&context, 1.into());
+
+    let error = context
+        .api_client
+        .request::<Vec<CertificateHeader>, _>(
+            "interop_listCertificateHeaders",
+            rpc_params![NetworkId::new(1), Height::ZERO, 0],
+        )
+        .await
+        .unwrap_err();
+
+    match error {
+        ClientError::Call(error) => {
+            assert_eq!(error.code(), RpcErrorCode::InvalidParams.code());
+        }
+        error => panic!("expected JSON-RPC call error, got {error}"),
+    }
+}
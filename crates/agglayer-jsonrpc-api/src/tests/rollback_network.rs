@@ -0,0 +1,35 @@
+//! Tests for `admin_rollbackNetwork` on a node without a rollback service.
+
+use agglayer_rpc::network_rollback::NetworkRollbackPlan;
+use agglayer_types::{Digest, Height, NetworkId};
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+
+use crate::testutils::TestContext;
+
+#[test_log::test(tokio::test)]
+async fn rollback_network_without_rollback_service_is_disabled() {
+    let config = TestContext::get_default_config();
+    let context = TestContext::new_with_config(config).await;
+
+    let result: Result<NetworkRollbackPlan, ClientError> = context
+        .admin_client
+        .request(
+            "admin_rollbackNetwork",
+            rpc_params![NetworkId::new(1), Height::ZERO, None::<Digest>],
+        )
+        .await;
+
+    let error = result.unwrap_err();
+    let ClientError::Call(err) = error else {
+        panic!("expected a call error, got: {error}");
+    };
+
+    assert_eq!(err.code(), -10009);
+    assert_eq!(
+        err.message(),
+        "The admin_rollbackNetwork method is disabled"
+    );
+}
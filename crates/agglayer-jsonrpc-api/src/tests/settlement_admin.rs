@@ -7,9 +7,9 @@ use std::{
 use agglayer_storage::stores::{EditEvenIfCompleted, SettlementReader, SettlementWriter};
 use agglayer_types::{
     Address, CertificateId, ClientError as SettlementClientError, ClientErrorType,
-    ContractCallOutcome, ContractCallResult, Digest, Nonce, RpcErrorCode, SettlementAttempt,
-    SettlementAttemptNumber, SettlementAttemptResult, SettlementJob, SettlementJobId,
-    SettlementJobResult, SettlementTxHash, B256, U256,
+    ContractCallOutcome, ContractCallResult, Digest, NetworkId, Nonce, RpcErrorCode,
+    SettlementAttempt, SettlementAttemptNumber, SettlementAttemptResult, SettlementGasCost,
+    SettlementJob, SettlementJobId, SettlementJobResult, SettlementTxHash, B256, U256,
 };
 use alloy::{
     network::EthereumWallet,
@@ -24,7 +24,10 @@ use jsonrpsee::{
 };
 
 use crate::{
-    settlement_admin::{SettlementJobDetail, SettlementJobStatus, SettlementJobSummary},
+    settlement_admin::{
+        SettlementCost, SettlementCostReport, SettlementJobDetail, SettlementJobStatus,
+        SettlementJobSummary,
+    },
     testutils::TestContext,
 };
 
@@ -68,6 +71,10 @@ fn settlement_job() -> SettlementJob {
         calldata: vec![0x34, 0x56].into(),
         eth_value: U256::from(0),
         gas_limit: 100_000,
+        network_id: None,
+        tx_type: agglayer_types::SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
 
@@ -82,6 +89,7 @@ fn settlement_result() -> SettlementJobResult {
             block_hash: B256::from([0xbc; 32]),
             block_number: 1,
             tx_hash: SettlementTxHash::new(Digest::from([0xde; 32])),
+            gas_cost: None,
         },
     }
 }
@@ -94,6 +102,7 @@ fn settlement_attempt() -> SettlementAttempt {
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 10,
         max_priority_fee_per_gas: 1,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -764,6 +773,7 @@ fn read_attempt(seed: u8, nonce: u64) -> SettlementAttempt {
             + std::time::Duration::from_secs(1_700_000_000 + nonce),
         max_fee_per_gas: 100 + nonce as u128,
         max_priority_fee_per_gas: 10 + nonce as u128,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -807,6 +817,7 @@ fn seed_read_fixtures(context: &TestContext) -> ReadFixtures {
         block_hash: B256::from([0x52; 32]),
         block_number: 42,
         tx_hash: SettlementTxHash::new(Digest::from([0x53; 32])),
+        gas_cost: None,
     };
     context
         .state_store
@@ -1033,3 +1044,88 @@ async fn admin_get_settlement_job_unknown_id_is_not_found() {
 
     let _ = error_payload(error, RpcErrorCode::NotFound);
 }
+
+fn seed_costed_job(context: &TestContext, job_seed: u128, network_id: u32, gas_used: u64) {
+    let job_id = SettlementJobId::from(job_seed);
+    let attempt = read_attempt(job_seed as u8, 1);
+    context
+        .state_store
+        .insert_settlement_job_with_certificate(
+            &job_id,
+            &SettlementJob {
+                network_id: Some(NetworkId::new(network_id)),
+                ..settlement_job()
+            },
+            &CertificateId::new(Digest::from([job_seed as u8; 32])),
+        )
+        .unwrap();
+    context
+        .state_store
+        .insert_settlement_attempt(&job_id, 0, &attempt)
+        .unwrap();
+    context
+        .state_store
+        .record_settlement_attempt_result(
+            &job_id,
+            0,
+            &SettlementAttemptResult::ContractCall(ContractCallResult {
+                outcome: ContractCallOutcome::Revert,
+                metadata: Default::default(),
+                block_hash: B256::from([0x61; 32]),
+                block_number: 7,
+                tx_hash: attempt.hash,
+                gas_cost: Some(SettlementGasCost {
+                    gas_used,
+                    effective_gas_price: 2,
+                }),
+            }),
+        )
+        .unwrap();
+}
+
+#[test_log::test(tokio::test)]
+async fn admin_get_settlement_cost_report_sums_the_spend_of_one_network() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+    seed_costed_job(&context, 0x71, 1, 1_000);
+    seed_costed_job(&context, 0x72, 1, 3_000);
+    seed_costed_job(&context, 0x73, 2, 5_000);
+
+    let report: SettlementCostReport = context
+        .admin_client
+        .request(
+            "admin_getSettlementCostReport",
+            rpc_params![NetworkId::new(1), 1_700_000_000_u64, 1_800_000_000_u64],
+        )
+        .await
+        .unwrap();
+
+    let expected = SettlementCost {
+        transactions: 2,
+        gas_used: 4_000,
+        spent_wei: 8_000,
+    };
+    assert_eq!(report.total, expected);
+    assert_eq!(report.networks.len(), 1);
+    assert_eq!(report.networks[0].network_id, Some(NetworkId::new(1)));
+    assert_eq!(report.wallets.len(), 2);
+    // No certificate header is stored, so the spend has no epoch.
+    assert_eq!(report.epochs.len(), 1);
+    assert_eq!(report.epochs[0].epoch_number, None);
+    assert_eq!(report.epochs[0].cost, expected);
+}
+
+#[test_log::test(tokio::test)]
+async fn admin_get_settlement_cost_report_rejects_an_inverted_window() {
+    let context = TestContext::new_with_config(TestContext::get_default_config()).await;
+
+    let error = context
+        .admin_client
+        .request::<SettlementCostReport, _>(
+            "admin_getSettlementCostReport",
+            rpc_params![Option::<NetworkId>::None, 2_u64, 1_u64],
+        )
+        .await
+        .expect_err("inverted window must fail");
+
+    assert!(matches!(error, ClientError::Call(_)), "got: {error:?}");
+}
@@ -0,0 +1,88 @@
+use agglayer_storage::stores::{StateReader as _, StateWriter as _};
+use agglayer_types::{Certificate, CertificateHeader, CertificateStatus, Height};
+use jsonrpsee::rpc_params;
+use rstest::*;
+
+use crate::{
+    testutils::{raw_rpc, RawRpcContext},
+    AgglayerServer,
+};
+
+fn insert_header(raw_rpc: &RawRpcContext, certificate: &Certificate) -> CertificateHeader {
+    raw_rpc
+        .state_store
+        .insert_certificate_header(certificate, CertificateStatus::Pending)
+        .unwrap();
+
+    raw_rpc
+        .state_store
+        .get_certificate_header(&certificate.hash())
+        .unwrap()
+        .unwrap()
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn subscribe_certificate_status_by_network(#[future] raw_rpc: RawRpcContext) {
+    let header = insert_header(&raw_rpc, &Certificate::new_for_test(1.into(), Height::ZERO));
+    let other_header = insert_header(&raw_rpc, &Certificate::new_for_test(2.into(), Height::ZERO));
+    let sender = raw_rpc.certificate_status_sender.clone();
+
+    let rpc = raw_rpc.rpc.into_rpc();
+    let mut subscription = rpc
+        .subscribe_unbounded("interop_subscribeCertificateStatus", rpc_params![1])
+        .await
+        .unwrap();
+
+    // The transition of the other network is filtered out.
+    sender.send(other_header).unwrap();
+
+    let mut settled = header.clone();
+    settled.status = CertificateStatus::Settled;
+    sender.send(header.clone()).unwrap();
+    sender.send(settled.clone()).unwrap();
+
+    for expected in [header, settled] {
+        let (received, _) = subscription
+            .next::<CertificateHeader>()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(received, expected);
+    }
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn subscribe_certificate_status_by_certificate(#[future] raw_rpc: RawRpcContext) {
+    let certificate = Certificate::new_for_test(1.into(), Height::ZERO);
+    let header = insert_header(&raw_rpc, &certificate);
+    let other_header = insert_header(
+        &raw_rpc,
+        &Certificate::new_for_test(1.into(), Height::new(1)),
+    );
+    let sender = raw_rpc.certificate_status_sender.clone();
+
+    let rpc = raw_rpc.rpc.into_rpc();
+    let mut subscription = rpc
+        .subscribe_unbounded(
+            "interop_subscribeCertificateStatus",
+            rpc_params![Option::<u32>::None, certificate.hash()],
+        )
+        .await
+        .unwrap();
+
+    sender.send(other_header).unwrap();
+    sender.send(header.clone()).unwrap();
+
+    let (received, _) = subscription
+        .next::<CertificateHeader>()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(received, header);
+}
@@ -0,0 +1,29 @@
+//! Tests for `admin_verifyState` on a node without a state verifier.
+
+use agglayer_rpc::state_verification::NetworkStateVerification;
+use agglayer_types::NetworkId;
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    rpc_params,
+};
+
+use crate::testutils::TestContext;
+
+#[test_log::test(tokio::test)]
+async fn verify_state_without_verifier_is_disabled() {
+    let config = TestContext::get_default_config();
+    let context = TestContext::new_with_config(config).await;
+
+    let result: Result<Vec<NetworkStateVerification>, ClientError> = context
+        .admin_client
+        .request("admin_verifyState", rpc_params![Some(NetworkId::new(1))])
+        .await;
+
+    let error = result.unwrap_err();
+    let ClientError::Call(err) = error else {
+        panic!("expected a call error, got: {error}");
+    };
+
+    assert_eq!(err.code(), -10009);
+    assert_eq!(err.message(), "The admin_verifyState method is disabled");
+}
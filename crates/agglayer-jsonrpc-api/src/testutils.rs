@@ -11,7 +11,7 @@ use agglayer_storage::{
     stores::{debug::DebugStore, epochs::EpochsStore, pending::PendingStore, state::StateStore},
     tests::TempDBDir,
 };
-use agglayer_types::{Certificate, CertificateId, Height, NetworkId};
+use agglayer_types::{Certificate, CertificateHeader, CertificateId, Height, NetworkId};
 use alloy::{
     network::EthereumWallet,
     providers::{
@@ -54,6 +54,7 @@ pub struct RawRpcContext {
     pub state_store: Arc<StateStore>,
     pub pending_store: Arc<PendingStore>,
     pub debug_store: Arc<DebugStore>,
+    pub certificate_status_sender: tokio::sync::broadcast::Sender<CertificateHeader>,
 }
 
 impl RawRpcContext {
@@ -325,16 +326,22 @@ impl TestContext {
             .unwrap(),
         );
 
+        // Create the certificate status channel
+        let (certificate_status_sender, _) = tokio::sync::broadcast::channel(10);
+
         // Create agglayer_rpc::AgglayerService
-        let rpc_service = Arc::new(agglayer_rpc::AgglayerService::new(
-            certificate_sender,
-            pending_store.clone(),
-            state_store.clone(),
-            debug_store.clone(),
-            epochs_store,
-            config.clone(),
-            Arc::new(l1_rpc_client),
-        ));
+        let rpc_service = Arc::new(
+            agglayer_rpc::AgglayerService::new(
+                certificate_sender,
+                pending_store.clone(),
+                state_store.clone(),
+                debug_store.clone(),
+                epochs_store,
+                config.clone(),
+                Arc::new(l1_rpc_client),
+            )
+            .with_certificate_status_sender(certificate_status_sender.clone()),
+        );
 
         // Create AgglayerImpl
         let agglayer_impl = crate::AgglayerImpl::new(v0_service, rpc_service);
@@ -345,6 +352,7 @@ impl TestContext {
             state_store,
             pending_store,
             debug_store,
+            certificate_status_sender,
         }
     }
 }
@@ -11,10 +11,12 @@ mod epoch_synchronizer;
 mod l1_tracing;
 mod metrics;
 mod node;
+mod state_verification;
 mod url_redact;
 
 use agglayer_telemetry::ServerBuilder as MetricsBuilder;
 use l1_tracing::L1TraceLayer;
+pub use state_verification::verify_state;
 use url_redact::UrlRedactLayer;
 
 /// This is the main node entrypoint.
@@ -6,11 +6,16 @@ use agglayer_clock::{BlockClock, Clock, TimeClock};
 use agglayer_config::{storage::backup::BackupConfig, Config, Epoch};
 use agglayer_contracts::{contracts::PolygonRollupManager, L1RpcClient};
 use agglayer_jsonrpc_api::{
-    admin::AdminAgglayerImpl, kernel::Kernel, service::AgglayerService, AgglayerImpl,
+    admin::AdminAgglayerImpl,
+    admin_auth::{serve_tls, TlsAcceptor},
+    kernel::Kernel,
+    service::AgglayerService,
+    AgglayerImpl,
 };
-use agglayer_signer::{ConfiguredSigner, ConfiguredSigners};
+use agglayer_rpc::{network_rollback::NetworkRollbackService, state_verification::StateVerifier};
+use agglayer_signer::ConfiguredSigners;
 use agglayer_storage::{
-    backup::{BackupClient, BackupEngine},
+    backup::{remote::RemoteBackups, BackupClient, BackupEngine},
     stores::{
         debug::DebugStore, epochs::EpochsStore, pending::PendingStore, state::StateStore,
         PerEpochReader as _,
@@ -21,7 +26,10 @@ use alloy::{
     providers::{ProviderBuilder, WalletProvider, WsConnect},
 };
 use eyre::Context as _;
-use tokio::{sync::mpsc, task::JoinHandle};
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::JoinHandle,
+};
 use tokio_util::sync::CancellationToken;
 use tower::buffer::Buffer;
 use tracing::{debug, error, info, warn};
@@ -29,6 +37,9 @@ use tracing::{debug, error, info, warn};
 use crate::epoch_synchronizer::EpochSynchronizer;
 
 pub(crate) mod api;
+mod epoch_retention;
+mod rate_limiter_persistence;
+mod state_snapshots;
 
 pub(crate) struct Node {
     pub(crate) rpc_handle: JoinHandle<()>,
@@ -95,9 +106,11 @@ impl Node {
             path,
             state_max_backup_count,
             pending_max_backup_count,
+            remote,
+            restore_drill_interval,
         } = &config.storage.backup
         {
-            let (backup_engine, client) = BackupEngine::new(
+            let (mut backup_engine, client) = BackupEngine::new(
                 path,
                 state_db.clone(),
                 pending_db.clone(),
@@ -105,6 +118,14 @@ impl Node {
                 *pending_max_backup_count,
                 cancellation_token.clone(),
             )?;
+            if let Some(remote) = remote {
+                info!(bucket = %remote.bucket, "Uploading backups to remote storage");
+                backup_engine = backup_engine.with_remote(RemoteBackups::from_config(remote).await);
+            }
+            if let Some(interval) = restore_drill_interval {
+                info!(?interval, "Running backup restore drills");
+                backup_engine = backup_engine.with_restore_drill(*interval);
+            }
             tokio::spawn(backup_engine.run());
 
             client
@@ -166,6 +187,18 @@ impl Node {
             backup_client,
         )?);
 
+        if !config.storage.epoch_retention.is_disabled() {
+            info!("Archiving the epochs out of the retention window");
+            epoch_retention::spawn(
+                epochs_store.clone(),
+                state_store.clone(),
+                clock_ref.subscribe()?,
+                cancellation_token.clone(),
+            );
+        }
+
+        state_snapshots::spawn(epochs_store.clone(), state_store.clone());
+
         info!("Epoch synchronization started.");
         let current_epoch_store =
             EpochSynchronizer::start(state_store.clone(), epochs_store.clone(), clock_ref.clone())
@@ -183,11 +216,11 @@ impl Node {
 
         let (rpc_pp_settlement, rpc_tx_settlement) = {
             // We will use the same parameterization to create both providers.
-            let fn_build_provider = |signer: ConfiguredSigner| {
+            let fn_build_provider = |wallet: EthereumWallet| {
                 Arc::new(
                     ProviderBuilder::new()
                         .with_simple_nonce_management()
-                        .wallet(EthereumWallet::from(signer))
+                        .wallet(wallet)
                         .connect_client(
                             alloy::rpc::client::RpcClient::builder()
                                 .layer(crate::L1TraceLayer)
@@ -198,11 +231,21 @@ impl Node {
             };
 
             let signers = ConfiguredSigners::new(&config).await?;
-            let provider_cert = fn_build_provider(signers.pp_settlement);
+            // The settlement service settles through the pp-settlement provider,
+            // so the settlement pool wallets are registered next to its signer.
+            let mut wallet_cert = EthereumWallet::from(signers.pp_settlement);
+            for signer in signers.settlement_pool {
+                tracing::info!(
+                    "Settlement pool wallet address: {:?}",
+                    alloy::signers::Signer::address(&signer)
+                );
+                wallet_cert.register_signer(signer);
+            }
+            let provider_cert = fn_build_provider(wallet_cert);
 
             let tx_settlement_configured = signers.tx_settlement.is_some();
             let provider_tx = if let Some(tx_settlement) = signers.tx_settlement {
-                fn_build_provider(tx_settlement)
+                fn_build_provider(EthereumWallet::from(tx_settlement))
             } else {
                 warn!("Using the same provider for certificate and tx settlement");
                 provider_cert.clone()
@@ -265,9 +308,25 @@ impl Node {
         )
         .await?;
         info!("Certifier client created.");
+        let simulation_certifier = Arc::new(certifier_client.clone());
+
+        // The rate limiter is shared by the `sendTx` settlement and the
+        // certificate submission, whichever API they come from.
+        let rate_limiter = if config.rate_limiting.persist() {
+            agglayer_rate_limiting::RateLimiter::with_persistence(
+                config.rate_limiting.clone(),
+                Arc::new(rate_limiter_persistence::StateStorePersistence::new(
+                    state_store.clone(),
+                )),
+            )
+        } else {
+            agglayer_rate_limiting::RateLimiter::new(config.rate_limiting.clone())
+        };
 
         // Construct the core.
-        let core = Kernel::new(rpc_tx_settlement.clone(), config.clone()).unwrap();
+        let core = Kernel::new(rpc_tx_settlement.clone(), config.clone())
+            .unwrap()
+            .with_rate_limiter(rate_limiter.clone());
 
         let current_epoch_store = Arc::new(arc_swap::ArcSwap::new(Arc::new(current_epoch_store)));
 
@@ -295,6 +354,11 @@ impl Node {
                 .input_backpressure_buffer_size,
         );
 
+        // Certificate status transitions, published by the orchestrator and the
+        // RPC service, and consumed by the status subscriptions.
+        let (certificate_status_sender, _) =
+            broadcast::channel(agglayer_rpc::DEFAULT_CERTIFICATE_STATUS_CHANNEL_SIZE);
+
         let certificate_orchestrator_handle = CertificateOrchestrator::builder()
             .clock(clock_ref)
             .data_receiver(data_receiver)
@@ -305,6 +369,7 @@ impl Node {
             .state_store(state_store.clone())
             .certifier_task_builder(certifier_client)
             .settlement_service(settlement_service)
+            .certificate_status_sender(certificate_status_sender.clone())
             .start()
             .await
             .context("Failed starting certificate orchestrator")?;
@@ -313,15 +378,20 @@ impl Node {
 
         // Set up the core service object.
         let service = Arc::new(AgglayerService::new(core));
-        let rpc_service = Arc::new(agglayer_rpc::AgglayerService::new(
-            data_sender.clone(),
-            pending_store.clone(),
-            state_store.clone(),
-            debug_store.clone(),
-            epochs_store.clone(),
-            config.clone(),
-            Arc::clone(&rollup_manager),
-        ));
+        let rpc_service = Arc::new(
+            agglayer_rpc::AgglayerService::new(
+                data_sender.clone(),
+                pending_store.clone(),
+                state_store.clone(),
+                debug_store.clone(),
+                epochs_store.clone(),
+                config.clone(),
+                Arc::clone(&rollup_manager),
+            )
+            .with_certificate_status_sender(certificate_status_sender)
+            .with_certifier(simulation_certifier)
+            .with_rate_limiter(rate_limiter),
+        );
 
         let admin_router = AdminAgglayerImpl::new(
             data_sender,
@@ -331,9 +401,27 @@ impl Node {
             config.clone(),
             settlement_service_for_admin,
         )
+        .with_state_verifier(Arc::new(StateVerifier::new(
+            state_store.clone(),
+            epochs_store.clone(),
+            Some(Arc::clone(&rollup_manager)),
+        )))
+        .with_network_rollback(Arc::new(NetworkRollbackService::new(
+            state_store.clone(),
+            pending_store.clone(),
+            epochs_store.clone(),
+        )))
         .start()
         .await
         .context("Failed starting admin router")?;
+        let admin_tls = config
+            .rpc
+            .admin_auth
+            .as_ref()
+            .and_then(|admin_auth| admin_auth.tls.as_ref())
+            .map(TlsAcceptor::from_config)
+            .transpose()
+            .context("Failed loading admin TLS configuration")?;
 
         // Bind the core to the RPC server.
         let json_rpc_router = AgglayerImpl::new(service, rpc_service.clone())
@@ -365,8 +453,20 @@ impl Node {
         let public_grpc_server = axum::serve(public_grpc_listener, public_grpc_router)
             .with_graceful_shutdown(cancellation_token.clone().cancelled_owned());
 
-        let admin_server = axum::serve(admin_listener, admin_router)
-            .with_graceful_shutdown(cancellation_token.clone().cancelled_owned());
+        let admin_shutdown = cancellation_token.clone();
+        let admin_server = async move {
+            match admin_tls {
+                Some(acceptor) => {
+                    serve_tls(admin_listener, admin_router, acceptor, admin_shutdown).await;
+                    Ok(())
+                }
+                None => {
+                    axum::serve(admin_listener, admin_router)
+                        .with_graceful_shutdown(admin_shutdown.cancelled_owned())
+                        .await
+                }
+            }
+        };
 
         let rpc_handle = tokio::spawn(async move {
             tokio::select! {
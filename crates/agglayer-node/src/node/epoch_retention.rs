@@ -0,0 +1,62 @@
+//! Archival of the epochs falling out of the retention window.
+
+use std::sync::Arc;
+
+use agglayer_storage::stores::{
+    epochs::EpochsStore, pending::PendingStore, state::StateStore, MetadataReader as _,
+};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+/// Apply the epoch retention policy at startup, then every time an epoch
+/// ends.
+pub(crate) fn spawn(
+    epochs_store: Arc<EpochsStore<PendingStore, StateStore>>,
+    state_store: Arc<StateStore>,
+    mut clock_events: broadcast::Receiver<agglayer_clock::Event>,
+    cancellation_token: CancellationToken,
+) {
+    tokio::spawn(async move {
+        loop {
+            apply(&epochs_store, &state_store).await;
+
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                event = clock_events.recv() => match event {
+                    Ok(agglayer_clock::Event::EpochEnded(_)) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    });
+}
+
+async fn apply(
+    epochs_store: &Arc<EpochsStore<PendingStore, StateStore>>,
+    state_store: &StateStore,
+) {
+    let latest_settled_epoch = match state_store.get_latest_settled_epoch() {
+        Ok(Some(epoch)) => epoch,
+        Ok(None) => return,
+        Err(error) => {
+            warn!(
+                ?error,
+                "Unable to read the latest settled epoch for the epoch retention"
+            );
+            return;
+        }
+    };
+
+    let epochs_store = epochs_store.clone();
+    match tokio::task::spawn_blocking(move || epochs_store.apply_retention(latest_settled_epoch))
+        .await
+    {
+        Ok(Ok(archived)) if !archived.is_empty() => {
+            info!(?archived, "Archived the epochs out of the retention window");
+        }
+        Ok(Ok(_)) => {}
+        Ok(Err(error)) => error!(?error, "Unable to apply the epoch retention"),
+        Err(error) => error!(?error, "Epoch retention task failed"),
+    }
+}
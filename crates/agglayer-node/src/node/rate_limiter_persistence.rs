@@ -0,0 +1,42 @@
+//! Persistence of the rate limiter state in the state store.
+
+use std::{sync::Arc, time::SystemTime};
+
+use agglayer_rate_limiting::{NetworkId, Persistence, PersistenceError};
+use agglayer_storage::stores::{RateLimiterStateReader, RateLimiterStateWriter};
+
+/// Persists the rate limiter state in the `rate_limiter_state_cf` column of
+/// the state store.
+pub(crate) struct StateStorePersistence<StateStore>(Arc<StateStore>);
+
+impl<StateStore> StateStorePersistence<StateStore> {
+    pub(crate) fn new(state_store: Arc<StateStore>) -> Self {
+        Self(state_store)
+    }
+}
+
+impl<StateStore> Persistence for StateStorePersistence<StateStore>
+where
+    StateStore: RateLimiterStateReader + RateLimiterStateWriter,
+{
+    fn load(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+    ) -> Result<Vec<SystemTime>, PersistenceError> {
+        Ok(self
+            .0
+            .get_rate_limiter_events(network_id.into(), component)?)
+    }
+
+    fn save(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+        events: &[SystemTime],
+    ) -> Result<(), PersistenceError> {
+        Ok(self
+            .0
+            .set_rate_limiter_events(network_id.into(), component, events)?)
+    }
+}
@@ -0,0 +1,28 @@
+//! Backfill of the snapshots of the settled network states.
+
+use std::sync::Arc;
+
+use agglayer_storage::stores::{epochs::EpochsStore, pending::PendingStore, state::StateStore};
+use tracing::{error, info};
+
+/// Record the snapshots of the states settled before the snapshots got
+/// introduced, in the background.
+///
+/// Until then, the settled states at those heights aren't served. The heights
+/// already having a snapshot are skipped, so this is a no-op once done.
+pub(crate) fn spawn(
+    epochs_store: Arc<EpochsStore<PendingStore, StateStore>>,
+    state_store: Arc<StateStore>,
+) {
+    tokio::task::spawn_blocking(move || {
+        match agglayer_rpc::state_snapshots::backfill_state_snapshots(&*state_store, &*epochs_store)
+        {
+            Ok(0) => {}
+            Ok(written) => info!(written, "Backfilled the snapshots of the settled states"),
+            Err(error) => error!(
+                ?error,
+                "Unable to backfill the snapshots of the settled states"
+            ),
+        }
+    });
+}
@@ -0,0 +1,68 @@
+//! Offline verification of the persisted network states.
+
+use std::sync::Arc;
+
+use agglayer_config::Config;
+use agglayer_contracts::{contracts::PolygonRollupManager, GasPriceParams, L1RpcClient};
+use agglayer_rpc::state_verification::{NetworkStateVerification, StateVerifier};
+use agglayer_storage::stores::{epochs::EpochsStore, pending::PendingStore, state::StateStore};
+use agglayer_types::NetworkId;
+use alloy::providers::ProviderBuilder;
+use eyre::Context as _;
+
+/// Rebuild the state of a network, or of every settled network, from its
+/// settled certificates and compare it with the persisted state.
+///
+/// The stores are opened read-only, so this can run next to a running node.
+/// The pessimistic roots are checked against L1 when `check_l1` is set.
+pub async fn verify_state(
+    config: Arc<Config>,
+    network_id: Option<NetworkId>,
+    check_l1: bool,
+) -> eyre::Result<Vec<NetworkStateVerification>> {
+    let pending_store = Arc::new(
+        PendingStore::new_readonly_with_path(&config.storage.pending_db_path)
+            .context("Failed to open the pending database")?,
+    );
+    let state_store = Arc::new(
+        StateStore::new_readonly_with_path(&config.storage.state_db_path)
+            .context("Failed to open the state database")?,
+    );
+    let epochs_store = Arc::new(
+        EpochsStore::new_readonly(config.clone(), pending_store, state_store.clone())
+            .context("Failed to open the epochs")?,
+    );
+
+    let l1_rpc = if check_l1 {
+        let rpc = ProviderBuilder::new().connect_http(config.l1.node_url.clone());
+        let settle_cert_config = config
+            .outbound
+            .as_ref()
+            .map(|outbound| outbound.rpc.settle_cert.clone())
+            .unwrap_or_default();
+
+        // Nothing is settled from here, the gas parameters are unused.
+        let l1_rpc = L1RpcClient::try_new(
+            Arc::new(rpc.clone()),
+            PolygonRollupManager::new(config.l1.rollup_manager_contract.into(), rpc),
+            config.l1.polygon_zkevm_global_exit_root_v2_contract.into(),
+            settle_cert_config.gas_multiplier_factor,
+            GasPriceParams::default(),
+            config.l1.event_filter_block_range.get(),
+        )
+        .await
+        .context("Failed to create the L1 client")?;
+
+        Some(Arc::new(l1_rpc))
+    } else {
+        None
+    };
+
+    let verifier = StateVerifier::new(state_store, epochs_store, l1_rpc);
+    let reports = match network_id {
+        Some(network_id) => vec![verifier.verify_network(network_id).await?],
+        None => verifier.verify_all().await?,
+    };
+
+    Ok(reports)
+}
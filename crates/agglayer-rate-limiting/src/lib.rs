@@ -1,6 +1,7 @@
 //! Transaction settlement rate limiter implementation.
 
 mod local;
+mod persistence;
 mod top_level;
 
 #[cfg(test)]
@@ -9,6 +10,9 @@ pub mod tests;
 pub use agglayer_config::rate_limiting::{NetworkId, RateLimitingConfig};
 use local::LocalRateLimiter;
 pub use local::{component, Component, RateLimited, SlotGuard};
+pub use persistence::{Persistence, PersistenceError};
 pub use top_level::RateLimiter;
 
 pub type SendTxSlotGuard = SlotGuard<component::SendTx>;
+
+pub type SendCertificateSlotGuard = SlotGuard<component::SendCertificate>;
@@ -1,4 +1,6 @@
-use super::{limiter, state, LocalRateLimiter, RateLimited, RateLimiter, RawState};
+use super::{
+    limiter, state, LocalRateLimiter, PersistentState, RateLimited, RateLimiter, RawState,
+};
 
 /// A component of a rate limiter.
 ///
@@ -7,6 +9,9 @@ use super::{limiter, state, LocalRateLimiter, RateLimited, RateLimiter, RawState
 /// tags corresponding to the two components, i.e. [`component::SendTx`] and
 /// (hypothetical) `component::PeanutRate`, each implementing [Component].
 pub trait Component {
+    /// Name of this component, under which its state is persisted.
+    const NAME: &'static str;
+
     /// Time measure for this limiter component.
     type Instant: Copy + Ord;
 
@@ -14,7 +19,7 @@ pub trait Component {
     type LimitedInfo;
 
     /// Internal state type for this component.
-    type State: RawState<Instant = Self::Instant, LimitedInfo = Self::LimitedInfo>;
+    type State: RawState<Instant = Self::Instant, LimitedInfo = Self::LimitedInfo> + PersistentState;
 
     /// Check various preconditions before the rate limiter proper is applied.
     fn precondition(_limiter: &mut LocalRateLimiter) -> Result<(), RateLimited> {
@@ -32,6 +37,8 @@ pub trait Component {
 pub enum SendTx {}
 
 impl Component for SendTx {
+    const NAME: &'static str = "send-tx";
+
     type Instant = tokio::time::Instant;
     type LimitedInfo = state::WallClockLimitedInfo;
     type State = state::WallClockState;
@@ -44,3 +51,22 @@ impl Component for SendTx {
         RateLimited::send_tx(info)
     }
 }
+
+/// The rate limiter component for certificate submission.
+pub enum SendCertificate {}
+
+impl Component for SendCertificate {
+    const NAME: &'static str = "send-certificate";
+
+    type Instant = tokio::time::Instant;
+    type LimitedInfo = state::WallClockLimitedInfo;
+    type State = state::WallClockState;
+
+    fn component(limiter: &mut LocalRateLimiter) -> &mut RateLimiter<Self::State> {
+        &mut limiter.send_certificate
+    }
+
+    fn error(info: limiter::RateLimited<Self::LimitedInfo>) -> RateLimited {
+        RateLimited::send_certificate(info)
+    }
+}
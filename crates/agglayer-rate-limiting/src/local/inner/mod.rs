@@ -1,9 +1,12 @@
-use agglayer_config::rate_limiting::{NetworkRateLimitingConfig, TimeRateLimit};
+use std::sync::Arc;
+
+use agglayer_config::rate_limiting::{NetworkId, NetworkRateLimitingConfig, TimeRateLimit};
 
 use super::{
     limiter::{self, RateLimiter, SlotTracker},
-    state::{self, RawState, WallClockLimitedInfo, WallClockState},
+    state::{self, PersistentState, RawState, WallClockLimitedInfo, WallClockState},
 };
+use crate::persistence::{Clock, NetworkPersistence, PendingSave, Persistence};
 
 pub mod component;
 
@@ -13,11 +16,43 @@ pub use component::Component;
 pub struct LocalRateLimiter {
     /// Rate limiter for `sendTx` settlement.
     send_tx: RateLimiter<WallClockState>,
+
+    /// Rate limiter for certificate submission.
+    send_certificate: RateLimiter<WallClockState>,
+
+    /// Where the recorded events are persisted, if anywhere.
+    persistence: Option<NetworkPersistence>,
+
+    /// Generation of the last snapshot taken for persistence.
+    save_generation: u64,
 }
 
 impl LocalRateLimiter {
     pub fn from_config(config: &NetworkRateLimitingConfig) -> Self {
-        let send_tx = match config.send_tx {
+        LocalRateLimiter {
+            send_tx: Self::time_limiter(config.send_tx),
+            send_certificate: Self::time_limiter(config.send_certificate),
+            persistence: None,
+            save_generation: 0,
+        }
+    }
+
+    /// Persist the recorded events, starting from the ones saved earlier.
+    pub fn with_persistence(
+        mut self,
+        network_id: NetworkId,
+        persistence: Arc<dyn Persistence>,
+    ) -> Self {
+        let persistence = NetworkPersistence::new(network_id, persistence);
+        let clock = Clock::now();
+        self.restore::<component::SendTx>(&persistence, &clock);
+        self.restore::<component::SendCertificate>(&persistence, &clock);
+        self.persistence = Some(persistence);
+        self
+    }
+
+    fn time_limiter(limit: &TimeRateLimit) -> RateLimiter<WallClockState> {
+        match limit {
             TimeRateLimit::Unlimited => RateLimiter::Unlimited,
 
             TimeRateLimit::Limited {
@@ -29,9 +64,7 @@ impl LocalRateLimiter {
                     RateLimiter::limited(WallClockState::new(max_per_interval, *time_interval))
                 },
             ),
-        };
-
-        LocalRateLimiter { send_tx }
+        }
     }
 
     pub fn reserve<C: Component>(&mut self, time: C::Instant) -> Result<SlotTracker, RateLimited> {
@@ -43,8 +76,42 @@ impl LocalRateLimiter {
         C::component(self).release(slot)
     }
 
-    pub fn record<C: Component>(&mut self, time: C::Instant, slot: SlotTracker) {
-        C::component(self).record(time, slot)
+    /// Record an event, returning the snapshot to persist, if any.
+    ///
+    /// The snapshot is to be written once the limiter lock is released, so
+    /// that storage latency does not hold up the other requests.
+    pub fn record<C: Component>(
+        &mut self,
+        time: C::Instant,
+        slot: SlotTracker,
+    ) -> Option<PendingSave> {
+        C::component(self).record(time, slot);
+        self.snapshot::<C>()
+    }
+
+    fn restore<C: Component>(&mut self, persistence: &NetworkPersistence, clock: &Clock) {
+        match persistence.load(C::NAME) {
+            Ok(events) => C::component(self).restore(&events, clock),
+            Err(error) => tracing::warn!(
+                network_id = persistence.network_id(),
+                component = C::NAME,
+                ?error,
+                "Failed to load the rate limiter state, starting from scratch"
+            ),
+        }
+    }
+
+    fn snapshot<C: Component>(&mut self) -> Option<PendingSave> {
+        let persistence = self.persistence.clone()?;
+        let events = C::component(self).events(&Clock::now())?;
+        self.save_generation += 1;
+
+        Some(PendingSave::new(
+            persistence,
+            C::NAME,
+            self.save_generation,
+            events,
+        ))
     }
 }
 
@@ -56,6 +123,12 @@ pub enum RateLimited {
 
     #[error("The `sendTx` settlement disabled by rate limiter")]
     SendTxDisabled {},
+
+    #[error("The certificate submission has been limited: {0}")]
+    SendCertificateRateLimited(WallClockLimitedInfo),
+
+    #[error("The certificate submission disabled by rate limiter")]
+    SendCertificateDisabled {},
 }
 
 impl RateLimited {
@@ -65,4 +138,23 @@ impl RateLimited {
             limiter::RateLimited::Inner(err) => Self::SendTxRateLimited(err),
         }
     }
+
+    fn send_certificate(err: limiter::RateLimited<WallClockLimitedInfo>) -> Self {
+        match err {
+            limiter::RateLimited::Disabled {} => Self::SendCertificateDisabled {},
+            limiter::RateLimited::Inner(err) => Self::SendCertificateRateLimited(err),
+        }
+    }
+
+    /// Time left until the rate limit is expected to pass, if known.
+    ///
+    /// It is never known for a disabled endpoint.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::SendTxRateLimited(info) | Self::SendCertificateRateLimited(info) => {
+                info.until_next
+            }
+            Self::SendTxDisabled {} | Self::SendCertificateDisabled {} => None,
+        }
+    }
 }
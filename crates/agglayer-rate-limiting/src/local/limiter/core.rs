@@ -63,10 +63,14 @@ impl<S: RawState> RateLimiterCore<S> {
     }
 
     /// Access the raw state
-    #[cfg(test)]
     pub fn raw(&self) -> &S {
         self.state.raw()
     }
+
+    /// Modify the raw state
+    pub fn raw_mut(&mut self) -> &mut S {
+        self.state.raw_mut()
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
+use std::time::SystemTime;
+
 use agglayer_utils::log_assert;
 
-use super::state::{self, RawState};
+use super::state::{self, PersistentState, RawState};
+use crate::persistence::Clock;
 
 mod core;
 mod slot_tracker;
@@ -63,6 +66,24 @@ impl<S: RawState> RateLimiter<S> {
     }
 }
 
+impl<S: PersistentState> RateLimiter<S> {
+    /// The recorded events, if this limiter records any.
+    pub fn events(&self, clock: &Clock) -> Option<Vec<SystemTime>> {
+        match self {
+            Self::Limited(inner) => Some(inner.raw().events(clock)),
+            Self::Disabled | Self::Unlimited => None,
+        }
+    }
+
+    /// Restore the saved events, if this limiter records any.
+    pub fn restore(&mut self, events: &[SystemTime], clock: &Clock) {
+        match self {
+            Self::Limited(inner) => inner.raw_mut().restore(events, clock),
+            Self::Disabled | Self::Unlimited => (),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 pub enum RateLimited<E> {
     Disabled {},
@@ -2,7 +2,8 @@
 //!
 //! A rate limiter consists of multiple components, one per each class of events
 //! being limited. For example, [component::SendTx] takes care of limiting the
-//! rate of `sendTx` settlement on L1. More components can be added as needed.
+//! rate of `sendTx` settlement on L1, and [component::SendCertificate] the rate
+//! of certificate submission. More components can be added as needed.
 //!
 //! The implementation is defined in multiple layers, each adding some features.
 //! The layers correspond to modules as follows:
@@ -19,9 +20,11 @@
 
 use std::sync::Arc;
 
-use agglayer_config::rate_limiting::NetworkRateLimitingConfig;
+use agglayer_config::rate_limiting::{NetworkId, NetworkRateLimitingConfig};
 use parking_lot::Mutex;
 
+use crate::persistence::Persistence;
+
 mod inner;
 mod limiter;
 mod slot_guard;
@@ -40,6 +43,18 @@ impl LocalRateLimiter {
         Self(Arc::new(Mutex::new(inner)))
     }
 
+    /// Create a rate limiter persisting its state, from configuration and the
+    /// state saved earlier.
+    pub fn with_persistence(
+        config: &NetworkRateLimitingConfig,
+        network_id: NetworkId,
+        persistence: Arc<dyn Persistence>,
+    ) -> Self {
+        let inner =
+            inner::LocalRateLimiter::from_config(config).with_persistence(network_id, persistence);
+        Self(Arc::new(Mutex::new(inner)))
+    }
+
     /// Duplicate a handle to the rate limiter.
     pub fn shallow_clone(&self) -> Self {
         Self(Arc::clone(&self.0))
@@ -25,7 +25,12 @@ impl<C: Component> SlotGuard<C> {
 
     /// Record a rate limiting event.
     pub fn record(mut self, time: C::Instant) {
-        self.limiter.0.lock().record::<C>(time, self.slot.take());
+        let pending_save = self.limiter.0.lock().record::<C>(time, self.slot.take());
+        // Written with the limiter unlocked: the other requests of the network
+        // need not wait for the storage.
+        if let Some(pending_save) = pending_save {
+            pending_save.write();
+        }
     }
 }
 
@@ -1,3 +1,7 @@
+use std::time::SystemTime;
+
+use crate::persistence::Clock;
+
 /// A low-level interface to the internal state of a rate limiter.
 ///
 /// This is a low-level interface that a limiter implements. Not to be used
@@ -24,3 +28,13 @@ pub trait RawState {
     /// Get the maximum number of events in the limiter.
     fn max_events(&self) -> usize;
 }
+
+/// A rate limiter state which can be saved and restored across restarts.
+pub trait PersistentState: RawState {
+    /// The recorded events, as wall-clock times.
+    fn events(&self, clock: &Clock) -> Vec<SystemTime>;
+
+    /// Replace the recorded events with the saved `events`, checked against
+    /// the current `clock`.
+    fn restore(&mut self, events: &[SystemTime], clock: &Clock);
+}
@@ -2,6 +2,6 @@ mod interface;
 mod wall_clock;
 mod wrapper;
 
-pub use interface::RawState;
+pub use interface::{PersistentState, RawState};
 pub use wall_clock::{RateLimited as WallClockLimitedInfo, WallClockState};
 pub use wrapper::State;
@@ -1,8 +1,13 @@
-use std::{num::NonZeroU32, time::Duration};
+use std::{
+    num::NonZeroU32,
+    time::{Duration, SystemTime},
+};
 
 use serde_with::DurationSeconds;
 use tokio::time::Instant;
 
+use crate::persistence::Clock;
+
 /// An error indicating the request has been rate limited.
 #[serde_with::serde_as]
 #[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, thiserror::Error)]
@@ -106,18 +111,58 @@ impl super::RawState for WallClockState {
     }
 }
 
+impl super::PersistentState for WallClockState {
+    fn events(&self, clock: &Clock) -> Vec<SystemTime> {
+        self.past.iter().map(|t| clock.to_system(*t)).collect()
+    }
+
+    fn restore(&mut self, events: &[SystemTime], clock: &Clock) {
+        let now = clock.instant();
+        let mut past: Vec<Instant> = events
+            .iter()
+            .filter_map(|event| match clock.age_of(*event) {
+                // Expired while the limiter was not running.
+                Some(age) if age >= self.params.time_interval => None,
+                // The monotonic clock may not reach that far back, e.g. right
+                // after boot. Counting the event as recorded now errs on the
+                // side of limiting.
+                Some(age) => Some(now.checked_sub(age).unwrap_or(now)),
+                None => {
+                    tracing::warn!(
+                        ?event,
+                        "Rate limiter event recorded in the future, the wall clock may have \
+                         stepped back; counting it as recorded now"
+                    );
+                    Some(now)
+                }
+            })
+            .collect();
+
+        // A limit lowered since the events were saved only keeps the most
+        // recent ones.
+        past.sort_unstable();
+        let excess = past.len().saturating_sub(self.params.max_per_interval());
+        past.drain(..excess);
+
+        self.past = past;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{num::NonZeroU32, time::Duration};
 
     use tokio::time::Instant;
 
-    use crate::local::{
-        limiter::RateLimiterCore,
-        state::{
-            wall_clock::{Params, RateLimited, WallClockState},
-            RawState,
+    use crate::{
+        local::{
+            limiter::RateLimiterCore,
+            state::{
+                wall_clock::{Params, RateLimited, WallClockState},
+                PersistentState, RawState,
+            },
         },
+        persistence::Clock,
     };
 
     type TestLimiter = RateLimiterCore<WallClockState>;
@@ -268,4 +313,66 @@ mod tests {
             assert_eq!(res.is_ok(), ok, "offset {offset}");
         }
     }
+
+    #[test]
+    fn save_and_restore() {
+        let time_interval = Duration::from_secs(100);
+        let now = Instant::now() + Duration::from_secs(3600);
+        let clock = Clock::new(now, std::time::SystemTime::now());
+        let mut limiter = TestLimiter::from_params(NonZeroU32::new(3).unwrap(), time_interval);
+
+        assert_eq!(
+            limiter.check_and_limit(now - Duration::from_secs(30)),
+            Ok(())
+        );
+        assert_eq!(
+            limiter.check_and_limit(now - Duration::from_secs(10)),
+            Ok(())
+        );
+        let events = limiter.raw().events(&clock);
+
+        // Restart an hour later on the monotonic clock, ten seconds later on
+        // the wall clock.
+        let restarted_now = now + Duration::from_secs(3600);
+        let restarted_clock = Clock::new(
+            restarted_now,
+            clock.to_system(now) + Duration::from_secs(10),
+        );
+        let mut restored = TestLimiter::from_params(NonZeroU32::new(3).unwrap(), time_interval);
+        restored.raw_mut().restore(&events, &restarted_clock);
+
+        assert_eq!(
+            restored.raw().past,
+            [
+                restarted_now - Duration::from_secs(40),
+                restarted_now - Duration::from_secs(20),
+            ]
+        );
+        assert_eq!(restored.check_and_limit(restarted_now), Ok(()));
+        assert!(restored.check_and_limit(restarted_now).is_err());
+    }
+
+    #[test]
+    fn restore_checks_events_against_the_clock() {
+        let time_interval = Duration::from_secs(100);
+        let now = Instant::now() + Duration::from_secs(3600);
+        let system_now = std::time::SystemTime::now();
+        let clock = Clock::new(now, system_now);
+        let mut state = WallClockState::new(NonZeroU32::new(2).unwrap(), time_interval);
+
+        state.restore(
+            &[
+                // Expired.
+                system_now - Duration::from_secs(100),
+                system_now - Duration::from_secs(99),
+                // Beyond the limit, only the most recent ones are kept.
+                system_now - Duration::from_secs(98),
+                // In the future.
+                system_now + Duration::from_secs(60),
+            ],
+            &clock,
+        );
+
+        assert_eq!(state.past, [now - Duration::from_secs(98), now]);
+    }
 }
@@ -52,6 +52,11 @@ impl<S: RawState> State<S> {
         &self.raw
     }
 
+    /// Get modifying access to the underlying raw state.
+    pub fn raw_mut(&mut self) -> &mut S {
+        &mut self.raw
+    }
+
     /// Remove no longer relevant events.
     fn prune(&mut self, time: S::Instant) {
         let n_before = self.raw.query();
@@ -0,0 +1,163 @@
+//! Persistence of the rate limiter state across restarts.
+//!
+//! The limiters track their events with a monotonic clock, which does not
+//! survive a restart. The events are thus persisted as wall-clock times, and
+//! checked against the current wall clock when restored.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use parking_lot::Mutex;
+use tokio::time::Instant;
+
+use crate::NetworkId;
+
+/// Error reported by a [Persistence] backend.
+pub type PersistenceError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Storage for the events recorded by the rate limiters.
+///
+/// Events are stored per network and per limiter component, the component
+/// being identified by its [name](crate::Component::NAME).
+pub trait Persistence: Send + Sync {
+    /// Load the events last saved for the `component` of `network_id`.
+    fn load(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+    ) -> Result<Vec<SystemTime>, PersistenceError>;
+
+    /// Replace the events saved for the `component` of `network_id`.
+    fn save(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+        events: &[SystemTime],
+    ) -> Result<(), PersistenceError>;
+}
+
+/// The [Persistence] of one network's limiter.
+#[derive(Clone)]
+pub(crate) struct NetworkPersistence {
+    network_id: NetworkId,
+    backend: Arc<dyn Persistence>,
+    /// The generation of the snapshot last written per component. Writes
+    /// happen outside of the limiter lock, so they may be reordered.
+    written: Arc<Mutex<HashMap<&'static str, u64>>>,
+}
+
+impl NetworkPersistence {
+    pub(crate) fn new(network_id: NetworkId, backend: Arc<dyn Persistence>) -> Self {
+        Self {
+            network_id,
+            backend,
+            written: Arc::default(),
+        }
+    }
+
+    pub(crate) fn network_id(&self) -> NetworkId {
+        self.network_id
+    }
+
+    pub(crate) fn load(
+        &self,
+        component: &'static str,
+    ) -> Result<Vec<SystemTime>, PersistenceError> {
+        self.backend.load(self.network_id, component)
+    }
+}
+
+/// Events of a component, snapshot under the limiter lock and written once the
+/// lock is released.
+#[must_use = "the snapshot is only persisted by `write`"]
+pub(crate) struct PendingSave {
+    persistence: NetworkPersistence,
+    component: &'static str,
+    /// Orders the snapshots of one limiter: the later, the greater.
+    generation: u64,
+    events: Vec<SystemTime>,
+}
+
+impl PendingSave {
+    pub(crate) fn new(
+        persistence: NetworkPersistence,
+        component: &'static str,
+        generation: u64,
+        events: Vec<SystemTime>,
+    ) -> Self {
+        Self {
+            persistence,
+            component,
+            generation,
+            events,
+        }
+    }
+
+    /// Write the snapshot, unless a later one was written already.
+    pub(crate) fn write(self) {
+        let NetworkPersistence {
+            network_id,
+            backend,
+            written,
+        } = self.persistence;
+        let mut written = written.lock();
+        let last_written = written.entry(self.component).or_default();
+        if *last_written > self.generation {
+            return;
+        }
+        *last_written = self.generation;
+
+        // The limit still applies to the running limiter, only a restart
+        // would forget about the event.
+        if let Err(error) = backend.save(network_id, self.component, &self.events) {
+            tracing::warn!(
+                network_id,
+                component = self.component,
+                ?error,
+                "Failed to persist the rate limiter state"
+            );
+        }
+    }
+}
+
+/// A simultaneous reading of the monotonic and the wall clock, used to
+/// convert between the two.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Clock {
+    instant: Instant,
+    system: SystemTime,
+}
+
+impl Clock {
+    pub(crate) fn new(instant: Instant, system: SystemTime) -> Self {
+        Self { instant, system }
+    }
+
+    pub(crate) fn now() -> Self {
+        Self::new(Instant::now(), SystemTime::now())
+    }
+
+    /// The current monotonic time.
+    pub(crate) fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// Wall-clock time of the given monotonic time.
+    pub(crate) fn to_system(self, instant: Instant) -> SystemTime {
+        if instant <= self.instant {
+            self.system
+                .checked_sub(self.instant - instant)
+                .unwrap_or(SystemTime::UNIX_EPOCH)
+        } else {
+            self.system + (instant - self.instant)
+        }
+    }
+
+    /// Age of the given wall-clock time, or `None` if it is in the future.
+    pub(crate) fn age_of(self, time: SystemTime) -> Option<Duration> {
+        self.system.duration_since(time).ok()
+    }
+}
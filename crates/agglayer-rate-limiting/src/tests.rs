@@ -1,19 +1,33 @@
 use std::{
-    sync::atomic::{AtomicU32, Ordering},
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
     thread,
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use agglayer_config::rate_limiting::{NetworkId, TimeRateLimit};
+use parking_lot::Mutex;
 use tokio::time::Instant;
 
-use super::{RateLimited, RateLimiter, RateLimitingConfig};
+use super::{Persistence, PersistenceError, RateLimited, RateLimiter, RateLimitingConfig};
 
 impl RateLimiter {
     fn limit_send_tx(&self, network_id: NetworkId, time: Instant) -> Result<(), RateLimited> {
         self.reserve_send_tx(network_id, time)
             .map(|guard| guard.record(time))
     }
+
+    fn limit_send_certificate(
+        &self,
+        network_id: NetworkId,
+        time: Instant,
+    ) -> Result<(), RateLimited> {
+        self.reserve_send_certificate(network_id, time)
+            .map(|guard| guard.record(time))
+    }
 }
 
 const ONE_PER_100S: TimeRateLimit = TimeRateLimit::Limited {
@@ -122,3 +136,185 @@ fn network_disabled() {
     );
     assert_eq!(limiter.limit_send_tx(19, now), Ok(()));
 }
+
+#[test]
+fn send_certificate_is_limited_separately() {
+    let config = RateLimitingConfig::new(ONE_PER_100S).with_send_certificate(THREE_PER_100S);
+    let limiter = RateLimiter::new(config);
+    let now = Instant::now();
+    let at = |secs: u64| now + Duration::from_secs(secs);
+
+    assert_eq!(limiter.limit_send_tx(3, at(0)), Ok(()));
+    assert_eq!(limiter.limit_send_certificate(3, at(1)), Ok(()));
+    assert_eq!(limiter.limit_send_certificate(3, at(2)), Ok(()));
+    assert_eq!(limiter.limit_send_certificate(3, at(3)), Ok(()));
+    let Err(RateLimited::SendCertificateRateLimited(info)) =
+        limiter.limit_send_certificate(3, at(4))
+    else {
+        panic!("the fourth certificate should be rate limited");
+    };
+    assert_eq!(info.max_per_interval, 3);
+    assert_eq!(info.until_next, Some(Duration::from_secs(97)));
+    assert!(limiter.limit_send_tx(3, at(5)).is_err());
+    assert_eq!(limiter.limit_send_certificate(3, at(101)), Ok(()));
+}
+
+#[test]
+fn send_certificate_override() {
+    let config = RateLimitingConfig::new(TimeRateLimit::Unlimited)
+        .with_send_certificate(ONE_PER_100S)
+        .with_send_certificate_override(42, TimeRateLimit::Unlimited)
+        .with_send_certificate_override(55, DISABLED);
+    let limiter = RateLimiter::new(config);
+    let now = Instant::now();
+
+    assert_eq!(limiter.limit_send_certificate(7, now), Ok(()));
+    assert!(limiter.limit_send_certificate(7, now).is_err());
+    assert_eq!(limiter.limit_send_certificate(42, now), Ok(()));
+    assert_eq!(limiter.limit_send_certificate(42, now), Ok(()));
+    assert_eq!(
+        limiter.limit_send_certificate(55, now),
+        Err(RateLimited::SendCertificateDisabled {})
+    );
+    assert_eq!(limiter.limit_send_tx(55, now), Ok(()));
+}
+
+#[test]
+fn retry_after() {
+    let limiter = RateLimiter::new(
+        RateLimitingConfig::DEFAULT
+            .with_send_certificate(ONE_PER_100S)
+            .with_send_certificate_override(55, DISABLED),
+    );
+    let now = Instant::now();
+
+    assert_eq!(limiter.limit_send_certificate(7, now), Ok(()));
+    let limited = limiter
+        .limit_send_certificate(7, now + Duration::from_secs(40))
+        .unwrap_err();
+    assert_eq!(limited.retry_after(), Some(Duration::from_secs(60)));
+
+    let disabled = limiter.limit_send_certificate(55, now).unwrap_err();
+    assert_eq!(disabled.retry_after(), None);
+}
+
+/// In-memory persistence, shared by the limiters of successive "runs".
+#[derive(Default)]
+struct MemoryPersistence {
+    events: Mutex<BTreeMap<(NetworkId, &'static str), Vec<SystemTime>>>,
+    broken: bool,
+}
+
+impl Persistence for MemoryPersistence {
+    fn load(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+    ) -> Result<Vec<SystemTime>, PersistenceError> {
+        if self.broken {
+            return Err("storage unavailable".into());
+        }
+        let events = self.events.lock();
+        Ok(events
+            .get(&(network_id, component))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn save(
+        &self,
+        network_id: NetworkId,
+        component: &'static str,
+        events: &[SystemTime],
+    ) -> Result<(), PersistenceError> {
+        if self.broken {
+            return Err("storage unavailable".into());
+        }
+        self.events
+            .lock()
+            .insert((network_id, component), events.to_vec());
+        Ok(())
+    }
+}
+
+#[test]
+fn persisted_state_survives_restart() {
+    let config = RateLimitingConfig::new(ONE_PER_100S).with_send_certificate(ONE_PER_100S);
+    let persistence = Arc::new(MemoryPersistence::default());
+
+    let limiter = RateLimiter::with_persistence(config.clone(), persistence.clone());
+    assert_eq!(limiter.limit_send_certificate(7, Instant::now()), Ok(()));
+    assert_eq!(persistence.events.lock().len(), 1);
+    drop(limiter);
+
+    let restarted = RateLimiter::with_persistence(config.clone(), persistence.clone());
+    let now = Instant::now();
+    assert!(matches!(
+        restarted.limit_send_certificate(7, now),
+        Err(RateLimited::SendCertificateRateLimited(_))
+    ));
+    assert_eq!(restarted.limit_send_tx(7, now), Ok(()));
+    assert_eq!(restarted.limit_send_certificate(8, now), Ok(()));
+
+    // Without persistence, the state is lost on restart.
+    let forgetful = RateLimiter::new(config);
+    assert_eq!(forgetful.limit_send_certificate(7, now), Ok(()));
+}
+
+#[test]
+fn persistence_failures_do_not_fail_requests() {
+    let persistence = Arc::new(MemoryPersistence {
+        broken: true,
+        ..Default::default()
+    });
+    let limiter = RateLimiter::with_persistence(RateLimitingConfig::new(ONE_PER_100S), persistence);
+    let now = Instant::now();
+
+    assert_eq!(limiter.limit_send_tx(7, now), Ok(()));
+    assert!(limiter.limit_send_tx(7, now).is_err());
+}
+
+/// Persistence checking that the limiter is usable while it saves.
+#[derive(Default)]
+struct ReentrantPersistence {
+    limiter: std::sync::OnceLock<RateLimiter>,
+    saved_unlocked: Mutex<Vec<bool>>,
+}
+
+impl Persistence for ReentrantPersistence {
+    fn load(
+        &self,
+        _network_id: NetworkId,
+        _component: &'static str,
+    ) -> Result<Vec<SystemTime>, PersistenceError> {
+        Ok(Vec::new())
+    }
+
+    fn save(
+        &self,
+        network_id: NetworkId,
+        _component: &'static str,
+        _events: &[SystemTime],
+    ) -> Result<(), PersistenceError> {
+        let limiter = self.limiter.get().unwrap().clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        thread::spawn(move || {
+            let reserved = limiter.reserve_send_tx(network_id, Instant::now()).is_ok();
+            let _ = sender.send(reserved);
+        });
+        let unlocked = receiver.recv_timeout(Duration::from_secs(5)) == Ok(true);
+        self.saved_unlocked.lock().push(unlocked);
+        Ok(())
+    }
+}
+
+#[test]
+fn persistence_writes_with_the_limiter_unlocked() {
+    let persistence = Arc::new(ReentrantPersistence::default());
+    let limiter =
+        RateLimiter::with_persistence(RateLimitingConfig::new(THREE_PER_100S), persistence.clone());
+    let _ = persistence.limiter.set(limiter.clone());
+
+    assert_eq!(limiter.limit_send_tx(7, Instant::now()), Ok(()));
+    assert_eq!(*persistence.saved_unlocked.lock(), vec![true]);
+}
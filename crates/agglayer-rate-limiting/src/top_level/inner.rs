@@ -1,16 +1,20 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 use super::{super::LocalRateLimiter, NetworkId, RateLimitingConfig};
+use crate::persistence::Persistence;
 
 /// A global rate-limiter implementation.
 ///
 /// It contains individual rate limiters for individual networks and endpoints.
 pub struct RateLimiter {
-    /// A `sendTx` settlement limiter, one per network
+    /// A rate limiter, one per network
     per_network: BTreeMap<NetworkId, LocalRateLimiter>,
 
     /// Rate limiting configuration
     config: RateLimitingConfig,
+
+    /// Where the state of the network rate limiters is persisted, if anywhere.
+    persistence: Option<Arc<dyn Persistence>>,
 }
 
 impl RateLimiter {
@@ -18,11 +22,28 @@ impl RateLimiter {
         Self {
             per_network: BTreeMap::new(),
             config,
+            persistence: None,
+        }
+    }
+
+    pub fn with_persistence(config: RateLimitingConfig, persistence: Arc<dyn Persistence>) -> Self {
+        Self {
+            persistence: Some(persistence),
+            ..Self::new(config)
         }
     }
 
     pub fn limiter_for(&mut self, network_id: NetworkId) -> LocalRateLimiter {
-        let mk_limiter = || LocalRateLimiter::from_config(&self.config.config_for(network_id));
+        // The state of a network is restored when its limiter is first needed.
+        let mk_limiter = || {
+            let config = self.config.config_for(network_id);
+            match &self.persistence {
+                Some(persistence) => {
+                    LocalRateLimiter::with_persistence(&config, network_id, persistence.clone())
+                }
+                None => LocalRateLimiter::from_config(&config),
+            }
+        };
         self.per_network
             .entry(network_id)
             .or_insert_with(mk_limiter)
@@ -5,7 +5,9 @@ use std::sync::Arc;
 use parking_lot::{Mutex, MutexGuard};
 use tokio::time::Instant;
 
-use super::{component, Component, NetworkId, RateLimited, RateLimitingConfig, SlotGuard};
+use super::{
+    component, Component, NetworkId, Persistence, RateLimited, RateLimitingConfig, SlotGuard,
+};
 
 /// A global rate-limiter.
 ///
@@ -22,6 +24,16 @@ impl RateLimiter {
         Self(Arc::new(Mutex::new(inner::RateLimiter::new(config))))
     }
 
+    /// Create a new rate limiter persisting its state.
+    ///
+    /// The state of each network is restored from `persistence` when the
+    /// network is first rate limited, and saved whenever an event is
+    /// recorded.
+    pub fn with_persistence(config: RateLimitingConfig, persistence: Arc<dyn Persistence>) -> Self {
+        let inner = inner::RateLimiter::with_persistence(config, persistence);
+        Self(Arc::new(Mutex::new(inner)))
+    }
+
     /// Reserve rate limiting slot for `sendTx`.
     pub fn reserve_send_tx(
         &self,
@@ -31,6 +43,15 @@ impl RateLimiter {
         self.reserve::<component::SendTx>(network_id, time)
     }
 
+    /// Reserve rate limiting slot for certificate submission.
+    pub fn reserve_send_certificate(
+        &self,
+        network_id: NetworkId,
+        time: Instant,
+    ) -> Result<SlotGuard<component::SendCertificate>, RateLimited> {
+        self.reserve::<component::SendCertificate>(network_id, time)
+    }
+
     /// Reserve rate limiting slot for given component.
     pub fn reserve<C: Component>(
         &self,
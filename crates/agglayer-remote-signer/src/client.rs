@@ -0,0 +1,120 @@
+//! Minimal JSON-RPC client used to reach the remote signer.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use agglayer_config::RemoteSignerConfig;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::Error;
+
+#[derive(Serialize)]
+struct Request<'a, P> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: P,
+}
+
+#[derive(Deserialize)]
+struct Response<R> {
+    result: Option<R>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// JSON-RPC over HTTP client, retrying transient failures with an exponential
+/// backoff.
+#[derive(Debug)]
+pub(crate) struct RemoteSignerClient {
+    http: reqwest::Client,
+    url: Url,
+    max_retries: u32,
+    retry_backoff: Duration,
+    next_id: AtomicU64,
+}
+
+impl RemoteSignerClient {
+    pub(crate) fn new(config: &RemoteSignerConfig) -> Result<Self, Error> {
+        let http = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()?;
+
+        Ok(Self {
+            http,
+            url: config.url.clone(),
+            max_retries: config.max_retries,
+            retry_backoff: config.retry_backoff,
+            next_id: AtomicU64::new(0),
+        })
+    }
+
+    /// Calls `method` with `params`, retrying on transient errors.
+    pub(crate) async fn request<P, R>(&self, method: &str, params: P) -> Result<R, Error>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            match self.request_once(method, &params).await {
+                Err(error) if error.is_transient() && attempt < self.max_retries => {
+                    attempt += 1;
+                    warn!(
+                        %error,
+                        attempt,
+                        "Remote signer request {method} failed, retrying in {backoff:?}"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn request_once<P, R>(&self, method: &str, params: &P) -> Result<R, Error>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        debug!(id, "Sending {method} request to the remote signer");
+
+        let response = self
+            .http
+            .post(self.url.clone())
+            .json(&Request {
+                jsonrpc: "2.0",
+                id,
+                method,
+                params,
+            })
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::HttpStatus(status));
+        }
+
+        let response: Response<R> = response.json().await?;
+        match (response.result, response.error) {
+            (_, Some(RpcError { code, message })) => Err(Error::Rpc { code, message }),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(Error::InvalidResponse(format!(
+                "no result nor error in the response to {method}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use alloy_primitives::Address;
+
+/// Errors raised while talking to the remote signer.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The request could not be delivered or its response could not be read.
+    #[error("remote signer transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    /// The remote signer answered with a non-success HTTP status.
+    #[error("remote signer returned HTTP status {0}")]
+    HttpStatus(reqwest::StatusCode),
+
+    /// The remote signer answered with a JSON-RPC error.
+    #[error("remote signer error {code}: {message}")]
+    Rpc { code: i64, message: String },
+
+    /// The response of the remote signer could not be decoded.
+    #[error("invalid remote signer response: {0}")]
+    InvalidResponse(String),
+
+    /// The remote signer does not hold the configured key.
+    #[error("remote signer does not manage the account {0}")]
+    UnknownAccount(Address),
+
+    /// The signature returned by the remote signer was not made by the
+    /// expected key.
+    #[error("remote signer returned a signature from {recovered}, expected {expected}")]
+    SignerMismatch {
+        expected: Address,
+        recovered: Address,
+    },
+}
+
+impl Error {
+    /// Returns whether the request may succeed if retried.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Error::Transport(error) => {
+                error.is_timeout() || error.is_connect() || error.is_request()
+            }
+            Error::HttpStatus(status) => {
+                status.is_server_error() || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            Error::Rpc { .. }
+            | Error::InvalidResponse(_)
+            | Error::UnknownAccount(_)
+            | Error::SignerMismatch { .. } => false,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! The [`RemoteSigners`] struct provides functionality to create signers
+//! backed by a Web3Signer-compatible JSON-RPC service from a configuration.
+
+use std::sync::Arc;
+
+use agglayer_config::RemoteSignerConfig;
+use alloy_primitives::Address;
+use eyre::Context as _;
+use serde_json::json;
+use tracing::debug;
+
+pub(crate) mod client;
+pub(crate) mod error;
+pub(crate) mod signer;
+
+pub use error::Error;
+pub use signer::RemoteSigner;
+
+use crate::client::RemoteSignerClient;
+
+#[derive(Debug)]
+pub struct RemoteSigners {
+    // The signer for PP settlement.
+    pub pp_settlement: RemoteSigner,
+    // The signer for transaction settlement, if not defined is expected
+    // that `pp_settlement` signer will be used.
+    pub tx_settlement: Option<RemoteSigner>,
+    // The signers for the additional settlement pool wallets.
+    pub settlement_pool: Vec<RemoteSigner>,
+}
+
+impl RemoteSigners {
+    /// Creates the remote signers from the configuration.
+    ///
+    /// The remote signer is queried with `eth_accounts` to make sure that it
+    /// manages the configured addresses, so a misconfiguration is reported at
+    /// startup rather than on the first settlement.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the remote signer cannot be
+    /// reached or if it does not manage one of the configured addresses.
+    pub async fn new(chain_id: u64, config: &RemoteSignerConfig) -> eyre::Result<Self> {
+        Self::new_with_settlement_pool(chain_id, config, &[]).await
+    }
+
+    /// Creates the remote signers from the configuration, along with a signer
+    /// for each of the `settlement_pool` wallets.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`RemoteSigners::new`], the settlement pool wallets being
+    /// checked against `eth_accounts` as well.
+    pub async fn new_with_settlement_pool(
+        chain_id: u64,
+        config: &RemoteSignerConfig,
+        settlement_pool: &[Address],
+    ) -> eyre::Result<Self> {
+        let client = Arc::new(
+            RemoteSignerClient::new(config)
+                .wrap_err("Unable to create the remote signer client")?,
+        );
+
+        let accounts: Vec<Address> = client
+            .request("eth_accounts", json!([]))
+            .await
+            .wrap_err("Unable to list the remote signer accounts")?;
+        debug!("Remote signer at {} manages {accounts:?}", config.url);
+
+        let pp_settlement_address: Address = config.pp_settlement_address.into();
+        let tx_settlement_address: Option<Address> = config
+            .tx_settlement_address
+            .map(Into::into)
+            .filter(|address| *address != pp_settlement_address);
+
+        for address in std::iter::once(pp_settlement_address)
+            .chain(tx_settlement_address)
+            .chain(settlement_pool.iter().copied())
+        {
+            if !accounts.contains(&address) {
+                return Err(Error::UnknownAccount(address).into());
+            }
+        }
+
+        Ok(Self {
+            pp_settlement: RemoteSigner::new(client.clone(), pp_settlement_address, Some(chain_id)),
+            tx_settlement: tx_settlement_address
+                .map(|address| RemoteSigner::new(client.clone(), address, Some(chain_id))),
+            settlement_pool: settlement_pool
+                .iter()
+                .map(|address| RemoteSigner::new(client.clone(), *address, Some(chain_id)))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
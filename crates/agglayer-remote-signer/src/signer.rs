@@ -0,0 +1,204 @@
+//! The [`RemoteSigner`] struct delegates the signing of messages and
+//! transactions to a Web3Signer-compatible JSON-RPC service.
+
+use std::sync::Arc;
+
+use alloy::{
+    consensus::{SignableTransaction, Transaction as _, TxEnvelope, TypedTransaction},
+    eips::eip2718::Decodable2718 as _,
+    network::TxSigner,
+    rpc::types::{TransactionInput, TransactionRequest},
+    signers::{Signer, UnsupportedSignerOperation},
+};
+use alloy_primitives::{hex, Address, Bytes, ChainId, Signature, SignatureError, B256};
+use async_trait::async_trait;
+use eyre::Context as _;
+
+use crate::{client::RemoteSignerClient, Error};
+
+/// A signer holding no key material, asking a remote service to sign on
+/// behalf of one of its accounts.
+///
+/// Every signature returned by the remote service is checked to recover to
+/// the configured address.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: Arc<RemoteSignerClient>,
+    address: Address,
+    chain_id: Option<ChainId>,
+}
+
+impl RemoteSigner {
+    pub(crate) fn new(
+        client: Arc<RemoteSignerClient>,
+        address: Address,
+        chain_id: Option<ChainId>,
+    ) -> Self {
+        Self {
+            client,
+            address,
+            chain_id,
+        }
+    }
+
+    /// Signs a transaction through the remote signer.
+    pub async fn sign_transaction(&self, tx: &TypedTransaction) -> eyre::Result<Signature> {
+        let mut tx_clone = tx.clone();
+        TxSigner::sign_transaction(self, &mut tx_clone)
+            .await
+            .wrap_err("Unable to sign transaction")
+    }
+
+    /// Returns the address associated with the signer.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the chain ID associated with the signer.
+    pub fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    /// Sets a new chain ID for the signer.
+    pub fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = Some(chain_id.into());
+        self
+    }
+
+    /// Checks that a signature returned by the remote signer was made by the
+    /// configured key.
+    fn check_recovered(&self, recovered: Result<Address, SignatureError>) -> Result<(), Error> {
+        let recovered = recovered.map_err(|error| {
+            Error::InvalidResponse(format!("unable to recover the signer: {error}"))
+        })?;
+
+        if recovered != self.address {
+            return Err(Error::SignerMismatch {
+                expected: self.address,
+                recovered,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Builds the `eth_signTransaction` parameter for the given transaction.
+    fn transaction_request(&self, tx: &dyn SignableTransaction<Signature>) -> TransactionRequest {
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = if tx.is_dynamic_fee() {
+            (
+                None,
+                Some(tx.max_fee_per_gas()),
+                tx.max_priority_fee_per_gas(),
+            )
+        } else {
+            (tx.gas_price(), None, None)
+        };
+
+        TransactionRequest {
+            from: Some(self.address),
+            to: Some(tx.kind()),
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            max_fee_per_blob_gas: tx.max_fee_per_blob_gas(),
+            gas: Some(tx.gas_limit()),
+            value: Some(tx.value()),
+            input: TransactionInput::both(tx.input().clone()),
+            nonce: Some(tx.nonce()),
+            chain_id: tx.chain_id(),
+            access_list: tx.access_list().cloned(),
+            transaction_type: Some(tx.ty()),
+            blob_versioned_hashes: tx.blob_versioned_hashes().map(<[B256]>::to_vec),
+            authorization_list: tx.authorization_list().map(<[_]>::to_vec),
+            ..Default::default()
+        }
+    }
+}
+
+/// Implementation of alloy's [`Signer`] trait for [`RemoteSigner`].
+///
+/// Web3Signer only signs EIP-191 prefixed messages, so signing a raw hash is
+/// not supported.
+#[async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, _hash: &B256) -> Result<Signature, alloy::signers::Error> {
+        Err(alloy::signers::Error::UnsupportedOperation(
+            UnsupportedSignerOperation::SignHash,
+        ))
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, alloy::signers::Error> {
+        let signature: Bytes = self
+            .client
+            .request("eth_sign", (self.address, hex::encode_prefixed(message)))
+            .await
+            .map_err(alloy::signers::Error::other)?;
+
+        let signature = Signature::from_raw(&signature).map_err(|error| {
+            alloy::signers::Error::other(Error::InvalidResponse(format!(
+                "invalid signature: {error}"
+            )))
+        })?;
+        self.check_recovered(signature.recover_address_from_msg(message))
+            .map_err(alloy::signers::Error::other)?;
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+/// Implementation of alloy's [`TxSigner`] trait for [`RemoteSigner`].
+///
+/// The transaction is sent to `eth_signTransaction`, and the signature is
+/// extracted from the returned raw transaction.
+#[async_trait]
+impl TxSigner<Signature> for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> Result<Signature, alloy::signers::Error> {
+        if let Some(chain_id) = self.chain_id {
+            if !tx.set_chain_id_checked(chain_id) {
+                return Err(alloy::signers::Error::TransactionChainIdMismatch {
+                    signer: chain_id,
+                    tx: tx.chain_id().unwrap_or_default(),
+                });
+            }
+        }
+
+        let raw: Bytes = self
+            .client
+            .request("eth_signTransaction", [self.transaction_request(tx)])
+            .await
+            .map_err(alloy::signers::Error::other)?;
+
+        let envelope = TxEnvelope::decode_2718(&mut raw.as_ref()).map_err(|error| {
+            alloy::signers::Error::other(Error::InvalidResponse(format!(
+                "invalid signed transaction: {error}"
+            )))
+        })?;
+        let signature = *envelope.signature();
+
+        // Checking against our own signature hash also guarantees that the
+        // remote signer did not alter the transaction.
+        self.check_recovered(signature.recover_address_from_prehash(&tx.signature_hash()))
+            .map_err(alloy::signers::Error::other)?;
+
+        Ok(signature)
+    }
+}
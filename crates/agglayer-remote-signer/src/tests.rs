@@ -0,0 +1,258 @@
+use std::time::Duration;
+
+use agglayer_config::RemoteSignerConfig;
+use alloy::{
+    consensus::{SignableTransaction as _, TxEip1559, TypedTransaction},
+    eips::eip2718::Encodable2718 as _,
+    network::{Ethereum, EthereumWallet, NetworkWallet},
+    rpc::types::TransactionRequest,
+    signers::{local::PrivateKeySigner, Signer as _},
+};
+use alloy_primitives::{Address, Bytes, TxKind, B256, U256};
+use jsonrpsee::{
+    server::{Server, ServerHandle},
+    types::ErrorObjectOwned,
+    RpcModule,
+};
+
+use super::*;
+
+const CHAIN_ID: u64 = 1337;
+
+struct MockSigner {
+    /// The key actually used to sign.
+    wallet: PrivateKeySigner,
+    /// The accounts advertised through `eth_accounts`.
+    accounts: Vec<Address>,
+}
+
+fn signing_error(error: impl std::fmt::Display) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32000, error.to_string(), None::<()>)
+}
+
+/// Starts a Web3Signer-like JSON-RPC server signing with the given wallet.
+async fn start_mock_signer(mock: MockSigner) -> (ServerHandle, url::Url) {
+    let mut module = RpcModule::new(mock);
+    module
+        .register_method("eth_accounts", |_, mock, _| {
+            Ok::<_, ErrorObjectOwned>(mock.accounts.clone())
+        })
+        .unwrap();
+    module
+        .register_async_method("eth_sign", |params, mock, _| async move {
+            let (_address, data): (Address, Bytes) = params.parse()?;
+            let signature = mock
+                .wallet
+                .sign_message(&data)
+                .await
+                .map_err(signing_error)?;
+            Ok::<_, ErrorObjectOwned>(Bytes::from(signature.as_bytes().to_vec()))
+        })
+        .unwrap();
+    module
+        .register_async_method("eth_signTransaction", |params, mock, _| async move {
+            let (mut request,): (TransactionRequest,) = params.parse()?;
+            // Always sign with the wallet, whatever the requested account.
+            request.from = Some(mock.wallet.address());
+            let wallet = EthereumWallet::from(mock.wallet.clone());
+            let envelope = NetworkWallet::<Ethereum>::sign_request(&wallet, request)
+                .await
+                .map_err(signing_error)?;
+            Ok::<_, ErrorObjectOwned>(Bytes::from(envelope.encoded_2718()))
+        })
+        .unwrap();
+
+    let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", server.local_addr().unwrap())
+        .parse()
+        .unwrap();
+
+    (server.start(module), url)
+}
+
+fn config(url: url::Url, pp_settlement_address: Address) -> RemoteSignerConfig {
+    RemoteSignerConfig {
+        url,
+        pp_settlement_address: pp_settlement_address.into(),
+        tx_settlement_address: None,
+        request_timeout: Duration::from_secs(1),
+        max_retries: 2,
+        retry_backoff: Duration::from_millis(10),
+    }
+}
+
+fn test_transaction() -> TypedTransaction {
+    TypedTransaction::Eip1559(TxEip1559 {
+        chain_id: CHAIN_ID,
+        nonce: 123,
+        gas_limit: 21000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: TxKind::Call(Address::from([0x11; 20])),
+        value: U256::from(1_000_000_000_u64),
+        access_list: Default::default(),
+        input: Default::default(),
+    })
+}
+
+#[tokio::test]
+async fn signs_transactions_and_messages() {
+    let wallet = PrivateKeySigner::from_slice(&[0x55; 32]).unwrap();
+    let address = wallet.address();
+    let (_handle, url) = start_mock_signer(MockSigner {
+        wallet,
+        accounts: vec![address],
+    })
+    .await;
+
+    let signers = RemoteSigners::new(CHAIN_ID, &config(url, address))
+        .await
+        .unwrap();
+    assert!(signers.tx_settlement.is_none());
+    let signer = signers.pp_settlement;
+
+    let tx = test_transaction();
+    let signature = signer.sign_transaction(&tx).await.unwrap();
+    assert_eq!(
+        signature
+            .recover_address_from_prehash(&tx.signature_hash())
+            .unwrap(),
+        address
+    );
+
+    let message = b"test message";
+    let signature = alloy::signers::Signer::sign_message(&signer, message)
+        .await
+        .unwrap();
+    assert_eq!(
+        signature.recover_address_from_msg(message).unwrap(),
+        address
+    );
+
+    let error = alloy::signers::Signer::sign_hash(&signer, &B256::from([0x42; 32]))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        alloy::signers::Error::UnsupportedOperation(_)
+    ));
+}
+
+#[tokio::test]
+async fn creates_distinct_tx_settlement_signer() {
+    let wallet = PrivateKeySigner::from_slice(&[0x55; 32]).unwrap();
+    let address = wallet.address();
+    let tx_settlement_address = Address::from([0x22; 20]);
+    let (_handle, url) = start_mock_signer(MockSigner {
+        wallet,
+        accounts: vec![address, tx_settlement_address],
+    })
+    .await;
+
+    let mut config = config(url, address);
+    config.tx_settlement_address = Some(tx_settlement_address.into());
+    let signers = RemoteSigners::new(CHAIN_ID, &config).await.unwrap();
+
+    assert_eq!(signers.pp_settlement.address(), address);
+    assert_eq!(
+        signers.tx_settlement.map(|signer| signer.address()),
+        Some(tx_settlement_address)
+    );
+}
+
+#[tokio::test]
+async fn creates_settlement_pool_signers() {
+    let wallet = PrivateKeySigner::from_slice(&[0x55; 32]).unwrap();
+    let address = wallet.address();
+    let pool_address = Address::from([0x33; 20]);
+    let (_handle, url) = start_mock_signer(MockSigner {
+        wallet,
+        accounts: vec![address, pool_address],
+    })
+    .await;
+
+    let signers = RemoteSigners::new_with_settlement_pool(
+        CHAIN_ID,
+        &config(url.clone(), address),
+        &[pool_address],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        signers
+            .settlement_pool
+            .iter()
+            .map(RemoteSigner::address)
+            .collect::<Vec<_>>(),
+        vec![pool_address]
+    );
+
+    let error = RemoteSigners::new_with_settlement_pool(
+        CHAIN_ID,
+        &config(url, address),
+        &[Address::from([0x44; 20])],
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::UnknownAccount(unknown)) if *unknown == Address::from([0x44; 20])
+    ));
+}
+
+#[tokio::test]
+async fn rejects_unknown_account() {
+    let wallet = PrivateKeySigner::from_slice(&[0x55; 32]).unwrap();
+    let (_handle, url) = start_mock_signer(MockSigner {
+        wallet,
+        accounts: Vec::new(),
+    })
+    .await;
+
+    let address = Address::from([0x11; 20]);
+    let error = RemoteSigners::new(CHAIN_ID, &config(url, address))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::UnknownAccount(unknown)) if *unknown == address
+    ));
+}
+
+#[tokio::test]
+async fn rejects_signature_from_another_key() {
+    let wallet = PrivateKeySigner::from_slice(&[0x55; 32]).unwrap();
+    let advertised = Address::from([0x11; 20]);
+    let (_handle, url) = start_mock_signer(MockSigner {
+        wallet,
+        accounts: vec![advertised],
+    })
+    .await;
+
+    let signers = RemoteSigners::new(CHAIN_ID, &config(url, advertised))
+        .await
+        .unwrap();
+
+    let error = signers
+        .pp_settlement
+        .sign_transaction(&test_transaction())
+        .await
+        .unwrap_err();
+    assert!(format!("{error:?}").contains("expected"));
+}
+
+#[tokio::test]
+async fn reports_unreachable_signer_after_retries() {
+    // Nothing listens on the discard port.
+    let url = "http://127.0.0.1:9".parse().unwrap();
+
+    let error = RemoteSigners::new(CHAIN_ID, &config(url, Address::from([0x11; 20])))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::Transport(_))
+    ));
+}
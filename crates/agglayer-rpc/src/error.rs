@@ -2,7 +2,7 @@
 use agglayer_contracts::L1RpcError;
 pub use agglayer_storage::error::Error as StorageError;
 pub use agglayer_types::primitives::Digest;
-use agglayer_types::{Address, CertificateId, Height, NetworkId, SignerError};
+use agglayer_types::{Address, CertificateId, Height, NetworkId, SettlementJobId, SignerError};
 use alloy::contract::Error as ContractError;
 
 pub use crate::rate_limiting::RateLimited as RateLimitedError;
@@ -16,6 +16,15 @@ pub enum CertificateRetrievalError {
     NotFound { certificate_id: CertificateId },
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum ListCertificateHeadersError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error("The limit must be positive")]
+    InvalidLimit,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CertificateSubmissionError {
     #[error(transparent)]
@@ -37,6 +46,12 @@ pub enum CertificateSubmissionError {
         #[source]
         source: Option<L1RpcError>,
     },
+
+    #[error(transparent)]
+    RateLimited(#[from] RateLimitedError),
+
+    #[error("Network {network_id} already has {max} pending certificates")]
+    TooManyPendingCertificates { network_id: NetworkId, max: u32 },
 }
 
 /// Errors related to signature verification process.
@@ -167,3 +182,132 @@ pub enum GetNetworkInfoError {
         source: eyre::Error,
     },
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum StateProofError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error("No settled state for network {network_id}")]
+    NoSettledState { network_id: NetworkId },
+
+    #[error(
+        "The settled state of network {network_id} does not match its latest settled certificate \
+         {certificate_id}, it is likely being updated"
+    )]
+    UnanchoredState {
+        network_id: NetworkId,
+        certificate_id: CertificateId,
+    },
+
+    #[error(
+        "Height {height} of network {network_id} is not settled, the latest settled height is \
+         {settled}"
+    )]
+    HeightNotSettled {
+        network_id: NetworkId,
+        height: Height,
+        settled: Height,
+    },
+
+    #[error("No state of network {network_id} can be read at height {height}")]
+    NoStateSnapshot {
+        network_id: NetworkId,
+        height: Height,
+    },
+
+    #[error(
+        "Leaf index {leaf_index} is out of the local exit tree of network {network_id}, which has \
+         {leaf_count} leaves"
+    )]
+    LeafIndexOutOfRange {
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: u32,
+    },
+
+    #[error("The local exit tree leaves of network {network_id} are not available")]
+    LeavesUnavailable { network_id: NetworkId },
+
+    #[error("Failed to generate the proof for network {network_id}: {reason}")]
+    ProofGeneration {
+        network_id: NetworkId,
+        reason: String,
+    },
+}
+
+impl StateProofError {
+    pub(crate) fn proof_generation(network_id: NetworkId, error: impl ToString) -> Self {
+        Self::ProofGeneration {
+            network_id,
+            reason: error.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertificateSimulationError {
+    #[error("Certificate simulation is not available on this node")]
+    Unavailable,
+
+    #[error(
+        "Certificate height {got} does not follow the settled state of network {network_id}, \
+         expected height {expected}"
+    )]
+    UnexpectedHeight {
+        network_id: NetworkId,
+        expected: Height,
+        got: Height,
+    },
+
+    #[error(transparent)]
+    SettledState(#[from] StateProofError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StateVerificationError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error("Unable to fetch the pessimistic root of network {network_id} from L1: {source}")]
+    L1 {
+        network_id: NetworkId,
+        source: L1RpcError,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkRollbackError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error(
+        "Network {network_id} has no settled certificate at height {height}, the latest settled \
+         height is {settled:?}"
+    )]
+    HeightNotSettled {
+        network_id: NetworkId,
+        height: Height,
+        settled: Option<Height>,
+    },
+
+    #[error("Unable to rebuild the state of network {network_id} at height {height}: {reason}")]
+    Rebuild {
+        network_id: NetworkId,
+        height: Height,
+        reason: String,
+    },
+
+    #[error("Network {0} must be disabled before being rolled back")]
+    NetworkNotDisabled(NetworkId),
+
+    #[error(
+        "Settlement job {settlement_job_id} of network {network_id} is part of the open \
+         settlement batch {batch_job_id}, roll back once the batch completes"
+    )]
+    SettlementBatchOpen {
+        network_id: NetworkId,
+        batch_job_id: SettlementJobId,
+        settlement_job_id: SettlementJobId,
+    },
+}
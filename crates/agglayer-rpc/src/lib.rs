@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use agglayer_certificate_orchestrator::Certifier;
 use agglayer_config::{epoch::BlockClockConfig, Config, Epoch};
 use agglayer_contracts::{AggchainContract, L1TransactionFetcher, RollupContract};
 use agglayer_primitives::Hashable;
@@ -11,22 +12,71 @@ use agglayer_storage::{
         PendingCertificateWriter, SettlementReader, StateReader, StateWriter,
     },
 };
+use agglayer_tries::roots::LocalExitRoot;
 use agglayer_types::{
-    aggchain_data::MultisigCtx, aggchain_proof::AggchainData, Certificate, CertificateHeader,
-    CertificateId, CertificateStatus, ContractCallOutcome, EpochConfiguration, Height, NetworkId,
-    NetworkInfo, NetworkStatus, NetworkType, SettledClaim, U256,
+    aggchain_data::MultisigCtx, aggchain_proof::AggchainData, BalanceProof, Certificate,
+    CertificateHeader, CertificateId, CertificateSimulation, CertificateStatus,
+    ContractCallOutcome, EpochConfiguration, ExecutionMode, GlobalIndex, Height, ListDirection,
+    LocalExitTreeProof, LocalNetworkStateData, NetworkId, NetworkInfo, NetworkStatus, NetworkType,
+    NullifierProof, NullifierProofPath, SettledClaim, SettledStateAnchor, SimulatedRoots,
+    SimulationOutcome, TokenInfo, U256,
 };
 use error::SignatureVerificationError;
-use tokio::sync::mpsc;
+use pessimistic_proof::{local_balance_tree::BalanceTree, nullifier_tree::NullifierKey};
+use tokio::sync::{broadcast, mpsc};
 use tracing::{debug, error, info, instrument, warn};
 
-pub use self::error::{CertificateRetrievalError, CertificateSubmissionError, GetNetworkInfoError};
-use crate::error::{GetLatestCertificateError, GetLatestSettledClaimError, ProofRetrievalError};
+pub use self::error::{
+    CertificateRetrievalError, CertificateSimulationError, CertificateSubmissionError,
+    GetNetworkInfoError, ListCertificateHeadersError, NetworkRollbackError, StateProofError,
+    StateVerificationError,
+};
+use crate::{
+    error::{GetLatestCertificateError, GetLatestSettledClaimError, ProofRetrievalError},
+    local_exit_tree_cache::LocalExitTreeCache,
+};
 
 pub mod error;
+mod local_exit_tree_cache;
+pub mod network_rollback;
+pub mod state_snapshots;
+pub mod state_verification;
 #[cfg(test)]
 mod tests;
 
+/// Default capacity of the certificate status channel.
+///
+/// Subscribers lagging behind by more than this number of transitions miss
+/// the oldest ones.
+pub const DEFAULT_CERTIFICATE_STATUS_CHANNEL_SIZE: usize = 1000;
+
+/// Maximum number of certificate headers returned by a single listing.
+///
+/// Larger limits requested by the clients are clamped to this value.
+pub const MAX_CERTIFICATE_HEADERS_LIMIT: usize = 1000;
+
+/// Filter applied to the certificate status transitions by a subscriber.
+///
+/// An empty filter matches every transition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CertificateStatusFilter {
+    /// Only match the certificates of this network.
+    pub network_id: Option<NetworkId>,
+    /// Only match this certificate.
+    pub certificate_id: Option<CertificateId>,
+}
+
+impl CertificateStatusFilter {
+    /// Returns whether the given header matches the filter.
+    pub fn matches(&self, header: &CertificateHeader) -> bool {
+        self.network_id
+            .is_none_or(|network_id| network_id == header.network_id)
+            && self
+                .certificate_id
+                .is_none_or(|certificate_id| certificate_id == header.certificate_id)
+    }
+}
+
 /// The RPC agglayer service implementation.
 pub struct AgglayerService<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore> {
     certificate_sender: mpsc::Sender<(NetworkId, Height, CertificateId)>,
@@ -36,6 +86,10 @@ pub struct AgglayerService<L1Rpc, PendingStore, StateStore, DebugStore, EpochsSt
     epochs_store: Arc<EpochsStore>,
     config: Arc<Config>,
     l1_rpc_provider: Arc<L1Rpc>,
+    certificate_status_sender: broadcast::Sender<CertificateHeader>,
+    certifier: Option<Arc<dyn Certifier>>,
+    rate_limiter: rate_limiting::RateLimiter,
+    local_exit_trees: LocalExitTreeCache,
 }
 
 impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
@@ -51,6 +105,10 @@ impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
         config: Arc<Config>,
         l1_rpc_provider: Arc<L1Rpc>,
     ) -> Self {
+        let (certificate_status_sender, _) =
+            broadcast::channel(DEFAULT_CERTIFICATE_STATUS_CHANNEL_SIZE);
+        let rate_limiter = rate_limiting::RateLimiter::new(config.rate_limiting.clone());
+
         Self {
             certificate_sender,
             pending_store,
@@ -59,9 +117,47 @@ impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
             epochs_store,
             config,
             l1_rpc_provider,
+            certificate_status_sender,
+            certifier: None,
+            rate_limiter,
+            local_exit_trees: LocalExitTreeCache::default(),
         }
     }
 
+    /// Use the given channel to publish and subscribe to the certificate
+    /// status transitions.
+    ///
+    /// The same sender is expected to be given to the certificate
+    /// orchestrator, which publishes the transitions happening after the
+    /// submission.
+    pub fn with_certificate_status_sender(
+        mut self,
+        certificate_status_sender: broadcast::Sender<CertificateHeader>,
+    ) -> Self {
+        self.certificate_status_sender = certificate_status_sender;
+        self
+    }
+
+    /// Use the given certifier to simulate the certificates.
+    ///
+    /// Without a certifier, the simulations are refused.
+    pub fn with_certifier(mut self, certifier: Arc<dyn Certifier>) -> Self {
+        self.certifier = Some(certifier);
+        self
+    }
+
+    /// Use the given rate limiter for the certificate submission, e.g. one
+    /// shared with other services.
+    pub fn with_rate_limiter(mut self, rate_limiter: rate_limiting::RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Subscribe to the certificate status transitions.
+    pub fn subscribe_certificate_status(&self) -> broadcast::Receiver<CertificateHeader> {
+        self.certificate_status_sender.subscribe()
+    }
+
     /// Get access to the configuration.
     pub fn config(&self) -> &Config {
         &self.config
@@ -295,6 +391,29 @@ where
             .ok_or(CertificateRetrievalError::NotFound { certificate_id })
     }
 
+    /// List the settled certificate headers of a network, starting at
+    /// `from_height` and walking in the given direction.
+    ///
+    /// A zero `limit` is refused, larger ones are clamped to
+    /// [`MAX_CERTIFICATE_HEADERS_LIMIT`].
+    pub fn list_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        direction: ListDirection,
+    ) -> Result<Vec<CertificateHeader>, ListCertificateHeadersError> {
+        if limit == 0 {
+            return Err(ListCertificateHeadersError::InvalidLimit);
+        }
+        let limit = limit.min(MAX_CERTIFICATE_HEADERS_LIMIT);
+
+        Ok(self
+            .state
+            .list_certificate_headers(network_id, from_height, limit, direction)
+            .inspect_err(|err| error!("Failed to list certificate headers: {err}"))?)
+    }
+
     /// Get the proof for a certificate by certificate ID
     pub fn get_proof(
         &self,
@@ -432,6 +551,264 @@ where
         Ok(None)
     }
 
+    /// Read the settled local network state of a network, along with the
+    /// anchor against which the proofs drawn from it are issued.
+    ///
+    /// Without `height`, the state is the one of the latest settled
+    /// certificate. Otherwise, it is the snapshot recorded when the certificate
+    /// at `height` got settled. The states are never replayed from here, the
+    /// heights settled before the snapshots got recorded being served once
+    /// backfilled by [`state_snapshots::backfill_state_snapshots`]. Either
+    /// way, the state is checked against the local exit root of its
+    /// certificate, so that a state being advanced by an ongoing settlement is
+    /// not served.
+    fn read_settled_state(
+        &self,
+        network_id: NetworkId,
+        height: Option<Height>,
+    ) -> Result<(SettledStateAnchor, LocalNetworkStateData), StateProofError> {
+        let Some((_, SettledCertificate(latest_certificate_id, settled_height, _, _))) = self
+            .state
+            .get_latest_settled_certificate_per_network(&network_id)?
+        else {
+            return Err(StateProofError::NoSettledState { network_id });
+        };
+
+        let (certificate_id, height, state) = match height {
+            None => {
+                let Some(state) = self.state.read_local_network_state(network_id)? else {
+                    return Err(StateProofError::NoSettledState { network_id });
+                };
+                (latest_certificate_id, settled_height, state)
+            }
+            Some(height) if height > settled_height => {
+                return Err(StateProofError::HeightNotSettled {
+                    network_id,
+                    height,
+                    settled: settled_height,
+                });
+            }
+            Some(height) => {
+                let no_snapshot = StateProofError::NoStateSnapshot { network_id, height };
+                let Some(header) = self
+                    .state
+                    .get_certificate_header_by_cursor(network_id, height)?
+                else {
+                    return Err(no_snapshot);
+                };
+                let Some(state) = self.state.read_local_network_state_at(network_id, height)?
+                else {
+                    return Err(no_snapshot);
+                };
+                (header.certificate_id, height, state)
+            }
+        };
+
+        let local_exit_root = LocalExitRoot::from(state.exit_tree.get_root());
+        let header = self.state.get_certificate_header(&certificate_id)?;
+        if header.is_none_or(|header| header.new_local_exit_root != local_exit_root) {
+            warn!(
+                %network_id,
+                %certificate_id,
+                %height,
+                "Settled state does not match its settled certificate"
+            );
+            return Err(StateProofError::UnanchoredState {
+                network_id,
+                certificate_id,
+            });
+        }
+
+        let anchor = SettledStateAnchor {
+            certificate_id,
+            height,
+            local_exit_root,
+            let_leaf_count: state.exit_tree.leaf_count(),
+            balance_root: state.balance_tree.root,
+            nullifier_root: state.nullifier_tree.root,
+        };
+
+        Ok((anchor, state))
+    }
+
+    /// Get the roots of the settled state of a network at `height`.
+    #[instrument(skip(self))]
+    pub fn get_network_state_at(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<SettledStateAnchor, StateProofError> {
+        let (anchor, _) = self.read_settled_state(network_id, Some(height))?;
+
+        Ok(anchor)
+    }
+
+    /// Prove the balance of a token in the settled local balance tree of a
+    /// network, at the latest settled height unless `height` is given.
+    #[instrument(skip(self))]
+    pub fn get_balance_proof(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+        height: Option<Height>,
+    ) -> Result<BalanceProof, StateProofError> {
+        let (anchor, state) = self.read_settled_state(network_id, height)?;
+        let balance_tree = BalanceTree(state.balance_tree);
+
+        let balance = balance_tree.get_balance(token_info);
+        let path = if balance.is_zero() {
+            balance_tree.0.get_inclusion_proof_zero(token_info)
+        } else {
+            balance_tree.0.get_inclusion_proof(token_info)
+        }
+        .map_err(|error| StateProofError::proof_generation(network_id, error))?;
+
+        Ok(BalanceProof {
+            anchor,
+            token_info,
+            balance,
+            path,
+        })
+    }
+
+    /// Prove that a global index is claimed or not in the settled nullifier
+    /// tree of a network, at the latest settled height unless `height` is
+    /// given.
+    #[instrument(skip(self))]
+    pub fn get_nullifier_proof(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+        height: Option<Height>,
+    ) -> Result<NullifierProof, StateProofError> {
+        let (anchor, state) = self.read_settled_state(network_id, height)?;
+        let nullifier_tree = state.nullifier_tree;
+
+        let key = NullifierKey::from(global_index);
+        let path = if nullifier_tree.get(key).is_some() {
+            nullifier_tree
+                .get_inclusion_proof(key)
+                .map(NullifierProofPath::Inclusion)
+        } else {
+            nullifier_tree
+                .get_non_inclusion_proof(key)
+                .map(NullifierProofPath::NonInclusion)
+        }
+        .map_err(|error| StateProofError::proof_generation(network_id, error))?;
+
+        Ok(NullifierProof {
+            anchor,
+            global_index,
+            path,
+        })
+    }
+
+    /// Prove a leaf of the settled local exit tree of a network, at the
+    /// latest settled height unless `height` is given.
+    ///
+    /// Only the frontier of the tree is kept in the local network state, so
+    /// the tree is rebuilt once from the stored leaves and cached, then
+    /// extended with the leaves settled since.
+    #[instrument(skip(self))]
+    pub fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        height: Option<Height>,
+    ) -> Result<LocalExitTreeProof, StateProofError> {
+        let (anchor, _) = self.read_settled_state(network_id, height)?;
+
+        let leaf_count = anchor.let_leaf_count;
+        if leaf_index >= leaf_count {
+            return Err(StateProofError::LeafIndexOutOfRange {
+                network_id,
+                leaf_index,
+                leaf_count,
+            });
+        }
+
+        let (leaf, path) = self.local_exit_trees.prove(
+            &*self.state,
+            network_id,
+            leaf_index,
+            leaf_count,
+            anchor.local_exit_root,
+        )?;
+
+        Ok(LocalExitTreeProof {
+            anchor,
+            leaf_index,
+            leaf,
+            path,
+        })
+    }
+
+    /// Dry-run a certificate on top of the settled state of its network.
+    ///
+    /// The certificate goes through the same witness generation and native
+    /// execution of the pessimistic proof as in the certifier, but no proof is
+    /// generated and nothing is persisted. A certificate that would be put in
+    /// error yields a [`SimulationOutcome::Failure`] with the error.
+    #[instrument(skip(self, certificate), fields(
+        certificate_id = %certificate.hash(),
+        network_id = %certificate.network_id,
+        height = %certificate.height,
+    ))]
+    pub async fn simulate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> Result<CertificateSimulation, CertificateSimulationError> {
+        let certifier = self
+            .certifier
+            .as_ref()
+            .ok_or(CertificateSimulationError::Unavailable)?;
+        let network_id = certificate.network_id;
+
+        let (expected_height, mut state) = match self.read_settled_state(network_id, None) {
+            Ok((anchor, state)) => (anchor.height.next(), state),
+            Err(StateProofError::NoSettledState { .. }) => {
+                (Height::ZERO, LocalNetworkStateData::default())
+            }
+            Err(error) => return Err(error.into()),
+        };
+        if certificate.height != expected_height {
+            return Err(CertificateSimulationError::UnexpectedHeight {
+                network_id,
+                expected: expected_height,
+                got: certificate.height,
+            });
+        }
+
+        let prefix = ExecutionMode::DryRun.prefix();
+        let outcome = match certifier
+            .witness_generation(&certificate, &mut state, None)
+            .await
+        {
+            Ok((_, _, output)) => {
+                info!("{prefix}Certificate successfully executed");
+                SimulationOutcome::Success(SimulatedRoots {
+                    new_local_exit_root: state.exit_tree.get_root().into(),
+                    new_let_leaf_count: state.exit_tree.leaf_count(),
+                    new_balance_root: state.balance_tree.root,
+                    new_nullifier_root: state.nullifier_tree.root,
+                    prev_pessimistic_root: output.prev_pessimistic_root,
+                    new_pessimistic_root: output.new_pessimistic_root,
+                })
+            }
+            Err(error) => {
+                info!(?error, "{prefix}Certificate execution failed");
+                SimulationOutcome::Failure(error.into())
+            }
+        };
+
+        Ok(CertificateSimulation {
+            certificate_id: certificate.hash(),
+            network_id,
+            height: certificate.height,
+            outcome,
+        })
+    }
+
     /// Assemble the current information of the specified network from
     /// the data in various sources.
     #[instrument(skip(self))]
@@ -739,6 +1116,50 @@ where
     }
 }
 
+impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
+    AgglayerService<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
+where
+    PendingStore: PendingCertificateReader + 'static,
+{
+    /// Refuses a new pending certificate once the network reached its cap.
+    ///
+    /// Replacing a pending certificate does not grow the queue, so it is
+    /// always allowed.
+    #[allow(clippy::result_large_err)]
+    fn check_pending_certificate_cap(
+        &self,
+        certificate: &Certificate,
+    ) -> Result<(), CertificateSubmissionError> {
+        let network_id = certificate.network_id;
+        let Some(max) = self
+            .config
+            .rate_limiting
+            .config_for(network_id.to_u32())
+            .max_pending_certificates
+        else {
+            return Ok(());
+        };
+
+        let pending = self
+            .pending_store
+            .count_pending_certificates_for_network(network_id)?;
+        if pending < max as usize
+            || self
+                .pending_store
+                .get_certificate(network_id, certificate.height)?
+                .is_some()
+        {
+            return Ok(());
+        }
+
+        warn!(
+            hash = %certificate.hash(),
+            "Network {network_id} reached its cap of {max} pending certificates"
+        );
+        Err(CertificateSubmissionError::TooManyPendingCertificates { network_id, max })
+    }
+}
+
 impl<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
     AgglayerService<L1Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 where
@@ -979,11 +1400,21 @@ where
                 CertificateSubmissionError::SignatureError(error)
             })?;
 
+        // Throttle only once the signature is checked, so that nobody but the
+        // network itself can exhaust its quota.
+        self.check_pending_certificate_cap(&certificate)?;
+        let now = tokio::time::Instant::now();
+        let rate_guard = self
+            .rate_limiter
+            .reserve_send_certificate(certificate.network_id.to_u32(), now)
+            .inspect_err(|error| warn!(%hash, "Certificate submission rate limited: {error}"))?;
+
         // TODO: Batch the different queries.
         // Insert the certificate into the pending store.
         self.pending_store
             .insert_pending_certificate(certificate.network_id, certificate.height, &certificate)
             .inspect_err(|e| error!("Failed to insert certificate into pending store: {e}"))?;
+        rate_guard.record(now);
 
         // Insert the certificate header into the state store.
         self.state
@@ -1006,6 +1437,18 @@ where
                 CertificateSubmissionError::OrchestratorNotResponsive
             })?;
 
+        if self.certificate_status_sender.receiver_count() > 0 {
+            match self.state.get_certificate_header(&hash) {
+                Ok(Some(header)) => {
+                    _ = self.certificate_status_sender.send(header);
+                }
+                Ok(None) => warn!(%hash, "Unable to publish the pending certificate status"),
+                Err(error) => {
+                    warn!(%hash, "Unable to publish the pending certificate status: {error}")
+                }
+            }
+        }
+
         Ok(hash)
     }
 }
@@ -0,0 +1,107 @@
+//! Local exit trees of the networks, kept in memory to serve their proofs.
+//!
+//! Only the frontier of a local exit tree is kept in the local network state,
+//! so proving a leaf needs the whole tree, rebuilt from the stored leaves. The
+//! tree is append-only: a cached tree is extended with the leaves settled
+//! since it was built, and proofs at a previous settled height are drawn from
+//! it without reading any leaf.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use agglayer_storage::stores::StateReader;
+use agglayer_tries::roots::LocalExitRoot;
+use agglayer_types::{Digest, NetworkId};
+use pessimistic_proof::{local_exit_tree::data::LocalExitTreeData, unified_bridge::LETMerkleProof};
+use tracing::{debug, error};
+
+use crate::StateProofError;
+
+/// The local exit trees rebuilt so far, one per network.
+///
+/// Rebuilding the tree of a network is serialized by its lock, so concurrent
+/// proof requests read the stored leaves of a network at most once.
+#[derive(Default)]
+pub(crate) struct LocalExitTreeCache {
+    trees: Mutex<BTreeMap<NetworkId, Arc<Mutex<LocalExitTreeData>>>>,
+}
+
+impl LocalExitTreeCache {
+    /// Prove the leaf at `leaf_index` of the local exit tree of a network
+    /// holding its first `leaf_count` leaves, whose root is
+    /// `local_exit_root`.
+    ///
+    /// Returns the leaf and its path.
+    pub(crate) fn prove(
+        &self,
+        state: &impl StateReader,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: u32,
+        local_exit_root: LocalExitRoot,
+    ) -> Result<(Digest, LETMerkleProof<32>), StateProofError> {
+        let tree = self
+            .trees
+            .lock()
+            .map_err(|_| poisoned(network_id))?
+            .entry(network_id)
+            .or_default()
+            .clone();
+        let mut tree = tree.lock().map_err(|_| poisoned(network_id))?;
+
+        if !Self::extend(state, network_id, &mut tree, leaf_count, local_exit_root)? {
+            // The cached leaves were rewritten, e.g. by a network rollback.
+            debug!(%network_id, "Rebuilding the cached local exit tree");
+            *tree = LocalExitTreeData::new();
+            if !Self::extend(state, network_id, &mut tree, leaf_count, local_exit_root)? {
+                error!(
+                    %network_id,
+                    leaf_count,
+                    "Stored local exit tree leaves do not match the settled local exit root"
+                );
+                *tree = LocalExitTreeData::new();
+                return Err(StateProofError::LeavesUnavailable { network_id });
+            }
+        }
+
+        let path = tree
+            .get_proof_at(leaf_index, leaf_count as usize)
+            .map_err(|error| StateProofError::proof_generation(network_id, error))?;
+
+        Ok((tree.layers[0][leaf_index as usize], path))
+    }
+
+    /// Append the stored leaves missing from `tree` up to `leaf_count`, and
+    /// check that its first `leaf_count` leaves have `local_exit_root` as
+    /// root.
+    fn extend(
+        state: &impl StateReader,
+        network_id: NetworkId,
+        tree: &mut LocalExitTreeData,
+        leaf_count: u32,
+        local_exit_root: LocalExitRoot,
+    ) -> Result<bool, StateProofError> {
+        let cached = tree.leaf_count() as u32;
+        if cached < leaf_count {
+            let leaves = state
+                .read_local_exit_tree_leaves(network_id, cached..leaf_count)?
+                .ok_or(StateProofError::LeavesUnavailable { network_id })?;
+            for leaf in leaves {
+                tree.add_leaf(leaf)
+                    .map_err(|error| StateProofError::proof_generation(network_id, error))?;
+            }
+        }
+
+        let root = tree
+            .get_root_at(leaf_count as usize)
+            .map_err(|error| StateProofError::proof_generation(network_id, error))?;
+
+        Ok(LocalExitRoot::from(root) == local_exit_root)
+    }
+}
+
+fn poisoned(network_id: NetworkId) -> StateProofError {
+    StateProofError::proof_generation(network_id, "the local exit tree cache lock is poisoned")
+}
@@ -0,0 +1,509 @@
+//! Rollback of a network to a previous settled height.
+//!
+//! The state at the target height is rebuilt by replaying the settled
+//! certificates up to it, as the heights settled before the network states
+//! got recorded have no snapshot to restore. A
+//! rollback is always planned first: the plan lists everything it removes and
+//! is identified by a hash, which must be given back to apply it.
+
+use std::sync::Arc;
+
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    stores::{
+        EpochStoreReader, PendingCertificateReader, PendingCertificateWriter, SettlementReader,
+        StateReader, StateWriter,
+    },
+};
+use agglayer_types::{
+    Address, CertificateId, CertificateStatus, ClientError, ClientErrorType, Digest, Height,
+    LocalNetworkStateData, NetworkId, SettlementAttemptResult, SettlementJobId, SettlementTxHash,
+};
+use pessimistic_proof::keccak::keccak256_combine;
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    error::NetworkRollbackError,
+    state_verification::{replay_settled_state, StateRoots},
+};
+
+/// Everything a rollback of a network removes, and the state it restores.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkRollbackPlan {
+    pub network_id: NetworkId,
+    /// The height of the latest settled certificate before the rollback.
+    pub settled_height: Height,
+    /// The height of the settled certificate the network is rolled back to.
+    pub target_height: Height,
+    pub target_certificate_id: CertificateId,
+    /// The roots of the persisted state, if it can be read.
+    pub current_state: Option<StateRoots>,
+    /// The roots of the state rebuilt at the target height.
+    pub restored_state: StateRoots,
+    /// The settled and pending certificates above the target height.
+    pub removed_certificates: Vec<RemovedCertificate>,
+    /// The settlement jobs of the removed certificates that haven't completed.
+    pub cancelled_settlement_jobs: Vec<SettlementJobId>,
+    /// The attempts of the cancelled settlement jobs that got broadcast, and
+    /// may land on L1 nonetheless.
+    ///
+    /// They aren't part of the plan hash: the live tasks of the jobs are only
+    /// aborted when the plan is applied, and the applied plan lists the
+    /// attempts recorded by then.
+    pub broadcast_settlement_attempts: Vec<BroadcastSettlementAttempt>,
+    /// Whether the network is disabled, which is required to apply the plan.
+    pub network_disabled: bool,
+    /// The hash identifying this plan, to give back to apply it.
+    pub plan_hash: Digest,
+}
+
+impl NetworkRollbackPlan {
+    /// Hash of everything the plan removes and restores.
+    ///
+    /// Whether the network is disabled isn't part of it, so that a plan can
+    /// be previewed before disabling the network.
+    fn compute_hash(&self) -> Digest {
+        let roots = |roots: &StateRoots| {
+            [
+                roots.local_exit_root.as_slice().to_vec(),
+                roots.leaf_count.to_be_bytes().to_vec(),
+                roots.balance_root.as_slice().to_vec(),
+                roots.nullifier_root.as_slice().to_vec(),
+            ]
+        };
+
+        let mut items = vec![
+            self.network_id.to_be_bytes().to_vec(),
+            self.settled_height.as_u64().to_be_bytes().to_vec(),
+            self.target_height.as_u64().to_be_bytes().to_vec(),
+            self.target_certificate_id.as_slice().to_vec(),
+        ];
+        items.extend(roots(&self.restored_state));
+        for removed in &self.removed_certificates {
+            items.push(removed.certificate_id.as_slice().to_vec());
+            items.push(removed.height.as_u64().to_be_bytes().to_vec());
+        }
+        for job_id in &self.cancelled_settlement_jobs {
+            items.push(job_id.to_be_bytes().to_vec());
+        }
+
+        keccak256_combine(items)
+    }
+}
+
+/// A certificate removed by a rollback.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemovedCertificate {
+    pub certificate_id: CertificateId,
+    pub height: Height,
+    /// The status of the certificate, if it has a header.
+    pub status: Option<CertificateStatus>,
+    /// Whether the certificate is in the pending queue.
+    pub pending: bool,
+    pub settlement_job_id: Option<SettlementJobId>,
+}
+
+/// An attempt of a cancelled settlement job that may land on L1.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastSettlementAttempt {
+    pub settlement_job_id: SettlementJobId,
+    pub attempt_number: u64,
+    pub sender_wallet: Address,
+    pub nonce: u64,
+    pub tx_hash: SettlementTxHash,
+}
+
+/// A planned rollback, along with the state it restores.
+#[derive(Debug)]
+pub struct NetworkRollback {
+    pub plan: NetworkRollbackPlan,
+    settled: SettledCertificate,
+    state: LocalNetworkStateData,
+    leaves: Vec<Digest>,
+}
+
+/// Rollback of the networks, whatever the stores backing it.
+pub trait RollbackNetwork: Send + Sync {
+    /// Plan the rollback of a network to its settled certificate at `height`,
+    /// without writing anything.
+    fn plan_rollback(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<NetworkRollback, NetworkRollbackError>;
+
+    /// Apply a planned rollback. The network must be disabled.
+    ///
+    /// Returns the applied plan, listing the attempts of the cancelled
+    /// settlement jobs broadcast by the time they got cancelled.
+    fn apply_rollback(
+        &self,
+        rollback: &NetworkRollback,
+    ) -> Result<NetworkRollbackPlan, NetworkRollbackError>;
+}
+
+/// Plans and applies the rollbacks on the state and pending stores.
+pub struct NetworkRollbackService<StateStore, PendingStore, EpochsStore> {
+    state: Arc<StateStore>,
+    pending_store: Arc<PendingStore>,
+    epochs_store: Arc<EpochsStore>,
+}
+
+impl<StateStore, PendingStore, EpochsStore>
+    NetworkRollbackService<StateStore, PendingStore, EpochsStore>
+{
+    pub fn new(
+        state: Arc<StateStore>,
+        pending_store: Arc<PendingStore>,
+        epochs_store: Arc<EpochsStore>,
+    ) -> Self {
+        Self {
+            state,
+            pending_store,
+            epochs_store,
+        }
+    }
+}
+
+impl<StateStore, PendingStore, EpochsStore>
+    NetworkRollbackService<StateStore, PendingStore, EpochsStore>
+where
+    StateStore: StateReader + SettlementReader,
+    PendingStore: PendingCertificateReader,
+    EpochsStore: EpochStoreReader,
+{
+    /// Rebuild the state of a network at `height` by replaying its settled
+    /// certificates, along with the leaves of its exit tree.
+    fn rebuild(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<(SettledCertificate, LocalNetworkStateData, Vec<Digest>), NetworkRollbackError>
+    {
+        let (header, state, leaves) =
+            replay_settled_state(&*self.state, &*self.epochs_store, network_id, height)?.map_err(
+                |(height, reason)| NetworkRollbackError::Rebuild {
+                    network_id,
+                    height,
+                    reason,
+                },
+            )?;
+
+        let settled = header
+            .epoch_number
+            .zip(header.certificate_index)
+            .map(|(epoch_number, certificate_index)| {
+                SettledCertificate(
+                    header.certificate_id,
+                    header.height,
+                    epoch_number,
+                    certificate_index,
+                )
+            })
+            .ok_or_else(|| NetworkRollbackError::Rebuild {
+                network_id,
+                height,
+                reason: "No settled certificate".to_string(),
+            })?;
+
+        Ok((settled, state, leaves))
+    }
+
+    /// List the settled and pending certificates above `height`, by
+    /// increasing height.
+    fn removed_certificates(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+        settled_height: Height,
+    ) -> Result<Vec<RemovedCertificate>, NetworkRollbackError> {
+        let mut removed: Vec<RemovedCertificate> = Vec::new();
+
+        for removed_height in (height.as_u64() + 1..=settled_height.as_u64()).map(Height::new) {
+            if let Some(header) = self
+                .state
+                .get_certificate_header_by_cursor(network_id, removed_height)?
+            {
+                removed.push(RemovedCertificate {
+                    certificate_id: header.certificate_id,
+                    height: header.height,
+                    status: Some(header.status),
+                    pending: false,
+                    settlement_job_id: None,
+                });
+            }
+        }
+
+        for certificate in self
+            .pending_store
+            .list_pending_certificates_for_network(network_id, height.next())?
+        {
+            let certificate_id = certificate.hash();
+            if let Some(known) = removed
+                .iter_mut()
+                .find(|known| known.certificate_id == certificate_id)
+            {
+                known.pending = true;
+                continue;
+            }
+
+            let status = self
+                .state
+                .get_certificate_header(&certificate_id)?
+                .map(|header| header.status);
+            removed.push(RemovedCertificate {
+                certificate_id,
+                height: certificate.height,
+                status,
+                pending: true,
+                settlement_job_id: None,
+            });
+        }
+
+        for certificate in &mut removed {
+            certificate.settlement_job_id = self
+                .state
+                .get_certificate_settlement_job_id(&certificate.certificate_id)?;
+        }
+        removed.sort_by_key(|certificate| certificate.height);
+
+        Ok(removed)
+    }
+
+    /// Refuse to cancel jobs settled by an open settlement batch.
+    ///
+    /// The batch transaction settles the certificates of other networks too,
+    /// so the batch can't be cancelled along with the jobs, and it would
+    /// record its result onto them should it land.
+    fn check_no_open_batch(
+        &self,
+        network_id: NetworkId,
+        cancelled_settlement_jobs: &[SettlementJobId],
+    ) -> Result<(), NetworkRollbackError> {
+        if cancelled_settlement_jobs.is_empty() {
+            return Ok(());
+        }
+
+        for batch_job_id in self.state.list_settlement_job_ids()? {
+            let Some(batch_job) = self.state.get_settlement_job(&batch_job_id)? else {
+                continue;
+            };
+            let Some(&settlement_job_id) = batch_job
+                .batched_jobs
+                .iter()
+                .find(|job_id| cancelled_settlement_jobs.contains(job_id))
+            else {
+                continue;
+            };
+            if self
+                .state
+                .get_settlement_job_result(&batch_job_id)?
+                .is_none()
+                && self
+                    .state
+                    .get_settlement_job_cancellation(&batch_job_id)?
+                    .is_none()
+            {
+                return Err(NetworkRollbackError::SettlementBatchOpen {
+                    network_id,
+                    batch_job_id,
+                    settlement_job_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List the attempts of `settlement_jobs` that got broadcast, unless their
+    /// result rules out that they land on L1.
+    fn broadcast_settlement_attempts(
+        &self,
+        settlement_jobs: &[SettlementJobId],
+    ) -> Result<Vec<BroadcastSettlementAttempt>, NetworkRollbackError> {
+        let mut broadcast = Vec::new();
+
+        for &settlement_job_id in settlement_jobs {
+            let results = self
+                .state
+                .list_settlement_attempt_results(&settlement_job_id)?;
+            for (attempt_number, attempt) in
+                self.state.list_settlement_attempts(&settlement_job_id)?
+            {
+                let result = results
+                    .iter()
+                    .find(|(result_number, _)| *result_number == attempt_number)
+                    .map(|(_, result)| result);
+                if result.is_some_and(rules_out_inclusion) {
+                    continue;
+                }
+
+                broadcast.push(BroadcastSettlementAttempt {
+                    settlement_job_id,
+                    attempt_number,
+                    sender_wallet: attempt.sender_wallet,
+                    nonce: attempt.nonce.0,
+                    tx_hash: attempt.hash,
+                });
+            }
+        }
+
+        Ok(broadcast)
+    }
+}
+
+/// Whether an attempt with this result can't land on L1: it never got
+/// broadcast, or its nonce got used by another transaction.
+fn rules_out_inclusion(result: &SettlementAttemptResult) -> bool {
+    result.is_resolved_elsewhere()
+        || matches!(
+            result,
+            SettlementAttemptResult::ClientError(ClientError {
+                kind: ClientErrorType::SimulatedRevert,
+                ..
+            })
+        )
+}
+
+impl<StateStore, PendingStore, EpochsStore> RollbackNetwork
+    for NetworkRollbackService<StateStore, PendingStore, EpochsStore>
+where
+    StateStore: StateReader + StateWriter + SettlementReader,
+    PendingStore: PendingCertificateReader + PendingCertificateWriter,
+    EpochsStore: EpochStoreReader,
+{
+    #[instrument(skip(self))]
+    fn plan_rollback(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<NetworkRollback, NetworkRollbackError> {
+        let settled_height = self
+            .state
+            .get_latest_settled_certificate_per_network(&network_id)?
+            .map(|(_, SettledCertificate(_, height, _, _))| height);
+        let Some(settled_height) = settled_height.filter(|settled| height <= *settled) else {
+            return Err(NetworkRollbackError::HeightNotSettled {
+                network_id,
+                height,
+                settled: settled_height,
+            });
+        };
+
+        let (settled, state, leaves) = self.rebuild(network_id, height)?;
+        let removed_certificates = self.removed_certificates(network_id, height, settled_height)?;
+
+        let mut cancelled_settlement_jobs = Vec::new();
+        for job_id in removed_certificates
+            .iter()
+            .filter_map(|certificate| certificate.settlement_job_id)
+        {
+            if self.state.get_settlement_job_result(&job_id)?.is_none() {
+                cancelled_settlement_jobs.push(job_id);
+            }
+        }
+        self.check_no_open_batch(network_id, &cancelled_settlement_jobs)?;
+
+        let broadcast_settlement_attempts =
+            self.broadcast_settlement_attempts(&cancelled_settlement_jobs)?;
+
+        let current_state = match self.state.read_local_network_state(network_id) {
+            Ok(state) => state.as_ref().map(StateRoots::from),
+            Err(error) => {
+                warn!(%network_id, ?error, "Unable to read the current network state");
+                None
+            }
+        };
+
+        let mut plan = NetworkRollbackPlan {
+            network_id,
+            settled_height,
+            target_height: height,
+            target_certificate_id: settled.0,
+            current_state,
+            restored_state: StateRoots::from(&state),
+            removed_certificates,
+            cancelled_settlement_jobs,
+            broadcast_settlement_attempts,
+            network_disabled: self.state.is_network_disabled(&network_id)?,
+            plan_hash: Digest::ZERO,
+        };
+        plan.plan_hash = plan.compute_hash();
+
+        Ok(NetworkRollback {
+            plan,
+            settled,
+            state,
+            leaves,
+        })
+    }
+
+    /// The state store is rolled back before the pending store. Should the
+    /// latter fail, rolling back again to the same height completes the
+    /// rollback.
+    #[instrument(skip_all, fields(network_id = %rollback.plan.network_id))]
+    fn apply_rollback(
+        &self,
+        rollback: &NetworkRollback,
+    ) -> Result<NetworkRollbackPlan, NetworkRollbackError> {
+        let plan = &rollback.plan;
+        let network_id = plan.network_id;
+        if !self.state.is_network_disabled(&network_id)? {
+            return Err(NetworkRollbackError::NetworkNotDisabled(network_id));
+        }
+
+        let removed: Vec<CertificateId> = plan
+            .removed_certificates
+            .iter()
+            .map(|certificate| certificate.certificate_id)
+            .collect();
+
+        self.state.rollback_network_state(
+            &network_id,
+            &rollback.settled,
+            &rollback.state,
+            &rollback.leaves,
+            &removed,
+            &plan.cancelled_settlement_jobs,
+        )?;
+        self.pending_store.rollback_pending_certificates(
+            &network_id,
+            &plan.target_height,
+            &plan.target_certificate_id,
+            &removed,
+        )?;
+
+        info!(
+            %network_id,
+            target_height = %plan.target_height,
+            removed_certificates = removed.len(),
+            "Network rolled back"
+        );
+
+        // The cancelled jobs won't be resumed: list what they broadcast until
+        // their tasks got aborted, as it may still land.
+        let mut applied = plan.clone();
+        match self.broadcast_settlement_attempts(&plan.cancelled_settlement_jobs) {
+            Ok(attempts) => applied.broadcast_settlement_attempts = attempts,
+            Err(error) => warn!(
+                %network_id,
+                ?error,
+                "Unable to list the broadcast attempts of the cancelled settlement jobs"
+            ),
+        }
+        for attempt in &applied.broadcast_settlement_attempts {
+            warn!(
+                %network_id,
+                settlement_job_id = %attempt.settlement_job_id,
+                tx_hash = %attempt.tx_hash,
+                "Attempt of a cancelled settlement job may still land on L1"
+            );
+        }
+
+        Ok(applied)
+    }
+}
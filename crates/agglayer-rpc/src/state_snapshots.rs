@@ -0,0 +1,95 @@
+//! Backfill of the snapshots of the settled network states.
+//!
+//! A snapshot of the state of a network is recorded every time one of its
+//! certificates settles, so the heights settled before the snapshots got
+//! introduced have none. They are rebuilt once here, by replaying the settled
+//! certificates in order, so that the settled states at a given height are
+//! only ever read back from storage when serving requests.
+
+use std::collections::BTreeSet;
+
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    stores::{EpochStoreReader, StateReader, StateWriter},
+};
+use agglayer_tries::roots::LocalExitRoot;
+use agglayer_types::{Height, LocalNetworkStateData, NetworkId};
+use tracing::{info, instrument, warn};
+
+use crate::{error::StorageError, state_verification::read_settled_certificate};
+
+/// Record the missing snapshots of the settled states of every network.
+///
+/// The networks whose certificates can't be replayed are reported and
+/// skipped. Returns the number of snapshots written.
+pub fn backfill_state_snapshots(
+    state: &(impl StateReader + StateWriter),
+    epochs_store: &impl EpochStoreReader,
+) -> Result<usize, StorageError> {
+    let mut written = 0;
+    for (network_id, SettledCertificate(_, settled_height, _, _)) in
+        state.get_current_settled_height()?
+    {
+        written += backfill_network(state, epochs_store, network_id, settled_height)?;
+    }
+
+    Ok(written)
+}
+
+/// Record the missing snapshots of the settled states of a network, up to
+/// `settled_height`.
+#[instrument(skip(state, epochs_store))]
+fn backfill_network(
+    state: &(impl StateReader + StateWriter),
+    epochs_store: &impl EpochStoreReader,
+    network_id: NetworkId,
+    settled_height: Height,
+) -> Result<usize, StorageError> {
+    let mut missing = BTreeSet::new();
+    for height in (0..=settled_height.as_u64()).map(Height::new) {
+        if !state.has_network_state_snapshot(network_id, height)? {
+            missing.insert(height);
+        }
+    }
+    let Some(&last_missing) = missing.last() else {
+        return Ok(0);
+    };
+
+    let mut local_state = LocalNetworkStateData::default();
+    let mut written = 0;
+    for height in (0..=last_missing.as_u64()).map(Height::new) {
+        let reason = match read_settled_certificate(state, epochs_store, network_id, height)? {
+            Ok((header, certificate)) => match local_state.replay_certificate(&certificate) {
+                Ok(())
+                    if LocalExitRoot::from(local_state.exit_tree.get_root())
+                        != header.new_local_exit_root =>
+                {
+                    Some("Local exit root mismatch".to_string())
+                }
+                Ok(()) => None,
+                Err(error) => Some(error.to_string()),
+            },
+            Err(reason) => Some(reason),
+        };
+        if let Some(reason) = reason {
+            warn!(
+                %network_id,
+                %height,
+                reason,
+                "Unable to replay the settled state, its missing snapshots are not recorded"
+            );
+            break;
+        }
+
+        if missing.contains(&height) {
+            state.write_network_state_snapshot(&network_id, &height, &local_state)?;
+            written += 1;
+        }
+    }
+
+    if written > 0 {
+        info!(%network_id, written, "Recorded the missing snapshots of the settled states");
+    }
+
+    Ok(written)
+}
@@ -0,0 +1,393 @@
+//! Consistency check of the persisted local network states.
+//!
+//! The state of a network is rebuilt from scratch by replaying its settled
+//! certificates in order. Along the way, the pessimistic root of the rebuilt
+//! state is checked against the one settled on L1, and the rebuilt trees are
+//! finally compared with the persisted ones.
+
+use std::sync::Arc;
+
+use agglayer_contracts::RollupContract;
+use agglayer_primitives::Hashable;
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    stores::{EpochStoreReader, StateReader},
+};
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateStatus, Digest, Height, LocalNetworkStateData,
+    NetworkId,
+};
+use pessimistic_proof::core::commitment::{
+    PessimisticRootCommitmentValues, PessimisticRootCommitmentVersion,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument, warn};
+
+use crate::error::{StateVerificationError, StorageError};
+
+/// Outcome of the verification of the state of one network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStateVerification {
+    pub network_id: NetworkId,
+    /// The height of the latest settled certificate, if any.
+    pub settled_height: Option<Height>,
+    /// The number of certificates replayed before the first divergence.
+    pub replayed_certificates: u64,
+    /// Whether the pessimistic roots were checked against L1.
+    pub l1_checked: bool,
+    /// The first divergence found, if any.
+    pub divergence: Option<StateDivergence>,
+}
+
+impl NetworkStateVerification {
+    /// Whether the persisted state matches the rebuilt one.
+    pub fn is_consistent(&self) -> bool {
+        self.divergence.is_none()
+    }
+}
+
+/// First point at which the rebuilt state departs from the persisted one, or
+/// from L1.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum StateDivergence {
+    /// The settled certificate at this height can't be read, so the state
+    /// can't be rebuilt any further.
+    MissingCertificate { height: Height, reason: String },
+
+    /// The settled certificate at this height doesn't apply on the rebuilt
+    /// state.
+    Replay {
+        height: Height,
+        error: agglayer_types::Error,
+    },
+
+    /// The pessimistic root settled on L1 before the certificate at this
+    /// height doesn't commit to the rebuilt state.
+    PessimisticRoot {
+        height: Height,
+        l1: Digest,
+        rebuilt_v2: Digest,
+        rebuilt_v3: Digest,
+    },
+
+    /// The persisted trees don't match the state rebuilt up to the settled
+    /// height.
+    PersistedState {
+        persisted: StateRoots,
+        rebuilt: StateRoots,
+    },
+}
+
+/// Roots of the trees making up a local network state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateRoots {
+    pub local_exit_root: Digest,
+    pub leaf_count: u32,
+    pub balance_root: Digest,
+    pub nullifier_root: Digest,
+}
+
+impl From<&LocalNetworkStateData> for StateRoots {
+    fn from(state: &LocalNetworkStateData) -> Self {
+        Self {
+            local_exit_root: state.exit_tree.get_root(),
+            leaf_count: state.exit_tree.leaf_count(),
+            balance_root: state.balance_tree.root,
+            nullifier_root: state.nullifier_tree.root,
+        }
+    }
+}
+
+/// Verification of the persisted network states, whatever the stores and the
+/// L1 client backing it.
+#[async_trait::async_trait]
+pub trait VerifyState: Send + Sync {
+    /// Verify the state of one network, or of every settled network if none
+    /// is given.
+    async fn verify_state(
+        &self,
+        network_id: Option<NetworkId>,
+    ) -> Result<Vec<NetworkStateVerification>, StateVerificationError>;
+}
+
+/// Rebuilds the network states from their settled certificates and compares
+/// them with the persisted states and with L1.
+pub struct StateVerifier<StateStore, EpochsStore, L1Rpc> {
+    state: Arc<StateStore>,
+    epochs_store: Arc<EpochsStore>,
+    /// The pessimistic roots aren't checked without an L1 client.
+    l1_rpc: Option<Arc<L1Rpc>>,
+}
+
+impl<StateStore, EpochsStore, L1Rpc> StateVerifier<StateStore, EpochsStore, L1Rpc> {
+    pub fn new(
+        state: Arc<StateStore>,
+        epochs_store: Arc<EpochsStore>,
+        l1_rpc: Option<Arc<L1Rpc>>,
+    ) -> Self {
+        Self {
+            state,
+            epochs_store,
+            l1_rpc,
+        }
+    }
+}
+
+impl<StateStore, EpochsStore, L1Rpc> StateVerifier<StateStore, EpochsStore, L1Rpc>
+where
+    StateStore: StateReader,
+    EpochsStore: EpochStoreReader,
+    L1Rpc: RollupContract + Send + Sync,
+{
+    /// Verify the state of every network having settled a certificate.
+    pub async fn verify_all(
+        &self,
+    ) -> Result<Vec<NetworkStateVerification>, StateVerificationError> {
+        let mut reports = Vec::new();
+        for (network_id, _) in self.state.get_current_settled_height()? {
+            reports.push(self.verify_network(network_id).await?);
+        }
+
+        Ok(reports)
+    }
+
+    /// Rebuild the state of a network by replaying its settled certificates,
+    /// reporting the first divergence found.
+    ///
+    /// The latest pessimistic root on L1 may briefly be ahead of the persisted
+    /// state while a settlement is being recorded, which is reported as a
+    /// divergence at the height following the settled one.
+    #[instrument(skip(self))]
+    pub async fn verify_network(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<NetworkStateVerification, StateVerificationError> {
+        let settled_height = self
+            .state
+            .get_latest_settled_certificate_per_network(&network_id)?
+            .map(|(_, SettledCertificate(_, height, _, _))| height);
+
+        let mut report = NetworkStateVerification {
+            network_id,
+            settled_height,
+            replayed_certificates: 0,
+            l1_checked: self.l1_rpc.is_some(),
+            divergence: None,
+        };
+        report.divergence = self
+            .find_divergence(
+                network_id,
+                settled_height,
+                &mut report.replayed_certificates,
+            )
+            .await?;
+
+        match &report.divergence {
+            None => info!(%network_id, ?settled_height, "Network state is consistent"),
+            Some(divergence) => warn!(%network_id, ?divergence, "Network state diverges"),
+        }
+
+        Ok(report)
+    }
+
+    async fn find_divergence(
+        &self,
+        network_id: NetworkId,
+        settled_height: Option<Height>,
+        replayed_certificates: &mut u64,
+    ) -> Result<Option<StateDivergence>, StateVerificationError> {
+        let mut state = LocalNetworkStateData::default();
+
+        let heights = settled_height.map_or(0, |height| height.as_u64() + 1);
+        for height in (0..heights).map(Height::new) {
+            let (header, certificate) = match read_settled_certificate(
+                &*self.state,
+                &*self.epochs_store,
+                network_id,
+                height,
+            )? {
+                Ok(settled) => settled,
+                Err(reason) => {
+                    return Ok(Some(StateDivergence::MissingCertificate { height, reason }))
+                }
+            };
+
+            // The root settled right before this certificate commits to the
+            // state it applies on.
+            if let Some(tx_hash) = header.settlement_tx_hash {
+                let divergence = self
+                    .check_pessimistic_root(network_id, height, &state, Some(tx_hash.into()))
+                    .await?;
+                if divergence.is_some() {
+                    return Ok(divergence);
+                }
+            }
+
+            if let Err(error) = state.replay_certificate(&certificate) {
+                return Ok(Some(StateDivergence::Replay { height, error }));
+            }
+            *replayed_certificates += 1;
+        }
+
+        let persisted = self
+            .state
+            .read_local_network_state(network_id)?
+            .unwrap_or_default();
+        let (persisted, rebuilt) = (StateRoots::from(&persisted), StateRoots::from(&state));
+        if persisted != rebuilt {
+            return Ok(Some(StateDivergence::PersistedState { persisted, rebuilt }));
+        }
+
+        let next_height = settled_height.map_or(Height::ZERO, |height| height.next());
+        self.check_pessimistic_root(network_id, next_height, &state, None)
+            .await
+    }
+
+    /// Check that the pessimistic root settled on L1, before the given
+    /// transaction or at the latest block, commits to the state on which the
+    /// certificate at `height` applies.
+    async fn check_pessimistic_root(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+        state: &LocalNetworkStateData,
+        before_tx: Option<alloy::primitives::TxHash>,
+    ) -> Result<Option<StateDivergence>, StateVerificationError> {
+        let Some(l1_rpc) = &self.l1_rpc else {
+            return Ok(None);
+        };
+
+        let l1 = l1_rpc
+            .get_prev_pessimistic_root(network_id.to_u32(), before_tx)
+            .await
+            .map_err(|source| StateVerificationError::L1 { network_id, source })?;
+        let l1 = Digest(l1);
+
+        let values = PessimisticRootCommitmentValues {
+            balance_root: state.balance_tree.root.into(),
+            nullifier_root: state.nullifier_tree.root.into(),
+            ler_leaf_count: state.exit_tree.leaf_count(),
+            height: height.as_u64(),
+            origin_network: network_id,
+        };
+        if values.infer_settled_pp_root_version(l1).is_ok() {
+            return Ok(None);
+        }
+
+        Ok(Some(StateDivergence::PessimisticRoot {
+            height,
+            l1,
+            rebuilt_v2: values.compute_pp_root(PessimisticRootCommitmentVersion::V2),
+            rebuilt_v3: values.compute_pp_root(PessimisticRootCommitmentVersion::V3),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl<StateStore, EpochsStore, L1Rpc> VerifyState for StateVerifier<StateStore, EpochsStore, L1Rpc>
+where
+    StateStore: StateReader,
+    EpochsStore: EpochStoreReader,
+    L1Rpc: RollupContract + Send + Sync,
+{
+    async fn verify_state(
+        &self,
+        network_id: Option<NetworkId>,
+    ) -> Result<Vec<NetworkStateVerification>, StateVerificationError> {
+        match network_id {
+            Some(network_id) => Ok(vec![self.verify_network(network_id).await?]),
+            None => self.verify_all().await,
+        }
+    }
+}
+
+/// Read the settled certificate at the given height along with its
+/// header, or the reason why it can't be read.
+pub(crate) fn read_settled_certificate(
+    state: &impl StateReader,
+    epochs_store: &impl EpochStoreReader,
+    network_id: NetworkId,
+    height: Height,
+) -> Result<Result<(CertificateHeader, Certificate), String>, StorageError> {
+    let Some(header) = state.get_certificate_header_by_cursor(network_id, height)? else {
+        return Ok(Err("No certificate header".to_string()));
+    };
+
+    if header.status != CertificateStatus::Settled {
+        return Ok(Err(format!(
+            "Certificate {} is {}",
+            header.certificate_id, header.status
+        )));
+    }
+
+    let (Some(epoch_number), Some(certificate_index)) =
+        (header.epoch_number, header.certificate_index)
+    else {
+        return Ok(Err(format!(
+            "Certificate {} has no epoch",
+            header.certificate_id
+        )));
+    };
+
+    let Some(certificate) = epochs_store.get_certificate(epoch_number, certificate_index)? else {
+        return Ok(Err(format!(
+            "Certificate {} is missing from epoch {epoch_number} at index {certificate_index}",
+            header.certificate_id
+        )));
+    };
+
+    if certificate.hash() != header.certificate_id {
+        return Ok(Err(format!(
+            "Epoch {epoch_number} holds certificate {} at index {certificate_index} instead of {}",
+            certificate.hash(),
+            header.certificate_id
+        )));
+    }
+
+    Ok(Ok((header, certificate)))
+}
+
+/// Rebuild the settled state of a network at the given height by replaying
+/// its settled certificates, along with the leaves of its exit tree.
+///
+/// Returns the header of the certificate settled at `height`, or the height
+/// at which the replay stopped and why.
+pub(crate) fn replay_settled_state(
+    state: &impl StateReader,
+    epochs_store: &impl EpochStoreReader,
+    network_id: NetworkId,
+    height: Height,
+) -> Result<
+    Result<(CertificateHeader, LocalNetworkStateData, Vec<Digest>), (Height, String)>,
+    StorageError,
+> {
+    let mut local_state = LocalNetworkStateData::default();
+    let mut leaves = Vec::new();
+    let mut settled = None;
+
+    for replayed in (0..=height.as_u64()).map(Height::new) {
+        let (header, certificate) =
+            match read_settled_certificate(state, epochs_store, network_id, replayed)? {
+                Ok(settled) => settled,
+                Err(reason) => return Ok(Err((replayed, reason))),
+            };
+
+        if let Err(error) = local_state.replay_certificate(&certificate) {
+            return Ok(Err((replayed, error.to_string())));
+        }
+        leaves.extend(certificate.bridge_exits.iter().map(|exit| exit.hash()));
+        settled = Some(header);
+    }
+
+    match settled {
+        Some(header) => Ok(Ok((header, local_state, leaves))),
+        None => Ok(Err((height, "No settled certificate".to_string()))),
+    }
+}
@@ -31,6 +31,7 @@ fn job_result(outcome: ContractCallOutcome) -> SettlementJobResult {
             block_hash: B256::ZERO,
             block_number: 0,
             tx_hash: SettlementTxHash::new(Digest([9u8; 32])),
+            gas_cost: None,
         },
     }
 }
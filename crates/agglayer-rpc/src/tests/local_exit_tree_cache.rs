@@ -0,0 +1,90 @@
+use agglayer_storage::tests::mocks::MockStateStore;
+use agglayer_types::{Digest, NetworkId};
+use mockall::predicate::eq;
+use pessimistic_proof::local_exit_tree::data::LocalExitTreeData;
+
+use crate::{local_exit_tree_cache::LocalExitTreeCache, StateProofError};
+
+const NETWORK_1: NetworkId = NetworkId::new(1);
+
+fn leaves(bytes: &[u8]) -> Vec<Digest> {
+    bytes.iter().map(|byte| Digest([*byte; 32])).collect()
+}
+
+fn root(leaves: &[Digest]) -> Digest {
+    LocalExitTreeData::<32>::from_leaves(leaves.iter().copied())
+        .unwrap()
+        .get_root()
+}
+
+#[test]
+fn cached_tree_is_extended_and_serves_previous_heights() {
+    let settled = leaves(&[1, 2, 3, 4]);
+    let mut state_store = MockStateStore::new();
+    let first = settled[..3].to_vec();
+    state_store
+        .expect_read_local_exit_tree_leaves()
+        .with(eq(NETWORK_1), eq(0..3))
+        .once()
+        .returning(move |_, _| Ok(Some(first.clone())));
+    let next = settled[3..].to_vec();
+    state_store
+        .expect_read_local_exit_tree_leaves()
+        .with(eq(NETWORK_1), eq(3..4))
+        .once()
+        .returning(move |_, _| Ok(Some(next.clone())));
+
+    let cache = LocalExitTreeCache::default();
+    for (leaf_index, leaf_count) in [(2, 3), (0, 1), (1, 3), (3, 4), (2, 4)] {
+        let root = root(&settled[..leaf_count as usize]);
+        let (leaf, path) = cache
+            .prove(&state_store, NETWORK_1, leaf_index, leaf_count, root.into())
+            .unwrap();
+
+        assert_eq!(leaf, settled[leaf_index as usize]);
+        assert!(path.verify(leaf, leaf_index, root));
+    }
+}
+
+#[test]
+fn cached_tree_is_rebuilt_once_its_leaves_are_rewritten() {
+    let before = leaves(&[1, 2, 3]);
+    let after = leaves(&[1, 2, 9]);
+    let mut state_store = MockStateStore::new();
+    let mut reads = vec![after.clone(), before.clone()];
+    state_store
+        .expect_read_local_exit_tree_leaves()
+        .with(eq(NETWORK_1), eq(0..3))
+        .times(2)
+        .returning(move |_, _| Ok(reads.pop()));
+
+    let cache = LocalExitTreeCache::default();
+    cache
+        .prove(&state_store, NETWORK_1, 2, 3, root(&before).into())
+        .unwrap();
+
+    // The settled leaves got rewritten, e.g. by a network rollback.
+    let (leaf, path) = cache
+        .prove(&state_store, NETWORK_1, 2, 3, root(&after).into())
+        .unwrap();
+    assert_eq!(leaf, after[2]);
+    assert!(path.verify(leaf, 2, root(&after)));
+}
+
+#[test]
+fn leaves_not_matching_the_settled_root_are_unavailable() {
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_read_local_exit_tree_leaves()
+        .with(eq(NETWORK_1), eq(0..2))
+        .times(2)
+        .returning(|_, _| Ok(Some(leaves(&[1, 2]))));
+
+    let error = LocalExitTreeCache::default()
+        .prove(&state_store, NETWORK_1, 0, 2, root(&leaves(&[1, 3])).into())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        StateProofError::LeavesUnavailable { network_id } if network_id == NETWORK_1
+    ));
+}
@@ -1,2 +1,8 @@
 pub mod certificate_replacement;
+pub mod local_exit_tree_cache;
 pub mod network_info;
+pub mod network_rollback;
+pub mod settled_state;
+pub mod simulate_certificate;
+pub mod state_verification;
+pub mod submission_limits;
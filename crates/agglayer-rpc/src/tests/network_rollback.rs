@@ -0,0 +1,461 @@
+use std::{collections::HashMap, sync::Arc, time::SystemTime};
+
+use agglayer_primitives::Hashable;
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    tests::mocks::{MockEpochsStore, MockPendingStore, MockStateStore},
+};
+use agglayer_types::{
+    Address, Certificate, CertificateHeader, CertificateId, CertificateIndex, CertificateStatus,
+    ClientError, ContractCallOutcome, ContractCallResult, Digest, EpochNumber, Height,
+    LocalNetworkStateData, Metadata, NetworkId, Nonce, SettlementAttempt, SettlementAttemptNumber,
+    SettlementAttemptResult, SettlementJobId, SettlementJobResult, SettlementTxHash, B256, U256,
+};
+use mockall::predicate::{always, eq};
+use pessimistic_proof_test_suite::{forest::Forest, sample_data::USDC};
+
+use crate::{
+    network_rollback::{
+        BroadcastSettlementAttempt, NetworkRollbackService, RemovedCertificate, RollbackNetwork,
+    },
+    state_verification::StateRoots,
+    NetworkRollbackError,
+};
+
+const NETWORK: NetworkId = NetworkId::new(1);
+
+/// Settlement job of the certificate at height 2, which completed.
+fn completed_job() -> SettlementJobId {
+    SettlementJobId::from(2u128)
+}
+
+/// Settlement job of the certificate at height 3, which is still pending.
+fn pending_job() -> SettlementJobId {
+    SettlementJobId::from(3u128)
+}
+
+/// Batch job settling the pending job along with a job of another network.
+fn batch_job() -> SettlementJobId {
+    SettlementJobId::from(7u128)
+}
+
+/// Settlement batch holding the pending job.
+#[derive(Clone, Copy)]
+enum Batch {
+    Absent,
+    Open,
+    Completed,
+}
+
+/// Three settled certificates, then a proven one waiting in the pending queue.
+struct History {
+    certificates: Vec<Certificate>,
+    /// The state after each certificate.
+    states: Vec<LocalNetworkStateData>,
+}
+
+impl History {
+    fn new() -> Self {
+        let mut forest = Forest::default();
+        let mut certificates = Vec::new();
+        let mut states = Vec::new();
+
+        let events = [
+            (vec![(USDC, U256::from(100))], vec![]),
+            (vec![], vec![(USDC, U256::from(10))]),
+            (vec![], vec![(USDC, U256::from(20))]),
+            (vec![], vec![(USDC, U256::from(30))]),
+        ];
+        for (height, (imported, bridged)) in events.iter().enumerate() {
+            let mut certificate = forest.apply_events(imported, bridged);
+            certificate.height = Height::new(height as u64);
+            certificates.push(certificate);
+            states.push(forest.state_b.clone());
+        }
+
+        Self {
+            certificates,
+            states,
+        }
+    }
+
+    fn certificate_id(&self, height: u64) -> CertificateId {
+        self.certificates[height as usize].hash()
+    }
+
+    fn header(&self, height: u64) -> CertificateHeader {
+        let certificate = &self.certificates[height as usize];
+        let settled = height <= 2;
+        CertificateHeader {
+            network_id: NETWORK,
+            height: certificate.height,
+            epoch_number: settled.then_some(EpochNumber::ZERO),
+            certificate_index: settled.then(|| CertificateIndex::new(height)),
+            certificate_id: certificate.hash(),
+            prev_local_exit_root: certificate.prev_local_exit_root,
+            new_local_exit_root: certificate.new_local_exit_root,
+            metadata: Metadata::DEFAULT,
+            status: if settled {
+                CertificateStatus::Settled
+            } else {
+                CertificateStatus::Proven
+            },
+            settlement_tx_hash: None,
+        }
+    }
+
+    fn state_store(&self, disabled: bool) -> MockStateStore {
+        self.state_store_with_batch(disabled, Batch::Absent)
+    }
+
+    fn state_store_with_batch(&self, disabled: bool, batch: Batch) -> MockStateStore {
+        let mut state_store = MockStateStore::new();
+
+        let settled_id = self.certificate_id(2);
+        state_store
+            .expect_get_latest_settled_certificate_per_network()
+            .with(eq(NETWORK))
+            .returning(move |_| {
+                Ok(Some((
+                    NETWORK,
+                    SettledCertificate(
+                        settled_id,
+                        Height::new(2),
+                        EpochNumber::ZERO,
+                        CertificateIndex::new(2),
+                    ),
+                )))
+            });
+
+        let settled_headers: Vec<_> = (0..=2).map(|height| self.header(height)).collect();
+        state_store
+            .expect_get_certificate_header_by_cursor()
+            .returning(move |network_id, height| {
+                assert_eq!(network_id, NETWORK);
+                Ok(settled_headers.get(height.as_u64() as usize).cloned())
+            });
+
+        let headers: HashMap<_, _> = (0..=3)
+            .map(|height| (self.certificate_id(height), self.header(height)))
+            .collect();
+        state_store
+            .expect_get_certificate_header()
+            .returning(move |certificate_id| Ok(headers.get(certificate_id).cloned()));
+
+        let jobs = HashMap::from([
+            (self.certificate_id(2), completed_job()),
+            (self.certificate_id(3), pending_job()),
+        ]);
+        state_store
+            .expect_get_certificate_settlement_job_id()
+            .returning(move |certificate_id| Ok(jobs.get(certificate_id).copied()));
+        state_store
+            .expect_get_settlement_job_result()
+            .returning(move |job_id| {
+                let completed = *job_id == completed_job()
+                    || (*job_id == batch_job() && matches!(batch, Batch::Completed));
+                Ok(completed.then(job_result))
+            });
+        state_store
+            .expect_list_settlement_job_ids()
+            .returning(move || {
+                let mut job_ids = vec![completed_job(), pending_job()];
+                if !matches!(batch, Batch::Absent) {
+                    job_ids.push(batch_job());
+                }
+                Ok(job_ids)
+            });
+        state_store.expect_get_settlement_job().returning(|job_id| {
+            let batched_jobs = if *job_id == batch_job() {
+                vec![SettlementJobId::from(8u128), pending_job()]
+            } else {
+                Vec::new()
+            };
+            Ok(Some(SettlementJob {
+                contract_address: Address::new([2u8; 20]),
+                calldata: Default::default(),
+                eth_value: U256::ZERO,
+                gas_limit: 0,
+                network_id: None,
+                tx_type: SettlementTxType::Eip1559,
+                access_list: None,
+                batched_jobs,
+            }))
+        });
+        state_store
+            .expect_get_settlement_job_cancellation()
+            .returning(|_| Ok(None));
+        state_store
+            .expect_list_settlement_attempts()
+            .with(eq(pending_job()))
+            .returning(|_| Ok(vec![(0, attempt(0)), (1, attempt(1))]));
+        state_store
+            .expect_list_settlement_attempt_results()
+            .with(eq(pending_job()))
+            .returning(|_| {
+                Ok(vec![(
+                    0,
+                    SettlementAttemptResult::ClientError(ClientError::simulated_revert(
+                        "execution reverted",
+                    )),
+                )])
+            });
+
+        let persisted = self.states[3].clone();
+        state_store
+            .expect_read_local_network_state()
+            .with(eq(NETWORK))
+            .returning(move |_| Ok(Some(persisted.clone())));
+        state_store
+            .expect_is_network_disabled()
+            .with(eq(NETWORK))
+            .returning(move |_| Ok(disabled));
+
+        state_store
+    }
+
+    fn pending_store(&self) -> MockPendingStore {
+        let mut pending_store = MockPendingStore::new();
+        let pending = self.certificates[3].clone();
+        pending_store
+            .expect_list_pending_certificates_for_network()
+            .with(eq(NETWORK), always())
+            .returning(move |_, _| Ok(vec![pending.clone()]));
+
+        pending_store
+    }
+
+    fn epochs_store(&self) -> MockEpochsStore {
+        let certificates = self.certificates.clone();
+        let mut epochs_store = MockEpochsStore::new();
+        epochs_store
+            .expect_get_certificate()
+            .returning(move |epoch_number, index| {
+                assert_eq!(epoch_number, EpochNumber::ZERO);
+                Ok(certificates.get(index.as_u64() as usize).cloned())
+            });
+
+        epochs_store
+    }
+}
+
+/// An attempt of the pending settlement job. The first one got refused by
+/// its pre-flight simulation, the second one got broadcast.
+fn attempt(nonce: u64) -> SettlementAttempt {
+    SettlementAttempt {
+        sender_wallet: Address::new([1u8; 20]),
+        nonce: Nonce(nonce),
+        hash: SettlementTxHash::new(Digest([nonce as u8; 32])),
+        submission_time: SystemTime::UNIX_EPOCH,
+        max_fee_per_gas: 0,
+        max_priority_fee_per_gas: 0,
+        gas_oracle_inputs: None,
+    }
+}
+
+fn job_result() -> SettlementJobResult {
+    SettlementJobResult {
+        wallet: Address::new([0u8; 20]),
+        nonce: Nonce(0),
+        attempt_number: SettlementAttemptNumber(0),
+        contract_call_result: ContractCallResult {
+            outcome: ContractCallOutcome::Success,
+            metadata: Default::default(),
+            block_hash: B256::ZERO,
+            block_number: 0,
+            tx_hash: SettlementTxHash::new(Digest([9u8; 32])),
+            gas_cost: None,
+        },
+    }
+}
+
+fn service(
+    state_store: MockStateStore,
+    pending_store: MockPendingStore,
+    epochs_store: MockEpochsStore,
+) -> NetworkRollbackService<MockStateStore, MockPendingStore, MockEpochsStore> {
+    NetworkRollbackService::new(
+        Arc::new(state_store),
+        Arc::new(pending_store),
+        Arc::new(epochs_store),
+    )
+}
+
+#[test]
+fn plan_rebuilds_the_state_at_the_target_height() {
+    let history = History::new();
+    let service = service(
+        history.state_store(false),
+        history.pending_store(),
+        history.epochs_store(),
+    );
+
+    let plan = service.plan_rollback(NETWORK, Height::new(1)).unwrap().plan;
+
+    assert_eq!(plan.settled_height, Height::new(2));
+    assert_eq!(plan.target_height, Height::new(1));
+    assert_eq!(plan.target_certificate_id, history.certificate_id(1));
+    assert_eq!(
+        plan.current_state,
+        Some(StateRoots::from(&history.states[3]))
+    );
+    assert_eq!(plan.restored_state, StateRoots::from(&history.states[1]));
+    assert_eq!(
+        plan.removed_certificates,
+        vec![
+            RemovedCertificate {
+                certificate_id: history.certificate_id(2),
+                height: Height::new(2),
+                status: Some(CertificateStatus::Settled),
+                pending: false,
+                settlement_job_id: Some(completed_job()),
+            },
+            RemovedCertificate {
+                certificate_id: history.certificate_id(3),
+                height: Height::new(3),
+                status: Some(CertificateStatus::Proven),
+                pending: true,
+                settlement_job_id: Some(pending_job()),
+            },
+        ]
+    );
+    assert_eq!(plan.cancelled_settlement_jobs, vec![pending_job()]);
+    assert_eq!(
+        plan.broadcast_settlement_attempts,
+        vec![BroadcastSettlementAttempt {
+            settlement_job_id: pending_job(),
+            attempt_number: 1,
+            sender_wallet: Address::new([1u8; 20]),
+            nonce: 1,
+            tx_hash: SettlementTxHash::new(Digest([1u8; 32])),
+        }]
+    );
+    assert!(!plan.network_disabled);
+
+    let replanned = service.plan_rollback(NETWORK, Height::new(1)).unwrap().plan;
+    assert_eq!(replanned.plan_hash, plan.plan_hash);
+    let other = service.plan_rollback(NETWORK, Height::new(0)).unwrap().plan;
+    assert_ne!(other.plan_hash, plan.plan_hash);
+}
+
+#[test]
+fn plan_refuses_heights_above_the_settled_one() {
+    let history = History::new();
+    let service = service(
+        history.state_store(true),
+        MockPendingStore::new(),
+        MockEpochsStore::new(),
+    );
+
+    let error = service.plan_rollback(NETWORK, Height::new(3)).unwrap_err();
+
+    assert!(matches!(
+        error,
+        NetworkRollbackError::HeightNotSettled { network_id, settled, .. }
+            if network_id == NETWORK && settled == Some(Height::new(2))
+    ));
+}
+
+#[test]
+fn apply_requires_a_disabled_network() {
+    let history = History::new();
+    let service = service(
+        history.state_store(false),
+        history.pending_store(),
+        history.epochs_store(),
+    );
+
+    let rollback = service.plan_rollback(NETWORK, Height::new(1)).unwrap();
+    let error = service.apply_rollback(&rollback).unwrap_err();
+
+    assert!(matches!(
+        error,
+        NetworkRollbackError::NetworkNotDisabled(network_id) if network_id == NETWORK
+    ));
+}
+
+#[test]
+fn apply_rolls_back_the_state_and_pending_stores() {
+    let history = History::new();
+    let removed = vec![history.certificate_id(2), history.certificate_id(3)];
+    let leaves: Vec<Digest> = history.certificates[..=1]
+        .iter()
+        .flat_map(|certificate| certificate.bridge_exits.iter().map(|exit| exit.hash()))
+        .collect();
+    let restored = StateRoots::from(&history.states[1]);
+
+    let mut state_store = history.state_store(true);
+    let target_id = history.certificate_id(1);
+    let expected_removed = removed.clone();
+    state_store
+        .expect_rollback_network_state()
+        .once()
+        .withf(
+            move |network_id, settled, state, written_leaves, removed, cancelled| {
+                *network_id == NETWORK
+                    && *settled
+                        == SettledCertificate(
+                            target_id,
+                            Height::new(1),
+                            EpochNumber::ZERO,
+                            CertificateIndex::new(1),
+                        )
+                    && StateRoots::from(state) == restored
+                    && written_leaves == leaves.as_slice()
+                    && removed == expected_removed.as_slice()
+                    && cancelled == [pending_job()].as_slice()
+            },
+        )
+        .returning(|_, _, _, _, _, _| Ok(()));
+
+    let mut pending_store = history.pending_store();
+    pending_store
+        .expect_rollback_pending_certificates()
+        .once()
+        .withf(move |network_id, height, certificate_id, removed_proofs| {
+            *network_id == NETWORK
+                && *height == Height::new(1)
+                && *certificate_id == target_id
+                && removed_proofs == removed.as_slice()
+        })
+        .returning(|_, _, _, _| Ok(()));
+
+    let service = service(state_store, pending_store, history.epochs_store());
+    let rollback = service.plan_rollback(NETWORK, Height::new(1)).unwrap();
+    assert!(rollback.plan.network_disabled);
+
+    let applied = service.apply_rollback(&rollback).unwrap();
+    assert_eq!(applied.plan_hash, rollback.plan.plan_hash);
+    assert_eq!(applied.broadcast_settlement_attempts.len(), 1);
+}
+
+#[test]
+fn plan_refuses_jobs_held_by_an_open_batch() {
+    let history = History::new();
+    let open = service(
+        history.state_store_with_batch(true, Batch::Open),
+        history.pending_store(),
+        history.epochs_store(),
+    );
+
+    let error = open.plan_rollback(NETWORK, Height::new(1)).unwrap_err();
+
+    assert!(matches!(
+        error,
+        NetworkRollbackError::SettlementBatchOpen { network_id, batch_job_id, settlement_job_id }
+            if network_id == NETWORK
+                && batch_job_id == batch_job()
+                && settlement_job_id == pending_job()
+    ));
+
+    let completed = service(
+        history.state_store_with_batch(true, Batch::Completed),
+        history.pending_store(),
+        history.epochs_store(),
+    );
+    let plan = completed
+        .plan_rollback(NETWORK, Height::new(1))
+        .unwrap()
+        .plan;
+    assert_eq!(plan.cancelled_settlement_jobs, vec![pending_job()]);
+}
@@ -0,0 +1,241 @@
+use std::{collections::HashMap, sync::Arc};
+
+use agglayer_config::Config;
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    tests::mocks::{MockDebugStore, MockEpochsStore, MockPendingStore, MockStateStore},
+};
+use agglayer_tries::roots::LocalExitRoot;
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateIndex, CertificateStatus, EpochNumber, Height,
+    LocalNetworkStateData, Metadata, NetworkId, SettledStateAnchor, U256,
+};
+use alloy::providers::{mock::Asserter, ProviderBuilder};
+use mockall::predicate::eq;
+use pessimistic_proof_test_suite::{forest::Forest, sample_data::USDC};
+
+use crate::{
+    state_snapshots::backfill_state_snapshots, state_verification::StateRoots, StateProofError,
+};
+
+const NETWORK: NetworkId = NetworkId::new(1);
+
+/// Two settled certificates, the first one settled before the states got
+/// recorded.
+struct History {
+    certificates: Vec<Certificate>,
+    /// The state after each certificate.
+    states: Vec<LocalNetworkStateData>,
+}
+
+impl History {
+    fn new() -> Self {
+        let mut forest = Forest::default();
+        let mut certificates = Vec::new();
+        let mut states = Vec::new();
+
+        let events = [
+            (vec![(USDC, U256::from(100))], vec![(USDC, U256::from(10))]),
+            (vec![], vec![(USDC, U256::from(20))]),
+        ];
+        for (height, (imported, bridged)) in events.iter().enumerate() {
+            let mut certificate = forest.apply_events(imported, bridged);
+            certificate.height = Height::new(height as u64);
+            certificates.push(certificate);
+            states.push(forest.state_b.clone());
+        }
+
+        Self {
+            certificates,
+            states,
+        }
+    }
+
+    fn header(&self, height: u64) -> CertificateHeader {
+        let certificate = &self.certificates[height as usize];
+        CertificateHeader {
+            network_id: NETWORK,
+            height: certificate.height,
+            epoch_number: Some(EpochNumber::ZERO),
+            certificate_index: Some(CertificateIndex::new(height)),
+            certificate_id: certificate.hash(),
+            prev_local_exit_root: certificate.prev_local_exit_root,
+            new_local_exit_root: certificate.new_local_exit_root,
+            metadata: Metadata::DEFAULT,
+            status: CertificateStatus::Settled,
+            settlement_tx_hash: None,
+        }
+    }
+
+    /// Only the state at the latest settled height has a snapshot.
+    fn state_store(&self) -> MockStateStore {
+        let mut state_store = MockStateStore::new();
+
+        let settled = self.header(1);
+        state_store
+            .expect_get_latest_settled_certificate_per_network()
+            .with(eq(NETWORK))
+            .returning(move |_| {
+                Ok(Some((
+                    NETWORK,
+                    SettledCertificate(
+                        settled.certificate_id,
+                        settled.height,
+                        EpochNumber::ZERO,
+                        CertificateIndex::new(1),
+                    ),
+                )))
+            });
+
+        let headers: Vec<_> = (0..=1).map(|height| self.header(height)).collect();
+        let cursor_headers = headers.clone();
+        state_store
+            .expect_get_certificate_header_by_cursor()
+            .returning(move |network_id, height| {
+                assert_eq!(network_id, NETWORK);
+                Ok(cursor_headers.get(height.as_u64() as usize).cloned())
+            });
+        let headers: HashMap<_, _> = headers
+            .into_iter()
+            .map(|header| (header.certificate_id, header))
+            .collect();
+        state_store
+            .expect_get_certificate_header()
+            .returning(move |certificate_id| Ok(headers.get(certificate_id).cloned()));
+
+        let snapshot = self.states[1].clone();
+        state_store
+            .expect_read_local_network_state_at()
+            .returning(move |network_id, height| {
+                assert_eq!(network_id, NETWORK);
+                Ok((height == Height::new(1)).then(|| snapshot.clone()))
+            });
+
+        state_store
+    }
+
+    /// The state store as seen by the backfill of the snapshots.
+    fn backfill_state_store(&self) -> MockStateStore {
+        let mut state_store = self.state_store();
+
+        let settled = SettledCertificate(
+            self.certificates[1].hash(),
+            Height::new(1),
+            EpochNumber::ZERO,
+            CertificateIndex::new(1),
+        );
+        state_store
+            .expect_get_current_settled_height()
+            .returning(move || Ok(vec![(NETWORK, settled.clone())]));
+        state_store
+            .expect_has_network_state_snapshot()
+            .returning(|network_id, height| {
+                assert_eq!(network_id, NETWORK);
+                Ok(height == Height::new(1))
+            });
+
+        state_store
+    }
+
+    fn epochs_store(&self) -> MockEpochsStore {
+        let certificates = self.certificates.clone();
+        let mut epochs_store = MockEpochsStore::new();
+        epochs_store
+            .expect_get_certificate()
+            .returning(move |epoch_number, index| {
+                assert_eq!(epoch_number, EpochNumber::ZERO);
+                Ok(certificates.get(index.as_u64() as usize).cloned())
+            });
+
+        epochs_store
+    }
+}
+
+/// Get the roots of the settled state of [`NETWORK`] at `height`.
+fn get_network_state_at(
+    state_store: MockStateStore,
+    epochs_store: MockEpochsStore,
+    height: Height,
+) -> Result<SettledStateAnchor, StateProofError> {
+    let service = crate::AgglayerService::new(
+        tokio::sync::mpsc::channel(1).0,
+        Arc::new(MockPendingStore::new()),
+        Arc::new(state_store),
+        Arc::new(MockDebugStore::new()),
+        Arc::new(epochs_store),
+        Arc::new(Config::default()),
+        Arc::new(ProviderBuilder::new().connect_mocked_client(Asserter::new())),
+    );
+
+    service.get_network_state_at(NETWORK, height)
+}
+
+#[test]
+fn state_is_read_from_its_snapshot() {
+    let history = History::new();
+
+    let anchor = get_network_state_at(
+        history.state_store(),
+        MockEpochsStore::new(),
+        Height::new(1),
+    )
+    .unwrap();
+
+    let state = &history.states[1];
+    assert_eq!(anchor.certificate_id, history.certificates[1].hash());
+    assert_eq!(anchor.height, Height::new(1));
+    assert_eq!(
+        anchor.local_exit_root,
+        LocalExitRoot::from(state.exit_tree.get_root())
+    );
+    assert_eq!(anchor.let_leaf_count, state.exit_tree.leaf_count());
+    assert_eq!(anchor.balance_root, state.balance_tree.root);
+    assert_eq!(anchor.nullifier_root, state.nullifier_tree.root);
+}
+
+#[test]
+fn state_predating_the_snapshots_is_not_replayed() {
+    let history = History::new();
+
+    // Any read of the settled certificates fails the test.
+    assert!(matches!(
+        get_network_state_at(history.state_store(), MockEpochsStore::new(), Height::ZERO),
+        Err(StateProofError::NoStateSnapshot { network_id, height })
+            if network_id == NETWORK && height == Height::ZERO
+    ));
+}
+
+#[test]
+fn missing_snapshots_are_backfilled() {
+    let history = History::new();
+    let mut state_store = history.backfill_state_store();
+    let backfilled = StateRoots::from(&history.states[0]);
+    state_store
+        .expect_write_network_state_snapshot()
+        .once()
+        .withf(move |network_id, height, state| {
+            *network_id == NETWORK
+                && *height == Height::ZERO
+                && StateRoots::from(state) == backfilled
+        })
+        .returning(|_, _, _| Ok(()));
+
+    let written = backfill_state_snapshots(&state_store, &history.epochs_store()).unwrap();
+
+    assert_eq!(written, 1);
+}
+
+#[test]
+fn snapshots_are_not_backfilled_without_their_certificates() {
+    let history = History::new();
+    let mut state_store = history.backfill_state_store();
+    state_store.expect_write_network_state_snapshot().never();
+    let mut epochs_store = MockEpochsStore::new();
+    epochs_store
+        .expect_get_certificate()
+        .returning(|_, _| Ok(None));
+
+    let written = backfill_state_snapshots(&state_store, &epochs_store).unwrap();
+
+    assert_eq!(written, 0);
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use agglayer_certificate_orchestrator::{CertificationError, Certifier, CertifierResult};
+use agglayer_config::Config;
+use agglayer_contracts::rollup::VerifierType;
+use agglayer_storage::tests::mocks::{
+    MockDebugStore, MockEpochsStore, MockPendingStore, MockStateStore,
+};
+use agglayer_types::{
+    aggchain_data::CertificateAggchainDataCtx, Address, Certificate, CertificateStatusError,
+    Digest, GenerationType, Height, L1WitnessCtx, LocalNetworkStateData, NetworkId,
+    PessimisticRootInput, SimulationOutcome, U256,
+};
+use alloy::providers::{mock::Asserter, ProviderBuilder};
+use mockall::predicate::eq;
+use pessimistic_proof::{
+    core::{commitment::PessimisticRootCommitmentVersion, generate_pessimistic_proof},
+    multi_batch_header::MultiBatchHeader,
+    unified_bridge::L1_ETH,
+    LocalNetworkState, PessimisticProofOutput,
+};
+use pessimistic_proof_test_suite::forest::Forest;
+
+use crate::CertificateSimulationError;
+
+/// Certifier running the witness generation and the native execution against
+/// a fixed trusted sequencer, in place of the L1.
+struct NativeCertifier {
+    signer: Address,
+}
+
+#[async_trait::async_trait]
+impl Certifier for NativeCertifier {
+    async fn certify(
+        &self,
+        _full_state: LocalNetworkStateData,
+        _network_id: NetworkId,
+        _height: Height,
+    ) -> CertifierResult {
+        Err(CertificationError::InternalError(
+            "unimplemented".to_string(),
+        ))
+    }
+
+    async fn witness_generation(
+        &self,
+        certificate: &Certificate,
+        state: &mut LocalNetworkStateData,
+        _certificate_tx_hash: Option<Digest>,
+    ) -> Result<(MultiBatchHeader, LocalNetworkState, PessimisticProofOutput), CertificationError>
+    {
+        let l1_info_root = certificate
+            .l1_info_root()
+            .map_err(|source| CertificationError::Types { source })?
+            .unwrap_or_default();
+        let initial_state = LocalNetworkState::from(state.clone());
+        let multi_batch_header = state
+            .apply_certificate(
+                certificate,
+                L1WitnessCtx {
+                    l1_info_root,
+                    prev_pessimistic_root: PessimisticRootInput::Computed(
+                        PessimisticRootCommitmentVersion::V2,
+                    ),
+                    aggchain_data_ctx: CertificateAggchainDataCtx::LegacyEcdsa {
+                        signer: self.signer,
+                    },
+                },
+            )
+            .map_err(|source| CertificationError::Types { source })?;
+        let (output, _) =
+            generate_pessimistic_proof(initial_state.clone().into(), &multi_batch_header)
+                .map_err(|source| CertificationError::NativeExecutionFailed { source })?;
+
+        Ok((multi_batch_header, initial_state, output))
+    }
+
+    fn rollup_manager_address(&self) -> Address {
+        Address::ZERO
+    }
+
+    async fn verifier_type(&self, _rollup_id: u32) -> Result<VerifierType, CertificationError> {
+        Err(CertificationError::InternalError(
+            "unimplemented".to_string(),
+        ))
+    }
+
+    fn default_l1_info_tree_leaf_count(&self) -> u32 {
+        0
+    }
+}
+
+/// Service over a network without settled certificate.
+///
+/// The mocks have no expectation on any write, so the test fails if the
+/// simulation persists anything.
+fn service(
+    network_id: NetworkId,
+    certifier: Option<NativeCertifier>,
+) -> crate::AgglayerService<
+    impl alloy::providers::Provider,
+    MockPendingStore,
+    MockStateStore,
+    MockDebugStore,
+    MockEpochsStore,
+> {
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .with(eq(network_id))
+        .returning(|_| Ok(None));
+
+    let certificate_sender = tokio::sync::mpsc::channel(1).0;
+    let asserter = Asserter::new();
+    let l1_rpc_provider = Arc::new(ProviderBuilder::new().connect_mocked_client(asserter));
+
+    let service = crate::AgglayerService::new(
+        certificate_sender,
+        Arc::new(MockPendingStore::new()),
+        Arc::new(state_store),
+        Arc::new(MockDebugStore::new()),
+        Arc::new(MockEpochsStore::new()),
+        Arc::new(Config::default()),
+        l1_rpc_provider,
+    );
+
+    match certifier {
+        Some(certifier) => service.with_certifier(Arc::new(certifier)),
+        None => service,
+    }
+}
+
+#[tokio::test]
+async fn simulation_returns_the_roots_of_a_valid_certificate() {
+    let mut forest = Forest::default();
+    let certificate = forest.apply_events(&[(L1_ETH, U256::from(100u64))], &[]);
+    let network_id = certificate.network_id;
+    let service = service(
+        network_id,
+        Some(NativeCertifier {
+            signer: forest.get_signer(),
+        }),
+    );
+
+    let simulation = service
+        .simulate_certificate(certificate.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(simulation.certificate_id, certificate.hash());
+    assert_eq!(simulation.height, Height::ZERO);
+    let SimulationOutcome::Success(roots) = simulation.outcome else {
+        panic!("expected a successful simulation, got {simulation:?}");
+    };
+    let expected = forest.state_b.get_roots();
+    assert_eq!(roots.new_local_exit_root, expected.exit_root.into());
+    assert_eq!(roots.new_let_leaf_count, expected.ler_leaf_count);
+    assert_eq!(roots.new_balance_root, expected.balance_root);
+    assert_eq!(roots.new_nullifier_root, expected.nullifier_root);
+}
+
+#[tokio::test]
+async fn simulation_returns_the_error_of_an_invalid_certificate() {
+    let mut forest = Forest::default();
+    let certificate = forest.apply_events(&[(L1_ETH, U256::from(100u64))], &[]);
+    let network_id = certificate.network_id;
+    let service = service(
+        network_id,
+        Some(NativeCertifier {
+            signer: Forest::default().with_signer_seed(1).get_signer(),
+        }),
+    );
+
+    let simulation = service.simulate_certificate(certificate).await.unwrap();
+
+    assert!(
+        matches!(
+            simulation.outcome,
+            SimulationOutcome::Failure(CertificateStatusError::ProofGenerationError {
+                generation_type: GenerationType::Native,
+                ..
+            })
+        ),
+        "unexpected outcome: {:?}",
+        simulation.outcome
+    );
+}
+
+#[tokio::test]
+async fn simulation_refuses_certificates_not_following_the_settled_state() {
+    let forest = Forest::default();
+    let certificate = Certificate::new_for_test(NetworkId::from(forest.network_id), Height::new(1));
+    let service = service(
+        certificate.network_id,
+        Some(NativeCertifier {
+            signer: forest.get_signer(),
+        }),
+    );
+
+    let error = service.simulate_certificate(certificate).await.unwrap_err();
+
+    assert!(
+        matches!(
+            error,
+            CertificateSimulationError::UnexpectedHeight {
+                expected: Height::ZERO,
+                got,
+                ..
+            } if got == Height::new(1)
+        ),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn simulation_requires_a_certifier() {
+    let certificate = Certificate::new_for_test(NetworkId::new(1), Height::ZERO);
+    let service = service(certificate.network_id, None);
+
+    let error = service.simulate_certificate(certificate).await.unwrap_err();
+
+    assert!(matches!(error, CertificateSimulationError::Unavailable));
+}
@@ -0,0 +1,315 @@
+use std::{collections::HashMap, sync::Arc};
+
+use agglayer_contracts::{rollup::VerifierType, L1RpcError, RollupContract};
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    tests::mocks::{MockEpochsStore, MockStateStore},
+};
+use agglayer_types::{
+    aggchain_data::CertificateAggchainDataCtx, Address, Certificate, CertificateHeader,
+    CertificateIndex, CertificateStatus, Digest, EpochNumber, Height, L1WitnessCtx,
+    LocalNetworkStateData, Metadata, NetworkId, PessimisticRootInput, SettlementTxHash, U256,
+};
+use alloy::primitives::TxHash;
+use mockall::predicate::eq;
+use pessimistic_proof::core::commitment::{
+    PessimisticRootCommitmentValues, PessimisticRootCommitmentVersion,
+};
+use pessimistic_proof_test_suite::{forest::Forest, sample_data::USDC};
+
+use crate::state_verification::{StateDivergence, StateRoots, StateVerifier};
+
+const NETWORK: NetworkId = NetworkId::new(1);
+
+/// Pessimistic roots settled on L1, keyed by the settlement transaction they
+/// precede, `None` being the latest one.
+struct SettledRoots(HashMap<Option<TxHash>, [u8; 32]>);
+
+#[async_trait::async_trait]
+impl RollupContract for SettledRoots {
+    async fn get_trusted_sequencer_address(
+        &self,
+        _rollup_id: u32,
+        _proof_signers: HashMap<u32, Address>,
+    ) -> Result<Address, L1RpcError> {
+        unimplemented!()
+    }
+
+    async fn get_rollup_contract_address(&self, _rollup_id: u32) -> Result<Address, L1RpcError> {
+        unimplemented!()
+    }
+
+    async fn get_prev_pessimistic_root(
+        &self,
+        rollup_id: u32,
+        before_tx: Option<TxHash>,
+    ) -> Result<[u8; 32], L1RpcError> {
+        assert_eq!(rollup_id, NETWORK.to_u32());
+        Ok(self.0[&before_tx])
+    }
+
+    async fn get_l1_info_root(&self, _l1_leaf_count: u32) -> Result<[u8; 32], L1RpcError> {
+        unimplemented!()
+    }
+
+    async fn get_verifier_type(&self, _rollup_id: u32) -> Result<VerifierType, L1RpcError> {
+        unimplemented!()
+    }
+
+    fn default_l1_info_tree_entry(&self) -> (u32, [u8; 32]) {
+        unimplemented!()
+    }
+
+    fn get_rollup_manager_address(&self) -> Address {
+        unimplemented!()
+    }
+
+    fn get_event_filter_block_range(&self) -> u64 {
+        unimplemented!()
+    }
+}
+
+/// Two settled certificates: 100 USDC imported, then 10 USDC bridged out.
+struct History {
+    certificates: Vec<Certificate>,
+    /// The state before each certificate, then the final state.
+    states: Vec<LocalNetworkStateData>,
+}
+
+impl History {
+    fn new() -> Self {
+        let mut forest = Forest::default();
+        let signer = forest.get_signer();
+
+        let mut certificates = vec![
+            forest.apply_events(&[(USDC, U256::from(100))], &[]),
+            forest.apply_events(&[], &[(USDC, U256::from(10))]),
+        ];
+        certificates[1].height = Height::new(1);
+
+        let mut states = vec![LocalNetworkStateData::default()];
+        for certificate in &certificates {
+            let mut state = states.last().unwrap().clone();
+            state
+                .apply_certificate(
+                    certificate,
+                    L1WitnessCtx {
+                        l1_info_root: certificate.l1_info_root().unwrap().unwrap_or_default(),
+                        prev_pessimistic_root: PessimisticRootInput::Computed(
+                            PessimisticRootCommitmentVersion::V3,
+                        ),
+                        aggchain_data_ctx: CertificateAggchainDataCtx::LegacyEcdsa { signer },
+                    },
+                )
+                .unwrap();
+            states.push(state);
+        }
+
+        Self {
+            certificates,
+            states,
+        }
+    }
+
+    fn settled_height(&self) -> Height {
+        Height::new(self.certificates.len() as u64 - 1)
+    }
+
+    fn final_state(&self) -> LocalNetworkStateData {
+        self.states.last().unwrap().clone()
+    }
+
+    /// The pessimistic root settled on L1 before each certificate, then the
+    /// latest one.
+    fn settled_roots(&self) -> HashMap<Option<TxHash>, [u8; 32]> {
+        self.states
+            .iter()
+            .enumerate()
+            .map(|(height, state)| {
+                let before_tx = (height < self.certificates.len())
+                    .then(|| TxHash::from(settlement_tx_hash(height as u64)));
+                (before_tx, pp_root(state, Height::new(height as u64)))
+            })
+            .collect()
+    }
+
+    /// State store with the settled certificates and the given persisted
+    /// state.
+    fn state_store(&self, persisted: LocalNetworkStateData) -> MockStateStore {
+        let mut state_store = MockStateStore::new();
+
+        let settled_height = self.settled_height();
+        let last_certificate_id = self.certificates.last().unwrap().hash();
+        state_store
+            .expect_get_latest_settled_certificate_per_network()
+            .with(eq(NETWORK))
+            .returning(move |_| {
+                Ok(Some((
+                    NETWORK,
+                    SettledCertificate(
+                        last_certificate_id,
+                        settled_height,
+                        EpochNumber::ZERO,
+                        CertificateIndex::new(settled_height.as_u64()),
+                    ),
+                )))
+            });
+
+        let headers = self
+            .certificates
+            .iter()
+            .map(|certificate| CertificateHeader {
+                network_id: NETWORK,
+                height: certificate.height,
+                epoch_number: Some(EpochNumber::ZERO),
+                certificate_index: Some(CertificateIndex::new(certificate.height.as_u64())),
+                certificate_id: certificate.hash(),
+                prev_local_exit_root: certificate.prev_local_exit_root,
+                new_local_exit_root: certificate.new_local_exit_root,
+                metadata: Metadata::DEFAULT,
+                status: CertificateStatus::Settled,
+                settlement_tx_hash: Some(settlement_tx_hash(certificate.height.as_u64())),
+            })
+            .collect::<Vec<_>>();
+        state_store
+            .expect_get_certificate_header_by_cursor()
+            .returning(move |network_id, height| {
+                assert_eq!(network_id, NETWORK);
+                Ok(headers.get(height.as_u64() as usize).cloned())
+            });
+
+        state_store
+            .expect_read_local_network_state()
+            .with(eq(NETWORK))
+            .returning(move |_| Ok(Some(persisted.clone())));
+
+        state_store
+    }
+
+    fn epochs_store(&self) -> MockEpochsStore {
+        let certificates = self.certificates.clone();
+        let mut epochs_store = MockEpochsStore::new();
+        epochs_store
+            .expect_get_certificate()
+            .returning(move |epoch_number, index| {
+                assert_eq!(epoch_number, EpochNumber::ZERO);
+                Ok(certificates.get(index.as_u64() as usize).cloned())
+            });
+
+        epochs_store
+    }
+}
+
+fn settlement_tx_hash(height: u64) -> SettlementTxHash {
+    SettlementTxHash::new(Digest([height as u8 + 1; 32]))
+}
+
+/// The V3 pessimistic root of the state on which the certificate at `height`
+/// applies.
+fn pp_root(state: &LocalNetworkStateData, height: Height) -> [u8; 32] {
+    PessimisticRootCommitmentValues {
+        balance_root: state.balance_tree.root.into(),
+        nullifier_root: state.nullifier_tree.root.into(),
+        ler_leaf_count: state.exit_tree.leaf_count(),
+        height: height.as_u64(),
+        origin_network: NETWORK,
+    }
+    .compute_pp_root(PessimisticRootCommitmentVersion::V3)
+    .0
+}
+
+#[tokio::test]
+async fn consistent_state() {
+    let history = History::new();
+    let verifier = StateVerifier::new(
+        Arc::new(history.state_store(history.final_state())),
+        Arc::new(history.epochs_store()),
+        Some(Arc::new(SettledRoots(history.settled_roots()))),
+    );
+
+    let report = verifier.verify_network(NETWORK).await.unwrap();
+
+    assert_eq!(report.divergence, None);
+    assert_eq!(report.settled_height, Some(Height::new(1)));
+    assert_eq!(report.replayed_certificates, 2);
+    assert!(report.l1_checked);
+}
+
+#[tokio::test]
+async fn persisted_state_drift() {
+    let history = History::new();
+    let mut persisted = history.final_state();
+    persisted
+        .balance_tree
+        .insert(USDC, U256::from(1000).to_be_bytes().into())
+        .unwrap();
+    let verifier = StateVerifier::<_, _, SettledRoots>::new(
+        Arc::new(history.state_store(persisted.clone())),
+        Arc::new(history.epochs_store()),
+        None,
+    );
+
+    let report = verifier.verify_network(NETWORK).await.unwrap();
+
+    assert_eq!(
+        report.divergence,
+        Some(StateDivergence::PersistedState {
+            persisted: StateRoots::from(&persisted),
+            rebuilt: StateRoots::from(&history.final_state()),
+        })
+    );
+    assert_eq!(report.replayed_certificates, 2);
+    assert!(!report.l1_checked);
+}
+
+#[tokio::test]
+async fn first_diverging_pessimistic_root() {
+    let history = History::new();
+    let mut settled_roots = history.settled_roots();
+    let before_second = Some(TxHash::from(settlement_tx_hash(1)));
+    settled_roots.insert(before_second, [0xff; 32]);
+    // Diverges as well, but later.
+    settled_roots.insert(None, [0xff; 32]);
+    let verifier = StateVerifier::new(
+        Arc::new(history.state_store(history.final_state())),
+        Arc::new(history.epochs_store()),
+        Some(Arc::new(SettledRoots(settled_roots))),
+    );
+
+    let report = verifier.verify_network(NETWORK).await.unwrap();
+
+    let Some(StateDivergence::PessimisticRoot {
+        height,
+        l1,
+        rebuilt_v3,
+        ..
+    }) = report.divergence
+    else {
+        panic!("expected a pessimistic root divergence");
+    };
+    assert_eq!(height, Height::new(1));
+    assert_eq!(l1, Digest([0xff; 32]));
+    assert_eq!(rebuilt_v3.0, pp_root(&history.states[1], Height::new(1)));
+    assert_eq!(report.replayed_certificates, 1);
+}
+
+#[tokio::test]
+async fn missing_certificate() {
+    let mut history = History::new();
+    let state_store = history.state_store(history.final_state());
+    // The second certificate is missing from its epoch.
+    history.certificates.pop();
+    let verifier = StateVerifier::<_, _, SettledRoots>::new(
+        Arc::new(state_store),
+        Arc::new(history.epochs_store()),
+        None,
+    );
+
+    let report = verifier.verify_network(NETWORK).await.unwrap();
+
+    assert!(matches!(
+        report.divergence,
+        Some(StateDivergence::MissingCertificate { height, .. }) if height == Height::new(1)
+    ));
+    assert_eq!(report.replayed_certificates, 1);
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use agglayer_config::{rate_limiting::RateLimitingConfig, Config};
+use agglayer_storage::tests::mocks::{
+    MockDebugStore, MockEpochsStore, MockPendingStore, MockStateStore,
+};
+use agglayer_types::{Certificate, Height, NetworkId};
+use alloy::providers::{mock::Asserter, ProviderBuilder};
+use mockall::predicate::eq;
+
+use crate::CertificateSubmissionError;
+
+const NETWORK_1: NetworkId = NetworkId::new(1);
+
+fn service_with_pending(
+    pending_store: MockPendingStore,
+    rate_limiting: RateLimitingConfig,
+) -> crate::AgglayerService<
+    impl alloy::providers::Provider,
+    MockPendingStore,
+    MockStateStore,
+    MockDebugStore,
+    MockEpochsStore,
+> {
+    let certificate_sender = tokio::sync::mpsc::channel(1).0;
+    let asserter = Asserter::new();
+    let l1_rpc_provider = Arc::new(ProviderBuilder::new().connect_mocked_client(asserter));
+    let mut config = Config::default();
+    config.rate_limiting = rate_limiting;
+
+    crate::AgglayerService::new(
+        certificate_sender,
+        Arc::new(pending_store),
+        Arc::new(MockStateStore::new()),
+        Arc::new(MockDebugStore::new()),
+        Arc::new(MockEpochsStore::new()),
+        Arc::new(config),
+        l1_rpc_provider,
+    )
+}
+
+fn pending_store_with(count: usize) -> MockPendingStore {
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_count_pending_certificates_for_network()
+        .with(eq(NETWORK_1))
+        .once()
+        .returning(move |_| Ok(count));
+    pending_store
+}
+
+#[test]
+fn pending_certificates_are_not_capped_by_default() {
+    let certificate = Certificate::new_for_test(NETWORK_1, Height::new(3));
+    let service = service_with_pending(MockPendingStore::new(), RateLimitingConfig::DEFAULT);
+
+    service.check_pending_certificate_cap(&certificate).unwrap();
+}
+
+#[test]
+fn certificate_below_the_cap_is_accepted() {
+    let certificate = Certificate::new_for_test(NETWORK_1, Height::new(3));
+    let service = service_with_pending(
+        pending_store_with(1),
+        RateLimitingConfig::DEFAULT.with_max_pending_certificates(2),
+    );
+
+    service.check_pending_certificate_cap(&certificate).unwrap();
+}
+
+#[test]
+fn new_certificate_is_refused_once_the_cap_is_reached() {
+    let certificate = Certificate::new_for_test(NETWORK_1, Height::new(3));
+    let mut pending_store = pending_store_with(2);
+    pending_store
+        .expect_get_certificate()
+        .with(eq(NETWORK_1), eq(Height::new(3)))
+        .once()
+        .returning(|_, _| Ok(None));
+    let service = service_with_pending(
+        pending_store,
+        RateLimitingConfig::DEFAULT.with_max_pending_certificates_override(1, 2),
+    );
+
+    let error = service
+        .check_pending_certificate_cap(&certificate)
+        .expect_err("the network has no room for another pending certificate");
+
+    assert!(
+        matches!(
+            error,
+            CertificateSubmissionError::TooManyPendingCertificates { network_id, max: 2 }
+                if network_id == NETWORK_1
+        ),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[test]
+fn replacement_is_accepted_once_the_cap_is_reached() {
+    let certificate = Certificate::new_for_test(NETWORK_1, Height::new(3));
+    let mut pending_store = pending_store_with(2);
+    pending_store
+        .expect_get_certificate()
+        .with(eq(NETWORK_1), eq(Height::new(3)))
+        .once()
+        .returning(|network_id, height| Ok(Some(Certificate::new_for_test(network_id, height))));
+    let service = service_with_pending(
+        pending_store,
+        RateLimitingConfig::DEFAULT.with_max_pending_certificates(2),
+    );
+
+    service.check_pending_certificate_cap(&certificate).unwrap();
+}
@@ -8,12 +8,21 @@
 //!   operations
 //! - `SettlementTask` (internal): worker task for processing individual
 //!   settlements
+//! - `WalletPool` (internal): assignment of settlement jobs to the configured
+//!   settlement wallets
+//! - `WalletBalanceMonitor` (internal): settlement wallet balance polling and
+//!   low-funds circuit breaker
+//! - `SettlementBatcher` (internal): batching of certificate settlements into a
+//!   single transaction
 
+mod settlement_batcher;
 pub mod settlement_service;
 pub mod settlement_service_trait;
 mod settlement_task;
 mod utils;
+mod wallet_balances;
 mod wallet_nonce_locks;
+mod wallet_pool;
 
 pub use settlement_service::{LiveTaskNotification, NewSettlementAttempt, SettlementService};
 #[cfg(feature = "testutils")]
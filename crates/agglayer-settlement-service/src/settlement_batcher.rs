@@ -0,0 +1,145 @@
+//! Batching of certificate settlements into a single L1 transaction.
+//!
+//! Each batched job is persisted and gets its task as usual, but the task
+//! waits for the [`BatchOutcome`] of its batch before doing anything on L1.
+//! Once the batch window closes, the calls of its jobs are combined into one
+//! `aggregate3` call to the configured batcher, settled by a job of its own
+//! that records the batched job ids. That batch job is what startup recovery
+//! uses to reattach the batched jobs to their batch.
+//!
+//! A batch only settles its jobs when it succeeds as a whole. When it cannot
+//! be created, typically because one of its calls reverts during gas
+//! estimation, or when it reverts on L1, its jobs are settled one transaction
+//! each, which pins the revert on the faulty certificate.
+
+use std::time::Duration;
+
+use agglayer_config::settlement_service::SettlementBatchingConfig;
+use agglayer_contracts::settler::aggregate3_calldata;
+use agglayer_types::{SettlementJob, SettlementJobId, SettlementJobResult, SettlementTxType, U256};
+use tokio::sync::oneshot;
+
+/// What a batched job does once its batch is over.
+#[derive(Debug)]
+pub(crate) enum BatchOutcome {
+    /// The batch settled the job's call, with the given batch job result.
+    Settled(SettlementJobResult),
+    /// The batch did not settle the job, which must be settled on its own.
+    SettleIndividually,
+}
+
+/// A job waiting for its batch, with the channel its task waits on.
+#[derive(Debug)]
+pub(crate) struct BatchMember {
+    pub(crate) job_id: SettlementJobId,
+    pub(crate) job: SettlementJob,
+    pub(crate) outcome: oneshot::Sender<BatchOutcome>,
+}
+
+/// Result of adding a job to the open batch.
+#[derive(Debug)]
+pub(crate) enum Enqueued {
+    /// The job opened a new batch, to be closed with `generation` at the end
+    /// of the window.
+    Opened { generation: u64 },
+    /// The job joined the open batch.
+    Joined,
+    /// The job filled the open batch, which is closed with these members.
+    Full(Vec<BatchMember>),
+}
+
+#[derive(Debug, Default)]
+struct OpenBatch {
+    /// Incremented every time a batch opens, so that the end of the window
+    /// of a batch closed early never closes the next one.
+    generation: u64,
+    members: Vec<BatchMember>,
+}
+
+/// The batch currently collecting jobs for one
+/// [`SettlementService`](crate::SettlementService).
+#[derive(Debug)]
+pub(crate) struct SettlementBatcher {
+    config: SettlementBatchingConfig,
+    open: std::sync::Mutex<OpenBatch>,
+}
+
+impl SettlementBatcher {
+    pub(crate) fn new(config: SettlementBatchingConfig) -> eyre::Result<Self> {
+        if config.max_batch_size < 2 {
+            eyre::bail!(
+                "Settlement batches need room for at least 2 settlements, got a maximum batch \
+                 size of {}",
+                config.max_batch_size
+            );
+        }
+
+        Ok(Self {
+            config,
+            open: Default::default(),
+        })
+    }
+
+    /// How long a batch stays open after its first job.
+    pub(crate) fn window(&self) -> Duration {
+        self.config.window
+    }
+
+    /// Whether the job of a certificate settlement can be batched.
+    ///
+    /// The batched calls carry neither value nor an envelope of their own:
+    /// jobs sending ETH or needing an EIP-7702 envelope settle on their own.
+    pub(crate) fn accepts(job: &SettlementJob) -> bool {
+        job.eth_value == U256::ZERO && job.tx_type == SettlementTxType::Eip1559 && !job.is_batch()
+    }
+
+    pub(crate) fn enqueue(&self, member: BatchMember) -> Enqueued {
+        let mut open = self.open.lock().expect("settlement batch lock poisoned");
+        open.members.push(member);
+        if open.members.len() >= self.config.max_batch_size {
+            return Enqueued::Full(std::mem::take(&mut open.members));
+        }
+        if open.members.len() == 1 {
+            open.generation += 1;
+            return Enqueued::Opened {
+                generation: open.generation,
+            };
+        }
+        Enqueued::Joined
+    }
+
+    /// Closes the batch opened with `generation`, returning its members.
+    ///
+    /// Returns nothing if that batch was already closed by filling up.
+    pub(crate) fn close(&self, generation: u64) -> Vec<BatchMember> {
+        let mut open = self.open.lock().expect("settlement batch lock poisoned");
+        if open.generation != generation {
+            return Vec::new();
+        }
+        std::mem::take(&mut open.members)
+    }
+
+    /// The job settling the calls of `members`, in order, in one transaction.
+    ///
+    /// Its gas limit is resolved when it is created, like for any other job.
+    pub(crate) fn batch_job(&self, members: &[BatchMember]) -> SettlementJob {
+        SettlementJob {
+            contract_address: self.config.batcher_address,
+            calldata: aggregate3_calldata(members.iter().map(|member| {
+                (
+                    member.job.contract_address.into_alloy(),
+                    member.job.calldata.clone(),
+                )
+            })),
+            eth_value: U256::ZERO,
+            gas_limit: 0,
+            network_id: None,
+            tx_type: SettlementTxType::Eip1559,
+            access_list: None,
+            batched_jobs: members.iter().map(|member| member.job_id).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
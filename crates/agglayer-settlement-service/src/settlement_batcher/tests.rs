@@ -0,0 +1,164 @@
+use agglayer_contracts::contracts::IMulticall3;
+use agglayer_types::Address;
+use alloy::sol_types::SolCall as _;
+
+use super::*;
+
+fn batcher_address() -> Address {
+    Address::from([0xBA; 20])
+}
+
+fn mk_batcher(max_batch_size: usize) -> SettlementBatcher {
+    SettlementBatcher::new(SettlementBatchingConfig {
+        batcher_address: batcher_address(),
+        window: Duration::from_secs(12),
+        max_batch_size,
+    })
+    .expect("valid batching configuration")
+}
+
+fn mk_job(seed: u8) -> SettlementJob {
+    SettlementJob {
+        contract_address: Address::from([seed; 20]),
+        calldata: vec![seed, seed.wrapping_add(1)].into(),
+        eth_value: U256::ZERO,
+        gas_limit: seed as u128 + 100_000,
+        network_id: None,
+        tx_type: SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
+    }
+}
+
+fn mk_member(seed: u8) -> (BatchMember, oneshot::Receiver<BatchOutcome>) {
+    let (outcome, outcome_receiver) = oneshot::channel();
+    let member = BatchMember {
+        job_id: SettlementJobId::from(seed as u128),
+        job: mk_job(seed),
+        outcome,
+    };
+    (member, outcome_receiver)
+}
+
+fn job_ids(members: &[BatchMember]) -> Vec<SettlementJobId> {
+    members.iter().map(|member| member.job_id).collect()
+}
+
+#[test]
+fn batches_need_room_for_two_settlements() {
+    let error = SettlementBatcher::new(SettlementBatchingConfig {
+        batcher_address: batcher_address(),
+        window: Duration::from_secs(12),
+        max_batch_size: 1,
+    })
+    .expect_err("a batch of one settlement is not a batch");
+
+    assert!(error.to_string().contains("at least 2 settlements"));
+}
+
+#[test]
+fn only_plain_calls_are_batched() {
+    assert!(SettlementBatcher::accepts(&mk_job(1)));
+
+    let with_value = SettlementJob {
+        eth_value: U256::from(1),
+        ..mk_job(1)
+    };
+    assert!(!SettlementBatcher::accepts(&with_value));
+
+    let set_code = SettlementJob {
+        tx_type: SettlementTxType::Eip7702 {
+            authorization_list: Vec::new(),
+        },
+        ..mk_job(1)
+    };
+    assert!(!SettlementBatcher::accepts(&set_code));
+
+    let batch = SettlementJob {
+        batched_jobs: vec![SettlementJobId::from(7u128)],
+        ..mk_job(1)
+    };
+    assert!(!SettlementBatcher::accepts(&batch));
+}
+
+#[test]
+fn full_batch_closes_immediately() {
+    let batcher = mk_batcher(2);
+    let (first, _) = mk_member(1);
+    let (second, _) = mk_member(2);
+    let expected = vec![first.job_id, second.job_id];
+
+    let Enqueued::Opened { generation } = batcher.enqueue(first) else {
+        panic!("the first job must open a batch");
+    };
+    let Enqueued::Full(members) = batcher.enqueue(second) else {
+        panic!("the second job must fill the batch");
+    };
+    assert_eq!(job_ids(&members), expected);
+
+    // The end of the window of the closed batch finds nothing to settle.
+    assert!(batcher.close(generation).is_empty());
+}
+
+#[test]
+fn window_end_of_closed_batch_leaves_next_batch_open() {
+    let batcher = mk_batcher(2);
+    let (first, _) = mk_member(1);
+    let (second, _) = mk_member(2);
+    let (third, _) = mk_member(3);
+    let third_job_id = third.job_id;
+
+    let Enqueued::Opened {
+        generation: first_generation,
+    } = batcher.enqueue(first)
+    else {
+        panic!("the first job must open a batch");
+    };
+    assert!(matches!(batcher.enqueue(second), Enqueued::Full(_)));
+    let Enqueued::Opened { generation } = batcher.enqueue(third) else {
+        panic!("the third job must open a new batch");
+    };
+
+    assert!(batcher.close(first_generation).is_empty());
+    assert_eq!(job_ids(&batcher.close(generation)), vec![third_job_id]);
+}
+
+#[test]
+fn jobs_join_the_open_batch_until_its_window_ends() {
+    let batcher = mk_batcher(16);
+    let (first, _) = mk_member(1);
+    let (second, _) = mk_member(2);
+    let expected = vec![first.job_id, second.job_id];
+
+    let Enqueued::Opened { generation } = batcher.enqueue(first) else {
+        panic!("the first job must open a batch");
+    };
+    assert!(matches!(batcher.enqueue(second), Enqueued::Joined));
+
+    assert_eq!(job_ids(&batcher.close(generation)), expected);
+    assert!(batcher.close(generation).is_empty());
+}
+
+#[test]
+fn batch_job_performs_the_calls_of_its_jobs_in_order() {
+    let batcher = mk_batcher(16);
+    let members = vec![mk_member(1).0, mk_member(2).0];
+
+    let batch_job = batcher.batch_job(&members);
+
+    assert_eq!(batch_job.contract_address, batcher_address());
+    assert_eq!(batch_job.eth_value, U256::ZERO);
+    assert_eq!(batch_job.network_id, None);
+    assert_eq!(batch_job.batched_jobs, job_ids(&members));
+    assert!(batch_job.is_batch());
+
+    let calls = IMulticall3::aggregate3Call::abi_decode(&batch_job.calldata)
+        .expect("batch calldata must be an aggregate3 call")
+        .calls;
+    assert_eq!(calls.len(), 2);
+    for (call, member) in calls.iter().zip(&members) {
+        assert_eq!(call.target, member.job.contract_address.into_alloy());
+        assert_eq!(call.callData, member.job.calldata);
+        assert!(!call.allowFailure);
+    }
+}
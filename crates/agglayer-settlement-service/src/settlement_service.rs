@@ -3,8 +3,9 @@ use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc, time::Syste
 use agglayer_config::settlement_service::{SettlementServiceConfig, SettlementTransactionConfig};
 use agglayer_storage::stores::{EditEvenIfCompleted, SettlementReader, SettlementWriter};
 use agglayer_types::{
-    Address, CertificateId, ClientError, Nonce, RpcErrorCode, SettlementAttempt,
-    SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementJobResult, SettlementTxHash,
+    Address, CertificateId, ClientError, ContractCallOutcome, Nonce, RpcErrorCode,
+    SettlementAttempt, SettlementAttemptResult, SettlementJob, SettlementJobId,
+    SettlementJobResult, SettlementTxHash,
 };
 use alloy::{
     consensus::Transaction as _,
@@ -13,16 +14,19 @@ use alloy::{
 };
 use educe::Educe;
 use eyre::Context as _;
-use tokio::sync::{mpsc, watch, Mutex};
+use tokio::sync::{mpsc, oneshot, watch, Mutex};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
 use crate::{
+    settlement_batcher::{BatchMember, BatchOutcome, Enqueued, SettlementBatcher},
     settlement_task::{
         RecoveredSettlementJob, SettlementTask, SettlementTaskRunResult, StoredSettlementJob,
         TaskAdminCommand, TaskControl, TaskControlHandle,
     },
+    wallet_balances::WalletBalanceMonitor,
     wallet_nonce_locks::WalletNonceLocks,
+    wallet_pool::WalletPool,
 };
 
 /// How the live task for a job (if any) was told about an admin mutation.
@@ -127,8 +131,21 @@ pub struct SettlementService<L1Provider, SettlementStore> {
     /// concurrent settlement tasks.
     /// XREF: https://github.com/agglayer/agglayer/issues/1597
     wallet_nonce_locks: Arc<WalletNonceLocks>,
+    /// Settlement wallets the tasks are assigned to, each with its own nonce
+    /// lane.
+    wallet_pool: Arc<WalletPool>,
+    /// Last observed balances of the pool wallets, refreshed in the
+    /// background.
+    balance_monitor: Arc<WalletBalanceMonitor>,
+    /// Batch collecting the certificate settlements to send together, when
+    /// batching is enabled.
+    batcher: Option<Arc<SettlementBatcher>>,
 }
 
+/// Batched jobs waiting for the outcome of their batch, with the channels
+/// their tasks wait on.
+type BatchMemberOutcomes = Vec<(SettlementJobId, oneshot::Sender<BatchOutcome>)>;
+
 struct TaskControlRegistrationGuard {
     job_id: SettlementJobId,
     task_controls: Arc<std::sync::Mutex<HashMap<SettlementJobId, TaskControlHandle>>>,
@@ -206,12 +223,37 @@ impl<
     > SettlementService<L1Provider, SettlementStore>
 {
     pub async fn start(
-        _config: SettlementServiceConfig,
+        config: SettlementServiceConfig,
         tx_config: Arc<SettlementTransactionConfig>,
         provider: Arc<L1Provider>,
         store: Arc<SettlementStore>,
         cancellation_token: CancellationToken,
     ) -> eyre::Result<(Self, u64)> {
+        let wallet_pool = WalletPool::new(
+            provider.default_signer_address(),
+            &config.wallet_pool,
+            |wallet| provider.has_signer_for(wallet),
+        )
+        .wrap_err("Invalid settlement wallet pool configuration")?;
+        let balance_monitor = match &config.balance_monitor {
+            Some(balance_monitor_config) => {
+                let balance_monitor = Arc::new(WalletBalanceMonitor::new(balance_monitor_config));
+                balance_monitor.spawn(
+                    provider.clone(),
+                    wallet_pool.wallets().to_vec(),
+                    balance_monitor_config.poll_interval,
+                    cancellation_token.clone(),
+                );
+                balance_monitor
+            }
+            None => Arc::default(),
+        };
+        let batcher = config
+            .batching
+            .map(SettlementBatcher::new)
+            .transpose()
+            .wrap_err("Invalid settlement batching configuration")?
+            .map(Arc::new);
         let this = Self {
             tx_config,
             provider,
@@ -221,6 +263,9 @@ impl<
             result_watchers: Arc::new(Mutex::new(HashMap::new())),
             admin_operation_lock: Arc::new(Mutex::new(())),
             wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+            wallet_pool: Arc::new(wallet_pool),
+            balance_monitor,
+            batcher,
         };
         let recovery_skipped_jobs = this.resume_pending_settlement_jobs().await?;
         Ok((this, recovery_skipped_jobs))
@@ -237,6 +282,8 @@ impl<
             self.provider.clone(),
             self.store.clone(),
             self.wallet_nonce_locks.clone(),
+            self.wallet_pool.clone(),
+            self.balance_monitor.clone(),
             task_control,
         )
         .await
@@ -254,8 +301,14 @@ impl<
             .wrap_err("Failed to scan settlement job ids during startup recovery")?;
 
         let mut completed_jobs = 0usize;
+        let mut cancelled_jobs = 0usize;
         let mut resumed_jobs = 0usize;
         let mut skipped_jobs = 0u64;
+        // Pending jobs are only spawned once the scan is over, as a batched
+        // job can only be reattached to its batch once the batch is known.
+        let mut pending_jobs = Vec::new();
+        let mut pending_batch_of = HashMap::new();
+        let mut settled_by_batch = HashMap::new();
         for job_id in job_ids {
             match SettlementTask::recover_from_storage(
                 job_id,
@@ -263,19 +316,27 @@ impl<
                 self.provider.clone(),
                 self.store.clone(),
                 self.wallet_nonce_locks.clone(),
+                self.wallet_pool.clone(),
+                self.balance_monitor.clone(),
             )
             .await
             {
-                Ok(RecoveredSettlementJob::Completed(_)) => {
+                Ok(RecoveredSettlementJob::Completed { job, result }) => {
+                    if result.contract_call_result.outcome == ContractCallOutcome::Success {
+                        for batched_job_id in job.batched_jobs {
+                            settled_by_batch.insert(batched_job_id, result.clone());
+                        }
+                    }
                     completed_jobs += 1;
                 }
+                Ok(RecoveredSettlementJob::Cancelled) => {
+                    cancelled_jobs += 1;
+                }
                 Ok(RecoveredSettlementJob::Pending(pending)) => {
-                    let (task_control_handle, task_control) =
-                        TaskControlHandle::new(&self.cancellation_token);
-                    let task = pending.into_task(task_control);
-                    self.spawn_settlement_task(job_id, task, task_control_handle)
-                        .await;
-                    resumed_jobs += 1;
+                    for batched_job_id in &pending.job().batched_jobs {
+                        pending_batch_of.insert(*batched_job_id, job_id);
+                    }
+                    pending_jobs.push((job_id, pending));
                 }
                 // Load fails only when this job's stored rows cannot be read
                 // back (corrupt or undecodable data); never expected in
@@ -293,18 +354,109 @@ impl<
             }
         }
 
+        let mut pending_batches = HashMap::new();
+        let mut pending_batch_members: HashMap<_, BatchMemberOutcomes> = HashMap::new();
+        for (job_id, pending) in pending_jobs {
+            // The batch settled the job, but the node stopped before the
+            // result got recorded for the job.
+            if let Some(result) = settled_by_batch.remove(&job_id) {
+                match self.store.insert_settlement_job_result(&job_id, &result) {
+                    Ok(()) => completed_jobs += 1,
+                    Err(error) => {
+                        error!(
+                            ?error,
+                            %job_id,
+                            "Failed to record the batch result of a settlement job during \
+                             startup recovery; skipping"
+                        );
+                        skipped_jobs += 1;
+                    }
+                }
+                continue;
+            }
+
+            let is_batch = pending.job().is_batch();
+            let (task_control_handle, task_control) =
+                TaskControlHandle::new(&self.cancellation_token);
+            let task = pending.into_task(task_control);
+            match pending_batch_of.get(&job_id) {
+                Some(batch_job_id) => {
+                    let (outcome_sender, outcome_receiver) = oneshot::channel();
+                    self.spawn_batched_settlement_task(
+                        job_id,
+                        task,
+                        task_control_handle,
+                        outcome_receiver,
+                    )
+                    .await;
+                    pending_batch_members
+                        .entry(*batch_job_id)
+                        .or_default()
+                        .push((job_id, outcome_sender));
+                }
+                None => {
+                    let watcher = self
+                        .spawn_settlement_task(job_id, task, task_control_handle)
+                        .await;
+                    if is_batch {
+                        pending_batches.insert(job_id, watcher);
+                    }
+                }
+            }
+            resumed_jobs += 1;
+        }
+        for (job_id, watcher) in pending_batches {
+            let members = pending_batch_members.remove(&job_id).unwrap_or_default();
+            let this = self.clone();
+            tokio::spawn(async move {
+                this.await_batch(SettlementJobWatcher { watcher, job_id }, members)
+                    .await
+            });
+        }
+
         info!(
             completed_jobs,
-            resumed_jobs, skipped_jobs, "Settlement service startup recovery scan completed"
+            cancelled_jobs,
+            resumed_jobs,
+            skipped_jobs,
+            "Settlement service startup recovery scan completed"
         );
         Ok(skipped_jobs)
     }
 
     async fn spawn_settlement_task(
+        &self,
+        job_id: SettlementJobId,
+        task: SettlementTask<L1Provider, SettlementStore>,
+        task_control_handle: TaskControlHandle,
+    ) -> watch::Receiver<Option<SettlementJobResult>> {
+        self.spawn_task(job_id, task, task_control_handle, None)
+            .await
+    }
+
+    /// Spawns the task of a batched job, which only starts settling the job
+    /// on its own if its batch does not settle it.
+    ///
+    /// Admin commands queued while the task waits for its batch are only
+    /// acted upon once it settles the job on its own. Cancellation is not
+    /// delayed.
+    async fn spawn_batched_settlement_task(
+        &self,
+        job_id: SettlementJobId,
+        task: SettlementTask<L1Provider, SettlementStore>,
+        task_control_handle: TaskControlHandle,
+        batch_outcome: oneshot::Receiver<BatchOutcome>,
+    ) -> watch::Receiver<Option<SettlementJobResult>> {
+        self.spawn_task(job_id, task, task_control_handle, Some(batch_outcome))
+            .await
+    }
+
+    async fn spawn_task(
         &self,
         job_id: SettlementJobId,
         mut task: SettlementTask<L1Provider, SettlementStore>,
         task_control_handle: TaskControlHandle,
+        batch_outcome: Option<oneshot::Receiver<BatchOutcome>>,
     ) -> watch::Receiver<Option<SettlementJobResult>> {
         let (result_sender, result_receiver) = watch::channel(None);
         // Register the watcher first so a concurrent retrieval observes a
@@ -313,6 +465,7 @@ impl<
             .lock()
             .await
             .insert(job_id, result_receiver.clone());
+        let batch_wait = batch_outcome.map(|outcome| (outcome, task_control_handle.clone()));
         self.task_controls
             .lock()
             .expect("settlement task_controls lock poisoned")
@@ -323,12 +476,50 @@ impl<
         let provider = self.provider.clone();
         let store = self.store.clone();
         let wallet_nonce_locks = self.wallet_nonce_locks.clone();
+        let wallet_pool = self.wallet_pool.clone();
+        let balance_monitor = self.balance_monitor.clone();
         let cancellation_token = self.cancellation_token.clone();
         tokio::task::spawn(async move {
             let _task_control_registration = TaskControlRegistrationGuard {
                 job_id,
                 task_controls: task_controls.clone(),
             };
+            if let Some((batch_outcome, task_control_handle)) = batch_wait {
+                tokio::select! {
+                    _ = task_control_handle.cancelled() => {
+                        info!(?job_id, "Settlement task cancelled while waiting for its batch");
+                        result_watchers.lock().await.remove(&job_id);
+                        return;
+                    }
+                    outcome = batch_outcome => match outcome {
+                        Ok(BatchOutcome::Settled(result)) => {
+                            if let Err(error) = result_sender.send(Some(result)) {
+                                error!(
+                                    ?error,
+                                    ?job_id,
+                                    "Failed to send settlement job result to watchers"
+                                );
+                            }
+                            return;
+                        }
+                        Ok(BatchOutcome::SettleIndividually) => {
+                            info!(
+                                ?job_id,
+                                "Settlement batch did not settle the job; settling it alone"
+                            );
+                        }
+                        Err(_) => {
+                            warn!(
+                                ?job_id,
+                                "Settlement batch stopped before settling the job; it resumes \
+                                 on the next startup"
+                            );
+                            result_watchers.lock().await.remove(&job_id);
+                            return;
+                        }
+                    },
+                }
+            }
             loop {
                 match task.run().await {
                     SettlementTaskRunResult::Completed(result) => {
@@ -360,6 +551,8 @@ impl<
                             provider.clone(),
                             store.clone(),
                             wallet_nonce_locks.clone(),
+                            wallet_pool.clone(),
+                            balance_monitor.clone(),
                             task_control,
                         )
                         .await
@@ -377,6 +570,11 @@ impl<
                                 }
                                 break;
                             }
+                            Ok(StoredSettlementJob::Cancelled) => {
+                                info!(?job_id, "Settlement job cancelled by a network rollback");
+                                result_watchers.lock().await.remove(&job_id);
+                                break;
+                            }
                             Err(error) => {
                                 error!(
                                     ?error,
@@ -417,8 +615,20 @@ impl<
         match self.store.get_settlement_job_result(&job_id) {
             Ok(Some(_)) => eyre::eyre!("settlement job {job_id} already completed")
                 .wrap_err(RpcErrorCode::AlreadyCompleted),
-            Ok(None) => eyre::eyre!("no live settlement task for pending job {job_id}")
-                .wrap_err(RpcErrorCode::NoLiveTask),
+            Ok(None) => match self.store.get_settlement_job_cancellation(&job_id) {
+                Ok(Some(cancellation)) => eyre::eyre!(
+                    "settlement job {job_id} was cancelled by the rollback of network {} to \
+                     height {}",
+                    cancellation.network_id,
+                    cancellation.target_height
+                )
+                .wrap_err(RpcErrorCode::AlreadyCompleted),
+                Ok(None) => eyre::eyre!("no live settlement task for pending job {job_id}")
+                    .wrap_err(RpcErrorCode::NoLiveTask),
+                Err(error) => eyre::Report::new(error).wrap_err(format!(
+                    "Failed to read settlement job cancellation for id {job_id}"
+                )),
+            },
             Err(error) => eyre::Report::new(error).wrap_err(format!(
                 "Failed to read settlement job terminal result for id {job_id}"
             )),
@@ -544,6 +754,10 @@ impl<
                 "settlement job {job_id} completed while it was being reloaded"
             )
             .wrap_err(RpcErrorCode::AlreadyCompleted)),
+            StoredSettlementJob::Cancelled => Err(eyre::eyre!(
+                "settlement job {job_id} was cancelled while it was being reloaded"
+            )
+            .wrap_err(RpcErrorCode::AlreadyCompleted)),
         }
     }
 
@@ -684,6 +898,8 @@ impl<
                         .and_then(|tx| tx.max_priority_fee_per_gas())
                 })
                 .unwrap_or(0),
+            // Registered by hand, not priced by a gas oracle.
+            gas_oracle_inputs: None,
         })
     }
 
@@ -831,14 +1047,42 @@ impl<
                 "Settlement job {job_id} still has a terminal result right after its removal; was \
                  one re-recorded concurrently?"
             )),
+            // A network rollback removed the certificate of the job, which
+            // stays without a task.
+            StoredSettlementJob::Cancelled => {
+                warn!(
+                    ?job_id,
+                    "Removed the terminal result of a settlement job cancelled by a network \
+                     rollback; not respawning it"
+                );
+                Ok(())
+            }
         }
     }
 
+    /// Persists a new settlement job and spawns its task.
+    ///
+    /// When batching is enabled, certificate settlements that can be batched
+    /// are settled together with the ones requested within the batch window.
     #[tracing::instrument(skip(self))]
     pub async fn request_new_settlement(
         &self,
         certificate_id: Option<CertificateId>,
         job: SettlementJob,
+    ) -> eyre::Result<SettlementJobWatcher> {
+        match &self.batcher {
+            Some(batcher) if certificate_id.is_some() && SettlementBatcher::accepts(&job) => {
+                self.request_batched_settlement(batcher, certificate_id, job)
+                    .await
+            }
+            _ => self.spawn_new_settlement(certificate_id, job).await,
+        }
+    }
+
+    async fn spawn_new_settlement(
+        &self,
+        certificate_id: Option<CertificateId>,
+        job: SettlementJob,
     ) -> eyre::Result<SettlementJobWatcher> {
         let (task_control_handle, task_control) = TaskControlHandle::new(&self.cancellation_token);
         let (job_id, task) = SettlementTask::create(
@@ -848,6 +1092,8 @@ impl<
             self.provider.clone(),
             self.store.clone(),
             self.wallet_nonce_locks.clone(),
+            self.wallet_pool.clone(),
+            self.balance_monitor.clone(),
             task_control,
         )
         .await?;
@@ -860,6 +1106,162 @@ impl<
         })
     }
 
+    /// Persists a new settlement job and adds it to the open batch.
+    ///
+    /// The job is created like any other, so a job whose call reverts is
+    /// refused here rather than failing its whole batch.
+    async fn request_batched_settlement(
+        &self,
+        batcher: &Arc<SettlementBatcher>,
+        certificate_id: Option<CertificateId>,
+        job: SettlementJob,
+    ) -> eyre::Result<SettlementJobWatcher> {
+        let (task_control_handle, task_control) = TaskControlHandle::new(&self.cancellation_token);
+        let (job_id, task) = SettlementTask::create(
+            certificate_id,
+            job.clone(),
+            self.tx_config.clone(),
+            self.provider.clone(),
+            self.store.clone(),
+            self.wallet_nonce_locks.clone(),
+            self.wallet_pool.clone(),
+            self.balance_monitor.clone(),
+            task_control,
+        )
+        .await?;
+        let (outcome_sender, outcome_receiver) = oneshot::channel();
+        let result_receiver = self
+            .spawn_batched_settlement_task(job_id, task, task_control_handle, outcome_receiver)
+            .await;
+        self.enqueue_batch_member(
+            batcher,
+            BatchMember {
+                job_id,
+                job,
+                outcome: outcome_sender,
+            },
+        );
+        Ok(SettlementJobWatcher {
+            watcher: result_receiver,
+            job_id,
+        })
+    }
+
+    fn enqueue_batch_member(&self, batcher: &Arc<SettlementBatcher>, member: BatchMember) {
+        match batcher.enqueue(member) {
+            Enqueued::Opened { generation } => {
+                let this = self.clone();
+                let batcher = batcher.clone();
+                tokio::spawn(async move {
+                    // On shutdown, the open batch is dropped along with the
+                    // tasks of its jobs, which settle on their own once
+                    // resumed.
+                    tokio::select! {
+                        _ = this.cancellation_token.cancelled() => {}
+                        _ = tokio::time::sleep(batcher.window()) => {
+                            let members = batcher.close(generation);
+                            this.settle_batch(&batcher, members).await;
+                        }
+                    }
+                });
+            }
+            Enqueued::Joined => {}
+            Enqueued::Full(members) => {
+                let this = self.clone();
+                let batcher = batcher.clone();
+                tokio::spawn(async move { this.settle_batch(&batcher, members).await });
+            }
+        }
+    }
+
+    /// Settles the calls of `members` in one transaction, or each on its own
+    /// if the batch cannot be created.
+    async fn settle_batch(&self, batcher: &SettlementBatcher, mut members: Vec<BatchMember>) {
+        // Jobs whose task got aborted while the batch was open, such as the
+        // jobs cancelled by a network rollback, are left out.
+        members.retain(|member| !member.outcome.is_closed());
+        if members.len() < 2 {
+            for member in members {
+                _ = member.outcome.send(BatchOutcome::SettleIndividually);
+            }
+            return;
+        }
+
+        let batch_job = batcher.batch_job(&members);
+        let members: BatchMemberOutcomes = members
+            .into_iter()
+            .map(|member| (member.job_id, member.outcome))
+            .collect();
+        match self.spawn_new_settlement(None, batch_job).await {
+            Ok(watcher) => {
+                info!(
+                    batch_job_id = %watcher.job_id(),
+                    batched_jobs = members.len(),
+                    "Settling settlement jobs in one batch"
+                );
+                self.await_batch(watcher, members).await;
+            }
+            Err(error) => {
+                warn!(
+                    ?error,
+                    "Failed to create settlement batch; settling its jobs individually"
+                );
+                for (_, outcome) in members {
+                    _ = outcome.send(BatchOutcome::SettleIndividually);
+                }
+            }
+        }
+    }
+
+    /// Waits for the batch job of `watcher`, then hands its outcome to the
+    /// tasks of its jobs.
+    ///
+    /// The batch result is recorded as the result of each job it settled
+    /// before their tasks are told, so that a settled job never gets settled
+    /// again. Startup recovery completes that recording if the node stops in
+    /// between.
+    async fn await_batch(&self, mut watcher: SettlementJobWatcher, members: BatchMemberOutcomes) {
+        let batch_job_id = watcher.job_id();
+        let result = match watcher.wait_for_result().await {
+            Ok(result) => result,
+            // The batched jobs resume with their batch on the next startup.
+            Err(error) => {
+                warn!(
+                    ?error,
+                    %batch_job_id,
+                    "Settlement batch stopped before producing a result"
+                );
+                return;
+            }
+        };
+
+        match result.contract_call_result.outcome {
+            ContractCallOutcome::Success => {
+                for (job_id, outcome) in members {
+                    if let Err(error) = self.store.insert_settlement_job_result(&job_id, &result) {
+                        error!(
+                            ?error,
+                            %job_id,
+                            %batch_job_id,
+                            "Failed to record the batch result of a settlement job"
+                        );
+                    }
+                    _ = outcome.send(BatchOutcome::Settled(result.clone()));
+                }
+            }
+            ContractCallOutcome::Revert => {
+                warn!(
+                    %batch_job_id,
+                    tx_hash = %result.contract_call_result.tx_hash,
+                    "Settlement batch reverted; settling its jobs individually"
+                );
+                for (_, outcome) in members {
+                    _ = outcome.send(BatchOutcome::SettleIndividually);
+                }
+            }
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn retrieve_settlement_result(
         &self,
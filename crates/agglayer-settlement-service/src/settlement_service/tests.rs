@@ -152,6 +152,10 @@ fn mk_job(seed: u8) -> SettlementJob {
         calldata: vec![seed, seed.wrapping_add(1)].into(),
         eth_value: U256::from(seed),
         gas_limit: seed as u128 + 100_000,
+        network_id: None,
+        tx_type: agglayer_types::SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
 
@@ -171,6 +175,9 @@ fn expect_pending_job_load(store: &mut MockStateStore, job_id: SettlementJobId,
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -194,6 +201,7 @@ fn mk_result(seed: u8, outcome: ContractCallOutcome) -> SettlementJobResult {
             block_hash: B256::from([seed; 32]),
             block_number: seed as u64,
             tx_hash: SettlementTxHash::new(Digest::from([seed.wrapping_add(2); 32])),
+            gas_cost: None,
         },
     }
 }
@@ -326,6 +334,9 @@ async fn start_resumes_pending_job_with_task_controls_tied_to_service_cancellati
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -505,6 +516,10 @@ async fn retrieve_fails_for_unknown_job_id() {
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_get_settlement_job()
         .once()
@@ -532,6 +547,10 @@ async fn retrieve_fails_when_pending_job_has_no_running_task() {
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_get_settlement_job()
         .once()
@@ -591,6 +610,9 @@ async fn reload_and_restart_preserves_watcher_when_reload_finds_completed_job()
                 Ok(Some(completed_result_for_store.clone()))
             }
         });
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -614,13 +636,17 @@ async fn reload_and_restart_preserves_watcher_when_reload_finds_completed_job()
         service.provider.clone(),
         service.store.clone(),
         service.wallet_nonce_locks.clone(),
+        service.wallet_pool.clone(),
+        service.balance_monitor.clone(),
         task_control,
     )
     .await
     .expect("settlement task should load")
     {
         StoredSettlementJob::Pending(task) => task,
-        StoredSettlementJob::Completed(_) => panic!("initial load should be pending"),
+        StoredSettlementJob::Completed(_) | StoredSettlementJob::Cancelled => {
+            panic!("initial load should be pending")
+        }
     };
 
     let mut result_receiver = service
@@ -659,6 +685,9 @@ async fn reload_and_restart_replaces_live_task_when_reload_finds_pending_job() {
         .times(2)
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .returning(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .times(2)
@@ -693,7 +722,9 @@ async fn reload_and_restart_replaces_live_task_when_reload_finds_pending_job() {
         .expect("initial settlement task should load")
     {
         StoredSettlementJob::Pending(task) => task,
-        StoredSettlementJob::Completed(_) => panic!("initial load should be pending"),
+        StoredSettlementJob::Completed(_) | StoredSettlementJob::Cancelled => {
+            panic!("initial load should be pending")
+        }
     };
 
     let result_receiver = service
@@ -767,6 +798,9 @@ async fn reload_and_restart_load_failure_cleans_up_live_registrations() {
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -789,7 +823,9 @@ async fn reload_and_restart_load_failure_cleans_up_live_registrations() {
         .expect("initial settlement task should load")
     {
         StoredSettlementJob::Pending(task) => task,
-        StoredSettlementJob::Completed(_) => panic!("initial load should be pending"),
+        StoredSettlementJob::Completed(_) | StoredSettlementJob::Cancelled => {
+            panic!("initial load should be pending")
+        }
     };
 
     let mut result_receiver = service
@@ -976,6 +1012,9 @@ async fn admin_abort_pending_job_without_task_is_tagged_no_live_task() {
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
 
     let service = mk_service(Arc::new(store)).await;
 
@@ -1011,6 +1050,9 @@ async fn panicked_task_deregisters_control_and_abort_reports_no_live_task() {
         .times(2)
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .returning(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -1116,6 +1158,9 @@ async fn abort_then_reload_respawns_pending_job() {
         .times(3)
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .returning(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .times(2)
@@ -1309,6 +1354,9 @@ async fn reload_retries_after_closed_handle_teardown() {
         .times(2)
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .returning(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -1418,6 +1466,9 @@ async fn reload_load_failure_leaves_no_registrations() {
         .once()
         .withf(move |requested_job_id| requested_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
 
     let service = mk_service(Arc::new(store)).await;
     let (stale_sender, stale_watcher) = watch::channel(None);
@@ -1469,6 +1520,9 @@ async fn reload_completed_during_load_is_tagged_already_completed() {
                 }
             }
         });
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
 
     let service = mk_service(Arc::new(store)).await;
     let error = service
@@ -1708,6 +1762,7 @@ fn mk_resolved_attempt(seed: u8, tx_hash: SettlementTxHash) -> SettlementAttempt
         submission_time: std::time::SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 30,
         max_priority_fee_per_gas: 3,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -2530,4 +2585,5 @@ async fn live_job_count_tracks_the_task_registry() {
     assert_eq!(service.live_job_count(), 0);
 }
 
+mod batching;
 mod same_wallet_nonce_race;
@@ -0,0 +1,324 @@
+use agglayer_config::settlement_service::SettlementBatchingConfig;
+use agglayer_contracts::contracts::IMulticall3;
+use alloy::sol_types::SolCall as _;
+
+use super::*;
+
+fn mk_batching_config(max_batch_size: usize) -> SettlementServiceConfig {
+    SettlementServiceConfig {
+        batching: Some(SettlementBatchingConfig {
+            batcher_address: agglayer_types::Address::from([0xBA; 20]),
+            window: Duration::from_secs(3600),
+            max_batch_size,
+        }),
+        ..SettlementServiceConfig::default()
+    }
+}
+
+fn mk_batchable_job(seed: u8) -> SettlementJob {
+    SettlementJob {
+        eth_value: U256::ZERO,
+        ..mk_job(seed)
+    }
+}
+
+fn mk_batch_job(batched_jobs: Vec<SettlementJobId>) -> SettlementJob {
+    SettlementJob {
+        contract_address: agglayer_types::Address::from([0xBA; 20]),
+        batched_jobs,
+        ..mk_batchable_job(0xBA)
+    }
+}
+
+fn mk_batch_watcher(
+    job_id: SettlementJobId,
+) -> (
+    SettlementJobWatcher,
+    watch::Sender<Option<SettlementJobResult>>,
+) {
+    let (result_sender, watcher) = watch::channel(None);
+    (SettlementJobWatcher { watcher, job_id }, result_sender)
+}
+
+fn expect_job_load(
+    store: &mut MockStateStore,
+    job_id: SettlementJobId,
+    job: SettlementJob,
+    result: Option<SettlementJobResult>,
+) {
+    let pending = result.is_none();
+    store
+        .expect_get_settlement_job()
+        .once()
+        .withf(move |requested_job_id| requested_job_id == &job_id)
+        .return_once(move |_| Ok(Some(job)));
+    store
+        .expect_get_settlement_job_result()
+        .once()
+        .withf(move |requested_job_id| requested_job_id == &job_id)
+        .return_once(move |_| Ok(result));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
+    if pending {
+        let attempt = mk_resolved_attempt(1, SettlementTxHash::new(Digest::from([0xAB; 32])));
+        store
+            .expect_list_settlement_attempt_results()
+            .once()
+            .withf(move |requested_job_id| requested_job_id == &job_id)
+            .return_once(|_| Ok(Vec::new()));
+        store
+            .expect_list_settlement_attempts()
+            .once()
+            .withf(move |requested_job_id| requested_job_id == &job_id)
+            .return_once(move |_| Ok(vec![(0, attempt)]));
+    }
+}
+
+#[tokio::test]
+async fn certificate_settlements_are_batched_into_one_aggregate3_call() {
+    let mut store = MockStateStore::new();
+    expect_empty_startup_recovery(&mut store);
+    store
+        .expect_insert_settlement_job_with_certificate()
+        .times(2)
+        .returning(|_, _, _| Ok(()));
+    let recorded_batch_job = Arc::new(Mutex::new(None));
+    store.expect_insert_settlement_job().once().return_once({
+        let recorded_batch_job = recorded_batch_job.clone();
+        move |_, job| {
+            *recorded_batch_job.lock().unwrap() = Some(job.clone());
+            Ok(())
+        }
+    });
+
+    // One gas estimate for each batched job, and one for the batch.
+    let asserter = Asserter::new();
+    for _ in 0..3 {
+        asserter.push_success(&U64::from(200_000));
+    }
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::from(
+            PrivateKeySigner::from_slice(&[0x11; 32]).expect("valid test signing key"),
+        ))
+        .connect_mocked_client(asserter);
+    let cancellation_token = CancellationToken::new();
+    let service = SettlementService::start(
+        mk_batching_config(2),
+        Arc::new(SettlementTransactionConfig::default()),
+        Arc::new(provider),
+        Arc::new(store),
+        cancellation_token.clone(),
+    )
+    .await
+    .expect("settlement service should start")
+    .0;
+
+    let jobs = [mk_batchable_job(1), mk_batchable_job(2)];
+    let mut job_ids = Vec::new();
+    for (seed, job) in [7, 8].into_iter().zip(jobs.clone()) {
+        let watcher = service
+            .request_new_settlement(Some(CertificateId::new(Digest::from([seed; 32]))), job)
+            .await
+            .expect("settlement request should be accepted");
+        job_ids.push(watcher.job_id());
+    }
+    wait_until(|| recorded_batch_job.lock().unwrap().is_some()).await;
+    cancellation_token.cancel();
+
+    let batch_job = recorded_batch_job.lock().unwrap().take().unwrap();
+    assert_eq!(batch_job.batched_jobs, job_ids);
+    assert_eq!(
+        batch_job.contract_address,
+        agglayer_types::Address::from([0xBA; 20])
+    );
+    let calls = IMulticall3::aggregate3Call::abi_decode(&batch_job.calldata)
+        .expect("batch calldata must be an aggregate3 call")
+        .calls;
+    assert_eq!(calls.len(), jobs.len());
+    for (call, job) in calls.iter().zip(&jobs) {
+        assert_eq!(call.target, job.contract_address.into_alloy());
+        assert_eq!(call.callData, job.calldata);
+    }
+}
+
+#[tokio::test]
+async fn successful_batch_records_its_result_for_each_job() {
+    let mut store = MockStateStore::new();
+    expect_empty_startup_recovery(&mut store);
+    let job_ids = [mk_job_id(1), mk_job_id(2)];
+    let batch_result = mk_result(3, ContractCallOutcome::Success);
+    for job_id in job_ids {
+        let expected_result = batch_result.clone();
+        store
+            .expect_insert_settlement_job_result()
+            .once()
+            .withf(move |recorded_job_id, result| {
+                recorded_job_id == &job_id && result == &expected_result
+            })
+            .returning(|_, _| Ok(()));
+    }
+    let service = mk_service(Arc::new(store)).await;
+
+    let (watcher, result_sender) = mk_batch_watcher(mk_job_id(3));
+    let mut outcomes = Vec::new();
+    let mut members = Vec::new();
+    for job_id in job_ids {
+        let (outcome_sender, outcome_receiver) = oneshot::channel();
+        members.push((job_id, outcome_sender));
+        outcomes.push(outcome_receiver);
+    }
+    result_sender.send(Some(batch_result.clone())).unwrap();
+    service.await_batch(watcher, members).await;
+
+    for outcome in outcomes {
+        match outcome.await.expect("the batch must hand out its outcome") {
+            BatchOutcome::Settled(result) => assert_eq!(result, batch_result),
+            outcome => panic!("expected the batch to settle the job, got {outcome:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn reverted_batch_settles_its_jobs_individually() {
+    let mut store = MockStateStore::new();
+    expect_empty_startup_recovery(&mut store);
+    store.expect_insert_settlement_job_result().never();
+    let service = mk_service(Arc::new(store)).await;
+
+    let (watcher, result_sender) = mk_batch_watcher(mk_job_id(3));
+    let (outcome_sender, outcome_receiver) = oneshot::channel();
+    result_sender
+        .send(Some(mk_result(3, ContractCallOutcome::Revert)))
+        .unwrap();
+    service
+        .await_batch(watcher, vec![(mk_job_id(1), outcome_sender)])
+        .await;
+
+    assert!(matches!(
+        outcome_receiver.await,
+        Ok(BatchOutcome::SettleIndividually)
+    ));
+}
+
+#[tokio::test]
+async fn batched_job_settles_alone_when_its_batch_does_not_settle_it() {
+    let mut store = MockStateStore::new();
+    expect_empty_startup_recovery(&mut store);
+    let job_id = mk_job_id(10);
+    expect_job_load(&mut store, job_id, mk_batchable_job(10), None);
+
+    let cancellation_token = CancellationToken::new();
+    let (provider, request_count) = mk_parked_provider();
+    let service = SettlementService::start(
+        SettlementServiceConfig::default(),
+        Arc::new(SettlementTransactionConfig::default()),
+        Arc::new(provider),
+        Arc::new(store),
+        cancellation_token.clone(),
+    )
+    .await
+    .expect("settlement service should start")
+    .0;
+    let (task_control_handle, task_control) = TaskControlHandle::new(&cancellation_token);
+    let StoredSettlementJob::Pending(task) = service
+        .load_stored_job(job_id, task_control)
+        .await
+        .expect("settlement task should load")
+    else {
+        panic!("the job should be pending");
+    };
+    let (outcome_sender, outcome_receiver) = oneshot::channel();
+    service
+        .spawn_batched_settlement_task(job_id, task, task_control_handle, outcome_receiver)
+        .await;
+
+    // The task leaves L1 alone while it waits for its batch.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(request_count.load(Ordering::SeqCst), 0);
+    assert!(service.has_live_task(job_id));
+
+    outcome_sender
+        .send(BatchOutcome::SettleIndividually)
+        .unwrap();
+    wait_until_l1_request_is_parked(&request_count).await;
+
+    cancellation_token.cancel();
+    wait_until(|| !service.has_live_task(job_id)).await;
+}
+
+#[tokio::test]
+async fn start_records_batch_result_of_jobs_settled_before_a_restart() {
+    let mut store = MockStateStore::new();
+    let batch_job_id = mk_job_id(1);
+    let job_id = mk_job_id(2);
+    let batch_result = mk_result(1, ContractCallOutcome::Success);
+    store
+        .expect_list_settlement_job_ids()
+        .once()
+        .return_once(move || Ok(vec![batch_job_id, job_id]));
+    expect_job_load(
+        &mut store,
+        batch_job_id,
+        mk_batch_job(vec![job_id]),
+        Some(batch_result.clone()),
+    );
+    expect_job_load(&mut store, job_id, mk_batchable_job(2), None);
+    store
+        .expect_insert_settlement_job_result()
+        .once()
+        .withf(move |recorded_job_id, result| recorded_job_id == &job_id && result == &batch_result)
+        .returning(|_, _| Ok(()));
+
+    let (provider, request_count) = mk_parked_provider();
+    let (service, recovery_skipped_jobs) = SettlementService::start(
+        SettlementServiceConfig::default(),
+        Arc::new(SettlementTransactionConfig::default()),
+        Arc::new(provider),
+        Arc::new(store),
+        CancellationToken::new(),
+    )
+    .await
+    .expect("settlement service should start");
+
+    assert_eq!(recovery_skipped_jobs, 0);
+    assert_eq!(service.live_job_count(), 0);
+    assert!(service.result_watchers.lock().await.is_empty());
+    assert_eq!(request_count.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn start_reattaches_batched_jobs_to_their_pending_batch() {
+    let mut store = MockStateStore::new();
+    let batch_job_id = mk_job_id(1);
+    let job_id = mk_job_id(2);
+    store
+        .expect_list_settlement_job_ids()
+        .once()
+        .return_once(move || Ok(vec![batch_job_id, job_id]));
+    expect_job_load(&mut store, batch_job_id, mk_batch_job(vec![job_id]), None);
+    expect_job_load(&mut store, job_id, mk_batchable_job(2), None);
+
+    let cancellation_token = CancellationToken::new();
+    let (provider, request_count) = mk_parked_provider();
+    let service = SettlementService::start(
+        SettlementServiceConfig::default(),
+        Arc::new(SettlementTransactionConfig::default()),
+        Arc::new(provider),
+        Arc::new(store),
+        cancellation_token.clone(),
+    )
+    .await
+    .expect("settlement service should start")
+    .0;
+
+    assert!(service.has_live_task(batch_job_id));
+    assert!(service.has_live_task(job_id));
+    // Only the batch goes to L1, the batched job waits for it.
+    wait_until_l1_request_is_parked(&request_count).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+    cancellation_token.cancel();
+    wait_until(|| service.live_job_count() == 0).await;
+}
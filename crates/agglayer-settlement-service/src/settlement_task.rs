@@ -6,27 +6,36 @@ use std::{
 };
 
 use agglayer_config::{
-    settlement_service::{SettlementPolicy, SettlementTransactionConfig},
+    settlement_service::{GasOracleConfig, SettlementPolicy, SettlementTransactionConfig},
     Multiplier,
 };
+use agglayer_contracts::{
+    gas_oracle::{
+        AnyGasOracle, FeeHistoryGasOracle, FixedGasOracle, GasOracle as _, ProviderGasOracle,
+    },
+    settler::decode_contract_revert,
+};
 use agglayer_storage::stores::{SettlementReader, SettlementWriter};
 use agglayer_telemetry::settlement::{
-    record_settlement_attempt, record_settlement_attempt_error, record_settlement_job_duration,
-    SettlementAttemptErrorKind, SettlementAttemptKind, SettlementJobOutcome,
+    record_settlement_attempt, record_settlement_attempt_error, record_settlement_gas_spent,
+    record_settlement_job_duration, SettlementAttemptErrorKind, SettlementAttemptKind,
+    SettlementJobOutcome,
 };
 use agglayer_types::{
     CertificateId, ClientError, ClientErrorType, ContractCallOutcome, ContractCallResult, Digest,
-    Nonce, SettlementAttempt, SettlementAttemptNumber, SettlementAttemptResult, SettlementJob,
-    SettlementJobId, SettlementJobResult, SettlementTxHash,
+    GasOracleInputs, NetworkId, Nonce, SettlementAttempt, SettlementAttemptNumber,
+    SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementJobResult, SettlementTxHash,
+    SettlementTxType,
 };
 use alloy::{
     consensus::{BlockHeader as _, EthereumTxEnvelope, Transaction as _, TxEip4844Variant},
-    eips::{eip1559::Eip1559Estimation, eip2718::Encodable2718 as _, BlockNumberOrTag},
+    contract::Error as ContractError,
+    eips::{eip1559::Eip1559Estimation, eip2718::Encodable2718 as _, BlockId, BlockNumberOrTag},
     network::{
         BlockResponse as _, Ethereum, ReceiptResponse as _, TransactionBuilder as _,
         TransactionBuilderError,
     },
-    primitives::{Address, TxHash},
+    primitives::{Address, Bytes, TxHash, B256},
     providers::{Provider, WalletProvider},
     rpc::types::TransactionRequest,
     transports::{TransportError, TransportErrorKind},
@@ -34,9 +43,14 @@ use alloy::{
 use eyre::Context as _;
 use tokio::sync::{mpsc, OwnedMutexGuard};
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, warn};
 
-use crate::{utils::RetryCallbackError, wallet_nonce_locks::WalletNonceLocks};
+use crate::{
+    utils::RetryCallbackError,
+    wallet_balances::WalletBalanceMonitor,
+    wallet_nonce_locks::WalletNonceLocks,
+    wallet_pool::{WalletLease, WalletPool},
+};
 
 type TxEnvelope = EthereumTxEnvelope<TxEip4844Variant>;
 
@@ -48,6 +62,34 @@ struct GasParams {
     max_priority_fee_per_gas: u128,
 }
 
+/// Gas oracle selected by the configuration.
+fn gas_oracle(config: &GasOracleConfig) -> AnyGasOracle {
+    match *config {
+        GasOracleConfig::Provider => AnyGasOracle::Provider(ProviderGasOracle),
+        GasOracleConfig::FeeHistory {
+            block_count,
+            reward_percentile,
+        } => AnyGasOracle::FeeHistory(FeeHistoryGasOracle {
+            block_count,
+            reward_percentile,
+        }),
+        GasOracleConfig::Fixed {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        } => AnyGasOracle::Fixed(FixedGasOracle {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        }),
+    }
+}
+
+fn oracle_estimate(inputs: &GasOracleInputs) -> Eip1559Estimation {
+    Eip1559Estimation {
+        max_fee_per_gas: inputs.max_fee_per_gas,
+        max_priority_fee_per_gas: inputs.max_priority_fee_per_gas,
+    }
+}
+
 /// Clamps `value` into `[floor, ceiling]`. When `floor > ceiling` the ceiling
 /// wins (unlike [`Ord::clamp`], this never panics on an inverted range).
 fn clamp_u128(value: u128, floor: u128, ceiling: u128) -> u128 {
@@ -231,6 +273,8 @@ struct NonRecoverableError {
 pub enum StoredSettlementJob<L1Provider, SettlementStore> {
     Pending(SettlementTask<L1Provider, SettlementStore>),
     Completed(SettlementJobResult),
+    /// Cancelled by a network rollback before completing.
+    Cancelled,
 }
 
 /// Hydrated pending settlement job that has not yet been bound to runtime
@@ -243,10 +287,16 @@ pub(crate) struct PendingSettlementJob<L1Provider, SettlementStore> {
     provider: Arc<L1Provider>,
     store: Arc<SettlementStore>,
     wallet_nonce_locks: Arc<WalletNonceLocks>,
+    wallet_pool: Arc<WalletPool>,
+    balance_monitor: Arc<WalletBalanceMonitor>,
     attempts: ActiveSettlementAttempts,
 }
 
 impl<L1Provider, SettlementStore> PendingSettlementJob<L1Provider, SettlementStore> {
+    pub(crate) fn job(&self) -> &SettlementJob {
+        &self.job
+    }
+
     pub(crate) fn into_task(
         self,
         control: TaskControl,
@@ -258,6 +308,9 @@ impl<L1Provider, SettlementStore> PendingSettlementJob<L1Provider, SettlementSto
             provider: self.provider,
             store: self.store,
             wallet_nonce_locks: self.wallet_nonce_locks,
+            wallet_pool: self.wallet_pool,
+            wallet_lease: None,
+            balance_monitor: self.balance_monitor,
             control,
             attempts: self.attempts,
         }
@@ -266,7 +319,12 @@ impl<L1Provider, SettlementStore> PendingSettlementJob<L1Provider, SettlementSto
 
 pub(crate) enum RecoveredSettlementJob<L1Provider, SettlementStore> {
     Pending(PendingSettlementJob<L1Provider, SettlementStore>),
-    Completed(SettlementJobResult),
+    Completed {
+        job: SettlementJob,
+        result: SettlementJobResult,
+    },
+    /// Cancelled by a network rollback, never to be resumed.
+    Cancelled,
 }
 
 #[derive(Debug)]
@@ -312,6 +370,11 @@ impl TaskControlHandle {
         self.cancellation_token.is_cancelled()
     }
 
+    /// Completes once cancellation has been requested for this task.
+    pub async fn cancelled(&self) {
+        self.cancellation_token.cancelled().await
+    }
+
     /// Queues an admin command for the task. This does not interrupt a wait
     /// in progress: the task drains the queue only at its run-loop control
     /// checks, unlike [`cancel`](Self::cancel) which its waits select on.
@@ -329,6 +392,18 @@ pub enum SettlementTaskRunResult {
     ReloadAndRestart,
 }
 
+/// Outcome of the pre-flight `eth_call` simulation of a settlement attempt.
+enum SimulationOutcome {
+    /// The call succeeded, or could not be simulated: broadcast the attempt.
+    Passed,
+    /// The call reverted: broadcasting the attempt would only burn gas.
+    Reverted {
+        reason: String,
+        revert_data: Bytes,
+    },
+    Cancelled,
+}
+
 enum TaskControlAction {
     Cancelled,
     ReloadAndRestart,
@@ -396,6 +471,16 @@ pub struct SettlementTask<L1Provider, SettlementStore> {
     /// nonce read-to-save window in [`Self::run`].
     /// XREF: https://github.com/agglayer/agglayer/issues/1597
     wallet_nonce_locks: Arc<WalletNonceLocks>,
+    /// Shared settlement wallet pool from
+    /// [`SettlementService`](crate::SettlementService).
+    wallet_pool: Arc<WalletPool>,
+    /// Lease on the wallet of the job's current nonce lane, taken on first
+    /// use. `None` until then, and for as long as the pool is empty.
+    wallet_lease: Option<WalletLease>,
+    /// Shared settlement wallet balances from
+    /// [`SettlementService`](crate::SettlementService); attempts from a wallet
+    /// below the minimum balance are refused.
+    balance_monitor: Arc<WalletBalanceMonitor>,
     control: TaskControl,
     attempts: ActiveSettlementAttempts,
 }
@@ -404,12 +489,68 @@ static ID_GENERATOR: OnceLock<std::sync::Mutex<ulid::Generator>> = OnceLock::new
 
 /// The settlement call without nonce, gas, or fees — shared by gas estimation
 /// and the final attempt build.
+///
+/// The job's envelope and access list are part of the call, so that the gas
+/// estimate accounts for them and the built attempt has the requested type.
 fn settlement_call_request(job: &SettlementJob, wallet: Address) -> TransactionRequest {
-    TransactionRequest::default()
+    let mut request = TransactionRequest::default()
         .from(wallet)
         .to(job.contract_address.into_alloy())
         .value(job.eth_value)
-        .input(job.calldata.clone().into())
+        .input(job.calldata.clone().into());
+    request.access_list = job.access_list.clone();
+    match &job.tx_type {
+        SettlementTxType::Eip1559 => {}
+        SettlementTxType::Eip7702 { authorization_list } => {
+            request.authorization_list = Some(authorization_list.clone());
+        }
+    }
+    request
+}
+
+/// Precompute the access list of the settlement call with
+/// `eth_createAccessList` when configured, unless the job already has one.
+///
+/// This runs before gas estimation, so the estimate accounts for the access
+/// list. The access list is an optimization only: a failure to compute it is
+/// logged and the job settles without one.
+async fn resolve_settlement_access_list<P: Provider + WalletProvider>(
+    provider: &P,
+    wallet: Address,
+    tx_config: &SettlementTransactionConfig,
+    mut job: SettlementJob,
+    cancellation_token: &CancellationToken,
+) -> eyre::Result<SettlementJob> {
+    if !tx_config.precompute_access_list || job.access_list.is_some() {
+        return Ok(job);
+    }
+
+    let request = settlement_call_request(&job, wallet);
+    let result = crate::utils::retry_alloy_callback_until_success(
+        &tx_config.retry_on_transient_failure,
+        cancellation_token,
+        || provider.create_access_list(&request).into_future(),
+    )
+    .await;
+
+    match result {
+        Ok(result) => match result.error {
+            Some(error) => {
+                warn!(%error, "Settlement call fails with its access list; settling without one")
+            }
+            None => job.access_list = Some(result.access_list),
+        },
+        Err(RetryCallbackError::Cancelled) => {
+            eyre::bail!("settlement access list computation cancelled before completion")
+        }
+        Err(RetryCallbackError::Error(error)) => {
+            warn!(
+                ?error,
+                "Failed to precompute settlement access list; settling without one"
+            )
+        }
+    }
+    Ok(job)
 }
 
 /// Resolve the gas limit (`min(multiplier × estimateGas, ceiling)`) before the
@@ -418,11 +559,11 @@ fn settlement_call_request(job: &SettlementJob, wallet: Address) -> TransactionR
 /// Transient RPC failures retry; deterministic ones propagate.
 async fn resolve_settlement_gas_limit<P: Provider + WalletProvider>(
     provider: &P,
+    wallet: Address,
     tx_config: &SettlementTransactionConfig,
     mut job: SettlementJob,
     cancellation_token: &CancellationToken,
 ) -> eyre::Result<SettlementJob> {
-    let wallet = provider.default_signer_address();
     let request = settlement_call_request(&job, wallet);
 
     let gas_estimate = crate::utils::retry_alloy_callback_until_success(
@@ -459,10 +600,25 @@ impl<
         provider: Arc<L1Provider>,
         store: Arc<SettlementStore>,
         wallet_nonce_locks: Arc<WalletNonceLocks>,
+        wallet_pool: Arc<WalletPool>,
+        balance_monitor: Arc<WalletBalanceMonitor>,
         control: TaskControl,
     ) -> eyre::Result<(SettlementJobId, Self)> {
+        let wallet_lease = wallet_pool.assign(job.network_id);
+        let wallet = wallet_lease
+            .as_ref()
+            .map_or_else(|| provider.default_signer_address(), WalletLease::wallet);
+        let job = resolve_settlement_access_list(
+            provider.as_ref(),
+            wallet,
+            tx_config.as_ref(),
+            job,
+            &control.cancellation_token,
+        )
+        .await?;
         let job = resolve_settlement_gas_limit(
             provider.as_ref(),
+            wallet,
             tx_config.as_ref(),
             job,
             &control.cancellation_token,
@@ -486,6 +642,9 @@ impl<
             provider,
             store,
             wallet_nonce_locks,
+            wallet_pool,
+            wallet_lease,
+            balance_monitor,
             control,
             attempts: BTreeMap::new(),
         };
@@ -512,14 +671,28 @@ impl<
         provider: Arc<L1Provider>,
         store: Arc<SettlementStore>,
         wallet_nonce_locks: Arc<WalletNonceLocks>,
+        wallet_pool: Arc<WalletPool>,
+        balance_monitor: Arc<WalletBalanceMonitor>,
         control: TaskControl,
     ) -> eyre::Result<StoredSettlementJob<L1Provider, SettlementStore>> {
-        match Self::recover_from_storage(id, tx_config, provider, store, wallet_nonce_locks).await?
+        match Self::recover_from_storage(
+            id,
+            tx_config,
+            provider,
+            store,
+            wallet_nonce_locks,
+            wallet_pool,
+            balance_monitor,
+        )
+        .await?
         {
             RecoveredSettlementJob::Pending(pending) => {
                 Ok(StoredSettlementJob::Pending(pending.into_task(control)))
             }
-            RecoveredSettlementJob::Completed(result) => Ok(StoredSettlementJob::Completed(result)),
+            RecoveredSettlementJob::Completed { result, .. } => {
+                Ok(StoredSettlementJob::Completed(result))
+            }
+            RecoveredSettlementJob::Cancelled => Ok(StoredSettlementJob::Cancelled),
         }
     }
 
@@ -532,9 +705,14 @@ impl<
         provider: Arc<L1Provider>,
         store: Arc<SettlementStore>,
         wallet_nonce_locks: Arc<WalletNonceLocks>,
+        wallet_pool: Arc<WalletPool>,
+        balance_monitor: Arc<WalletBalanceMonitor>,
     ) -> eyre::Result<RecoveredSettlementJob<L1Provider, SettlementStore>> {
         match Self::load_settlement_job_from_db(store.as_ref(), id).await? {
-            (_job, Some(result)) => Ok(RecoveredSettlementJob::Completed(result)),
+            (job, Some(result)) => Ok(RecoveredSettlementJob::Completed { job, result }),
+            (_, None) if Self::is_cancelled(store.as_ref(), id)? => {
+                Ok(RecoveredSettlementJob::Cancelled)
+            }
             (job, None) => {
                 let attempts = Self::load_settlement_attempts_from_store(store.as_ref(), id)?;
                 Ok(RecoveredSettlementJob::Pending(PendingSettlementJob {
@@ -544,6 +722,8 @@ impl<
                     provider,
                     store,
                     wallet_nonce_locks,
+                    wallet_pool,
+                    balance_monitor,
                     attempts,
                 }))
             }
@@ -575,6 +755,9 @@ impl<
             };
         }
 
+        // The wallets this task currently refuses to build attempts from, so
+        // that the refusal is only reported when it starts and stops.
+        let mut underfunded_wallets = BTreeSet::new();
         'start: loop {
             if let Some(run_result) = self.try_handle_control_action() {
                 return run_result;
@@ -582,6 +765,7 @@ impl<
 
             // Process in a big loop. We'll come back here whenever a reorg is detected, and
             // after waiting when we're done with one cycle.
+            let current_wallet = self.settlement_wallet();
 
             // First, for each nonce we know of, identify whether it is done or whether we
             // need to submit more txes for it. For this, we'll keep a list of
@@ -592,6 +776,8 @@ impl<
             let mut not_included_on_l1 = BTreeSet::new();
             let mut all_nonces_seen_on_l1 = true;
             let mut need_to_submit_attempt_with_new_nonce = true;
+            let mut current_wallet_stuck = false;
+            let mut waiting_for_funds = false;
             'nonces: for (wallet, nonce) in self.all_used_nonces() {
                 if let Some(run_result) = self.try_handle_control_action() {
                     return run_result;
@@ -646,8 +832,12 @@ impl<
                     }
                     // This nonce is not included yet and we still know the privkey, so we won't
                     // need to submit an attempt with a new nonce, regardless of whether we
-                    // resubmit.
-                    need_to_submit_attempt_with_new_nonce = false;
+                    // resubmit. Nonces left behind on a wallet the job failed over from are
+                    // still resubmitted, but only the current wallet's lane decides this.
+                    if wallet == current_wallet {
+                        need_to_submit_attempt_with_new_nonce = false;
+                        current_wallet_stuck |= self.is_nonce_stuck(wallet, nonce);
+                    }
                     if self.is_any_attempt_pending_for_nonce(wallet, nonce) {
                         // At least one attempt is not in-error yet, so we'll need to wait for the
                         // previous nonce to be included before processing it further.
@@ -667,7 +857,11 @@ impl<
                     if deadline > SystemTime::now() {
                         continue 'nonces; // wait for deadline to be reached
                     }
-                    let Some((attempt_number, attempt_kind, tx)) = retry!(
+                    if self.is_wallet_underfunded(wallet, &mut underfunded_wallets) {
+                        waiting_for_funds = true;
+                        continue 'nonces; // wait for the wallet to be topped up
+                    }
+                    let Some((attempt_number, attempt_kind, tx, gas_oracle_inputs)) = retry!(
                         self.build_next_attempt_with_nonce(wallet, nonce).await,
                         "building next settlement attempt for wallet {wallet} / nonce {nonce}",
                     ) else {
@@ -682,6 +876,7 @@ impl<
                             attempt_number,
                             attempt_kind,
                             tx,
+                            Some(gas_oracle_inputs),
                         )
                         .await
                     {
@@ -757,60 +952,90 @@ impl<
             // There was no successful attempt, and either at least one nonce was not yet
             // seen on L1 or there is no reverting attempt. So we need to wait
             // for more nonces to be seen on L1.
+            if current_wallet_stuck && self.fail_over_to_another_wallet() {
+                // The current wallet's lane has been stuck for too long: continue on a fresh
+                // lane. The stuck nonces are still tracked above, so whichever attempt lands
+                // first settles the job, and the rollup manager rejects the later ones since
+                // they no longer extend the settled state.
+                need_to_submit_attempt_with_new_nonce = true;
+            }
             if need_to_submit_attempt_with_new_nonce {
                 // There was no attempt that was pending or that received a retry in the
                 // `'nonces` loop above. This means that either all nonces were
                 // used externally, or that we no longer have the required wallets to bump
                 // pending nonces. So we need to submit a new attempt with a new
-                // nonce.
+                // nonce, unless the wallet is underfunded.
                 //
                 // Hold the wallet's nonce lock from before the nonce is read
                 // until the attempt is saved, so no other same-wallet task
                 // can pick the same nonce in that window; XREF:
                 // https://github.com/agglayer/agglayer/issues/1597.
-                let locked_wallet = self.provider.default_signer_address();
-                // Race the lock wait against cancellation: the holder may be
-                // stuck in transient L1 retries, and an aborted task must not
-                // stay parked in the lock queue until the holder releases.
-                let nonce_guard = tokio::select! {
-                    biased;
-                    _ = self.control.cancellation_token.cancelled() => {
-                        return SettlementTaskRunResult::Cancelled;
+                let locked_wallet = self.settlement_wallet();
+                if self.is_wallet_underfunded(locked_wallet, &mut underfunded_wallets) {
+                    waiting_for_funds = true;
+                } else {
+                    // Race the lock wait against cancellation: the holder may be
+                    // stuck in transient L1 retries, and an aborted task must not
+                    // stay parked in the lock queue until the holder releases.
+                    let nonce_guard = tokio::select! {
+                        biased;
+                        _ = self.control.cancellation_token.cancelled() => {
+                            return SettlementTaskRunResult::Cancelled;
+                        }
+                        guard = self.wallet_nonce_locks.lock(locked_wallet) => guard,
+                    };
+                    let (wallet, nonce, attempt_number, tx, gas_oracle_inputs) = retry!(
+                        self.build_next_attempt_with_new_nonce(locked_wallet).await,
+                        "building next settlement attempt with a new nonce",
+                    );
+                    debug_assert_eq!(wallet, locked_wallet);
+                    not_included_on_l1.insert((wallet, nonce));
+                    if let Some(run_result) = self
+                        .save_attempt_to_db_and_submit_to_l1(
+                            Some(nonce_guard),
+                            wallet,
+                            nonce,
+                            attempt_number,
+                            SettlementAttemptKind::Submission,
+                            tx,
+                            Some(gas_oracle_inputs),
+                        )
+                        .await
+                    {
+                        return run_result;
                     }
-                    guard = self.wallet_nonce_locks.lock(locked_wallet) => guard,
-                };
-                let (wallet, nonce, attempt_number, tx) = retry!(
-                    self.build_next_attempt_with_new_nonce().await,
-                    "building next settlement attempt with a new nonce",
-                );
-                // The build derives its wallet the same way; if wallet
-                // selection ever becomes dynamic, the lock key must follow.
-                debug_assert_eq!(wallet, locked_wallet);
-                not_included_on_l1.insert((wallet, nonce));
-                if let Some(run_result) = self
-                    .save_attempt_to_db_and_submit_to_l1(
-                        Some(nonce_guard),
-                        wallet,
-                        nonce,
-                        attempt_number,
-                        SettlementAttemptKind::Submission,
-                        tx,
-                    )
-                    .await
-                {
-                    return run_result;
                 }
             }
-            // We now are sure we did at least one step to make things move forward. Wait
-            // for the next external event or for the next deadline.
-            let timeout = self
-                .next_overall_deadline()
-                .expect("There is at least one attempt but no deadline")
-                .duration_since(SystemTime::now())
+            // We now are sure we did at least one step to make things move forward, or
+            // that an underfunded wallet holds them back. Wait for the next external event
+            // or for the next deadline.
+            let now = SystemTime::now();
+            let next_deadline = self.next_overall_deadline();
+            let timeout_at = if waiting_for_funds {
+                // The refused attempts are already due, and a job refused its first
+                // attempt has no deadline at all: wait for the wallet to be topped up,
+                // polling at the transient retry interval in case the wake-up is missed.
+                let poll_at = now + self.tx_config.retry_on_transient_failure.max_interval;
+                next_deadline
+                    .filter(|deadline| *deadline > now)
+                    .map_or(poll_at, |deadline| deadline.min(poll_at))
+            } else {
+                next_deadline.expect("There is at least one attempt but no deadline")
+            };
+            let timeout = timeout_at
+                .duration_since(now)
                 .unwrap_or_else(|_| Duration::from_secs(0));
-            let _ =
-                tokio::time::timeout(timeout, self.wait_for_any_nonce_on_l1(&not_included_on_l1))
-                    .await;
+            let _ = tokio::time::timeout(timeout, async {
+                tokio::select! {
+                    _ = self.wait_for_any_nonce_on_l1(&not_included_on_l1),
+                        if !not_included_on_l1.is_empty() => {}
+                    // Attempts refused for a low balance are due as soon as their wallet is
+                    // topped up.
+                    _ = self.balance_monitor.funds_restored() => {}
+                    _ = self.control.cancellation_token.cancelled() => {}
+                }
+            })
+            .await;
         }
     }
 
@@ -846,13 +1071,25 @@ impl<
 
     /// Saves the attempt, then submits it to L1.
     ///
+    /// `gas_oracle_inputs` is the fee estimate `tx` was priced from, recorded
+    /// with the attempt for audit.
+    ///
     /// `nonce_guard` is the per-wallet nonce lock held since before the
     /// nonce was assigned; XREF:
-    /// https://github.com/agglayer/agglayer/issues/1597. It is dropped as
-    /// soon as the attempt is saved: from that point the nonce is visible to
-    /// other tasks through the store, and the L1 submission does not need to
-    /// block them. The retry path passes `None` because it reuses a nonce
-    /// this job already owns.
+    /// https://github.com/agglayer/agglayer/issues/1597. It is dropped once
+    /// the attempt is saved and, with `simulate_before_broadcast`, once its
+    /// simulation passed or its revert is recorded: from that point the nonce
+    /// is visible to other tasks through the store, and the L1 submission does
+    /// not need to block them. A nonce whose attempts all reverted in
+    /// simulation was never broadcast: the job releases it, and the next
+    /// same-wallet task reuses it. The retry path passes `None` because it
+    /// reuses a nonce this job already owns.
+    ///
+    /// With `simulate_before_broadcast`, the saved attempt is first run with
+    /// `eth_call` against the pending block. If it reverts, it is not
+    /// broadcast: it is recorded as a `SimulatedRevert` client error and, when
+    /// no other attempt of the job is still pending, the job completes as a
+    /// revert right away.
     ///
     /// Returns `Some(SettlementTaskRunResult::Cancelled)` when submission was
     /// interrupted by a shutdown, so the runner stops promptly while leaving
@@ -867,9 +1104,32 @@ impl<
         attempt_number: SettlementAttemptNumber,
         attempt_kind: SettlementAttemptKind,
         tx: TxEnvelope,
+        gas_oracle_inputs: Option<GasOracleInputs>,
     ) -> Option<SettlementTaskRunResult> {
-        self.save_attempt_to_db(wallet, nonce, attempt_number, &tx);
-        // The nonce is recorded; other same-wallet tasks may now read it.
+        self.save_attempt_to_db(wallet, nonce, attempt_number, &tx, gas_oracle_inputs);
+        if self.tx_config.simulate_before_broadcast {
+            // Keep the nonce lock through the simulation: should it revert, the
+            // nonce is never broadcast, and no other same-wallet task may have
+            // taken the next one in the meantime.
+            match self.simulate_attempt(wallet).await {
+                SimulationOutcome::Passed => {}
+                SimulationOutcome::Cancelled => return Some(SettlementTaskRunResult::Cancelled),
+                SimulationOutcome::Reverted {
+                    reason,
+                    revert_data,
+                } => {
+                    let run_result = self
+                        .record_simulated_revert(wallet, nonce, attempt_number, reason, revert_data)
+                        .await;
+                    // The revert is recorded; the nonce is free for other
+                    // same-wallet tasks again.
+                    drop(nonce_guard);
+                    return run_result;
+                }
+            }
+        }
+        // The nonce is recorded and going to L1; other same-wallet tasks may now
+        // read it.
         drop(nonce_guard);
         record_settlement_attempt(attempt_kind);
         match self.submit_attempt_to_l1(tx).await {
@@ -894,8 +1154,167 @@ impl<
         }
     }
 
+    /// Runs the settlement call of this job from `wallet` with `eth_call`
+    /// against the pending block.
+    ///
+    /// Only an execution revert stops the broadcast. Any other simulation
+    /// failure is logged and the attempt is broadcast as usual, so a node that
+    /// does not support the call cannot stall settlement.
+    async fn simulate_attempt(&self, wallet: Address) -> SimulationOutcome {
+        let request = settlement_call_request(&self.job, wallet)
+            .gas_limit(u64::try_from(self.job.gas_limit).unwrap_or(u64::MAX));
+        let simulation = crate::utils::retry_alloy_callback_until_success(
+            &self.tx_config.retry_on_transient_failure,
+            &self.control.cancellation_token,
+            || {
+                self.provider
+                    .call(request.clone())
+                    .block(BlockId::pending())
+                    .into_future()
+            },
+        )
+        .await;
+
+        match simulation {
+            Ok(_) => SimulationOutcome::Passed,
+            Err(RetryCallbackError::Cancelled) => SimulationOutcome::Cancelled,
+            Err(RetryCallbackError::Error(error)) => {
+                let revert_data = error.as_error_resp().and_then(|payload| {
+                    payload
+                        .as_revert_data()
+                        // Some nodes omit the data of a revert without reason.
+                        .or_else(|| (payload.code == 3).then(Bytes::new))
+                });
+                let Some(revert_data) = revert_data else {
+                    warn!(
+                        settlement_job_id = %self.id,
+                        ?error,
+                        "Failed to simulate settlement attempt; broadcasting it anyway"
+                    );
+                    return SimulationOutcome::Passed;
+                };
+                let reason = decode_contract_revert(&ContractError::TransportError(error))
+                    .unwrap_or_else(|| "unknown revert reason".to_string());
+                SimulationOutcome::Reverted {
+                    reason,
+                    revert_data,
+                }
+            }
+        }
+    }
+
+    /// Records that the attempt was not broadcast because its simulation
+    /// reverted, and completes the job as a revert unless another attempt of
+    /// the job may still land on L1.
+    async fn record_simulated_revert(
+        &mut self,
+        wallet: Address,
+        nonce: Nonce,
+        attempt_number: SettlementAttemptNumber,
+        reason: String,
+        revert_data: Bytes,
+    ) -> Option<SettlementTaskRunResult> {
+        warn!(
+            settlement_job_id = %self.id,
+            %wallet,
+            %nonce,
+            %reason,
+            "Settlement attempt reverted in pre-flight simulation; not broadcasting it"
+        );
+        record_settlement_attempt_error(
+            SettlementAttemptErrorKind::SimulatedRevert,
+            &wallet.to_string(),
+        );
+        self.write_client_error_to_db(attempt_number, ClientError::simulated_revert(&reason))
+            .await;
+
+        let any_attempt_pending = self
+            .all_used_nonces()
+            .into_iter()
+            .any(|(wallet, nonce)| self.is_any_attempt_pending_for_nonce(wallet, nonce));
+        if any_attempt_pending {
+            return None;
+        }
+
+        let tx_hash = self
+            .attempts
+            .get(&(wallet, nonce))
+            .and_then(|attempts_for_nonce| attempts_for_nonce.get(&attempt_number))
+            .expect("the simulated attempt was saved before its simulation")
+            .attempt
+            .hash;
+        // The attempt never reached L1: there is no block to point to.
+        let tx_result = ContractCallResult {
+            outcome: ContractCallOutcome::Revert,
+            metadata: revert_data,
+            block_hash: B256::ZERO,
+            block_number: 0,
+            tx_hash,
+            // Never broadcast, so nothing was paid.
+            gas_cost: None,
+        };
+        Some(SettlementTaskRunResult::Completed(
+            self.insert_job_result_to_db(wallet, nonce, attempt_number, tx_result),
+        ))
+    }
+
+    /// Whether `wallet` is below the minimum balance, in which case no attempt
+    /// is built from it: nothing is signed, assigned a nonce or saved until a
+    /// balance poll observes the wallet topped up.
+    ///
+    /// `underfunded_wallets` holds the wallets already refused, so that the
+    /// refusal is logged and counted once per pause rather than on every pass
+    /// of the run loop.
+    fn is_wallet_underfunded(
+        &self,
+        wallet: Address,
+        underfunded_wallets: &mut BTreeSet<Address>,
+    ) -> bool {
+        let Some(balance) = self.balance_monitor.low_balance(wallet) else {
+            if underfunded_wallets.remove(&wallet) {
+                info!(
+                    settlement_job_id = %self.id,
+                    %wallet,
+                    "Resuming settlement attempts: wallet topped up"
+                );
+            }
+            return false;
+        };
+        if underfunded_wallets.insert(wallet) {
+            warn!(
+                settlement_job_id = %self.id,
+                %wallet,
+                %balance,
+                min_wallet_balance = self.balance_monitor.min_wallet_balance(),
+                "Refusing settlement attempts: wallet balance below the minimum"
+            );
+            record_settlement_attempt_error(
+                SettlementAttemptErrorKind::LowBalance,
+                &wallet.to_string(),
+            );
+        }
+        true
+    }
+
+    /// The nonces the job holds. A nonce whose every attempt reverted in
+    /// simulation was never broadcast: it is released, as the store no longer
+    /// reserves it, and another same-wallet task may take it over.
     fn all_used_nonces(&self) -> BTreeSet<(Address, Nonce)> {
-        self.attempts.keys().cloned().collect()
+        self.attempts
+            .iter()
+            .filter(|(_, attempts_for_nonce)| {
+                !attempts_for_nonce.values().all(|attempt| {
+                    matches!(
+                        attempt.result,
+                        Some(SettlementAttemptResult::ClientError(ClientError {
+                            kind: ClientErrorType::SimulatedRevert,
+                            ..
+                        }))
+                    )
+                })
+            })
+            .map(|(&key, _)| key)
+            .collect()
     }
 
     fn all_attempt_keys(&self) -> Vec<(Address, Nonce, SettlementAttemptNumber)> {
@@ -973,10 +1392,86 @@ impl<
         self.provider.has_signer_for(&wallet)
     }
 
+    /// Returns the wallet whose nonce lane the job currently uses, leasing it
+    /// from the pool on first use.
+    ///
+    /// A job with recorded attempts stays on the wallet of its latest attempt
+    /// as long as its signer is still configured, so a restart does not move
+    /// it to another lane. With an empty pool, the job signs with the
+    /// provider's default signer.
+    fn settlement_wallet(&mut self) -> Address {
+        if let Some(lease) = &self.wallet_lease {
+            return lease.wallet();
+        }
+        let latest_attempt_wallet = self
+            .attempts
+            .iter()
+            .flat_map(|(&(wallet, _), attempts_for_nonce)| {
+                attempts_for_nonce
+                    .keys()
+                    .map(move |attempt_number| (*attempt_number, wallet))
+            })
+            .max()
+            .map(|(_, wallet)| wallet)
+            .filter(|wallet| self.is_wallet_privkey_known(*wallet));
+        self.wallet_lease = match latest_attempt_wallet {
+            Some(wallet) => Some(self.wallet_pool.lease(wallet)),
+            None => self.wallet_pool.assign(self.job.network_id),
+        };
+        self.wallet_lease.as_ref().map_or_else(
+            || self.provider.default_signer_address(),
+            WalletLease::wallet,
+        )
+    }
+
+    /// Whether `(wallet, nonce)` has been pending on L1 for longer than the
+    /// pool's failover delay, counting from its first attempt.
+    fn is_nonce_stuck(&self, wallet: Address, nonce: Nonce) -> bool {
+        self.failover_deadline_for_nonce(wallet, nonce)
+            .is_some_and(|deadline| deadline <= SystemTime::now())
+    }
+
+    fn failover_deadline_for_nonce(&self, wallet: Address, nonce: Nonce) -> Option<SystemTime> {
+        self.attempts
+            .get(&(wallet, nonce))?
+            .values()
+            .map(|attempt| attempt.attempt.submission_time)
+            .min()?
+            .checked_add(self.wallet_pool.failover_after())
+    }
+
+    /// Moves the job to a pool wallet it has not used yet. Returns `false`
+    /// when every pool wallet was already tried, in which case the job keeps
+    /// waiting on its current lane.
+    fn fail_over_to_another_wallet(&mut self) -> bool {
+        let used_wallets: BTreeSet<Address> = self
+            .attempts
+            .keys()
+            .map(|(wallet, _)| *wallet)
+            .chain(self.wallet_lease.as_ref().map(WalletLease::wallet))
+            .collect();
+        let Some(lease) = self
+            .wallet_pool
+            .fail_over(self.job.network_id, &used_wallets)
+        else {
+            return false;
+        };
+        warn!(
+            settlement_job_id = %self.id,
+            from = ?self.wallet_lease.as_ref().map(WalletLease::wallet),
+            to = %lease.wallet(),
+            "Settlement job stuck on its wallet; failing over to another settlement wallet"
+        );
+        self.wallet_lease = Some(lease);
+        true
+    }
+
     /// Returns when the next attempt for `(wallet, nonce)` is due: the most
     /// recent attempt's submission time plus exponential backoff (the fast
     /// transient policy after an RPC `ClientError`, else the slower
-    /// non-inclusion policy). Returns `now` when no attempt is tracked.
+    /// non-inclusion policy). Returns `now` when no attempt is tracked, or
+    /// when the most recent attempt was refused for a low balance and the
+    /// wallet has since been topped up.
     fn next_attempt_deadline_for_nonce(&self, wallet: Address, nonce: Nonce) -> SystemTime {
         let Some(attempts_for_nonce) = self.attempts.get(&(wallet, nonce)) else {
             return SystemTime::now();
@@ -984,7 +1479,6 @@ impl<
         let Some((_, last_attempt)) = attempts_for_nonce.last_key_value() else {
             return SystemTime::now();
         };
-
         // RPC-level failures retry on the fast transient policy; an attempt still
         // pending inclusion on L1 retries on the slow non-inclusion policy.
         let policy = match last_attempt.result {
@@ -1012,9 +1506,19 @@ impl<
     }
 
     fn next_overall_deadline(&self) -> Option<SystemTime> {
-        self.attempts
-            .keys()
-            .map(|(wallet, nonce)| self.next_attempt_deadline_for_nonce(*wallet, *nonce))
+        let current_wallet = self.wallet_lease.as_ref().map(WalletLease::wallet);
+        self.all_used_nonces()
+            .into_iter()
+            .flat_map(|(wallet, nonce)| {
+                // Also wake up when the current lane becomes eligible for failover. A
+                // deadline already past means that every pool wallet was already tried.
+                let failover_deadline = (Some(wallet) == current_wallet)
+                    .then(|| self.failover_deadline_for_nonce(wallet, nonce))
+                    .flatten()
+                    .filter(|deadline| *deadline > SystemTime::now());
+                std::iter::once(self.next_attempt_deadline_for_nonce(wallet, nonce))
+                    .chain(failover_deadline)
+            })
             .min()
     }
 
@@ -1319,6 +1823,13 @@ impl<
         }
     }
 
+    /// Reads the current fee estimate from the configured gas oracle.
+    async fn estimate_fees(&self) -> Result<GasOracleInputs, TransportError> {
+        gas_oracle(&self.tx_config.gas_oracle)
+            .estimate_fees(self.provider.as_ref())
+            .await
+    }
+
     fn resolve_base_gas_params(&self, estimate: &Eip1559Estimation) -> GasParams {
         let config = self.tx_config.as_ref();
 
@@ -1464,7 +1975,12 @@ impl<
         wallet: Address,
         nonce: Nonce,
     ) -> Result<
-        Option<(SettlementAttemptNumber, SettlementAttemptKind, TxEnvelope)>,
+        Option<(
+            SettlementAttemptNumber,
+            SettlementAttemptKind,
+            TxEnvelope,
+            GasOracleInputs,
+        )>,
         RetryCallbackError<BuildAttemptError>,
     > {
         let attempt_number = self.next_attempt_number();
@@ -1489,10 +2005,11 @@ impl<
                 // The nonce is fixed; only chain id and the fee estimate are
                 // read, concurrently, to keep each (retried) build to one
                 // round-trip.
-                let (chain_id, estimate) = tokio::try_join!(
+                let (chain_id, inputs) = tokio::try_join!(
                     self.provider.get_chain_id().into_future(),
-                    self.provider.estimate_eip1559_fees().into_future(),
+                    self.estimate_fees(),
                 )?;
+                let estimate = oracle_estimate(&inputs);
                 let gas = match live_tx_fees {
                     Some((previous_max_fee_per_gas, previous_max_priority_fee_per_gas)) => {
                         // Out-bid the live tx; impossible once it sits at the ceiling.
@@ -1511,7 +2028,7 @@ impl<
                     }
                 };
                 let tx = self.build_attempt(wallet, nonce, chain_id, gas).await?;
-                Ok(Some((attempt_number, attempt_kind, tx)))
+                Ok(Some((attempt_number, attempt_kind, tx, inputs)))
             },
             |error| retry_policy.should_retry(error),
             |_| true,
@@ -1519,18 +2036,24 @@ impl<
         .await
     }
 
-    /// Selects a wallet and a fresh nonce, resolves base gas parameters from
-    /// the latest L1 fee estimate, and builds a signed settlement attempt.
+    /// Selects a fresh nonce on `wallet`, resolves base gas parameters from
+    /// the configured gas oracle, and builds a signed settlement attempt.
     ///
     /// Transient L1 RPC failures are retried in place using the configured
     /// transient-failure policy; a build/sign failure is non-recoverable.
     async fn build_next_attempt_with_new_nonce(
         &self,
+        wallet: Address,
     ) -> Result<
-        (Address, Nonce, SettlementAttemptNumber, TxEnvelope),
+        (
+            Address,
+            Nonce,
+            SettlementAttemptNumber,
+            TxEnvelope,
+            GasOracleInputs,
+        ),
         RetryCallbackError<BuildAttemptError>,
     > {
-        let wallet = self.provider.default_signer_address();
         let attempt_number = self.next_attempt_number();
         let mut retry_policy = BuildRetryPolicy::new();
 
@@ -1540,7 +2063,7 @@ impl<
             || async {
                 // These fetches are independent, so run them concurrently to
                 // keep each (retried) build to one round-trip.
-                let (nonce, chain_id, estimate) = tokio::try_join!(
+                let (nonce, chain_id, inputs) = tokio::try_join!(
                     self.assign_next_nonce_for_wallet(wallet),
                     async {
                         self.provider
@@ -1548,16 +2071,11 @@ impl<
                             .await
                             .map_err(BuildAttemptError::from)
                     },
-                    async {
-                        self.provider
-                            .estimate_eip1559_fees()
-                            .await
-                            .map_err(BuildAttemptError::from)
-                    },
+                    async { self.estimate_fees().await.map_err(BuildAttemptError::from) },
                 )?;
-                let gas = self.resolve_base_gas_params(&estimate);
+                let gas = self.resolve_base_gas_params(&oracle_estimate(&inputs));
                 let tx = self.build_attempt(wallet, nonce, chain_id, gas).await?;
-                Ok((wallet, nonce, attempt_number, tx))
+                Ok((wallet, nonce, attempt_number, tx, inputs))
             },
             |error| retry_policy.should_retry(error),
             |_| true,
@@ -1623,6 +2141,14 @@ impl<
         Ok((job, result))
     }
 
+    /// Whether a network rollback cancelled the job.
+    fn is_cancelled(store: &SettlementStore, id: SettlementJobId) -> eyre::Result<bool> {
+        Ok(store
+            .get_settlement_job_cancellation(&id)
+            .wrap_err_with(|| format!("Failed to read settlement job cancellation for id {id}"))?
+            .is_some())
+    }
+
     #[cfg(test)]
     fn load_settlement_attempts_from_db(&mut self) -> eyre::Result<()> {
         self.attempts = Self::load_settlement_attempts_from_store(self.store.as_ref(), self.id)?;
@@ -1644,6 +2170,7 @@ impl<
         nonce: Nonce,
         attempt_number: SettlementAttemptNumber,
         tx: &TxEnvelope,
+        gas_oracle_inputs: Option<GasOracleInputs>,
     ) {
         if let Some((existing_wallet, existing_nonce)) =
             self.attempt_key_for_attempt_number(attempt_number)
@@ -1662,6 +2189,7 @@ impl<
             submission_time: SystemTime::now(),
             max_fee_per_gas: tx.max_fee_per_gas(),
             max_priority_fee_per_gas: tx.max_priority_fee_per_gas().unwrap_or(0),
+            gas_oracle_inputs,
         };
 
         self.store
@@ -1808,6 +2336,18 @@ impl<
             }
         }
 
+        self.insert_job_result_to_db(wallet, nonce, attempt_number, tx_result)
+    }
+
+    /// Persists the terminal result of the job, whose outcome is `tx_result`
+    /// of the given attempt.
+    fn insert_job_result_to_db(
+        &mut self,
+        wallet: Address,
+        nonce: Nonce,
+        attempt_number: SettlementAttemptNumber,
+        tx_result: ContractCallResult,
+    ) -> SettlementJobResult {
         let job_result = SettlementJobResult {
             wallet: wallet.into(),
             nonce,
@@ -1846,6 +2386,32 @@ impl<
             .unwrap_or_default()
     }
 
+    /// The networks charged for the gas of the job's transactions: the
+    /// network of each batched job for a batch, each paying an even share.
+    fn gas_cost_networks(&self) -> Vec<Option<NetworkId>> {
+        if !self.job.is_batch() {
+            return vec![self.job.network_id];
+        }
+        self.job
+            .batched_jobs
+            .iter()
+            .map(
+                |batched_job_id| match self.store.get_settlement_job(batched_job_id) {
+                    Ok(batched_job) => batched_job.and_then(|batched_job| batched_job.network_id),
+                    Err(error) => {
+                        warn!(
+                            settlement_job_id = %self.id,
+                            %batched_job_id,
+                            ?error,
+                            "Failed to read batched settlement job; charging its gas to no network"
+                        );
+                        None
+                    }
+                },
+            )
+            .collect()
+    }
+
     fn record_attempt_result_to_db(
         &mut self,
         attempt_number: SettlementAttemptNumber,
@@ -1899,6 +2465,37 @@ impl<
                 )
             });
 
+        // Count the gas of a mined transaction once, when its cost is first
+        // stored, so a restart re-reading the same receipt adds nothing.
+        let already_costed = matches!(
+            active_attempt.result,
+            Some(SettlementAttemptResult::ContractCall(ContractCallResult {
+                gas_cost: Some(_),
+                ..
+            }))
+        );
+        if let SettlementAttemptResult::ContractCall(ContractCallResult {
+            gas_cost: Some(gas_cost),
+            ..
+        }) = &result
+        {
+            if !already_costed {
+                let networks = self.gas_cost_networks();
+                for (network_id, (gas_used, spent_wei)) in
+                    networks.iter().zip(gas_cost.split(networks.len()))
+                {
+                    let network = network_id
+                        .map_or_else(|| "none".to_string(), |network_id| network_id.to_string());
+                    record_settlement_gas_spent(
+                        &network,
+                        &wallet.to_string(),
+                        gas_used,
+                        spent_wei as f64,
+                    );
+                }
+            }
+        }
+
         active_attempt.result = Some(result);
     }
 }
@@ -8,7 +8,8 @@ use std::{
 use agglayer_config::Multiplier;
 use agglayer_storage::{error::Error, tests::mocks::MockStateStore};
 use agglayer_types::{
-    ClientError, ClientErrorType, ContractCallOutcome, Digest, SettlementAttemptResult, B256, U256,
+    ClientError, ClientErrorType, ContractCallOutcome, Digest, Height, NetworkId,
+    SettlementAttemptResult, SettlementJobCancellation, B256, U256,
 };
 use alloy::{
     consensus::{Signed, TxEip1559},
@@ -129,6 +130,10 @@ fn mk_job() -> SettlementJob {
         calldata: vec![2, 3].into(),
         eth_value: U256::from(0),
         gas_limit: 100_000,
+        network_id: None,
+        tx_type: agglayer_types::SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
 
@@ -165,6 +170,7 @@ fn mk_contract_call_result(seed: u8, outcome: ContractCallOutcome) -> ContractCa
         block_hash: B256::from([seed.wrapping_add(2); 32]),
         block_number: seed as u64,
         tx_hash: mk_tx_hash(seed.wrapping_add(3)),
+        gas_cost: None,
     }
 }
 
@@ -191,6 +197,7 @@ fn mk_active_attempt(
             submission_time: SystemTime::UNIX_EPOCH,
             max_fee_per_gas: 0,
             max_priority_fee_per_gas: 0,
+            gas_oracle_inputs: None,
         },
         result,
     }
@@ -204,6 +211,7 @@ fn mk_stored_attempt(seed: u8, sender_wallet: Address, nonce: Nonce) -> Settleme
         submission_time: SystemTime::UNIX_EPOCH + Duration::from_secs(seed.into()),
         max_fee_per_gas: 0,
         max_priority_fee_per_gas: 0,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -250,6 +258,9 @@ fn mk_task_with_id_and_provider<L1Provider: Provider + WalletProvider + 'static>
         provider: Arc::new(provider),
         store,
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts,
     }
@@ -266,12 +277,18 @@ fn mk_task_with_tx_config<P: Provider + WalletProvider + 'static>(
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts: BTreeMap::new(),
     }
 }
 
+mod low_balance;
 mod nonce_lock;
+mod simulation;
+mod wallet_pool;
 
 #[test]
 fn next_attempt_number_starts_at_zero_and_increments_past_max() {
@@ -352,6 +369,8 @@ async fn create_generates_settlement_job_id() {
         Arc::new(mk_mock_provider_with_gas_estimate(200_000)),
         Arc::new(store),
         Arc::new(WalletNonceLocks::default()),
+        Arc::default(),
+        Arc::default(),
         mk_control(),
     )
     .await
@@ -378,6 +397,9 @@ async fn load_settlement_job_from_db_returns_pending_job() {
         .once()
         .withf(move |recorded_job_id| recorded_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
 
     let (loaded_job, loaded_result) = load_job_from_store(mk_provider(), &store, job_id)
         .await
@@ -460,17 +482,25 @@ async fn recover_from_storage_returns_completed_without_loading_attempts() {
         Arc::new(mk_provider()),
         Arc::new(store),
         Arc::new(WalletNonceLocks::default()),
+        Arc::default(),
+        Arc::default(),
     )
     .await
     .expect("completed settlement job should recover");
 
     match loaded {
-        RecoveredSettlementJob::Completed(loaded_result) => {
+        RecoveredSettlementJob::Completed {
+            result: loaded_result,
+            ..
+        } => {
             assert_eq!(loaded_result, expected_job_result);
         }
         RecoveredSettlementJob::Pending(_) => {
             panic!("completed settlement job should not recover as pending")
         }
+        RecoveredSettlementJob::Cancelled => {
+            panic!("completed settlement job should not recover as cancelled")
+        }
     }
 }
 
@@ -491,6 +521,9 @@ async fn recover_from_storage_returns_pending_with_hydrated_attempts() {
         .once()
         .withf(move |recorded_job_id| recorded_job_id == &job_id)
         .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .returning(|_| Ok(None));
     store
         .expect_list_settlement_attempt_results()
         .once()
@@ -508,6 +541,8 @@ async fn recover_from_storage_returns_pending_with_hydrated_attempts() {
         Arc::new(mk_provider()),
         Arc::new(store),
         Arc::new(WalletNonceLocks::default()),
+        Arc::default(),
+        Arc::default(),
     )
     .await
     .expect("pending settlement job should recover");
@@ -518,12 +553,59 @@ async fn recover_from_storage_returns_pending_with_hydrated_attempts() {
             assert_eq!(pending.job, expected_job);
             assert!(pending.attempts.is_empty());
         }
-        RecoveredSettlementJob::Completed(_) => {
+        RecoveredSettlementJob::Completed { .. } => {
             panic!("pending settlement job should not recover as completed")
         }
+        RecoveredSettlementJob::Cancelled => {
+            panic!("pending settlement job should not recover as cancelled")
+        }
     }
 }
 
+#[tokio::test]
+async fn recover_from_storage_skips_job_cancelled_by_rollback() {
+    let mut store = MockStateStore::new();
+    let job_id = mk_job_id(52);
+    let job = mk_job();
+
+    store
+        .expect_get_settlement_job()
+        .once()
+        .withf(move |recorded_job_id| recorded_job_id == &job_id)
+        .return_once(move |_| Ok(Some(job)));
+    store
+        .expect_get_settlement_job_result()
+        .once()
+        .withf(move |recorded_job_id| recorded_job_id == &job_id)
+        .return_once(|_| Ok(None));
+    store
+        .expect_get_settlement_job_cancellation()
+        .once()
+        .withf(move |recorded_job_id| recorded_job_id == &job_id)
+        .return_once(|_| {
+            Ok(Some(SettlementJobCancellation {
+                network_id: NetworkId::new(1),
+                target_height: Height::new(3),
+            }))
+        });
+    store.expect_list_settlement_attempts().never();
+    store.expect_list_settlement_attempt_results().never();
+
+    let loaded = SettlementTask::recover_from_storage(
+        job_id,
+        Arc::new(SettlementTransactionConfig::default()),
+        Arc::new(mk_provider()),
+        Arc::new(store),
+        Arc::new(WalletNonceLocks::default()),
+        Arc::default(),
+        Arc::default(),
+    )
+    .await
+    .expect("cancelled settlement job should recover");
+
+    assert!(matches!(loaded, RecoveredSettlementJob::Cancelled));
+}
+
 #[tokio::test]
 async fn load_returns_completed_settlement_job() {
     let mut store = MockStateStore::new();
@@ -551,6 +633,8 @@ async fn load_returns_completed_settlement_job() {
         Arc::new(mk_provider()),
         Arc::new(store),
         Arc::new(WalletNonceLocks::default()),
+        Arc::default(),
+        Arc::default(),
         mk_control(),
     )
     .await
@@ -563,6 +647,9 @@ async fn load_returns_completed_settlement_job() {
         StoredSettlementJob::Pending(_) => {
             panic!("completed settlement job should not reload as pending")
         }
+        StoredSettlementJob::Cancelled => {
+            panic!("completed settlement job should not reload as cancelled")
+        }
     }
 }
 
@@ -672,7 +759,7 @@ async fn save_attempt_to_db_records_attempt_in_storage_and_memory() {
 
     let mut task = mk_task(Arc::new(store), BTreeMap::new());
 
-    task.save_attempt_to_db(wallet, nonce, attempt_number, &tx);
+    task.save_attempt_to_db(wallet, nonce, attempt_number, &tx, None);
 
     let active_attempt = task
         .attempts
@@ -702,7 +789,7 @@ async fn save_attempt_to_db_does_not_track_attempt_when_storage_write_fails() {
     let mut task = mk_task(Arc::new(store), BTreeMap::new());
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        task.save_attempt_to_db(wallet, nonce, SettlementAttemptNumber(3), &tx);
+        task.save_attempt_to_db(wallet, nonce, SettlementAttemptNumber(3), &tx, None);
     }));
 
     assert!(result.is_err());
@@ -730,7 +817,7 @@ async fn save_attempt_to_db_rejects_attempt_number_already_tracked_for_other_non
     let tx = mk_tx(4);
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        task.save_attempt_to_db(new_wallet, new_nonce, attempt_number, &tx);
+        task.save_attempt_to_db(new_wallet, new_nonce, attempt_number, &tx, None);
     }));
 
     assert!(result.is_err());
@@ -1051,6 +1138,7 @@ async fn run_finishes_interrupted_completion_before_other_nonces() {
         block_hash,
         block_number,
         tx_hash: mk_tx_hash(60),
+        gas_cost: None,
     };
 
     let attempts = mk_interrupted_completion_attempts(
@@ -1111,6 +1199,9 @@ async fn run_finishes_interrupted_completion_before_other_nonces() {
         provider: Arc::new(provider),
         store: Arc::new(store),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control,
         attempts,
     };
@@ -1262,6 +1353,9 @@ async fn run_records_external_nonce_use_before_completing_with_revert() {
         provider: Arc::new(provider),
         store: Arc::new(store),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control,
         attempts,
     };
@@ -1716,6 +1810,7 @@ async fn save_and_submit_records_non_transient_broadcast_failure() {
             attempt_number,
             SettlementAttemptKind::Submission,
             tx,
+            None,
         )
         .await;
 
@@ -1760,6 +1855,9 @@ async fn submit_attempt_to_l1_broadcasts_signed_envelope() {
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts: BTreeMap::new(),
     };
@@ -1807,6 +1905,9 @@ async fn submit_attempt_to_l1_skips_broadcast_when_already_cancelled() {
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts: BTreeMap::new(),
     };
@@ -2110,6 +2211,110 @@ async fn build_attempt_produces_signed_eip1559_envelope() {
     assert_eq!(envelope.recover_signer().unwrap(), wallet_address);
 }
 
+fn mk_access_list() -> agglayer_types::AccessList {
+    agglayer_types::AccessList(vec![agglayer_types::AccessListItem {
+        address: Address::repeat_byte(0x44),
+        storage_keys: vec![B256::repeat_byte(0x55)],
+    }])
+}
+
+#[tokio::test]
+async fn build_attempt_produces_eip7702_envelope_with_the_job_access_list() {
+    use alloy::consensus::Transaction as _;
+
+    let authorization = agglayer_types::SignedAuthorization::new_unchecked(
+        agglayer_types::Authorization {
+            chain_id: U256::from(1337),
+            address: Address::repeat_byte(0x33),
+            nonce: 0,
+        },
+        0,
+        U256::from(1),
+        U256::from(1),
+    );
+    let mut task = mk_task(Arc::new(MockStateStore::new()), BTreeMap::new());
+    task.job = SettlementJob {
+        tx_type: SettlementTxType::Eip7702 {
+            authorization_list: vec![authorization.clone()],
+        },
+        access_list: Some(mk_access_list()),
+        ..mk_job()
+    };
+
+    let gas = GasParams {
+        gas_limit: 100_000,
+        max_fee_per_gas: 30_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+    };
+
+    let envelope = task
+        .build_attempt(test_signer().address(), Nonce(9), 1337, gas)
+        .await
+        .expect("attempt should build");
+
+    assert!(matches!(envelope, TxEnvelope::Eip7702(_)));
+    assert_eq!(envelope.authorization_list(), Some(&[authorization][..]));
+    assert_eq!(envelope.access_list(), Some(&mk_access_list()));
+}
+
+#[tokio::test]
+async fn access_list_is_precomputed_when_configured() {
+    let asserter = Asserter::new();
+    asserter.push_success(&alloy::rpc::types::AccessListResult {
+        access_list: mk_access_list(),
+        gas_used: U256::from(50_000),
+        error: None,
+    });
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::from(test_signer()))
+        .connect_mocked_client(asserter);
+    let tx_config = SettlementTransactionConfig {
+        precompute_access_list: true,
+        ..SettlementTransactionConfig::default()
+    };
+
+    let job = resolve_settlement_access_list(
+        &provider,
+        test_signer().address(),
+        &tx_config,
+        mk_job(),
+        &CancellationToken::new(),
+    )
+    .await
+    .expect("access list resolution should not fail");
+
+    assert_eq!(job.access_list, Some(mk_access_list()));
+}
+
+#[tokio::test]
+async fn access_list_precomputation_failure_keeps_the_job_without_one() {
+    let asserter = Asserter::new();
+    asserter.push_failure(alloy::rpc::json_rpc::ErrorPayload {
+        code: -32601,
+        message: "the method eth_createAccessList does not exist".into(),
+        data: None,
+    });
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::from(test_signer()))
+        .connect_mocked_client(asserter);
+    let tx_config = SettlementTransactionConfig {
+        precompute_access_list: true,
+        ..SettlementTransactionConfig::default()
+    };
+
+    let job = resolve_settlement_access_list(
+        &provider,
+        test_signer().address(),
+        &tx_config,
+        mk_job(),
+        &CancellationToken::new(),
+    )
+    .await
+    .expect("access list resolution should not fail");
+
+    assert_eq!(job, mk_job());
+}
+
 #[tokio::test]
 async fn build_next_attempt_with_new_nonce_uses_assigned_nonce_and_default_wallet() {
     use alloy::{
@@ -2153,12 +2358,15 @@ async fn build_next_attempt_with_new_nonce_uses_assigned_nonce_and_default_walle
         provider: Arc::new(provider),
         store: Arc::new(store),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts: BTreeMap::new(),
     };
 
-    let (used_wallet, nonce, attempt_number, envelope) = task
-        .build_next_attempt_with_new_nonce()
+    let (used_wallet, nonce, attempt_number, envelope, _) = task
+        .build_next_attempt_with_new_nonce(wallet_address)
         .await
         .expect("attempt should build");
 
@@ -2172,6 +2380,58 @@ async fn build_next_attempt_with_new_nonce_uses_assigned_nonce_and_default_walle
     assert!(envelope.max_fee_per_gas() <= 100_000_000_000);
 }
 
+#[tokio::test]
+async fn fixed_gas_oracle_prices_the_attempt_and_is_recorded_with_it() {
+    let wallet = test_signer().address();
+    let expected_inputs = agglayer_types::GasOracleInputs {
+        source: agglayer_types::GasOracleSource::Fixed,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 2_000_000_000,
+    };
+
+    let mut store = MockStateStore::new();
+    store
+        .expect_max_settlement_nonce_for_wallet()
+        .returning(|_| Ok(None));
+    let recorded_inputs = expected_inputs.clone();
+    store
+        .expect_insert_settlement_attempt()
+        .once()
+        .withf(move |_, _, attempt| attempt.gas_oracle_inputs.as_ref() == Some(&recorded_inputs))
+        .returning(|_, _, _| Ok(()));
+
+    // The pending nonce and the chain id are read concurrently; answering both
+    // with the same value keeps the test independent of their order.
+    let asserter = Asserter::new();
+    asserter.push_success(&U64::from(7));
+    asserter.push_success(&U64::from(7));
+    let (provider, requests) = mk_recording_provider(asserter);
+    let mut task = mk_task_with_provider(provider, Arc::new(store), BTreeMap::new());
+    task.tx_config = Arc::new(SettlementTransactionConfig {
+        gas_oracle: GasOracleConfig::Fixed {
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 2_000_000_000,
+        },
+        ..SettlementTransactionConfig::default()
+    });
+
+    let (_, nonce, attempt_number, envelope, inputs) = task
+        .build_next_attempt_with_new_nonce(wallet)
+        .await
+        .expect("attempt should build");
+
+    assert_eq!(inputs, expected_inputs);
+    assert_eq!(envelope.max_fee_per_gas(), 20_000_000_000);
+    assert_eq!(envelope.max_priority_fee_per_gas(), Some(2_000_000_000));
+    assert!(requests
+        .lock()
+        .expect("recorded request lock poisoned")
+        .iter()
+        .all(|request| !request.contains("eth_feeHistory")));
+
+    task.save_attempt_to_db(wallet, nonce, attempt_number, &envelope, Some(inputs));
+}
+
 #[test]
 fn polling_signals_skip_the_retry_warning_log() {
     assert!(!WaitForSettlementError::NotIncludedYet.needs_warning_log());
@@ -2234,6 +2494,7 @@ async fn build_next_attempt_with_nonce_bumps_fees_over_previous_attempt() {
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 30_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     };
     let attempts = BTreeMap::from([(
         (wallet_address, nonce),
@@ -2253,11 +2514,14 @@ async fn build_next_attempt_with_nonce_bumps_fees_over_previous_attempt() {
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts,
     };
 
-    let (attempt_number, attempt_kind, envelope) = task
+    let (attempt_number, attempt_kind, envelope, _) = task
         .build_next_attempt_with_nonce(wallet_address, nonce)
         .await
         .expect("build should not fail")
@@ -2292,6 +2556,7 @@ async fn build_next_attempt_with_nonce_returns_none_at_ceiling() {
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 30_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     };
     let attempts = BTreeMap::from([(
         (wallet_address, nonce),
@@ -2320,6 +2585,9 @@ async fn build_next_attempt_with_nonce_returns_none_at_ceiling() {
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts,
     };
@@ -2354,6 +2622,7 @@ async fn build_next_attempt_with_nonce_rebroadcasts_errored_attempt_at_ceiling()
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 30_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     };
     let attempts = BTreeMap::from([(
         (wallet_address, nonce),
@@ -2380,11 +2649,14 @@ async fn build_next_attempt_with_nonce_rebroadcasts_errored_attempt_at_ceiling()
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts,
     };
 
-    let (attempt_number, attempt_kind, envelope) = task
+    let (attempt_number, attempt_kind, envelope, _) = task
         .build_next_attempt_with_nonce(wallet_address, nonce)
         .await
         .expect("build should not fail")
@@ -2420,6 +2692,7 @@ async fn build_next_attempt_with_nonce_bumps_over_live_tx_ignoring_errored_ceili
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 10_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     };
     // A newer attempt that errored on broadcast at the ceiling (no live tx).
     let errored = SettlementAttempt {
@@ -2429,6 +2702,7 @@ async fn build_next_attempt_with_nonce_bumps_over_live_tx_ignoring_errored_ceili
         submission_time: SystemTime::UNIX_EPOCH,
         max_fee_per_gas: 30_000_000_000,
         max_priority_fee_per_gas: 1_000_000_000,
+        gas_oracle_inputs: None,
     };
     let attempts = BTreeMap::from([(
         (wallet_address, nonce),
@@ -2465,11 +2739,14 @@ async fn build_next_attempt_with_nonce_bumps_over_live_tx_ignoring_errored_ceili
         provider: Arc::new(provider),
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control: mk_control(),
         attempts,
     };
 
-    let (attempt_number, attempt_kind, envelope) = task
+    let (attempt_number, attempt_kind, envelope, _) = task
         .build_next_attempt_with_nonce(wallet_address, nonce)
         .await
         .expect("build should not fail")
@@ -2636,6 +2913,7 @@ async fn metrics_are_emitted_through_the_production_call_sites() {
         attempt_number,
         SettlementAttemptKind::GasBump,
         mk_tx(112),
+        None,
     )
     .await;
 
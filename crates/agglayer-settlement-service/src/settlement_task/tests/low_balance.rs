@@ -0,0 +1,73 @@
+use agglayer_config::settlement_service::SettlementBalanceMonitorConfig;
+
+use super::*;
+
+const MIN_WALLET_BALANCE: u128 = 1_000;
+
+fn mk_balance_monitor(wallet: Address, balance: u64) -> Arc<WalletBalanceMonitor> {
+    let monitor = WalletBalanceMonitor::new(&SettlementBalanceMonitorConfig {
+        min_wallet_balance: MIN_WALLET_BALANCE,
+        ..Default::default()
+    });
+    monitor.record_balance(wallet, U256::from(balance));
+    Arc::new(monitor)
+}
+
+/// While the wallet is underfunded, the task neither builds, signs nor saves
+/// an attempt: it waits for the wallet to be topped up instead.
+#[tokio::test(start_paused = true)]
+async fn run_builds_no_attempt_below_minimum_balance() {
+    let provider = mk_provider();
+    let wallet = provider.default_signer_address();
+
+    let (control_handle, control) = TaskControlHandle::new(&CancellationToken::new());
+    let mut task = SettlementTask {
+        id: mk_job_id(1),
+        job: mk_job(),
+        tx_config: Arc::new(SettlementTransactionConfig::default()),
+        provider: Arc::new(provider),
+        // No expectations: a refused attempt must not touch the store.
+        store: Arc::new(MockStateStore::new()),
+        wallet_nonce_locks: Arc::new(WalletNonceLocks::default()),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: mk_balance_monitor(wallet, 999),
+        control,
+        attempts: BTreeMap::new(),
+    };
+    let run = tokio::spawn(async move {
+        let result = task.run().await;
+        (result, task)
+    });
+
+    // Several retry deadlines go by without the wallet being topped up.
+    tokio::time::sleep(Duration::from_secs(3_600)).await;
+    control_handle.cancel();
+
+    let (result, task) = tokio::time::timeout(Duration::from_secs(600), run)
+        .await
+        .expect("an underfunded task must keep honouring cancellation")
+        .expect("settlement task must not panic");
+    assert!(matches!(result, SettlementTaskRunResult::Cancelled));
+    assert!(task.attempts.is_empty());
+}
+
+#[test]
+fn refusal_is_reported_once_per_pause() {
+    let wallet = Address::from([9; 20]);
+    let mut task = mk_task(Arc::new(MockStateStore::new()), BTreeMap::new());
+    task.balance_monitor = mk_balance_monitor(wallet, 999);
+    let mut underfunded_wallets = BTreeSet::new();
+
+    assert!(task.is_wallet_underfunded(wallet, &mut underfunded_wallets));
+    assert!(underfunded_wallets.contains(&wallet));
+    // Still paused on the next pass: nothing new to report.
+    assert!(task.is_wallet_underfunded(wallet, &mut underfunded_wallets));
+    assert_eq!(underfunded_wallets.len(), 1);
+
+    task.balance_monitor
+        .record_balance(wallet, U256::from(MIN_WALLET_BALANCE));
+
+    assert!(!task.is_wallet_underfunded(wallet, &mut underfunded_wallets));
+    assert!(underfunded_wallets.is_empty());
+}
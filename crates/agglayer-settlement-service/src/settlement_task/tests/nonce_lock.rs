@@ -23,6 +23,9 @@ async fn abort_while_queued_on_wallet_nonce_lock_cancels_run() {
         // No expectations: an aborted queued task must not touch the store.
         store: Arc::new(MockStateStore::new()),
         wallet_nonce_locks: wallet_nonce_locks.clone(),
+        wallet_pool: Arc::default(),
+        wallet_lease: None,
+        balance_monitor: Arc::default(),
         control,
         attempts: BTreeMap::new(),
     };
@@ -0,0 +1,244 @@
+use alloy::{
+    rpc::json_rpc::ErrorPayload,
+    sol_types::{Revert, SolError as _},
+};
+
+use super::*;
+
+fn mk_simulation_task(
+    asserter: Asserter,
+    store: MockStateStore,
+    attempts: ActiveSettlementAttempts,
+) -> SettlementTask<impl Provider + WalletProvider + 'static, MockStateStore> {
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::from(test_signer()))
+        .connect_mocked_client(asserter);
+    let mut task = mk_task_with_provider(provider, Arc::new(store), attempts);
+    task.tx_config = Arc::new(SettlementTransactionConfig {
+        simulate_before_broadcast: true,
+        ..SettlementTransactionConfig::default()
+    });
+    task
+}
+
+fn revert_data(reason: &str) -> Bytes {
+    Revert::from(reason).abi_encode().into()
+}
+
+fn revert_payload(revert_data: &Bytes) -> ErrorPayload {
+    ErrorPayload {
+        code: 3,
+        message: "execution reverted".into(),
+        data: Some(
+            serde_json::value::to_raw_value(revert_data).expect("revert data should serialize"),
+        ),
+    }
+}
+
+fn is_simulated_revert(result: &SettlementAttemptResult) -> bool {
+    matches!(
+        result,
+        SettlementAttemptResult::ClientError(ClientError {
+            kind: ClientErrorType::SimulatedRevert,
+            message,
+        }) if message.contains("InvalidProof")
+    )
+}
+
+#[tokio::test]
+async fn simulated_revert_completes_the_job_without_broadcasting() {
+    let wallet = test_signer().address();
+    let nonce = Nonce(2);
+    let attempt_number = SettlementAttemptNumber(0);
+    let tx = mk_tx(112);
+    let tx_hash = SettlementTxHash::from(Digest::from(*tx.tx_hash()));
+    let revert_data = revert_data("InvalidProof");
+
+    let mut store = MockStateStore::new();
+    store
+        .expect_insert_settlement_attempt()
+        .once()
+        .returning(|_, _, _| Ok(()));
+    store
+        .expect_record_settlement_attempt_result()
+        .once()
+        .withf(move |_, stored_attempt_number, result| {
+            *stored_attempt_number == attempt_number.0 && is_simulated_revert(result)
+        })
+        .returning(|_, _, _| Ok(()));
+    let expected_revert_data = revert_data.clone();
+    store
+        .expect_insert_settlement_job_result()
+        .once()
+        .withf(move |_, result| {
+            result.nonce == nonce
+                && result.attempt_number == attempt_number
+                && result.contract_call_result.outcome == ContractCallOutcome::Revert
+                && result.contract_call_result.metadata == expected_revert_data
+                && result.contract_call_result.tx_hash == tx_hash
+        })
+        .returning(|_, _| Ok(()));
+
+    // Only the simulation is answered: a broadcast would fail the test on the
+    // `Unknown` client error it records.
+    let asserter = Asserter::new();
+    asserter.push_failure(revert_payload(&revert_data));
+    let mut task = mk_simulation_task(asserter, store, BTreeMap::new());
+
+    let run_result = task
+        .save_attempt_to_db_and_submit_to_l1(
+            None,
+            wallet,
+            nonce,
+            attempt_number,
+            SettlementAttemptKind::Submission,
+            tx,
+            None,
+        )
+        .await;
+
+    assert!(matches!(
+        run_result,
+        Some(SettlementTaskRunResult::Completed(_))
+    ));
+}
+
+#[tokio::test]
+async fn simulated_revert_waits_for_other_pending_attempts() {
+    let wallet = test_signer().address();
+    let nonce = Nonce(2);
+    let attempt_number = SettlementAttemptNumber(1);
+    let revert_data = revert_data("InvalidProof");
+
+    let mut store = MockStateStore::new();
+    store
+        .expect_insert_settlement_attempt()
+        .once()
+        .returning(|_, _, _| Ok(()));
+    store
+        .expect_record_settlement_attempt_result()
+        .once()
+        .withf(|_, _, result| is_simulated_revert(result))
+        .returning(|_, _, _| Ok(()));
+    store.expect_insert_settlement_job_result().never();
+
+    let asserter = Asserter::new();
+    asserter.push_failure(revert_payload(&revert_data));
+    // An earlier attempt on the previous nonce may still land and settle the
+    // job.
+    let attempts = BTreeMap::from([(
+        (wallet, Nonce(1)),
+        BTreeMap::from([(
+            SettlementAttemptNumber(0),
+            mk_active_attempt(wallet, Nonce(1), mk_tx_hash(1), None),
+        )]),
+    )]);
+    let mut task = mk_simulation_task(asserter, store, attempts);
+
+    let run_result = task
+        .save_attempt_to_db_and_submit_to_l1(
+            None,
+            wallet,
+            nonce,
+            attempt_number,
+            SettlementAttemptKind::Submission,
+            mk_tx(113),
+            None,
+        )
+        .await;
+
+    assert!(run_result.is_none());
+    assert!(!task.is_any_attempt_pending_for_nonce(wallet, nonce));
+    // The unbroadcast nonce is released rather than retried.
+    assert_eq!(task.all_used_nonces(), BTreeSet::from([(wallet, Nonce(1))]));
+}
+
+#[tokio::test]
+async fn successful_simulation_broadcasts_the_attempt() {
+    let wallet = test_signer().address();
+    let nonce = Nonce(2);
+    let tx = mk_tx(114);
+
+    let mut store = MockStateStore::new();
+    store
+        .expect_insert_settlement_attempt()
+        .once()
+        .returning(|_, _, _| Ok(()));
+    store.expect_record_settlement_attempt_result().never();
+
+    let asserter = Asserter::new();
+    asserter.push_success(&Bytes::new());
+    asserter.push_success(tx.tx_hash());
+    let mut task = mk_simulation_task(asserter, store, BTreeMap::new());
+
+    let run_result = task
+        .save_attempt_to_db_and_submit_to_l1(
+            None,
+            wallet,
+            nonce,
+            SettlementAttemptNumber(0),
+            SettlementAttemptKind::Submission,
+            tx,
+            None,
+        )
+        .await;
+
+    assert!(run_result.is_none());
+    assert!(task.is_any_attempt_pending_for_nonce(wallet, nonce));
+}
+
+/// The nonce lock is held until the revert of a fresh nonce is recorded, so
+/// no other same-wallet task can take the next nonce while this one may still
+/// be left unbroadcast.
+#[tokio::test]
+async fn simulated_revert_is_recorded_under_the_nonce_lock() {
+    let wallet = test_signer().address();
+    let nonce = Nonce(2);
+    let attempt_number = SettlementAttemptNumber(0);
+    let revert_data = revert_data("InvalidProof");
+    let nonce_lock = Arc::new(tokio::sync::Mutex::new(()));
+
+    let mut store = MockStateStore::new();
+    store
+        .expect_insert_settlement_attempt()
+        .once()
+        .returning(|_, _, _| Ok(()));
+    let held_lock = nonce_lock.clone();
+    store
+        .expect_record_settlement_attempt_result()
+        .once()
+        .withf(move |_, _, result| is_simulated_revert(result))
+        .returning(move |_, _, _| {
+            assert!(
+                held_lock.try_lock().is_err(),
+                "the nonce lock must be held until the revert is recorded"
+            );
+            Ok(())
+        });
+    store
+        .expect_insert_settlement_job_result()
+        .once()
+        .returning(|_, _| Ok(()));
+
+    let asserter = Asserter::new();
+    asserter.push_failure(revert_payload(&revert_data));
+    let mut task = mk_simulation_task(asserter, store, BTreeMap::new());
+
+    let run_result = task
+        .save_attempt_to_db_and_submit_to_l1(
+            Some(nonce_lock.clone().lock_owned().await),
+            wallet,
+            nonce,
+            attempt_number,
+            SettlementAttemptKind::Submission,
+            mk_tx(112),
+            None,
+        )
+        .await;
+
+    assert!(matches!(
+        run_result,
+        Some(SettlementTaskRunResult::Completed(_))
+    ));
+    assert!(nonce_lock.try_lock().is_ok());
+}
@@ -0,0 +1,141 @@
+use agglayer_config::settlement_service::SettlementWalletPoolConfig;
+
+use super::*;
+
+fn other_signer() -> PrivateKeySigner {
+    PrivateKeySigner::from_slice(&[0x22; 32]).expect("valid test signing key")
+}
+
+/// Provider holding the keys of both pool wallets; the default signer is
+/// [`test_signer`].
+fn mk_two_wallet_provider() -> impl Provider + WalletProvider + 'static {
+    let mut wallet = EthereumWallet::from(test_signer());
+    wallet.register_signer(other_signer());
+    ProviderBuilder::new().wallet(wallet).connect_http(
+        "http://127.0.0.1:0"
+            .parse()
+            .expect("test provider URL should parse"),
+    )
+}
+
+fn mk_two_wallet_pool() -> Arc<WalletPool> {
+    let config = SettlementWalletPoolConfig {
+        wallets: vec![other_signer().address().into()],
+        ..Default::default()
+    };
+    Arc::new(
+        WalletPool::new(test_signer().address(), &config, |_| true).expect("valid wallet pool"),
+    )
+}
+
+fn mk_pool_task(
+    attempts: ActiveSettlementAttempts,
+) -> SettlementTask<impl Provider + WalletProvider + 'static, MockStateStore> {
+    let mut task = mk_task_with_provider(
+        mk_two_wallet_provider(),
+        Arc::new(MockStateStore::new()),
+        attempts,
+    );
+    task.wallet_pool = mk_two_wallet_pool();
+    task
+}
+
+fn mk_pending_attempts(
+    wallet: Address,
+    nonce: Nonce,
+    submission_time: SystemTime,
+) -> ActiveSettlementAttempts {
+    let mut attempt = mk_active_attempt(wallet, nonce, mk_tx_hash(1), None);
+    attempt.attempt.submission_time = submission_time;
+    BTreeMap::from([(
+        (wallet, nonce),
+        BTreeMap::from([(SettlementAttemptNumber(0), attempt)]),
+    )])
+}
+
+#[test]
+fn settlement_wallet_is_assigned_from_the_pool_once() {
+    let mut task = mk_pool_task(BTreeMap::new());
+
+    let wallet = task.settlement_wallet();
+
+    assert_eq!(wallet, test_signer().address());
+    assert_eq!(task.settlement_wallet(), wallet);
+    assert_eq!(task.wallet_pool.pending_jobs(wallet), 1);
+}
+
+#[test]
+fn settlement_wallet_keeps_the_lane_of_a_recovered_job() {
+    let wallet = other_signer().address();
+    let mut task = mk_pool_task(mk_pending_attempts(wallet, Nonce(3), SystemTime::now()));
+
+    assert_eq!(task.settlement_wallet(), wallet);
+    assert_eq!(task.wallet_pool.pending_jobs(wallet), 1);
+    assert_eq!(task.wallet_pool.pending_jobs(test_signer().address()), 0);
+}
+
+#[test]
+fn settlement_wallet_falls_back_to_default_signer_with_empty_pool() {
+    let mut task = mk_task(Arc::new(MockStateStore::new()), BTreeMap::new());
+
+    assert_eq!(task.settlement_wallet(), test_signer().address());
+    assert!(task.wallet_lease.is_none());
+}
+
+#[test]
+fn nonce_is_stuck_only_after_the_failover_delay() {
+    let wallet = test_signer().address();
+    let recent = mk_pool_task(mk_pending_attempts(wallet, Nonce(3), SystemTime::now()));
+    let old = mk_pool_task(mk_pending_attempts(
+        wallet,
+        Nonce(3),
+        SystemTime::now() - Duration::from_secs(3600),
+    ));
+
+    assert!(!recent.is_nonce_stuck(wallet, Nonce(3)));
+    assert!(old.is_nonce_stuck(wallet, Nonce(3)));
+    assert!(!old.is_nonce_stuck(wallet, Nonce(4)));
+}
+
+#[test]
+fn fail_over_moves_the_job_to_an_unused_wallet() {
+    let stuck_wallet = test_signer().address();
+    let mut task = mk_pool_task(mk_pending_attempts(
+        stuck_wallet,
+        Nonce(3),
+        SystemTime::UNIX_EPOCH,
+    ));
+    assert_eq!(task.settlement_wallet(), stuck_wallet);
+
+    assert!(task.fail_over_to_another_wallet());
+
+    let new_wallet = other_signer().address();
+    assert_eq!(task.settlement_wallet(), new_wallet);
+    assert_eq!(task.wallet_pool.pending_jobs(stuck_wallet), 0);
+    assert_eq!(task.wallet_pool.pending_jobs(new_wallet), 1);
+
+    // Every pool wallet has now been used by the job: it stays on its lane
+    // rather than opening a third concurrent settlement transaction.
+    assert!(!task.fail_over_to_another_wallet());
+    assert_eq!(task.settlement_wallet(), new_wallet);
+}
+
+#[test]
+fn failover_deadline_wakes_up_the_run_loop() {
+    let wallet = test_signer().address();
+    let submission_time = SystemTime::now();
+    let mut task = mk_pool_task(mk_pending_attempts(wallet, Nonce(3), submission_time));
+    task.tx_config = Arc::new(SettlementTransactionConfig {
+        retry_on_not_included_on_l1: agglayer_config::settlement_service::TxRetryPolicy {
+            initial_interval: Duration::from_secs(24 * 3600),
+            ..SettlementTransactionConfig::default().retry_on_not_included_on_l1
+        },
+        ..SettlementTransactionConfig::default()
+    });
+    task.settlement_wallet();
+
+    assert_eq!(
+        task.next_overall_deadline(),
+        Some(submission_time + task.wallet_pool.failover_after())
+    );
+}
@@ -1,7 +1,9 @@
 use std::{future::Future, time::Duration};
 
 use agglayer_config::settlement_service::TxRetryPolicy;
-use agglayer_types::{ContractCallOutcome, ContractCallResult, Nonce, SettlementTxHash};
+use agglayer_types::{
+    ContractCallOutcome, ContractCallResult, Nonce, SettlementGasCost, SettlementTxHash,
+};
 use alloy::{
     network::{ReceiptResponse, TransactionResponse as _},
     primitives::{Address, Bytes},
@@ -223,7 +225,8 @@ pub(crate) async fn tx_hash_on_l1_for_nonce(
 /// `None` if the receipt has no block info yet.
 ///
 /// The metadata (return data or revert reason) is not available in receipts,
-/// so it is left empty.
+/// so it is left empty. The gas paid is recorded whatever the outcome, since a
+/// reverted settlement costs as much as a successful one.
 pub(crate) fn contract_call_result_from_receipt(
     receipt: &impl ReceiptResponse,
 ) -> Option<ContractCallResult> {
@@ -246,6 +249,10 @@ pub(crate) fn contract_call_result_from_receipt(
         block_hash,
         block_number,
         tx_hash: SettlementTxHash::from(receipt.transaction_hash()),
+        gas_cost: Some(SettlementGasCost {
+            gas_used: receipt.gas_used(),
+            effective_gas_price: receipt.effective_gas_price(),
+        }),
     })
 }
 
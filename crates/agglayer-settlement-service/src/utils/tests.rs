@@ -797,6 +797,12 @@ async fn contract_call_result_from_receipt_maps_revert() {
     let result = contract_call_result_from_receipt(&receipt)
         .expect("mined reverted tx should have a result");
     assert_eq!(result.outcome, ContractCallOutcome::Revert);
+    // The revert still paid for its gas.
+    let gas_cost = result
+        .gas_cost
+        .expect("mined tx should record its gas cost");
+    assert_eq!(gas_cost.gas_used, receipt.gas_used);
+    assert_eq!(gas_cost.effective_gas_price, receipt.effective_gas_price);
 }
 
 // Manual run for any custom L1 RPC endpoint:
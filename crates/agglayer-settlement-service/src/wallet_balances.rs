@@ -0,0 +1,138 @@
+//! Background monitoring of the settlement wallet balances.
+//!
+//! The monitor polls the balance of every settlement wallet, exports it
+//! through [`agglayer_telemetry::settlement`], and acts as a circuit breaker:
+//! while a wallet is below the configured minimum, settlement tasks refuse to
+//! build attempts from it, without assigning a nonce or saving anything. Tasks
+//! resume on their own once a poll observes the wallet topped up.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use agglayer_config::settlement_service::SettlementBalanceMonitorConfig;
+use agglayer_telemetry::settlement::record_settlement_wallet_balance;
+use alloy::{
+    primitives::{Address, U256},
+    providers::Provider,
+};
+use tokio::sync::{futures::Notified, Notify};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+/// Last observed balances of the settlement wallets, shared by all settlement
+/// tasks of one [`SettlementService`](crate::SettlementService).
+///
+/// The default monitor has no minimum balance and never observes anything,
+/// so it never refuses an attempt.
+#[derive(Debug, Default)]
+pub(crate) struct WalletBalanceMonitor {
+    min_wallet_balance: u128,
+    balances: std::sync::Mutex<HashMap<Address, U256>>,
+    funds_restored: Notify,
+}
+
+impl WalletBalanceMonitor {
+    pub(crate) fn new(config: &SettlementBalanceMonitorConfig) -> Self {
+        Self {
+            min_wallet_balance: config.min_wallet_balance,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn min_wallet_balance(&self) -> u128 {
+        self.min_wallet_balance
+    }
+
+    /// Returns the last observed balance of `wallet` when it is below the
+    /// minimum, `None` otherwise.
+    ///
+    /// A wallet that was never observed is not considered underfunded: a
+    /// failing balance query must not stop settlement on its own.
+    pub(crate) fn low_balance(&self, wallet: Address) -> Option<U256> {
+        let balances = self.balances.lock().expect("wallet balances poisoned");
+        balances
+            .get(&wallet)
+            .copied()
+            .filter(|balance| self.is_below_minimum(*balance))
+    }
+
+    /// Completes the next time a wallet goes back above the minimum balance.
+    pub(crate) fn funds_restored(&self) -> Notified<'_> {
+        self.funds_restored.notified()
+    }
+
+    /// Records a balance observation for `wallet`, and wakes up the waiting
+    /// settlement tasks when it tops the wallet back up.
+    pub(crate) fn record_balance(&self, wallet: Address, balance: U256) {
+        let low = self.is_below_minimum(balance);
+        let previous = self
+            .balances
+            .lock()
+            .expect("wallet balances poisoned")
+            .insert(wallet, balance);
+        record_settlement_wallet_balance(
+            &wallet.to_string(),
+            balance.saturating_to::<u128>() as f64,
+            low,
+        );
+
+        let was_low = previous.is_some_and(|previous| self.is_below_minimum(previous));
+        match (was_low, low) {
+            (false, true) => warn!(
+                %wallet,
+                %balance,
+                min_wallet_balance = self.min_wallet_balance,
+                "Settlement wallet balance below the minimum; refusing new settlement attempts \
+                 from it"
+            ),
+            (true, false) => {
+                info!(
+                    %wallet,
+                    %balance,
+                    "Settlement wallet topped up; resuming settlement attempts from it"
+                );
+                self.funds_restored.notify_waiters();
+            }
+            _ => {}
+        }
+    }
+
+    /// Polls the balance of `wallets` every `poll_interval` until
+    /// `cancellation_token` is cancelled. The first poll happens right away.
+    pub(crate) fn spawn<L1Provider: Provider + 'static>(
+        self: &Arc<Self>,
+        provider: Arc<L1Provider>,
+        wallets: Vec<Address>,
+        poll_interval: Duration,
+        cancellation_token: CancellationToken,
+    ) {
+        let this = self.clone();
+        tokio::task::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+                this.poll_once(provider.as_ref(), &wallets).await;
+            }
+        });
+    }
+
+    async fn poll_once<L1Provider: Provider>(&self, provider: &L1Provider, wallets: &[Address]) {
+        for &wallet in wallets {
+            match provider.get_balance(wallet).await {
+                Ok(balance) => self.record_balance(wallet, balance),
+                // Keep the last observation: the next poll retries.
+                Err(error) => warn!(?error, %wallet, "Failed to query settlement wallet balance"),
+            }
+        }
+    }
+
+    fn is_below_minimum(&self, balance: U256) -> bool {
+        balance < U256::from(self.min_wallet_balance)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use alloy::providers::{mock::Asserter, ProviderBuilder};
+
+use super::*;
+
+const WALLET_A: Address = Address::new([0xAA; 20]);
+const WALLET_B: Address = Address::new([0xBB; 20]);
+
+fn mk_monitor(min_wallet_balance: u128) -> WalletBalanceMonitor {
+    WalletBalanceMonitor::new(&SettlementBalanceMonitorConfig {
+        min_wallet_balance,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn wallet_below_minimum_is_low() {
+    let monitor = mk_monitor(1_000);
+
+    monitor.record_balance(WALLET_A, U256::from(999));
+    monitor.record_balance(WALLET_B, U256::from(1_000));
+
+    assert_eq!(monitor.low_balance(WALLET_A), Some(U256::from(999)));
+    assert_eq!(monitor.low_balance(WALLET_B), None);
+}
+
+#[test]
+fn unobserved_wallet_is_not_low() {
+    let monitor = mk_monitor(1_000);
+
+    assert_eq!(monitor.low_balance(WALLET_A), None);
+}
+
+#[test]
+fn zero_minimum_never_refuses() {
+    let monitor = WalletBalanceMonitor::default();
+
+    monitor.record_balance(WALLET_A, U256::ZERO);
+
+    assert_eq!(monitor.low_balance(WALLET_A), None);
+}
+
+#[tokio::test]
+async fn top_up_wakes_up_waiters() {
+    let monitor = mk_monitor(1_000);
+    monitor.record_balance(WALLET_A, U256::from(10));
+
+    let funds_restored = monitor.funds_restored();
+    tokio::pin!(funds_restored);
+    funds_restored.as_mut().enable();
+
+    monitor.record_balance(WALLET_A, U256::from(5_000));
+
+    tokio::time::timeout(Duration::from_secs(1), funds_restored)
+        .await
+        .expect("a top-up should wake up the waiters");
+    assert_eq!(monitor.low_balance(WALLET_A), None);
+}
+
+#[tokio::test]
+async fn failed_poll_keeps_the_last_observation() {
+    let monitor = mk_monitor(1_000);
+    let asserter = Asserter::new();
+    asserter.push_success(&U256::from(10));
+    asserter.push_failure(alloy::rpc::json_rpc::ErrorPayload {
+        code: -32000,
+        message: "balance unavailable".into(),
+        data: None,
+    });
+    let provider = ProviderBuilder::new().connect_mocked_client(asserter);
+
+    monitor.poll_once(&provider, &[WALLET_A]).await;
+    assert_eq!(monitor.low_balance(WALLET_A), Some(U256::from(10)));
+
+    monitor.poll_once(&provider, &[WALLET_A]).await;
+    assert_eq!(monitor.low_balance(WALLET_A), Some(U256::from(10)));
+}
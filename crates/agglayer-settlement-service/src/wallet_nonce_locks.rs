@@ -18,8 +18,8 @@ use tracing::debug;
 /// [`SettlementService`](crate::SettlementService).
 ///
 /// Entries are created on first use and never removed; the map is bounded by
-/// the number of distinct wallets ever used, which is one per service
-/// instance today (the provider's default signer).
+/// the number of distinct wallets ever used, which is the size of the
+/// settlement wallet pool plus any wallet a recovered job was still using.
 #[derive(Debug, Default)]
 pub(crate) struct WalletNonceLocks {
     locks: std::sync::Mutex<HashMap<Address, Arc<tokio::sync::Mutex<()>>>>,
@@ -0,0 +1,234 @@
+//! Assignment of settlement jobs to the wallets of the settlement pool.
+//!
+//! Each wallet of the pool is an independent nonce lane: its nonces are
+//! serialized by [`WalletNonceLocks`](crate::wallet_nonce_locks), and a job
+//! stuck behind a wallet's pending nonce does not hold back jobs assigned to
+//! the other wallets.
+//!
+//! A job holds a [`WalletLease`] on the wallet of its current lane for as long
+//! as its task lives, which is what the least-pending policy counts.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+use agglayer_config::settlement_service::{SettlementWalletPoolConfig, WalletAssignmentPolicy};
+use agglayer_types::NetworkId;
+use alloy::primitives::Address;
+use tracing::debug;
+
+/// The settlement wallets shared by all settlement tasks of one
+/// [`SettlementService`](crate::SettlementService).
+///
+/// The default pool is empty: tasks then sign with the provider's default
+/// signer and never fail over.
+#[derive(Debug)]
+pub(crate) struct WalletPool {
+    wallets: Vec<Address>,
+    policy: WalletAssignmentPolicy,
+    pinned_networks: BTreeMap<NetworkId, Address>,
+    failover_after: Duration,
+    state: std::sync::Mutex<WalletPoolState>,
+}
+
+#[derive(Debug, Default)]
+struct WalletPoolState {
+    /// Index of the next wallet to consider with the round-robin policy.
+    next_round_robin: usize,
+    /// Number of live leases per wallet.
+    pending_jobs: HashMap<Address, usize>,
+}
+
+impl Default for WalletPool {
+    fn default() -> Self {
+        Self::from_parts(
+            Vec::new(),
+            &SettlementWalletPoolConfig::default(),
+            BTreeMap::new(),
+        )
+    }
+}
+
+impl WalletPool {
+    /// Builds the pool from the configuration. `default_wallet` is the
+    /// provider's default signer, which always comes first in the pool.
+    ///
+    /// Every pool wallet must be known to the provider, otherwise the tasks
+    /// assigned to it could not sign their settlement transactions.
+    pub(crate) fn new(
+        default_wallet: Address,
+        config: &SettlementWalletPoolConfig,
+        has_signer_for: impl Fn(&Address) -> bool,
+    ) -> eyre::Result<Self> {
+        let mut wallets = vec![default_wallet];
+        for wallet in config.wallets.iter().map(|wallet| wallet.into_alloy()) {
+            if !wallets.contains(&wallet) {
+                wallets.push(wallet);
+            }
+        }
+
+        if let Some(wallet) = wallets.iter().find(|wallet| !has_signer_for(wallet)) {
+            eyre::bail!("No signer configured for settlement pool wallet {wallet}");
+        }
+
+        let mut pinned_networks = BTreeMap::new();
+        for (&network_id, wallet) in &config.pinned_networks {
+            let wallet = wallet.into_alloy();
+            if !wallets.contains(&wallet) {
+                eyre::bail!(
+                    "Wallet {wallet} pinned for network {network_id} is not part of the \
+                     settlement wallet pool"
+                );
+            }
+            pinned_networks.insert(NetworkId::new(network_id), wallet);
+        }
+
+        Ok(Self::from_parts(wallets, config, pinned_networks))
+    }
+
+    fn from_parts(
+        wallets: Vec<Address>,
+        config: &SettlementWalletPoolConfig,
+        pinned_networks: BTreeMap<NetworkId, Address>,
+    ) -> Self {
+        Self {
+            wallets,
+            policy: config.assignment_policy,
+            pinned_networks,
+            failover_after: config.failover_after,
+            state: std::sync::Mutex::new(WalletPoolState::default()),
+        }
+    }
+
+    /// The wallets of the pool, the provider's default signer first.
+    pub(crate) fn wallets(&self) -> &[Address] {
+        &self.wallets
+    }
+
+    /// How long the pending nonces of a job may stay unmined before the job
+    /// fails over to another wallet.
+    pub(crate) fn failover_after(&self) -> Duration {
+        self.failover_after
+    }
+
+    /// Leases a wallet for a new job according to the assignment policy.
+    ///
+    /// Returns `None` only for an empty pool.
+    pub(crate) fn assign(self: &Arc<Self>, network_id: Option<NetworkId>) -> Option<WalletLease> {
+        self.select(network_id, &BTreeSet::new())
+    }
+
+    /// Leases a wallet for a job leaving its current lane, among the wallets
+    /// the job has not used yet.
+    ///
+    /// Excluding every wallet the job already used bounds the number of
+    /// in-flight settlement transactions of a job by the size of the pool.
+    pub(crate) fn fail_over(
+        self: &Arc<Self>,
+        network_id: Option<NetworkId>,
+        used_wallets: &BTreeSet<Address>,
+    ) -> Option<WalletLease> {
+        self.select(network_id, used_wallets)
+    }
+
+    /// Leases `wallet` for a job that already has a lane, typically a job
+    /// recovered from storage. The wallet does not need to be in the pool.
+    pub(crate) fn lease(self: &Arc<Self>, wallet: Address) -> WalletLease {
+        let mut state = self.state.lock().expect("wallet pool state poisoned");
+        *state.pending_jobs.entry(wallet).or_default() += 1;
+        WalletLease {
+            pool: self.clone(),
+            wallet,
+        }
+    }
+
+    /// Number of live jobs currently assigned to `wallet`.
+    pub(crate) fn pending_jobs(&self, wallet: Address) -> usize {
+        let state = self.state.lock().expect("wallet pool state poisoned");
+        state.pending_jobs.get(&wallet).copied().unwrap_or(0)
+    }
+
+    fn select(
+        self: &Arc<Self>,
+        network_id: Option<NetworkId>,
+        excluded: &BTreeSet<Address>,
+    ) -> Option<WalletLease> {
+        let mut state = self.state.lock().expect("wallet pool state poisoned");
+        let pinned = network_id
+            .and_then(|network_id| self.pinned_networks.get(&network_id))
+            .filter(|wallet| !excluded.contains(wallet));
+        let wallet = match self.policy {
+            WalletAssignmentPolicy::RoundRobin => self.next_round_robin(&mut state, excluded),
+            WalletAssignmentPolicy::LeastPending => self.least_pending(&state, excluded),
+            WalletAssignmentPolicy::Pinned => pinned
+                .copied()
+                .or_else(|| self.least_pending(&state, excluded)),
+        }?;
+        debug!(%wallet, ?network_id, policy = ?self.policy, "Assigned settlement wallet");
+        *state.pending_jobs.entry(wallet).or_default() += 1;
+        Some(WalletLease {
+            pool: self.clone(),
+            wallet,
+        })
+    }
+
+    fn next_round_robin(
+        &self,
+        state: &mut WalletPoolState,
+        excluded: &BTreeSet<Address>,
+    ) -> Option<Address> {
+        let len = self.wallets.len();
+        (0..len)
+            .map(|offset| (state.next_round_robin + offset) % len)
+            .find(|&index| !excluded.contains(&self.wallets[index]))
+            .map(|index| {
+                state.next_round_robin = (index + 1) % len;
+                self.wallets[index]
+            })
+    }
+
+    /// Picks the wallet with the fewest live jobs, ties going to the wallet
+    /// listed first.
+    fn least_pending(
+        &self,
+        state: &WalletPoolState,
+        excluded: &BTreeSet<Address>,
+    ) -> Option<Address> {
+        self.wallets
+            .iter()
+            .filter(|wallet| !excluded.contains(wallet))
+            .min_by_key(|wallet| state.pending_jobs.get(wallet).copied().unwrap_or(0))
+            .copied()
+    }
+
+    fn release(&self, wallet: Address) {
+        let mut state = self.state.lock().expect("wallet pool state poisoned");
+        if let Some(pending) = state.pending_jobs.get_mut(&wallet) {
+            *pending = pending.saturating_sub(1);
+        }
+    }
+}
+
+/// A job's claim on the wallet of its current nonce lane, released on drop.
+#[derive(Debug)]
+pub(crate) struct WalletLease {
+    pool: Arc<WalletPool>,
+    wallet: Address,
+}
+
+impl WalletLease {
+    pub(crate) fn wallet(&self) -> Address {
+        self.wallet
+    }
+}
+
+impl Drop for WalletLease {
+    fn drop(&mut self) {
+        self.pool.release(self.wallet);
+    }
+}
+
+#[cfg(test)]
+mod tests;
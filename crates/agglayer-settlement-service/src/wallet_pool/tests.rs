@@ -0,0 +1,136 @@
+use super::*;
+
+const WALLET_A: Address = Address::new([0xAA; 20]);
+const WALLET_B: Address = Address::new([0xBB; 20]);
+const WALLET_C: Address = Address::new([0xCC; 20]);
+
+fn mk_pool(
+    policy: WalletAssignmentPolicy,
+    pinned_networks: BTreeMap<u32, Address>,
+) -> Arc<WalletPool> {
+    let config = SettlementWalletPoolConfig {
+        wallets: vec![WALLET_B.into(), WALLET_C.into()],
+        assignment_policy: policy,
+        pinned_networks: pinned_networks
+            .into_iter()
+            .map(|(network_id, wallet)| (network_id, wallet.into()))
+            .collect(),
+        ..Default::default()
+    };
+    Arc::new(WalletPool::new(WALLET_A, &config, |_| true).expect("valid wallet pool"))
+}
+
+#[test]
+fn round_robin_cycles_through_wallets() {
+    let pool = mk_pool(WalletAssignmentPolicy::RoundRobin, BTreeMap::new());
+
+    let wallets: Vec<_> = (0..4)
+        .map(|_| pool.assign(None).expect("non-empty pool").wallet())
+        .collect();
+
+    assert_eq!(wallets, vec![WALLET_A, WALLET_B, WALLET_C, WALLET_A]);
+}
+
+#[test]
+fn least_pending_prefers_idle_wallets() {
+    let pool = mk_pool(WalletAssignmentPolicy::LeastPending, BTreeMap::new());
+
+    let lease_a = pool.assign(None).expect("non-empty pool");
+    let lease_b = pool.assign(None).expect("non-empty pool");
+    assert_eq!(lease_a.wallet(), WALLET_A);
+    assert_eq!(lease_b.wallet(), WALLET_B);
+
+    // Releasing A makes it the least loaded wallet again.
+    drop(lease_a);
+    let lease = pool.assign(None).expect("non-empty pool");
+    assert_eq!(lease.wallet(), WALLET_A);
+    assert_eq!(pool.pending_jobs(WALLET_A), 1);
+    assert_eq!(pool.pending_jobs(WALLET_B), 1);
+    assert_eq!(pool.pending_jobs(WALLET_C), 0);
+}
+
+#[test]
+fn pinned_networks_use_their_wallet() {
+    let pool = mk_pool(
+        WalletAssignmentPolicy::Pinned,
+        BTreeMap::from([(7, WALLET_C)]),
+    );
+
+    let pinned = pool
+        .assign(Some(NetworkId::new(7)))
+        .expect("non-empty pool");
+    let pinned_again = pool
+        .assign(Some(NetworkId::new(7)))
+        .expect("non-empty pool");
+    assert_eq!(pinned.wallet(), WALLET_C);
+    assert_eq!(pinned_again.wallet(), WALLET_C);
+
+    // Unpinned networks and jobs without a network fall back to least-pending.
+    let unpinned = pool
+        .assign(Some(NetworkId::new(8)))
+        .expect("non-empty pool");
+    assert_eq!(unpinned.wallet(), WALLET_A);
+    let no_network = pool.assign(None).expect("non-empty pool");
+    assert_eq!(no_network.wallet(), WALLET_B);
+}
+
+#[test]
+fn fail_over_skips_used_wallets() {
+    let pool = mk_pool(
+        WalletAssignmentPolicy::Pinned,
+        BTreeMap::from([(7, WALLET_A)]),
+    );
+
+    let lease = pool
+        .fail_over(Some(NetworkId::new(7)), &BTreeSet::from([WALLET_A]))
+        .expect("another wallet is available");
+    assert_eq!(lease.wallet(), WALLET_B);
+
+    let lease = pool
+        .fail_over(
+            Some(NetworkId::new(7)),
+            &BTreeSet::from([WALLET_A, WALLET_B]),
+        )
+        .expect("another wallet is available");
+    assert_eq!(lease.wallet(), WALLET_C);
+
+    assert!(pool
+        .fail_over(None, &BTreeSet::from([WALLET_A, WALLET_B, WALLET_C]))
+        .is_none());
+}
+
+#[test]
+fn empty_pool_assigns_nothing() {
+    let pool = Arc::new(WalletPool::default());
+
+    assert!(pool.assign(None).is_none());
+
+    // Leasing an existing lane is still tracked.
+    let lease = pool.lease(WALLET_A);
+    assert_eq!(pool.pending_jobs(WALLET_A), 1);
+    drop(lease);
+    assert_eq!(pool.pending_jobs(WALLET_A), 0);
+}
+
+#[test]
+fn new_rejects_wallets_without_signer() {
+    let config = SettlementWalletPoolConfig {
+        wallets: vec![WALLET_B.into()],
+        ..Default::default()
+    };
+
+    let error = WalletPool::new(WALLET_A, &config, |wallet| *wallet == WALLET_A)
+        .expect_err("wallet B has no signer");
+    assert!(error.to_string().contains(&WALLET_B.to_string()));
+}
+
+#[test]
+fn new_rejects_pinned_wallets_outside_the_pool() {
+    let config = SettlementWalletPoolConfig {
+        assignment_policy: WalletAssignmentPolicy::Pinned,
+        pinned_networks: BTreeMap::from([(1, WALLET_C.into())]),
+        ..Default::default()
+    };
+
+    assert!(WalletPool::new(WALLET_A, &config, |_| true).is_err());
+}
@@ -1,11 +1,14 @@
 //! This crate provides a [`Signer`](trait@alloy_signer::Signer)
-//! implementation that can house either a local keystore or a GCP KMS signer.
+//! implementation that can house either a local keystore, a GCP or AWS KMS
+//! signer or a remote signer.
 //! (more signers can be added in the future)
 //!
 //! See: [`ConfiguredSigner`](enum@ConfiguredSigner)
 
+use agglayer_aws_kms::{AwsKms, KmsSigner as AwsKmsSigner};
 use agglayer_config::{AuthConfig, Config, LocalConfig};
 use agglayer_gcp_kms::{KmsSigner, KMS};
+use agglayer_remote_signer::{RemoteSigner, RemoteSigners};
 use alloy::{
     consensus::TypedTransaction,
     network::TxSigner,
@@ -15,8 +18,8 @@ use alloy_primitives::{Address, ChainId, Signature, B256};
 use async_trait::async_trait;
 use eyre::{eyre, Context as _};
 
-/// A an alloy [`Signer`] that can house either a local keystore or a KMS
-/// signer.
+/// A an alloy [`Signer`] that can house either a local keystore, a KMS
+/// signer (GCP or AWS) or a remote signer.
 ///
 /// An alloy [`Provider`] using a signer must have its
 /// [`Signer`] type specified at compile time, and the Signer type is not object
@@ -26,6 +29,8 @@ use eyre::{eyre, Context as _};
 pub enum ConfiguredSigner {
     Local(PrivateKeySigner),
     Kms(KmsSigner),
+    AwsKms(AwsKmsSigner),
+    Remote(RemoteSigner),
 }
 
 impl ConfiguredSigner {
@@ -54,6 +59,43 @@ impl ConfiguredSigner {
         Ok((signer1, signer2))
     }
 
+    /// Decrypt the local keystores of the settlement pool wallets.
+    ///
+    /// Every configured keystore is a candidate, the first two included, so
+    /// that a pool wallet can share its key with the pp- or tx-settlement
+    /// signer.
+    pub(crate) fn local_settlement_pool(
+        chain_id: u64,
+        local: &LocalConfig,
+        settlement_pool: &[Address],
+    ) -> eyre::Result<Vec<PrivateKeySigner>> {
+        if settlement_pool.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut signers = Vec::new();
+        for pk in &local.private_keys {
+            signers.push(
+                PrivateKeySigner::decrypt_keystore(&pk.path, &pk.password)
+                    .wrap_err("local signer error")?
+                    .with_chain_id(Some(chain_id)),
+            );
+        }
+
+        settlement_pool
+            .iter()
+            .map(|address| {
+                signers
+                    .iter()
+                    .find(|signer| signer.address() == *address)
+                    .cloned()
+                    .ok_or_else(|| {
+                        eyre!("no private key specified for settlement pool wallet {address}")
+                    })
+            })
+            .collect()
+    }
+
     /// Create a new ConfiguredSigner from a local private key signer.
     /// This is a more efficient constructor when you already have a local
     /// signer.
@@ -68,6 +110,18 @@ impl ConfiguredSigner {
     pub const fn from_kms(signer: KmsSigner) -> Self {
         Self::Kms(signer)
     }
+
+    /// Create a new ConfiguredSigner from an AWS KMS signer.
+    #[inline]
+    pub const fn from_aws_kms(signer: AwsKmsSigner) -> Self {
+        Self::AwsKms(signer)
+    }
+
+    /// Create a new ConfiguredSigner from a remote signer.
+    #[inline]
+    pub const fn from_remote(signer: RemoteSigner) -> Self {
+        Self::Remote(signer)
+    }
 }
 
 /// Configured signers for different purposes.
@@ -78,26 +132,88 @@ pub struct ConfiguredSigners {
     /// The signer for transaction settlement, if not defined is expected
     /// that `pp_settlement` signer will be used.
     pub tx_settlement: Option<ConfiguredSigner>,
+    /// The signers for the additional wallets of the settlement wallet pool.
+    pub settlement_pool: Vec<ConfiguredSigner>,
 }
 
 impl ConfiguredSigners {
-    /// Get either a local wallet or GCP KMS signer based on the configuration.
+    /// Get either a local wallet, GCP KMS, AWS KMS or remote signer based on
+    /// the configuration.
+    ///
+    /// The additional wallets of the settlement wallet pool are only supported
+    /// with local keystores and remote signers.
     pub async fn new(config: &Config) -> eyre::Result<Self> {
+        let settlement_pool: Vec<Address> = config
+            .settlement
+            .settlement_service_config
+            .wallet_pool
+            .wallets
+            .iter()
+            .map(|address| (*address).into())
+            .collect();
+        let ensure_no_settlement_pool = |backend: &str| {
+            if settlement_pool.is_empty() {
+                Ok(())
+            } else {
+                Err(eyre!(
+                    "settlement wallet pool is not supported with the {backend} signer"
+                ))
+            }
+        };
+
         match &config.auth {
             AuthConfig::GcpKms(ref kms) => {
+                ensure_no_settlement_pool("GCP KMS")?;
                 let kms = KMS::new(config.l1.chain_id, kms.clone());
                 let kms_signers = kms.gcp_kms_signers().await?;
                 Ok(Self {
                     pp_settlement: ConfiguredSigner::Kms(kms_signers.pp_settlement),
                     tx_settlement: kms_signers.tx_settlement.map(ConfiguredSigner::Kms),
+                    settlement_pool: Vec::new(),
+                })
+            }
+            AuthConfig::AwsKms(ref aws_kms) => {
+                ensure_no_settlement_pool("AWS KMS")?;
+                let kms = AwsKms::new(config.l1.chain_id, aws_kms.clone());
+                let kms_signers = kms.aws_kms_signers().await?;
+                Ok(Self {
+                    pp_settlement: ConfiguredSigner::AwsKms(kms_signers.pp_settlement),
+                    tx_settlement: kms_signers.tx_settlement.map(ConfiguredSigner::AwsKms),
+                    settlement_pool: Vec::new(),
+                })
+            }
+            AuthConfig::Remote(ref remote) => {
+                let remote_signers = RemoteSigners::new_with_settlement_pool(
+                    config.l1.chain_id,
+                    remote,
+                    &settlement_pool,
+                )
+                .await?;
+                Ok(Self {
+                    pp_settlement: ConfiguredSigner::Remote(remote_signers.pp_settlement),
+                    tx_settlement: remote_signers.tx_settlement.map(ConfiguredSigner::Remote),
+                    settlement_pool: remote_signers
+                        .settlement_pool
+                        .into_iter()
+                        .map(ConfiguredSigner::Remote)
+                        .collect(),
                 })
             }
             AuthConfig::Local(ref local) => {
                 let (local_signer_cert, local_signer_tx) =
                     ConfiguredSigner::local_wallet(config.l1.chain_id, local)?;
+                let local_settlement_pool = ConfiguredSigner::local_settlement_pool(
+                    config.l1.chain_id,
+                    local,
+                    &settlement_pool,
+                )?;
                 Ok(Self {
                     pp_settlement: ConfiguredSigner::Local(local_signer_cert),
                     tx_settlement: local_signer_tx.map(ConfiguredSigner::Local),
+                    settlement_pool: local_settlement_pool
+                        .into_iter()
+                        .map(ConfiguredSigner::Local)
+                        .collect(),
                 })
             }
         }
@@ -114,6 +230,8 @@ impl Signer for ConfiguredSigner {
         match self {
             ConfiguredSigner::Local(signer) => signer.sign_hash(hash).await,
             ConfiguredSigner::Kms(signer) => signer.sign_hash(hash).await,
+            ConfiguredSigner::AwsKms(signer) => signer.sign_hash(hash).await,
+            ConfiguredSigner::Remote(signer) => signer.sign_hash(hash).await,
         }
     }
 
@@ -125,6 +243,11 @@ impl Signer for ConfiguredSigner {
                 .sign_message(message)
                 .await
                 .map_err(alloy::signers::Error::other),
+            ConfiguredSigner::AwsKms(signer) => signer
+                .sign_message(message)
+                .await
+                .map_err(alloy::signers::Error::other),
+            ConfiguredSigner::Remote(signer) => Signer::sign_message(signer, message).await,
         }
     }
 
@@ -134,6 +257,8 @@ impl Signer for ConfiguredSigner {
         match self {
             ConfiguredSigner::Local(wallet) => wallet.address(),
             ConfiguredSigner::Kms(signer) => signer.address(),
+            ConfiguredSigner::AwsKms(signer) => signer.address(),
+            ConfiguredSigner::Remote(signer) => signer.address(),
         }
     }
 
@@ -143,6 +268,8 @@ impl Signer for ConfiguredSigner {
         match self {
             ConfiguredSigner::Local(wallet) => wallet.chain_id(),
             ConfiguredSigner::Kms(signer) => signer.chain_id(),
+            ConfiguredSigner::AwsKms(signer) => signer.chain_id(),
+            ConfiguredSigner::Remote(signer) => signer.chain_id(),
         }
     }
 
@@ -156,6 +283,12 @@ impl Signer for ConfiguredSigner {
             ConfiguredSigner::Kms(signer) => {
                 signer.set_chain_id(chain_id);
             }
+            ConfiguredSigner::AwsKms(signer) => {
+                signer.set_chain_id(chain_id);
+            }
+            ConfiguredSigner::Remote(signer) => {
+                signer.set_chain_id(chain_id);
+            }
         }
     }
 }
@@ -170,6 +303,8 @@ impl TxSigner<Signature> for ConfiguredSigner {
         match self {
             ConfiguredSigner::Local(wallet) => wallet.address(),
             ConfiguredSigner::Kms(signer) => signer.address(),
+            ConfiguredSigner::AwsKms(signer) => signer.address(),
+            ConfiguredSigner::Remote(signer) => signer.address(),
         }
     }
 
@@ -181,6 +316,8 @@ impl TxSigner<Signature> for ConfiguredSigner {
         match self {
             ConfiguredSigner::Local(wallet) => wallet.sign_transaction(tx).await,
             ConfiguredSigner::Kms(signer) => TxSigner::sign_transaction(signer, tx).await,
+            ConfiguredSigner::AwsKms(signer) => TxSigner::sign_transaction(signer, tx).await,
+            ConfiguredSigner::Remote(signer) => TxSigner::sign_transaction(signer, tx).await,
         }
     }
 }
@@ -210,6 +347,13 @@ impl ConfiguredSigner {
             ConfiguredSigner::Kms(signer) => {
                 signer.sign_transaction(tx).await.wrap_err("GcpKMS error")
             }
+            ConfiguredSigner::AwsKms(signer) => {
+                signer.sign_transaction(tx).await.wrap_err("AwsKMS error")
+            }
+            ConfiguredSigner::Remote(signer) => signer
+                .sign_transaction(tx)
+                .await
+                .wrap_err("Remote signer error"),
         }
     }
 }
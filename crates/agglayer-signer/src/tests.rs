@@ -1,4 +1,4 @@
-use agglayer_config::{AuthConfig, Config, LocalConfig};
+use agglayer_config::{AuthConfig, AwsKmsConfig, Config, LocalConfig};
 use alloy::{
     consensus::{SignableTransaction, TxEip1559, TypedTransaction},
     signers::Signer,
@@ -102,3 +102,25 @@ async fn configured_signers_reports_missing_private_key() {
         "no private keys specified in the configuration"
     );
 }
+
+#[tokio::test]
+async fn configured_signers_rejects_settlement_pool_with_aws_kms() {
+    let mut config = Config::new(std::path::Path::new("/tmp/agglayer"));
+    config.auth = AuthConfig::AwsKms(AwsKmsConfig {
+        region: None,
+        pp_settlement_key_id: "alias/pp-settlement".to_string(),
+        tx_settlement_key_id: "alias/tx-settlement".to_string(),
+    });
+    config
+        .settlement
+        .settlement_service_config
+        .wallet_pool
+        .wallets = vec![Address::from([0x33; 20]).into()];
+
+    let error = ConfiguredSigners::new(&config).await.unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "settlement wallet pool is not supported with the AWS KMS signer"
+    );
+}
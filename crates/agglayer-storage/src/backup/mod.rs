@@ -3,6 +3,7 @@ use std::{
     fs::read_dir,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use agglayer_errors::ResultExt as _;
@@ -15,10 +16,17 @@ use rocksdb::backup::{
 use serde::Serialize;
 use tokio::sync;
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{error, info};
 
+use self::{
+    remote::RemoteBackups,
+    verify::{VerificationReport, Verifier},
+};
 use crate::storage::DB;
 
+pub mod remote;
+pub mod verify;
+
 #[cfg(test)]
 mod test_hooks {
     use std::{sync::Mutex, thread, time::Duration};
@@ -54,6 +62,7 @@ pub struct BackupRequest {
 }
 
 struct BackupEngineConfig {
+    root_path: PathBuf,
     state_backup_path: PathBuf,
     pending_backup_path: PathBuf,
     epochs_backup_path: PathBuf,
@@ -68,6 +77,7 @@ impl BackupEngineConfig {
 impl From<&Path> for BackupEngineConfig {
     fn from(path: &Path) -> Self {
         Self {
+            root_path: path.to_path_buf(),
             state_backup_path: path.join(Self::DEFAULT_STATE_DIR),
             pending_backup_path: path.join(Self::DEFAULT_PENDING_DIR),
             epochs_backup_path: path.join(Self::DEFAULT_EPOCHS_DIR),
@@ -75,6 +85,47 @@ impl From<&Path> for BackupEngineConfig {
     }
 }
 
+/// Database with backups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BackupDb {
+    State,
+    Pending,
+    Epoch(u64),
+}
+
+impl BackupDb {
+    /// Path of the backups of the database, relative to the backup root
+    /// locally and to the prefix of the remote storage.
+    pub fn path(&self) -> String {
+        match self {
+            Self::State => BackupEngineConfig::DEFAULT_STATE_DIR.to_string(),
+            Self::Pending => BackupEngineConfig::DEFAULT_PENDING_DIR.to_string(),
+            Self::Epoch(epoch_number) => {
+                format!("{}/{epoch_number}", BackupEngineConfig::DEFAULT_EPOCHS_DIR)
+            }
+        }
+    }
+
+    pub(crate) fn from_path(path: &str) -> Option<Self> {
+        match path {
+            BackupEngineConfig::DEFAULT_STATE_DIR => Some(Self::State),
+            BackupEngineConfig::DEFAULT_PENDING_DIR => Some(Self::Pending),
+            path => path
+                .strip_prefix(BackupEngineConfig::DEFAULT_EPOCHS_DIR)?
+                .strip_prefix('/')?
+                .parse()
+                .ok()
+                .map(Self::Epoch),
+        }
+    }
+}
+
+impl std::fmt::Display for BackupDb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.path())
+    }
+}
+
 /// Client used to request a backup.
 #[derive(Clone)]
 pub struct BackupClient {
@@ -128,6 +179,8 @@ pub struct BackupEngine {
     backup_request: sync::mpsc::Receiver<BackupRequest>,
     state_max_backup_number: usize,
     pending_max_backup_number: usize,
+    remote: Option<RemoteBackups>,
+    restore_drill_interval: Option<Duration>,
     cancellation_token: CancellationToken,
 }
 
@@ -162,6 +215,8 @@ impl BackupEngine {
                 backup_request,
                 state_max_backup_number,
                 pending_max_backup_number,
+                remote: None,
+                restore_drill_interval: None,
                 cancellation_token,
             },
             BackupClient {
@@ -170,6 +225,54 @@ impl BackupEngine {
         ))
     }
 
+    /// Upload the backups to the given remote storage once created.
+    pub fn with_remote(mut self, remote: RemoteBackups) -> Self {
+        self.remote = Some(remote);
+        self
+    }
+
+    /// Restore and verify the latest state and pending backups at the given
+    /// interval.
+    pub fn with_restore_drill(mut self, interval: Duration) -> Self {
+        self.restore_drill_interval = Some(interval);
+        self
+    }
+
+    /// Restore the latest state and pending backups into a scratch directory
+    /// and verify them.
+    pub fn run_restore_drill(&mut self) -> eyre::Result<VerificationReport> {
+        let mut verifier = Verifier::new()?;
+
+        for (db, engine) in [
+            (BackupDb::State, &mut self.state_engine),
+            (BackupDb::Pending, &mut self.pending_engine),
+        ] {
+            let Some(backup_id) = engine
+                .get_backup_info()
+                .iter()
+                .map(|info| info.backup_id)
+                .max()
+            else {
+                continue;
+            };
+
+            let path = verifier.restore_path(db);
+            let restored = std::fs::create_dir_all(&path)
+                .map_err(eyre::Error::from)
+                .and_then(|()| {
+                    Ok(engine.restore_from_backup(
+                        &path,
+                        &path,
+                        &RestoreOptions::default(),
+                        backup_id,
+                    )?)
+                });
+            verifier.add(db, backup_id, restored);
+        }
+
+        Ok(verifier.finish())
+    }
+
     /// Create a new backup for the state, pending and epochs databases.
     /// This function will also purge old backups as configured.
     pub fn create_new_backup(&mut self, request: &BackupRequest) -> eyre::Result<()> {
@@ -220,13 +323,60 @@ impl BackupEngine {
     }
 
     /// Run the backup engine, listen for new backup requests.
+    ///
+    /// When a remote storage is configured, the backups are uploaded in the
+    /// background after each new backup.
     pub async fn run(mut self) -> eyre::Result<()> {
+        let uploader = self.remote.take().map(|remote| {
+            let notify = Arc::new(sync::Notify::new());
+            tokio::spawn(remote.run_uploader(
+                self.config.root_path.clone(),
+                notify.clone(),
+                self.cancellation_token.clone(),
+            ));
+
+            notify
+        });
+
+        let mut restore_drill = self.restore_drill_interval.map(|period| {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
+
         loop {
             tokio::select! {
                 _ = self.cancellation_token.cancelled() => {
                     info!("Backup engine cancelled");
                     break;
                 }
+                _ = async {
+                    match restore_drill.as_mut() {
+                        Some(interval) => {
+                            interval.tick().await;
+                        }
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    let (backup_engine, result) = tokio::task::spawn_blocking(move || {
+                        let mut backup_engine = self;
+                        let result = backup_engine.run_restore_drill();
+
+                        (backup_engine, result)
+                    })
+                    .await?;
+
+                    self = backup_engine;
+                    match result {
+                        Ok(report) if report.ok => info!("Restore drill succeeded"),
+                        Ok(report) => error!(
+                            report = %serde_json::to_string(&report).unwrap_or_default(),
+                            "Restore drill failed"
+                        ),
+                        Err(error) => error!("Failed to run the restore drill: {error:?}"),
+                    }
+                }
                 Some(request) = self.backup_request.recv() =>{
                     let (backup_engine, result) = tokio::task::spawn_blocking(move || {
                         let mut backup_engine = self;
@@ -238,6 +388,10 @@ impl BackupEngine {
 
                     self = backup_engine;
                     result?;
+
+                    if let Some(uploader) = &uploader {
+                        uploader.notify_one();
+                    }
                 }
             }
         }
@@ -269,6 +423,23 @@ impl BackupEngine {
         Ok(engine.restore_from_backup(db_path, db_path, &RestoreOptions::default(), version)?)
     }
 
+    /// Create a backup of a single database at the given backup path, outside
+    /// of the running engine, and return the id of the new backup.
+    pub fn backup_at(path: &Path, db: &DB) -> eyre::Result<u32> {
+        let env = rocksdb::Env::new()?;
+        let opts = rocksdb::backup::BackupEngineOptions::new(path)?;
+
+        let mut engine = RocksBackupEngine::open(&opts, &env)?;
+        engine.create_new_backup_flush(db.raw_rocksdb(), true)?;
+
+        engine
+            .get_backup_info()
+            .iter()
+            .map(|info| info.backup_id)
+            .max()
+            .ok_or_else(|| eyre!("No backup found at {} after creating one", path.display()))
+    }
+
     pub fn list_backups(path: &Path) -> eyre::Result<BackupReport> {
         let env = rocksdb::Env::new()?;
 
@@ -459,4 +630,49 @@ mod tests {
         cancellation_token.cancel();
         backup_handle.abort();
     }
+
+    #[test]
+    fn restore_drill_verifies_the_latest_backups() {
+        let tmp = TempDBDir::new();
+        let state_db = Arc::new(
+            StateStore::init_db(&tmp.path.join("state")).expect("state db should initialize"),
+        );
+        let pending_db = Arc::new(
+            PendingStore::init_db(&tmp.path.join("pending")).expect("pending db should initialize"),
+        );
+        let (mut backup_engine, _) = BackupEngine::new(
+            &tmp.path.join("backup"),
+            state_db,
+            pending_db,
+            10,
+            10,
+            CancellationToken::new(),
+        )
+        .expect("backup engine should initialize");
+
+        let report = backup_engine
+            .run_restore_drill()
+            .expect("restore drill should run");
+        assert!(report.ok);
+        assert!(report.databases.is_empty());
+
+        for _ in 0..2 {
+            backup_engine
+                .create_new_backup(&BackupRequest { epoch_db: None })
+                .expect("backup should be created");
+        }
+
+        let report = backup_engine
+            .run_restore_drill()
+            .expect("restore drill should run");
+        assert!(report.ok, "{report:#?}");
+        assert_eq!(
+            report
+                .databases
+                .iter()
+                .map(|db| (db.db.as_str(), db.backup_id))
+                .collect::<Vec<_>>(),
+            [("state", 2), ("pending", 2)]
+        );
+    }
 }
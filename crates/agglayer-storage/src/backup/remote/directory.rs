@@ -0,0 +1,103 @@
+//! Object storage in a local directory, standing in for an S3-compatible
+//! bucket in tests.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::{file_checksum, ObjectInfo, ObjectStore};
+
+/// Object storage keeping each object in a file of a local directory, at the
+/// path of its key.
+pub struct DirectoryObjectStore {
+    root: PathBuf,
+}
+
+impl DirectoryObjectStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+
+    fn create_parent(path: &Path) -> io::Result<()> {
+        match path.parent() {
+            Some(parent) => std::fs::create_dir_all(parent),
+            None => Ok(()),
+        }
+    }
+
+    fn collect_keys(dir: &Path, prefix: &str, keys: &mut Vec<String>) -> io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let key = format!("{prefix}{}", entry.file_name().to_string_lossy());
+            if entry.file_type()?.is_dir() {
+                Self::collect_keys(&entry.path(), &format!("{key}/"), keys)?;
+            } else {
+                keys.push(key);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for DirectoryObjectStore {
+    async fn head(&self, key: &str) -> eyre::Result<Option<ObjectInfo>> {
+        let path = self.path(key);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let (size, sha256) = file_checksum(&path).await?;
+
+        Ok(Some(ObjectInfo {
+            size,
+            sha256: Some(sha256),
+        }))
+    }
+
+    async fn put_file(&self, key: &str, file: &Path, _sha256: &str) -> eyre::Result<()> {
+        let path = self.path(key);
+        Self::create_parent(&path)?;
+        std::fs::copy(file, path)?;
+
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, content: Vec<u8>) -> eyre::Result<()> {
+        let path = self.path(key);
+        Self::create_parent(&path)?;
+        std::fs::write(path, content)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> eyre::Result<Option<Vec<u8>>> {
+        match std::fs::read(self.path(key)) {
+            Ok(content) => Ok(Some(content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn get_to_file(&self, key: &str, file: &Path) -> eyre::Result<()> {
+        std::fs::copy(self.path(key), file)?;
+
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> eyre::Result<Vec<String>> {
+        let mut keys = Vec::new();
+        if self.root.is_dir() {
+            Self::collect_keys(&self.root, "", &mut keys)?;
+        }
+        keys.retain(|key| key.starts_with(prefix));
+        keys.sort();
+
+        Ok(keys)
+    }
+}
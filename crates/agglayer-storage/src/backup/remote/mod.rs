@@ -0,0 +1,531 @@
+//! Offsite copies of the RocksDB backups in an S3-compatible object storage.
+//!
+//! Each local backup is uploaded file by file, followed by a manifest listing
+//! the files with their size and SHA-256. The manifest is only written once
+//! every file is stored, so a backup without manifest is an incomplete upload
+//! that is resumed on the next sync, skipping the files already present.
+//!
+//! The objects mirror the local backup directories:
+//!
+//! - `<prefix>/<db>/files/<path>`: the files of the RocksDB backup directory.
+//! - `<prefix>/<db>/manifests/<backup_id>.json`: the manifest of a backup.
+//!
+//! where `<db>` is `state`, `pending` or `epochs/<epoch_number>`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Read as _,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use agglayer_config::storage::backup::RemoteBackupConfig;
+use eyre::{bail, eyre, Context as _};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use super::{BackupDb, BackupEngineConfig, BackupEngineInfo, BackupReport};
+
+#[cfg(any(test, feature = "testutils"))]
+pub mod directory;
+mod s3;
+mod store;
+
+#[cfg(test)]
+mod tests;
+
+pub use s3::S3ObjectStore;
+pub use store::{ObjectInfo, ObjectStore};
+
+/// File of a backup, as recorded in its manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    /// Path relative to the backup directory.
+    pub path: String,
+    pub size: u64,
+    /// SHA-256 of the content, hex encoded.
+    pub sha256: String,
+}
+
+/// Description of a backup stored remotely.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub backup_id: u32,
+    /// Creation time of the backup, in seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Files of the backup, the RocksDB meta file being the last one.
+    pub files: Vec<ManifestFile>,
+    /// SHA-256 over the fields above, hex encoded.
+    pub checksum: String,
+}
+
+impl BackupManifest {
+    fn new(backup_id: u32, timestamp: i64, files: Vec<ManifestFile>) -> Self {
+        let checksum = Self::compute_checksum(backup_id, timestamp, &files);
+
+        Self {
+            backup_id,
+            timestamp,
+            files,
+            checksum,
+        }
+    }
+
+    fn compute_checksum(backup_id: u32, timestamp: i64, files: &[ManifestFile]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{backup_id}\n{timestamp}\n"));
+        for file in files {
+            hasher.update(format!("{} {} {}\n", file.path, file.size, file.sha256));
+        }
+
+        hex::encode(hasher.finalize())
+    }
+
+    /// Check that the manifest is consistent with its checksum.
+    pub fn verify(&self) -> eyre::Result<()> {
+        let expected = Self::compute_checksum(self.backup_id, self.timestamp, &self.files);
+        if expected != self.checksum {
+            bail!(
+                "Checksum mismatch for the manifest of backup {}",
+                self.backup_id
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The RocksDB meta file of the backup.
+    fn meta_file(&self) -> Option<&ManifestFile> {
+        self.files
+            .last()
+            .filter(|file| file.path == meta_path(self.backup_id))
+    }
+
+    pub fn info(&self) -> BackupEngineInfo {
+        BackupEngineInfo {
+            backup_id: self.backup_id,
+            timestamp: chrono::DateTime::<chrono::Utc>::from_timestamp(self.timestamp, 0)
+                .unwrap_or_default(),
+            size: self.files.iter().map(|file| file.size).sum(),
+            // The meta file is not accounted for by RocksDB.
+            num_files: self
+                .files
+                .len()
+                .saturating_sub(1)
+                .try_into()
+                .unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// Remote copies of the backups.
+#[derive(Clone)]
+pub struct RemoteBackups {
+    store: Arc<dyn ObjectStore>,
+    prefix: String,
+}
+
+impl RemoteBackups {
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: impl Into<String>) -> Self {
+        Self {
+            store,
+            prefix: prefix.into().trim_matches('/').to_string(),
+        }
+    }
+
+    /// Remote backups in the S3-compatible bucket of the configuration.
+    pub async fn from_config(config: &RemoteBackupConfig) -> Self {
+        Self::new(
+            Arc::new(S3ObjectStore::from_config(config).await),
+            config.prefix.clone(),
+        )
+    }
+
+    fn key(&self, path: &str) -> String {
+        if self.prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}/{path}", self.prefix)
+        }
+    }
+
+    fn file_key(&self, db: BackupDb, path: &str) -> String {
+        self.key(&format!("{db}/files/{path}"))
+    }
+
+    fn manifest_key(&self, db: BackupDb, backup_id: u32) -> String {
+        self.key(&format!("{db}/manifests/{backup_id}.json"))
+    }
+
+    /// Fetch and verify the manifest of a backup, if it was uploaded.
+    pub async fn manifest(
+        &self,
+        db: BackupDb,
+        backup_id: u32,
+    ) -> eyre::Result<Option<BackupManifest>> {
+        let Some(content) = self.store.get(&self.manifest_key(db, backup_id)).await? else {
+            return Ok(None);
+        };
+
+        let manifest: BackupManifest = serde_json::from_slice(&content)
+            .with_context(|| format!("Invalid manifest for {db} backup {backup_id}"))?;
+        manifest.verify()?;
+        if manifest.backup_id != backup_id || manifest.meta_file().is_none() {
+            bail!("Inconsistent manifest for {db} backup {backup_id}");
+        }
+        // The files are written under the local backup directory on download.
+        if let Some(file) = manifest
+            .files
+            .iter()
+            .find(|file| !is_backup_file_path(&file.path))
+        {
+            bail!(
+                "Invalid file path {} in the manifest for {db} backup {backup_id}",
+                file.path
+            );
+        }
+
+        Ok(Some(manifest))
+    }
+
+    /// Upload a local backup, skipping the files already stored remotely.
+    ///
+    /// `backup_dir` is the RocksDB backup directory of the database.
+    pub async fn upload(
+        &self,
+        db: BackupDb,
+        backup_dir: &Path,
+        backup_id: u32,
+    ) -> eyre::Result<BackupManifest> {
+        let local = LocalBackup::read(backup_dir, backup_id)?;
+
+        let meta_path = meta_path(backup_id);
+        let (meta_size, meta_sha256) = file_checksum(&backup_dir.join(&meta_path)).await?;
+        if let Some(manifest) = self.manifest(db, backup_id).await? {
+            let meta = manifest
+                .meta_file()
+                .ok_or_else(|| eyre!("Missing meta file in the {db} manifest {backup_id}"))?;
+            if meta.size != meta_size || meta.sha256 != meta_sha256 {
+                bail!(
+                    "The remote {db} backup {backup_id} differs from the local one, refusing to \
+                     overwrite it"
+                );
+            }
+
+            return Ok(manifest);
+        }
+
+        let mut files = Vec::with_capacity(local.files.len() + 1);
+        for path in local.files {
+            files.push(self.upload_file(db, backup_dir, path).await?);
+        }
+        files.push(self.upload_file(db, backup_dir, meta_path).await?);
+
+        let manifest = BackupManifest::new(backup_id, local.timestamp, files);
+        self.store
+            .put(
+                &self.manifest_key(db, backup_id),
+                serde_json::to_vec_pretty(&manifest)?,
+            )
+            .await?;
+
+        info!("Uploaded {db} backup {backup_id}");
+
+        Ok(manifest)
+    }
+
+    async fn upload_file(
+        &self,
+        db: BackupDb,
+        backup_dir: &Path,
+        path: String,
+    ) -> eyre::Result<ManifestFile> {
+        let local_path = backup_dir.join(&path);
+        let (size, sha256) = file_checksum(&local_path).await?;
+        let key = self.file_key(db, &path);
+
+        let stored = self.store.head(&key).await?;
+        if stored.is_some_and(|info| info.size == size && info.sha256.as_ref() == Some(&sha256)) {
+            debug!("Skipping {key}, already uploaded");
+        } else {
+            self.store.put_file(&key, &local_path, &sha256).await?;
+        }
+
+        Ok(ManifestFile { path, size, sha256 })
+    }
+
+    /// Upload every local backup under `root` that isn't in `uploaded` yet.
+    ///
+    /// Failures are logged and retried on the next sync.
+    pub async fn sync(&self, root: &Path, uploaded: &mut BTreeSet<(BackupDb, u32)>) {
+        let config: BackupEngineConfig = root.into();
+        let mut dbs = vec![
+            (BackupDb::State, config.state_backup_path),
+            (BackupDb::Pending, config.pending_backup_path),
+        ];
+        if let Ok(entries) = std::fs::read_dir(&config.epochs_backup_path) {
+            dbs.extend(entries.flatten().filter_map(|entry| {
+                let epoch_number = entry.file_name().to_string_lossy().parse().ok()?;
+
+                Some((BackupDb::Epoch(epoch_number), entry.path()))
+            }));
+        }
+        dbs.sort();
+
+        for (db, backup_dir) in dbs {
+            let backup_ids = match local_backup_ids(&backup_dir) {
+                Ok(backup_ids) => backup_ids,
+                Err(error) => {
+                    warn!("Failed to list the local {db} backups: {error:?}");
+                    continue;
+                }
+            };
+
+            for backup_id in backup_ids {
+                if uploaded.contains(&(db, backup_id)) {
+                    continue;
+                }
+
+                match self.upload(db, &backup_dir, backup_id).await {
+                    Ok(_) => {
+                        uploaded.insert((db, backup_id));
+                    }
+                    Err(error) => warn!("Failed to upload {db} backup {backup_id}: {error:?}"),
+                }
+            }
+        }
+    }
+
+    /// Upload the backups under `root` on startup and whenever `notify` is
+    /// notified, until cancelled.
+    pub async fn run_uploader(
+        self,
+        root: PathBuf,
+        notify: Arc<Notify>,
+        cancellation_token: CancellationToken,
+    ) {
+        let mut uploaded = BTreeSet::new();
+
+        loop {
+            self.sync(&root, &mut uploaded).await;
+
+            tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    info!("Remote backup uploader cancelled");
+                    break;
+                }
+                _ = notify.notified() => {}
+            }
+        }
+    }
+
+    /// List the backups stored remotely.
+    pub async fn list(&self) -> eyre::Result<BackupReport> {
+        let root = self.key("");
+        let mut backups = BTreeMap::<BackupDb, Vec<BackupEngineInfo>>::new();
+
+        let mut manifests = Vec::new();
+        for key in self.store.list(&root).await? {
+            let Some((db, file)) = key[root.len()..].split_once("/manifests/") else {
+                continue;
+            };
+            let Some(db) = BackupDb::from_path(db) else {
+                continue;
+            };
+            let Some(Ok(backup_id)) = file.strip_suffix(".json").map(str::parse::<u32>) else {
+                continue;
+            };
+            manifests.push((db, backup_id));
+        }
+        manifests.sort();
+
+        for (db, backup_id) in manifests {
+            match self.manifest(db, backup_id).await {
+                Ok(Some(manifest)) => backups.entry(db).or_default().push(manifest.info()),
+                Ok(None) => {}
+                Err(error) => warn!("Skipping {db} backup {backup_id}: {error:?}"),
+            }
+        }
+
+        let state = backups.remove(&BackupDb::State).unwrap_or_default();
+        let pending = backups.remove(&BackupDb::Pending).unwrap_or_default();
+        let epochs = backups.into_iter().filter_map(|(db, infos)| match db {
+            BackupDb::Epoch(epoch_number) => Some((epoch_number, infos)),
+            BackupDb::State | BackupDb::Pending => None,
+        });
+
+        Ok(BackupReport::new(
+            state.into_iter(),
+            pending.into_iter(),
+            epochs,
+        ))
+    }
+
+    /// Download a backup into the RocksDB backup directory `backup_dir`, so it
+    /// can be restored with [`super::BackupEngine::restore_at`].
+    ///
+    /// Files already present locally with the expected checksum are kept.
+    /// Manifests listing a file outside of the backup directory are refused.
+    /// Every downloaded file is verified against the manifest, and the meta
+    /// file is written last so an interrupted download is never seen as a
+    /// backup by RocksDB.
+    pub async fn download(
+        &self,
+        db: BackupDb,
+        backup_id: u32,
+        backup_dir: &Path,
+    ) -> eyre::Result<()> {
+        let manifest = self
+            .manifest(db, backup_id)
+            .await?
+            .ok_or_else(|| eyre!("No remote {db} backup {backup_id}"))?;
+
+        let meta = manifest
+            .meta_file()
+            .ok_or_else(|| eyre!("Missing meta file in the {db} manifest {backup_id}"))?;
+        let local_meta = backup_dir.join(&meta.path);
+        if local_meta.exists() {
+            if file_checksum(&local_meta).await? == (meta.size, meta.sha256.clone()) {
+                info!("The {db} backup {backup_id} is already available locally");
+                return Ok(());
+            }
+
+            bail!(
+                "A different {db} backup {backup_id} exists locally in {}",
+                backup_dir.display()
+            );
+        }
+
+        for file in &manifest.files {
+            let path = backup_dir.join(&file.path);
+            if path.exists() && file_checksum(&path).await? == (file.size, file.sha256.clone()) {
+                debug!("Skipping {}, already downloaded", file.path);
+                continue;
+            }
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut download_path = path.clone().into_os_string();
+            download_path.push(".download");
+            let download_path = PathBuf::from(download_path);
+            self.store
+                .get_to_file(&self.file_key(db, &file.path), &download_path)
+                .await?;
+
+            let (size, sha256) = file_checksum(&download_path).await?;
+            if size != file.size || sha256 != file.sha256 {
+                std::fs::remove_file(&download_path)?;
+                bail!(
+                    "Checksum mismatch for {} of the {db} backup {backup_id}",
+                    file.path
+                );
+            }
+            std::fs::rename(&download_path, &path)?;
+        }
+
+        info!("Downloaded {db} backup {backup_id}");
+
+        Ok(())
+    }
+}
+
+/// Path of the RocksDB meta file of a backup, relative to the backup
+/// directory.
+fn meta_path(backup_id: u32) -> String {
+    format!("meta/{backup_id}")
+}
+
+/// Whether `path` is a file of a RocksDB backup directory: a relative path
+/// within `private/`, `shared*/` or `meta/`, without any `..` component.
+fn is_backup_file_path(path: &str) -> bool {
+    let mut components = Path::new(path).components();
+    let Some(Component::Normal(dir)) = components.next() else {
+        return false;
+    };
+    let dir = dir.to_string_lossy();
+
+    (dir == "private" || dir == "meta" || dir.starts_with("shared"))
+        && components.clone().next().is_some()
+        && components.all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// Identifiers of the completed backups of a RocksDB backup directory.
+///
+/// RocksDB writes the meta file of a backup once all its files are written,
+/// so only the completed backups are returned.
+fn local_backup_ids(backup_dir: &Path) -> eyre::Result<Vec<u32>> {
+    let meta_dir = backup_dir.join("meta");
+    if !meta_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut backup_ids = std::fs::read_dir(meta_dir)?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_string_lossy().parse().ok())
+        .collect::<Vec<u32>>();
+    backup_ids.sort();
+
+    Ok(backup_ids)
+}
+
+/// Content of a local backup, read from its RocksDB meta file.
+struct LocalBackup {
+    timestamp: i64,
+    /// Files of the backup, excluding the meta file.
+    files: Vec<String>,
+}
+
+impl LocalBackup {
+    fn read(backup_dir: &Path, backup_id: u32) -> eyre::Result<Self> {
+        let meta = std::fs::read_to_string(backup_dir.join(meta_path(backup_id)))
+            .with_context(|| format!("Failed to read the meta file of backup {backup_id}"))?;
+
+        // The meta file starts with an optional schema version, followed by the
+        // timestamp, the sequence number, optional application metadata and the
+        // number of files. Each file is then listed on its own line, starting
+        // with its path.
+        let mut lines = meta
+            .lines()
+            .filter(|line| !line.starts_with("schema_version"));
+        let timestamp = lines
+            .next()
+            .and_then(|line| line.trim().parse().ok())
+            .ok_or_else(|| eyre!("Invalid timestamp in the meta file of backup {backup_id}"))?;
+
+        let files = lines
+            .filter_map(|line| line.split_whitespace().next())
+            .filter(|path| path.starts_with("private/") || path.starts_with("shared"))
+            .map(str::to_string)
+            .collect();
+
+        Ok(Self { timestamp, files })
+    }
+}
+
+/// Size and SHA-256 of a file, hex encoded.
+pub(crate) async fn file_checksum(path: &Path) -> eyre::Result<(u64, String)> {
+    let path = path.to_path_buf();
+
+    tokio::task::spawn_blocking(move || {
+        let mut file = std::fs::File::open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0; 1 << 16];
+        let mut size = 0;
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+            size += read as u64;
+        }
+
+        Ok((size, hex::encode(hasher.finalize())))
+    })
+    .await?
+}
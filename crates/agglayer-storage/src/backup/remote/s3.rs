@@ -0,0 +1,163 @@
+use std::path::Path;
+
+use agglayer_config::storage::backup::RemoteBackupConfig;
+use aws_config::{BehaviorVersion, Region};
+use aws_sdk_s3::primitives::ByteStream;
+use tokio::io::AsyncWriteExt as _;
+
+use super::{ObjectInfo, ObjectStore};
+
+/// Name of the user metadata holding the SHA-256 of an object.
+const SHA256_METADATA: &str = "sha256";
+
+/// Object storage in an S3-compatible bucket.
+///
+/// Objects are uploaded in a single request, which bounds their size to 5
+/// GiB. RocksDB backup files stay well below that.
+pub struct S3ObjectStore {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl S3ObjectStore {
+    /// Connect to the bucket of the configuration.
+    ///
+    /// Credentials are loaded through the default AWS provider chain. The
+    /// region is taken from the configuration, falling back to the provider
+    /// chain (e.g. the `AWS_REGION` environment variable).
+    pub async fn from_config(config: &RemoteBackupConfig) -> Self {
+        let mut loader = aws_config::defaults(BehaviorVersion::latest());
+        if let Some(region) = &config.region {
+            loader = loader.region(Region::new(region.clone()));
+        }
+        let mut s3_config = aws_sdk_s3::config::Builder::from(&loader.load().await);
+        if let Some(endpoint) = &config.endpoint {
+            // S3-compatible stores, e.g. MinIO, usually only support path-style
+            // addressing.
+            s3_config = s3_config
+                .endpoint_url(endpoint.as_str())
+                .force_path_style(true);
+        }
+
+        Self {
+            client: aws_sdk_s3::Client::from_conf(s3_config.build()),
+            bucket: config.bucket.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for S3ObjectStore {
+    async fn head(&self, key: &str) -> eyre::Result<Option<ObjectInfo>> {
+        match self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => Ok(Some(ObjectInfo {
+                size: output.content_length().unwrap_or_default().try_into()?,
+                sha256: output
+                    .metadata()
+                    .and_then(|metadata| metadata.get(SHA256_METADATA))
+                    .cloned(),
+            })),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_not_found()) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn put_file(&self, key: &str, file: &Path, sha256: &str) -> eyre::Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from_path(file).await?)
+            .metadata(SHA256_METADATA, sha256)
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, content: Vec<u8>) -> eyre::Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(content))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> eyre::Result<Option<Vec<u8>>> {
+        match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => Ok(Some(output.body.collect().await?.to_vec())),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_no_such_key()) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn get_to_file(&self, key: &str, file: &Path) -> eyre::Result<()> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await?;
+
+        let mut body = output.body.into_async_read();
+        let mut file = tokio::fs::File::create(file).await?;
+        tokio::io::copy(&mut body, &mut file).await?;
+        file.flush().await?;
+        file.sync_all().await?;
+
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> eyre::Result<Vec<String>> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut keys = Vec::new();
+        while let Some(page) = pages.next().await {
+            keys.extend(
+                page?
+                    .contents()
+                    .iter()
+                    .filter_map(|object| object.key().map(str::to_owned)),
+            );
+        }
+
+        Ok(keys)
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+/// Size and checksum of an object of the remote storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectInfo {
+    pub size: u64,
+    /// SHA-256 of the content, hex encoded, if it was recorded on upload.
+    pub sha256: Option<String>,
+}
+
+/// Object storage holding the remote backups.
+///
+/// Keys are `/`-separated paths.
+#[async_trait::async_trait]
+pub trait ObjectStore: Send + Sync {
+    /// Size and checksum of the object `key`, if it exists.
+    async fn head(&self, key: &str) -> eyre::Result<Option<ObjectInfo>>;
+
+    /// Upload the content of `file` as the object `key`, recording its
+    /// SHA-256.
+    async fn put_file(&self, key: &str, file: &Path, sha256: &str) -> eyre::Result<()>;
+
+    /// Upload `content` as the object `key`.
+    async fn put(&self, key: &str, content: Vec<u8>) -> eyre::Result<()>;
+
+    /// Content of the object `key`, if it exists.
+    async fn get(&self, key: &str) -> eyre::Result<Option<Vec<u8>>>;
+
+    /// Download the object `key` into `file`.
+    async fn get_to_file(&self, key: &str, file: &Path) -> eyre::Result<()>;
+
+    /// Keys of the objects starting with `prefix`.
+    async fn list(&self, prefix: &str) -> eyre::Result<Vec<String>>;
+}
@@ -0,0 +1,322 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use agglayer_types::NetworkId;
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    directory::DirectoryObjectStore, is_backup_file_path, BackupManifest, ManifestFile,
+    RemoteBackups,
+};
+use crate::{
+    backup::{BackupClient, BackupDb, BackupEngine, BackupEngineInfo, BackupRequest},
+    stores::{
+        pending::PendingStore, state::StateStore, RateLimiterStateReader as _,
+        RateLimiterStateWriter as _,
+    },
+    tests::TempDBDir,
+};
+
+const COMPONENT: &str = "send-certificate";
+
+struct Setup {
+    tmp: TempDBDir,
+    state: StateStore,
+    engine: BackupEngine,
+    remote: RemoteBackups,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let tmp = TempDBDir::new();
+        let state_db = Arc::new(StateStore::init_db(&tmp.path.join("state")).unwrap());
+        let pending_db = Arc::new(PendingStore::init_db(&tmp.path.join("pending")).unwrap());
+        let (engine, _) = BackupEngine::new(
+            &tmp.path.join("backup"),
+            state_db.clone(),
+            pending_db,
+            10,
+            10,
+            CancellationToken::new(),
+        )
+        .unwrap();
+        let remote = RemoteBackups::new(
+            Arc::new(DirectoryObjectStore::new(tmp.path.join("remote"))),
+            "mainnet",
+        );
+
+        Self {
+            state: StateStore::new(state_db, BackupClient::noop()),
+            engine,
+            remote,
+            tmp,
+        }
+    }
+
+    fn backup_root(&self) -> PathBuf {
+        self.tmp.path.join("backup")
+    }
+
+    fn remote_path(&self, path: &str) -> PathBuf {
+        self.tmp.path.join("remote").join("mainnet").join(path)
+    }
+
+    fn backup_with_events(&mut self, events: &[SystemTime]) {
+        self.state
+            .set_rate_limiter_events(NetworkId::new(1), COMPONENT, events)
+            .unwrap();
+        self.engine
+            .create_new_backup(&BackupRequest { epoch_db: None })
+            .unwrap();
+    }
+}
+
+fn events(count: u64) -> Vec<SystemTime> {
+    (0..count)
+        .map(|i| SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000 + i))
+        .collect()
+}
+
+fn remote_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap().flatten() {
+        if entry.file_type().unwrap().is_dir() {
+            files.extend(remote_files(&entry.path()));
+        } else {
+            files.push(entry.path());
+        }
+    }
+
+    files
+}
+
+#[tokio::test]
+async fn sync_uploads_every_backup_with_a_manifest() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup.backup_with_events(&events(2));
+
+    let mut uploaded = BTreeSet::new();
+    setup.remote.sync(&setup.backup_root(), &mut uploaded).await;
+
+    assert_eq!(
+        uploaded,
+        BTreeSet::from([
+            (BackupDb::State, 1),
+            (BackupDb::State, 2),
+            (BackupDb::Pending, 1),
+            (BackupDb::Pending, 2),
+        ])
+    );
+
+    let local = BackupEngine::list_backups(&setup.backup_root()).unwrap();
+    let remote = setup.remote.list().await.unwrap();
+    let ids = |infos: &[BackupEngineInfo]| {
+        infos
+            .iter()
+            .map(|info| (info.backup_id, info.num_files))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(remote.get_state()), ids(local.get_state()));
+    assert_eq!(ids(remote.get_pending()), ids(local.get_pending()));
+    assert!(remote.get_epochs().is_empty());
+
+    let manifest = setup
+        .remote
+        .manifest(BackupDb::State, 2)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(manifest.files.last().unwrap().path, "meta/2");
+}
+
+#[tokio::test]
+async fn sync_resumes_interrupted_uploads() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup
+        .remote
+        .sync(&setup.backup_root(), &mut BTreeSet::new())
+        .await;
+    let manifest = setup
+        .remote
+        .manifest(BackupDb::State, 1)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Simulate an upload interrupted before the manifest and one of the files.
+    std::fs::remove_file(setup.remote_path("state/manifests/1.json")).unwrap();
+    let removed = manifest.files[0].path.clone();
+    std::fs::remove_file(setup.remote_path(&format!("state/files/{removed}"))).unwrap();
+    assert!(setup
+        .remote
+        .manifest(BackupDb::State, 1)
+        .await
+        .unwrap()
+        .is_none());
+
+    let mut uploaded = BTreeSet::new();
+    setup.remote.sync(&setup.backup_root(), &mut uploaded).await;
+
+    assert!(uploaded.contains(&(BackupDb::State, 1)));
+    assert_eq!(
+        setup.remote.manifest(BackupDb::State, 1).await.unwrap(),
+        Some(manifest)
+    );
+    assert!(setup
+        .remote_path(&format!("state/files/{removed}"))
+        .is_file());
+}
+
+#[tokio::test]
+async fn downloaded_backup_restores_the_database() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup.backup_with_events(&events(3));
+    setup
+        .remote
+        .sync(&setup.backup_root(), &mut BTreeSet::new())
+        .await;
+
+    // The local backups are lost, only the remote copies remain.
+    std::fs::remove_dir_all(setup.backup_root()).unwrap();
+    let restore_dir = setup.tmp.path.join("restore");
+    let backup_dir = restore_dir.join("backup").join(BackupDb::State.path());
+    setup
+        .remote
+        .download(BackupDb::State, 1, &backup_dir)
+        .await
+        .unwrap();
+
+    let db_path = restore_dir.join("state");
+    BackupEngine::restore_at(&backup_dir, &db_path, 1).unwrap();
+
+    let restored = StateStore::new_with_path(&db_path, BackupClient::noop()).unwrap();
+    assert_eq!(
+        restored
+            .get_rate_limiter_events(NetworkId::new(1), COMPONENT)
+            .unwrap(),
+        events(1)
+    );
+
+    // Downloading again is a no-op.
+    setup
+        .remote
+        .download(BackupDb::State, 1, &backup_dir)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn corrupted_remote_file_is_rejected() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup
+        .remote
+        .sync(&setup.backup_root(), &mut BTreeSet::new())
+        .await;
+
+    let file = remote_files(&setup.remote_path("state/files/private"))
+        .into_iter()
+        .next()
+        .unwrap();
+    std::fs::write(file, b"corrupted").unwrap();
+
+    let backup_dir = setup.tmp.path.join("restore");
+    let error = setup
+        .remote
+        .download(BackupDb::State, 1, &backup_dir)
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("Checksum mismatch"), "{error:?}");
+    assert!(!backup_dir.join("meta/1").exists());
+}
+
+#[tokio::test]
+async fn tampered_manifest_is_rejected() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup
+        .remote
+        .sync(&setup.backup_root(), &mut BTreeSet::new())
+        .await;
+
+    let path = setup.remote_path("state/manifests/1.json");
+    let manifest = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, manifest.replace("\"meta/1\"", "\"meta/01\"")).unwrap();
+
+    assert!(setup.remote.manifest(BackupDb::State, 1).await.is_err());
+    assert!(setup.remote.list().await.unwrap().get_state().is_empty());
+}
+
+#[tokio::test]
+async fn manifest_escaping_the_backup_directory_is_rejected() {
+    let mut setup = Setup::new();
+    setup.backup_with_events(&events(1));
+    setup
+        .remote
+        .sync(&setup.backup_root(), &mut BTreeSet::new())
+        .await;
+
+    // A manifest rewritten with a consistent checksum, writing out of the
+    // backup directory.
+    let path = setup.remote_path("state/manifests/1.json");
+    let manifest: BackupManifest = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    let mut files = manifest.files.clone();
+    files.insert(
+        0,
+        ManifestFile {
+            path: "private/../../escaped".to_string(),
+            ..files[0].clone()
+        },
+    );
+    let tampered = BackupManifest::new(manifest.backup_id, manifest.timestamp, files);
+    std::fs::write(&path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+
+    assert!(setup.remote.manifest(BackupDb::State, 1).await.is_err());
+    let backup_dir = setup.tmp.path.join("restore");
+    setup
+        .remote
+        .download(BackupDb::State, 1, &backup_dir)
+        .await
+        .unwrap_err();
+    assert!(!setup.tmp.path.join("escaped").exists());
+}
+
+#[test]
+fn backup_file_paths_stay_in_the_backup_directory() {
+    for path in [
+        "private/1/000008.sst",
+        "shared_checksum/000010_1_2.sst",
+        "meta/1",
+    ] {
+        assert!(is_backup_file_path(path), "{path}");
+    }
+    for path in [
+        "/etc/passwd",
+        "private/../../escaped",
+        "../meta/1",
+        "meta",
+        "private/",
+        "manifests/1.json",
+        "",
+    ] {
+        assert!(!is_backup_file_path(path), "{path}");
+    }
+}
+
+#[test]
+fn backup_db_paths_round_trip() {
+    for db in [BackupDb::State, BackupDb::Pending, BackupDb::Epoch(42)] {
+        assert_eq!(BackupDb::from_path(&db.path()), Some(db));
+    }
+    assert_eq!(BackupDb::Epoch(42).path(), "epochs/42");
+    assert_eq!(BackupDb::from_path("epochs"), None);
+    assert_eq!(BackupDb::from_path("epochs/x"), None);
+}
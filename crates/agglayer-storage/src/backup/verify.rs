@@ -0,0 +1,591 @@
+//! Restore drills: prove that backups are restorable and consistent.
+//!
+//! The backups are restored into a scratch directory and opened with the
+//! migration [`Builder`](crate::storage::Builder) of their store, exactly as
+//! the node would open them. The rows are then decoded with the
+//! [`diagnostics`] scans, and the invariants between the restored stores are
+//! checked. Nothing is written to the backups, and the scratch directory is
+//! removed once done.
+//!
+//! The cross-store invariants only involve the restored databases: a settled
+//! certificate is only looked up in its epoch if the backup of this epoch is
+//! part of the verification.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+use agglayer_types::{CertificateStatus, EpochNumber};
+use eyre::Context as _;
+use rocksdb::{Direction, ReadOptions};
+use serde::Serialize;
+
+use super::{BackupDb, BackupEngine};
+use crate::{
+    columns::{
+        certificate_header::CertificateHeaderColumn,
+        certificate_per_network::CertificatePerNetworkColumn,
+        epochs::certificates::CertificatePerIndexProtoColumn,
+        latest_settled_certificate_per_network::{
+            LatestSettledCertificatePerNetworkColumn, SettledCertificate,
+        },
+        pending_queue::{PendingQueueKey, PendingQueueProtoColumn},
+    },
+    diagnostics::{self, UnparsableRow},
+    storage::{DBError, DB},
+    stores::{pending::PendingStore, per_epoch::PerEpochStore, state::StateStore},
+};
+
+/// Outcome of the restoration of a backup.
+#[derive(Debug, Serialize)]
+pub struct DatabaseVerification {
+    pub db: String,
+    pub backup_id: u32,
+    /// Why the backup could not be restored or opened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// An invariant that does not hold in the restored databases.
+#[derive(Debug, Serialize)]
+pub struct InvariantViolation {
+    pub invariant: &'static str,
+    pub details: String,
+}
+
+/// Machine-readable report of a verification.
+#[derive(Debug, Default, Serialize)]
+pub struct VerificationReport {
+    /// Whether every backup was restored, decoded and found consistent.
+    pub ok: bool,
+    pub databases: Vec<DatabaseVerification>,
+    pub unparsable_rows: Vec<UnparsableRow>,
+    pub violations: Vec<InvariantViolation>,
+}
+
+/// Restore the given backups from the backup directory `backup_root` and
+/// verify them.
+///
+/// Failures to restore or open a backup are part of the report, the error
+/// is only about the scratch directory.
+pub fn verify_backups(
+    backup_root: &Path,
+    backups: &[(BackupDb, u32)],
+) -> eyre::Result<VerificationReport> {
+    let mut verifier = Verifier::new()?;
+    for &(db, backup_id) in backups {
+        let restored = BackupEngine::restore_at(
+            &backup_root.join(db.path()),
+            &verifier.restore_path(db),
+            backup_id,
+        );
+        verifier.add(db, backup_id, restored);
+    }
+
+    Ok(verifier.finish())
+}
+
+/// Scratch directory removed on drop.
+struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    fn new() -> std::io::Result<Self> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "agglayer-restore-drill-{}-{nanos}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+
+        Ok(Self(path))
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Verification of backups restored into a scratch directory.
+pub(crate) struct Verifier {
+    report: VerificationReport,
+    state: Option<DB>,
+    pending: Option<DB>,
+    epochs: BTreeMap<u64, DB>,
+    // Declared last so the databases are closed before the directory is
+    // removed.
+    scratch: ScratchDir,
+}
+
+impl Verifier {
+    pub(crate) fn new() -> eyre::Result<Self> {
+        Ok(Self {
+            report: VerificationReport::default(),
+            state: None,
+            pending: None,
+            epochs: BTreeMap::new(),
+            scratch: ScratchDir::new().context("Failed to create the scratch directory")?,
+        })
+    }
+
+    /// Path the backup of `db` must be restored to.
+    pub(crate) fn restore_path(&self, db: BackupDb) -> PathBuf {
+        self.scratch.0.join(db.path())
+    }
+
+    /// Record the restoration of a backup into [`Self::restore_path`] and
+    /// open the restored database.
+    pub(crate) fn add(&mut self, db: BackupDb, backup_id: u32, restored: eyre::Result<()>) {
+        let path = self.restore_path(db);
+        let opened = restored
+            .context("Failed to restore the backup")
+            .and_then(|()| Self::open(db, &path).context("Failed to open the restored database"));
+
+        let error = match opened {
+            Ok(opened) => {
+                match db {
+                    BackupDb::State => self.state = Some(opened),
+                    BackupDb::Pending => self.pending = Some(opened),
+                    BackupDb::Epoch(epoch_number) => {
+                        self.epochs.insert(epoch_number, opened);
+                    }
+                }
+
+                None
+            }
+            Err(error) => Some(format!("{error:#}")),
+        };
+
+        self.report.databases.push(DatabaseVerification {
+            db: db.to_string(),
+            backup_id,
+            error,
+        });
+    }
+
+    fn open(db: BackupDb, path: &Path) -> eyre::Result<DB> {
+        Ok(match db {
+            BackupDb::State => StateStore::init_db(path)?,
+            BackupDb::Pending => PendingStore::init_db(path)?,
+            BackupDb::Epoch(_) => PerEpochStore::<(), ()>::init_db(path)?,
+        })
+    }
+
+    /// Scan and check the restored databases.
+    pub(crate) fn finish(mut self) -> VerificationReport {
+        self.scan();
+
+        let mut violations = Vec::new();
+        if let Some(state) = &self.state {
+            if let Err(error) = check_state(state, &self.epochs, &mut violations) {
+                violations.push(InvariantViolation {
+                    invariant: "state-readable",
+                    details: error.to_string(),
+                });
+            }
+        }
+        if let Some(pending) = &self.pending {
+            if let Err(error) = check_pending(pending, &mut violations) {
+                violations.push(InvariantViolation {
+                    invariant: "pending-readable",
+                    details: error.to_string(),
+                });
+            }
+        }
+        self.report.violations.extend(violations);
+
+        self.report.ok = self.report.databases.iter().all(|db| db.error.is_none())
+            && self.report.unparsable_rows.is_empty()
+            && self.report.violations.is_empty();
+
+        self.report
+    }
+
+    fn scan(&mut self) {
+        let report = &mut self.report;
+
+        if let Some(state) = &self.state {
+            report
+                .unparsable_rows
+                .extend(diagnostics::scan_column::<CertificateHeaderColumn>(
+                    state, "state",
+                ));
+            report
+                .unparsable_rows
+                .extend(diagnostics::scan_column::<CertificatePerNetworkColumn>(
+                    state, "state",
+                ));
+            report.unparsable_rows.extend(diagnostics::scan_column::<
+                LatestSettledCertificatePerNetworkColumn,
+            >(state, "state"));
+        }
+
+        if let Some(pending) = &self.pending {
+            match diagnostics::scan_unparsable_pending_rows(
+                &self.scratch.0.join(BackupDb::Pending.path()),
+            ) {
+                Ok(rows) => report.unparsable_rows.extend(rows),
+                Err(error) => report.violations.push(InvariantViolation {
+                    invariant: "pending-decode-scan",
+                    details: error.to_string(),
+                }),
+            }
+            report
+                .unparsable_rows
+                .extend(diagnostics::scan_column::<PendingQueueProtoColumn>(
+                    pending, "pending",
+                ));
+        }
+
+        for (&epoch_number, epoch) in &self.epochs {
+            let path = self.scratch.0.join(BackupDb::Epoch(epoch_number).path());
+            match diagnostics::scan_unparsable_epoch_db_rows(&path, epoch_number) {
+                Ok(rows) => report.unparsable_rows.extend(rows),
+                Err(error) => report.violations.push(InvariantViolation {
+                    invariant: "epoch-decode-scan",
+                    details: error.to_string(),
+                }),
+            }
+            report.unparsable_rows.extend(
+                diagnostics::scan_column::<CertificatePerIndexProtoColumn>(
+                    epoch,
+                    &format!("epoch {epoch_number}"),
+                ),
+            );
+        }
+    }
+}
+
+/// Check the invariants of the state DB, and between the state DB and the
+/// restored epochs.
+///
+/// Rows that fail to decode are skipped, the scan already reports them.
+fn check_state(
+    state: &DB,
+    epochs: &BTreeMap<u64, DB>,
+    violations: &mut Vec<InvariantViolation>,
+) -> Result<(), DBError> {
+    let headers = state
+        .iter_with_direction::<CertificateHeaderColumn>(ReadOptions::default(), Direction::Forward)?
+        .filter_map(Result::ok);
+
+    for (certificate_id, header) in headers {
+        if header.status != CertificateStatus::Settled {
+            continue;
+        }
+
+        let (Some(epoch_number), Some(certificate_index)) =
+            (header.epoch_number, header.certificate_index)
+        else {
+            violations.push(InvariantViolation {
+                invariant: "settled-certificate-assigned-to-epoch",
+                details: format!(
+                    "Settled certificate {certificate_id} is not assigned to an epoch"
+                ),
+            });
+            continue;
+        };
+
+        let Some(epoch) = epochs.get(&epoch_number.as_u64()) else {
+            continue;
+        };
+        match epoch.get::<CertificatePerIndexProtoColumn>(&certificate_index)? {
+            Some(certificate) if certificate.hash() == certificate_id => {}
+            Some(certificate) => violations.push(InvariantViolation {
+                invariant: "settled-certificate-in-epoch",
+                details: format!(
+                    "Settled certificate {certificate_id} is at index {certificate_index} of \
+                     epoch {epoch_number}, which holds certificate {}",
+                    certificate.hash()
+                ),
+            }),
+            None => violations.push(InvariantViolation {
+                invariant: "settled-certificate-in-epoch",
+                details: format!(
+                    "Settled certificate {certificate_id} is missing at index {certificate_index} \
+                     of epoch {epoch_number}"
+                ),
+            }),
+        }
+    }
+
+    for (&epoch_number, epoch) in epochs {
+        let certificates = epoch
+            .iter_with_direction::<CertificatePerIndexProtoColumn>(
+                ReadOptions::default(),
+                Direction::Forward,
+            )?
+            .filter_map(Result::ok);
+
+        for (certificate_index, certificate) in certificates {
+            let certificate_id = certificate.hash();
+            let header = state.get::<CertificateHeaderColumn>(&certificate_id)?;
+            let consistent = header.is_some_and(|header| {
+                header.status == CertificateStatus::Settled
+                    && header.epoch_number == Some(EpochNumber::new(epoch_number))
+                    && header.certificate_index == Some(certificate_index)
+            });
+            if !consistent {
+                violations.push(InvariantViolation {
+                    invariant: "epoch-certificate-has-settled-header",
+                    details: format!(
+                        "Certificate {certificate_id} at index {certificate_index} of epoch \
+                         {epoch_number} has no matching settled header"
+                    ),
+                });
+            }
+        }
+    }
+
+    let latest_settled = state
+        .iter_with_direction::<LatestSettledCertificatePerNetworkColumn>(
+            ReadOptions::default(),
+            Direction::Forward,
+        )?
+        .filter_map(Result::ok);
+
+    for (network_id, SettledCertificate(certificate_id, height, epoch_number, certificate_index)) in
+        latest_settled
+    {
+        let header = state.get::<CertificateHeaderColumn>(&certificate_id)?;
+        let consistent = header.is_some_and(|header| {
+            header.status == CertificateStatus::Settled
+                && header.network_id == network_id
+                && header.height == height
+                && header.epoch_number == Some(epoch_number)
+                && header.certificate_index == Some(certificate_index)
+        });
+        if !consistent {
+            violations.push(InvariantViolation {
+                invariant: "latest-settled-certificate-has-header",
+                details: format!(
+                    "Latest settled certificate {certificate_id} of network {network_id} at \
+                     height {height} has no matching settled header"
+                ),
+            });
+        }
+    }
+
+    let per_network = state
+        .iter_with_direction::<CertificatePerNetworkColumn>(
+            ReadOptions::default(),
+            Direction::Forward,
+        )?
+        .filter_map(Result::ok);
+
+    for (key, certificate_id) in per_network {
+        let header = state.get::<CertificateHeaderColumn>(&certificate_id)?;
+        let consistent = header.is_some_and(|header| {
+            header.status == CertificateStatus::Settled
+                && header.network_id.to_u32() == key.network_id
+                && header.height == key.height
+        });
+        if !consistent {
+            violations.push(InvariantViolation {
+                invariant: "certificate-per-network-has-header",
+                details: format!(
+                    "Certificate {certificate_id} of network {} at height {} has no matching \
+                     settled header",
+                    key.network_id, key.height
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the invariants of the pending DB.
+fn check_pending(pending: &DB, violations: &mut Vec<InvariantViolation>) -> Result<(), DBError> {
+    let certificates = pending
+        .iter_with_direction::<PendingQueueProtoColumn>(ReadOptions::default(), Direction::Forward)?
+        .filter_map(Result::ok);
+
+    for (PendingQueueKey(network_id, height), certificate) in certificates {
+        if certificate.network_id != network_id || certificate.height != height {
+            violations.push(InvariantViolation {
+                invariant: "pending-certificate-matches-key",
+                details: format!(
+                    "Pending certificate {} of network {} at height {} is stored for network \
+                     {network_id} at height {height}",
+                    certificate.hash(),
+                    certificate.network_id,
+                    certificate.height
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use agglayer_types::{Certificate, CertificateIndex, Height, NetworkId};
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::{
+        backup::{BackupClient, BackupRequest},
+        stores::StateWriter as _,
+        tests::TempDBDir,
+    };
+
+    struct Setup {
+        tmp: TempDBDir,
+        state_db: Arc<DB>,
+        state: StateStore,
+        epoch: Arc<DB>,
+        engine: BackupEngine,
+    }
+
+    impl Setup {
+        fn new() -> Self {
+            let tmp = TempDBDir::new();
+            let state_db = Arc::new(StateStore::init_db(&tmp.path.join("state")).unwrap());
+            let pending_db = Arc::new(PendingStore::init_db(&tmp.path.join("pending")).unwrap());
+            let epoch = Arc::new(
+                PerEpochStore::<(), ()>::init_db(&tmp.path.join("epochs").join("0")).unwrap(),
+            );
+            let (engine, _) = BackupEngine::new(
+                &tmp.path.join("backup"),
+                state_db.clone(),
+                pending_db,
+                10,
+                10,
+                CancellationToken::new(),
+            )
+            .unwrap();
+
+            Self {
+                state: StateStore::new(state_db.clone(), BackupClient::noop()),
+                state_db,
+                epoch,
+                engine,
+                tmp,
+            }
+        }
+
+        /// Settle `certificate` at `index` of epoch 0, optionally storing
+        /// it in the epoch.
+        fn settle(&self, certificate: &Certificate, index: u64, in_epoch: bool) {
+            let index = CertificateIndex::new(index);
+            self.state
+                .insert_certificate_header(certificate, CertificateStatus::Pending)
+                .unwrap();
+            self.state
+                .assign_certificate_to_epoch(&certificate.hash(), &EpochNumber::ZERO, &index)
+                .unwrap();
+            if in_epoch {
+                self.epoch
+                    .put::<CertificatePerIndexProtoColumn>(&index, certificate)
+                    .unwrap();
+            }
+        }
+
+        fn backup(&mut self) -> eyre::Result<VerificationReport> {
+            self.engine
+                .create_new_backup(&BackupRequest { epoch_db: None })
+                .unwrap();
+            self.engine
+                .create_new_backup(&BackupRequest {
+                    epoch_db: Some((self.epoch.clone(), EpochNumber::ZERO)),
+                })
+                .unwrap();
+
+            verify_backups(
+                &self.tmp.path.join("backup"),
+                &[
+                    (BackupDb::State, 1),
+                    (BackupDb::Pending, 1),
+                    (BackupDb::Epoch(0), 1),
+                ],
+            )
+        }
+    }
+
+    #[test]
+    fn consistent_backups_are_verified() {
+        let mut setup = Setup::new();
+        setup.settle(
+            &Certificate::new_for_test(NetworkId::new(1), Height::ZERO),
+            0,
+            true,
+        );
+
+        let report = setup.backup().unwrap();
+
+        assert!(report.ok, "{report:#?}");
+        assert_eq!(report.databases.len(), 3);
+    }
+
+    #[test]
+    fn settled_certificate_missing_from_its_epoch_is_reported() {
+        let mut setup = Setup::new();
+        let certificate = Certificate::new_for_test(NetworkId::new(1), Height::ZERO);
+        setup.settle(&certificate, 0, false);
+
+        let report = setup.backup().unwrap();
+
+        assert!(!report.ok);
+        assert_eq!(report.violations.len(), 1, "{report:#?}");
+        assert_eq!(
+            report.violations[0].invariant,
+            "settled-certificate-in-epoch"
+        );
+        assert!(report.violations[0]
+            .details
+            .contains(&certificate.hash().to_string()));
+    }
+
+    #[test]
+    fn unparsable_rows_are_reported() {
+        let mut setup = Setup::new();
+        let cf = setup
+            .state_db
+            .raw_rocksdb()
+            .cf_handle(crate::columns::CERTIFICATE_HEADER_CF)
+            .unwrap();
+        setup
+            .state_db
+            .raw_rocksdb()
+            .put_cf(&cf, [1u8; 32], [0xff; 16])
+            .unwrap();
+
+        let report = setup.backup().unwrap();
+
+        assert!(!report.ok);
+        assert_eq!(report.unparsable_rows.len(), 1, "{report:#?}");
+        assert_eq!(report.unparsable_rows[0].source, "state");
+        assert_eq!(
+            report.unparsable_rows[0].cf,
+            crate::columns::CERTIFICATE_HEADER_CF
+        );
+    }
+
+    #[test]
+    fn missing_backup_is_reported() {
+        let setup = Setup::new();
+
+        let report =
+            verify_backups(&setup.tmp.path.join("backup"), &[(BackupDb::State, 7)]).unwrap();
+
+        assert!(!report.ok);
+        assert!(report.databases[0]
+            .error
+            .as_ref()
+            .is_some_and(|error| error.starts_with("Failed to restore the backup")));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{columns::ADMIN_AUDIT_EVENTS_CF, schema::ColumnSchema};
+
+/// Column family containing the append-only audit log of admin mutations.
+///
+/// ## Column definition
+///
+/// | key                 | value             |
+/// | --                  | --                |
+/// | `AdminAuditEventId` | `AdminAuditEvent` |
+///
+/// Event ids are ULIDs, so the column is ordered by recording time.
+pub(crate) struct AdminAuditEventsColumn;
+
+impl ColumnSchema for AdminAuditEventsColumn {
+    type Key = crate::types::admin_audit::Key;
+    type Value = crate::types::admin_audit::Value;
+
+    const COLUMN_FAMILY_NAME: &'static str = ADMIN_AUDIT_EVENTS_CF;
+}
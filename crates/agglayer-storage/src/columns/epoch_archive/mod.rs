@@ -0,0 +1,64 @@
+use agglayer_types::{Certificate, EpochNumber, Proof};
+
+use crate::{
+    columns::{
+        EPOCH_ARCHIVE_CERTIFICATES_CF, EPOCH_ARCHIVE_COLUMN_OPTIONS, EPOCH_ARCHIVE_EPOCHS_CF,
+        EPOCH_ARCHIVE_PROOFS_CF,
+    },
+    schema::ColumnSchema,
+    types::epoch_archive::{ArchivedEpoch, Key},
+};
+
+/// Column family for the certificates of the archived epochs.
+///
+/// ## Column definition
+///
+/// | key   | value         |
+/// | --    | --            |
+/// | `Key` | `Certificate` |
+///
+/// Keys are ordered by epoch number, then by certificate index.
+pub(crate) struct ArchivedCertificatesColumn;
+
+impl ColumnSchema for ArchivedCertificatesColumn {
+    type Key = Key;
+    type Value = Certificate;
+
+    const COLUMN_FAMILY_NAME: &'static str = EPOCH_ARCHIVE_CERTIFICATES_CF;
+    const COLUMN_OPTIONS: crate::schema::options::ColumnOptions = EPOCH_ARCHIVE_COLUMN_OPTIONS;
+}
+
+/// Column family for the proofs of the archived epochs.
+///
+/// ## Column definition
+///
+/// | key   | value   |
+/// | --    | --      |
+/// | `Key` | `Proof` |
+///
+/// Keys are ordered by epoch number, then by certificate index.
+pub(crate) struct ArchivedProofsColumn;
+
+impl ColumnSchema for ArchivedProofsColumn {
+    type Key = Key;
+    type Value = Proof;
+
+    const COLUMN_FAMILY_NAME: &'static str = EPOCH_ARCHIVE_PROOFS_CF;
+    const COLUMN_OPTIONS: crate::schema::options::ColumnOptions = EPOCH_ARCHIVE_COLUMN_OPTIONS;
+}
+
+/// Column family for the archived epochs.
+///
+/// ## Column definition
+///
+/// | key           | value           |
+/// | --            | --              |
+/// | `EpochNumber` | `ArchivedEpoch` |
+pub(crate) struct ArchivedEpochsColumn;
+
+impl ColumnSchema for ArchivedEpochsColumn {
+    type Key = EpochNumber;
+    type Value = ArchivedEpoch;
+
+    const COLUMN_FAMILY_NAME: &'static str = EPOCH_ARCHIVE_EPOCHS_CF;
+}
@@ -11,6 +11,9 @@ pub const NETWORK_INFO_CF: &str = "network_info_cf";
 pub const DISABLED_NETWORKS_CF: &str = "disabled_networks_cf";
 pub const SETTLEMENT_JOB_ID_PER_CERTIFICATE_ID_CF: &str = "settlement_job_id_per_certificate_id_cf";
 pub const CERTIFICATE_ID_PER_SETTLEMENT_JOB_ID_CF: &str = "certificate_id_per_settlement_job_id_cf";
+pub const ADMIN_AUDIT_EVENTS_CF: &str = "admin_audit_events_cf";
+pub const RATE_LIMITER_STATE_CF: &str = "rate_limiter_state_cf";
+pub const NETWORK_STATE_SNAPSHOTS_CF: &str = "network_state_snapshots_cf";
 
 // Metadata CFs
 pub const CERTIFICATE_HEADER_CF: &str = "certificate_header_cf";
@@ -30,12 +33,18 @@ pub const PER_EPOCH_PROOFS_CF: &str = "per_epoch_proofs_cf";
 pub const PER_EPOCH_END_CHECKPOINT_CF: &str = "per_epoch_end_checkpoint_cf";
 pub const PER_EPOCH_START_CHECKPOINT_CF: &str = "per_epoch_start_checkpoint_cf";
 
+// Epoch archive CFs
+pub const EPOCH_ARCHIVE_CERTIFICATES_CF: &str = "epoch_archive_certificates_cf";
+pub const EPOCH_ARCHIVE_PROOFS_CF: &str = "epoch_archive_proofs_cf";
+pub const EPOCH_ARCHIVE_EPOCHS_CF: &str = "epoch_archive_epochs_cf";
+
 // Settlement related CFs
 pub const SETTLEMENT_ATTEMPTS_CF: &str = "settlement_attempts_cf";
 pub const SETTLEMENT_ATTEMPT_PER_WALLET_CF: &str = "settlement_attempt_per_wallet_cf";
 pub const SETTLEMENT_ATTEMPT_RESULTS_CF: &str = "settlement_attempt_results_cf";
 pub const SETTLEMENT_JOBS_CF: &str = "settlement_jobs_cf";
 pub const SETTLEMENT_JOB_RESULTS_CF: &str = "settlement_job_results_cf";
+pub const SETTLEMENT_JOB_CANCELLATIONS_CF: &str = "settlement_job_cancellations_cf";
 
 pub const SETTLEMENT_ATTEMPTS_COLUMN_OPTIONS: ColumnOptions = ColumnOptions {
     compression: crate::schema::options::ColumnCompressionType::Lz4,
@@ -66,6 +75,14 @@ pub const CHECKPOINT_COLUMN_OPTIONS: ColumnOptions = ColumnOptions {
     },
 };
 
+// Column options for the archived certificates and proofs, grouped by epoch.
+pub const EPOCH_ARCHIVE_COLUMN_OPTIONS: ColumnOptions = ColumnOptions {
+    compression: crate::schema::options::ColumnCompressionType::Lz4,
+    prefix_extractor: crate::schema::options::PrefixExtractor::Fixed {
+        size: crate::types::epoch_archive::Key::PREFIX_LEN,
+    },
+};
+
 // Pending related CFs
 pub const PENDING_QUEUE_CF: &str = "pending_queue_cf";
 pub const PENDING_QUEUE_PROTO_CF: &str = "pending_queue_proto_cf";
@@ -76,13 +93,16 @@ pub const DEBUG_CERTIFICATES_CF: &str = "debug_certificates";
 pub const DEBUG_CERTIFICATES_PROTO_CF: &str = "debug_certificates_proto_cf";
 
 // State
+pub(crate) mod admin_audit_events;
 pub(crate) mod balance_tree_per_network;
 pub(crate) mod certificate_id_per_settlement_job_id;
 pub(crate) mod certificate_per_network;
 pub(crate) mod disabled_networks;
 pub(crate) mod local_exit_tree_per_network;
 pub(crate) mod network_info;
+pub(crate) mod network_state_snapshots;
 pub(crate) mod nullifier_tree_per_network;
+pub(crate) mod rate_limiter_state;
 pub(crate) mod settlement_job_id_per_certificate_id;
 
 // Pending
@@ -102,6 +122,7 @@ pub(crate) mod debug_certificates;
 pub(crate) mod settlement_attempt_per_wallet;
 pub(crate) mod settlement_attempt_results;
 pub(crate) mod settlement_attempts;
+pub(crate) mod settlement_job_cancellations;
 pub(crate) mod settlement_job_results;
 pub(crate) mod settlement_jobs;
 
@@ -113,3 +134,6 @@ pub mod epochs {
     pub(crate) mod proofs;
     pub(crate) mod start_checkpoint;
 }
+
+// Epoch archive
+pub(crate) mod epoch_archive;
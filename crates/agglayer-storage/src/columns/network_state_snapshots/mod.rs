@@ -0,0 +1,22 @@
+use crate::{
+    columns::NETWORK_STATE_SNAPSHOTS_CF,
+    schema::ColumnSchema,
+    types::network_state_snapshot::{Key, NetworkStateSnapshot},
+};
+
+/// Column family for the roots of the local network states at each settled
+/// height.
+///
+/// ## Column definition
+///
+/// | key                     | value                  |
+/// | --                      | --                     |
+/// | (`NetworkId`, `Height`) | `NetworkStateSnapshot` |
+pub(crate) struct NetworkStateSnapshotsColumn;
+
+impl ColumnSchema for NetworkStateSnapshotsColumn {
+    type Key = Key;
+    type Value = NetworkStateSnapshot;
+
+    const COLUMN_FAMILY_NAME: &'static str = NETWORK_STATE_SNAPSHOTS_CF;
+}
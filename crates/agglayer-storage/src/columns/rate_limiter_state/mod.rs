@@ -0,0 +1,17 @@
+use crate::{columns::RATE_LIMITER_STATE_CF, schema::ColumnSchema};
+
+/// Column family containing the events recorded by the rate limiter.
+///
+/// ## Column definition
+///
+/// | key                            | value               |
+/// | --                             | --                  |
+/// | `(NetworkId, component name)`  | `RateLimiterEvents` |
+pub(crate) struct RateLimiterStateColumn;
+
+impl ColumnSchema for RateLimiterStateColumn {
+    type Key = crate::types::rate_limiter_state::Key;
+    type Value = crate::types::rate_limiter_state::Value;
+
+    const COLUMN_FAMILY_NAME: &'static str = RATE_LIMITER_STATE_CF;
+}
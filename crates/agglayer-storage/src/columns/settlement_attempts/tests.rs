@@ -82,5 +82,6 @@ fn mk_settlement_attempt(seed: u64) -> SettlementAttempt {
         submission_time: None,
         max_fee_per_gas: None,
         max_priority_fee_per_gas: None,
+        gas_oracle_inputs: None,
     }
 }
@@ -0,0 +1,18 @@
+use crate::{columns::SETTLEMENT_JOB_CANCELLATIONS_CF, schema::ColumnSchema};
+
+/// Column family containing the settlement jobs cancelled by a network
+/// rollback.
+///
+/// ## Column definition
+///
+/// | key               | value                       |
+/// | --                | --                          |
+/// | `SettlementJobId` | `SettlementJobCancellation` |
+pub(crate) struct SettlementJobCancellationsColumn;
+
+impl ColumnSchema for SettlementJobCancellationsColumn {
+    type Key = crate::types::settlement::job_cancellation::Key;
+    type Value = crate::types::settlement::job_cancellation::Value;
+
+    const COLUMN_FAMILY_NAME: &'static str = SETTLEMENT_JOB_CANCELLATIONS_CF;
+}
@@ -43,5 +43,9 @@ fn mk_settlement_job() -> SettlementJob {
         gas_limit: Some(Uint128 {
             value: vec![0x02; 16].into(),
         }),
+        network_id: Some(3),
+        tx_type: None,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
@@ -7,7 +7,7 @@
 //! in the on-disk data that the migration helper logged-and-skipped. The
 //! scan functions in this module enumerate those rows so the operator can
 //! see them all in one pass and decide what to do (purge via the
-//! `storage-doctor` CLI, restore from backup, etc.).
+//! `agglayer storage purge` command, restore from backup, etc.).
 //!
 //! The scan opens each store in read-only mode and re-iterates the legacy
 //! certificate CFs, attempting to decode each value. It is purely
@@ -17,15 +17,20 @@
 //! proto CFs. After a clean migration the legacy CFs are still on disk
 //! (for rollback) but unused at runtime; their contents are exactly what
 //! the scan reports on.
+//!
+//! The [`doctor`] submodule extends these scans to every typed column of
+//! every store, and backs the `agglayer storage` commands.
 
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
+
 use crate::{
     columns::{
         debug_certificates::DebugCertificatesColumn,
         epochs::certificates::CertificatePerIndexColumn, pending_queue::PendingQueueColumn,
     },
-    schema::{Codec as _, ColumnSchema as _},
+    schema::{Codec as _, ColumnSchema},
     storage::DB,
     stores::{
         debug::cf_definitions::DEBUG_DB_V0, pending::cf_definitions::PENDING_DB_V0,
@@ -34,9 +39,12 @@ use crate::{
     types::LegacyCertificate,
 };
 
-/// A single legacy-CF row that could not be decoded as a
-/// [`crate::types::LegacyCertificate`].
-#[derive(Debug, Clone, PartialEq, Eq)]
+mod decoders;
+pub mod doctor;
+
+/// A single row that could not be decoded, typically a legacy-CF row
+/// that is not a valid [`crate::types::LegacyCertificate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct UnparsableRow {
     /// Human-readable origin (`"pending"`, `"debug"`, `"epoch <n>"`).
     pub source: String,
@@ -45,7 +53,7 @@ pub struct UnparsableRow {
     pub cf: &'static str,
     /// Hex of the raw key bytes as stored on disk.
     pub key_hex: String,
-    /// `LegacyCertificate::decode` error message.
+    /// Decode error message.
     pub error: String,
 }
 
@@ -91,6 +99,16 @@ pub fn scan_unparsable_debug_rows(db_path: &Path) -> Result<Vec<UnparsableRow>,
 /// epoch subdirectory under `epochs_db_path`. Non-numeric subdirectories
 /// (`lost+found`, etc.) are ignored.
 pub fn scan_unparsable_epoch_rows(epochs_db_path: &Path) -> Result<Vec<UnparsableRow>, ScanError> {
+    let mut out = Vec::new();
+    for (n, path) in epoch_dirs(epochs_db_path)? {
+        out.extend(scan_unparsable_epoch_db_rows(&path, n)?);
+    }
+    Ok(out)
+}
+
+/// The numeric epoch subdirectories under `epochs_db_path`, sorted by epoch
+/// number.
+fn epoch_dirs(epochs_db_path: &Path) -> Result<Vec<(u64, PathBuf)>, ScanError> {
     let entries = std::fs::read_dir(epochs_db_path).map_err(|source| ScanError::EpochDir {
         path: epochs_db_path.to_path_buf(),
         source,
@@ -105,17 +123,20 @@ pub fn scan_unparsable_epoch_rows(epochs_db_path: &Path) -> Result<Vec<Unparsabl
         })
         .collect();
     numeric_dirs.sort_by_key(|(n, _)| *n);
+    Ok(numeric_dirs)
+}
 
-    let mut out = Vec::new();
-    for (n, path) in numeric_dirs {
-        let db = open_readonly_v0(&path, EPOCHS_DB_V0)?;
-        out.extend(scan_legacy_cf(
-            &db,
-            CertificatePerIndexColumn::COLUMN_FAMILY_NAME,
-            format!("epoch {n}"),
-        ));
-    }
-    Ok(out)
+/// Scan the legacy `epoch_certificate_per_index` CF of a single epoch DB.
+pub fn scan_unparsable_epoch_db_rows(
+    db_path: &Path,
+    epoch_number: u64,
+) -> Result<Vec<UnparsableRow>, ScanError> {
+    let db = open_readonly_v0(db_path, EPOCHS_DB_V0)?;
+    Ok(scan_legacy_cf(
+        &db,
+        CertificatePerIndexColumn::COLUMN_FAMILY_NAME,
+        format!("epoch {epoch_number}"),
+    ))
 }
 
 fn open_readonly_v0(
@@ -160,6 +181,35 @@ fn scan_legacy_cf(db: &DB, cf_name: &'static str, source: String) -> Vec<Unparsa
     out
 }
 
+/// Iterate every row of the column `C` and attempt to decode both its key
+/// and value with the column codecs. Unlike [`scan_legacy_cf`], this works
+/// on any column of an already opened DB.
+pub(crate) fn scan_column<C: ColumnSchema>(db: &DB, source: &str) -> Vec<UnparsableRow> {
+    let Some(cf) = db.raw_rocksdb().cf_handle(C::COLUMN_FAMILY_NAME) else {
+        return Vec::new();
+    };
+
+    let mut iter = db.raw_rocksdb().raw_iterator_cf(&cf);
+    iter.seek_to_first();
+
+    let mut out = Vec::new();
+    while iter.valid() {
+        if let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+            let decoded = C::Key::decode(key).and_then(|_| C::Value::decode(value).map(|_| ()));
+            if let Err(error) = decoded {
+                out.push(UnparsableRow {
+                    source: source.to_string(),
+                    cf: C::COLUMN_FAMILY_NAME,
+                    key_hex: hex::encode(key),
+                    error: error.to_string(),
+                });
+            }
+        }
+        iter.next();
+    }
+    out
+}
+
 #[cfg(test)]
 #[cfg(feature = "testutils")]
 mod tests {
@@ -0,0 +1,128 @@
+//! Type-erased access to the typed columns of each store, so that rows can
+//! be checked and dumped by column family name.
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use super::{scan_column, UnparsableRow};
+use crate::{
+    columns::{
+        admin_audit_events::AdminAuditEventsColumn,
+        balance_tree_per_network::BalanceTreePerNetworkColumn,
+        certificate_header::CertificateHeaderColumn,
+        certificate_id_per_settlement_job_id::CertificateIdPerSettlementJobIdColumn,
+        certificate_per_network::CertificatePerNetworkColumn,
+        debug_certificates::{DebugCertificatesColumn, DebugCertificatesProtoColumn},
+        disabled_networks::DisabledNetworksColumn,
+        epochs::{
+            certificates::{CertificatePerIndexColumn, CertificatePerIndexProtoColumn},
+            end_checkpoint::EndCheckpointColumn,
+            metadata::PerEpochMetadataColumn,
+            proofs::ProofPerIndexColumn,
+            start_checkpoint::StartCheckpointColumn,
+        },
+        latest_pending_certificate_per_network::LatestPendingCertificatePerNetworkColumn,
+        latest_proven_certificate_per_network::LatestProvenCertificatePerNetworkColumn,
+        latest_settled_certificate_per_network::LatestSettledCertificatePerNetworkColumn,
+        local_exit_tree_per_network::LocalExitTreePerNetworkColumn,
+        metadata::MetadataColumn,
+        network_info::NetworkInfoColumn,
+        network_state_snapshots::NetworkStateSnapshotsColumn,
+        nullifier_tree_per_network::NullifierTreePerNetworkColumn,
+        pending_queue::{PendingQueueColumn, PendingQueueProtoColumn},
+        proof_per_certificate::ProofPerCertificateColumn,
+        rate_limiter_state::RateLimiterStateColumn,
+        settlement_attempt_per_wallet::SettlementAttemptPerWalletColumn,
+        settlement_attempt_results::SettlementAttemptResultsColumn,
+        settlement_attempts::SettlementAttemptsColumn,
+        settlement_job_cancellations::SettlementJobCancellationsColumn,
+        settlement_job_id_per_certificate_id::SettlementJobIdPerCertificateIdColumn,
+        settlement_job_results::SettlementJobResultsColumn,
+        settlement_jobs::SettlementJobsColumn,
+    },
+    schema::{Codec, CodecError, ColumnSchema},
+    storage::DB,
+};
+
+/// A typed column, addressed by its column family name.
+pub(crate) trait ColumnDecoder {
+    fn name(&self) -> &'static str;
+
+    /// Rows of the column whose key or value fails to decode.
+    fn scan(&self, db: &DB, source: &str) -> Vec<UnparsableRow>;
+
+    /// Decode a raw row into the debug representation of its key and value.
+    fn decode(&self, key: &[u8], value: &[u8]) -> Result<(String, String), CodecError>;
+}
+
+struct Decoder<C>(PhantomData<fn() -> C>);
+
+impl<C> Decoder<C> {
+    const NEW: Self = Self(PhantomData);
+}
+
+impl<C> ColumnDecoder for Decoder<C>
+where
+    C: ColumnSchema,
+    C::Key: Debug,
+    C::Value: Debug,
+{
+    fn name(&self) -> &'static str {
+        C::COLUMN_FAMILY_NAME
+    }
+
+    fn scan(&self, db: &DB, source: &str) -> Vec<UnparsableRow> {
+        scan_column::<C>(db, source)
+    }
+
+    fn decode(&self, key: &[u8], value: &[u8]) -> Result<(String, String), CodecError> {
+        let key = C::Key::decode(key)?;
+        let value = C::Value::decode(value)?;
+
+        Ok((format!("{key:?}"), format!("{value:?}")))
+    }
+}
+
+pub(crate) const STATE_COLUMNS: &[&dyn ColumnDecoder] = &[
+    &Decoder::<CertificateHeaderColumn>::NEW,
+    &Decoder::<CertificatePerNetworkColumn>::NEW,
+    &Decoder::<LatestSettledCertificatePerNetworkColumn>::NEW,
+    &Decoder::<MetadataColumn>::NEW,
+    &Decoder::<LocalExitTreePerNetworkColumn>::NEW,
+    &Decoder::<BalanceTreePerNetworkColumn>::NEW,
+    &Decoder::<NullifierTreePerNetworkColumn>::NEW,
+    &Decoder::<NetworkInfoColumn>::NEW,
+    &Decoder::<DisabledNetworksColumn>::NEW,
+    &Decoder::<SettlementJobIdPerCertificateIdColumn>::NEW,
+    &Decoder::<CertificateIdPerSettlementJobIdColumn>::NEW,
+    &Decoder::<SettlementJobsColumn>::NEW,
+    &Decoder::<SettlementJobResultsColumn>::NEW,
+    &Decoder::<SettlementAttemptsColumn>::NEW,
+    &Decoder::<SettlementAttemptResultsColumn>::NEW,
+    &Decoder::<SettlementAttemptPerWalletColumn>::NEW,
+    &Decoder::<AdminAuditEventsColumn>::NEW,
+    &Decoder::<RateLimiterStateColumn>::NEW,
+    &Decoder::<NetworkStateSnapshotsColumn>::NEW,
+    &Decoder::<SettlementJobCancellationsColumn>::NEW,
+];
+
+pub(crate) const PENDING_COLUMNS: &[&dyn ColumnDecoder] = &[
+    &Decoder::<LatestProvenCertificatePerNetworkColumn>::NEW,
+    &Decoder::<LatestPendingCertificatePerNetworkColumn>::NEW,
+    &Decoder::<PendingQueueColumn>::NEW,
+    &Decoder::<PendingQueueProtoColumn>::NEW,
+    &Decoder::<ProofPerCertificateColumn>::NEW,
+];
+
+pub(crate) const DEBUG_COLUMNS: &[&dyn ColumnDecoder] = &[
+    &Decoder::<DebugCertificatesColumn>::NEW,
+    &Decoder::<DebugCertificatesProtoColumn>::NEW,
+];
+
+pub(crate) const EPOCHS_COLUMNS: &[&dyn ColumnDecoder] = &[
+    &Decoder::<CertificatePerIndexColumn>::NEW,
+    &Decoder::<CertificatePerIndexProtoColumn>::NEW,
+    &Decoder::<PerEpochMetadataColumn>::NEW,
+    &Decoder::<ProofPerIndexColumn>::NEW,
+    &Decoder::<StartCheckpointColumn>::NEW,
+    &Decoder::<EndCheckpointColumn>::NEW,
+];
@@ -0,0 +1,441 @@
+//! Operator tooling behind the `agglayer storage` commands: scanning every
+//! store for unparsable rows, purging them, per column family statistics and
+//! dumping single rows.
+//!
+//! Everything but [`purge_unparsable_rows`] opens the databases in read-only
+//! mode and can run next to a live node. Purging needs the node to be
+//! stopped, and always backs the database up before deleting anything.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use agglayer_config::storage::StorageConfig;
+use agglayer_types::EpochNumber;
+use rocksdb::WriteBatch;
+use serde::Serialize;
+
+use super::{
+    decoders::{ColumnDecoder, DEBUG_COLUMNS, EPOCHS_COLUMNS, PENDING_COLUMNS, STATE_COLUMNS},
+    epoch_dirs, ScanError, UnparsableRow,
+};
+use crate::{
+    backup::{BackupDb, BackupEngine},
+    schema::ColumnDescriptor,
+    storage::{DBError, DBOpenError, DB},
+    stores::{
+        debug::{cf_definitions::DEBUG_DB, DebugStore},
+        pending::{cf_definitions::PENDING_DB, PendingStore},
+        per_epoch::{cf_definitions::EPOCHS_DB, PerEpochStore},
+        state::{cf_definitions::STATE_DB, StateStore},
+    },
+};
+
+/// Backup directory of the debug store, which has no backups of its own.
+const DEBUG_BACKUP_DIR: &str = "debug";
+
+/// One of the databases of the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Store {
+    State,
+    Pending,
+    Debug,
+    Epoch(u64),
+}
+
+impl Store {
+    /// Every store present on disk for the given storage configuration, epochs
+    /// in ascending order.
+    pub fn all(config: &StorageConfig) -> Result<Vec<Self>, ScanError> {
+        let mut stores: Vec<_> = [Self::State, Self::Pending, Self::Debug]
+            .into_iter()
+            .filter(|store| store.db_path(config).is_dir())
+            .collect();
+
+        if config.epochs_db_path.is_dir() {
+            stores.extend(
+                epoch_dirs(&config.epochs_db_path)?
+                    .into_iter()
+                    .map(|(epoch_number, _)| Self::Epoch(epoch_number)),
+            );
+        }
+
+        Ok(stores)
+    }
+
+    /// Path of the database for the given storage configuration.
+    pub fn db_path(&self, config: &StorageConfig) -> PathBuf {
+        match self {
+            Self::State => config.state_db_path.clone(),
+            Self::Pending => config.pending_db_path.clone(),
+            Self::Debug => config.debug_db_path.clone(),
+            Self::Epoch(epoch_number) => config.epoch_db_path(EpochNumber::new(*epoch_number)),
+        }
+    }
+
+    /// Path of the backups of the database under the backup root. Uses the
+    /// layout of the backup engine, so that `backup restore` picks them up.
+    pub fn backup_path(&self, backup_root: &Path) -> PathBuf {
+        match self.backup_db() {
+            Some(db) => backup_root.join(db.path()),
+            None => backup_root.join(DEBUG_BACKUP_DIR),
+        }
+    }
+
+    fn backup_db(&self) -> Option<BackupDb> {
+        match self {
+            Self::State => Some(BackupDb::State),
+            Self::Pending => Some(BackupDb::Pending),
+            Self::Debug => None,
+            Self::Epoch(epoch_number) => Some(BackupDb::Epoch(*epoch_number)),
+        }
+    }
+
+    pub(crate) fn cfs(&self) -> &'static [ColumnDescriptor] {
+        match self {
+            Self::State => STATE_DB,
+            Self::Pending => PENDING_DB,
+            Self::Debug => DEBUG_DB,
+            Self::Epoch(_) => EPOCHS_DB,
+        }
+    }
+
+    fn columns(&self) -> &'static [&'static dyn ColumnDecoder] {
+        match self {
+            Self::State => STATE_COLUMNS,
+            Self::Pending => PENDING_COLUMNS,
+            Self::Debug => DEBUG_COLUMNS,
+            Self::Epoch(_) => EPOCHS_COLUMNS,
+        }
+    }
+
+    fn column(&self, cf: &str) -> Option<&'static dyn ColumnDecoder> {
+        self.columns()
+            .iter()
+            .copied()
+            .find(|column| column.name() == cf)
+    }
+
+    fn open_readonly(&self, path: &Path) -> Result<DB, ScanError> {
+        DB::open_cf_readonly(path, self.cfs()).map_err(|source| ScanError::OpenError {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Open the database for writing, running the pending migrations like the
+    /// node does at startup.
+    fn open(&self, path: &Path) -> Result<DB, DoctorError> {
+        match self {
+            Self::State => StateStore::init_db(path),
+            Self::Pending => PendingStore::init_db(path),
+            Self::Debug => DebugStore::init_db(path),
+            Self::Epoch(_) => PerEpochStore::<(), ()>::init_db(path),
+        }
+        .map_err(|source| DoctorError::Open {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// Matches the `source` of the [`UnparsableRow`]s found in the store, and
+/// parses back into the store.
+impl fmt::Display for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::State => f.write_str("state"),
+            Self::Pending => f.write_str("pending"),
+            Self::Debug => f.write_str("debug"),
+            Self::Epoch(epoch_number) => write!(f, "epoch {epoch_number}"),
+        }
+    }
+}
+
+/// Accepts the displayed names, and `epoch_<n>` as used on the command line.
+impl FromStr for Store {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().trim() {
+            "state" => Ok(Self::State),
+            "pending" => Ok(Self::Pending),
+            "debug" => Ok(Self::Debug),
+            s => {
+                let Some(epoch) = s
+                    .strip_prefix("epoch_")
+                    .or_else(|| s.strip_prefix("epoch "))
+                else {
+                    return Err(format!("Unexpected store: {s}"));
+                };
+
+                epoch
+                    .trim_start()
+                    .parse::<u64>()
+                    .map(Self::Epoch)
+                    .map_err(|e| format!("Invalid epoch: {e}"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DoctorError {
+    #[error(transparent)]
+    Scan(#[from] ScanError),
+
+    #[error("failed to open database at {path} for writing: {source}")]
+    Open {
+        path: PathBuf,
+        #[source]
+        source: DBOpenError,
+    },
+
+    #[error("unknown column family {cf:?} in the {store} store")]
+    UnknownColumnFamily { store: Store, cf: String },
+
+    #[error("no backup path to back up the {store} store to before purging")]
+    MissingBackupPath { store: Store },
+
+    #[error("failed to back up the {store} store to {path} before purging: {error:#}")]
+    Backup {
+        store: Store,
+        path: PathBuf,
+        error: eyre::Report,
+    },
+
+    #[error(transparent)]
+    Database(#[from] DBError),
+}
+
+/// Size of a single column family.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnFamilyStats {
+    pub source: String,
+    pub cf: String,
+    pub keys: u64,
+    /// Sum of the raw key sizes, in bytes.
+    pub key_bytes: u64,
+    /// Sum of the raw value sizes, in bytes.
+    pub value_bytes: u64,
+    /// Size of the SST files on disk, as estimated by RocksDB.
+    pub sst_bytes: Option<u64>,
+}
+
+/// A single row, decoded with the codecs of its column when possible.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RowDump {
+    pub source: String,
+    pub cf: String,
+    pub key_hex: String,
+    pub value_hex: String,
+    /// Debug representation of the decoded key.
+    pub key: Option<String>,
+    /// Debug representation of the decoded value.
+    pub value: Option<String>,
+    /// Decode error, when the row is unparsable.
+    pub error: Option<String>,
+}
+
+/// Outcome of [`purge_unparsable_rows`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PurgeReport {
+    pub dry_run: bool,
+    /// Id of the backup taken before purging, `None` when nothing was
+    /// deleted.
+    pub backup_id: Option<u32>,
+    pub backup_path: Option<PathBuf>,
+    /// Rows deleted, or that would be deleted on a dry run.
+    pub rows: Vec<UnparsableRow>,
+}
+
+/// Scan every typed column of the store, legacy ones included, and return
+/// the rows whose key or value fails to decode.
+pub fn scan_store(store: Store, db_path: &Path) -> Result<Vec<UnparsableRow>, ScanError> {
+    let db = store.open_readonly(db_path)?;
+
+    Ok(scan_db(store, &db))
+}
+
+fn scan_db(store: Store, db: &DB) -> Vec<UnparsableRow> {
+    let source = store.to_string();
+
+    store
+        .columns()
+        .iter()
+        .flat_map(|column| column.scan(db, &source))
+        .collect()
+}
+
+/// Delete the unparsable rows of the store, optionally restricted to a single
+/// column family.
+///
+/// Unless `dry_run` is set, a backup of the database is created under
+/// `backup_root` before anything is deleted, which is then mandatory. Nothing
+/// is written when the store has no unparsable rows.
+pub fn purge_unparsable_rows(
+    store: Store,
+    db_path: &Path,
+    backup_root: Option<&Path>,
+    cf: Option<&str>,
+    dry_run: bool,
+) -> Result<PurgeReport, DoctorError> {
+    if let Some(cf) = cf {
+        if store.column(cf).is_none() {
+            return Err(DoctorError::UnknownColumnFamily {
+                store,
+                cf: cf.to_string(),
+            });
+        }
+    }
+    let selected = |rows: Vec<UnparsableRow>| -> Vec<UnparsableRow> {
+        rows.into_iter()
+            .filter(|row| cf.is_none_or(|cf| row.cf == cf))
+            .collect()
+    };
+
+    if dry_run {
+        return Ok(PurgeReport {
+            dry_run,
+            backup_id: None,
+            backup_path: None,
+            rows: selected(scan_store(store, db_path)?),
+        });
+    }
+
+    let db = store.open(db_path)?;
+    let rows = selected(scan_db(store, &db));
+    if rows.is_empty() {
+        return Ok(PurgeReport {
+            dry_run,
+            backup_id: None,
+            backup_path: None,
+            rows,
+        });
+    }
+
+    let Some(backup_root) = backup_root else {
+        return Err(DoctorError::MissingBackupPath { store });
+    };
+    let backup_path = store.backup_path(backup_root);
+    let backup_id =
+        BackupEngine::backup_at(&backup_path, &db).map_err(|error| DoctorError::Backup {
+            store,
+            path: backup_path.clone(),
+            error,
+        })?;
+
+    let mut batch = WriteBatch::default();
+    for row in &rows {
+        let cf = db
+            .raw_rocksdb()
+            .cf_handle(row.cf)
+            .ok_or(DBError::ColumnFamilyNotFound)?;
+        let key = hex::decode(&row.key_hex).expect("key_hex is produced by hex::encode");
+        batch.delete_cf(&cf, key);
+    }
+    db.write_batch(batch)?;
+
+    Ok(PurgeReport {
+        dry_run,
+        backup_id: Some(backup_id),
+        backup_path: Some(backup_path),
+        rows,
+    })
+}
+
+/// Count the keys and their sizes in every column family of the store,
+/// including the ones unknown to this binary.
+pub fn store_stats(store: Store, db_path: &Path) -> Result<Vec<ColumnFamilyStats>, DoctorError> {
+    let db = store.open_readonly(db_path)?;
+    let names =
+        rocksdb::DB::list_cf(&rocksdb::Options::default(), db_path).map_err(DBError::from)?;
+
+    let mut out = Vec::new();
+    for name in names
+        .into_iter()
+        .filter(|name| name != rocksdb::DEFAULT_COLUMN_FAMILY_NAME)
+    {
+        let Some(cf) = db.raw_rocksdb().cf_handle(&name) else {
+            continue;
+        };
+
+        let mut stats = ColumnFamilyStats {
+            source: store.to_string(),
+            cf: name.clone(),
+            keys: 0,
+            key_bytes: 0,
+            value_bytes: 0,
+            sst_bytes: db
+                .raw_rocksdb()
+                .property_int_value_cf(&cf, "rocksdb.total-sst-files-size")
+                .map_err(DBError::from)?,
+        };
+
+        let mut iter = db.raw_rocksdb().raw_iterator_cf(&cf);
+        iter.seek_to_first();
+        while iter.valid() {
+            if let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+                stats.keys += 1;
+                stats.key_bytes += key.len() as u64;
+                stats.value_bytes += value.len() as u64;
+            }
+            iter.next();
+        }
+        iter.status().map_err(DBError::from)?;
+
+        out.push(stats);
+    }
+
+    Ok(out)
+}
+
+/// Read a single row by its raw key and decode it with the codecs of the
+/// column family. Returns `None` when the key is absent.
+pub fn get_row(
+    store: Store,
+    db_path: &Path,
+    cf: &str,
+    key: &[u8],
+) -> Result<Option<RowDump>, DoctorError> {
+    let column = store
+        .column(cf)
+        .ok_or_else(|| DoctorError::UnknownColumnFamily {
+            store,
+            cf: cf.to_string(),
+        })?;
+    let db = store.open_readonly(db_path)?;
+    let handle = db
+        .raw_rocksdb()
+        .cf_handle(cf)
+        .ok_or(DBError::ColumnFamilyNotFound)?;
+
+    let Some(value) = db
+        .raw_rocksdb()
+        .get_cf(&handle, key)
+        .map_err(DBError::from)?
+    else {
+        return Ok(None);
+    };
+
+    let (decoded_key, decoded_value, error) = match column.decode(key, &value) {
+        Ok((key, value)) => (Some(key), Some(value), None),
+        Err(error) => (None, None, Some(error.to_string())),
+    };
+
+    Ok(Some(RowDump {
+        source: store.to_string(),
+        cf: column.name().to_string(),
+        key_hex: hex::encode(key),
+        value_hex: hex::encode(&value),
+        key: decoded_key,
+        value: decoded_value,
+        error,
+    }))
+}
+
+#[cfg(test)]
+#[cfg(feature = "testutils")]
+mod tests;
@@ -0,0 +1,290 @@
+use std::{path::Path, sync::Arc};
+
+use agglayer_config::storage::StorageConfig;
+use agglayer_types::{Certificate, CertificateId, Height, NetworkId};
+
+use super::*;
+use crate::{
+    columns::{
+        pending_queue::{PendingQueueKey, PendingQueueProtoColumn},
+        proof_per_certificate::ProofPerCertificateColumn,
+    },
+    schema::{Codec as _, ColumnSchema},
+    stores::PendingCertificateWriter as _,
+    tests::TempDBDir,
+};
+
+const CORRUPT_PROOF: &[u8] = b"not a proof";
+
+/// Create a pending store holding one certificate and an unreadable proof
+/// for it, returning the certificate id.
+fn pending_with_corrupt_proof(path: &Path) -> CertificateId {
+    let db = Arc::new(PendingStore::init_db(path).unwrap());
+    let store = PendingStore::new(db.clone());
+    let certificate = Certificate::new_for_test(NetworkId::new(1), Height::ZERO);
+    store
+        .insert_pending_certificate(NetworkId::new(1), Height::ZERO, &certificate)
+        .unwrap();
+
+    let cf = db
+        .raw_rocksdb()
+        .cf_handle(ProofPerCertificateColumn::COLUMN_FAMILY_NAME)
+        .unwrap();
+    db.raw_rocksdb()
+        .put_cf(&cf, certificate.hash().encode().unwrap(), CORRUPT_PROOF)
+        .unwrap();
+
+    certificate.hash()
+}
+
+fn pending_queue_key() -> Vec<u8> {
+    PendingQueueKey(NetworkId::new(1), Height::ZERO)
+        .encode()
+        .unwrap()
+}
+
+#[test]
+fn scan_reports_unparsable_rows_of_typed_columns() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    let certificate_id = pending_with_corrupt_proof(&path);
+
+    let rows = scan_store(Store::Pending, &path).unwrap();
+
+    assert_eq!(rows.len(), 1, "{rows:?}");
+    assert_eq!(rows[0].source, "pending");
+    assert_eq!(rows[0].cf, ProofPerCertificateColumn::COLUMN_FAMILY_NAME);
+    assert_eq!(
+        rows[0].key_hex,
+        hex::encode(certificate_id.encode().unwrap())
+    );
+}
+
+#[test]
+fn purge_dry_run_leaves_the_store_untouched() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    pending_with_corrupt_proof(&path);
+
+    let report = purge_unparsable_rows(Store::Pending, &path, None, None, true).unwrap();
+
+    assert!(report.dry_run);
+    assert_eq!(report.rows.len(), 1);
+    assert_eq!(report.backup_id, None);
+    assert_eq!(scan_store(Store::Pending, &path).unwrap(), report.rows);
+
+    // Without a dry run, the rows are only deleted once backed up.
+    assert!(matches!(
+        purge_unparsable_rows(Store::Pending, &path, None, None, false),
+        Err(DoctorError::MissingBackupPath {
+            store: Store::Pending
+        })
+    ));
+    assert_eq!(scan_store(Store::Pending, &path).unwrap(), report.rows);
+}
+
+#[test]
+fn purge_backs_the_store_up_before_deleting_unparsable_rows() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    let backup_root = tmp.path.join("backup");
+    pending_with_corrupt_proof(&path);
+    let unparsable = scan_store(Store::Pending, &path).unwrap();
+
+    let report = purge_unparsable_rows(
+        Store::Pending,
+        &path,
+        Some(backup_root.as_path()),
+        None,
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(report.rows, unparsable);
+    assert_eq!(report.backup_id, Some(1));
+    assert_eq!(report.backup_path, Some(backup_root.join("pending")));
+    assert!(scan_store(Store::Pending, &path).unwrap().is_empty());
+    assert!(get_row(
+        Store::Pending,
+        &path,
+        PendingQueueProtoColumn::COLUMN_FAMILY_NAME,
+        &pending_queue_key(),
+    )
+    .unwrap()
+    .is_some());
+
+    // The backup still holds the purged rows.
+    let restored = tmp.path.join("restored");
+    BackupEngine::restore_at(&backup_root.join("pending"), &restored, 1).unwrap();
+    assert_eq!(scan_store(Store::Pending, &restored).unwrap(), unparsable);
+}
+
+#[test]
+fn purge_is_restricted_to_the_selected_column_family() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    let backup_root = tmp.path.join("backup");
+    pending_with_corrupt_proof(&path);
+
+    let report = purge_unparsable_rows(
+        Store::Pending,
+        &path,
+        Some(backup_root.as_path()),
+        Some(PendingQueueProtoColumn::COLUMN_FAMILY_NAME),
+        false,
+    )
+    .unwrap();
+
+    assert!(report.rows.is_empty());
+    assert_eq!(report.backup_id, None);
+    assert_eq!(scan_store(Store::Pending, &path).unwrap().len(), 1);
+
+    let error = purge_unparsable_rows(
+        Store::Pending,
+        &path,
+        Some(backup_root.as_path()),
+        Some("unknown"),
+        false,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        DoctorError::UnknownColumnFamily {
+            store: Store::Pending,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn stats_count_the_keys_of_every_column_family() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    let certificate_id = pending_with_corrupt_proof(&path);
+
+    let stats = store_stats(Store::Pending, &path).unwrap();
+
+    // Every column family on disk is listed, the migration records included.
+    for cf in PENDING_DB {
+        assert!(stats.iter().any(|stats| stats.cf == cf.name()), "{stats:?}");
+    }
+    assert!(stats.len() > PENDING_DB.len());
+
+    let proofs = stats
+        .iter()
+        .find(|stats| stats.cf == ProofPerCertificateColumn::COLUMN_FAMILY_NAME)
+        .unwrap();
+    assert_eq!(proofs.source, "pending");
+    assert_eq!(proofs.keys, 1);
+    assert_eq!(
+        proofs.key_bytes,
+        certificate_id.encode().unwrap().len() as u64
+    );
+    assert_eq!(proofs.value_bytes, CORRUPT_PROOF.len() as u64);
+
+    let keys: u64 = stats
+        .iter()
+        .filter(|stats| PENDING_DB.iter().any(|cf| cf.name() == stats.cf))
+        .map(|stats| stats.keys)
+        .sum();
+    assert_eq!(keys, 3);
+}
+
+#[test]
+fn get_row_decodes_the_row_or_reports_the_decode_error() {
+    let tmp = TempDBDir::new();
+    let path = tmp.path.join("pending");
+    let certificate_id = pending_with_corrupt_proof(&path);
+
+    let row = get_row(
+        Store::Pending,
+        &path,
+        PendingQueueProtoColumn::COLUMN_FAMILY_NAME,
+        &pending_queue_key(),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(row.key_hex, hex::encode(pending_queue_key()));
+    assert!(row.key.is_some());
+    assert!(row.value.is_some());
+    assert_eq!(row.error, None);
+
+    let key = certificate_id.encode().unwrap();
+    let row = get_row(
+        Store::Pending,
+        &path,
+        ProofPerCertificateColumn::COLUMN_FAMILY_NAME,
+        &key,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(row.value_hex, hex::encode(CORRUPT_PROOF));
+    assert_eq!(row.value, None);
+    assert!(row.error.is_some());
+
+    let missing = CertificateId::new(agglayer_types::Digest([7u8; 32]))
+        .encode()
+        .unwrap();
+    assert_eq!(
+        get_row(
+            Store::Pending,
+            &path,
+            ProofPerCertificateColumn::COLUMN_FAMILY_NAME,
+            &missing,
+        )
+        .unwrap(),
+        None
+    );
+    assert!(matches!(
+        get_row(Store::Pending, &path, "unknown", &key),
+        Err(DoctorError::UnknownColumnFamily { .. })
+    ));
+}
+
+#[test]
+fn all_lists_the_stores_present_on_disk() {
+    let tmp = TempDBDir::new();
+    let config = StorageConfig::new_from_path(&tmp.path);
+    assert!(Store::all(&config).unwrap().is_empty());
+
+    PendingStore::init_db(&config.pending_db_path).unwrap();
+    PerEpochStore::<(), ()>::init_db(&config.epochs_db_path.join("3")).unwrap();
+    PerEpochStore::<(), ()>::init_db(&config.epochs_db_path.join("12")).unwrap();
+    std::fs::create_dir_all(config.epochs_db_path.join("lost+found")).unwrap();
+
+    assert_eq!(
+        Store::all(&config).unwrap(),
+        vec![Store::Pending, Store::Epoch(3), Store::Epoch(12)]
+    );
+    assert_eq!(
+        Store::Epoch(12).db_path(&config),
+        config.epochs_db_path.join("12")
+    );
+}
+
+#[test]
+fn store_names() {
+    for (name, store) in [
+        ("state", Store::State),
+        ("Pending", Store::Pending),
+        ("debug", Store::Debug),
+        ("epoch_42", Store::Epoch(42)),
+        ("epoch 42", Store::Epoch(42)),
+    ] {
+        assert_eq!(name.parse::<Store>(), Ok(store));
+        assert_eq!(store.to_string().parse::<Store>(), Ok(store));
+    }
+    assert!("epoch_x".parse::<Store>().is_err());
+    assert!("epoch".parse::<Store>().is_err());
+    assert!("metadata".parse::<Store>().is_err());
+
+    assert_eq!(Store::Epoch(42).to_string(), "epoch 42");
+    assert_eq!(
+        Store::Epoch(42).backup_path(Path::new("/backup")),
+        Path::new("/backup/epochs/42")
+    );
+    assert_eq!(
+        Store::Debug.backup_path(Path::new("/backup")),
+        Path::new("/backup/debug")
+    );
+}
@@ -1,5 +1,7 @@
+use std::path::PathBuf;
+
 use agglayer_types::{
-    CertificateId, CertificateStatusError, EpochNumber, Height, NetworkId, SettlementJobId,
+    CertificateId, CertificateStatusError, Digest, EpochNumber, Height, NetworkId, SettlementJobId,
 };
 
 use crate::storage::{DBError, DBOpenError};
@@ -38,6 +40,19 @@ pub enum Error {
     #[error("The epoch {0} is already finished")]
     AlreadyPacked(EpochNumber),
 
+    #[error("The epoch {0} is archived")]
+    EpochArchived(EpochNumber),
+
+    #[error("Epoch archive I/O error on {}: {source}", path.display())]
+    EpochArchiveIo {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Corrupted epoch archive: {0}")]
+    CorruptedEpochArchive(String),
+
     #[error(transparent)]
     CertificateCandidateError(#[from] CertificateCandidateError),
 
@@ -73,6 +88,9 @@ pub enum Error {
     #[error("Smt node not found")]
     SmtNodeNotFound,
 
+    #[error("Smt node {0} does not hash to its key")]
+    SmtNodeHashMismatch(Digest),
+
     #[error(transparent)]
     SettlementCompat(#[from] crate::types::settlement::compat::Error),
 
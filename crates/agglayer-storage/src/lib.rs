@@ -5,6 +5,9 @@ pub mod storage;
 // Backups.
 pub mod backup;
 
+// Portable snapshots.
+pub mod snapshot;
+
 // Domain-specific modules.
 #[macro_use]
 pub mod columns;
@@ -0,0 +1,873 @@
+//! Portable snapshots of the stores, to bootstrap a node without copying the
+//! RocksDB directories around.
+//!
+//! A snapshot holds the rows of the state store, node metadata included, and
+//! of the pending store. The file starts with [`MAGIC`], followed by
+//! length-delimited [`v0::SnapshotFrame`]s: a header, the raw rows of the
+//! stores, and a trailer with the number of rows and the SHA-256 of everything
+//! before it. The rows are copied as stored, so a snapshot can only be
+//! imported by a node recording the same migration steps.
+//!
+//! The export only runs against the stores of a stopped node: it holds their
+//! locks while reading them, so that the stores are read as of a single point
+//! and no node starts writing into them meanwhile. The import writes into
+//! scratch databases next to the target ones, and only moves them in place once
+//! the checksum and the settled state of every network check out.
+
+use std::{
+    fs,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use agglayer_config::storage::StorageConfig;
+use agglayer_tries::roots::LocalExitRoot;
+use agglayer_types::{CertificateId, Digest, Height, NetworkId, SettledStateAnchor};
+use chrono::{DateTime, Utc};
+use prost::{bytes::Bytes, Message as _};
+use rocksdb::WriteBatch;
+use serde::Serialize;
+use sha2::{Digest as _, Sha256};
+use tracing::{info, warn};
+
+use crate::{
+    backup::BackupClient,
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    diagnostics::doctor::Store,
+    storage::{DBError, DBOpenError, DB},
+    stores::{pending::PendingStore, state::StateStore, StateReader as _},
+    types::generated::agglayer::storage::v0::{self, snapshot_frame::Frame},
+};
+
+/// Magic number starting every snapshot file.
+const MAGIC: &[u8; 8] = b"AGGLSNAP";
+
+/// Version of the snapshot format written by this binary.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// The stores of a snapshot, in the order their rows are written.
+const STORES: [Store; 2] = [Store::State, Store::Pending];
+
+/// Number of rows written per batch on import.
+const IMPORT_BATCH_ROWS: usize = 10_000;
+
+/// Upper bound of the length of a frame, to refuse garbage lengths before
+/// allocating.
+const MAX_FRAME_LEN: u64 = 1 << 30;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("I/O error on {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to open database at {path}: {source}")]
+    Open {
+        path: PathBuf,
+        #[source]
+        source: DBError,
+    },
+
+    #[error("failed to create database at {path}: {source}")]
+    Create {
+        path: PathBuf,
+        #[source]
+        source: DBOpenError,
+    },
+
+    #[error(transparent)]
+    Database(#[from] DBError),
+
+    #[error(transparent)]
+    Storage(#[from] crate::error::Error),
+
+    #[error("{path} is in use, snapshots are only exported from the stores of a stopped node")]
+    StoresInUse { path: PathBuf },
+
+    #[error("{path} is not empty, snapshots are only imported into empty stores")]
+    TargetNotEmpty { path: PathBuf },
+
+    #[error("not a snapshot file")]
+    NotASnapshot,
+
+    #[error("unsupported snapshot format version {0}, expected {SNAPSHOT_FORMAT_VERSION}")]
+    UnsupportedVersion(u32),
+
+    #[error(
+        "the {store} store of the snapshot has {snapshot} migration steps, this node records \
+         {expected}"
+    )]
+    SchemaMismatch {
+        store: Store,
+        snapshot: u32,
+        expected: u32,
+    },
+
+    #[error("corrupted snapshot: {0}")]
+    Corrupted(String),
+
+    #[error("checksum mismatch, the snapshot is corrupted")]
+    ChecksumMismatch,
+
+    #[error("the settled state of network {network_id} does not check out: {reason}")]
+    NetworkStateMismatch {
+        network_id: NetworkId,
+        reason: String,
+    },
+}
+
+/// The settled state of a network, as recorded in a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SnapshotNetworkState {
+    pub network_id: NetworkId,
+    #[serde(flatten)]
+    pub anchor: SettledStateAnchor,
+}
+
+/// Outcome of an export or an import.
+#[derive(Clone, Debug, Serialize)]
+pub struct SnapshotReport {
+    pub path: PathBuf,
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+    pub rows: u64,
+    /// SHA-256 of the snapshot, as recorded in its trailer.
+    pub sha256: String,
+    pub networks: Vec<SnapshotNetworkState>,
+}
+
+/// Export the state and pending stores of `config` to a snapshot file at
+/// `output`.
+///
+/// The stores must not be in use, which is refused with
+/// [`SnapshotError::StoresInUse`] while a node runs on them. The file is
+/// written through a temporary file, so a failed export never leaves a partial
+/// snapshot behind.
+pub fn export_snapshot(
+    config: &StorageConfig,
+    output: &Path,
+) -> Result<SnapshotReport, SnapshotError> {
+    // Every store is locked before any is read, so that none of them changes
+    // until the export is done.
+    let stores = STORES
+        .into_iter()
+        .map(|store| {
+            let path = store.db_path(config);
+            DB::open_cf_exclusive(&path, store.cfs())
+                .map(|db| (store, Arc::new(db)))
+                .map_err(|source| match source {
+                    DBError::RocksDB(error) if is_lock_error(&error) => {
+                        SnapshotError::StoresInUse { path }
+                    }
+                    source => SnapshotError::Open { path, source },
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (_, state_db) = &stores[0];
+    let networks = network_states(&StateStore::new(state_db.clone(), BackupClient::noop()))?;
+
+    let created_at = SystemTime::now();
+    let header = snapshot_header(created_at, &stores, &networks)?;
+
+    let tmp_path = output.with_extension("tmp");
+    let written = write_snapshot(&tmp_path, header, &stores).and_then(|written| {
+        fs::rename(&tmp_path, output).map_err(|source| SnapshotError::Io {
+            path: output.to_path_buf(),
+            source,
+        })?;
+        Ok(written)
+    });
+    let (rows, sha256) = match written {
+        Ok(written) => written,
+        Err(error) => {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(error);
+        }
+    };
+
+    info!(path = %output.display(), rows, "Snapshot exported");
+
+    Ok(SnapshotReport {
+        path: output.to_path_buf(),
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        created_at: created_at.into(),
+        rows,
+        sha256,
+        networks,
+    })
+}
+
+/// Import the snapshot file at `input` into the state and pending stores of
+/// `config`, which must be missing or empty.
+///
+/// The rows are written into scratch databases next to the target ones, which
+/// are only moved in place once the whole snapshot has been checked.
+pub fn import_snapshot(
+    config: &StorageConfig,
+    input: &Path,
+) -> Result<SnapshotReport, SnapshotError> {
+    let targets: Vec<_> = STORES
+        .into_iter()
+        .map(|store| (store, store.db_path(config)))
+        .collect();
+    for (_, path) in &targets {
+        if !is_empty_dir(path)? {
+            return Err(SnapshotError::TargetNotEmpty { path: path.clone() });
+        }
+    }
+
+    let staging: Vec<_> = targets
+        .iter()
+        .map(|(store, path)| (*store, staging_path(path)))
+        .collect();
+    // Leftovers of a previous failed import.
+    for (_, path) in &staging {
+        remove_dir_if_exists(path)?;
+    }
+
+    let report = match import_into(input, &staging) {
+        Ok(report) => report,
+        Err(error) => {
+            for (_, path) in &staging {
+                let _ = fs::remove_dir_all(path);
+            }
+            return Err(error);
+        }
+    };
+
+    for ((_, staging_path), (_, target)) in staging.iter().zip(&targets) {
+        remove_dir_if_exists(target)?;
+        fs::rename(staging_path, target).map_err(|source| SnapshotError::Io {
+            path: target.clone(),
+            source,
+        })?;
+    }
+
+    info!(path = %input.display(), rows = report.rows, "Snapshot imported");
+
+    Ok(report)
+}
+
+/// The header of a snapshot of `stores`, holding the settled state of
+/// `networks`.
+fn snapshot_header(
+    created_at: SystemTime,
+    stores: &[(Store, Arc<DB>)],
+    networks: &[SnapshotNetworkState],
+) -> Result<v0::SnapshotHeader, SnapshotError> {
+    Ok(v0::SnapshotHeader {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        created_at: Some(prost_types::Timestamp::from(created_at)),
+        stores: stores
+            .iter()
+            .map(|(store, db)| {
+                Ok(v0::SnapshotStoreSchema {
+                    store: proto_store(*store) as i32,
+                    migration_steps: db.migration_steps()?,
+                })
+            })
+            .collect::<Result<_, DBError>>()?,
+        networks: networks.iter().map(Into::into).collect(),
+    })
+}
+
+/// Whether opening a store failed on its lock, held by another instance.
+fn is_lock_error(error: &rocksdb::Error) -> bool {
+    error.kind() == rocksdb::ErrorKind::IOError && error.as_ref().contains("LOCK")
+}
+
+fn write_snapshot(
+    path: &Path,
+    header: v0::SnapshotHeader,
+    stores: &[(Store, Arc<DB>)],
+) -> Result<(u64, String), SnapshotError> {
+    let file = fs::File::create(path).map_err(|source| SnapshotError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let mut writer = SnapshotWriter::new(BufWriter::new(file), path)?;
+    writer.write_frame(Frame::Header(header))?;
+
+    for (store, db) in stores {
+        for descriptor in store.cfs() {
+            let Some(cf) = db.raw_rocksdb().cf_handle(descriptor.name()) else {
+                warn!(%store, cf = descriptor.name(), "Column family missing from the store");
+                continue;
+            };
+
+            let mut iter = db.raw_rocksdb().raw_iterator_cf(&cf);
+            iter.seek_to_first();
+            while iter.valid() {
+                if let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+                    writer.write_frame(Frame::Row(v0::SnapshotRow {
+                        store: proto_store(*store) as i32,
+                        cf: descriptor.name().to_string(),
+                        key: Bytes::copy_from_slice(key),
+                        value: Bytes::copy_from_slice(value),
+                    }))?;
+                    writer.rows += 1;
+                }
+                iter.next();
+            }
+            iter.status().map_err(DBError::from)?;
+        }
+    }
+
+    writer.finish()
+}
+
+fn import_into(
+    input: &Path,
+    staging: &[(Store, PathBuf)],
+) -> Result<SnapshotReport, SnapshotError> {
+    let file = fs::File::open(input).map_err(|source| SnapshotError::Io {
+        path: input.to_path_buf(),
+        source,
+    })?;
+    let mut reader = SnapshotReader::new(BufReader::new(file), input)?;
+
+    let Some(Frame::Header(header)) = reader.read_frame()? else {
+        return Err(SnapshotError::Corrupted(
+            "the snapshot does not start with a header".to_string(),
+        ));
+    };
+    if header.format_version != SNAPSHOT_FORMAT_VERSION {
+        return Err(SnapshotError::UnsupportedVersion(header.format_version));
+    }
+    let created_at: SystemTime = header
+        .created_at
+        .ok_or_else(|| SnapshotError::Corrupted("missing creation time".to_string()))?
+        .try_into()
+        .map_err(|error: prost_types::TimestampError| {
+            SnapshotError::Corrupted(format!("invalid creation time: {error}"))
+        })?;
+    let networks = header
+        .networks
+        .into_iter()
+        .map(SnapshotNetworkState::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stores = Vec::new();
+    for (store, path) in staging {
+        let db = create_db(*store, path)?;
+        let expected = db.migration_steps()?;
+        let snapshot = header
+            .stores
+            .iter()
+            .find(|schema| schema.store == proto_store(*store) as i32)
+            .ok_or_else(|| SnapshotError::Corrupted(format!("no schema for the {store} store")))?
+            .migration_steps;
+        if snapshot != expected {
+            return Err(SnapshotError::SchemaMismatch {
+                store: *store,
+                snapshot,
+                expected,
+            });
+        }
+
+        stores.push((*store, db, WriteBatch::default()));
+    }
+
+    let mut rows = 0;
+    let trailer = loop {
+        let row = match reader.read_frame()? {
+            Some(Frame::Row(row)) => row,
+            Some(Frame::Trailer(trailer)) => break trailer,
+            Some(Frame::Header(_)) => {
+                return Err(SnapshotError::Corrupted("duplicate header".to_string()))
+            }
+            None => {
+                return Err(SnapshotError::Corrupted(
+                    "the snapshot is truncated".to_string(),
+                ))
+            }
+        };
+
+        let store = store_from_proto(row.store)?;
+        let Some((_, db, batch)) = stores.iter_mut().find(|(known, ..)| *known == store) else {
+            return Err(SnapshotError::Corrupted(format!(
+                "row of the {store} store, which is not part of snapshots"
+            )));
+        };
+        let cf = db.raw_rocksdb().cf_handle(&row.cf).ok_or_else(|| {
+            SnapshotError::Corrupted(format!(
+                "unknown column family {:?} in the {store} store",
+                row.cf
+            ))
+        })?;
+        batch.put_cf(&cf, &row.key, &row.value);
+        if batch.len() >= IMPORT_BATCH_ROWS {
+            db.write_batch(std::mem::take(batch))?;
+        }
+        rows += 1;
+    };
+
+    let sha256 = reader.sha256();
+    if *trailer.sha256 != sha256[..] {
+        return Err(SnapshotError::ChecksumMismatch);
+    }
+    if trailer.rows != rows {
+        return Err(SnapshotError::Corrupted(format!(
+            "the trailer records {} rows, {rows} were read",
+            trailer.rows
+        )));
+    }
+    if reader.read_frame()?.is_some() {
+        return Err(SnapshotError::Corrupted(
+            "data after the trailer".to_string(),
+        ));
+    }
+
+    let mut state_db = None;
+    for (store, db, batch) in stores {
+        db.write_batch(batch)?;
+        if store == Store::State {
+            state_db = Some(db);
+        }
+    }
+    let state_db = state_db.expect("the state store is part of snapshots");
+    check_network_states(
+        &networks,
+        &network_states(&StateStore::new(Arc::new(state_db), BackupClient::noop()))?,
+    )?;
+
+    Ok(SnapshotReport {
+        path: input.to_path_buf(),
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        created_at: created_at.into(),
+        rows,
+        sha256: hex::encode(sha256),
+        networks,
+    })
+}
+
+/// The settled state of every network with a local network state.
+///
+/// The trees are read back by rehashing their nodes, so the roots are the
+/// ones of the stored nodes. Each state is checked against the local exit root
+/// of the latest settled certificate of its network, which fails while a
+/// settlement is being recorded, and against the balance and nullifier roots
+/// recorded when that certificate settled.
+fn network_states(state: &StateStore) -> Result<Vec<SnapshotNetworkState>, SnapshotError> {
+    let mut networks = Vec::new();
+    for (network_id, SettledCertificate(certificate_id, height, _, _)) in
+        state.get_current_settled_height()?
+    {
+        let Some(local_state) = read_trees(network_id, state.read_local_network_state(network_id))?
+        else {
+            warn!(%network_id, "Settled network without a local network state");
+            continue;
+        };
+
+        let local_exit_root = LocalExitRoot::from(local_state.exit_tree.get_root());
+        let anchored = state
+            .get_certificate_header(&certificate_id)?
+            .is_some_and(|header| header.new_local_exit_root == local_exit_root);
+        if !anchored {
+            return Err(SnapshotError::NetworkStateMismatch {
+                network_id,
+                reason: format!(
+                    "the local network state does not match the latest settled certificate \
+                     {certificate_id}"
+                ),
+            });
+        }
+
+        // Networks settled before the snapshots got recorded may have none
+        // until it is backfilled.
+        if let Some(settled_state) = read_trees(
+            network_id,
+            state.read_local_network_state_at(network_id, height),
+        )? {
+            if settled_state.balance_tree.root != local_state.balance_tree.root
+                || settled_state.nullifier_tree.root != local_state.nullifier_tree.root
+            {
+                return Err(SnapshotError::NetworkStateMismatch {
+                    network_id,
+                    reason: format!(
+                        "the local network state does not match the one recorded when certificate \
+                         {certificate_id} settled"
+                    ),
+                });
+            }
+        }
+
+        networks.push(SnapshotNetworkState {
+            network_id,
+            anchor: SettledStateAnchor {
+                certificate_id,
+                height,
+                local_exit_root,
+                let_leaf_count: local_state.exit_tree.leaf_count(),
+                balance_root: local_state.balance_tree.root,
+                nullifier_root: local_state.nullifier_tree.root,
+            },
+        });
+    }
+
+    Ok(networks)
+}
+
+/// Report the trees of a network whose nodes don't check out as a mismatch of
+/// its state.
+fn read_trees<T>(
+    network_id: NetworkId,
+    read: Result<T, crate::error::Error>,
+) -> Result<T, SnapshotError> {
+    read.map_err(|error| match error {
+        crate::error::Error::SmtNodeNotFound | crate::error::Error::SmtNodeHashMismatch(_) => {
+            SnapshotError::NetworkStateMismatch {
+                network_id,
+                reason: error.to_string(),
+            }
+        }
+        error => error.into(),
+    })
+}
+
+/// Check the network states of the imported rows against the ones recorded
+/// in the header of the snapshot.
+fn check_network_states(
+    expected: &[SnapshotNetworkState],
+    imported: &[SnapshotNetworkState],
+) -> Result<(), SnapshotError> {
+    for network in expected {
+        match imported
+            .iter()
+            .find(|imported| imported.network_id == network.network_id)
+        {
+            Some(imported) if imported == network => {}
+            Some(imported) => {
+                return Err(SnapshotError::NetworkStateMismatch {
+                    network_id: network.network_id,
+                    reason: format!(
+                        "the header records {:?}, the rows hold {:?}",
+                        network.anchor, imported.anchor
+                    ),
+                })
+            }
+            None => {
+                return Err(SnapshotError::NetworkStateMismatch {
+                    network_id: network.network_id,
+                    reason: "missing from the rows".to_string(),
+                })
+            }
+        }
+    }
+
+    if let Some(unexpected) = imported
+        .iter()
+        .find(|imported| !expected.iter().any(|n| n.network_id == imported.network_id))
+    {
+        return Err(SnapshotError::NetworkStateMismatch {
+            network_id: unexpected.network_id,
+            reason: "missing from the header".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn proto_store(store: Store) -> v0::SnapshotStore {
+    match store {
+        Store::State => v0::SnapshotStore::State,
+        Store::Pending => v0::SnapshotStore::Pending,
+        Store::Debug | Store::Epoch(_) => v0::SnapshotStore::Unspecified,
+    }
+}
+
+fn store_from_proto(store: i32) -> Result<Store, SnapshotError> {
+    match v0::SnapshotStore::try_from(store) {
+        Ok(v0::SnapshotStore::State) => Ok(Store::State),
+        Ok(v0::SnapshotStore::Pending) => Ok(Store::Pending),
+        Ok(v0::SnapshotStore::Unspecified) | Err(_) => {
+            Err(SnapshotError::Corrupted(format!("unknown store {store}")))
+        }
+    }
+}
+
+/// Create a store, running the migrations like the node does at startup.
+fn create_db(store: Store, path: &Path) -> Result<DB, SnapshotError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|source| SnapshotError::Io {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+
+    match store {
+        Store::State => StateStore::init_db(path),
+        Store::Pending => PendingStore::init_db(path),
+        Store::Debug | Store::Epoch(_) => unreachable!("the {store} store is not in snapshots"),
+    }
+    .map_err(|source| SnapshotError::Create {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Scratch directory of an import, next to its target.
+fn staging_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".snapshot-import");
+    path.with_file_name(name)
+}
+
+fn is_empty_dir(path: &Path) -> Result<bool, SnapshotError> {
+    match fs::read_dir(path) {
+        Ok(mut entries) => Ok(entries.next().is_none()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(source) => Err(SnapshotError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn remove_dir_if_exists(path: &Path) -> Result<(), SnapshotError> {
+    match fs::remove_dir_all(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(source) => Err(SnapshotError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+impl From<&SnapshotNetworkState> for v0::SnapshotNetworkState {
+    fn from(value: &SnapshotNetworkState) -> Self {
+        let anchor = &value.anchor;
+        let local_exit_root: &[u8] = anchor.local_exit_root.as_ref();
+
+        Self {
+            network_id: value.network_id.to_u32(),
+            certificate_id: Some(v0::SettledCertificateId {
+                id: Bytes::copy_from_slice(anchor.certificate_id.as_slice()),
+            }),
+            height: anchor.height.as_u64(),
+            local_exit_root: Some(v0::SettledLocalExitRoot {
+                root: Bytes::copy_from_slice(local_exit_root),
+            }),
+            let_leaf_count: Some(v0::SettledLocalExitTreeLeafCount {
+                settled_let_leaf_count: anchor.let_leaf_count.into(),
+            }),
+            balance_root: Bytes::copy_from_slice(anchor.balance_root.as_slice()),
+            nullifier_root: Bytes::copy_from_slice(anchor.nullifier_root.as_slice()),
+        }
+    }
+}
+
+impl TryFrom<v0::SnapshotNetworkState> for SnapshotNetworkState {
+    type Error = SnapshotError;
+
+    fn try_from(value: v0::SnapshotNetworkState) -> Result<Self, Self::Error> {
+        let network_id = NetworkId::new(value.network_id);
+        let corrupted = |field: &str| {
+            SnapshotError::Corrupted(format!("invalid {field} for network {network_id}"))
+        };
+        let digest =
+            |bytes: &[u8], field: &str| Digest::try_from(bytes).map_err(|_| corrupted(field));
+
+        let certificate_id = value
+            .certificate_id
+            .ok_or_else(|| corrupted("certificate id"))?;
+        let local_exit_root = value
+            .local_exit_root
+            .ok_or_else(|| corrupted("local exit root"))?;
+        let let_leaf_count = value
+            .let_leaf_count
+            .and_then(|count| u32::try_from(count.settled_let_leaf_count).ok())
+            .ok_or_else(|| corrupted("leaf count"))?;
+
+        Ok(Self {
+            network_id,
+            anchor: SettledStateAnchor {
+                certificate_id: CertificateId::from(digest(&certificate_id.id, "certificate id")?),
+                height: Height::new(value.height),
+                local_exit_root: LocalExitRoot::from(digest(
+                    &local_exit_root.root,
+                    "local exit root",
+                )?),
+                let_leaf_count,
+                balance_root: digest(&value.balance_root, "balance root")?,
+                nullifier_root: digest(&value.nullifier_root, "nullifier root")?,
+            },
+        })
+    }
+}
+
+/// Writes the frames of a snapshot, hashing them along the way.
+struct SnapshotWriter<W> {
+    writer: W,
+    path: PathBuf,
+    hasher: Sha256,
+    rows: u64,
+}
+
+impl<W: Write> SnapshotWriter<W> {
+    fn new(writer: W, path: &Path) -> Result<Self, SnapshotError> {
+        let mut writer = Self {
+            writer,
+            path: path.to_path_buf(),
+            hasher: Sha256::new(),
+            rows: 0,
+        };
+        writer.write_bytes(MAGIC)?;
+
+        Ok(writer)
+    }
+
+    fn write_frame(&mut self, frame: Frame) -> Result<(), SnapshotError> {
+        let bytes = v0::SnapshotFrame { frame: Some(frame) }.encode_length_delimited_to_vec();
+        self.write_bytes(&bytes)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), SnapshotError> {
+        self.hasher.update(bytes);
+        self.writer
+            .write_all(bytes)
+            .map_err(|source| SnapshotError::Io {
+                path: self.path.clone(),
+                source,
+            })
+    }
+
+    /// Write the trailer, which is not part of the checksum, and flush the
+    /// snapshot. Returns the number of rows and the hex encoded checksum.
+    fn finish(mut self) -> Result<(u64, String), SnapshotError> {
+        let sha256 = self.hasher.clone().finalize();
+        let trailer = v0::SnapshotFrame {
+            frame: Some(Frame::Trailer(v0::SnapshotTrailer {
+                rows: self.rows,
+                sha256: Bytes::copy_from_slice(&sha256),
+            })),
+        };
+
+        let io_error = |source| SnapshotError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        self.writer
+            .write_all(&trailer.encode_length_delimited_to_vec())
+            .map_err(io_error)?;
+        self.writer.flush().map_err(io_error)?;
+
+        Ok((self.rows, hex::encode(sha256)))
+    }
+}
+
+/// Reads the frames of a snapshot, hashing all of them but the trailer.
+struct SnapshotReader<R> {
+    reader: R,
+    path: PathBuf,
+    hasher: Sha256,
+}
+
+impl<R: Read> SnapshotReader<R> {
+    fn new(mut reader: R, path: &Path) -> Result<Self, SnapshotError> {
+        let mut magic = [0; MAGIC.len()];
+        match reader.read_exact(&mut magic) {
+            Ok(()) if magic == *MAGIC => {}
+            Ok(()) => return Err(SnapshotError::NotASnapshot),
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(SnapshotError::NotASnapshot)
+            }
+            Err(source) => {
+                return Err(SnapshotError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(magic);
+
+        Ok(Self {
+            reader,
+            path: path.to_path_buf(),
+            hasher,
+        })
+    }
+
+    /// Read the next frame, `None` at the end of the file.
+    fn read_frame(&mut self) -> Result<Option<Frame>, SnapshotError> {
+        let truncated = || SnapshotError::Corrupted("the snapshot is truncated".to_string());
+
+        let mut prefix = Vec::with_capacity(10);
+        loop {
+            let mut byte = [0];
+            match self.reader.read_exact(&mut byte) {
+                Ok(()) => {}
+                Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                    return if prefix.is_empty() {
+                        Ok(None)
+                    } else {
+                        Err(truncated())
+                    };
+                }
+                Err(source) => {
+                    return Err(SnapshotError::Io {
+                        path: self.path.clone(),
+                        source,
+                    })
+                }
+            }
+
+            prefix.push(byte[0]);
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+            if prefix.len() == 10 {
+                return Err(SnapshotError::Corrupted("invalid frame length".to_string()));
+            }
+        }
+
+        let len = prost::encoding::decode_varint(&mut prefix.as_slice())
+            .map_err(|error| SnapshotError::Corrupted(format!("invalid frame length: {error}")))?;
+        if len > MAX_FRAME_LEN {
+            return Err(SnapshotError::Corrupted(format!(
+                "frame of {len} bytes is too large"
+            )));
+        }
+
+        let mut body = vec![0; len as usize];
+        self.reader.read_exact(&mut body).map_err(|error| {
+            if error.kind() == io::ErrorKind::UnexpectedEof {
+                truncated()
+            } else {
+                SnapshotError::Io {
+                    path: self.path.clone(),
+                    source: error,
+                }
+            }
+        })?;
+
+        let frame = v0::SnapshotFrame::decode(body.as_slice())
+            .map_err(|error| SnapshotError::Corrupted(format!("invalid frame: {error}")))?
+            .frame
+            .ok_or_else(|| SnapshotError::Corrupted("empty frame".to_string()))?;
+        if !matches!(frame, Frame::Trailer(_)) {
+            self.hasher.update(&prefix);
+            self.hasher.update(&body);
+        }
+
+        Ok(Some(frame))
+    }
+
+    /// SHA-256 of the frames read so far, the trailer excluded.
+    fn sha256(&self) -> [u8; 32] {
+        self.hasher.clone().finalize().into()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "testutils")]
+mod tests;
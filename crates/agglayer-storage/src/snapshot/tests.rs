@@ -0,0 +1,234 @@
+use std::sync::Arc;
+
+use agglayer_config::storage::StorageConfig;
+use agglayer_types::{
+    Certificate, CertificateIndex, CertificateStatus, Digest, EpochNumber, Height,
+    LocalNetworkStateData, NetworkId,
+};
+
+use super::*;
+use crate::{
+    columns::balance_tree_per_network::BalanceTreePerNetworkColumn,
+    stores::{
+        PendingCertificateReader as _, PendingCertificateWriter as _, StateReader as _,
+        StateWriter as _,
+    },
+    tests::TempDBDir,
+    types::{SmtKey, SmtKeyType, SmtValue},
+};
+
+const NETWORK_ID: NetworkId = NetworkId::new(1);
+
+/// Settle a certificate for [`NETWORK_ID`] and queue the next one, returning
+/// the settled local network state.
+///
+/// The stores are closed on return, like those of a stopped node.
+fn seed_stores(config: &StorageConfig, anchored: bool) -> LocalNetworkStateData {
+    let state_db = Arc::new(create_db(Store::State, &config.state_db_path).unwrap());
+    let state = StateStore::new(state_db, BackupClient::noop());
+
+    let mut local_state = LocalNetworkStateData::default();
+    let leaf = Digest([1; 32]);
+    local_state.exit_tree.add_leaf(leaf).unwrap();
+
+    let mut certificate = Certificate::new_for_test(NETWORK_ID, Height::ZERO);
+    if anchored {
+        certificate.new_local_exit_root = local_state.exit_tree.get_root().into();
+    }
+    let certificate_id = certificate.hash();
+    state
+        .insert_certificate_header(&certificate, CertificateStatus::Settled)
+        .unwrap();
+    state
+        .write_local_network_state(&NETWORK_ID, &local_state, &[leaf])
+        .unwrap();
+    state
+        .set_latest_settled_certificate_for_network(
+            &NETWORK_ID,
+            &Height::ZERO,
+            &certificate_id,
+            &EpochNumber::ZERO,
+            &CertificateIndex::new(0),
+        )
+        .unwrap();
+
+    let pending_db = Arc::new(create_db(Store::Pending, &config.pending_db_path).unwrap());
+    PendingStore::new(pending_db)
+        .insert_pending_certificate(
+            NETWORK_ID,
+            Height::new(1),
+            &Certificate::new_for_test(NETWORK_ID, Height::new(1)),
+        )
+        .unwrap();
+
+    local_state
+}
+
+#[test]
+fn snapshot_round_trips_into_empty_stores() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    let local_state = seed_stores(&source_config, true);
+    let path = source.path.join("node.snapshot");
+
+    let exported = export_snapshot(&source_config, &path).unwrap();
+
+    assert_eq!(exported.networks.len(), 1);
+    assert_eq!(exported.networks[0].network_id, NETWORK_ID);
+    assert_eq!(
+        exported.networks[0].anchor.balance_root,
+        local_state.balance_tree.root
+    );
+    assert!(!path.with_extension("tmp").exists());
+
+    let target = TempDBDir::new();
+    let target_config = StorageConfig::new_from_path(&target.path);
+    let imported = import_snapshot(&target_config, &path).unwrap();
+
+    assert_eq!(imported.rows, exported.rows);
+    assert_eq!(imported.sha256, exported.sha256);
+    assert_eq!(imported.networks, exported.networks);
+
+    let state =
+        StateStore::new_with_path(&target_config.state_db_path, BackupClient::noop()).unwrap();
+    let imported_state = state.read_local_network_state(NETWORK_ID).unwrap().unwrap();
+    assert_eq!(
+        imported_state.exit_tree.get_root(),
+        local_state.exit_tree.get_root()
+    );
+
+    let pending = PendingStore::new(Arc::new(
+        PendingStore::init_db(&target_config.pending_db_path).unwrap(),
+    ));
+    assert!(pending
+        .get_certificate(NETWORK_ID, Height::new(1))
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn import_refuses_stores_holding_data() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    seed_stores(&source_config, true);
+    let path = source.path.join("node.snapshot");
+    export_snapshot(&source_config, &path).unwrap();
+
+    assert!(matches!(
+        import_snapshot(&source_config, &path),
+        Err(SnapshotError::TargetNotEmpty { path }) if path == source_config.state_db_path
+    ));
+}
+
+#[test]
+fn import_rejects_a_corrupted_snapshot() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    seed_stores(&source_config, true);
+    let path = source.path.join("node.snapshot");
+    export_snapshot(&source_config, &path).unwrap();
+
+    // Flip a bit of the checksum held by the trailer.
+    let mut bytes = fs::read(&path).unwrap();
+    *bytes.last_mut().unwrap() ^= 1;
+    fs::write(&path, bytes).unwrap();
+
+    let target = TempDBDir::new();
+    let target_config = StorageConfig::new_from_path(&target.path);
+    assert!(matches!(
+        import_snapshot(&target_config, &path),
+        Err(SnapshotError::ChecksumMismatch)
+    ));
+
+    for store in STORES {
+        let target_path = store.db_path(&target_config);
+        assert!(!target_path.exists());
+        assert!(!staging_path(&target_path).exists());
+    }
+}
+
+#[test]
+fn import_rehashes_the_tree_nodes() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    seed_stores(&source_config, true);
+    let path = source.path.join("node.snapshot");
+    let exported = export_snapshot(&source_config, &path).unwrap();
+
+    // Replace a node below the balance root, leaving the root and the
+    // recorded network states untouched.
+    {
+        let state_db = create_db(Store::State, &source_config.state_db_path).unwrap();
+        let root = state_db
+            .get::<BalanceTreePerNetworkColumn>(&SmtKey {
+                network_id: NETWORK_ID.to_u32(),
+                key_type: SmtKeyType::Root,
+            })
+            .unwrap();
+        let Some(SmtValue::Node(left, _)) = root else {
+            panic!("the balance root is stored as a node");
+        };
+        state_db
+            .put::<BalanceTreePerNetworkColumn>(
+                &SmtKey {
+                    network_id: NETWORK_ID.to_u32(),
+                    key_type: SmtKeyType::Node(left),
+                },
+                &SmtValue::Leaf(Digest([7; 32])),
+            )
+            .unwrap();
+    }
+
+    let stores: Vec<_> = STORES
+        .into_iter()
+        .map(|store| {
+            let db = DB::open_cf_exclusive(&store.db_path(&source_config), store.cfs()).unwrap();
+            (store, Arc::new(db))
+        })
+        .collect();
+    // Written like an export would, but with the states computed before
+    // the node got replaced.
+    let header = snapshot_header(SystemTime::now(), &stores, &exported.networks).unwrap();
+    write_snapshot(&path, header, &stores).unwrap();
+    drop(stores);
+
+    let target = TempDBDir::new();
+    let target_config = StorageConfig::new_from_path(&target.path);
+    assert!(matches!(
+        import_snapshot(&target_config, &path),
+        Err(SnapshotError::NetworkStateMismatch { network_id, .. }) if network_id == NETWORK_ID
+    ));
+    assert!(!target_config.state_db_path.exists());
+}
+
+#[test]
+fn export_refuses_an_unanchored_network_state() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    seed_stores(&source_config, false);
+    let path = source.path.join("node.snapshot");
+
+    assert!(matches!(
+        export_snapshot(&source_config, &path),
+        Err(SnapshotError::NetworkStateMismatch { network_id, .. }) if network_id == NETWORK_ID
+    ));
+    assert!(!path.exists());
+}
+
+#[test]
+fn export_refuses_stores_in_use() {
+    let source = TempDBDir::new();
+    let source_config = StorageConfig::new_from_path(&source.path);
+    seed_stores(&source_config, true);
+    let path = source.path.join("node.snapshot");
+
+    // Like a running node.
+    let _state =
+        StateStore::new_with_path(&source_config.state_db_path, BackupClient::noop()).unwrap();
+
+    assert!(matches!(
+        export_snapshot(&source_config, &path),
+        Err(SnapshotError::StoresInUse { path }) if path == source_config.state_db_path
+    ));
+    assert!(!path.exists());
+}
@@ -325,6 +325,19 @@ impl Builder {
     }
 }
 
+impl DB {
+    /// Number of migration steps recorded in the database.
+    pub(crate) fn migration_steps(&self) -> Result<u32, DBError> {
+        let mut steps = 0;
+        for step in self.keys::<MigrationRecordColumn>()? {
+            step?;
+            steps += 1;
+        }
+
+        Ok(steps)
+    }
+}
+
 impl std::fmt::Debug for Builder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Self {
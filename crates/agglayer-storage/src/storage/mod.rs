@@ -55,18 +55,8 @@ impl DB {
     /// column families. This prevents concurrency issues when multiple
     /// processes need to read from the database.
     pub fn open_cf_readonly(path: &Path, cfs: &[ColumnDescriptor]) -> Result<DB, DBError> {
-        let mut options = Options::default();
-        options.create_if_missing(false); // Don't create if missing in readonly mode
-        options.create_missing_column_families(false); // Don't create missing column families
-
-        let descriptors: Vec<_> = match rocksdb::DB::list_cf(&options, path) {
-            Ok(names) => names
-                .into_iter()
-                .filter(|name| name != rocksdb::DEFAULT_COLUMN_FAMILY_NAME)
-                .map(|name| Self::descriptor_for_existing(&name, cfs))
-                .collect(),
-            Err(_) => cfs.iter().map(Self::descriptor).collect(),
-        };
+        let options = Self::existing_options();
+        let descriptors = Self::descriptors_on_disk(&options, path, cfs);
 
         Ok(DB {
             rocksdb: rocksdb::DB::open_cf_descriptors_read_only(
@@ -79,6 +69,46 @@ impl DB {
         })
     }
 
+    /// Open an existing RocksDB instance at the given path with some column
+    /// families, holding its lock until the instance is dropped. Opening fails
+    /// while another instance holds it, e.g. a running node, and no other
+    /// instance can open it in the meantime. Writes are refused as in
+    /// read-only mode.
+    pub(crate) fn open_cf_exclusive(path: &Path, cfs: &[ColumnDescriptor]) -> Result<DB, DBError> {
+        let options = Self::existing_options();
+        let descriptors = Self::descriptors_on_disk(&options, path, cfs);
+
+        Ok(DB {
+            rocksdb: rocksdb::DB::open_cf_descriptors(&options, path, descriptors)?,
+            default_write_options: None,
+        })
+    }
+
+    /// Options opening an existing database as it is on disk.
+    fn existing_options() -> Options {
+        let mut options = Options::default();
+        options.create_if_missing(false); // Don't create if missing
+        options.create_missing_column_families(false); // Don't create missing column families
+        options
+    }
+
+    /// Descriptors of the column families found on disk, or of the given ones
+    /// if they can't be listed.
+    fn descriptors_on_disk(
+        options: &Options,
+        path: &Path,
+        cfs: &[ColumnDescriptor],
+    ) -> Vec<ColumnFamilyDescriptor> {
+        match rocksdb::DB::list_cf(options, path) {
+            Ok(names) => names
+                .into_iter()
+                .filter(|name| name != rocksdb::DEFAULT_COLUMN_FAMILY_NAME)
+                .map(|name| Self::descriptor_for_existing(&name, cfs))
+                .collect(),
+            Err(_) => cfs.iter().map(Self::descriptor).collect(),
+        }
+    }
+
     fn write_options(&self) -> Result<&WriteOptions, DBError> {
         self.default_write_options
             .as_ref()
@@ -196,6 +226,22 @@ impl DB {
         Ok(())
     }
 
+    pub(crate) fn multi_delete_batch<'a, C: ColumnSchema + 'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a C::Key>,
+        batch: &mut WriteBatch,
+    ) -> Result<(), DBError> {
+        let cf = self.cf::<C>()?;
+
+        keys.into_iter()
+            .try_for_each::<_, Result<_, DBError>>(|k| {
+                batch.delete_cf(&cf, k.encode()?);
+                Ok(())
+            })?;
+
+        Ok(())
+    }
+
     pub fn multi_insert<'a, C: ColumnSchema + 'a>(
         &self,
         key_val_pairs: impl IntoIterator<Item = (&'a C::Key, &'a C::Value)>,
@@ -234,6 +280,45 @@ impl DB {
         Ok(ColumnIterator::new(iterator, direction))
     }
 
+    /// Iterates over the column family starting at `key`, or at the closest
+    /// key after (forward) or before (reverse) it when `key` is absent.
+    pub(crate) fn iter_from<C: ColumnSchema>(
+        &self,
+        key: &C::Key,
+        direction: Direction,
+    ) -> Result<ColumnIterator<'_, C>, DBError> {
+        let cf = self.cf::<C>()?;
+        let key = key.encode()?;
+
+        let mut iterator = self.rocksdb.raw_iterator_cf(&cf);
+
+        match direction {
+            Direction::Forward => iterator.seek(&key),
+            Direction::Reverse => iterator.seek_for_prev(&key),
+        }
+
+        Ok(ColumnIterator::new(iterator, direction))
+    }
+
+    /// Same as [`Self::iter_from`], without decoding the values.
+    pub(crate) fn keys_from<C: ColumnSchema>(
+        &self,
+        key: &C::Key,
+        direction: Direction,
+    ) -> Result<KeysIterator<'_, C>, DBError> {
+        let cf = self.cf::<C>()?;
+        let key = key.encode()?;
+
+        let mut iterator = self.rocksdb.raw_iterator_cf(&cf);
+
+        match direction {
+            Direction::Forward => iterator.seek(&key),
+            Direction::Reverse => iterator.seek_for_prev(&key),
+        }
+
+        Ok(KeysIterator::new(iterator, direction))
+    }
+
     /// Iterates over the keys sharing `prefix`, relying on the column
     /// family's prefix extractor to stop at the prefix boundary. The column
     /// family MUST be opened with a prefix extractor covering exactly the
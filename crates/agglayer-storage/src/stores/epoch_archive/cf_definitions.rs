@@ -0,0 +1,13 @@
+use crate::{
+    columns::epoch_archive::{
+        ArchivedCertificatesColumn, ArchivedEpochsColumn, ArchivedProofsColumn,
+    },
+    schema::ColumnDescriptor,
+};
+
+/// Definitions for the column families in the epoch archive storage.
+pub const EPOCH_ARCHIVE_DB: &[ColumnDescriptor] = &[
+    ColumnDescriptor::new::<ArchivedCertificatesColumn>(),
+    ColumnDescriptor::new::<ArchivedProofsColumn>(),
+    ColumnDescriptor::new::<ArchivedEpochsColumn>(),
+];
@@ -0,0 +1,229 @@
+//! Cold archive of the epochs, stored as plain files.
+//!
+//! Certificates and proofs are stored once, under `objects/`, in files named
+//! after the SHA-256 of their content. Each archived epoch gets a manifest,
+//! `epochs/<epoch>.json`, listing its objects in index order. The manifest is
+//! written last, so an epoch without a manifest isn't archived.
+
+use std::{
+    fs,
+    io::{self, Write as _},
+    path::{Path, PathBuf},
+};
+
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use super::PackedEpoch;
+use crate::{error::Error, schema::Codec, storage::DBError};
+
+const OBJECTS_DIR: &str = "objects";
+const EPOCHS_DIR: &str = "epochs";
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Manifest {
+    epoch_number: EpochNumber,
+    certificates: Vec<ManifestEntry>,
+    start_checkpoint: Vec<(NetworkId, Height)>,
+    end_checkpoint: Vec<(NetworkId, Height)>,
+}
+
+/// SHA-256 of the objects holding a certificate and its proof.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ManifestEntry {
+    certificate: String,
+    proof: Option<String>,
+}
+
+pub(super) struct ColdArchive {
+    root: PathBuf,
+}
+
+impl ColdArchive {
+    pub(super) fn open(root: &Path) -> Result<Self, Error> {
+        for dir in [OBJECTS_DIR, EPOCHS_DIR] {
+            let path = root.join(dir);
+            fs::create_dir_all(&path).map_err(|source| Error::EpochArchiveIo { path, source })?;
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+        })
+    }
+
+    pub(super) fn open_readonly(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+        }
+    }
+
+    pub(super) fn is_archived(&self, epoch_number: EpochNumber) -> Result<bool, Error> {
+        let path = self.manifest_path(epoch_number);
+        path.try_exists()
+            .map_err(|source| Error::EpochArchiveIo { path, source })
+    }
+
+    pub(super) fn archive(&self, epoch: &PackedEpoch) -> Result<(), Error> {
+        let certificates = epoch
+            .certificates
+            .iter()
+            .map(|(certificate, proof)| {
+                Ok(ManifestEntry {
+                    certificate: self.put_object(&encode(certificate)?)?,
+                    proof: proof
+                        .as_ref()
+                        .map(|proof| self.put_object(&encode(proof)?))
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let manifest = Manifest {
+            epoch_number: epoch.epoch_number,
+            certificates,
+            start_checkpoint: epoch.start_checkpoint.clone().into_iter().collect(),
+            end_checkpoint: epoch.end_checkpoint.clone().into_iter().collect(),
+        };
+        let manifest = serde_json::to_vec_pretty(&manifest).map_err(|error| {
+            Error::Unexpected(format!("Unable to serialize the epoch manifest: {error}"))
+        })?;
+
+        write_file(&self.manifest_path(epoch.epoch_number), &manifest)
+    }
+
+    pub(super) fn get_certificate(
+        &self,
+        epoch_number: EpochNumber,
+        index: CertificateIndex,
+    ) -> Result<Option<Certificate>, Error> {
+        self.get_entry(epoch_number, index, |entry| Some(&entry.certificate))
+    }
+
+    pub(super) fn get_proof(
+        &self,
+        epoch_number: EpochNumber,
+        index: CertificateIndex,
+    ) -> Result<Option<Proof>, Error> {
+        self.get_entry(epoch_number, index, |entry| entry.proof.as_ref())
+    }
+
+    fn get_entry<T: Codec>(
+        &self,
+        epoch_number: EpochNumber,
+        index: CertificateIndex,
+        object: impl FnOnce(&ManifestEntry) -> Option<&String>,
+    ) -> Result<Option<T>, Error> {
+        let Some(manifest) = self.read_manifest(epoch_number)? else {
+            return Ok(None);
+        };
+
+        let Some(digest) = usize::try_from(index.as_u64())
+            .ok()
+            .and_then(|index| manifest.certificates.get(index))
+            .and_then(object)
+        else {
+            return Ok(None);
+        };
+
+        let bytes = self.get_object(digest)?;
+        Ok(Some(T::decode(&bytes).map_err(DBError::from)?))
+    }
+
+    fn read_manifest(&self, epoch_number: EpochNumber) -> Result<Option<Manifest>, Error> {
+        let path = self.manifest_path(epoch_number);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => return Err(Error::EpochArchiveIo { path, source }),
+        };
+
+        let manifest: Manifest = serde_json::from_slice(&bytes).map_err(|error| {
+            Error::CorruptedEpochArchive(format!("Unreadable manifest {}: {error}", path.display()))
+        })?;
+        if manifest.epoch_number != epoch_number {
+            return Err(Error::CorruptedEpochArchive(format!(
+                "Manifest {} describes the epoch {}",
+                path.display(),
+                manifest.epoch_number
+            )));
+        }
+
+        Ok(Some(manifest))
+    }
+
+    /// Store an object, returning its SHA-256. Objects already stored are
+    /// left untouched.
+    fn put_object(&self, bytes: &[u8]) -> Result<String, Error> {
+        let digest = hex::encode(Sha256::digest(bytes));
+        let path = self.object_path(&digest);
+        let exists = path.try_exists().map_err(|source| Error::EpochArchiveIo {
+            path: path.clone(),
+            source,
+        })?;
+        if !exists {
+            write_file(&path, bytes)?;
+        }
+
+        Ok(digest)
+    }
+
+    /// Read an object, checking its content against its SHA-256.
+    fn get_object(&self, digest: &str) -> Result<Vec<u8>, Error> {
+        let path = self.object_path(digest);
+        let bytes = fs::read(&path).map_err(|source| Error::EpochArchiveIo {
+            path: path.clone(),
+            source,
+        })?;
+
+        if hex::encode(Sha256::digest(&bytes)) != digest {
+            return Err(Error::CorruptedEpochArchive(format!(
+                "Checksum mismatch for {}",
+                path.display()
+            )));
+        }
+
+        Ok(bytes)
+    }
+
+    fn object_path(&self, digest: &str) -> PathBuf {
+        self.root.join(OBJECTS_DIR).join(digest)
+    }
+
+    fn manifest_path(&self, epoch_number: EpochNumber) -> PathBuf {
+        self.root
+            .join(EPOCHS_DIR)
+            .join(format!("{epoch_number}.json"))
+    }
+}
+
+fn encode(value: &impl Codec) -> Result<Vec<u8>, Error> {
+    value.encode().map_err(|error| Error::DBError(error.into()))
+}
+
+/// Write a file through a temporary file, so a crash never leaves a
+/// partially written file behind.
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), Error> {
+    let io_error = |source| Error::EpochArchiveIo {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let tmp_path = path.with_extension("tmp");
+    let mut file = fs::File::create(&tmp_path).map_err(io_error)?;
+    file.write_all(bytes).map_err(io_error)?;
+    file.sync_all().map_err(io_error)?;
+    fs::rename(&tmp_path, path).map_err(io_error)
+}
+
+#[cfg(test)]
+impl ColdArchive {
+    pub(super) fn object_paths(&self) -> Vec<PathBuf> {
+        let mut paths = fs::read_dir(self.root.join(OBJECTS_DIR))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+}
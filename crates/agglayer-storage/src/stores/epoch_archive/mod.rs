@@ -0,0 +1,196 @@
+use std::{collections::BTreeMap, path::Path};
+
+use agglayer_config::storage::epoch_retention::EpochArchiveConfig;
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
+use rocksdb::WriteBatch;
+
+use super::interfaces::reader::PerEpochReader;
+use crate::{
+    columns::epoch_archive::{
+        ArchivedCertificatesColumn, ArchivedEpochsColumn, ArchivedProofsColumn,
+    },
+    error::Error,
+    storage::DB,
+    types::epoch_archive::{ArchivedEpoch, Key},
+};
+
+pub(crate) mod cf_definitions;
+mod cold;
+
+#[cfg(test)]
+mod tests;
+
+/// Archive of the packed epochs evicted by the retention policy.
+///
+/// Archived epochs are either merged into a single compacted database, or
+/// exported to a cold-archive directory.
+pub struct EpochArchive {
+    backend: Backend,
+}
+
+enum Backend {
+    Database(DB),
+    ColdFiles(cold::ColdArchive),
+}
+
+/// Content of a packed epoch, as moved to the archive.
+pub(crate) struct PackedEpoch {
+    pub(crate) epoch_number: EpochNumber,
+    /// Certificates of the epoch, in index order, along with their proof.
+    pub(crate) certificates: Vec<(Certificate, Option<Proof>)>,
+    pub(crate) start_checkpoint: BTreeMap<NetworkId, Height>,
+    pub(crate) end_checkpoint: BTreeMap<NetworkId, Height>,
+}
+
+impl PackedEpoch {
+    /// Read the content of an epoch, or `None` if the epoch isn't packed yet.
+    pub(crate) fn read(store: &impl PerEpochReader) -> Result<Option<Self>, Error> {
+        if !store.is_epoch_packed() {
+            return Ok(None);
+        }
+
+        let mut certificates = Vec::new();
+        loop {
+            let index = CertificateIndex::new(certificates.len() as u64);
+            let Some(certificate) = store.get_certificate_at_index(index)? else {
+                break;
+            };
+            certificates.push((certificate, store.get_proof_at_index(index)?));
+        }
+
+        Ok(Some(Self {
+            epoch_number: store.get_epoch_number(),
+            certificates,
+            start_checkpoint: store.get_start_checkpoint().clone(),
+            end_checkpoint: store.get_end_checkpoint(),
+        }))
+    }
+}
+
+impl EpochArchive {
+    pub fn init_db(path: &Path) -> Result<DB, crate::storage::DBOpenError> {
+        DB::open_cf(path, cf_definitions::EPOCH_ARCHIVE_DB)
+    }
+
+    /// Open the archive, creating it if missing.
+    pub fn open(config: &EpochArchiveConfig) -> Result<Self, Error> {
+        let backend = match config {
+            EpochArchiveConfig::Database { path } => {
+                Backend::Database(Self::init_db(path).map_err(Error::DBOpenError)?)
+            }
+            EpochArchiveConfig::ColdFiles { path } => {
+                Backend::ColdFiles(cold::ColdArchive::open(path)?)
+            }
+        };
+
+        Ok(Self { backend })
+    }
+
+    /// Open the archive read-only, or `None` if nothing was archived yet.
+    pub fn open_readonly(config: &EpochArchiveConfig) -> Result<Option<Self>, Error> {
+        let path = match config {
+            EpochArchiveConfig::Database { path } | EpochArchiveConfig::ColdFiles { path } => path,
+        };
+        let exists = path.try_exists().map_err(|source| Error::EpochArchiveIo {
+            path: path.clone(),
+            source,
+        })?;
+        if !exists {
+            return Ok(None);
+        }
+
+        let backend = match config {
+            EpochArchiveConfig::Database { path } => Backend::Database(DB::open_cf_readonly(
+                path,
+                cf_definitions::EPOCH_ARCHIVE_DB,
+            )?),
+            EpochArchiveConfig::ColdFiles { path } => {
+                Backend::ColdFiles(cold::ColdArchive::open_readonly(path))
+            }
+        };
+
+        Ok(Some(Self { backend }))
+    }
+
+    /// Whether the epoch is fully archived.
+    pub fn is_archived(&self, epoch_number: EpochNumber) -> Result<bool, Error> {
+        match &self.backend {
+            Backend::Database(db) => Ok(db.get::<ArchivedEpochsColumn>(&epoch_number)?.is_some()),
+            Backend::ColdFiles(cold) => cold.is_archived(epoch_number),
+        }
+    }
+
+    /// Move the content of a packed epoch to the archive.
+    ///
+    /// The epoch is only reported as archived once all of its content is
+    /// stored, so an interrupted archival can safely be started over.
+    pub(crate) fn archive(&self, epoch: &PackedEpoch) -> Result<(), Error> {
+        match &self.backend {
+            Backend::Database(db) => {
+                let mut batch = WriteBatch::default();
+                for (index, (certificate, proof)) in epoch.certificates.iter().enumerate() {
+                    let key = Key::new(epoch.epoch_number, CertificateIndex::new(index as u64));
+                    db.multi_insert_batch::<ArchivedCertificatesColumn>(
+                        [(&key, certificate)],
+                        &mut batch,
+                    )?;
+                    if let Some(proof) = proof {
+                        db.multi_insert_batch::<ArchivedProofsColumn>([(&key, proof)], &mut batch)?;
+                    }
+                }
+
+                let record = ArchivedEpoch {
+                    certificates: epoch.certificates.len() as u64,
+                    start_checkpoint: epoch.start_checkpoint.clone(),
+                    end_checkpoint: epoch.end_checkpoint.clone(),
+                };
+                db.multi_insert_batch::<ArchivedEpochsColumn>(
+                    [(&epoch.epoch_number, &record)],
+                    &mut batch,
+                )?;
+
+                Ok(db.write_batch(batch)?)
+            }
+            Backend::ColdFiles(cold) => cold.archive(epoch),
+        }
+    }
+
+    /// Compact the archive database, merging the freshly archived epochs into
+    /// its existing files. Nothing to do for a cold archive.
+    pub fn compact(&self) {
+        if let Backend::Database(db) = &self.backend {
+            let rocksdb = db.raw_rocksdb();
+            for cf in cf_definitions::EPOCH_ARCHIVE_DB {
+                if let Some(handle) = rocksdb.cf_handle(cf.name()) {
+                    rocksdb.compact_range_cf(&handle, None::<&[u8]>, None::<&[u8]>);
+                }
+            }
+        }
+    }
+
+    pub fn get_certificate(
+        &self,
+        epoch_number: EpochNumber,
+        index: CertificateIndex,
+    ) -> Result<Option<Certificate>, Error> {
+        match &self.backend {
+            Backend::Database(db) => {
+                Ok(db.get::<ArchivedCertificatesColumn>(&Key::new(epoch_number, index))?)
+            }
+            Backend::ColdFiles(cold) => cold.get_certificate(epoch_number, index),
+        }
+    }
+
+    pub fn get_proof(
+        &self,
+        epoch_number: EpochNumber,
+        index: CertificateIndex,
+    ) -> Result<Option<Proof>, Error> {
+        match &self.backend {
+            Backend::Database(db) => {
+                Ok(db.get::<ArchivedProofsColumn>(&Key::new(epoch_number, index))?)
+            }
+            Backend::ColdFiles(cold) => cold.get_proof(epoch_number, index),
+        }
+    }
+}
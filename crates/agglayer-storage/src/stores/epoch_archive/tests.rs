@@ -0,0 +1,293 @@
+use std::{collections::BTreeMap, num::NonZeroU64, path::Path, sync::Arc};
+
+use agglayer_config::{
+    storage::{
+        epoch_retention::{EpochArchiveConfig, EpochRetentionConfig},
+        StorageConfig,
+    },
+    Config,
+};
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
+use rstest::rstest;
+
+use super::*;
+use crate::{
+    backup::BackupClient,
+    columns::epochs::{
+        certificates::CertificatePerIndexProtoColumn, end_checkpoint::EndCheckpointColumn,
+        metadata::PerEpochMetadataColumn, proofs::ProofPerIndexColumn,
+        start_checkpoint::StartCheckpointColumn,
+    },
+    schema::Codec as _,
+    stores::{
+        epochs::EpochsStore, pending::PendingStore, per_epoch::PerEpochStore, state::StateStore,
+        EpochStoreReader as _, EpochStoreWriter as _,
+    },
+    tests::TempDBDir,
+    types::{PerEpochMetadataKey, PerEpochMetadataValue},
+};
+
+const CERTIFICATES_PER_EPOCH: u64 = 2;
+
+#[derive(Clone, Copy, Debug)]
+enum ArchiveKind {
+    Database,
+    ColdFiles,
+}
+
+fn archive_config(kind: ArchiveKind, root: &Path) -> EpochArchiveConfig {
+    let path = root.join("epochs-archive");
+    match kind {
+        ArchiveKind::Database => EpochArchiveConfig::Database { path },
+        ArchiveKind::ColdFiles => EpochArchiveConfig::ColdFiles { path },
+    }
+}
+
+fn epochs_store(
+    tmp: &TempDBDir,
+    retention: EpochRetentionConfig,
+) -> EpochsStore<PendingStore, StateStore> {
+    let mut config = Config::new(&tmp.path);
+    config.storage.epoch_retention = retention;
+    let config = Arc::new(config);
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(
+        StateStore::new_with_path(&config.storage.state_db_path, BackupClient::noop()).unwrap(),
+    );
+
+    EpochsStore::new(config, pending_store, state_store, BackupClient::noop()).unwrap()
+}
+
+fn certificate(epoch: u64, index: u64) -> Certificate {
+    Certificate::new_for_test(NetworkId::new(1), Height::new(epoch * 10 + index))
+}
+
+/// Write an epoch database holding `CERTIFICATES_PER_EPOCH` certificates and
+/// their proof.
+fn seed_epoch(storage: &StorageConfig, epoch: u64, packed: bool) {
+    let db =
+        PerEpochStore::<(), ()>::init_db(&storage.epoch_db_path(EpochNumber::new(epoch))).unwrap();
+
+    for index in 0..CERTIFICATES_PER_EPOCH {
+        let key = CertificateIndex::new(index);
+        db.put::<CertificatePerIndexProtoColumn>(&key, &certificate(epoch, index))
+            .unwrap();
+        db.put::<ProofPerIndexColumn>(&key, &Proof::dummy())
+            .unwrap();
+    }
+    db.put::<StartCheckpointColumn>(&NetworkId::new(1), &Height::new(epoch * 10))
+        .unwrap();
+    db.put::<EndCheckpointColumn>(&NetworkId::new(1), &Height::new(epoch * 10 + 1))
+        .unwrap();
+    db.put::<PerEpochMetadataColumn>(
+        &PerEpochMetadataKey::Packed,
+        &PerEpochMetadataValue::Packed(packed),
+    )
+    .unwrap();
+}
+
+fn epoch_exists(storage: &StorageConfig, epoch: u64) -> bool {
+    storage.epoch_db_path(EpochNumber::new(epoch)).exists()
+}
+
+#[rstest]
+fn archived_epochs_stay_readable(
+    #[values(ArchiveKind::Database, ArchiveKind::ColdFiles)] kind: ArchiveKind,
+) {
+    let tmp = TempDBDir::new();
+    let retention =
+        EpochRetentionConfig::new(NonZeroU64::new(2).unwrap(), archive_config(kind, &tmp.path));
+    let storage = StorageConfig::new_from_path(&tmp.path);
+    for epoch in 0..5 {
+        seed_epoch(&storage, epoch, true);
+    }
+    // The epoch currently being filled.
+    seed_epoch(&storage, 5, false);
+    let store = epochs_store(&tmp, retention);
+
+    let archived = store.apply_retention(EpochNumber::new(4)).unwrap();
+
+    assert_eq!(archived, [0, 1, 2].map(EpochNumber::new));
+    for epoch in 0..=2 {
+        assert!(!epoch_exists(&storage, epoch));
+    }
+    for epoch in 3..=5 {
+        assert!(epoch_exists(&storage, epoch));
+    }
+
+    for epoch in 0..=4 {
+        let epoch_number = EpochNumber::new(epoch);
+        for index in 0..CERTIFICATES_PER_EPOCH {
+            let index = CertificateIndex::new(index);
+            assert_eq!(
+                store.get_certificate(epoch_number, index).unwrap(),
+                Some(certificate(epoch, index.as_u64()))
+            );
+            assert_eq!(
+                store
+                    .get_proof(epoch_number, index)
+                    .unwrap()
+                    .map(|proof| proof.encode().unwrap()),
+                Some(Proof::dummy().encode().unwrap())
+            );
+        }
+    }
+
+    let missing = CertificateIndex::new(CERTIFICATES_PER_EPOCH);
+    assert_eq!(
+        store.get_certificate(EpochNumber::new(1), missing).unwrap(),
+        None
+    );
+    assert!(store
+        .get_proof(EpochNumber::new(1), missing)
+        .unwrap()
+        .is_none());
+
+    // Archived epochs can't be written to anymore.
+    assert!(matches!(
+        store.open(EpochNumber::new(1)),
+        Err(Error::EpochArchived(epoch)) if epoch == EpochNumber::new(1)
+    ));
+
+    // Nothing is left to archive.
+    assert!(store
+        .apply_retention(EpochNumber::new(4))
+        .unwrap()
+        .is_empty());
+}
+
+#[rstest]
+fn unpacked_epochs_and_the_latest_settled_epoch_are_kept(
+    #[values(ArchiveKind::Database, ArchiveKind::ColdFiles)] kind: ArchiveKind,
+) {
+    let tmp = TempDBDir::new();
+    let retention = EpochRetentionConfig::new(NonZeroU64::MIN, archive_config(kind, &tmp.path));
+    let storage = StorageConfig::new_from_path(&tmp.path);
+    seed_epoch(&storage, 0, true);
+    seed_epoch(&storage, 1, false);
+    seed_epoch(&storage, 2, true);
+    seed_epoch(&storage, 3, true);
+    let store = epochs_store(&tmp, retention);
+
+    let archived = store.apply_retention(EpochNumber::new(3)).unwrap();
+
+    assert_eq!(archived, [0, 2].map(EpochNumber::new));
+    assert!(epoch_exists(&storage, 1));
+    assert!(epoch_exists(&storage, 3));
+    assert_eq!(
+        store
+            .get_certificate(EpochNumber::new(1), CertificateIndex::ZERO)
+            .unwrap(),
+        Some(certificate(1, 0))
+    );
+}
+
+#[rstest]
+fn archived_epochs_are_readable_read_only(
+    #[values(ArchiveKind::Database, ArchiveKind::ColdFiles)] kind: ArchiveKind,
+) {
+    let tmp = TempDBDir::new();
+    let retention = EpochRetentionConfig::new(NonZeroU64::MIN, archive_config(kind, &tmp.path));
+    let storage = StorageConfig::new_from_path(&tmp.path);
+    for epoch in 0..3 {
+        seed_epoch(&storage, epoch, true);
+    }
+    let store = epochs_store(&tmp, retention.clone());
+    assert_eq!(
+        store.apply_retention(EpochNumber::new(2)).unwrap(),
+        [0, 1].map(EpochNumber::new)
+    );
+
+    let mut config = Config::new(&tmp.path);
+    config.storage.epoch_retention = retention;
+    let readonly = EpochsStore::new_readonly(
+        Arc::new(config),
+        Arc::new(PendingStore::new_readonly_with_path(&storage.pending_db_path).unwrap()),
+        Arc::new(StateStore::new_readonly_with_path(&storage.state_db_path).unwrap()),
+    )
+    .unwrap();
+
+    for epoch in 0..3 {
+        assert_eq!(
+            readonly
+                .get_certificate(EpochNumber::new(epoch), CertificateIndex::ZERO)
+                .unwrap(),
+            Some(certificate(epoch, 0))
+        );
+    }
+}
+
+#[test]
+fn retention_is_disabled_by_default() {
+    let tmp = TempDBDir::new();
+    let storage = StorageConfig::new_from_path(&tmp.path);
+    seed_epoch(&storage, 0, true);
+    let store = epochs_store(&tmp, EpochRetentionConfig::Disabled);
+
+    assert!(store
+        .apply_retention(EpochNumber::new(100))
+        .unwrap()
+        .is_empty());
+    assert!(epoch_exists(&storage, 0));
+}
+
+fn packed_epoch(epoch: u64, certificates: Vec<Certificate>) -> PackedEpoch {
+    PackedEpoch {
+        epoch_number: EpochNumber::new(epoch),
+        certificates: certificates
+            .into_iter()
+            .map(|certificate| (certificate, Some(Proof::dummy())))
+            .collect(),
+        start_checkpoint: BTreeMap::new(),
+        end_checkpoint: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn cold_archive_stores_identical_objects_once() {
+    let tmp = TempDBDir::new();
+    let archive = EpochArchive::open(&archive_config(ArchiveKind::ColdFiles, &tmp.path)).unwrap();
+    let Backend::ColdFiles(cold) = &archive.backend else {
+        unreachable!()
+    };
+
+    archive
+        .archive(&packed_epoch(0, vec![certificate(0, 0)]))
+        .unwrap();
+    archive
+        .archive(&packed_epoch(1, vec![certificate(0, 0), certificate(1, 1)]))
+        .unwrap();
+
+    // Two distinct certificates and a single proof.
+    assert_eq!(cold.object_paths().len(), 3);
+    assert!(archive.is_archived(EpochNumber::new(1)).unwrap());
+    assert!(!archive.is_archived(EpochNumber::new(2)).unwrap());
+    assert_eq!(
+        archive
+            .get_certificate(EpochNumber::new(1), CertificateIndex::new(1))
+            .unwrap(),
+        Some(certificate(1, 1))
+    );
+}
+
+#[test]
+fn cold_archive_detects_corrupted_objects() {
+    let tmp = TempDBDir::new();
+    let archive = EpochArchive::open(&archive_config(ArchiveKind::ColdFiles, &tmp.path)).unwrap();
+    let Backend::ColdFiles(cold) = &archive.backend else {
+        unreachable!()
+    };
+
+    archive
+        .archive(&packed_epoch(0, vec![certificate(0, 0)]))
+        .unwrap();
+    for path in cold.object_paths() {
+        std::fs::write(path, b"corrupted").unwrap();
+    }
+
+    assert!(matches!(
+        archive.get_certificate(EpochNumber::ZERO, CertificateIndex::ZERO),
+        Err(Error::CorruptedEpochArchive(_))
+    ));
+}
@@ -1,11 +1,15 @@
 use std::{collections::BTreeMap, sync::Arc};
 
+use agglayer_config::storage::epoch_retention::EpochRetentionConfig;
 use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId};
+use tracing::{info, warn};
 
 use super::{
-    interfaces::reader::PerEpochReader, per_epoch::PerEpochStore, EpochStoreReader,
-    EpochStoreWriter, MetadataWriter, PendingCertificateReader, PendingCertificateWriter,
-    StateReader, StateWriter,
+    epoch_archive::{EpochArchive, PackedEpoch},
+    interfaces::reader::PerEpochReader,
+    per_epoch::PerEpochStore,
+    EpochStoreReader, EpochStoreWriter, MetadataWriter, PendingCertificateReader,
+    PendingCertificateWriter, StateReader, StateWriter,
 };
 use crate::{backup::BackupClient, error::Error};
 
@@ -14,6 +18,7 @@ pub struct EpochsStore<PendingStore, StateStore> {
     pending_store: Arc<PendingStore>,
     state_store: Arc<StateStore>,
     backup_client: BackupClient,
+    archive: Option<EpochArchive>,
 }
 
 impl<PendingStore, StateStore> EpochsStore<PendingStore, StateStore> {
@@ -23,13 +28,176 @@ impl<PendingStore, StateStore> EpochsStore<PendingStore, StateStore> {
         state_store: Arc<StateStore>,
         backup_client: BackupClient,
     ) -> Result<Self, Error> {
+        let archive = match &config.storage.epoch_retention {
+            EpochRetentionConfig::Disabled => None,
+            EpochRetentionConfig::Enabled { archive, .. } => Some(EpochArchive::open(archive)?),
+        };
+
         Ok(Self {
             config,
             pending_store,
             state_store,
             backup_client,
+            archive,
+        })
+    }
+
+    /// Create an epochs store opening the archive read-only, to read the
+    /// epochs while the node is running. It must not be written to.
+    pub fn new_readonly(
+        config: Arc<agglayer_config::Config>,
+        pending_store: Arc<PendingStore>,
+        state_store: Arc<StateStore>,
+    ) -> Result<Self, Error> {
+        let archive = match &config.storage.epoch_retention {
+            EpochRetentionConfig::Disabled => None,
+            EpochRetentionConfig::Enabled { archive, .. } => EpochArchive::open_readonly(archive)?,
+        };
+
+        Ok(Self {
+            config,
+            pending_store,
+            state_store,
+            backup_client: BackupClient::noop(),
+            archive,
         })
     }
+
+    /// The archive holding the epoch, if the epoch is archived.
+    fn archive_of(&self, epoch_number: EpochNumber) -> Result<Option<&EpochArchive>, Error> {
+        match &self.archive {
+            Some(archive) if archive.is_archived(epoch_number)? => Ok(Some(archive)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Read from the epoch database, or from the archive once the epoch is
+    /// archived.
+    fn read_epoch<T>(
+        &self,
+        epoch_number: EpochNumber,
+        from_store: impl FnOnce(PerEpochStore<PendingStore, StateStore>) -> Result<T, Error>,
+        from_archive: impl FnOnce(&EpochArchive) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        if let Some(archive) = self.archive_of(epoch_number)? {
+            return from_archive(archive);
+        }
+
+        // Use readonly access to prevent concurrency issues when multiple processes
+        // are accessing the database
+        match PerEpochStore::try_open_readonly(
+            self.config.clone(),
+            epoch_number,
+            self.pending_store.clone(),
+            self.state_store.clone(),
+        ) {
+            Ok(per_epoch_store) => from_store(per_epoch_store),
+            // The epoch database may have been removed once archived since the check above.
+            Err(error) => match self.archive_of(epoch_number)? {
+                Some(archive) => from_archive(archive),
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Move the packed epochs falling out of the retention window to the
+    /// archive, and remove their database.
+    ///
+    /// The `keep_epochs` most recent settled epochs are kept, as well as the
+    /// epochs that aren't packed. Returns the archived epochs.
+    pub fn apply_retention(
+        &self,
+        latest_settled_epoch: EpochNumber,
+    ) -> Result<Vec<EpochNumber>, Error>
+    where
+        PendingStore: Send + Sync,
+        StateStore: Send + Sync,
+    {
+        let (EpochRetentionConfig::Enabled { keep_epochs, .. }, Some(archive)) =
+            (&self.config.storage.epoch_retention, &self.archive)
+        else {
+            return Ok(Vec::new());
+        };
+        let oldest_kept = latest_settled_epoch
+            .as_u64()
+            .saturating_sub(keep_epochs.get() - 1);
+
+        let mut archived = Vec::new();
+        for epoch_number in self.epochs_on_disk()? {
+            if epoch_number.as_u64() >= oldest_kept {
+                continue;
+            }
+
+            let per_epoch_store = match PerEpochStore::try_open_readonly(
+                self.config.clone(),
+                epoch_number,
+                self.pending_store.clone(),
+                self.state_store.clone(),
+            ) {
+                Ok(per_epoch_store) => per_epoch_store,
+                Err(error) => {
+                    warn!(%epoch_number, "Unable to open the epoch for archival: {error}");
+                    continue;
+                }
+            };
+            let Some(epoch) = PackedEpoch::read(&per_epoch_store)? else {
+                warn!(%epoch_number, "Epoch isn't packed, keeping its database");
+                continue;
+            };
+            drop(per_epoch_store);
+
+            archive.archive(&epoch)?;
+            let path = self.config.storage.epoch_db_path(epoch_number);
+            std::fs::remove_dir_all(&path)
+                .map_err(|source| Error::EpochArchiveIo { path, source })?;
+
+            info!(
+                %epoch_number,
+                certificates = epoch.certificates.len(),
+                "Epoch archived"
+            );
+            archived.push(epoch_number);
+        }
+
+        if !archived.is_empty() {
+            archive.compact();
+        }
+
+        Ok(archived)
+    }
+
+    /// Epochs having a database, in ascending order.
+    fn epochs_on_disk(&self) -> Result<Vec<EpochNumber>, Error> {
+        let path = &self.config.storage.epochs_db_path;
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(Error::EpochArchiveIo {
+                    path: path.clone(),
+                    source,
+                })
+            }
+        };
+
+        let mut epochs = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| Error::EpochArchiveIo {
+                path: path.clone(),
+                source,
+            })?;
+            if let Some(epoch) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                epochs.push(EpochNumber::new(epoch));
+            }
+        }
+        epochs.sort();
+
+        Ok(epochs)
+    }
 }
 
 impl<PendingStore, StateStore> EpochStoreWriter for EpochsStore<PendingStore, StateStore>
@@ -42,6 +210,10 @@ where
         &self,
         epoch_number: EpochNumber,
     ) -> Result<PerEpochStore<PendingStore, StateStore>, Error> {
+        if self.archive_of(epoch_number)?.is_some() {
+            return Err(Error::EpochArchived(epoch_number));
+        }
+
         PerEpochStore::try_open(
             self.config.clone(),
             epoch_number,
@@ -57,6 +229,10 @@ where
         epoch_number: EpochNumber,
         start_checkpoint: BTreeMap<NetworkId, Height>,
     ) -> Result<Self::PerEpochStore, Error> {
+        if self.archive_of(epoch_number)?.is_some() {
+            return Err(Error::EpochArchived(epoch_number));
+        }
+
         PerEpochStore::try_open(
             self.config.clone(),
             epoch_number,
@@ -78,15 +254,11 @@ where
         epoch_number: EpochNumber,
         index: CertificateIndex,
     ) -> Result<Option<Certificate>, Error> {
-        // Use readonly access to prevent concurrency issues when multiple processes
-        // are accessing the database
-        let per_epoch_store = PerEpochStore::try_open_readonly(
-            self.config.clone(),
+        self.read_epoch(
             epoch_number,
-            self.pending_store.clone(),
-            self.state_store.clone(),
-        )?;
-        per_epoch_store.get_certificate_at_index(index)
+            |per_epoch_store| per_epoch_store.get_certificate_at_index(index),
+            |archive| archive.get_certificate(epoch_number, index),
+        )
     }
 
     fn get_proof(
@@ -94,14 +266,10 @@ where
         epoch_number: EpochNumber,
         index: CertificateIndex,
     ) -> Result<Option<agglayer_types::Proof>, Error> {
-        // Use readonly access to prevent concurrency issues when multiple processes
-        // are accessing the database
-        let per_epoch_store = PerEpochStore::try_open_readonly(
-            self.config.clone(),
+        self.read_epoch(
             epoch_number,
-            self.pending_store.clone(),
-            self.state_store.clone(),
-        )?;
-        per_epoch_store.get_proof_at_index(index)
+            |per_epoch_store| per_epoch_store.get_proof_at_index(index),
+            |archive| archive.get_proof(epoch_number, index),
+        )
     }
 }
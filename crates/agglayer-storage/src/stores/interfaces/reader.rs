@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, ops::Range};
 
 use agglayer_types::{
-    Certificate, CertificateHeader, CertificateId, CertificateIndex, EpochNumber, Height,
-    LocalNetworkStateData, NetworkId, Proof, SettlementJobId,
+    Certificate, CertificateHeader, CertificateId, CertificateIndex, Digest, EpochNumber, Height,
+    ListDirection, LocalNetworkStateData, NetworkId, Proof, SettlementJobId,
 };
 
 use crate::{
@@ -14,7 +14,9 @@ use crate::{
     error::Error,
 };
 
+pub mod admin_audit_reader;
 pub mod network_info_reader;
+pub mod rate_limiter_state_reader;
 pub mod settlement_reader;
 
 pub trait DebugReader: Send + Sync {
@@ -44,6 +46,18 @@ pub trait PendingCertificateReader: Send + Sync {
         network_id: &NetworkId,
     ) -> Result<Option<(CertificateId, Height)>, Error>;
 
+    /// Count the certificates of a network waiting in the pending queue.
+    fn count_pending_certificates_for_network(&self, network_id: NetworkId)
+        -> Result<usize, Error>;
+
+    /// List the certificates of a network waiting in the pending queue from
+    /// `from_height` onwards, by increasing height.
+    fn list_pending_certificates_for_network(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+    ) -> Result<Vec<Certificate>, Error>;
+
     /// Scan the latest pending certificate pointer of every network.
     ///
     /// Entries that fail to decode are skipped.
@@ -106,6 +120,17 @@ pub trait StateReader: Send + Sync {
         height: Height,
     ) -> Result<Option<CertificateHeader>, Error>;
 
+    /// List at most `limit` settled certificate headers of a network, starting
+    /// at `from_height` (or the closest settled height in `direction`) and
+    /// walking the heights in `direction`.
+    fn list_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        direction: ListDirection,
+    ) -> Result<Vec<CertificateHeader>, Error>;
+
     fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error>;
     fn get_latest_settled_certificate_per_network(
         &self,
@@ -117,6 +142,30 @@ pub trait StateReader: Send + Sync {
         &self,
         network_id: NetworkId,
     ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+    /// Get the local network state once the certificate at `height` got
+    /// settled, or `None` if no snapshot of it was recorded.
+    fn read_local_network_state_at(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+    /// Whether a snapshot of the local network state got recorded for the
+    /// certificate settled at `height`.
+    fn has_network_state_snapshot(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<bool, Error>;
+
+    /// Get the leaves of the local exit tree of a network at the indices in
+    /// `leaves`, or `None` if any of them is not stored.
+    fn read_local_exit_tree_leaves(
+        &self,
+        network_id: NetworkId,
+        leaves: Range<u32>,
+    ) -> Result<Option<Vec<Digest>>, Error>;
 }
 
 pub trait PerEpochReader: Send + Sync {
@@ -0,0 +1,18 @@
+use std::time::SystemTime;
+
+use agglayer_types::{AdminAuditEvent, AdminAuditEventId};
+
+use crate::error::Error;
+
+/// Read-only access to the admin audit log.
+pub trait AdminAuditReader: Send + Sync {
+    /// Returns the first `limit` audit events recorded in `[from, to)`,
+    /// oldest first, only those of `method` when given.
+    fn list_admin_audit_events(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+        method: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<(AdminAuditEventId, AdminAuditEvent)>, Error>;
+}
@@ -0,0 +1,18 @@
+use std::time::SystemTime;
+
+use agglayer_types::NetworkId;
+
+use crate::error::Error;
+
+/// Read-only access to the persisted state of the rate limiters.
+pub trait RateLimiterStateReader: Send + Sync {
+    /// Returns the events recorded by the rate limiter `component` of
+    /// `network_id`, as last persisted.
+    ///
+    /// Returns no event if nothing was persisted for that component.
+    fn get_rate_limiter_events(
+        &self,
+        network_id: NetworkId,
+        component: &str,
+    ) -> Result<Vec<SystemTime>, Error>;
+}
@@ -1,6 +1,6 @@
 use agglayer_types::{
     Address, CertificateId, Nonce, SettlementAttempt, SettlementAttemptResult, SettlementJob,
-    SettlementJobId, SettlementJobResult,
+    SettlementJobCancellation, SettlementJobId, SettlementJobResult,
 };
 
 use crate::error::Error;
@@ -32,6 +32,13 @@ pub trait SettlementReader: Send + Sync {
         settlement_job_id: &SettlementJobId,
     ) -> Result<Option<SettlementJobResult>, Error>;
 
+    /// Returns the cancellation of `settlement_job_id` by a network rollback,
+    /// if present.
+    fn get_settlement_job_cancellation(
+        &self,
+        settlement_job_id: &SettlementJobId,
+    ) -> Result<Option<SettlementJobCancellation>, Error>;
+
     /// Returns all settlement attempts recorded for `settlement_job_id`.
     fn list_settlement_attempts(
         &self,
@@ -45,5 +52,9 @@ pub trait SettlementReader: Send + Sync {
     ) -> Result<Vec<(u64, SettlementAttemptResult)>, Error>;
 
     /// Returns the highest settlement attempt nonce recorded for `wallet`.
+    ///
+    /// Attempts that were never broadcast because their pre-flight simulation
+    /// reverted are ignored, whether or not their job completed: their nonce
+    /// is still free on L1, and their job no longer drives it.
     fn max_settlement_nonce_for_wallet(&self, wallet: Address) -> Result<Option<Nonce>, Error>;
 }
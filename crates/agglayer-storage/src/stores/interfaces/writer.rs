@@ -2,11 +2,17 @@ use std::collections::BTreeMap;
 
 use agglayer_types::{
     primitives::Digest, Certificate, CertificateId, CertificateIndex, CertificateStatus,
-    EpochNumber, ExecutionMode, Height, LocalNetworkStateData, NetworkId, Proof, SettlementTxHash,
+    EpochNumber, ExecutionMode, Height, LocalNetworkStateData, NetworkId, Proof, SettlementJobId,
+    SettlementTxHash,
 };
 
-use crate::{error::Error, stores::PerEpochReader};
+use crate::{
+    columns::latest_settled_certificate_per_network::SettledCertificate, error::Error,
+    stores::PerEpochReader,
+};
 
+pub mod admin_audit_writer;
+pub mod rate_limiter_state_writer;
 pub mod settlement_writer;
 
 pub trait DebugWriter: Send + Sync {
@@ -109,6 +115,36 @@ pub trait StateWriter: Send + Sync {
         new_state: &LocalNetworkStateData,
         new_leaves: &[Digest],
     ) -> Result<(), Error>;
+
+    /// Records `state` as the snapshot of the network state at the settled
+    /// `height`, for heights settled before the snapshots got recorded.
+    ///
+    /// The current state of the network is left untouched: only the nodes
+    /// of the trees of `state` are written, along with the snapshot.
+    fn write_network_state_snapshot(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+        state: &LocalNetworkStateData,
+    ) -> Result<(), Error>;
+
+    /// Rolls a network back to its settled certificate `settled`, in one
+    /// atomic batch.
+    ///
+    /// The trees are replaced by `state`, whose exit tree holds `leaves`, the
+    /// settled certificates above `settled` leave the per-network index, and
+    /// the headers and settlement job links of `removed_certificates` are
+    /// deleted. The `cancelled_settlement_jobs` are recorded as cancelled, so
+    /// that they aren't resumed.
+    fn rollback_network_state(
+        &self,
+        network_id: &NetworkId,
+        settled: &SettledCertificate,
+        state: &LocalNetworkStateData,
+        leaves: &[Digest],
+        removed_certificates: &[CertificateId],
+        cancelled_settlement_jobs: &[SettlementJobId],
+    ) -> Result<(), Error>;
 }
 
 pub trait PendingCertificateWriter: Send + Sync {
@@ -146,4 +182,18 @@ pub trait PendingCertificateWriter: Send + Sync {
         height: &Height,
         certificate_id: &CertificateId,
     ) -> Result<(), Error>;
+
+    /// Rolls the pending certificates of a network back to its settled
+    /// certificate `certificate_id` at `height`, in one atomic batch.
+    ///
+    /// The pending certificates above `height` and the proofs of
+    /// `removed_proofs` are deleted, and the latest pending and proven
+    /// certificates of the network point to the settled one.
+    fn rollback_pending_certificates(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+        certificate_id: &CertificateId,
+        removed_proofs: &[CertificateId],
+    ) -> Result<(), Error>;
 }
@@ -0,0 +1,15 @@
+use agglayer_types::{AdminAuditEvent, AdminAuditEventId};
+
+use crate::error::Error;
+
+/// Write access to the admin audit log.
+///
+/// The log is append-only: recorded events are never updated nor removed.
+pub trait AdminAuditWriter: Send + Sync {
+    /// Appends `event` to the audit log, and returns its identifier.
+    ///
+    /// Identifiers increase in append order, so that events recorded in the
+    /// same millisecond are listed in the order they were appended.
+    fn append_admin_audit_event(&self, event: &AdminAuditEvent)
+        -> Result<AdminAuditEventId, Error>;
+}
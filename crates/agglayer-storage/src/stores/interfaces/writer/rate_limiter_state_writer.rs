@@ -0,0 +1,17 @@
+use std::time::SystemTime;
+
+use agglayer_types::NetworkId;
+
+use crate::error::Error;
+
+/// Write access to the persisted state of the rate limiters.
+pub trait RateLimiterStateWriter: Send + Sync {
+    /// Replaces the events persisted for the rate limiter `component` of
+    /// `network_id`.
+    fn set_rate_limiter_events(
+        &self,
+        network_id: NetworkId,
+        component: &str,
+        events: &[SystemTime],
+    ) -> Result<(), Error>;
+}
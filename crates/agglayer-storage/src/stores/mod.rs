@@ -2,10 +2,14 @@ mod interfaces;
 
 pub use interfaces::{
     reader::{
-        network_info_reader::NetworkInfoReader, settlement_reader::SettlementReader, DebugReader,
-        EpochStoreReader, MetadataReader, PendingCertificateReader, PerEpochReader, StateReader,
+        admin_audit_reader::AdminAuditReader, network_info_reader::NetworkInfoReader,
+        rate_limiter_state_reader::RateLimiterStateReader, settlement_reader::SettlementReader,
+        DebugReader, EpochStoreReader, MetadataReader, PendingCertificateReader, PerEpochReader,
+        StateReader,
     },
     writer::{
+        admin_audit_writer::AdminAuditWriter,
+        rate_limiter_state_writer::RateLimiterStateWriter,
         settlement_writer::{EditEvenIfCompleted, SettlementWriter},
         DebugWriter, EpochStoreWriter, MetadataWriter, PendingCertificateWriter, PerEpochWriter,
         StateWriter, UpdateEvenIfAlreadyPresent, UpdateStatusToCandidate,
@@ -13,6 +17,7 @@ pub use interfaces::{
 };
 
 pub mod debug;
+pub mod epoch_archive;
 pub mod epochs;
 pub mod pending;
 pub mod per_epoch;
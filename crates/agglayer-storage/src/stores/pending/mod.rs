@@ -1,7 +1,7 @@
 use std::{path::Path, sync::Arc};
 
 use agglayer_types::{Certificate, CertificateId, Height, NetworkId, Proof};
-use rocksdb::{Direction, ReadOptions};
+use rocksdb::{Direction, ReadOptions, WriteBatch};
 
 use super::{PendingCertificateReader, PendingCertificateWriter};
 use crate::{
@@ -49,6 +49,13 @@ impl PendingStore {
         Ok(Self::new(Arc::new(Self::init_db(path)?)))
     }
 
+    /// Open the pending database read-only, so that it can be inspected while
+    /// the node is running.
+    pub fn new_readonly_with_path(path: &Path) -> Result<Self, DBError> {
+        let db = DB::open_cf_readonly(path, cf_definitions::PENDING_DB)?;
+        Ok(Self::new(Arc::new(db)))
+    }
+
     fn decode_readable_proof(certificate_id: CertificateId, bytes: &[u8]) -> Result<Proof, Error> {
         Proof::decode(bytes).map_err(|source| Error::UnreadableProof {
             id: certificate_id,
@@ -116,6 +123,48 @@ impl PendingCertificateWriter for PendingStore {
         )?)
     }
 
+    fn rollback_pending_certificates(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+        certificate_id: &CertificateId,
+        removed_proofs: &[CertificateId],
+    ) -> Result<(), Error> {
+        let mut removed_pending = Vec::new();
+        for key in self.db.keys_from::<PendingQueueProtoColumn>(
+            &PendingQueueKey(*network_id, height.next()),
+            Direction::Forward,
+        )? {
+            let key = key?;
+            // The keys are ordered by network first, stop at the boundary.
+            if key.0 != *network_id {
+                break;
+            }
+            removed_pending.push(key);
+        }
+
+        let mut batch = WriteBatch::default();
+        self.db
+            .multi_delete_batch::<PendingQueueProtoColumn>(&removed_pending, &mut batch)?;
+        self.db
+            .multi_delete_batch::<ProofPerCertificateColumn>(removed_proofs, &mut batch)?;
+        self.db
+            .multi_insert_batch::<LatestPendingCertificatePerNetworkColumn>(
+                [(network_id, &PendingCertificate(*certificate_id, *height))],
+                &mut batch,
+            )?;
+        self.db
+            .multi_insert_batch::<LatestProvenCertificatePerNetworkColumn>(
+                [(
+                    network_id,
+                    &ProvenCertificate(*certificate_id, *network_id, *height),
+                )],
+                &mut batch,
+            )?;
+
+        Ok(self.db.write_batch(batch)?)
+    }
+
     fn insert_pending_certificate(
         &self,
         network_id: NetworkId,
@@ -184,6 +233,46 @@ impl PendingCertificateReader for PendingStore {
             .map(|v| v.map(|PendingCertificate(id, height)| (id, height)))?)
     }
 
+    fn count_pending_certificates_for_network(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<usize, Error> {
+        let mut count = 0;
+        for key in self.db.keys_from::<PendingQueueProtoColumn>(
+            &PendingQueueKey(network_id, Height::ZERO),
+            Direction::Forward,
+        )? {
+            // The keys are ordered by network first, stop at the boundary.
+            if key?.0 != network_id {
+                break;
+            }
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    fn list_pending_certificates_for_network(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+    ) -> Result<Vec<Certificate>, Error> {
+        let mut certificates = Vec::new();
+        for entry in self.db.iter_from::<PendingQueueProtoColumn>(
+            &PendingQueueKey(network_id, from_height),
+            Direction::Forward,
+        )? {
+            let (key, certificate) = entry?;
+            // The keys are ordered by network first, stop at the boundary.
+            if key.0 != network_id {
+                break;
+            }
+            certificates.push(certificate);
+        }
+
+        Ok(certificates)
+    }
+
     fn get_current_pending_heights(&self) -> Result<Vec<(NetworkId, PendingCertificate)>, Error> {
         Ok(self
             .db
@@ -253,3 +253,156 @@ fn get_current_pending_heights_returns_all_networks() {
         PendingCertificate(certificate_15.hash(), Height::new(3))
     );
 }
+
+#[test]
+fn count_pending_certificates_for_network_stops_at_network_boundary() {
+    let (_tmp, store) = store();
+    let network_1 = NetworkId::new(1);
+    let network_2 = NetworkId::new(2);
+
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(network_1)
+            .unwrap(),
+        0
+    );
+
+    for height in [Height::ZERO, Height::new(1), Height::new(4)] {
+        store
+            .insert_pending_certificate(
+                network_1,
+                height,
+                &Certificate::new_for_test(network_1, height),
+            )
+            .unwrap();
+    }
+    store
+        .insert_pending_certificate(
+            network_2,
+            Height::ZERO,
+            &Certificate::new_for_test(network_2, Height::ZERO),
+        )
+        .unwrap();
+
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(network_1)
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(network_2)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(NetworkId::new(3))
+            .unwrap(),
+        0
+    );
+
+    store
+        .remove_pending_certificate(network_1, Height::ZERO)
+        .unwrap();
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(network_1)
+            .unwrap(),
+        2
+    );
+}
+
+#[test]
+fn list_pending_certificates_for_network_stops_at_network_boundary() {
+    let (_tmp, store) = store();
+    let network_1 = NetworkId::new(1);
+    let network_2 = NetworkId::new(2);
+
+    let certificates = [Height::ZERO, Height::new(1), Height::new(4)]
+        .map(|height| Certificate::new_for_test(network_1, height));
+    for certificate in &certificates {
+        store
+            .insert_pending_certificate(network_1, certificate.height, certificate)
+            .unwrap();
+    }
+    store
+        .insert_pending_certificate(
+            network_2,
+            Height::ZERO,
+            &Certificate::new_for_test(network_2, Height::ZERO),
+        )
+        .unwrap();
+
+    let listed = store
+        .list_pending_certificates_for_network(network_1, Height::new(1))
+        .unwrap();
+
+    assert_eq!(
+        listed.iter().map(Certificate::hash).collect::<Vec<_>>(),
+        [certificates[1].hash(), certificates[2].hash()]
+    );
+}
+
+#[test]
+fn rollback_pending_certificates_resets_the_network_to_its_settled_certificate() {
+    let (_tmp, store) = store();
+    let network_1 = NetworkId::new(1);
+    let network_2 = NetworkId::new(2);
+
+    let certificates = (0..4)
+        .map(|height| Certificate::new_for_test(network_1, Height::new(height)))
+        .collect::<Vec<_>>();
+    for certificate in &certificates {
+        store
+            .insert_pending_certificate(network_1, certificate.height, certificate)
+            .unwrap();
+        store
+            .insert_generated_proof(&certificate.hash(), &Proof::dummy())
+            .unwrap();
+    }
+    let other_network = Certificate::new_for_test(network_2, Height::new(3));
+    store
+        .insert_pending_certificate(network_2, Height::new(3), &other_network)
+        .unwrap();
+
+    let removed_proofs = [certificates[2].hash(), certificates[3].hash()];
+    store
+        .rollback_pending_certificates(
+            &network_1,
+            &Height::new(1),
+            &certificates[1].hash(),
+            &removed_proofs,
+        )
+        .unwrap();
+
+    assert_eq!(
+        store
+            .count_pending_certificates_for_network(network_1)
+            .unwrap(),
+        2
+    );
+    assert!(store
+        .get_certificate(network_2, Height::new(3))
+        .unwrap()
+        .is_some());
+    for certificate in &certificates[..2] {
+        assert!(store.get_proof(certificate.hash()).unwrap().is_some());
+    }
+    for certificate_id in removed_proofs {
+        assert!(store.get_proof(certificate_id).unwrap().is_none());
+    }
+    assert_eq!(
+        store
+            .get_latest_pending_certificate_for_network(&network_1)
+            .unwrap(),
+        Some((certificates[1].hash(), Height::new(1)))
+    );
+    assert_eq!(
+        store
+            .get_latest_proven_certificate_per_network(&network_1)
+            .unwrap(),
+        Some((network_1, Height::new(1), certificates[1].hash()))
+    );
+}
@@ -0,0 +1,81 @@
+use std::time::SystemTime;
+
+use agglayer_types::{AdminAuditEvent, AdminAuditEventId};
+use rocksdb::{Direction, ReadOptions};
+
+use super::StateStore;
+use crate::{
+    columns::admin_audit_events::AdminAuditEventsColumn,
+    error::Error,
+    stores::{AdminAuditReader, AdminAuditWriter},
+    types::generated::agglayer::storage::v0,
+};
+
+impl AdminAuditReader for StateStore {
+    fn list_admin_audit_events(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+        method: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<(AdminAuditEventId, AdminAuditEvent)>, Error> {
+        // Event ids sort by recording time, at millisecond precision.
+        let end = AdminAuditEventId::first_at(to);
+        let mut events = Vec::new();
+        for entry in self.db.iter_from::<AdminAuditEventsColumn>(
+            &AdminAuditEventId::first_at(from),
+            Direction::Forward,
+        )? {
+            if events.len() >= limit {
+                break;
+            }
+
+            let (event_id, event) = entry?;
+            if event_id >= end {
+                break;
+            }
+            if method.is_some_and(|method| event.method != method) {
+                continue;
+            }
+            events.push((event_id, AdminAuditEvent::try_from(event)?));
+        }
+
+        Ok(events)
+    }
+}
+
+impl AdminAuditWriter for StateStore {
+    fn append_admin_audit_event(
+        &self,
+        event: &AdminAuditEvent,
+    ) -> Result<AdminAuditEventId, Error> {
+        let _admin_audit_write_lock = self
+            .admin_audit_write_lock
+            .lock()
+            .map_err(|_| Error::Unexpected("Admin audit write lock is poisoned".to_string()))?;
+
+        // Keep ids increasing in append order, even for events recorded in
+        // the same millisecond or while the clock steps back.
+        let mut event_id = AdminAuditEventId::new(event.recorded_at);
+        let latest = self
+            .db
+            .iter_with_direction::<AdminAuditEventsColumn>(
+                ReadOptions::default(),
+                Direction::Reverse,
+            )?
+            .next()
+            .transpose()?;
+        if let Some((latest_id, _)) = latest {
+            if event_id <= latest_id {
+                event_id = latest_id.successor().ok_or_else(|| {
+                    Error::Unexpected(format!("No admin audit event id after {latest_id}"))
+                })?;
+            }
+        }
+
+        self.db
+            .put::<AdminAuditEventsColumn>(&event_id, &v0::AdminAuditEvent::from(event))?;
+
+        Ok(event_id)
+    }
+}
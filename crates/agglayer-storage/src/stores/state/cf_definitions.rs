@@ -1,5 +1,6 @@
 use crate::{
     columns::{
+        admin_audit_events::AdminAuditEventsColumn,
         balance_tree_per_network::BalanceTreePerNetworkColumn,
         certificate_header::CertificateHeaderColumn,
         certificate_id_per_settlement_job_id::CertificateIdPerSettlementJobIdColumn,
@@ -7,10 +8,13 @@ use crate::{
         disabled_networks::DisabledNetworksColumn,
         latest_settled_certificate_per_network::LatestSettledCertificatePerNetworkColumn,
         local_exit_tree_per_network::LocalExitTreePerNetworkColumn, metadata::MetadataColumn,
-        network_info::NetworkInfoColumn, nullifier_tree_per_network::NullifierTreePerNetworkColumn,
+        network_info::NetworkInfoColumn, network_state_snapshots::NetworkStateSnapshotsColumn,
+        nullifier_tree_per_network::NullifierTreePerNetworkColumn,
+        rate_limiter_state::RateLimiterStateColumn,
         settlement_attempt_per_wallet::SettlementAttemptPerWalletColumn,
         settlement_attempt_results::SettlementAttemptResultsColumn,
         settlement_attempts::SettlementAttemptsColumn,
+        settlement_job_cancellations::SettlementJobCancellationsColumn,
         settlement_job_id_per_certificate_id::SettlementJobIdPerCertificateIdColumn,
         settlement_job_results::SettlementJobResultsColumn, settlement_jobs::SettlementJobsColumn,
     },
@@ -52,6 +56,22 @@ pub const STATE_DB_V2_ADDED_CFS: &[ColumnDescriptor] = &[
     ColumnDescriptor::new::<CertificateIdPerSettlementJobIdColumn>(),
 ];
 
+/// CFs added by the third catch-up migration.
+pub const STATE_DB_V3_ADDED_CFS: &[ColumnDescriptor] =
+    &[ColumnDescriptor::new::<AdminAuditEventsColumn>()];
+
+/// CFs added by the fourth catch-up migration.
+pub const STATE_DB_V4_ADDED_CFS: &[ColumnDescriptor] =
+    &[ColumnDescriptor::new::<RateLimiterStateColumn>()];
+
+/// CFs added by the fifth catch-up migration.
+pub const STATE_DB_V5_ADDED_CFS: &[ColumnDescriptor] =
+    &[ColumnDescriptor::new::<NetworkStateSnapshotsColumn>()];
+
+/// CFs added by the sixth catch-up migration.
+pub const STATE_DB_V6_ADDED_CFS: &[ColumnDescriptor] =
+    &[ColumnDescriptor::new::<SettlementJobCancellationsColumn>()];
+
 /// Definitions for the column families in the state storage. The
 /// authoritative target schema: `init_db` ensures every CF listed here
 /// exists on disk, regardless of whether the source was V0 or already at
@@ -74,4 +94,12 @@ pub const STATE_DB: &[ColumnDescriptor] = &[
     ColumnDescriptor::new::<SettlementAttemptsColumn>(),
     ColumnDescriptor::new::<SettlementAttemptResultsColumn>(),
     ColumnDescriptor::new::<SettlementAttemptPerWalletColumn>(),
+    // Admin audit log
+    ColumnDescriptor::new::<AdminAuditEventsColumn>(),
+    // Rate limiter state
+    ColumnDescriptor::new::<RateLimiterStateColumn>(),
+    // Historical network states
+    ColumnDescriptor::new::<NetworkStateSnapshotsColumn>(),
+    // Settlement jobs cancelled by network rollbacks
+    ColumnDescriptor::new::<SettlementJobCancellationsColumn>(),
 ];
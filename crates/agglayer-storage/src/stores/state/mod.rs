@@ -1,5 +1,6 @@
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    ops::Range,
     path::Path,
     sync::{Arc, Mutex},
     time::SystemTime,
@@ -8,8 +9,8 @@ use std::{
 use agglayer_tries::{node::Node, smt::Smt};
 use agglayer_types::{
     primitives::Digest, Certificate, CertificateHeader, CertificateId, CertificateIndex,
-    CertificateStatus, EpochNumber, Height, LocalNetworkStateData, NetworkId, SettlementJobId,
-    SettlementTxHash,
+    CertificateStatus, EpochNumber, Height, ListDirection, LocalNetworkStateData, NetworkId,
+    SettlementJobCancellation, SettlementJobId, SettlementTxHash,
 };
 use pessimistic_proof::{
     local_balance_tree::LOCAL_BALANCE_TREE_DEPTH, nullifier_tree::NULLIFIER_TREE_DEPTH,
@@ -31,18 +32,26 @@ use crate::{
         },
         local_exit_tree_per_network as LET,
         metadata::MetadataColumn,
+        network_state_snapshots::NetworkStateSnapshotsColumn,
         nullifier_tree_per_network::NullifierTreePerNetworkColumn,
+        settlement_job_cancellations::SettlementJobCancellationsColumn,
         settlement_job_id_per_certificate_id::SettlementJobIdPerCertificateIdColumn,
     },
     error::Error,
     schema::ColumnSchema,
     storage::DB,
     stores::interfaces::writer::{UpdateEvenIfAlreadyPresent, UpdateStatusToCandidate},
-    types::{MetadataKey, MetadataValue, SmtKey, SmtKeyType, SmtValue},
+    types::{
+        network_state_snapshot::{self, NetworkStateSnapshot},
+        settlement::job_cancellation,
+        MetadataKey, MetadataValue, SmtKey, SmtKeyType, SmtValue,
+    },
 };
 
+mod admin_audit;
 mod cf_definitions;
 mod network_info;
+mod rate_limiter_state;
 mod settlement;
 
 #[cfg(test)]
@@ -53,6 +62,9 @@ pub struct StateStore {
     db: Arc<DB>,
     backup_client: BackupClient,
     settlement_write_locks: Mutex<HashMap<SettlementJobId, Arc<Mutex<()>>>>,
+    /// Serializes admin audit appends, which derive their key from the
+    /// latest one.
+    admin_audit_write_lock: Mutex<()>,
 }
 
 impl StateStore {
@@ -67,6 +79,10 @@ impl StateStore {
         DB::builder(path, cf_definitions::STATE_DB_V0)?
             .ensure_cfs(cf_definitions::STATE_DB_V1_ADDED_CFS)?
             .ensure_cfs(cf_definitions::STATE_DB_V2_ADDED_CFS)?
+            .ensure_cfs(cf_definitions::STATE_DB_V3_ADDED_CFS)?
+            .ensure_cfs(cf_definitions::STATE_DB_V4_ADDED_CFS)?
+            .ensure_cfs(cf_definitions::STATE_DB_V5_ADDED_CFS)?
+            .ensure_cfs(cf_definitions::STATE_DB_V6_ADDED_CFS)?
             .finalize(cf_definitions::STATE_DB)
     }
 
@@ -75,6 +91,7 @@ impl StateStore {
             db,
             backup_client,
             settlement_write_locks: Mutex::new(HashMap::new()),
+            admin_audit_write_lock: Mutex::new(()),
         }
     }
 
@@ -87,8 +104,16 @@ impl StateStore {
             db,
             backup_client,
             settlement_write_locks: Mutex::new(HashMap::new()),
+            admin_audit_write_lock: Mutex::new(()),
         })
     }
+
+    /// Open the state database read-only, so that it can be inspected while
+    /// the node is running.
+    pub fn new_readonly_with_path(path: &Path) -> Result<Self, crate::storage::DBError> {
+        let db = DB::open_cf_readonly(path, cf_definitions::STATE_DB)?;
+        Ok(Self::new(Arc::new(db), BackupClient::noop()))
+    }
 }
 
 impl StateWriter for StateStore {
@@ -308,10 +333,33 @@ impl StateWriter for StateStore {
         epoch_number: &EpochNumber,
         certificate_index: &CertificateIndex,
     ) -> Result<(), Error> {
-        Ok(self.db.put::<LatestSettledCertificatePerNetworkColumn>(
-            network_id,
-            &SettledCertificate(*certificate_id, *height, *epoch_number, *certificate_index),
-        )?)
+        let mut atomic_batch = WriteBatch::default();
+        self.db
+            .multi_insert_batch::<LatestSettledCertificatePerNetworkColumn>(
+                [(
+                    network_id,
+                    &SettledCertificate(
+                        *certificate_id,
+                        *height,
+                        *epoch_number,
+                        *certificate_index,
+                    ),
+                )],
+                &mut atomic_batch,
+            )?;
+
+        // The stored state is the one produced by this certificate.
+        if let Some(snapshot) = self.read_network_state_snapshot(*network_id)? {
+            self.db.multi_insert_batch::<NetworkStateSnapshotsColumn>(
+                [(
+                    &network_state_snapshot::Key::new(*network_id, *height),
+                    &snapshot,
+                )],
+                &mut atomic_batch,
+            )?;
+        }
+
+        Ok(self.db.write_batch(atomic_batch)?)
     }
 
     fn write_local_network_state(
@@ -404,6 +452,184 @@ impl StateWriter for StateStore {
 
         Ok(())
     }
+
+    #[instrument(skip(self, state))]
+    fn write_network_state_snapshot(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+        state: &LocalNetworkStateData,
+    ) -> Result<(), Error> {
+        let mut atomic_batch = WriteBatch::default();
+        self.db.multi_insert_batch::<NetworkStateSnapshotsColumn>(
+            [(
+                &network_state_snapshot::Key::new(*network_id, *height),
+                &network_state_snapshot_of(state)?,
+            )],
+            &mut atomic_batch,
+        )?;
+
+        // The roots are held by the snapshot, the stored ones are the roots of
+        // the current state.
+        let network_id = network_id.to_u32();
+        self.write_smt_nodes::<BalanceTreePerNetworkColumn, LOCAL_BALANCE_TREE_DEPTH>(
+            network_id,
+            &state.balance_tree,
+            &mut atomic_batch,
+        )?;
+        self.write_smt_nodes::<NullifierTreePerNetworkColumn, NULLIFIER_TREE_DEPTH>(
+            network_id,
+            &state.nullifier_tree,
+            &mut atomic_batch,
+        )?;
+
+        Ok(self.db.write_batch(atomic_batch)?)
+    }
+
+    #[instrument(skip(self, state, leaves, removed_certificates))]
+    fn rollback_network_state(
+        &self,
+        network_id: &NetworkId,
+        settled: &SettledCertificate,
+        state: &LocalNetworkStateData,
+        leaves: &[Digest],
+        removed_certificates: &[CertificateId],
+        cancelled_settlement_jobs: &[SettlementJobId],
+    ) -> Result<(), Error> {
+        if leaves.len() != state.exit_tree.leaf_count() as usize {
+            return Err(Error::InconsistentState {
+                network_id: *network_id,
+            });
+        }
+        let SettledCertificate(_, settled_height, _, _) = settled;
+
+        let mut atomic_batch = WriteBatch::default();
+
+        // The settled certificates above the new tip leave the per-network index.
+        let mut removed_cursors = Vec::new();
+        for key in self.db.keys_from::<CertificatePerNetworkColumn>(
+            &certificate_per_network::Key {
+                network_id: network_id.to_u32(),
+                height: settled_height.next(),
+            },
+            Direction::Forward,
+        )? {
+            let key = key?;
+            // The keys are ordered by network first, stop at the boundary.
+            if key.network_id != network_id.to_u32() {
+                break;
+            }
+            removed_cursors.push(key);
+        }
+        self.db.multi_delete_batch::<CertificatePerNetworkColumn>(
+            &removed_cursors,
+            &mut atomic_batch,
+        )?;
+        self.db.multi_delete_batch::<CertificateHeaderColumn>(
+            removed_certificates,
+            &mut atomic_batch,
+        )?;
+        self.db
+            .multi_delete_batch::<SettlementJobIdPerCertificateIdColumn>(
+                removed_certificates,
+                &mut atomic_batch,
+            )?;
+        self.db
+            .multi_insert_batch::<LatestSettledCertificatePerNetworkColumn>(
+                [(network_id, settled)],
+                &mut atomic_batch,
+            )?;
+
+        // The cancelled settlement jobs must not be resumed by a restart.
+        let cancellation = job_cancellation::Value::from(&SettlementJobCancellation {
+            network_id: *network_id,
+            target_height: *settled_height,
+        });
+        self.db
+            .multi_insert_batch::<SettlementJobCancellationsColumn>(
+                cancelled_settlement_jobs
+                    .iter()
+                    .map(|job_id| (job_id, &cancellation)),
+                &mut atomic_batch,
+            )?;
+
+        // The snapshots above the new tip go, the one of the new tip is
+        // rewritten in case it predates the snapshots.
+        self.delete_network_keys::<NetworkStateSnapshotsColumn>(
+            &network_state_snapshot::Key::new(*network_id, settled_height.next()),
+            |key| key.network_id,
+            &mut atomic_batch,
+        )?;
+        self.db.multi_insert_batch::<NetworkStateSnapshotsColumn>(
+            [(
+                &network_state_snapshot::Key::new(*network_id, *settled_height),
+                &network_state_snapshot_of(state)?,
+            )],
+            &mut atomic_batch,
+        )?;
+
+        // Replace the exit tree: its stored entries are deleted first, the
+        // writes of the restored state being applied after them. The nodes of
+        // the balance and nullifier trees are addressed by their hash and
+        // shared with the snapshots, so only their roots are replaced.
+        let network_id = network_id.to_u32();
+        self.delete_network_keys::<LocalExitTreePerNetworkColumn>(
+            &LET::Key {
+                network_id,
+                key_type: LET::KeyType::LeafCount,
+            },
+            |key| key.network_id,
+            &mut atomic_batch,
+        )?;
+
+        let mut exit_tree_writes = BTreeMap::new();
+        exit_tree_writes.insert(
+            LET::Key {
+                network_id,
+                key_type: LET::KeyType::LeafCount,
+            },
+            LET::Value::LeafCount(state.exit_tree.leaf_count()),
+        );
+        for (index, leaf) in leaves.iter().enumerate() {
+            exit_tree_writes.insert(
+                LET::Key {
+                    network_id,
+                    key_type: LET::KeyType::Leaf(index as u32),
+                },
+                LET::Value::Leaf(*leaf.as_bytes()),
+            );
+        }
+        for layer in 0..32 {
+            exit_tree_writes.insert(
+                LET::Key {
+                    network_id,
+                    key_type: LET::KeyType::Frontier(layer),
+                },
+                LET::Value::Frontier(*state.exit_tree.frontier()[layer as usize]),
+            );
+        }
+        self.db
+            .multi_insert_batch::<LocalExitTreePerNetworkColumn>(
+                exit_tree_writes.iter(),
+                &mut atomic_batch,
+            )?;
+        self.write_smt::<BalanceTreePerNetworkColumn, LOCAL_BALANCE_TREE_DEPTH>(
+            network_id,
+            &state.balance_tree,
+            &mut atomic_batch,
+        )?;
+        self.write_smt::<NullifierTreePerNetworkColumn, NULLIFIER_TREE_DEPTH>(
+            network_id,
+            &state.nullifier_tree,
+            &mut atomic_batch,
+        )?;
+
+        self.db.write_batch(atomic_batch)?;
+
+        self.request_backup();
+
+        Ok(())
+    }
 }
 
 impl StateStore {
@@ -423,41 +649,56 @@ impl StateStore {
     where
         C: ColumnSchema<Key = SmtKey, Value = SmtValue>,
     {
-        let mut kv = BTreeMap::new();
-        smt.tree.iter().for_each(|(&node_hash, node)| {
-            // Write the node
-            kv.insert(
-                SmtKey {
-                    network_id,
-                    key_type: if node_hash == smt.root {
-                        SmtKeyType::Root
-                    } else {
-                        SmtKeyType::Node(node_hash)
-                    },
-                },
-                SmtValue::Node(node.left, node.right),
-            );
+        self.db
+            .multi_insert_batch::<C>(&smt_entries(network_id, smt), batch)?;
 
-            // Write the children as leaves if they are
-            [node.left, node.right]
-                .iter()
-                .filter(|&maybe_leaf| !smt.tree.contains_key(maybe_leaf))
-                .for_each(|&leaf| {
-                    kv.insert(
-                        SmtKey {
-                            network_id,
-                            key_type: SmtKeyType::Node(leaf),
-                        },
-                        SmtValue::Leaf(leaf),
-                    );
-                });
-        });
+        Ok(())
+    }
 
+    /// Writes the nodes of `smt` below its root, leaving the stored root
+    /// untouched.
+    fn write_smt_nodes<C, const DEPTH: usize>(
+        &self,
+        network_id: u32,
+        smt: &Smt<DEPTH>,
+        batch: &mut WriteBatch,
+    ) -> Result<(), Error>
+    where
+        C: ColumnSchema<Key = SmtKey, Value = SmtValue>,
+    {
+        let mut kv = smt_entries(network_id, smt);
+        kv.remove(&SmtKey {
+            network_id,
+            key_type: SmtKeyType::Root,
+        });
         self.db.multi_insert_batch::<C>(&kv, batch)?;
 
         Ok(())
     }
 
+    /// Queues the deletion of the keys of a network in a column keyed by
+    /// network first, from `first_key` onwards.
+    fn delete_network_keys<C: ColumnSchema>(
+        &self,
+        first_key: &C::Key,
+        key_network: impl Fn(&C::Key) -> u32,
+        batch: &mut WriteBatch,
+    ) -> Result<(), Error> {
+        let network_id = key_network(first_key);
+
+        let mut keys = Vec::new();
+        for key in self.db.keys_from::<C>(first_key, Direction::Forward)? {
+            let key = key?;
+            if key_network(&key) != network_id {
+                break;
+            }
+            keys.push(key);
+        }
+        self.db.multi_delete_batch::<C>(&keys, batch)?;
+
+        Ok(())
+    }
+
     fn read_local_exit_tree(&self, network_id: NetworkId) -> Result<Option<LocalExitTree>, Error> {
         let leaf_count = if let Some(leaf_count_value) =
             self.db.get::<LocalExitTreePerNetworkColumn>(&LET::Key {
@@ -515,6 +756,23 @@ impl StateStore {
             return Ok(None);
         };
 
+        self.read_smt_from_root::<C, DEPTH>(network_id, root_node)
+            .map(Some)
+    }
+
+    /// Read the tree below `root_node` from its nodes, which are addressed by
+    /// their hash.
+    ///
+    /// Every node is rehashed against its address, so that the tree is the
+    /// one committed to by `root_node` rather than whatever the rows hold.
+    fn read_smt_from_root<C, const DEPTH: usize>(
+        &self,
+        network_id: NetworkId,
+        root_node: Node,
+    ) -> Result<Smt<DEPTH>, Error>
+    where
+        C: ColumnSchema<Key = SmtKey, Value = SmtValue>,
+    {
         let mut keys = VecDeque::new();
         keys.push_back(SmtKeyType::Node(root_node.left));
         keys.push_back(SmtKeyType::Node(root_node.right));
@@ -532,12 +790,19 @@ impl StateStore {
                 })?
                 .ok_or(Error::SmtNodeNotFound)?;
 
+            let SmtKeyType::Node(hash) = key else {
+                unreachable!("only nodes are queued");
+            };
             match value {
                 SmtValue::Node(left, right) => {
-                    nodes.push(Node {
+                    let node = Node {
                         left: Digest(*left.as_bytes()),
                         right: Digest(*right.as_bytes()),
-                    });
+                    };
+                    if node.hash() != hash {
+                        return Err(Error::SmtNodeHashMismatch(hash));
+                    }
+                    nodes.push(node);
                     if queued.insert(left) {
                         keys.push_back(SmtKeyType::Node(left));
                     }
@@ -545,14 +810,105 @@ impl StateStore {
                         keys.push_back(SmtKeyType::Node(right));
                     }
                 }
-                SmtValue::Leaf(_) => {} // nothing to do
+                SmtValue::Leaf(leaf) if leaf == hash => {}
+                SmtValue::Leaf(_) => return Err(Error::SmtNodeHashMismatch(hash)),
             }
         }
 
-        Ok(Some(Smt::<DEPTH>::new_with_nodes(
+        Ok(Smt::<DEPTH>::new_with_nodes(
             root_node.hash(),
             nodes.as_slice(),
-        )))
+        ))
+    }
+
+    /// Read the roots of the stored local network state, if any.
+    fn read_network_state_snapshot(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<Option<NetworkStateSnapshot>, Error> {
+        let root_key = SmtKey {
+            network_id: network_id.into(),
+            key_type: SmtKeyType::Root,
+        };
+        let exit_tree = self.read_local_exit_tree(network_id)?;
+        let balance_root = self.db.get::<BalanceTreePerNetworkColumn>(&root_key)?;
+        let nullifier_root = self.db.get::<NullifierTreePerNetworkColumn>(&root_key)?;
+
+        match (exit_tree, balance_root, nullifier_root) {
+            (None, None, None) => Ok(None),
+            (
+                Some(exit_tree),
+                Some(SmtValue::Node(balance_left, balance_right)),
+                Some(SmtValue::Node(nullifier_left, nullifier_right)),
+            ) => Ok(Some(NetworkStateSnapshot {
+                leaf_count: exit_tree.leaf_count(),
+                frontier: std::array::from_fn(|layer| exit_tree.frontier()[layer]),
+                balance_root: (balance_left, balance_right),
+                nullifier_root: (nullifier_left, nullifier_right),
+            })),
+            (Some(_), Some(_), Some(_)) => Err(Error::WrongValueType),
+            _ => Err(Error::InconsistentState { network_id }),
+        }
+    }
+}
+
+/// The entries of the nodes of `smt`, its root being keyed as such.
+fn smt_entries<const DEPTH: usize>(
+    network_id: u32,
+    smt: &Smt<DEPTH>,
+) -> BTreeMap<SmtKey, SmtValue> {
+    let mut kv = BTreeMap::new();
+    smt.tree.iter().for_each(|(&node_hash, node)| {
+        // Write the node
+        kv.insert(
+            SmtKey {
+                network_id,
+                key_type: if node_hash == smt.root {
+                    SmtKeyType::Root
+                } else {
+                    SmtKeyType::Node(node_hash)
+                },
+            },
+            SmtValue::Node(node.left, node.right),
+        );
+
+        // Write the children as leaves if they are
+        [node.left, node.right]
+            .iter()
+            .filter(|&maybe_leaf| !smt.tree.contains_key(maybe_leaf))
+            .for_each(|&leaf| {
+                kv.insert(
+                    SmtKey {
+                        network_id,
+                        key_type: SmtKeyType::Node(leaf),
+                    },
+                    SmtValue::Leaf(leaf),
+                );
+            });
+    });
+
+    kv
+}
+
+/// The roots of a local network state, as stored in its snapshots.
+fn network_state_snapshot_of(state: &LocalNetworkStateData) -> Result<NetworkStateSnapshot, Error> {
+    fn root_children<const DEPTH: usize>(smt: &Smt<DEPTH>) -> Option<(Digest, Digest)> {
+        smt.tree.get(&smt.root).map(|root| (root.left, root.right))
+    }
+
+    match (
+        root_children(&state.balance_tree),
+        root_children(&state.nullifier_tree),
+    ) {
+        (Some(balance_root), Some(nullifier_root)) => Ok(NetworkStateSnapshot {
+            leaf_count: state.exit_tree.leaf_count(),
+            frontier: std::array::from_fn(|layer| state.exit_tree.frontier()[layer]),
+            balance_root,
+            nullifier_root,
+        }),
+        _ => Err(Error::Unexpected(
+            "The roots of the local network state are missing from its trees".to_string(),
+        )),
     }
 }
 
@@ -632,6 +988,50 @@ impl StateReader for StateStore {
             })
     }
 
+    fn list_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        direction: ListDirection,
+    ) -> Result<Vec<CertificateHeader>, Error> {
+        let direction = match direction {
+            ListDirection::Ascending => Direction::Forward,
+            ListDirection::Descending => Direction::Reverse,
+        };
+        let start = certificate_per_network::Key {
+            network_id: network_id.to_u32(),
+            height: from_height,
+        };
+
+        let mut headers = Vec::new();
+        for entry in self
+            .db
+            .iter_from::<CertificatePerNetworkColumn>(&start, direction)?
+        {
+            if headers.len() >= limit {
+                break;
+            }
+
+            let (key, certificate_id) = entry?;
+            // The keys are ordered by network first, stop at the boundary.
+            if key.network_id != network_id.to_u32() {
+                break;
+            }
+
+            match self.get_certificate_header(&certificate_id)? {
+                Some(header) => headers.push(header),
+                None => warn!(
+                    "Certificate header not found for certificate_id: {} while having a reference \
+                     in the CertificatePerNetworkColumn",
+                    certificate_id
+                ),
+            }
+        }
+
+        Ok(headers)
+    }
+
     fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error> {
         Ok(self
             .db
@@ -676,12 +1076,74 @@ impl StateReader for StateStore {
         }
     }
 
+    fn has_network_state_snapshot(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<bool, Error> {
+        Ok(self
+            .db
+            .get::<NetworkStateSnapshotsColumn>(&network_state_snapshot::Key::new(
+                network_id, height,
+            ))?
+            .is_some())
+    }
+
+    fn read_local_exit_tree_leaves(
+        &self,
+        network_id: NetworkId,
+        leaves: Range<u32>,
+    ) -> Result<Option<Vec<Digest>>, Error> {
+        self.db
+            .multi_get::<LocalExitTreePerNetworkColumn>(leaves.map(|index| LET::Key {
+                network_id: network_id.into(),
+                key_type: LET::KeyType::Leaf(index),
+            }))?
+            .into_iter()
+            .map(|value| match value {
+                Some(LET::Value::Leaf(leaf)) => Ok(Some(Digest(leaf))),
+                Some(_) => Err(Error::WrongValueType),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
     fn is_network_disabled(&self, network_id: &NetworkId) -> Result<bool, Error> {
         Ok(self
             .db
             .get::<crate::columns::disabled_networks::DisabledNetworksColumn>(network_id)
             .map(|v| v.is_some())?)
     }
+
+    fn read_local_network_state_at(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<LocalNetworkStateData>, Error> {
+        let Some(snapshot) =
+            self.db
+                .get::<NetworkStateSnapshotsColumn>(&network_state_snapshot::Key::new(
+                    network_id, height,
+                ))?
+        else {
+            return Ok(None);
+        };
+
+        let root_node = |(left, right): (Digest, Digest)| Node { left, right };
+        Ok(Some(LocalNetworkStateData {
+            exit_tree: LocalExitTree::from_parts(snapshot.leaf_count, snapshot.frontier),
+            balance_tree: self
+                .read_smt_from_root::<BalanceTreePerNetworkColumn, LOCAL_BALANCE_TREE_DEPTH>(
+                    network_id,
+                    root_node(snapshot.balance_root),
+                )?,
+            nullifier_tree: self
+                .read_smt_from_root::<NullifierTreePerNetworkColumn, NULLIFIER_TREE_DEPTH>(
+                    network_id,
+                    root_node(snapshot.nullifier_root),
+                )?,
+        }))
+    }
 }
 
 impl MetadataWriter for StateStore {
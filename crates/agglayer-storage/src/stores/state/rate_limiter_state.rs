@@ -0,0 +1,44 @@
+use std::time::SystemTime;
+
+use agglayer_types::NetworkId;
+
+use super::StateStore;
+use crate::{
+    columns::rate_limiter_state::RateLimiterStateColumn,
+    error::Error,
+    stores::{RateLimiterStateReader, RateLimiterStateWriter},
+    types::{generated::agglayer::storage::v0, rate_limiter_state::Key},
+};
+
+impl RateLimiterStateReader for StateStore {
+    fn get_rate_limiter_events(
+        &self,
+        network_id: NetworkId,
+        component: &str,
+    ) -> Result<Vec<SystemTime>, Error> {
+        let Some(events) = self
+            .db
+            .get::<RateLimiterStateColumn>(&Key::new(network_id, component))?
+        else {
+            return Ok(Vec::new());
+        };
+
+        Ok(events.try_into()?)
+    }
+}
+
+impl RateLimiterStateWriter for StateStore {
+    fn set_rate_limiter_events(
+        &self,
+        network_id: NetworkId,
+        component: &str,
+        events: &[SystemTime],
+    ) -> Result<(), Error> {
+        self.db.put::<RateLimiterStateColumn>(
+            &Key::new(network_id, component),
+            &v0::RateLimiterEvents::from(events),
+        )?;
+
+        Ok(())
+    }
+}
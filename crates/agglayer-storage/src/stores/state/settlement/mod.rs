@@ -1,6 +1,7 @@
 use agglayer_types::{
     Address, CertificateId, ClientError, ClientErrorType, Nonce, SettlementAttempt,
-    SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementJobResult,
+    SettlementAttemptResult, SettlementJob, SettlementJobCancellation, SettlementJobId,
+    SettlementJobResult,
 };
 use rocksdb::{Direction, WriteBatch};
 use tracing::warn;
@@ -12,6 +13,7 @@ use crate::{
         settlement_attempt_per_wallet::SettlementAttemptPerWalletColumn,
         settlement_attempt_results::SettlementAttemptResultsColumn,
         settlement_attempts::SettlementAttemptsColumn,
+        settlement_job_cancellations::SettlementJobCancellationsColumn,
         settlement_job_id_per_certificate_id::SettlementJobIdPerCertificateIdColumn,
         settlement_job_results::SettlementJobResultsColumn, settlement_jobs::SettlementJobsColumn,
     },
@@ -141,6 +143,30 @@ impl StateStore {
 
         Ok(())
     }
+
+    /// Whether the attempt indexed at `key` was never broadcast because its
+    /// pre-flight simulation reverted. Such an attempt never consumed its
+    /// nonce on L1, and its task does not retry it.
+    fn is_simulated_attempt(&self, key: &attempt_per_wallet::Key) -> Result<bool, Error> {
+        let attempt_key = SettlementAttemptKey {
+            settlement_job_id: key.settlement_job_id,
+            attempt_sequence_number: key.attempt_sequence_number,
+        };
+        let Some(result) = self
+            .db
+            .get::<SettlementAttemptResultsColumn>(&attempt_key)?
+        else {
+            return Ok(false);
+        };
+
+        Ok(matches!(
+            SettlementAttemptResult::try_from(result)?,
+            SettlementAttemptResult::ClientError(ClientError {
+                kind: ClientErrorType::SimulatedRevert,
+                ..
+            })
+        ))
+    }
 }
 
 impl SettlementReader for StateStore {
@@ -182,6 +208,16 @@ impl SettlementReader for StateStore {
             .transpose()?)
     }
 
+    fn get_settlement_job_cancellation(
+        &self,
+        settlement_job_id: &SettlementJobId,
+    ) -> Result<Option<SettlementJobCancellation>, Error> {
+        Ok(self
+            .db
+            .get::<SettlementJobCancellationsColumn>(settlement_job_id)?
+            .map(SettlementJobCancellation::from))
+    }
+
     fn list_settlement_attempts(
         &self,
         settlement_job_id: &SettlementJobId,
@@ -218,15 +254,20 @@ impl SettlementReader for StateStore {
 
     fn max_settlement_nonce_for_wallet(&self, wallet: Address) -> Result<Option<Nonce>, Error> {
         let prefix = wallet.into_array();
-        Ok(self
+        for entry in self
             .db
             .prefix_iterator_with_direction::<SettlementAttemptPerWalletColumn, _>(
                 &prefix,
                 Direction::Reverse,
             )?
-            .next()
-            .transpose()?
-            .map(|(key, _)| Nonce(key.nonce)))
+        {
+            let (key, _) = entry?;
+            if !self.is_simulated_attempt(&key)? {
+                return Ok(Some(Nonce(key.nonce)));
+            }
+        }
+
+        Ok(None)
     }
 }
 
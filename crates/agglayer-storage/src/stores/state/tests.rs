@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use agglayer_types::{
     aggchain_data::CertificateAggchainDataCtx, primitives::Hashable as _, Certificate,
-    CertificateId, CertificateIndex, Digest, EpochNumber, Height, L1WitnessCtx,
-    LocalNetworkStateData, NetworkId, PessimisticRootInput,
+    CertificateId, CertificateIndex, CertificateStatus, Digest, EpochNumber, Height, L1WitnessCtx,
+    LocalNetworkStateData, NetworkId, PessimisticRootInput, SettlementJobCancellation,
+    SettlementJobId, U256,
 };
 use pessimistic_proof::{
     core::{
@@ -24,13 +25,16 @@ use crate::{
     error::Error,
     schema::ColumnSchema as _,
     storage::DB,
-    stores::{state::StateStore, StateReader as _, StateWriter as _},
+    stores::{state::StateStore, SettlementReader as _, StateReader as _, StateWriter as _},
     tests::TempDBDir,
 };
 
+mod admin_audit;
 mod backup;
+mod certificate_headers;
 mod disabled_networks;
 mod metadata;
+mod rate_limiter_state;
 mod settlement;
 
 #[test]
@@ -119,6 +123,52 @@ fn init_db_adds_settlement_job_id_per_certificate_id_cf_to_v1_schema() {
     drop(db);
 }
 
+#[test]
+fn init_db_adds_admin_audit_events_cf_to_v2_schema() {
+    use std::collections::BTreeSet;
+
+    use crate::columns::admin_audit_events::AdminAuditEventsColumn;
+
+    let tmp = TempDBDir::new();
+    {
+        let previous_schema = DB::builder(tmp.path.as_path(), cf_definitions::STATE_DB_V0)
+            .expect("V0 schema initialization should succeed")
+            .ensure_cfs(cf_definitions::STATE_DB_V1_ADDED_CFS)
+            .expect("V1 schema migration should succeed")
+            .ensure_cfs(cf_definitions::STATE_DB_V2_ADDED_CFS)
+            .expect("V2 schema migration should succeed")
+            .finalize(cf_definitions::STATE_DB)
+            .expect("V2 schema finalization should succeed");
+        let pre_cfs = rocksdb::DB::list_cf(&rocksdb::Options::default(), tmp.path.as_path())
+            .expect("list_cf should succeed on the V2 schema");
+        assert!(
+            !pre_cfs
+                .iter()
+                .any(|cf| cf == AdminAuditEventsColumn::COLUMN_FAMILY_NAME),
+            "the V2 schema must not hold CF {:?}; got {pre_cfs:?}",
+            AdminAuditEventsColumn::COLUMN_FAMILY_NAME
+        );
+        drop(previous_schema);
+    }
+
+    let db = StateStore::init_db(tmp.path.as_path())
+        .expect("init_db should ensure the admin audit events CF is added");
+
+    let post_cfs: BTreeSet<&str> =
+        rocksdb::DB::list_cf(&rocksdb::Options::default(), tmp.path.as_path())
+            .expect("list_cf should succeed after init_db")
+            .into_iter()
+            .map(|s| Box::leak(s.into_boxed_str()) as &str)
+            .collect();
+
+    assert!(
+        post_cfs.contains(AdminAuditEventsColumn::COLUMN_FAMILY_NAME),
+        "expected CF {:?} to be present after init_db; got {post_cfs:?}",
+        AdminAuditEventsColumn::COLUMN_FAMILY_NAME
+    );
+    drop(db);
+}
+
 #[test]
 fn init_db_creates_certificate_id_per_settlement_job_id_cf() {
     use crate::columns::certificate_id_per_settlement_job_id::CertificateIdPerSettlementJobIdColumn;
@@ -262,6 +312,33 @@ fn can_update_existing_state(network_id: NetworkId, store: StateStore) {
     );
 }
 
+#[rstest]
+fn can_retrieve_local_exit_tree_leaves(network_id: NetworkId, store: StateStore) {
+    let mut lns = LocalNetworkStateData::default();
+    let leaves = (0..3u8).map(|i| Digest([i; 32])).collect::<Vec<_>>();
+    for l in &leaves {
+        lns.exit_tree.add_leaf(*l).unwrap();
+    }
+
+    store
+        .write_local_network_state(&network_id, &lns, leaves.as_slice())
+        .unwrap();
+
+    assert_eq!(
+        store.read_local_exit_tree_leaves(network_id, 0..3).unwrap(),
+        Some(leaves.clone())
+    );
+    assert_eq!(
+        store.read_local_exit_tree_leaves(network_id, 1..3).unwrap(),
+        Some(leaves[1..].to_vec())
+    );
+    // leaves past the stored ones are reported as missing
+    assert_eq!(
+        store.read_local_exit_tree_leaves(network_id, 0..4).unwrap(),
+        None
+    );
+}
+
 #[rstest]
 fn can_detect_inconsistent_state(network_id: NetworkId, store: StateStore) {
     let mut lns = LocalNetworkStateData::default();
@@ -282,6 +359,205 @@ fn can_detect_inconsistent_state(network_id: NetworkId, store: StateStore) {
     ));
 }
 
+#[rstest]
+fn can_roll_back_network_state(network_id: NetworkId, store: StateStore) {
+    let certificates = (0..3)
+        .map(|height| Certificate::new_for_test(network_id, Height::new(height)))
+        .collect::<Vec<_>>();
+    let settled = |height: usize| {
+        SettledCertificate(
+            certificates[height].hash(),
+            Height::new(height as u64),
+            EpochNumber::ZERO,
+            CertificateIndex::new(height as u64),
+        )
+    };
+
+    // One new leaf per settled certificate, the last one also holding a balance.
+    let leaves = (0..3u8).map(|i| Digest([i; 32])).collect::<Vec<_>>();
+    let mut states = Vec::new();
+    let mut lns = LocalNetworkStateData::default();
+    for leaf in &leaves {
+        lns.exit_tree.add_leaf(*leaf).unwrap();
+        states.push(lns.clone());
+    }
+    states[2]
+        .balance_tree
+        .insert(data::USDC, U256::from(100).to_be_bytes().into())
+        .unwrap();
+
+    for certificate in &certificates {
+        store
+            .insert_certificate_header(certificate, CertificateStatus::Settled)
+            .unwrap();
+    }
+    store
+        .write_local_network_state(&network_id, &states[2], &leaves)
+        .unwrap();
+    let cancelled_job = SettlementJobId::from(1u128);
+    let SettledCertificate(certificate_id, height, epoch_number, certificate_index) = settled(2);
+    store
+        .set_latest_settled_certificate_for_network(
+            &network_id,
+            &height,
+            &certificate_id,
+            &epoch_number,
+            &certificate_index,
+        )
+        .unwrap();
+
+    store
+        .rollback_network_state(
+            &network_id,
+            &settled(0),
+            &states[0],
+            &leaves[..1],
+            &[certificates[1].hash(), certificates[2].hash()],
+            &[cancelled_job],
+        )
+        .unwrap();
+
+    assert!(
+        matches!(store.read_local_network_state(network_id), Ok(Some(retrieved)) if equal_state(&states[0], &retrieved))
+    );
+    assert_eq!(
+        store.read_local_exit_tree_leaves(network_id, 0..1).unwrap(),
+        Some(leaves[..1].to_vec())
+    );
+    assert_eq!(
+        store.read_local_exit_tree_leaves(network_id, 0..2).unwrap(),
+        None
+    );
+    assert_eq!(
+        store
+            .get_latest_settled_certificate_per_network(&network_id)
+            .unwrap(),
+        Some((network_id, settled(0)))
+    );
+    assert!(store
+        .get_certificate_header_by_cursor(network_id, Height::ZERO)
+        .unwrap()
+        .is_some());
+    for certificate in &certificates[1..] {
+        assert!(store
+            .get_certificate_header_by_cursor(network_id, certificate.height)
+            .unwrap()
+            .is_none());
+        assert!(store
+            .get_certificate_header(&certificate.hash())
+            .unwrap()
+            .is_none());
+    }
+    assert_eq!(
+        store
+            .get_settlement_job_cancellation(&cancelled_job)
+            .unwrap(),
+        Some(SettlementJobCancellation {
+            network_id,
+            target_height: Height::ZERO,
+        })
+    );
+    assert_eq!(
+        store
+            .get_settlement_job_cancellation(&SettlementJobId::from(2u128))
+            .unwrap(),
+        None
+    );
+}
+
+#[rstest]
+fn rollback_requires_every_exit_tree_leaf(network_id: NetworkId, store: StateStore) {
+    let mut lns = LocalNetworkStateData::default();
+    lns.exit_tree.add_leaf(Digest([5u8; 32])).unwrap();
+    let settled = SettledCertificate(
+        Certificate::new_for_test(network_id, Height::ZERO).hash(),
+        Height::ZERO,
+        EpochNumber::ZERO,
+        CertificateIndex::new(0),
+    );
+
+    assert!(matches!(
+        store.rollback_network_state(&network_id, &settled, &lns, &[], &[], &[]),
+        Err(Error::InconsistentState { .. })
+    ));
+}
+
+#[rstest]
+fn can_read_local_network_state_at_settled_heights(network_id: NetworkId, store: StateStore) {
+    // A new leaf per settled certificate, and a balance changing at each one.
+    let mut states = Vec::new();
+    let mut lns = LocalNetworkStateData::default();
+    for height in 0..3u8 {
+        lns.exit_tree.add_leaf(Digest([height; 32])).unwrap();
+        lns.balance_tree
+            .update(
+                data::USDC,
+                U256::from(100 - 10 * height as u64).to_be_bytes().into(),
+            )
+            .unwrap();
+        // Only the nodes reachable from the roots are read back.
+        lns.balance_tree.traverse_and_prune().unwrap();
+        states.push(lns.clone());
+
+        let certificate = Certificate::new_for_test(network_id, Height::new(height as u64));
+        store
+            .write_local_network_state(&network_id, &lns, &[Digest([height; 32])])
+            .unwrap();
+        store
+            .set_latest_settled_certificate_for_network(
+                &network_id,
+                &certificate.height,
+                &certificate.hash(),
+                &EpochNumber::ZERO,
+                &CertificateIndex::new(height as u64),
+            )
+            .unwrap();
+    }
+
+    for (height, state) in states.iter().enumerate() {
+        let retrieved = store
+            .read_local_network_state_at(network_id, Height::new(height as u64))
+            .unwrap()
+            .unwrap();
+        assert!(equal_state(state, &retrieved));
+    }
+    assert!(store
+        .read_local_network_state_at(network_id, Height::new(3))
+        .unwrap()
+        .is_none());
+
+    // The snapshots above the settled certificate go with a rollback, the
+    // ones below are still readable.
+    let certificate = Certificate::new_for_test(network_id, Height::new(1));
+    store
+        .rollback_network_state(
+            &network_id,
+            &SettledCertificate(
+                certificate.hash(),
+                Height::new(1),
+                EpochNumber::ZERO,
+                CertificateIndex::new(1),
+            ),
+            &states[1],
+            &[Digest([0; 32]), Digest([1; 32])],
+            &[],
+            &[],
+        )
+        .unwrap();
+
+    for (height, state) in states[..2].iter().enumerate() {
+        let retrieved = store
+            .read_local_network_state_at(network_id, Height::new(height as u64))
+            .unwrap()
+            .unwrap();
+        assert!(equal_state(state, &retrieved));
+    }
+    assert!(store
+        .read_local_network_state_at(network_id, Height::new(2))
+        .unwrap()
+        .is_none());
+}
+
 use pessimistic_proof_test_suite::sample_data::{self as data};
 
 #[rstest]
@@ -441,3 +717,53 @@ fn certificate_serialization(#[case] cert_name: &str) {
     let hash = pessimistic_proof::keccak::keccak256(&encoded);
     insta::assert_debug_snapshot!(cert_name, hash);
 }
+
+#[rstest]
+fn can_backfill_network_state_snapshots(network_id: NetworkId, store: StateStore) {
+    let mut states = Vec::new();
+    let mut lns = LocalNetworkStateData::default();
+    for height in 0..3u8 {
+        lns.exit_tree.add_leaf(Digest([height; 32])).unwrap();
+        lns.balance_tree
+            .update(
+                data::USDC,
+                U256::from(100 - 10 * height as u64).to_be_bytes().into(),
+            )
+            .unwrap();
+        lns.balance_tree.traverse_and_prune().unwrap();
+        states.push(lns.clone());
+    }
+
+    // Only the latest state got written, before the snapshots got recorded.
+    let leaves: Vec<_> = (0..3u8).map(|height| Digest([height; 32])).collect();
+    store
+        .write_local_network_state(&network_id, &states[2], &leaves)
+        .unwrap();
+    assert!(!store
+        .has_network_state_snapshot(network_id, Height::ZERO)
+        .unwrap());
+
+    for (height, state) in states[..2].iter().enumerate() {
+        store
+            .write_network_state_snapshot(&network_id, &Height::new(height as u64), state)
+            .unwrap();
+    }
+
+    for (height, state) in states[..2].iter().enumerate() {
+        let height = Height::new(height as u64);
+        assert!(store
+            .has_network_state_snapshot(network_id, height)
+            .unwrap());
+        let retrieved = store
+            .read_local_network_state_at(network_id, height)
+            .unwrap()
+            .unwrap();
+        assert!(equal_state(state, &retrieved));
+    }
+    assert!(!store
+        .has_network_state_snapshot(network_id, Height::new(2))
+        .unwrap());
+
+    let current = store.read_local_network_state(network_id).unwrap().unwrap();
+    assert!(equal_state(&states[2], &current));
+}
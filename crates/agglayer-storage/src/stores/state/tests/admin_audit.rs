@@ -0,0 +1,137 @@
+use std::time::{Duration, SystemTime};
+
+use agglayer_types::{AdminAuditCaller, AdminAuditEvent, AdminAuditLiveTask, AdminAuditOutcome};
+
+use crate::{
+    backup::BackupClient,
+    stores::{state::StateStore, AdminAuditReader as _, AdminAuditWriter as _},
+    tests::TempDBDir,
+};
+
+fn mk_event(method: &str, recorded_at: SystemTime) -> AdminAuditEvent {
+    AdminAuditEvent {
+        recorded_at,
+        caller: AdminAuditCaller {
+            name: "oncall".to_string(),
+            role: Some("operator".to_string()),
+        },
+        method: method.to_string(),
+        network_id: Some(1.into()),
+        params: "[1]".to_string(),
+        before: Some("false".to_string()),
+        after: Some("true".to_string()),
+        outcome: AdminAuditOutcome::Applied {
+            live_task: Some(AdminAuditLiveTask::Queued),
+        },
+    }
+}
+
+#[test]
+fn audit_events_are_listed_by_recording_time() {
+    let tmp = TempDBDir::new();
+    let store = StateStore::new_with_path(tmp.path.as_path(), BackupClient::noop()).unwrap();
+
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let at = |secs| start + Duration::from_secs(secs);
+
+    // Appended out of order, listed in recording order.
+    let second = mk_event("admin_enableNetwork", at(20));
+    let first = mk_event("admin_disableNetwork", at(10));
+    let later = mk_event("admin_disableNetwork", at(30));
+    let second_id = store.append_admin_audit_event(&second).unwrap();
+    let first_id = store.append_admin_audit_event(&first).unwrap();
+    store.append_admin_audit_event(&later).unwrap();
+
+    let events = store
+        .list_admin_audit_events(at(10), at(30), None, usize::MAX)
+        .unwrap();
+    assert_eq!(events, vec![(first_id, first), (second_id, second)]);
+
+    assert!(store
+        .list_admin_audit_events(at(0), at(10), None, usize::MAX)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        store
+            .list_admin_audit_events(at(0), at(60), None, usize::MAX)
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn audit_events_are_kept_in_append_order_within_a_millisecond() {
+    let tmp = TempDBDir::new();
+    let store = StateStore::new_with_path(tmp.path.as_path(), BackupClient::noop()).unwrap();
+
+    let recorded_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let methods = [
+        "admin_disableNetwork",
+        "admin_enableNetwork",
+        "admin_disableNetwork",
+    ];
+    let ids = methods
+        .iter()
+        .map(|method| {
+            store
+                .append_admin_audit_event(&mk_event(method, recorded_at))
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let events = store
+        .list_admin_audit_events(
+            recorded_at,
+            recorded_at + Duration::from_millis(1),
+            None,
+            usize::MAX,
+        )
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|(id, event)| (*id, event.method.as_str()))
+            .collect::<Vec<_>>(),
+        ids.into_iter().zip(methods).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn audit_event_listing_is_filtered_by_method_before_the_limit() {
+    let tmp = TempDBDir::new();
+    let store = StateStore::new_with_path(tmp.path.as_path(), BackupClient::noop()).unwrap();
+
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let at = |secs| start + Duration::from_secs(secs);
+
+    let mut disable_ids = Vec::new();
+    for secs in 0..6 {
+        let method = if secs % 2 == 0 {
+            "admin_enableNetwork"
+        } else {
+            "admin_disableNetwork"
+        };
+        let event_id = store
+            .append_admin_audit_event(&mk_event(method, at(secs)))
+            .unwrap();
+        if secs % 2 == 1 {
+            disable_ids.push(event_id);
+        }
+    }
+
+    assert_eq!(
+        store
+            .list_admin_audit_events(at(0), at(60), None, 4)
+            .unwrap()
+            .len(),
+        4
+    );
+    let events = store
+        .list_admin_audit_events(at(0), at(60), Some("admin_disableNetwork"), 2)
+        .unwrap();
+    assert_eq!(
+        events.into_iter().map(|(id, _)| id).collect::<Vec<_>>(),
+        disable_ids[..2]
+    );
+}
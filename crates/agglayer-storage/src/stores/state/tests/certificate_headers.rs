@@ -0,0 +1,73 @@
+use agglayer_types::{Certificate, CertificateStatus, Height, ListDirection, NetworkId};
+use rstest::rstest;
+
+use super::store;
+use crate::stores::{state::StateStore, StateReader as _, StateWriter as _};
+
+fn insert_settled(store: &StateStore, network_id: u32, heights: impl IntoIterator<Item = u64>) {
+    for height in heights {
+        let certificate = Certificate::new_for_test(network_id.into(), Height::new(height));
+        store
+            .insert_certificate_header(&certificate, CertificateStatus::Settled)
+            .unwrap();
+    }
+}
+
+fn list(
+    store: &StateStore,
+    network_id: u32,
+    from_height: u64,
+    limit: usize,
+    direction: ListDirection,
+) -> Vec<u64> {
+    store
+        .list_certificate_headers(
+            NetworkId::new(network_id),
+            Height::new(from_height),
+            limit,
+            direction,
+        )
+        .unwrap()
+        .into_iter()
+        .inspect(|header| assert_eq!(header.network_id, NetworkId::new(network_id)))
+        .map(|header| header.height.as_u64())
+        .collect()
+}
+
+#[rstest]
+fn list_certificate_headers_stays_within_the_network(store: StateStore) {
+    insert_settled(&store, 1, 0..5);
+    insert_settled(&store, 2, 0..2);
+    insert_settled(&store, 3, 0..3);
+
+    assert_eq!(list(&store, 2, 0, 10, ListDirection::Ascending), [0, 1]);
+    assert_eq!(list(&store, 2, 10, 10, ListDirection::Descending), [1, 0]);
+    assert!(list(&store, 4, 0, 10, ListDirection::Ascending).is_empty());
+    assert!(list(&store, 0, 10, 10, ListDirection::Descending).is_empty());
+}
+
+#[rstest]
+fn list_certificate_headers_pages_in_both_directions(store: StateStore) {
+    insert_settled(&store, 1, 0..5);
+
+    assert_eq!(list(&store, 1, 0, 2, ListDirection::Ascending), [0, 1]);
+    assert_eq!(list(&store, 1, 2, 2, ListDirection::Ascending), [2, 3]);
+    assert_eq!(list(&store, 1, 4, 2, ListDirection::Ascending), [4]);
+    assert_eq!(list(&store, 1, 3, 2, ListDirection::Descending), [3, 2]);
+    assert_eq!(
+        list(&store, 1, u64::MAX, 2, ListDirection::Descending),
+        [4, 3]
+    );
+    assert!(list(&store, 1, 0, 0, ListDirection::Ascending).is_empty());
+}
+
+#[rstest]
+fn list_certificate_headers_skips_unsettled_certificates(store: StateStore) {
+    insert_settled(&store, 1, 0..2);
+    let pending = Certificate::new_for_test(1.into(), Height::new(2));
+    store
+        .insert_certificate_header(&pending, CertificateStatus::Pending)
+        .unwrap();
+
+    assert_eq!(list(&store, 1, 0, 10, ListDirection::Ascending), [0, 1]);
+}
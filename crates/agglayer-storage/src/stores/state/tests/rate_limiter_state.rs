@@ -0,0 +1,46 @@
+use std::time::{Duration, SystemTime};
+
+use agglayer_types::NetworkId;
+
+use crate::{
+    backup::BackupClient,
+    stores::{state::StateStore, RateLimiterStateReader as _, RateLimiterStateWriter as _},
+    tests::TempDBDir,
+};
+
+#[test]
+fn rate_limiter_events_are_stored_per_network_and_component() {
+    let tmp = TempDBDir::new();
+    let store = StateStore::new_with_path(tmp.path.as_path(), BackupClient::noop()).unwrap();
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let events = [start, start + Duration::from_millis(1_500)];
+
+    store
+        .set_rate_limiter_events(NetworkId::new(1), "send-certificate", &events)
+        .unwrap();
+
+    assert_eq!(
+        store
+            .get_rate_limiter_events(NetworkId::new(1), "send-certificate")
+            .unwrap(),
+        events
+    );
+    assert!(store
+        .get_rate_limiter_events(NetworkId::new(1), "send-tx")
+        .unwrap()
+        .is_empty());
+    assert!(store
+        .get_rate_limiter_events(NetworkId::new(2), "send-certificate")
+        .unwrap()
+        .is_empty());
+
+    store
+        .set_rate_limiter_events(NetworkId::new(1), "send-certificate", &events[1..])
+        .unwrap();
+    assert_eq!(
+        store
+            .get_rate_limiter_events(NetworkId::new(1), "send-certificate")
+            .unwrap(),
+        &events[1..]
+    );
+}
@@ -4,8 +4,9 @@ use std::{
 };
 
 use agglayer_types::{
-    Address, CertificateId, ClientError, ClientErrorType, Digest, Nonce, SettlementAttempt,
-    SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementTxHash, U256,
+    Address, CertificateId, ClientError, ClientErrorType, Digest, NetworkId, Nonce,
+    SettlementAttempt, SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementTxHash,
+    U256,
 };
 
 use crate::{
@@ -50,6 +51,10 @@ fn mk_settlement_job(seed: u8) -> SettlementJob {
         calldata: vec![seed, seed.wrapping_add(1)].into(),
         eth_value: U256::from_be_bytes([seed; 32]),
         gas_limit: u128::from_be_bytes([seed; 16]),
+        network_id: Some(NetworkId::new(seed as u32)),
+        tx_type: agglayer_types::SettlementTxType::Eip1559,
+        access_list: None,
+        batched_jobs: Vec::new(),
     }
 }
 
@@ -61,6 +66,7 @@ fn mk_settlement_attempt(seed: u64) -> SettlementAttempt {
         submission_time: SystemTime::UNIX_EPOCH + Duration::from_secs(seed),
         max_fee_per_gas: 30_000_000_000 + seed as u128,
         max_priority_fee_per_gas: 1_000_000_000 + seed as u128,
+        gas_oracle_inputs: None,
     }
 }
 
@@ -514,6 +520,61 @@ fn max_settlement_nonce_for_wallet_returns_highest_indexed_nonce() {
     );
 }
 
+#[test]
+fn max_settlement_nonce_for_wallet_skips_simulated_attempts() {
+    let (_tmp, _db, store) = setup_store();
+    let job_id = mk_job_id(409);
+    let wallet = Address::from([13; 20]);
+    let mut broadcast_attempt = mk_settlement_attempt(1);
+    let mut simulated_attempt = mk_settlement_attempt(2);
+    broadcast_attempt.sender_wallet = wallet;
+    broadcast_attempt.nonce = Nonce(20);
+    simulated_attempt.sender_wallet = wallet;
+    simulated_attempt.nonce = Nonce(21);
+
+    store
+        .insert_settlement_job(&job_id, &mk_settlement_job(44))
+        .expect("job insert must succeed");
+    store
+        .insert_settlement_attempt(&job_id, 1, &broadcast_attempt)
+        .expect("broadcast attempt insert must succeed");
+    store
+        .insert_settlement_attempt(&job_id, 2, &simulated_attempt)
+        .expect("simulated attempt insert must succeed");
+    store
+        .record_settlement_attempt_result(
+            &job_id,
+            2,
+            &SettlementAttemptResult::ClientError(ClientError::simulated_revert("nope")),
+        )
+        .expect("simulated revert result insert must succeed");
+
+    // The job is still live, but it no longer drives the unbroadcast nonce:
+    // reserving it would leave a gap once another attempt takes the next one.
+    assert_eq!(
+        store
+            .max_settlement_nonce_for_wallet(wallet)
+            .expect("max nonce lookup must succeed"),
+        Some(Nonce(20))
+    );
+
+    store
+        .insert_settlement_job_result(
+            &job_id,
+            &v0::SettlementJobResult::contract_call_success_for_test(44)
+                .try_into()
+                .expect("test tx result helper should be decodable"),
+        )
+        .expect("job result insert must succeed");
+
+    assert_eq!(
+        store
+            .max_settlement_nonce_for_wallet(wallet)
+            .expect("max nonce lookup must succeed"),
+        Some(Nonce(20))
+    );
+}
+
 #[test]
 fn get_settlement_job_returns_none_when_missing() {
     let (_tmp, _db, store) = setup_store();
@@ -28,6 +28,17 @@ mock! {
             network_id: &NetworkId,
         ) -> Result<Option<(CertificateId, Height)>, Error>;
 
+        fn count_pending_certificates_for_network(
+            &self,
+            network_id: NetworkId,
+        ) -> Result<usize, Error>;
+
+        fn list_pending_certificates_for_network(
+            &self,
+            network_id: NetworkId,
+            from_height: Height,
+        ) -> Result<Vec<Certificate>, Error>;
+
         fn get_current_pending_heights(
             &self,
         ) -> Result<Vec<(NetworkId, PendingCertificate)>, Error>;
@@ -84,6 +95,12 @@ mock! {
             certificate_id: &CertificateId,
         ) -> Result<(), Error>;
 
-
+        fn rollback_pending_certificates(
+            &self,
+            network_id: &NetworkId,
+            height: &Height,
+            certificate_id: &CertificateId,
+            removed_proofs: &[CertificateId],
+        ) -> Result<(), Error>;
     }
 }
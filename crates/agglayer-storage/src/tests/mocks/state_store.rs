@@ -1,7 +1,9 @@
 use agglayer_types::{
-    primitives::Digest, Address, Certificate, CertificateHeader, CertificateId, CertificateStatus,
-    EpochNumber, Height, LocalNetworkStateData, NetworkId, Nonce, SettlementAttempt,
-    SettlementAttemptResult, SettlementJob, SettlementJobId, SettlementJobResult, SettlementTxHash,
+    primitives::Digest, Address, AdminAuditEvent, AdminAuditEventId, Certificate,
+    CertificateHeader, CertificateId, CertificateStatus, EpochNumber, Height, ListDirection,
+    LocalNetworkStateData, NetworkId, Nonce, SettlementAttempt, SettlementAttemptResult,
+    SettlementJob, SettlementJobCancellation, SettlementJobId, SettlementJobResult,
+    SettlementTxHash,
 };
 use mockall::mock;
 
@@ -9,7 +11,8 @@ use crate::{
     columns::latest_settled_certificate_per_network::SettledCertificate,
     error::Error,
     stores::{
-        EditEvenIfCompleted, MetadataReader, MetadataWriter, NetworkInfoReader, SettlementReader,
+        AdminAuditReader, AdminAuditWriter, EditEvenIfCompleted, MetadataReader, MetadataWriter,
+        NetworkInfoReader, RateLimiterStateReader, RateLimiterStateWriter, SettlementReader,
         SettlementWriter, StateReader, StateWriter, UpdateEvenIfAlreadyPresent,
         UpdateStatusToCandidate,
     },
@@ -84,6 +87,23 @@ mock! {
             new_leaves: &[Digest],
         ) -> Result<(), Error>;
 
+        fn write_network_state_snapshot(
+            &self,
+            network_id: &NetworkId,
+            height: &Height,
+            state: &LocalNetworkStateData,
+        ) -> Result<(), Error>;
+
+        fn rollback_network_state(
+            &self,
+            network_id: &NetworkId,
+            settled: &SettledCertificate,
+            state: &LocalNetworkStateData,
+            leaves: &[Digest],
+            removed_certificates: &[CertificateId],
+            cancelled_settlement_jobs: &[SettlementJobId],
+        ) -> Result<(), Error>;
+
         fn disable_network(
             &self,
             network_id: &NetworkId,
@@ -117,12 +137,38 @@ mock! {
             network_id: NetworkId,
             height: Height,
         ) -> Result<Option<CertificateHeader>, Error>;
+
+        fn list_certificate_headers(
+            &self,
+            network_id: NetworkId,
+            from_height: Height,
+            limit: usize,
+            direction: ListDirection,
+        ) -> Result<Vec<CertificateHeader>, Error>;
         fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error>;
 
         fn read_local_network_state(
             &self,
             network_id: NetworkId,
         ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+        fn read_local_network_state_at(
+            &self,
+            network_id: NetworkId,
+            height: Height,
+        ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+        fn has_network_state_snapshot(
+            &self,
+            network_id: NetworkId,
+            height: Height,
+        ) -> Result<bool, Error>;
+
+        fn read_local_exit_tree_leaves(
+            &self,
+            network_id: NetworkId,
+            leaves: std::ops::Range<u32>,
+        ) -> Result<Option<Vec<Digest>>, Error>;
     }
 
     impl SettlementReader for StateStore {
@@ -143,6 +189,11 @@ mock! {
             settlement_job_id: &SettlementJobId,
         ) -> Result<Option<SettlementJobResult>, Error>;
 
+        fn get_settlement_job_cancellation(
+            &self,
+            settlement_job_id: &SettlementJobId,
+        ) -> Result<Option<SettlementJobCancellation>, Error>;
+
         fn list_settlement_attempts(
             &self,
             settlement_job_id: &SettlementJobId,
@@ -220,4 +271,38 @@ mock! {
             settlement_job_id: &SettlementJobId,
         ) -> Result<(), Error>;
     }
+
+    impl AdminAuditReader for StateStore {
+        fn list_admin_audit_events(
+            &self,
+            from: std::time::SystemTime,
+            to: std::time::SystemTime,
+            method: Option<&str>,
+            limit: usize,
+        ) -> Result<Vec<(AdminAuditEventId, AdminAuditEvent)>, Error>;
+    }
+
+    impl AdminAuditWriter for StateStore {
+        fn append_admin_audit_event(
+            &self,
+            event: &AdminAuditEvent,
+        ) -> Result<AdminAuditEventId, Error>;
+    }
+
+    impl RateLimiterStateReader for StateStore {
+        fn get_rate_limiter_events(
+            &self,
+            network_id: NetworkId,
+            component: &str,
+        ) -> Result<Vec<std::time::SystemTime>, Error>;
+    }
+
+    impl RateLimiterStateWriter for StateStore {
+        fn set_rate_limiter_events(
+            &self,
+            network_id: NetworkId,
+            component: &str,
+            events: &[std::time::SystemTime],
+        ) -> Result<(), Error>;
+    }
 }
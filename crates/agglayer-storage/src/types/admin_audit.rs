@@ -0,0 +1,194 @@
+use std::io;
+
+use agglayer_types::{
+    AdminAuditCaller, AdminAuditEvent, AdminAuditEventId, AdminAuditLiveTask, AdminAuditOutcome,
+    NetworkId,
+};
+
+use crate::{
+    schema::{Codec, CodecError},
+    types::{generated::agglayer::storage::v0, settlement::compat::Error},
+};
+
+pub type Key = AdminAuditEventId;
+
+pub type Value = v0::AdminAuditEvent;
+
+impl Codec for AdminAuditEventId {
+    fn encode_into<W: io::Write>(&self, mut writer: W) -> Result<(), CodecError> {
+        writer.write_all(&self.to_be_bytes())?;
+
+        Ok(())
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, CodecError> {
+        Ok(Self::from(crate::schema::decode_u128_be(
+            buf,
+            "admin audit event id",
+        )?))
+    }
+}
+
+crate::schema::impl_codec_using_protobuf_for!(Value);
+
+impl From<&AdminAuditEvent> for v0::AdminAuditEvent {
+    fn from(value: &AdminAuditEvent) -> Self {
+        use v0::admin_audit_event::Outcome;
+
+        let outcome = match &value.outcome {
+            AdminAuditOutcome::Applied { live_task } => Outcome::Applied(v0::AdminAuditApplied {
+                live_task: live_task.map_or(v0::AdminAuditLiveTask::Unspecified, Into::into) as i32,
+            }),
+            AdminAuditOutcome::Failed { error } => Outcome::Failed(v0::AdminAuditFailed {
+                error: error.clone(),
+            }),
+        };
+
+        Self {
+            recorded_at: Some(prost_types::Timestamp::from(value.recorded_at)),
+            caller: Some(v0::AdminAuditCaller {
+                name: value.caller.name.clone(),
+                role: value.caller.role.clone(),
+            }),
+            method: value.method.clone(),
+            network_id: value.network_id.map(|network_id| network_id.to_u32()),
+            params_json: value.params.clone(),
+            before_json: value.before.clone(),
+            after_json: value.after.clone(),
+            outcome: Some(outcome),
+        }
+    }
+}
+
+impl TryFrom<v0::AdminAuditEvent> for AdminAuditEvent {
+    type Error = Error;
+
+    fn try_from(value: v0::AdminAuditEvent) -> Result<Self, Self::Error> {
+        use v0::admin_audit_event::Outcome;
+
+        let recorded_at = value
+            .recorded_at
+            .ok_or_else(|| Error::missing_field("recorded_at"))?
+            .try_into()
+            .map_err(|error: prost_types::TimestampError| {
+                Error::from(error).inside_field("recorded_at")
+            })?;
+        let caller = value
+            .caller
+            .map(|caller| AdminAuditCaller {
+                name: caller.name,
+                role: caller.role,
+            })
+            .ok_or_else(|| Error::missing_field("caller"))?;
+        let outcome = match value.outcome {
+            None => return Err(Error::missing_field("outcome")),
+            Some(Outcome::Applied(applied)) => AdminAuditOutcome::Applied {
+                live_task: match v0::AdminAuditLiveTask::try_from(applied.live_task) {
+                    Ok(v0::AdminAuditLiveTask::Unspecified) => None,
+                    Ok(v0::AdminAuditLiveTask::Queued) => Some(AdminAuditLiveTask::Queued),
+                    Ok(v0::AdminAuditLiveTask::Absent) => Some(AdminAuditLiveTask::Absent),
+                    Ok(v0::AdminAuditLiveTask::NotifyFailed) => {
+                        Some(AdminAuditLiveTask::NotifyFailed)
+                    }
+                    Err(_) => {
+                        return Err(Error::invalid_data(format!(
+                            "unknown live task notification {}",
+                            applied.live_task
+                        ))
+                        .inside_field("live_task")
+                        .inside_field("outcome"))
+                    }
+                },
+            },
+            Some(Outcome::Failed(failed)) => AdminAuditOutcome::Failed {
+                error: failed.error,
+            },
+        };
+
+        Ok(Self {
+            recorded_at,
+            caller,
+            method: value.method,
+            network_id: value.network_id.map(NetworkId::new),
+            params: value.params_json,
+            before: value.before_json,
+            after: value.after_json,
+            outcome,
+        })
+    }
+}
+
+impl From<AdminAuditLiveTask> for v0::AdminAuditLiveTask {
+    fn from(value: AdminAuditLiveTask) -> Self {
+        match value {
+            AdminAuditLiveTask::Queued => Self::Queued,
+            AdminAuditLiveTask::Absent => Self::Absent,
+            AdminAuditLiveTask::NotifyFailed => Self::NotifyFailed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use prost::Message as _;
+
+    use super::*;
+
+    mod key {
+        use super::Key;
+
+        crate::types::codec_tests::codec_tests!(Key::from(0x0102030405060708090a0b0c0d0e0f10_u128));
+    }
+
+    fn mk_event(outcome: AdminAuditOutcome) -> AdminAuditEvent {
+        AdminAuditEvent {
+            recorded_at: SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123),
+            caller: AdminAuditCaller {
+                name: "oncall".to_string(),
+                role: Some("operator".to_string()),
+            },
+            method: "admin_markSettlementAttemptDefinitelyFailed".to_string(),
+            network_id: Some(NetworkId::new(7)),
+            params: r#"{"jobId":"01ARZ3NDEKTSV4RRFFQ69G5FAV","attemptNumber":1}"#.to_string(),
+            before: Some(r#"{"attemptResult":null}"#.to_string()),
+            after: None,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn admin_audit_event_roundtrips_through_storage() {
+        for outcome in [
+            AdminAuditOutcome::Applied { live_task: None },
+            AdminAuditOutcome::Applied {
+                live_task: Some(AdminAuditLiveTask::NotifyFailed),
+            },
+            AdminAuditOutcome::Failed {
+                error: "not found".to_string(),
+            },
+        ] {
+            let event = mk_event(outcome);
+
+            let encoded = v0::AdminAuditEvent::from(&event).encode_to_vec();
+            let decoded =
+                AdminAuditEvent::try_from(v0::AdminAuditEvent::decode(encoded.as_slice()).unwrap())
+                    .unwrap();
+
+            assert_eq!(decoded, event);
+        }
+    }
+
+    #[test]
+    fn admin_audit_event_without_outcome_is_rejected() {
+        let mut stored =
+            v0::AdminAuditEvent::from(&mk_event(AdminAuditOutcome::Applied { live_task: None }));
+        stored.outcome = None;
+
+        assert_eq!(
+            AdminAuditEvent::try_from(stored),
+            Err(Error::missing_field("outcome"))
+        );
+    }
+}
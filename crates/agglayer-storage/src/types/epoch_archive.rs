@@ -0,0 +1,79 @@
+use std::{collections::BTreeMap, io};
+
+use agglayer_types::{CertificateIndex, EpochNumber, Height, NetworkId};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Codec, CodecError};
+
+/// Position of a certificate, or of its proof, in the epoch archive.
+///
+/// Encoded big-endian so the rows of an epoch are contiguous and ordered by
+/// certificate index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub(crate) epoch_number: u64,
+    pub(crate) index: u64,
+}
+
+impl Key {
+    pub(crate) const PREFIX_LEN: usize = crate::schema::U64_LEN;
+    pub(crate) const LEN: usize = Self::PREFIX_LEN + crate::schema::U64_LEN;
+
+    pub(crate) fn new(epoch_number: EpochNumber, index: CertificateIndex) -> Self {
+        Self {
+            epoch_number: epoch_number.as_u64(),
+            index: index.as_u64(),
+        }
+    }
+}
+
+impl Codec for Key {
+    fn encode_into<W: io::Write>(&self, mut writer: W) -> Result<(), CodecError> {
+        writer.write_all(&self.epoch_number.to_be_bytes())?;
+        writer.write_all(&self.index.to_be_bytes())?;
+
+        Ok(())
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, CodecError> {
+        let key = crate::schema::fixed_bytes::<{ Self::LEN }>(buf, "epoch archive key")?;
+        let (epoch_number, index) = key.split_at(Self::PREFIX_LEN);
+
+        Ok(Self {
+            epoch_number: crate::schema::decode_u64_be(epoch_number, "epoch number")?,
+            index: crate::schema::decode_u64_be(index, "certificate index")?,
+        })
+    }
+}
+
+/// Record of an epoch moved to the archive database.
+///
+/// Written in the same batch as the certificates and proofs of the epoch, so
+/// its presence means the epoch is fully archived.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedEpoch {
+    pub(crate) certificates: u64,
+    pub(crate) start_checkpoint: BTreeMap<NetworkId, Height>,
+    pub(crate) end_checkpoint: BTreeMap<NetworkId, Height>,
+}
+
+crate::schema::impl_codec_using_bincode_for!(ArchivedEpoch);
+
+#[cfg(test)]
+mod tests {
+    use super::Key;
+
+    impl<'a> arbitrary::Arbitrary<'a> for Key {
+        fn arbitrary(input: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(Self {
+                epoch_number: <u64 as arbitrary::Arbitrary>::arbitrary(input)?,
+                index: <u64 as arbitrary::Arbitrary>::arbitrary(input)?,
+            })
+        }
+    }
+
+    crate::types::codec_tests::codec_tests!(Key {
+        epoch_number: 0x0102030405060708,
+        index: 0x1112131415161718,
+    });
+}
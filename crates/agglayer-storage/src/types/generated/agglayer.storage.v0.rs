@@ -1,5 +1,107 @@
 // @generated
 // This file is @generated by prost-build.
+/// A mutation performed through the admin RPC.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminAuditEvent {
+    /// When the mutation completed.
+    #[prost(message, optional, tag="1")]
+    pub recorded_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// Who performed the mutation.
+    #[prost(message, optional, tag="2")]
+    pub caller: ::core::option::Option<AdminAuditCaller>,
+    /// JSON-RPC method of the mutation.
+    #[prost(string, tag="3")]
+    pub method: ::prost::alloc::string::String,
+    /// Network whose state the mutation touched, if known.
+    #[prost(uint32, optional, tag="4")]
+    pub network_id: ::core::option::Option<u32>,
+    /// Call parameters, as JSON.
+    #[prost(string, tag="5")]
+    pub params_json: ::prost::alloc::string::String,
+    /// Touched record before the mutation, as JSON, if it was read.
+    #[prost(string, optional, tag="6")]
+    pub before_json: ::core::option::Option<::prost::alloc::string::String>,
+    /// Touched record after the mutation, as JSON, if it was read.
+    #[prost(string, optional, tag="7")]
+    pub after_json: ::core::option::Option<::prost::alloc::string::String>,
+    /// Result of the mutation.
+    #[prost(oneof="admin_audit_event::Outcome", tags="8, 9")]
+    pub outcome: ::core::option::Option<admin_audit_event::Outcome>,
+}
+/// Nested message and enum types in `AdminAuditEvent`.
+pub mod admin_audit_event {
+    /// Result of the mutation.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Outcome {
+        /// The mutation was applied.
+        #[prost(message, tag="8")]
+        Applied(super::AdminAuditApplied),
+        /// The mutation was refused or failed.
+        #[prost(message, tag="9")]
+        Failed(super::AdminAuditFailed),
+    }
+}
+/// Caller of an admin mutation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminAuditCaller {
+    /// Token name or JWT subject of the caller.
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Role the caller was authenticated with, if any.
+    #[prost(string, optional, tag="2")]
+    pub role: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Outcome of an applied admin mutation.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AdminAuditApplied {
+    /// How the live settlement task was told about the mutation.
+    #[prost(enumeration="AdminAuditLiveTask", tag="1")]
+    pub live_task: i32,
+}
+/// Outcome of a refused or failed admin mutation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdminAuditFailed {
+    /// Error message returned to the caller.
+    #[prost(string, tag="1")]
+    pub error: ::prost::alloc::string::String,
+}
+/// How the live settlement task of a job was told about a mutation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AdminAuditLiveTask {
+    /// The mutation does not notify a settlement task.
+    Unspecified = 0,
+    /// A reload command was queued for the live task.
+    Queued = 1,
+    /// The job had no live task.
+    Absent = 2,
+    /// The live task could not be notified.
+    NotifyFailed = 3,
+}
+impl AdminAuditLiveTask {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ADMIN_AUDIT_LIVE_TASK_UNSPECIFIED",
+            Self::Queued => "ADMIN_AUDIT_LIVE_TASK_QUEUED",
+            Self::Absent => "ADMIN_AUDIT_LIVE_TASK_ABSENT",
+            Self::NotifyFailed => "ADMIN_AUDIT_LIVE_TASK_NOTIFY_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ADMIN_AUDIT_LIVE_TASK_UNSPECIFIED" => Some(Self::Unspecified),
+            "ADMIN_AUDIT_LIVE_TASK_QUEUED" => Some(Self::Queued),
+            "ADMIN_AUDIT_LIVE_TASK_ABSENT" => Some(Self::Absent),
+            "ADMIN_AUDIT_LIVE_TASK_NOTIFY_FAILED" => Some(Self::NotifyFailed),
+            _ => None,
+        }
+    }
+}
 /// ECDSA signature with recovery byte (65 bytes).
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Signature {
@@ -811,6 +913,13 @@ impl DisabledBy {
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MigrationRecord {
 }
+/// Events recorded by one component of the rate limiter of a network.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RateLimiterEvents {
+    /// When each event was recorded, oldest first.
+    #[prost(message, repeated, tag="1")]
+    pub recorded_at: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+}
 /// Settlement job data.
 ///
 /// ----- Transaction details -----
@@ -828,6 +937,95 @@ pub struct SettlementJob {
     /// Gas limit for each settlement attempt.
     #[prost(message, optional, tag="4")]
     pub gas_limit: ::core::option::Option<Uint128>,
+    // ----- Scheduling details -----
+
+    /// Network whose certificate is settled by this job, if any.
+    /// Used to pick the settlement wallet for the job.
+    #[prost(uint32, optional, tag="5")]
+    pub network_id: ::core::option::Option<u32>,
+    // ----- Envelope details -----
+
+    /// Envelope of the settlement transactions.
+    /// Jobs stored without one use EIP-1559.
+    /// EIP-4844 blob transactions are not supported: settlement data is calldata.
+    #[prost(oneof="settlement_job::TxType", tags="6, 7")]
+    pub tx_type: ::core::option::Option<settlement_job::TxType>,
+    /// Access list attached to every settlement transaction, if any.
+    #[prost(message, optional, tag="8")]
+    pub access_list: ::core::option::Option<AccessList>,
+    // ----- Batching details -----
+
+    /// Settlement jobs whose calls this job settles together, in call order.
+    /// Empty for a job settling its own call.
+    #[prost(message, repeated, tag="9")]
+    pub batched_jobs: ::prost::alloc::vec::Vec<Uint128>,
+}
+/// Nested message and enum types in `SettlementJob`.
+pub mod settlement_job {
+    // ----- Envelope details -----
+
+    /// Envelope of the settlement transactions.
+    /// Jobs stored without one use EIP-1559.
+    /// EIP-4844 blob transactions are not supported: settlement data is calldata.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum TxType {
+        /// EIP-1559 dynamic-fee transaction.
+        #[prost(message, tag="6")]
+        Eip1559(super::Eip1559TxType),
+        /// EIP-7702 set-code transaction.
+        #[prost(message, tag="7")]
+        Eip7702(super::Eip7702TxType),
+    }
+}
+/// EIP-1559 settlement transaction envelope.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Eip1559TxType {
+}
+/// EIP-7702 settlement transaction envelope.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Eip7702TxType {
+    /// Signed authorizations carried by every settlement transaction.
+    #[prost(message, repeated, tag="1")]
+    pub authorization_list: ::prost::alloc::vec::Vec<SignedAuthorization>,
+}
+/// Signed EIP-7702 authorization.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignedAuthorization {
+    /// Chain the authorization is valid on, 0 for any chain.
+    #[prost(message, optional, tag="1")]
+    pub chain_id: ::core::option::Option<Uint256>,
+    /// Address of the code to delegate to.
+    #[prost(message, optional, tag="2")]
+    pub address: ::core::option::Option<Address>,
+    /// Nonce of the authorizing account.
+    #[prost(uint64, tag="3")]
+    pub nonce: u64,
+    /// Parity of the signature `y` coordinate.
+    #[prost(uint32, tag="4")]
+    pub y_parity: u32,
+    /// Signature `r` value.
+    #[prost(message, optional, tag="5")]
+    pub r: ::core::option::Option<Uint256>,
+    /// Signature `s` value.
+    #[prost(message, optional, tag="6")]
+    pub s: ::core::option::Option<Uint256>,
+}
+/// EIP-2930 access list.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccessList {
+    /// Accessed addresses and their storage slots.
+    #[prost(message, repeated, tag="1")]
+    pub items: ::prost::alloc::vec::Vec<AccessListItem>,
+}
+/// Storage slots accessed in one account.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccessListItem {
+    /// Accessed account.
+    #[prost(message, optional, tag="1")]
+    pub address: ::core::option::Option<Address>,
+    /// Accessed storage slots (32 bytes each).
+    #[prost(bytes="bytes", repeated, tag="2")]
+    pub storage_keys: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
 /// Result of one settlement attempt.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -899,6 +1097,19 @@ pub struct ContractCallResult {
     /// Transaction hash of the settlement transaction.
     #[prost(message, optional, tag="5")]
     pub tx_hash: ::core::option::Option<TxHash>,
+    /// Gas paid for the transaction, if it reached L1 and the cost was recorded.
+    #[prost(message, optional, tag="6")]
+    pub gas_cost: ::core::option::Option<SettlementGasCost>,
+}
+/// Gas paid by a settlement transaction, from its receipt.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettlementGasCost {
+    /// Gas used by the transaction.
+    #[prost(uint64, tag="1")]
+    pub gas_used: u64,
+    /// Price paid per unit of gas, base fee plus priority fee.
+    #[prost(message, optional, tag="2")]
+    pub effective_gas_price: ::core::option::Option<Uint128>,
 }
 /// Additional metadata about the call outcome.
 ///
@@ -938,6 +1149,60 @@ pub struct SettlementAttempt {
     /// Timestamp at which the attempt was submitted to L1.
     #[prost(message, optional, tag="6")]
     pub submission_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Fee estimate the transaction was priced from, if recorded.
+    #[prost(message, optional, tag="7")]
+    pub gas_oracle_inputs: ::core::option::Option<GasOracleInputs>,
+}
+/// Fee estimate returned by a gas oracle, along with the data it was derived
+/// from.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasOracleInputs {
+    /// Oracle the estimate comes from.
+    #[prost(oneof="gas_oracle_inputs::Source", tags="1, 2, 3")]
+    pub source: ::core::option::Option<gas_oracle_inputs::Source>,
+    /// Estimated max fee per gas.
+    #[prost(message, optional, tag="4")]
+    pub max_fee_per_gas: ::core::option::Option<Uint128>,
+    /// Estimated max priority fee per gas.
+    #[prost(message, optional, tag="5")]
+    pub max_priority_fee_per_gas: ::core::option::Option<Uint128>,
+}
+/// Nested message and enum types in `GasOracleInputs`.
+pub mod gas_oracle_inputs {
+    /// Oracle the estimate comes from.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        /// The L1 provider's EIP-1559 fee estimation.
+        #[prost(message, tag="1")]
+        Provider(super::ProviderGasOracle),
+        /// Percentile of the priority fees paid over recent blocks.
+        #[prost(message, tag="2")]
+        FeeHistory(super::FeeHistoryGasOracle),
+        /// Fees fixed in the configuration.
+        #[prost(message, tag="3")]
+        Fixed(super::FixedGasOracle),
+    }
+}
+/// The L1 provider's EIP-1559 fee estimation.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProviderGasOracle {
+}
+/// `eth_feeHistory` percentile oracle.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeHistoryGasOracle {
+    /// Number of blocks the priority fees were sampled over.
+    #[prost(uint64, tag="1")]
+    pub block_count: u64,
+    /// Percentile of the priority fees paid in each block.
+    #[prost(uint32, tag="2")]
+    pub reward_percentile: u32,
+    /// Base fee of the next block.
+    #[prost(message, optional, tag="3")]
+    pub base_fee_per_gas: ::core::option::Option<Uint128>,
+}
+/// Fees fixed in the configuration.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FixedGasOracle {
 }
 /// Type of client error.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -951,6 +1216,9 @@ pub enum ClientErrorType {
     SettlementSucceededElsewhere = 2,
     /// An administrator asserted that this attempt will never land on L1.
     AbandonedByAdmin = 3,
+    /// The pre-flight simulation of the attempt reverted, so it was never
+    /// broadcast.
+    SimulatedRevert = 5,
 }
 impl ClientErrorType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -963,6 +1231,7 @@ impl ClientErrorType {
             Self::NonceAlreadyUsed => "CLIENT_ERROR_TYPE_NONCE_ALREADY_USED",
             Self::SettlementSucceededElsewhere => "CLIENT_ERROR_TYPE_SETTLEMENT_SUCCEEDED_ELSEWHERE",
             Self::AbandonedByAdmin => "CLIENT_ERROR_TYPE_ABANDONED_BY_ADMIN",
+            Self::SimulatedRevert => "CLIENT_ERROR_TYPE_SIMULATED_REVERT",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -972,6 +1241,7 @@ impl ClientErrorType {
             "CLIENT_ERROR_TYPE_NONCE_ALREADY_USED" => Some(Self::NonceAlreadyUsed),
             "CLIENT_ERROR_TYPE_SETTLEMENT_SUCCEEDED_ELSEWHERE" => Some(Self::SettlementSucceededElsewhere),
             "CLIENT_ERROR_TYPE_ABANDONED_BY_ADMIN" => Some(Self::AbandonedByAdmin),
+            "CLIENT_ERROR_TYPE_SIMULATED_REVERT" => Some(Self::SimulatedRevert),
             _ => None,
         }
     }
@@ -1009,4 +1279,140 @@ impl ContractCallOutcome {
         }
     }
 }
+/// A frame of a portable snapshot of the stores.
+///
+/// A snapshot file is a magic number followed by length-delimited frames: a
+/// header, the rows of the stores, then a trailer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotFrame {
+    /// The content of the frame.
+    #[prost(oneof="snapshot_frame::Frame", tags="1, 2, 3")]
+    pub frame: ::core::option::Option<snapshot_frame::Frame>,
+}
+/// Nested message and enum types in `SnapshotFrame`.
+pub mod snapshot_frame {
+    /// The content of the frame.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Frame {
+        /// The first frame of the snapshot.
+        #[prost(message, tag="1")]
+        Header(super::SnapshotHeader),
+        /// A row of one of the stores.
+        #[prost(message, tag="2")]
+        Row(super::SnapshotRow),
+        /// The last frame of the snapshot.
+        #[prost(message, tag="3")]
+        Trailer(super::SnapshotTrailer),
+    }
+}
+/// Describes the snapshot, to check it before importing the rows.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotHeader {
+    /// Version of the snapshot format.
+    #[prost(uint32, tag="1")]
+    pub format_version: u32,
+    /// When the snapshot was taken.
+    #[prost(message, optional, tag="2")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// The schema of each store of the snapshot.
+    #[prost(message, repeated, tag="3")]
+    pub stores: ::prost::alloc::vec::Vec<SnapshotStoreSchema>,
+    /// The settled state of every network, checked against the imported rows.
+    #[prost(message, repeated, tag="4")]
+    pub networks: ::prost::alloc::vec::Vec<SnapshotNetworkState>,
+}
+/// The schema of a store.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SnapshotStoreSchema {
+    /// The store.
+    #[prost(enumeration="SnapshotStore", tag="1")]
+    pub store: i32,
+    /// The number of migration steps recorded in the store.
+    #[prost(uint32, tag="2")]
+    pub migration_steps: u32,
+}
+/// The settled state of a network.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotNetworkState {
+    /// The network.
+    #[prost(uint32, tag="1")]
+    pub network_id: u32,
+    /// The latest settled certificate of the network.
+    #[prost(message, optional, tag="2")]
+    pub certificate_id: ::core::option::Option<SettledCertificateId>,
+    /// The height of the latest settled certificate.
+    #[prost(uint64, tag="3")]
+    pub height: u64,
+    /// The local exit root of the network.
+    #[prost(message, optional, tag="4")]
+    pub local_exit_root: ::core::option::Option<SettledLocalExitRoot>,
+    /// The leaf count of the local exit tree.
+    #[prost(message, optional, tag="5")]
+    pub let_leaf_count: ::core::option::Option<SettledLocalExitTreeLeafCount>,
+    /// The root of the local balance tree.
+    #[prost(bytes="bytes", tag="6")]
+    pub balance_root: ::prost::bytes::Bytes,
+    /// The root of the nullifier tree.
+    #[prost(bytes="bytes", tag="7")]
+    pub nullifier_root: ::prost::bytes::Bytes,
+}
+/// A raw row of a store.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotRow {
+    /// The store of the row.
+    #[prost(enumeration="SnapshotStore", tag="1")]
+    pub store: i32,
+    /// The column family of the row.
+    #[prost(string, tag="2")]
+    pub cf: ::prost::alloc::string::String,
+    /// The encoded key.
+    #[prost(bytes="bytes", tag="3")]
+    pub key: ::prost::bytes::Bytes,
+    /// The encoded value.
+    #[prost(bytes="bytes", tag="4")]
+    pub value: ::prost::bytes::Bytes,
+}
+/// Closes the snapshot.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotTrailer {
+    /// The number of rows of the snapshot.
+    #[prost(uint64, tag="1")]
+    pub rows: u64,
+    /// SHA-256 of the snapshot up to this frame.
+    #[prost(bytes="bytes", tag="2")]
+    pub sha256: ::prost::bytes::Bytes,
+}
+/// The stores a snapshot is made of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SnapshotStore {
+    /// Unspecified store. This should never be used.
+    Unspecified = 0,
+    /// The state store, metadata included.
+    State = 1,
+    /// The pending store.
+    Pending = 2,
+}
+impl SnapshotStore {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SNAPSHOT_STORE_UNSPECIFIED",
+            Self::State => "SNAPSHOT_STORE_STATE",
+            Self::Pending => "SNAPSHOT_STORE_PENDING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SNAPSHOT_STORE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SNAPSHOT_STORE_STATE" => Some(Self::State),
+            "SNAPSHOT_STORE_PENDING" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
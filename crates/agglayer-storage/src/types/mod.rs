@@ -4,12 +4,16 @@ use agglayer_types::{
 };
 use serde::{Deserialize, Serialize};
 
+pub(crate) mod admin_audit;
 mod certificate;
 pub(crate) use certificate::LegacyCertificate;
 pub(crate) mod disabled_network;
+pub(crate) mod epoch_archive;
 pub mod generated; // TODO: remove "pub" once implementation of storage is completed
 pub(crate) mod network_info;
+pub(crate) mod network_state_snapshot;
 pub(crate) mod proof;
+pub(crate) mod rate_limiter_state;
 pub(crate) mod settlement;
 
 #[cfg(test)]
@@ -68,6 +72,7 @@ crate::schema::impl_codec_using_bincode_for!(
     CertificateIndex,
     CertificateHeader,
     Digest,
+    EpochNumber,
     Height,
     MetadataKey,
     MetadataValue,
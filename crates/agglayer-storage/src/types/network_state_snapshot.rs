@@ -0,0 +1,83 @@
+use std::io;
+
+use agglayer_types::{primitives::Digest, Height, NetworkId};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Codec, CodecError};
+
+/// Height of a settled certificate of a network.
+///
+/// Encoded big-endian so the snapshots of a network are contiguous and
+/// ordered by height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub(crate) network_id: u32,
+    pub(crate) height: u64,
+}
+
+impl Key {
+    pub(crate) const PREFIX_LEN: usize = crate::schema::U32_LEN;
+    pub(crate) const LEN: usize = Self::PREFIX_LEN + crate::schema::U64_LEN;
+
+    pub(crate) fn new(network_id: NetworkId, height: Height) -> Self {
+        Self {
+            network_id: network_id.to_u32(),
+            height: height.as_u64(),
+        }
+    }
+}
+
+impl Codec for Key {
+    fn encode_into<W: io::Write>(&self, mut writer: W) -> Result<(), CodecError> {
+        writer.write_all(&self.network_id.to_be_bytes())?;
+        writer.write_all(&self.height.to_be_bytes())?;
+
+        Ok(())
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, CodecError> {
+        let key = crate::schema::fixed_bytes::<{ Self::LEN }>(buf, "network state snapshot key")?;
+        let (network_id, height) = key.split_at(Self::PREFIX_LEN);
+
+        Ok(Self {
+            network_id: crate::schema::decode_u32_be(network_id, "network id")?,
+            height: crate::schema::decode_u64_be(height, "height")?,
+        })
+    }
+}
+
+/// Roots of the local network state once a certificate got settled.
+///
+/// The nodes of the balance and nullifier trees are addressed by their hash
+/// and never overwritten, so the trees are read back from the children of
+/// their roots. The local exit tree is read back from its frontier.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkStateSnapshot {
+    pub(crate) leaf_count: u32,
+    pub(crate) frontier: [Digest; 32],
+    /// The children of the root of the balance tree.
+    pub(crate) balance_root: (Digest, Digest),
+    /// The children of the root of the nullifier tree.
+    pub(crate) nullifier_root: (Digest, Digest),
+}
+
+crate::schema::impl_codec_using_bincode_for!(NetworkStateSnapshot);
+
+#[cfg(test)]
+mod tests {
+    use super::Key;
+
+    impl<'a> arbitrary::Arbitrary<'a> for Key {
+        fn arbitrary(input: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(Self {
+                network_id: <u32 as arbitrary::Arbitrary>::arbitrary(input)?,
+                height: <u64 as arbitrary::Arbitrary>::arbitrary(input)?,
+            })
+        }
+    }
+
+    crate::types::codec_tests::codec_tests!(Key {
+        network_id: 0x01020304,
+        height: 0x1112131415161718,
+    });
+}
@@ -0,0 +1,125 @@
+use std::{io, time::SystemTime};
+
+use agglayer_types::NetworkId;
+
+use crate::{
+    schema::{Codec, CodecError},
+    types::{generated::agglayer::storage::v0, settlement::compat::Error},
+};
+
+/// Key of the events of one rate limiter component of a network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub(crate) network_id: u32,
+    pub(crate) component: String,
+}
+
+impl Key {
+    pub(crate) const NETWORK_ID_LEN: usize = crate::schema::U32_LEN;
+
+    pub(crate) fn new(network_id: NetworkId, component: &str) -> Self {
+        Self {
+            network_id: network_id.to_u32(),
+            component: component.to_owned(),
+        }
+    }
+}
+
+pub type Value = v0::RateLimiterEvents;
+
+impl Codec for Key {
+    fn encode_into<W: io::Write>(&self, mut writer: W) -> Result<(), CodecError> {
+        writer.write_all(&self.network_id.to_be_bytes())?;
+        writer.write_all(self.component.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, CodecError> {
+        if buf.len() < Key::NETWORK_ID_LEN {
+            return Err(CodecError::Conversion(format!(
+                "rate limiter state key must be at least {} bytes long, got {}",
+                Key::NETWORK_ID_LEN,
+                buf.len()
+            )));
+        }
+        let (network_id, component) = buf.split_at(Key::NETWORK_ID_LEN);
+        let network_id = crate::schema::decode_u32_be(network_id, "rate limiter state network id")?;
+        let component = String::from_utf8(component.to_vec()).map_err(|error| {
+            CodecError::Conversion(format!("invalid rate limiter component name: {error}"))
+        })?;
+
+        Ok(Self {
+            network_id,
+            component,
+        })
+    }
+}
+
+crate::schema::impl_codec_using_protobuf_for!(Value);
+
+impl From<&[SystemTime]> for v0::RateLimiterEvents {
+    fn from(events: &[SystemTime]) -> Self {
+        Self {
+            recorded_at: events
+                .iter()
+                .map(|recorded_at| prost_types::Timestamp::from(*recorded_at))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<v0::RateLimiterEvents> for Vec<SystemTime> {
+    type Error = Error;
+
+    fn try_from(value: v0::RateLimiterEvents) -> Result<Self, Self::Error> {
+        value
+            .recorded_at
+            .into_iter()
+            .map(|recorded_at| {
+                SystemTime::try_from(recorded_at)
+                    .map_err(|error| Error::from(error).inside_field("recorded_at"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use prost::Message as _;
+
+    use super::*;
+
+    mod key {
+        use super::Key;
+
+        impl<'a> arbitrary::Arbitrary<'a> for Key {
+            fn arbitrary(input: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self {
+                    network_id: <u32 as arbitrary::Arbitrary>::arbitrary(input)?,
+                    component: <String as arbitrary::Arbitrary>::arbitrary(input)?,
+                })
+            }
+        }
+
+        crate::types::codec_tests::codec_tests!(Key {
+            network_id: 0x01020304,
+            component: "send-certificate".to_string(),
+        });
+    }
+
+    #[test]
+    fn events_roundtrip_through_storage() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let events = [start, start + Duration::from_nanos(1_500)];
+
+        let encoded = v0::RateLimiterEvents::from(&events[..]).encode_to_vec();
+        let decoded =
+            Vec::<SystemTime>::try_from(v0::RateLimiterEvents::decode(encoded.as_slice()).unwrap())
+                .unwrap();
+
+        assert_eq!(decoded, events);
+    }
+}
@@ -10,6 +10,7 @@ impl From<&ClientErrorType> for v0::ClientErrorType {
             ClientErrorType::NonceAlreadyUsed => Self::NonceAlreadyUsed,
             ClientErrorType::SettlementSucceededElsewhere => Self::SettlementSucceededElsewhere,
             ClientErrorType::AbandonedByAdmin => Self::AbandonedByAdmin,
+            ClientErrorType::SimulatedRevert => Self::SimulatedRevert,
         }
     }
 }
@@ -23,6 +24,7 @@ impl From<v0::ClientErrorType> for ClientErrorType {
                 ClientErrorType::SettlementSucceededElsewhere
             }
             v0::ClientErrorType::AbandonedByAdmin => ClientErrorType::AbandonedByAdmin,
+            v0::ClientErrorType::SimulatedRevert => ClientErrorType::SimulatedRevert,
         }
     }
 }
@@ -6,6 +6,7 @@ fn client_error_round_trip() {
         ClientErrorType::NonceAlreadyUsed,
         ClientErrorType::SettlementSucceededElsewhere,
         ClientErrorType::AbandonedByAdmin,
+        ClientErrorType::SimulatedRevert,
     ] {
         let error = ClientError {
             kind,
@@ -1,4 +1,4 @@
-use agglayer_types::{ContractCallOutcome, ContractCallResult};
+use agglayer_types::{ContractCallOutcome, ContractCallResult, SettlementGasCost};
 use prost::bytes::Bytes as ProstBytes;
 
 use super::Error;
@@ -43,6 +43,7 @@ impl From<&ContractCallResult> for v0::ContractCallResult {
             block_hash: Some(value.block_hash.into()),
             block_number: Some(value.block_number.into()),
             tx_hash: Some(value.tx_hash.into()),
+            gas_cost: value.gas_cost.as_ref().map(Into::into),
         }
     }
 }
@@ -69,6 +70,31 @@ impl TryFrom<v0::ContractCallResult> for ContractCallResult {
             tx_hash: required_field!(value, tx_hash =>
                 try_into::<agglayer_types::SettlementTxHash>
             ),
+            gas_cost: value
+                .gas_cost
+                .map(SettlementGasCost::try_from)
+                .transpose()
+                .map_err(|error| error.inside_field("gas_cost"))?,
+        })
+    }
+}
+
+impl From<&SettlementGasCost> for v0::SettlementGasCost {
+    fn from(value: &SettlementGasCost) -> Self {
+        Self {
+            gas_used: value.gas_used,
+            effective_gas_price: Some(value.effective_gas_price.into()),
+        }
+    }
+}
+
+impl TryFrom<v0::SettlementGasCost> for SettlementGasCost {
+    type Error = Error;
+
+    fn try_from(value: v0::SettlementGasCost) -> Result<Self, Self::Error> {
+        Ok(Self {
+            gas_used: value.gas_used,
+            effective_gas_price: required_field!(value, effective_gas_price => try_into::<u128>),
         })
     }
 }
@@ -87,6 +113,27 @@ mod tests {
             block_hash: agglayer_types::B256::from([8_u8; 32]),
             block_number: 42,
             tx_hash: SettlementTxHash::new(Digest::from([9_u8; 32])),
+            gas_cost: None,
+        };
+
+        let proto: v0::ContractCallResult = (&result).into();
+        let decoded = ContractCallResult::try_from(proto).unwrap();
+
+        assert_eq!(decoded, result);
+    }
+
+    #[test]
+    fn contract_call_result_round_trip_with_gas_cost() {
+        let result = ContractCallResult {
+            outcome: ContractCallOutcome::Revert,
+            metadata: vec![4].into(),
+            block_hash: agglayer_types::B256::from([8_u8; 32]),
+            block_number: 42,
+            tx_hash: SettlementTxHash::new(Digest::from([9_u8; 32])),
+            gas_cost: Some(SettlementGasCost {
+                gas_used: 350_000,
+                effective_gas_price: 12_000_000_000,
+            }),
         };
 
         let proto: v0::ContractCallResult = (&result).into();
@@ -95,6 +142,37 @@ mod tests {
         assert_eq!(decoded, result);
     }
 
+    #[test]
+    fn gas_cost_without_price_is_rejected() {
+        let proto = v0::ContractCallResult {
+            outcome: v0::ContractCallOutcome::Success as i32,
+            metadata: Some(v0::ContractCallMetadata {
+                metadata: ProstBytes::copy_from_slice(&[1]),
+            }),
+            block_hash: Some(v0::BlockHash {
+                hash: ProstBytes::copy_from_slice(&[2_u8; 32]),
+            }),
+            block_number: Some(v0::BlockNumber { number: 1 }),
+            tx_hash: Some(v0::TxHash {
+                hash: ProstBytes::copy_from_slice(&[3_u8; 32]),
+            }),
+            gas_cost: Some(v0::SettlementGasCost {
+                gas_used: 21_000,
+                effective_gas_price: None,
+            }),
+        };
+
+        let error = ContractCallResult::try_from(proto).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Field {
+                field: "gas_cost",
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn unspecified_outcome_fails() {
         let proto = v0::ContractCallResult {
@@ -109,6 +187,7 @@ mod tests {
             tx_hash: Some(v0::TxHash {
                 hash: ProstBytes::copy_from_slice(&[3_u8; 32]),
             }),
+            gas_cost: None,
         };
 
         assert!(ContractCallResult::try_from(proto).is_err());
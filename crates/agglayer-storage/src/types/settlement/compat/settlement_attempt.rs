@@ -1,4 +1,6 @@
-use agglayer_types::{Nonce, SettlementAttempt, SettlementAttemptNumber};
+use agglayer_types::{
+    GasOracleInputs, GasOracleSource, Nonce, SettlementAttempt, SettlementAttemptNumber,
+};
 
 use super::Error;
 use crate::types::generated::agglayer::storage::v0;
@@ -36,6 +38,7 @@ impl From<&SettlementAttempt> for v0::SettlementAttempt {
             submission_time: Some(prost_types::Timestamp::from(value.submission_time)),
             max_fee_per_gas: Some(value.max_fee_per_gas.into()),
             max_priority_fee_per_gas: Some(value.max_priority_fee_per_gas.into()),
+            gas_oracle_inputs: value.gas_oracle_inputs.as_ref().map(Into::into),
         }
     }
 }
@@ -57,6 +60,73 @@ impl TryFrom<v0::SettlementAttempt> for SettlementAttempt {
             max_priority_fee_per_gas: required_field!(value, max_priority_fee_per_gas =>
                 try_into::<u128>
             ),
+            gas_oracle_inputs: value
+                .gas_oracle_inputs
+                .map(GasOracleInputs::try_from)
+                .transpose()
+                .map_err(|error| error.inside_field("gas_oracle_inputs"))?,
+        })
+    }
+}
+
+impl From<&GasOracleInputs> for v0::GasOracleInputs {
+    fn from(value: &GasOracleInputs) -> Self {
+        use v0::gas_oracle_inputs::Source;
+
+        let source = match value.source {
+            GasOracleSource::Provider => Source::Provider(v0::ProviderGasOracle {}),
+            GasOracleSource::FeeHistory {
+                block_count,
+                reward_percentile,
+                base_fee_per_gas,
+            } => Source::FeeHistory(v0::FeeHistoryGasOracle {
+                block_count,
+                reward_percentile: reward_percentile.into(),
+                base_fee_per_gas: Some(base_fee_per_gas.into()),
+            }),
+            GasOracleSource::Fixed => Source::Fixed(v0::FixedGasOracle {}),
+        };
+
+        Self {
+            source: Some(source),
+            max_fee_per_gas: Some(value.max_fee_per_gas.into()),
+            max_priority_fee_per_gas: Some(value.max_priority_fee_per_gas.into()),
+        }
+    }
+}
+
+impl TryFrom<v0::GasOracleInputs> for GasOracleInputs {
+    type Error = Error;
+
+    fn try_from(value: v0::GasOracleInputs) -> Result<Self, Self::Error> {
+        use v0::gas_oracle_inputs::Source;
+
+        let source = match value.source {
+            None => return Err(Error::missing_field("source")),
+            Some(Source::Provider(v0::ProviderGasOracle {})) => GasOracleSource::Provider,
+            Some(Source::FeeHistory(fee_history)) => GasOracleSource::FeeHistory {
+                block_count: fee_history.block_count,
+                reward_percentile: u8::try_from(fee_history.reward_percentile).map_err(|_| {
+                    Error::invalid_data(format!(
+                        "invalid reward percentile {}",
+                        fee_history.reward_percentile
+                    ))
+                    .inside_field("reward_percentile")
+                    .inside_field("source")
+                })?,
+                base_fee_per_gas: required_field!(fee_history, base_fee_per_gas =>
+                    try_into::<u128>
+                ),
+            },
+            Some(Source::Fixed(v0::FixedGasOracle {})) => GasOracleSource::Fixed,
+        };
+
+        Ok(Self {
+            source,
+            max_fee_per_gas: required_field!(value, max_fee_per_gas => try_into::<u128>),
+            max_priority_fee_per_gas: required_field!(value, max_priority_fee_per_gas =>
+                try_into::<u128>
+            ),
         })
     }
 }
@@ -78,6 +148,7 @@ mod tests {
             submission_time: SystemTime::UNIX_EPOCH,
             max_fee_per_gas: 30_000_000_000,
             max_priority_fee_per_gas: 1_000_000_000,
+            gas_oracle_inputs: None,
         };
 
         let proto: v0::SettlementAttempt = (&attempt).into();
@@ -93,4 +164,59 @@ mod tests {
             attempt.max_priority_fee_per_gas
         );
     }
+
+    #[test]
+    fn settlement_attempt_round_trip_with_gas_oracle_inputs() {
+        let attempt = SettlementAttempt {
+            sender_wallet: Address::from([1_u8; 20]),
+            nonce: Nonce(7),
+            hash: SettlementTxHash::new(Digest::from([2_u8; 32])),
+            submission_time: SystemTime::UNIX_EPOCH,
+            max_fee_per_gas: 36_000_000_000,
+            max_priority_fee_per_gas: 1_200_000_000,
+            gas_oracle_inputs: Some(GasOracleInputs {
+                source: GasOracleSource::FeeHistory {
+                    block_count: 20,
+                    reward_percentile: 90,
+                    base_fee_per_gas: 14_000_000_000,
+                },
+                max_fee_per_gas: 30_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+            }),
+        };
+
+        let proto: v0::SettlementAttempt = (&attempt).into();
+        let decoded = SettlementAttempt::try_from(proto).unwrap();
+
+        assert_eq!(decoded, attempt);
+    }
+
+    #[test]
+    fn gas_oracle_inputs_without_source_are_rejected() {
+        let attempt = SettlementAttempt {
+            sender_wallet: Address::from([1_u8; 20]),
+            nonce: Nonce(7),
+            hash: SettlementTxHash::new(Digest::from([2_u8; 32])),
+            submission_time: SystemTime::UNIX_EPOCH,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            gas_oracle_inputs: Some(GasOracleInputs {
+                source: GasOracleSource::Provider,
+                max_fee_per_gas: 30_000_000_000,
+                max_priority_fee_per_gas: 1_000_000_000,
+            }),
+        };
+        let mut proto: v0::SettlementAttempt = (&attempt).into();
+        proto.gas_oracle_inputs.as_mut().unwrap().source = None;
+
+        let error = SettlementAttempt::try_from(proto).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Field {
+                field: "gas_oracle_inputs",
+                ..
+            }
+        ));
+    }
 }
@@ -1,4 +1,8 @@
-use agglayer_types::SettlementJob;
+use agglayer_types::{
+    AccessList, AccessListItem, Authorization, SettlementJob, SettlementTxType,
+    SignedAuthorization, B256,
+};
+use prost::bytes::Bytes as ProstBytes;
 
 use super::Error;
 use crate::types::generated::agglayer::storage::v0;
@@ -14,6 +18,20 @@ impl TryFrom<v0::SettlementJob> for SettlementJob {
             calldata: required_field!(value, calldata => into::<Vec<u8>>).into(),
             eth_value: required_field!(value, eth_value => try_into::<agglayer_types::U256>),
             gas_limit: required_field!(value, gas_limit => try_into::<u128>),
+            network_id: value.network_id.map(agglayer_types::NetworkId::new),
+            tx_type: tx_type_from_proto(value.tx_type)
+                .map_err(|error| error.inside_field("tx_type"))?,
+            access_list: value
+                .access_list
+                .map(access_list_from_proto)
+                .transpose()
+                .map_err(|error| error.inside_field("access_list"))?,
+            batched_jobs: value
+                .batched_jobs
+                .into_iter()
+                .map(|job_id| u128::try_from(job_id).map(agglayer_types::SettlementJobId::from))
+                .collect::<Result<_, _>>()
+                .map_err(|error| Error::from(error).inside_field("batched_jobs"))?,
         })
     }
 }
@@ -27,6 +45,14 @@ impl From<&SettlementJob> for v0::SettlementJob {
             }),
             eth_value: Some(value.eth_value.into()),
             gas_limit: Some(value.gas_limit.into()),
+            network_id: value.network_id.map(|network_id| network_id.to_u32()),
+            tx_type: Some(tx_type_to_proto(&value.tx_type)),
+            access_list: value.access_list.as_ref().map(access_list_to_proto),
+            batched_jobs: value
+                .batched_jobs
+                .iter()
+                .map(|job_id| u128::from_be_bytes(job_id.to_be_bytes()).into())
+                .collect(),
         }
     }
 }
@@ -37,6 +63,116 @@ impl From<SettlementJob> for v0::SettlementJob {
     }
 }
 
+/// Jobs stored before the envelope was recorded have none: they were all
+/// EIP-1559.
+fn tx_type_from_proto(
+    value: Option<v0::settlement_job::TxType>,
+) -> Result<SettlementTxType, Error> {
+    Ok(match value {
+        None | Some(v0::settlement_job::TxType::Eip1559(v0::Eip1559TxType {})) => {
+            SettlementTxType::Eip1559
+        }
+        Some(v0::settlement_job::TxType::Eip7702(tx_type)) => SettlementTxType::Eip7702 {
+            authorization_list: tx_type
+                .authorization_list
+                .into_iter()
+                .map(authorization_from_proto)
+                .collect::<Result<_, _>>()
+                .map_err(|error| error.inside_field("authorization_list"))?,
+        },
+    })
+}
+
+fn tx_type_to_proto(value: &SettlementTxType) -> v0::settlement_job::TxType {
+    match value {
+        SettlementTxType::Eip1559 => v0::settlement_job::TxType::Eip1559(v0::Eip1559TxType {}),
+        SettlementTxType::Eip7702 { authorization_list } => {
+            v0::settlement_job::TxType::Eip7702(v0::Eip7702TxType {
+                authorization_list: authorization_list
+                    .iter()
+                    .map(authorization_to_proto)
+                    .collect(),
+            })
+        }
+    }
+}
+
+fn authorization_from_proto(value: v0::SignedAuthorization) -> Result<SignedAuthorization, Error> {
+    let address: agglayer_types::Address =
+        required_field!(value, address => try_into::<agglayer_types::Address>);
+    let y_parity = u8::try_from(value.y_parity).map_err(|_| {
+        Error::invalid_data(format!("invalid y parity {}", value.y_parity)).inside_field("y_parity")
+    })?;
+
+    Ok(SignedAuthorization::new_unchecked(
+        Authorization {
+            chain_id: required_field!(value, chain_id => try_into::<agglayer_types::U256>),
+            address: address.into_alloy(),
+            nonce: value.nonce,
+        },
+        y_parity,
+        required_field!(value, r => try_into::<agglayer_types::U256>),
+        required_field!(value, s => try_into::<agglayer_types::U256>),
+    ))
+}
+
+fn authorization_to_proto(value: &SignedAuthorization) -> v0::SignedAuthorization {
+    v0::SignedAuthorization {
+        chain_id: Some(value.inner().chain_id.into()),
+        address: Some(agglayer_types::Address::from(value.inner().address).into()),
+        nonce: value.inner().nonce,
+        y_parity: value.y_parity().into(),
+        r: Some(value.r().into()),
+        s: Some(value.s().into()),
+    }
+}
+
+fn access_list_from_proto(value: v0::AccessList) -> Result<AccessList, Error> {
+    value
+        .items
+        .into_iter()
+        .map(|item| {
+            let address: agglayer_types::Address =
+                required_field!(item, address => try_into::<agglayer_types::Address>);
+            let storage_keys = item
+                .storage_keys
+                .iter()
+                .map(|key| {
+                    B256::try_from(key.as_ref()).map_err(|_| {
+                        Error::invalid_data(format!(
+                            "storage key must be 32 bytes long, got {}",
+                            key.len()
+                        ))
+                    })
+                })
+                .collect::<Result<_, _>>()
+                .map_err(|error| error.inside_field("storage_keys"))?;
+
+            Ok(AccessListItem {
+                address: address.into_alloy(),
+                storage_keys,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(AccessList)
+}
+
+fn access_list_to_proto(value: &AccessList) -> v0::AccessList {
+    v0::AccessList {
+        items: value
+            .iter()
+            .map(|item| v0::AccessListItem {
+                address: Some(agglayer_types::Address::from(item.address).into()),
+                storage_keys: item
+                    .storage_keys
+                    .iter()
+                    .map(|key| ProstBytes::copy_from_slice(key.as_slice()))
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use agglayer_types::Address;
@@ -50,9 +186,33 @@ mod tests {
             calldata: Bytes::from(vec![1, 2, 3]),
             eth_value: agglayer_types::U256::from(3_u64),
             gas_limit: 10,
+            network_id: Some(agglayer_types::NetworkId::new(7)),
+            tx_type: SettlementTxType::Eip1559,
+            access_list: None,
+            batched_jobs: Vec::new(),
         }
     }
 
+    fn sample_access_list() -> AccessList {
+        AccessList(vec![AccessListItem {
+            address: Address::from([2_u8; 20]).into_alloy(),
+            storage_keys: vec![B256::repeat_byte(3), B256::repeat_byte(4)],
+        }])
+    }
+
+    fn sample_authorization() -> SignedAuthorization {
+        SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: agglayer_types::U256::from(1_u64),
+                address: Address::from([5_u8; 20]).into_alloy(),
+                nonce: 6,
+            },
+            1,
+            agglayer_types::U256::from(7_u64),
+            agglayer_types::U256::from(8_u64),
+        )
+    }
+
     #[test]
     fn settlement_job_round_trip() {
         let job = sample_job();
@@ -63,6 +223,101 @@ mod tests {
         assert_eq!(decoded, job);
     }
 
+    #[test]
+    fn settlement_job_without_network_id_round_trip() {
+        let job = SettlementJob {
+            network_id: None,
+            ..sample_job()
+        };
+
+        let proto: v0::SettlementJob = (&job).into();
+        let decoded = SettlementJob::try_from(proto).unwrap();
+
+        assert_eq!(decoded, job);
+    }
+
+    #[test]
+    fn settlement_job_with_eip7702_envelope_and_access_list_round_trip() {
+        let job = SettlementJob {
+            tx_type: SettlementTxType::Eip7702 {
+                authorization_list: vec![sample_authorization()],
+            },
+            access_list: Some(sample_access_list()),
+            ..sample_job()
+        };
+
+        let proto: v0::SettlementJob = (&job).into();
+        let decoded = SettlementJob::try_from(proto).unwrap();
+
+        assert_eq!(decoded, job);
+    }
+
+    #[test]
+    fn batch_settlement_job_round_trip() {
+        let job = SettlementJob {
+            network_id: None,
+            batched_jobs: vec![
+                agglayer_types::SettlementJobId::from(1_u128),
+                agglayer_types::SettlementJobId::from(u128::MAX),
+            ],
+            ..sample_job()
+        };
+
+        let proto: v0::SettlementJob = (&job).into();
+        let decoded = SettlementJob::try_from(proto).unwrap();
+
+        assert_eq!(decoded, job);
+    }
+
+    #[test]
+    fn settlement_job_from_proto_rejects_invalid_batched_job_id() {
+        let mut proto: v0::SettlementJob = sample_job().into();
+        proto.batched_jobs = vec![v0::Uint128 {
+            value: ProstBytes::from_static(&[1, 2, 3]),
+        }];
+
+        let result = SettlementJob::try_from(proto);
+
+        assert!(matches!(
+            result,
+            Err(Error::Field {
+                field: "batched_jobs",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn settlement_job_without_tx_type_decodes_as_eip1559() {
+        let mut proto: v0::SettlementJob = sample_job().into();
+        proto.tx_type = None;
+
+        let decoded = SettlementJob::try_from(proto).unwrap();
+
+        assert_eq!(decoded.tx_type, SettlementTxType::Eip1559);
+    }
+
+    #[test]
+    fn settlement_job_from_proto_rejects_invalid_storage_key() {
+        let mut proto: v0::SettlementJob = SettlementJob {
+            access_list: Some(sample_access_list()),
+            ..sample_job()
+        }
+        .into();
+        proto.access_list.as_mut().unwrap().items[0].storage_keys[0] =
+            ProstBytes::from_static(&[1, 2, 3]);
+
+        let result = SettlementJob::try_from(proto);
+
+        assert!(matches!(
+            result,
+            Err(Error::Field {
+                field: "access_list",
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn settlement_job_from_proto_rejects_missing_required_field() {
         let mut proto: v0::SettlementJob = sample_job().into();
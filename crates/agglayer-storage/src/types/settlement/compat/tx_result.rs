@@ -100,6 +100,7 @@ mod tests {
             block_hash: B256::from([6_u8; 32]),
             block_number: 42,
             tx_hash: SettlementTxHash::new(Digest::from([7_u8; 32])),
+            gas_cost: None,
         }
     }
 
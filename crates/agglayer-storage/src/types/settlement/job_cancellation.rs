@@ -0,0 +1,31 @@
+use agglayer_types::{Height, NetworkId, SettlementJobCancellation, SettlementJobId};
+use serde::{Deserialize, Serialize};
+
+pub type Key = SettlementJobId;
+
+/// A settlement job cancelled by a network rollback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Value {
+    pub(crate) network_id: u32,
+    pub(crate) target_height: u64,
+}
+
+crate::schema::impl_codec_using_bincode_for!(Value);
+
+impl From<&SettlementJobCancellation> for Value {
+    fn from(cancellation: &SettlementJobCancellation) -> Self {
+        Self {
+            network_id: cancellation.network_id.to_u32(),
+            target_height: cancellation.target_height.as_u64(),
+        }
+    }
+}
+
+impl From<Value> for SettlementJobCancellation {
+    fn from(value: Value) -> Self {
+        Self {
+            network_id: NetworkId::new(value.network_id),
+            target_height: Height::new(value.target_height),
+        }
+    }
+}
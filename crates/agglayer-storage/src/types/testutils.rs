@@ -19,6 +19,7 @@ fn contract_call_success_for_test(seed: u8) -> ContractCallResult {
         tx_hash: Some(TxHash {
             hash: vec![seed.wrapping_add(2); 32].into(),
         }),
+        gas_cost: None,
     }
 }
 
@@ -1,6 +1,6 @@
 //! Settlement transaction metrics: live job count, attempt counts, attempt
-//! errors, and job durations, labeled by wallet where the wallet dimension
-//! is unambiguous.
+//! errors, job durations, settlement wallet balances, and gas spent, labeled
+//! by wallet where the wallet dimension is unambiguous.
 
 use lazy_static::lazy_static;
 use opentelemetry::{global, metrics::*, KeyValue};
@@ -18,6 +18,9 @@ const WALLET_LABEL_NAME: &str = "wallet";
 /// Name of the label carrying the job outcome.
 const OUTCOME_LABEL_NAME: &str = "outcome";
 
+/// Name of the label carrying the network a settlement is for.
+const NETWORK_LABEL_NAME: &str = "network_id";
+
 /// Gauge name: number of live settlement jobs.
 pub const SETTLEMENT_JOBS: &str = "agglayer_node_settlement_jobs";
 
@@ -46,6 +49,28 @@ pub const SETTLEMENT_JOB_DURATION_SECONDS: &str = "agglayer_node_settlement_job_
 /// [`SETTLEMENT_ATTEMPTS`] for the suffix convention.
 pub const SETTLEMENT_RECOVERY_SKIPPED_JOBS: &str = "agglayer_node_settlement_recovery_skipped_jobs";
 
+/// Gauge name: last observed balance of a settlement wallet, in wei, by
+/// `wallet`.
+pub const SETTLEMENT_WALLET_BALANCE_WEI: &str = "agglayer_node_settlement_wallet_balance_wei";
+
+/// Gauge name: 1 while a settlement wallet is below the configured minimum
+/// balance and its new attempts are refused, 0 otherwise, by `wallet`.
+pub const SETTLEMENT_WALLET_BALANCE_LOW: &str = "agglayer_node_settlement_wallet_balance_low";
+
+/// Counter instrument name: wei paid for the gas of mined settlement
+/// transactions, reverted ones included, by `network_id` and `wallet`.
+///
+/// Exported as `agglayer_node_settlement_gas_spent_wei_total`; see
+/// [`SETTLEMENT_ATTEMPTS`] for the suffix convention.
+pub const SETTLEMENT_GAS_SPENT_WEI: &str = "agglayer_node_settlement_gas_spent_wei";
+
+/// Counter instrument name: gas used by mined settlement transactions,
+/// reverted ones included, by `network_id` and `wallet`.
+///
+/// Exported as `agglayer_node_settlement_gas_used_total`; see
+/// [`SETTLEMENT_ATTEMPTS`] for the suffix convention.
+pub const SETTLEMENT_GAS_USED: &str = "agglayer_node_settlement_gas_used";
+
 /// A kind of settlement transaction attempt, rendered as the `kind` label
 /// value on [`SETTLEMENT_ATTEMPTS`].
 ///
@@ -73,6 +98,12 @@ pub enum SettlementAttemptErrorKind {
     NonceTooLow,
     Underpriced,
     Rpc,
+    /// A task paused its attempts because the wallet balance was below the
+    /// configured minimum. Counted once per pause, not per refused attempt.
+    LowBalance,
+    /// The attempt was not broadcast because its pre-flight simulation
+    /// reverted.
+    SimulatedRevert,
 }
 
 /// A terminal settlement job outcome, rendered as the `outcome` label value
@@ -141,6 +172,28 @@ lazy_static! {
                  be loaded"
             )
             .build();
+    static ref SETTLEMENT_WALLET_BALANCE_GAUGE: Gauge<f64> =
+        global::meter(AGGLAYER_NODE_SETTLEMENT_OTEL_SCOPE_NAME)
+            .f64_gauge(SETTLEMENT_WALLET_BALANCE_WEI)
+            .with_description("Last observed balance of a settlement wallet, in wei")
+            .build();
+    static ref SETTLEMENT_WALLET_BALANCE_LOW_GAUGE: Gauge<u64> =
+        global::meter(AGGLAYER_NODE_SETTLEMENT_OTEL_SCOPE_NAME)
+            .u64_gauge(SETTLEMENT_WALLET_BALANCE_LOW)
+            .with_description(
+                "Whether a settlement wallet is below the minimum balance (1) or not (0)"
+            )
+            .build();
+    static ref SETTLEMENT_GAS_SPENT_WEI_COUNTER: Counter<f64> =
+        global::meter(AGGLAYER_NODE_SETTLEMENT_OTEL_SCOPE_NAME)
+            .f64_counter(SETTLEMENT_GAS_SPENT_WEI)
+            .with_description("Wei paid for the gas of mined settlement transactions")
+            .build();
+    static ref SETTLEMENT_GAS_USED_COUNTER: Counter<u64> =
+        global::meter(AGGLAYER_NODE_SETTLEMENT_OTEL_SCOPE_NAME)
+            .u64_counter(SETTLEMENT_GAS_USED)
+            .with_description("Gas used by mined settlement transactions")
+            .build();
 }
 
 /// Records one settlement transaction attempt.
@@ -182,5 +235,32 @@ pub fn record_settlement_recovery_skipped_jobs(count: u64) {
     SETTLEMENT_RECOVERY_SKIPPED_JOBS_COUNTER.add(count, &[]);
 }
 
+/// Records the last observed balance of `wallet`, and whether it is below
+/// the configured minimum.
+///
+/// The balance is exported as a float: precision loss beyond 2^53 wei is
+/// irrelevant for alerting on an underfunded wallet.
+#[inline]
+pub fn record_settlement_wallet_balance(wallet: &str, balance_wei: f64, low: bool) {
+    let labels = [KeyValue::new(WALLET_LABEL_NAME, wallet.to_string())];
+    SETTLEMENT_WALLET_BALANCE_GAUGE.record(balance_wei, &labels);
+    SETTLEMENT_WALLET_BALANCE_LOW_GAUGE.record(u64::from(low), &labels);
+}
+
+/// Records the gas paid by one mined settlement transaction of `network`,
+/// sent from `wallet`. A batch transaction is recorded as one even share per
+/// batched job, under the network of each.
+///
+/// The amount spent is exported as a float, like the wallet balance.
+#[inline]
+pub fn record_settlement_gas_spent(network: &str, wallet: &str, gas_used: u64, spent_wei: f64) {
+    let labels = [
+        KeyValue::new(NETWORK_LABEL_NAME, network.to_string()),
+        KeyValue::new(WALLET_LABEL_NAME, wallet.to_string()),
+    ];
+    SETTLEMENT_GAS_SPENT_WEI_COUNTER.add(spent_wei, &labels);
+    SETTLEMENT_GAS_USED_COUNTER.add(gas_used, &labels);
+}
+
 #[cfg(test)]
 mod tests;
@@ -37,6 +37,8 @@ fn settlement_metrics_export_the_issue_1676_series() {
     record_settlement_attempt_error(SettlementAttemptErrorKind::NonceTooLow, "wallet-0");
     record_settlement_attempt_error(SettlementAttemptErrorKind::Underpriced, "wallet-0");
     record_settlement_attempt_error(SettlementAttemptErrorKind::Rpc, "wallet-1");
+    record_settlement_attempt_error(SettlementAttemptErrorKind::LowBalance, "wallet-1");
+    record_settlement_attempt_error(SettlementAttemptErrorKind::SimulatedRevert, "wallet-0");
 
     record_settlement_job_duration(SettlementJobOutcome::Success, "wallet-0", 42.0);
     record_settlement_job_duration(SettlementJobOutcome::Revert, "wallet-1", 3.0);
@@ -88,6 +90,22 @@ fn settlement_metrics_export_the_issue_1676_series() {
         ),
         Some(1.0),
     );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            &attempt_errors_series,
+            &[("kind", "low_balance"), ("wallet", "wallet-1")],
+        ),
+        Some(1.0),
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            &attempt_errors_series,
+            &[("kind", "simulated_revert"), ("wallet", "wallet-0")],
+        ),
+        Some(1.0),
+    );
 
     let count_series = format!("{SETTLEMENT_JOB_DURATION_SECONDS}_count");
     let sum_series = format!("{SETTLEMENT_JOB_DURATION_SECONDS}_sum");
@@ -199,3 +217,96 @@ fn settlement_jobs_gauge_reflects_the_sampler_on_every_scrape() {
         "drained gauge must still export zero, got:\n{metrics}"
     );
 }
+
+#[test]
+fn wallet_balance_gauges_export_the_last_observation() {
+    let harness = MetricsHarness::install();
+
+    record_settlement_wallet_balance("wallet-0", 5e17, false);
+    record_settlement_wallet_balance("wallet-1", 1e15, true);
+
+    let metrics = harness.gather();
+    assert_eq!(
+        sample_value(
+            &metrics,
+            SETTLEMENT_WALLET_BALANCE_WEI,
+            &[("wallet", "wallet-0")]
+        ),
+        Some(5e17),
+        "wallet balance gauge, got:\n{metrics}"
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            SETTLEMENT_WALLET_BALANCE_LOW,
+            &[("wallet", "wallet-0")]
+        ),
+        Some(0.0),
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            SETTLEMENT_WALLET_BALANCE_LOW,
+            &[("wallet", "wallet-1")]
+        ),
+        Some(1.0),
+    );
+
+    // A top-up overwrites the previous observation rather than adding to it.
+    record_settlement_wallet_balance("wallet-1", 2e18, false);
+    let metrics = harness.gather();
+    assert_eq!(
+        sample_value(
+            &metrics,
+            SETTLEMENT_WALLET_BALANCE_WEI,
+            &[("wallet", "wallet-1")]
+        ),
+        Some(2e18),
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            SETTLEMENT_WALLET_BALANCE_LOW,
+            &[("wallet", "wallet-1")]
+        ),
+        Some(0.0),
+    );
+}
+
+#[test]
+fn gas_spent_counters_accumulate_per_network_and_wallet() {
+    let harness = MetricsHarness::install();
+
+    record_settlement_gas_spent("1", "wallet-0", 300_000, 3e15);
+    record_settlement_gas_spent("1", "wallet-0", 100_000, 1e15);
+    record_settlement_gas_spent("2", "wallet-0", 50_000, 5e14);
+
+    let metrics = harness.gather();
+    let spent_series = format!("{SETTLEMENT_GAS_SPENT_WEI}_total");
+    let used_series = format!("{SETTLEMENT_GAS_USED}_total");
+    assert_eq!(
+        sample_value(
+            &metrics,
+            &spent_series,
+            &[("network_id", "1"), ("wallet", "wallet-0")]
+        ),
+        Some(4e15),
+        "gas spent counter, got:\n{metrics}"
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            &used_series,
+            &[("network_id", "1"), ("wallet", "wallet-0")]
+        ),
+        Some(400_000.0),
+    );
+    assert_eq!(
+        sample_value(
+            &metrics,
+            &used_series,
+            &[("network_id", "2"), ("wallet", "wallet-0")]
+        ),
+        Some(50_000.0),
+    );
+}
@@ -0,0 +1,122 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::NetworkId;
+
+/// Identifier of a recorded admin audit event.
+///
+/// A ULID, so that identifiers sort by the time their event was recorded.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    derive_more::Display,
+    derive_more::From,
+    derive_more::Into,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(transparent)]
+pub struct AdminAuditEventId(ulid::Ulid);
+
+impl AdminAuditEventId {
+    pub const BYTE_LEN: usize = std::mem::size_of::<u128>();
+
+    /// A fresh identifier for an event recorded at `recorded_at`.
+    pub fn new(recorded_at: SystemTime) -> Self {
+        Self(ulid::Ulid::from_datetime(recorded_at))
+    }
+
+    /// The lowest identifier of an event recorded at or after `time`.
+    pub fn first_at(time: SystemTime) -> Self {
+        let millis = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_millis()
+            .try_into()
+            .unwrap_or(u64::MAX);
+        Self(ulid::Ulid::from_parts(millis, 0))
+    }
+
+    /// The next identifier in sort order, if any.
+    pub fn successor(&self) -> Option<Self> {
+        self.0.increment().map(Self)
+    }
+
+    pub const fn from_be_bytes(bytes: [u8; Self::BYTE_LEN]) -> Self {
+        Self(ulid::Ulid::from_bytes(bytes))
+    }
+
+    pub const fn to_be_bytes(&self) -> [u8; Self::BYTE_LEN] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<u128> for AdminAuditEventId {
+    fn from(value: u128) -> Self {
+        Self(ulid::Ulid::from(value))
+    }
+}
+
+#[cfg(feature = "testutils")]
+impl<'a> arbitrary::Arbitrary<'a> for AdminAuditEventId {
+    fn arbitrary(input: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(ulid::Ulid::from(
+            <u128 as arbitrary::Arbitrary>::arbitrary(input)?,
+        )))
+    }
+}
+
+/// A mutation performed through the admin RPC, as recorded in the audit log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAuditEvent {
+    /// When the mutation completed.
+    pub recorded_at: SystemTime,
+    pub caller: AdminAuditCaller,
+    /// The JSON-RPC method, e.g. `admin_disableNetwork`.
+    pub method: String,
+    /// The network whose state the mutation touched, when known.
+    pub network_id: Option<NetworkId>,
+    /// The call parameters, as JSON.
+    pub params: String,
+    /// The touched record before the mutation, as JSON, when it was read.
+    pub before: Option<String>,
+    /// The touched record after the mutation, as JSON, when it was read.
+    pub after: Option<String>,
+    pub outcome: AdminAuditOutcome,
+}
+
+/// Who performed an audited admin mutation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminAuditCaller {
+    /// Token name or JWT subject of the caller, or `unauthenticated` when
+    /// admin authentication is disabled.
+    pub name: String,
+    /// Role the caller was authenticated with, if any.
+    pub role: Option<String>,
+}
+
+/// Result of an audited admin mutation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAuditOutcome {
+    /// The mutation was applied.
+    Applied {
+        /// How the live settlement task was told about the mutation, for
+        /// the settlement job mutations that report it.
+        live_task: Option<AdminAuditLiveTask>,
+    },
+    /// The mutation was refused or failed, with the returned error message.
+    Failed { error: String },
+}
+
+/// How the live settlement task of a job was told about an audited mutation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdminAuditLiveTask {
+    Queued,
+    Absent,
+    NotifyFailed,
+}
@@ -0,0 +1,12 @@
+/// Order in which certificates of a network are listed, by height.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ListDirection {
+    /// From the lowest to the highest height.
+    #[default]
+    Ascending,
+    /// From the highest to the lowest height.
+    Descending,
+}
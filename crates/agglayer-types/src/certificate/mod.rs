@@ -17,6 +17,7 @@ mod header;
 mod height;
 mod id;
 mod index;
+mod list_direction;
 mod metadata;
 #[cfg(feature = "testutils")]
 mod testutils;
@@ -27,6 +28,7 @@ pub use header::{CertificateHeader, CertificateStatus, SettlementTxHash};
 pub use height::Height;
 pub use id::CertificateId;
 pub use index::CertificateIndex;
+pub use list_direction::ListDirection;
 pub use metadata::Metadata;
 #[cfg(feature = "testutils")]
 pub use testutils::compute_signature_info;
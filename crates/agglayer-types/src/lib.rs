@@ -2,9 +2,11 @@ pub use agglayer_interop_types::{aggchain_proof, bincode, NetworkId};
 pub use agglayer_primitives::{self as primitives, Address, Digest, Signature, B256, U256, U512};
 use agglayer_tries::roots::LocalExitRoot;
 pub use pessimistic_proof::proof::Proof;
+pub use unified_bridge::{GlobalIndex, TokenInfo};
 
 pub mod aggchain_data;
 
+mod admin_audit;
 mod certificate;
 mod epoch;
 mod error;
@@ -13,6 +15,8 @@ pub mod network_info;
 mod proof_modes;
 mod rpc_error_code;
 mod settlement;
+mod simulation;
+mod state_proof;
 
 #[cfg(feature = "testutils")]
 pub mod testutils {
@@ -20,9 +24,12 @@ pub mod testutils {
 
     pub use crate::certificate::{compute_signature_info, dummy_sp1_stark_proof_with_version};
 }
+pub use admin_audit::{
+    AdminAuditCaller, AdminAuditEvent, AdminAuditEventId, AdminAuditLiveTask, AdminAuditOutcome,
+};
 pub use certificate::{
     Certificate, CertificateHeader, CertificateId, CertificateIndex, CertificateStatus, Height,
-    Metadata, SettlementTxHash,
+    ListDirection, Metadata, SettlementTxHash,
 };
 pub use epoch::{EpochConfiguration, EpochNumber};
 pub use error::{CertificateStatusError, Error, SignerError};
@@ -31,7 +38,13 @@ pub use network_info::{NetworkInfo, NetworkStatus, NetworkType, SettledClaim};
 pub use proof_modes::{ExecutionMode, GenerationType};
 pub use rpc_error_code::RpcErrorCode;
 pub use settlement::{
-    ClientError, ClientErrorType, ContractCallOutcome, ContractCallResult, Nonce,
-    SettlementAttempt, SettlementAttemptNumber, SettlementAttemptResult, SettlementJob,
-    SettlementJobId, SettlementJobResult,
+    AccessList, AccessListItem, Authorization, ClientError, ClientErrorType, ContractCallOutcome,
+    ContractCallResult, GasOracleInputs, GasOracleSource, Nonce, SettlementAttempt,
+    SettlementAttemptNumber, SettlementAttemptResult, SettlementGasCost, SettlementJob,
+    SettlementJobCancellation, SettlementJobId, SettlementJobResult, SettlementTxType,
+    SignedAuthorization,
+};
+pub use simulation::{CertificateSimulation, SimulatedRoots, SimulationOutcome};
+pub use state_proof::{
+    BalanceProof, LocalExitTreeProof, NullifierProof, NullifierProofPath, SettledStateAnchor,
 };
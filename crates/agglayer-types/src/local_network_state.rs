@@ -108,6 +108,52 @@ impl LocalNetworkStateData {
             .compute_pp_root(version),
         };
 
+        let (balances_proofs, imported_bridge_exits) = self.apply_transition(certificate)?;
+
+        let chain_payload = certificate
+            .aggchain_data
+            .clone()
+            .try_into()
+            .map_err(Error::InvalidChainData)?;
+
+        let aggchain_data = CertificateAggchainDataWithCtx(chain_payload, aggchain_data_ctx)
+            .try_into()
+            .map_err(Error::InvalidChainData)?;
+
+        Ok(MultiBatchHeader {
+            origin_network: certificate.network_id,
+            bridge_exits: certificate.bridge_exits.clone(),
+            imported_bridge_exits,
+            balances_proofs,
+            l1_info_root,
+            height: certificate.height.as_u64(),
+            prev_pessimistic_root,
+            aggchain_data,
+            certificate_id: certificate.hash().into(),
+        })
+    }
+
+    /// Replay an already settled [`Certificate`] on the current state.
+    ///
+    /// Only the state transition is applied: nothing is fetched from the L1,
+    /// and the aggchain data isn't checked.
+    pub fn replay_certificate(&mut self, certificate: &Certificate) -> Result<(), Error> {
+        self.apply_transition(certificate).map(|_| ())
+    }
+
+    /// Apply the bridge exits and imported bridge exits of the [`Certificate`]
+    /// to the trees, returning the paths against the initial state.
+    #[allow(clippy::type_complexity)]
+    fn apply_transition(
+        &mut self,
+        certificate: &Certificate,
+    ) -> Result<
+        (
+            BTreeMap<TokenInfo, (U256, LocalBalancePath)>,
+            Vec<(ImportedBridgeExit, NullifierPath)>,
+        ),
+        Error,
+    > {
         let prev_local_exit_root = self.exit_tree.get_root().into();
         if certificate.prev_local_exit_root != prev_local_exit_root {
             return Err(Error::MismatchPrevLocalExitRoot {
@@ -235,27 +281,7 @@ impl LocalNetworkStateData {
             });
         }
 
-        let chain_payload = certificate
-            .aggchain_data
-            .clone()
-            .try_into()
-            .map_err(Error::InvalidChainData)?;
-
-        let aggchain_data = CertificateAggchainDataWithCtx(chain_payload, aggchain_data_ctx)
-            .try_into()
-            .map_err(Error::InvalidChainData)?;
-
-        Ok(MultiBatchHeader {
-            origin_network: certificate.network_id,
-            bridge_exits: certificate.bridge_exits.clone(),
-            imported_bridge_exits,
-            balances_proofs,
-            l1_info_root,
-            height: certificate.height.as_u64(),
-            prev_pessimistic_root,
-            aggchain_data,
-            certificate_id: certificate.hash().into(),
-        })
+        Ok((balances_proofs, imported_bridge_exits))
     }
 
     /// Generates the [`MultiBatchHeader`] from the state and a [`Certificate`].
@@ -79,6 +79,16 @@ pub enum RpcErrorCode {
     /// retry later.
     #[error("unavailable")]
     Unavailable = -10014,
+
+    /// The authenticated admin caller's role does not allow the method —
+    /// use a credential with the operator role.
+    #[error("permission denied")]
+    PermissionDenied = -10015,
+
+    /// The network submitted too many certificates, or has too many of them
+    /// pending — retry after the time carried in the error data.
+    #[error("certificate submission rate limited")]
+    CertificateRateLimited = -10016,
 }
 
 impl RpcErrorCode {
@@ -105,6 +115,8 @@ impl RpcErrorCode {
             Self::NoLiveTask => "no-live-task",
             Self::TaskStillLive => "task-still-live",
             Self::Unavailable => "unavailable",
+            Self::PermissionDenied => "permission-denied",
+            Self::CertificateRateLimited => "certificate-rate-limited",
         }
     }
 }
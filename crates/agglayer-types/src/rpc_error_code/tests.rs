@@ -88,6 +88,18 @@ fn display_and_tag_are_pinned() {
             "unavailable",
             -10014,
         ),
+        (
+            RpcErrorCode::PermissionDenied,
+            "permission denied",
+            "permission-denied",
+            -10015,
+        ),
+        (
+            RpcErrorCode::CertificateRateLimited,
+            "certificate submission rate limited",
+            "certificate-rate-limited",
+            -10016,
+        ),
     ];
 
     for (code, expected_display, expected_tag, expected_code) in cases {
@@ -115,6 +127,8 @@ fn serialization_matches_tag() {
         RpcErrorCode::NoLiveTask,
         RpcErrorCode::TaskStillLive,
         RpcErrorCode::Unavailable,
+        RpcErrorCode::PermissionDenied,
+        RpcErrorCode::CertificateRateLimited,
     ];
 
     for code in codes {
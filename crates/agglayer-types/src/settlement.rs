@@ -1,8 +1,12 @@
 use std::time::SystemTime;
 
+pub use alloy::eips::{
+    eip2930::{AccessList, AccessListItem},
+    eip7702::{Authorization, SignedAuthorization},
+};
 use alloy::primitives::Bytes;
 
-use crate::{Address, SettlementTxHash, B256, U256};
+use crate::{Address, Height, NetworkId, SettlementTxHash, B256, U256};
 
 #[derive(
     Clone,
@@ -85,6 +89,42 @@ pub struct SettlementJob {
     pub calldata: Bytes,
     pub eth_value: U256,
     pub gas_limit: u128,
+    /// Network whose certificate the job settles, used to pick the
+    /// settlement wallet. `None` for jobs that are not tied to a network.
+    pub network_id: Option<NetworkId>,
+    /// Envelope of the settlement transactions.
+    pub tx_type: SettlementTxType,
+    /// Access list attached to every settlement transaction. `None` sends
+    /// the transactions without one.
+    pub access_list: Option<AccessList>,
+    /// Jobs whose calls this job settles together in one transaction, in
+    /// call order. Empty for a job settling its own call.
+    pub batched_jobs: Vec<SettlementJobId>,
+}
+
+impl SettlementJob {
+    /// Whether this job settles the calls of other jobs.
+    pub fn is_batch(&self) -> bool {
+        !self.batched_jobs.is_empty()
+    }
+}
+
+/// Envelope of the transactions of a settlement job.
+///
+/// EIP-4844 blob transactions are deliberately not supported: settlement
+/// calls carry their data as calldata, and a blob envelope would need the
+/// sidecar stored with the job and a `max_fee_per_blob_gas` priced and bumped
+/// apart from the execution fees. A variant must be added here, in the
+/// storage proto, and in the settlement fee handling together.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum SettlementTxType {
+    /// EIP-1559 dynamic-fee transaction.
+    #[default]
+    Eip1559,
+    /// EIP-7702 set-code transaction carrying `authorization_list`.
+    Eip7702 {
+        authorization_list: Vec<SignedAuthorization>,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -95,6 +135,20 @@ pub struct SettlementJobResult {
     pub contract_call_result: ContractCallResult,
 }
 
+/// Record of a settlement job cancelled by a network rollback, which removed
+/// the certificate the job settles.
+///
+/// Terminal for the job like a [`SettlementJobResult`], without any outcome
+/// on L1: its attempts are no longer driven, but the ones already broadcast
+/// may still be included.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SettlementJobCancellation {
+    /// The network that got rolled back.
+    pub network_id: NetworkId,
+    /// The settled height the network got rolled back to.
+    pub target_height: Height,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SettlementAttemptResult {
     ClientError(ClientError),
@@ -118,6 +172,9 @@ pub enum ClientErrorType {
     /// drive the settlement elsewhere. Only written through the admin
     /// override path; real on-chain evidence may still supersede it.
     AbandonedByAdmin,
+    /// The pre-flight simulation of the attempt reverted, so it was never
+    /// broadcast. The message carries the decoded revert reason.
+    SimulatedRevert,
 }
 
 impl ClientError {
@@ -150,14 +207,27 @@ impl ClientError {
             message: format!("Attempt abandoned by administrator: {reason}"),
         }
     }
+
+    pub fn simulated_revert(reason: &str) -> Self {
+        Self {
+            kind: ClientErrorType::SimulatedRevert,
+            message: format!("Pre-flight simulation reverted: {reason}"),
+        }
+    }
 }
 
 impl SettlementAttemptResult {
     /// May `replacement` overwrite `self`? Only a stronger result replaces a
-    /// weaker one.
+    /// weaker one. The same inclusion with its gas cost is stronger than one
+    /// recorded without it.
     pub fn can_be_replaced_by(&self, replacement: &Self) -> bool {
         match (self, replacement) {
             (Self::ClientError(_), Self::ContractCall(_)) => true,
+            (Self::ContractCall(existing_call), Self::ContractCall(replacement_call)) => {
+                existing_call.gas_cost.is_none()
+                    && replacement_call.gas_cost.is_some()
+                    && existing_call.is_same_inclusion(replacement_call)
+            }
             (Self::ClientError(existing_error), Self::ClientError(replacement_error)) => {
                 existing_error.kind == ClientErrorType::Unknown
                     && matches!(
@@ -192,6 +262,56 @@ pub struct ContractCallResult {
     pub block_hash: B256,
     pub block_number: u64,
     pub tx_hash: SettlementTxHash,
+    /// Gas paid for the transaction. `None` when it never reached L1, or for
+    /// results recorded before gas costs were tracked.
+    pub gas_cost: Option<SettlementGasCost>,
+}
+
+impl ContractCallResult {
+    /// Whether both results describe the same transaction in the same block,
+    /// regardless of whether their gas costs were recorded.
+    pub fn is_same_inclusion(&self, other: &Self) -> bool {
+        self.outcome == other.outcome
+            && self.metadata == other.metadata
+            && self.block_hash == other.block_hash
+            && self.block_number == other.block_number
+            && self.tx_hash == other.tx_hash
+    }
+}
+
+/// Gas paid by a settlement transaction included on L1, from its receipt.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SettlementGasCost {
+    pub gas_used: u64,
+    /// Price paid per unit of gas (wei): the block's base fee plus the
+    /// priority fee.
+    pub effective_gas_price: u128,
+}
+
+impl SettlementGasCost {
+    /// Fee paid for the transaction, in wei.
+    pub fn fee(&self) -> u128 {
+        u128::from(self.gas_used).saturating_mul(self.effective_gas_price)
+    }
+
+    /// Splits the gas used and the fee of a transaction settling `parts`
+    /// jobs at once into even `(gas_used, fee)` shares. The remainders go to
+    /// the first shares, so that the shares add up to the whole.
+    pub fn split(&self, parts: usize) -> Vec<(u64, u128)> {
+        if parts == 0 {
+            return Vec::new();
+        }
+        let parts = parts as u64;
+        let fee = self.fee();
+        (0..parts)
+            .map(|part| {
+                let gas_used = self.gas_used / parts + u64::from(part < self.gas_used % parts);
+                let fee = fee / u128::from(parts)
+                    + u128::from(u128::from(part) < fee % u128::from(parts));
+                (gas_used, fee)
+            })
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -212,6 +332,38 @@ pub struct SettlementAttempt {
     /// `max_priority_fee_per_gas` (wei) of the signed attempt; the baseline a
     /// retry bumps from.
     pub max_priority_fee_per_gas: u128,
+    /// Fee estimate the attempt was priced from, before the configured
+    /// multipliers, floors and ceilings. `None` for attempts recorded before
+    /// the gas oracle was introduced or registered through the admin API.
+    pub gas_oracle_inputs: Option<GasOracleInputs>,
+}
+
+/// Fee estimate returned by a gas oracle, along with the data it was derived
+/// from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasOracleInputs {
+    pub source: GasOracleSource,
+    /// Estimated `max_fee_per_gas` (wei).
+    pub max_fee_per_gas: u128,
+    /// Estimated `max_priority_fee_per_gas` (wei).
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// Oracle a fee estimate comes from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GasOracleSource {
+    /// The L1 provider's own EIP-1559 fee estimation.
+    Provider,
+    /// A percentile of the priority fees paid over recent blocks, read from
+    /// `eth_feeHistory`.
+    FeeHistory {
+        block_count: u64,
+        reward_percentile: u8,
+        /// Base fee of the next block (wei), as reported by the node.
+        base_fee_per_gas: u128,
+    },
+    /// Fees fixed in the configuration.
+    Fixed,
 }
 
 #[cfg(test)]
@@ -13,6 +13,7 @@ fn is_resolved_elsewhere_matches_used_and_settled_kinds() {
     assert!(client_error(ClientErrorType::SettlementSucceededElsewhere).is_resolved_elsewhere());
     assert!(!client_error(ClientErrorType::Unknown).is_resolved_elsewhere());
     assert!(!client_error(ClientErrorType::AbandonedByAdmin).is_resolved_elsewhere());
+    assert!(!client_error(ClientErrorType::SimulatedRevert).is_resolved_elsewhere());
 }
 
 #[test]
@@ -24,6 +25,7 @@ fn abandoned_by_admin_yields_only_to_on_chain_evidence() {
         block_hash: B256::ZERO,
         block_number: 0,
         tx_hash: SettlementTxHash::from(crate::Digest::from([0; 32])),
+        gas_cost: None,
     });
 
     // Real on-chain evidence supersedes an admin abandon assertion.
@@ -46,3 +48,49 @@ fn abandoned_by_admin_yields_only_to_on_chain_evidence() {
     assert!(!client_error(ClientErrorType::Unknown)
         .can_be_replaced_by(&client_error(ClientErrorType::AbandonedByAdmin)));
 }
+
+#[test]
+fn recorded_gas_cost_upgrades_the_same_inclusion_only() {
+    let without_cost = ContractCallResult {
+        outcome: ContractCallOutcome::Success,
+        metadata: Bytes::new(),
+        block_hash: B256::repeat_byte(1),
+        block_number: 10,
+        tx_hash: SettlementTxHash::from(crate::Digest::from([2; 32])),
+        gas_cost: None,
+    };
+    let with_cost = ContractCallResult {
+        gas_cost: Some(SettlementGasCost {
+            gas_used: 21_000,
+            effective_gas_price: 3,
+        }),
+        ..without_cost.clone()
+    };
+    let other_block = ContractCallResult {
+        block_hash: B256::repeat_byte(3),
+        ..with_cost.clone()
+    };
+
+    assert_eq!(with_cost.gas_cost.unwrap().fee(), 63_000);
+    assert!(SettlementAttemptResult::ContractCall(without_cost.clone())
+        .can_be_replaced_by(&SettlementAttemptResult::ContractCall(with_cost.clone())));
+    assert!(!SettlementAttemptResult::ContractCall(with_cost.clone())
+        .can_be_replaced_by(&SettlementAttemptResult::ContractCall(without_cost.clone())));
+    assert!(!SettlementAttemptResult::ContractCall(without_cost)
+        .can_be_replaced_by(&SettlementAttemptResult::ContractCall(other_block)));
+}
+
+#[test]
+fn split_gas_cost_adds_up_to_the_whole() {
+    let gas_cost = SettlementGasCost {
+        gas_used: 100_001,
+        effective_gas_price: 7,
+    };
+
+    assert_eq!(
+        gas_cost.split(3),
+        vec![(33_334, 233_336), (33_334, 233_336), (33_333, 233_335)]
+    );
+    assert_eq!(gas_cost.split(1), vec![(100_001, 700_007)]);
+    assert!(gas_cost.split(0).is_empty());
+}
@@ -0,0 +1,42 @@
+use agglayer_tries::roots::LocalExitRoot;
+use serde::{Deserialize, Serialize};
+
+use crate::{CertificateId, CertificateStatusError, Digest, Height, NetworkId};
+
+/// Result of the dry-run of a certificate against the settled state of its
+/// network.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CertificateSimulation {
+    pub certificate_id: CertificateId,
+    pub network_id: NetworkId,
+    pub height: Height,
+    pub outcome: SimulationOutcome,
+}
+
+/// Outcome of a certificate simulation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulationOutcome {
+    /// The certificate would be proven, leading to the given roots.
+    Success(SimulatedRoots),
+    /// The certificate would be put in error with the given error.
+    Failure(CertificateStatusError),
+}
+
+/// Roots of the local network state once the simulated certificate is
+/// applied.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SimulatedRoots {
+    /// The new root of the local exit tree.
+    pub new_local_exit_root: LocalExitRoot,
+    /// The new number of leaves of the local exit tree.
+    pub new_let_leaf_count: u32,
+    /// The new root of the local balance tree.
+    pub new_balance_root: Digest,
+    /// The new root of the nullifier tree.
+    pub new_nullifier_root: Digest,
+    /// The pessimistic root the certificate is applied on.
+    pub prev_pessimistic_root: Digest,
+    /// The pessimistic root the proof of the certificate would commit to.
+    pub new_pessimistic_root: Digest,
+}
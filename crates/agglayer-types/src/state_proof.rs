@@ -0,0 +1,82 @@
+use agglayer_tries::{proof::SmtMerkleProof, roots::LocalExitRoot};
+use pessimistic_proof::{
+    local_balance_tree::LocalBalancePath,
+    nullifier_tree::{NullifierPath, NULLIFIER_TREE_DEPTH},
+};
+use serde::{Deserialize, Serialize};
+use unified_bridge::{GlobalIndex, LETMerkleProof, TokenInfo};
+
+use crate::{CertificateId, Digest, Height, U256};
+
+/// The settled state against which a Merkle proof is issued.
+///
+/// The roots are the ones of the local network state once the certificate
+/// `certificate_id` at `height` got settled, i.e. the state proven by the
+/// pessimistic proof of that certificate. Unless a height is requested, this
+/// is the latest settled certificate of the network.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SettledStateAnchor {
+    /// The ID of the settled certificate.
+    pub certificate_id: CertificateId,
+    /// The height of the settled certificate.
+    pub height: Height,
+    /// The root of the local exit tree.
+    pub local_exit_root: LocalExitRoot,
+    /// The number of leaves of the local exit tree.
+    pub let_leaf_count: u32,
+    /// The root of the local balance tree.
+    pub balance_root: Digest,
+    /// The root of the nullifier tree.
+    pub nullifier_root: Digest,
+}
+
+/// Proof of the balance of one token in the local balance tree.
+///
+/// A token without balance is proven with a zero leaf.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BalanceProof {
+    pub anchor: SettledStateAnchor,
+    pub token_info: TokenInfo,
+    /// The balance, stored as the big-endian leaf value.
+    pub balance: U256,
+    /// The path from the leaf to [`SettledStateAnchor::balance_root`].
+    pub path: LocalBalancePath,
+}
+
+/// Proof that a global index is claimed or not in the nullifier tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NullifierProof {
+    pub anchor: SettledStateAnchor,
+    pub global_index: GlobalIndex,
+    /// The path to [`SettledStateAnchor::nullifier_root`].
+    pub path: NullifierProofPath,
+}
+
+/// Path of a [`NullifierProof`], depending on whether the global index is in
+/// the nullifier tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NullifierProofPath {
+    /// The global index has been claimed, its leaf is set to one.
+    Inclusion(SmtMerkleProof<NULLIFIER_TREE_DEPTH>),
+    /// The global index has not been claimed.
+    NonInclusion(NullifierPath),
+}
+
+impl NullifierProofPath {
+    /// Returns whether the global index has been claimed.
+    pub fn is_claimed(&self) -> bool {
+        matches!(self, Self::Inclusion(_))
+    }
+}
+
+/// Proof of one leaf of the local exit tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LocalExitTreeProof {
+    pub anchor: SettledStateAnchor,
+    pub leaf_index: u32,
+    /// The hash of the bridge exit at `leaf_index`.
+    pub leaf: Digest,
+    /// The path from the leaf to [`SettledStateAnchor::local_exit_root`].
+    pub path: LETMerkleProof<32>,
+}
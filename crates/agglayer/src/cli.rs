@@ -1,6 +1,7 @@
 //! Agglayer command line interface.
 use std::path::{Path, PathBuf};
 
+use agglayer_storage::{backup::BackupDb, diagnostics::doctor::Store};
 use clap::{Parser, Subcommand, ValueHint};
 
 use crate::version;
@@ -48,6 +49,14 @@ pub(crate) enum Commands {
 
     #[clap(subcommand)]
     Backup(Backup),
+
+    /// Inspect and repair the databases.
+    #[clap(subcommand, alias = "doctor")]
+    Storage(Storage),
+
+    /// Export and import portable snapshots of the state and pending stores.
+    #[clap(subcommand)]
+    Snapshot(Snapshot),
 }
 
 #[derive(Subcommand)]
@@ -56,6 +65,10 @@ pub(crate) enum Backup {
     List {
         #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
         config_path: PathBuf,
+        /// List the backups uploaded to the remote storage instead of the local
+        /// ones.
+        #[arg(long)]
+        remote: bool,
     },
 
     /// Restore from a backup.
@@ -64,6 +77,102 @@ pub(crate) enum Backup {
         config_path: PathBuf,
         #[arg(value_parser = parse_db_kind_version)]
         db_versions: Vec<(DbKind, u32)>,
+        /// Download the backups from the remote storage before restoring them.
+        #[arg(long)]
+        from_remote: bool,
+    },
+
+    /// Restore backups into a temporary directory and check that they are
+    /// readable and consistent, printing a JSON report.
+    Verify {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        #[arg(value_parser = parse_db_kind_version, required = true)]
+        db_versions: Vec<(DbKind, u32)>,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Storage {
+    /// Scan every store for rows that fail to decode, printing them as JSON.
+    Scan {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+    },
+
+    /// Delete the rows of a store that fail to decode, after backing the store
+    /// up. The node must be stopped.
+    Purge {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        /// The store to purge: `state`, `pending`, `debug` or `epoch_<n>`.
+        store: Store,
+        /// Only purge the rows of this column family.
+        #[arg(long)]
+        cf: Option<String>,
+        /// Report the rows that would be deleted without deleting them.
+        #[arg(long)]
+        dry_run: bool,
+        /// Back the store up under this directory instead of the configured
+        /// backup path.
+        #[arg(long, value_hint = ValueHint::DirPath)]
+        backup_path: Option<PathBuf>,
+    },
+
+    /// Print the number of keys and their size for every column family.
+    Stats {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        /// The stores to inspect, all of them if none is given.
+        stores: Vec<Store>,
+    },
+
+    /// Decode and print a single row.
+    Get {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        store: Store,
+        /// The column family of the row.
+        cf: String,
+        /// The raw key of the row, hex encoded.
+        key: String,
+    },
+
+    /// Rebuild the network states by replaying their settled certificates and
+    /// compare them with the persisted states and with L1, printing a JSON
+    /// report. The databases are opened read-only.
+    VerifyState {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        /// Only verify this network.
+        #[arg(long)]
+        network_id: Option<u32>,
+        /// Don't check the pessimistic roots against L1.
+        #[arg(long)]
+        skip_l1: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum Snapshot {
+    /// Export the state and pending stores to a snapshot file, printing a JSON
+    /// report. The node must be stopped.
+    Export {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        /// The snapshot file to write.
+        #[arg(value_hint = ValueHint::FilePath)]
+        output: PathBuf,
+    },
+
+    /// Import a snapshot file into empty state and pending stores, printing a
+    /// JSON report. The node must be stopped.
+    Import {
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+        /// The snapshot file to read.
+        #[arg(value_hint = ValueHint::FilePath)]
+        input: PathBuf,
     },
 }
 
@@ -89,6 +198,14 @@ impl DbKind {
             ),
         }
     }
+
+    pub(crate) fn backup_db(&self) -> BackupDb {
+        match self {
+            Self::State => BackupDb::State,
+            Self::Pending => BackupDb::Pending,
+            Self::Epoch(epoch_number) => BackupDb::Epoch(*epoch_number),
+        }
+    }
 }
 
 impl std::str::FromStr for DbKind {
@@ -176,4 +293,83 @@ mod tests {
         assert_eq!(destination, path_normal.join("epochs/10"));
         assert_eq!(backup, path_backup.join("epochs/10"));
     }
+
+    #[test]
+    fn storage_doctor_alias() {
+        let cli = Cli::try_parse_from([
+            "agglayer",
+            "doctor",
+            "purge",
+            "epoch_3",
+            "--dry-run",
+            "--cf",
+            "epoch_certificate_per_index",
+        ])
+        .unwrap();
+
+        let Commands::Storage(Storage::Purge {
+            store, cf, dry_run, ..
+        }) = cli.cmd
+        else {
+            panic!("expected a storage purge command");
+        };
+        assert_eq!(store, Store::Epoch(3));
+        assert_eq!(cf.as_deref(), Some("epoch_certificate_per_index"));
+        assert!(dry_run);
+    }
+
+    #[test]
+    fn storage_verify_state() {
+        let cli = Cli::try_parse_from([
+            "agglayer",
+            "storage",
+            "verify-state",
+            "--network-id",
+            "2",
+            "--skip-l1",
+        ])
+        .unwrap();
+
+        let Commands::Storage(Storage::VerifyState {
+            network_id,
+            skip_l1,
+            ..
+        }) = cli.cmd
+        else {
+            panic!("expected a storage verify-state command");
+        };
+        assert_eq!(network_id, Some(2));
+        assert!(skip_l1);
+    }
+
+    #[test]
+    fn snapshot_import() {
+        let cli = Cli::try_parse_from([
+            "agglayer",
+            "snapshot",
+            "import",
+            "--config-path",
+            "/tmp/agglayer.toml",
+            "/tmp/node.snapshot",
+        ])
+        .unwrap();
+
+        let Commands::Snapshot(Snapshot::Import { config_path, input }) = cli.cmd else {
+            panic!("expected a snapshot import command");
+        };
+        assert_eq!(config_path, PathBuf::from("/tmp/agglayer.toml"));
+        assert_eq!(input, PathBuf::from("/tmp/node.snapshot"));
+    }
+
+    #[test]
+    fn backup_db_matches_the_backup_path() {
+        let config = Config::new(&PathBuf::from("/tmp/normal"));
+        let path_backup = PathBuf::from("/tmp/storage/backup");
+
+        for kind in [DbKind::State, DbKind::Pending, DbKind::Epoch(10)] {
+            let (_, backup) = kind.create_paths(&config, &path_backup);
+
+            assert_eq!(backup, path_backup.join(kind.backup_db().path()));
+        }
+    }
 }
@@ -1,6 +1,11 @@
-use std::process::exit;
+use std::{process::exit, sync::Arc};
 
-use agglayer_config::storage::backup::BackupConfig;
+use agglayer_config::storage::backup::{BackupConfig, RemoteBackupConfig};
+use agglayer_storage::{
+    backup::remote::RemoteBackups,
+    diagnostics::doctor::{self, Store},
+    snapshot,
+};
 use clap::Parser;
 use cli::Cli;
 use eyre::Context as _;
@@ -57,10 +62,21 @@ fn main() -> eyre::Result<()> {
             println!("0x{vkey_selector_hex}");
         }
 
-        cli::Commands::Backup(cli::Backup::List { config_path: cfg }) => {
+        cli::Commands::Backup(cli::Backup::List {
+            config_path: cfg,
+            remote,
+        }) => {
             let cfg = agglayer_config::Config::try_load(&cfg)?;
 
-            if let BackupConfig::Enabled { path, .. } = cfg.storage.backup {
+            if remote {
+                let remote = remote_backup_config(&cfg.storage.backup);
+                match block_on(
+                    async move { RemoteBackups::from_config(&remote).await.list().await },
+                )? {
+                    Ok(result) => println!("{}", serde_json::to_string(&result).unwrap()),
+                    Err(error) => eprintln!("{error}"),
+                }
+            } else if let BackupConfig::Enabled { path, .. } = cfg.storage.backup {
                 match agglayer_storage::backup::BackupEngine::list_backups(&path) {
                     Ok(result) => println!("{}", serde_json::to_string(&result).unwrap()),
                     Err(error) => eprintln!("{error}"),
@@ -71,10 +87,31 @@ fn main() -> eyre::Result<()> {
         cli::Commands::Backup(cli::Backup::Restore {
             config_path: cfg,
             db_versions,
+            from_remote,
         }) => {
             let cfg = agglayer_config::Config::try_load(&cfg)?;
 
             if let BackupConfig::Enabled { ref path, .. } = cfg.storage.backup {
+                if from_remote {
+                    let remote = remote_backup_config(&cfg.storage.backup);
+                    let downloads = db_versions
+                        .iter()
+                        .map(|(db_kind, version)| {
+                            let (_, backup_path) = db_kind.create_paths(&cfg, path);
+                            (db_kind.backup_db(), *version, backup_path)
+                        })
+                        .collect::<Vec<_>>();
+
+                    block_on(async move {
+                        let remote = RemoteBackups::from_config(&remote).await;
+                        for (db, version, backup_path) in downloads {
+                            remote.download(db, version, &backup_path).await?;
+                        }
+
+                        Ok::<_, eyre::Error>(())
+                    })??;
+                }
+
                 for (db_kind, version) in db_versions {
                     let (db_path, backup_path) = db_kind.create_paths(&cfg, path);
 
@@ -89,11 +126,174 @@ fn main() -> eyre::Result<()> {
                 exit(1);
             }
         }
+
+        cli::Commands::Backup(cli::Backup::Verify {
+            config_path: cfg,
+            db_versions,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            if let BackupConfig::Enabled { ref path, .. } = cfg.storage.backup {
+                let backups = db_versions
+                    .iter()
+                    .map(|(db_kind, version)| (db_kind.backup_db(), *version))
+                    .collect::<Vec<_>>();
+                let report = agglayer_storage::backup::verify::verify_backups(path, &backups)?;
+
+                println!("{}", serde_json::to_string(&report)?);
+                if !report.ok {
+                    exit(2);
+                }
+            } else {
+                println!("Backups are not enabled in the configuration file.");
+                exit(1);
+            }
+        }
+
+        cli::Commands::Storage(cli::Storage::Scan { config_path: cfg }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            let mut rows = Vec::new();
+            for store in Store::all(&cfg.storage)? {
+                rows.extend(doctor::scan_store(store, &store.db_path(&cfg.storage))?);
+            }
+
+            println!("{}", serde_json::to_string(&rows)?);
+        }
+
+        cli::Commands::Storage(cli::Storage::Purge {
+            config_path: cfg,
+            store,
+            cf,
+            dry_run,
+            backup_path,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            let backup_root = backup_path.or(match cfg.storage.backup {
+                BackupConfig::Enabled { ref path, .. } => Some(path.clone()),
+                BackupConfig::Disabled => None,
+            });
+            if backup_root.is_none() && !dry_run {
+                println!(
+                    "Backups are not enabled in the configuration file, pass --backup-path to \
+                     back the store up before purging."
+                );
+                exit(1);
+            }
+
+            let report = doctor::purge_unparsable_rows(
+                store,
+                &store.db_path(&cfg.storage),
+                backup_root.as_deref(),
+                cf.as_deref(),
+                dry_run,
+            )?;
+
+            println!("{}", serde_json::to_string(&report)?);
+        }
+
+        cli::Commands::Storage(cli::Storage::Stats {
+            config_path: cfg,
+            stores,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            let stores = if stores.is_empty() {
+                Store::all(&cfg.storage)?
+            } else {
+                stores
+            };
+
+            let mut stats = Vec::new();
+            for store in stores {
+                stats.extend(doctor::store_stats(store, &store.db_path(&cfg.storage))?);
+            }
+
+            println!("{}", serde_json::to_string(&stats)?);
+        }
+
+        cli::Commands::Storage(cli::Storage::Get {
+            config_path: cfg,
+            store,
+            cf,
+            key,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+            let key = hex::decode(key.trim_start_matches("0x")).context("Invalid hex key")?;
+
+            match doctor::get_row(store, &store.db_path(&cfg.storage), &cf, &key)? {
+                Some(row) => println!("{}", serde_json::to_string(&row)?),
+                None => {
+                    println!("No row found for this key.");
+                    exit(1);
+                }
+            }
+        }
+
+        cli::Commands::Storage(cli::Storage::VerifyState {
+            config_path: cfg,
+            network_id,
+            skip_l1,
+        }) => {
+            let cfg = Arc::new(agglayer_config::Config::try_load(&cfg)?);
+
+            let reports = block_on(agglayer_node::verify_state(
+                cfg,
+                network_id.map(Into::into),
+                !skip_l1,
+            ))??;
+
+            println!("{}", serde_json::to_string(&reports)?);
+            if !reports.iter().all(|report| report.is_consistent()) {
+                exit(2);
+            }
+        }
+
+        cli::Commands::Snapshot(cli::Snapshot::Export {
+            config_path: cfg,
+            output,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            let report = snapshot::export_snapshot(&cfg.storage, &output)?;
+
+            println!("{}", serde_json::to_string(&report)?);
+        }
+
+        cli::Commands::Snapshot(cli::Snapshot::Import {
+            config_path: cfg,
+            input,
+        }) => {
+            let cfg = agglayer_config::Config::try_load(&cfg)?;
+
+            let report = snapshot::import_snapshot(&cfg.storage, &input)?;
+
+            println!("{}", serde_json::to_string(&report)?);
+        }
     }
 
     Ok(())
 }
 
+/// The remote backup storage of the configuration, exiting if none is
+/// configured.
+fn remote_backup_config(backup: &BackupConfig) -> RemoteBackupConfig {
+    let Some(remote) = backup.remote() else {
+        println!("Remote backups are not enabled in the configuration file.");
+        exit(1);
+    };
+
+    remote.clone()
+}
+
+fn block_on<F: std::future::Future>(future: F) -> eyre::Result<F::Output> {
+    Ok(tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(future))
+}
+
 fn install_default_crypto_provider() {
     // rustls cannot infer a provider when transitive dependencies enable both
     // built-in crypto backends. Install one before any TLS client is built.
@@ -117,6 +117,72 @@ impl<const TREE_DEPTH: usize> LocalExitTreeData<TREE_DEPTH> {
 
         Ok(LETMerkleProof { siblings })
     }
+
+    /// Returns the number of leaves of the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// Returns the root of the tree holding only the first `leaf_count`
+    /// leaves of this one.
+    pub fn get_root_at(&self, leaf_count: usize) -> Result<Digest, LocalExitTreeError> {
+        if leaf_count > self.leaf_count() {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+        let left = self.get_at(TREE_DEPTH - 1, 0, leaf_count)?;
+        let right = self.get_at(TREE_DEPTH - 1, 1, leaf_count)?;
+        Ok(keccak256_combine([&left, &right]))
+    }
+
+    /// Returns the proof of a leaf in the tree holding only the first
+    /// `leaf_count` leaves of this one.
+    pub fn get_proof_at(
+        &self,
+        leaf_index: u32,
+        leaf_count: usize,
+    ) -> Result<LETMerkleProof<TREE_DEPTH>, LocalExitTreeError> {
+        let leaf_index: usize = leaf_index
+            .try_into()
+            .map_err(|_| LocalExitTreeError::LeafIndexOverflow)?;
+        if leaf_count > self.leaf_count() || leaf_index >= leaf_count {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+        let mut siblings = [Default::default(); TREE_DEPTH];
+        let mut index = leaf_index;
+
+        for (height, sibling) in siblings.iter_mut().enumerate().take(TREE_DEPTH) {
+            *sibling = self.get_at(height, index ^ 1, leaf_count)?;
+            index >>= 1;
+        }
+
+        Ok(LETMerkleProof { siblings })
+    }
+
+    /// Returns the node at `height` and `index` of the tree holding only the
+    /// first `leaf_count` leaves of this one.
+    ///
+    /// Nodes covering only kept leaves are shared with this tree, and those
+    /// covering none are empty. The one node per layer straddling the last
+    /// kept leaf is recomputed from its children.
+    fn get_at(
+        &self,
+        height: usize,
+        index: usize,
+        leaf_count: usize,
+    ) -> Result<Digest, LocalExitTreeError> {
+        if index >= 1 << (TREE_DEPTH - height) {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+        if index << height >= leaf_count {
+            return Ok(empty_hash_array_at_height::<TREE_DEPTH>()[height]);
+        }
+        if (index + 1) << height <= leaf_count {
+            return self.get(height, index);
+        }
+        let left = self.get_at(height - 1, 2 * index, leaf_count)?;
+        let right = self.get_at(height - 1, 2 * index + 1, leaf_count)?;
+        Ok(keccak256_combine([&left, &right]))
+    }
 }
 
 #[cfg(test)]
@@ -189,4 +255,29 @@ mod tests {
         let proof = local_exit_tree_data.get_proof(leaf_index as u32).unwrap();
         assert!(proof.verify(leaf, leaf_index as u32, root));
     }
+
+    #[test]
+    fn test_merkle_proofs_at_fewer_leaves() {
+        let num_leaves = rng().random_range(1..=100.min(1 << TREE_DEPTH));
+        let leaves = (0..num_leaves).map(|_| random_digest()).collect::<Vec<_>>();
+        let local_exit_tree_data: LocalExitTreeData<TREE_DEPTH> =
+            LocalExitTreeData::from_leaves(leaves.iter().cloned()).unwrap();
+
+        for leaf_count in 1..=num_leaves {
+            let root =
+                LocalExitTreeData::<TREE_DEPTH>::from_leaves(leaves[..leaf_count].iter().cloned())
+                    .unwrap()
+                    .get_root();
+            assert_eq!(local_exit_tree_data.get_root_at(leaf_count).unwrap(), root);
+
+            let leaf_index = rng().random_range(0..leaf_count);
+            let proof = local_exit_tree_data
+                .get_proof_at(leaf_index as u32, leaf_count)
+                .unwrap();
+            assert!(proof.verify(leaves[leaf_index], leaf_index as u32, root));
+        }
+        assert!(local_exit_tree_data
+            .get_proof_at(0, num_leaves + 1)
+            .is_err());
+    }
 }
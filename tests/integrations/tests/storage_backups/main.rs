@@ -205,6 +205,8 @@ async fn purge_after_n_backup(#[case] state: Forest) {
         path: backup_dir.path.clone(),
         state_max_backup_count: 1,
         pending_max_backup_count: 1,
+        remote: None,
+        restore_drill_interval: None,
     };
 
     let handle = CancellationToken::new();